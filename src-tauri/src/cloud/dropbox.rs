@@ -0,0 +1,168 @@
+//! Dropbox API v2
+//!
+//! 150MB 이하는 `files/upload` 한 번, 그보다 크면 upload session으로 나눠 올립니다.
+
+use serde::Deserialize;
+
+use super::{error_from_response, parse_time, CloudBackupFile};
+
+const API_BASE: &str = "https://api.dropboxapi.com/2";
+const CONTENT_BASE: &str = "https://content.dropboxapi.com/2";
+const BACKUP_FOLDER: &str = "/OddEyes Backups";
+const SINGLE_UPLOAD_LIMIT: usize = 150 * 1024 * 1024;
+const SESSION_CHUNK: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+struct FileMetadata {
+    #[serde(rename = ".tag", default)]
+    tag: Option<String>,
+    id: String,
+    name: String,
+    #[serde(default)]
+    size: u64,
+    #[serde(default)]
+    server_modified: Option<String>,
+}
+
+impl From<FileMetadata> for CloudBackupFile {
+    fn from(m: FileMetadata) -> Self {
+        Self {
+            modified_at: m.server_modified.as_deref().map(parse_time).unwrap_or(0),
+            id: m.id,
+            name: m.name,
+            size: m.size,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListFolderResult {
+    entries: Vec<FileMetadata>,
+    cursor: String,
+    has_more: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionStart {
+    session_id: String,
+}
+
+/// content 엔드포인트 호출 (인자는 `Dropbox-API-Arg` 헤더로 전달)
+async fn content_call(token: &str, endpoint: &str, arg: serde_json::Value, body: Vec<u8>) -> Result<reqwest::Response, String> {
+    let response = crate::http::client()?
+        .post(format!("{}/{}", CONTENT_BASE, endpoint))
+        .bearer_auth(token)
+        .header("Dropbox-API-Arg", arg.to_string())
+        .header("Content-Type", "application/octet-stream")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Dropbox request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Dropbox", response).await);
+    }
+    Ok(response)
+}
+
+async fn rpc_call(token: &str, endpoint: &str, arg: serde_json::Value) -> Result<reqwest::Response, String> {
+    crate::http::client()?
+        .post(format!("{}/{}", API_BASE, endpoint))
+        .bearer_auth(token)
+        .json(&arg)
+        .send()
+        .await
+        .map_err(|e| format!("Dropbox request failed: {}", e))
+}
+
+pub async fn upload(token: &str, name: &str, bytes: Vec<u8>) -> Result<CloudBackupFile, String> {
+    let commit = serde_json::json!({
+        "path": format!("{}/{}", BACKUP_FOLDER, name),
+        "mode": "add",
+        "autorename": true,
+    });
+
+    let response = if bytes.len() <= SINGLE_UPLOAD_LIMIT {
+        content_call(token, "files/upload", commit, bytes).await?
+    } else {
+        let mut chunks = bytes.chunks(SESSION_CHUNK);
+        let first = chunks.next().unwrap_or_default().to_vec();
+        let mut offset = first.len();
+        let session: SessionStart = content_call(token, "files/upload_session/start", serde_json::json!({}), first)
+            .await?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Dropbox session: {}", e))?;
+        for chunk in chunks {
+            let arg = serde_json::json!({
+                "cursor": { "session_id": session.session_id, "offset": offset },
+            });
+            content_call(token, "files/upload_session/append_v2", arg, chunk.to_vec()).await?;
+            offset += chunk.len();
+        }
+        let arg = serde_json::json!({
+            "cursor": { "session_id": session.session_id, "offset": offset },
+            "commit": commit,
+        });
+        content_call(token, "files/upload_session/finish", arg, Vec::new()).await?
+    };
+
+    let metadata: FileMetadata = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Dropbox upload result: {}", e))?;
+    Ok(metadata.into())
+}
+
+pub async fn list(token: &str) -> Result<Vec<CloudBackupFile>, String> {
+    let response = rpc_call(token, "files/list_folder", serde_json::json!({ "path": BACKUP_FOLDER })).await?;
+    // 아직 백업한 적이 없으면 폴더가 없음 (409 path/not_found)
+    if response.status() == reqwest::StatusCode::CONFLICT {
+        return Ok(Vec::new());
+    }
+    if !response.status().is_success() {
+        return Err(error_from_response("Dropbox", response).await);
+    }
+
+    let mut files = Vec::new();
+    let mut page: ListFolderResult = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Dropbox folder: {}", e))?;
+    loop {
+        files.extend(
+            page.entries
+                .into_iter()
+                .filter(|e| e.tag.as_deref() == Some("file"))
+                .map(CloudBackupFile::from),
+        );
+        if !page.has_more {
+            break;
+        }
+        let response = rpc_call(token, "files/list_folder/continue", serde_json::json!({ "cursor": page.cursor })).await?;
+        if !response.status().is_success() {
+            return Err(error_from_response("Dropbox", response).await);
+        }
+        page = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Dropbox folder: {}", e))?;
+    }
+    Ok(files)
+}
+
+pub async fn download(token: &str, id: &str) -> Result<Vec<u8>, String> {
+    let response = content_call(token, "files/download", serde_json::json!({ "path": id }), Vec::new()).await?;
+    response
+        .bytes()
+        .await
+        .map(|b| b.to_vec())
+        .map_err(|e| format!("Failed to download from Dropbox: {}", e))
+}
+
+pub async fn delete(token: &str, id: &str) -> Result<(), String> {
+    let response = rpc_call(token, "files/delete_v2", serde_json::json!({ "path": id })).await?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Dropbox", response).await);
+    }
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! Google Drive API v3
+//!
+//! 크기와 관계없이 resumable upload(세션 생성 후 한 번에 PUT)를 사용합니다.
+
+use serde::Deserialize;
+
+use super::{error_from_response, parse_time, CloudBackupFile};
+use crate::http::RetryPolicy;
+
+const API_BASE: &str = "https://www.googleapis.com/drive/v3";
+const UPLOAD_BASE: &str = "https://www.googleapis.com/upload/drive/v3";
+/// 백업 파일 표시용 appProperties 키
+const BACKUP_PROPERTY: &str = "oddeyesBackup";
+const FILE_FIELDS: &str = "id,name,size,modifiedTime";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveFile {
+    id: String,
+    name: String,
+    /// int64는 문자열로 옴
+    #[serde(default)]
+    size: Option<String>,
+    #[serde(default)]
+    modified_time: Option<String>,
+}
+
+impl From<DriveFile> for CloudBackupFile {
+    fn from(f: DriveFile) -> Self {
+        Self {
+            size: f.size.and_then(|s| s.parse().ok()).unwrap_or(0),
+            modified_at: f.modified_time.as_deref().map(parse_time).unwrap_or(0),
+            id: f.id,
+            name: f.name,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileList {
+    #[serde(default)]
+    files: Vec<DriveFile>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+pub async fn upload(token: &str, name: &str, bytes: Vec<u8>) -> Result<CloudBackupFile, String> {
+    let client = crate::http::client()?;
+    let response = client
+        .post(format!("{}/files", UPLOAD_BASE))
+        .bearer_auth(token)
+        .query(&[("uploadType", "resumable"), ("fields", FILE_FIELDS)])
+        .header("X-Upload-Content-Type", "application/octet-stream")
+        .header("X-Upload-Content-Length", bytes.len().to_string())
+        .json(&serde_json::json!({
+            "name": name,
+            "mimeType": "application/octet-stream",
+            "appProperties": { BACKUP_PROPERTY: "1" },
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Google Drive request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Google Drive", response).await);
+    }
+    let session_url = response
+        .headers()
+        .get("location")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+        .ok_or("Google Drive did not return an upload session")?;
+
+    let response = client
+        .put(session_url)
+        .bearer_auth(token)
+        .header("Content-Type", "application/octet-stream")
+        .body(bytes)
+        .send()
+        .await
+        .map_err(|e| format!("Google Drive upload failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Google Drive", response).await);
+    }
+    let file: DriveFile = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Google Drive upload result: {}", e))?;
+    Ok(file.into())
+}
+
+pub async fn list(token: &str) -> Result<Vec<CloudBackupFile>, String> {
+    let client = crate::http::client()?;
+    let query = format!(
+        "appProperties has {{ key='{}' and value='1' }} and trashed = false",
+        BACKUP_PROPERTY
+    );
+    let fields = format!("nextPageToken,files({})", FILE_FIELDS);
+    let mut files = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let mut request = client
+            .get(format!("{}/files", API_BASE))
+            .bearer_auth(token)
+            .query(&[
+                ("q", query.as_str()),
+                ("fields", fields.as_str()),
+                ("orderBy", "modifiedTime desc"),
+                ("pageSize", "100"),
+            ]);
+        if let Some(t) = &page_token {
+            request = request.query(&[("pageToken", t)]);
+        }
+        let response = crate::http::send_with_retry(request, RetryPolicy::CLOUD)
+            .await
+            .map_err(|e| format!("Google Drive request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(error_from_response("Google Drive", response).await);
+        }
+        let page: FileList = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Google Drive files: {}", e))?;
+        files.extend(page.files.into_iter().map(CloudBackupFile::from));
+        match page.next_page_token {
+            Some(t) => page_token = Some(t),
+            None => break,
+        }
+    }
+    Ok(files)
+}
+
+pub async fn download(token: &str, id: &str) -> Result<Vec<u8>, String> {
+    let request = crate::http::client()?
+        .get(format!("{}/files/{}", API_BASE, urlencoding::encode(id)))
+        .bearer_auth(token)
+        .query(&[("alt", "media")]);
+    let response = crate::http::send_with_retry(request, RetryPolicy::CLOUD)
+        .await
+        .map_err(|e| format!("Google Drive request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Google Drive", response).await);
+    }
+    response
+        .bytes()
+        .await
+        .map(|b| b.to_vec())
+        .map_err(|e| format!("Failed to download from Google Drive: {}", e))
+}
+
+pub async fn delete(token: &str, id: &str) -> Result<(), String> {
+    let response = crate::http::client()?
+        .delete(format!("{}/files/{}", API_BASE, urlencoding::encode(id)))
+        .bearer_auth(token)
+        .send()
+        .await
+        .map_err(|e| format!("Google Drive request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Google Drive", response).await);
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Cloud Storage
+//!
+//! 커넥터(OAuth) 토큰으로 클라우드 저장소에 백업 파일을 올리고 내려받습니다.
+//! - Dropbox: `/OddEyes Backups` 폴더
+//! - Google Drive: `appProperties.oddeyesBackup = "1"`로 표시한 파일 (앱이 만든 파일만 보이는 권한으로도 동작)
+
+pub mod dropbox;
+pub mod gdrive;
+
+use serde::Serialize;
+
+/// 백업을 지원하는 커넥터
+pub const BACKUP_CONNECTORS: &[&str] = &["googledrive", "dropbox"];
+
+/// 백업 파일 이름 접두사
+pub const CLOUD_BACKUP_PREFIX: &str = "oddeyes-backup-";
+
+/// 클라우드에 있는 백업 파일
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudBackupFile {
+    /// 저장소별 파일 ID (Dropbox `id:…`, Drive file id)
+    pub id: String,
+    pub name: String,
+    pub size: u64,
+    /// 수정 시각 (Unix ms)
+    pub modified_at: i64,
+}
+
+fn unsupported(connector_id: &str) -> String {
+    format!(
+        "Cloud backup is not supported for connector: {} (supported: {})",
+        connector_id,
+        BACKUP_CONNECTORS.join(", ")
+    )
+}
+
+/// RFC 3339 시각 → Unix ms (해석할 수 없으면 0)
+fn parse_time(value: &str) -> i64 {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|t| t.timestamp_millis())
+        .unwrap_or(0)
+}
+
+fn is_backup_name(name: &str) -> bool {
+    name.starts_with(CLOUD_BACKUP_PREFIX)
+}
+
+async fn error_from_response(service: &str, response: reqwest::Response) -> String {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let body: String = body.chars().take(300).collect();
+    format!("{} API error ({}): {}", service, status, body)
+}
+
+pub async fn upload_backup(connector_id: &str, token: &str, name: &str, bytes: Vec<u8>) -> Result<CloudBackupFile, String> {
+    match connector_id {
+        "dropbox" => dropbox::upload(token, name, bytes).await,
+        "googledrive" => gdrive::upload(token, name, bytes).await,
+        _ => Err(unsupported(connector_id)),
+    }
+}
+
+/// 백업 파일 목록 (최신 순)
+pub async fn list_backups(connector_id: &str, token: &str) -> Result<Vec<CloudBackupFile>, String> {
+    let mut files = match connector_id {
+        "dropbox" => dropbox::list(token).await?,
+        "googledrive" => gdrive::list(token).await?,
+        _ => return Err(unsupported(connector_id)),
+    };
+    files.retain(|f| is_backup_name(&f.name));
+    files.sort_by(|a, b| b.modified_at.cmp(&a.modified_at).then_with(|| b.name.cmp(&a.name)));
+    Ok(files)
+}
+
+pub async fn download_backup(connector_id: &str, token: &str, id: &str) -> Result<Vec<u8>, String> {
+    match connector_id {
+        "dropbox" => dropbox::download(token, id).await,
+        "googledrive" => gdrive::download(token, id).await,
+        _ => Err(unsupported(connector_id)),
+    }
+}
+
+pub async fn delete_backup(connector_id: &str, token: &str, id: &str) -> Result<(), String> {
+    match connector_id {
+        "dropbox" => dropbox::delete(token, id).await,
+        "googledrive" => gdrive::delete(token, id).await,
+        _ => Err(unsupported(connector_id)),
+    }
+}
+
+/// 최신 `keep`개만 남기고 삭제, 반환: 삭제한 파일 수 (삭제 실패는 로그만 남김)
+pub async fn prune_backups(connector_id: &str, token: &str, keep: usize) -> Result<usize, String> {
+    let files = list_backups(connector_id, token).await?;
+    let mut deleted = 0;
+    for file in files.iter().skip(keep.max(1)) {
+        match delete_backup(connector_id, token, &file.id).await {
+            Ok(()) => deleted += 1,
+            Err(e) => eprintln!("[Cloud] Failed to delete old backup {}: {}", file.name, e),
+        }
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_and_backup_names() {
+        assert_eq!(parse_time("2024-01-02T03:04:05Z"), 1_704_164_645_000);
+        assert_eq!(parse_time("2024-01-02T03:04:05.500Z"), 1_704_164_645_500);
+        assert_eq!(parse_time("yesterday"), 0);
+        assert!(is_backup_name("oddeyes-backup-20240102-030405.itebak"));
+        assert!(!is_backup_name("notes.txt"));
+    }
+}
@@ -1,12 +1,16 @@
 use serde::Deserialize;
-use tauri::State;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, State};
 use uuid::Uuid;
 use std::path::Path;
 use std::fs;
 
-use crate::db::DbState;
-use crate::error::{CommandError, CommandResult};
+use crate::db::{AttachmentTextSlice, DbState};
+use crate::error::{CommandError, CommandResult, ErrorCode};
 use crate::models::{Attachment, AttachmentDto};
+use crate::text::encoding::read_text_file;
+use crate::text::lang::{detect_language, language_mismatch_warning};
+use crate::text::unicode::file_name_nfc;
 use crate::utils::validate_path;
 
 /// 첨부 파일 최대 크기 (100MB)
@@ -18,34 +22,51 @@ const MAX_TEMP_IMAGE_SIZE: usize = 10 * 1024 * 1024;
 /// 임시 파일 만료 시간 (24시간)
 const TEMP_FILE_MAX_AGE_SECS: u64 = 24 * 60 * 60;
 
+/// 드래그앤드롭/붙여넣기 이미지 임시 폴더
+pub(crate) fn temp_upload_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join("oddeyes-uploads")
+}
+
 fn is_image_extension(ext: &str) -> bool {
     matches!(ext, "png" | "jpg" | "jpeg" | "webp" | "gif")
 }
 
 /// 파일 크기 검증
 fn validate_file_size(path: &Path, max_size: u64) -> CommandResult<u64> {
-    let metadata = fs::metadata(path).map_err(|e| CommandError {
-        code: "FILE_ERROR".to_string(),
-        message: format!("파일 정보를 읽을 수 없습니다: {}", e),
-        details: None,
-    })?;
+    let metadata = fs::metadata(path).map_err(|e| CommandError::new(
+        ErrorCode::FileError,
+        format!("파일 정보를 읽을 수 없습니다: {}", e),
+    ))?;
 
     let size = metadata.len();
     if size > max_size {
-        return Err(CommandError {
-            code: "FILE_TOO_LARGE".to_string(),
-            message: format!(
+        return Err(CommandError::new(
+            ErrorCode::FileTooLarge,
+            format!(
                 "파일 크기가 너무 큽니다: {}MB (최대 {}MB)",
                 size / (1024 * 1024),
                 max_size / (1024 * 1024)
             ),
-            details: None,
-        });
+        ));
     }
 
     Ok(size)
 }
 
+/// 파일 내용 SHA-256 (hex)
+fn file_sha256(path: &Path) -> CommandResult<String> {
+    let mut file = fs::File::open(path).map_err(|e| CommandError::new(
+        ErrorCode::ReadError,
+        format!("Failed to read file: {}", e),
+    ))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(|e| CommandError::new(
+        ErrorCode::ReadError,
+        format!("Failed to read file: {}", e),
+    ))?;
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AttachFileArgs {
@@ -53,71 +74,97 @@ pub struct AttachFileArgs {
     pub path: String,
 }
 
+/// 프로젝트에 파일 첨부
+/// - 같은 파일(내용 해시)이 이미 첨부되어 있으면 그 항목을 갱신하고, 추출한 텍스트가 있으면 다시 추출하지 않습니다.
 #[tauri::command]
 pub async fn attach_file(
+    app: AppHandle,
     args: AttachFileArgs,
     db_state: State<'_, DbState>,
 ) -> CommandResult<AttachmentDto> {
-    // utils::validate_path (Blocklist 적용)
-    let path = validate_path(&args.path)?;
+    crate::crash::guard(&app, "attach_file", async {
+        // utils::validate_path (Blocklist 적용)
+        let path = validate_path(&args.path)?;
+
+        // 파일 크기 검증 (100MB 제한)
+        let file_size = validate_file_size(&path, MAX_ATTACHMENT_SIZE)? as i64;
+
+        let filename = file_name_nfc(&path).unwrap_or_else(|| "unknown".to_string());
+
+        let extension = path.extension()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_lowercase())
+            .unwrap_or_default();
+
+        let content_hash = file_sha256(&path)?;
+        let (existing, cached_text) = {
+            let db = db_state.0.lock().map_err(|_| CommandError::new(
+                ErrorCode::LockError,
+                "Failed to acquire database lock",
+            ))?;
+            (
+                db.find_attachment_by_hash(&args.project_id, &content_hash)
+                    .map_err(CommandError::from)?,
+                db.cached_attachment_text(&content_hash).map_err(CommandError::from)?,
+            )
+        };
 
-    // 파일 크기 검증 (100MB 제한)
-    let file_size = validate_file_size(&path, MAX_ATTACHMENT_SIZE)? as i64;
+        // Extract text based on file type (images are stored without extracted text)
+        let extracted_text: Option<String> = if is_image_extension(&extension) {
+            None
+        } else if cached_text.is_some() {
+            cached_text
+        } else {
+            Some(
+                extract_file_text(&path, &extension).map_err(|e| CommandError::new(
+                    ErrorCode::ExtractError,
+                    format!("Failed to extract text: {}", e),
+                ))?,
+            )
+        };
 
-    let filename = path.file_name()
-        .and_then(|s| s.to_str())
-        .unwrap_or("unknown")
-        .to_string();
+        let now = chrono::Utc::now().timestamp_millis();
+        let attachment = Attachment {
+            id: existing.as_ref().map(|a| a.id.clone()).unwrap_or_else(|| Uuid::new_v4().to_string()),
+            project_id: args.project_id.clone(),
+            filename: filename.clone(),
+            file_type: extension.clone(),
+            file_path: Some(path.to_string_lossy().to_string()),
+            extracted_text,
+            file_size: Some(file_size),
+            created_at: existing.as_ref().map_or(now, |a| a.created_at),
+            updated_at: now,
+            content_hash: Some(content_hash),
+        };
 
-    let extension = path.extension()
-        .and_then(|s| s.to_str())
-        .map(|s| s.to_lowercase())
-        .unwrap_or_default();
+        let db = db_state.0.lock().map_err(|_| CommandError::new(ErrorCode::LockError, "Failed to acquire database lock"))?;
 
-    // Extract text based on file type (images are stored without extracted text)
-    let extracted_text: Option<String> = if is_image_extension(&extension) {
-        None
-    } else {
-        Some(
-            extract_file_text(&path, &extension).map_err(|e| CommandError {
-                code: "EXTRACT_ERROR".to_string(),
-                message: format!("Failed to extract text: {}", e),
-                details: None,
-            })?,
-        )
-    };
-
-    let now = chrono::Utc::now().timestamp_millis();
-    let attachment = Attachment {
-        id: Uuid::new_v4().to_string(),
-        project_id: args.project_id.clone(),
-        filename: filename.clone(),
-        file_type: extension.clone(),
-        file_path: Some(path.to_string_lossy().to_string()),
-        extracted_text,
-        file_size: Some(file_size),
-        created_at: now,
-        updated_at: now,
-    };
-
-    let db = db_state.0.lock().map_err(|_| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: "Failed to acquire database lock".to_string(),
-        details: None,
-    })?;
-
-    db.save_attachment(&attachment).map_err(CommandError::from)?;
-
-    Ok(AttachmentDto {
-        id: attachment.id,
-        filename: attachment.filename,
-        file_type: attachment.file_type,
-        file_size: attachment.file_size,
-        extracted_text: attachment.extracted_text,
-        file_path: attachment.file_path,
-        created_at: attachment.created_at,
-        updated_at: attachment.updated_at,
+        db.save_attachment(&attachment).map_err(CommandError::from)?;
+
+        // 추출한 원문이 프로젝트 언어쌍과 맞지 않으면 경고 (판단 실패는 무시)
+        let language_warning = attachment.extracted_text.as_deref().and_then(|text| {
+            let metadata = db.load_project_metadata(&args.project_id).ok()?;
+            let source_language = metadata.source_language_or(db.load_app_settings().ok()?.default_source_language);
+            language_mismatch_warning(
+                &detect_language(text),
+                source_language.as_deref(),
+                metadata.target_language.as_deref(),
+            )
+        });
+
+        Ok(AttachmentDto {
+            id: attachment.id,
+            filename: attachment.filename,
+            file_type: attachment.file_type,
+            file_size: attachment.file_size,
+            extracted_text: attachment.extracted_text,
+            file_path: attachment.file_path,
+            created_at: attachment.created_at,
+            updated_at: attachment.updated_at,
+            language_warning,
+        })
     })
+    .await
 }
 
 #[derive(Debug, Deserialize)]
@@ -136,56 +183,55 @@ pub struct PreviewAttachmentArgs {
 /// - 채팅 컴포저 전용 첨부(일회성)에서 사용합니다.
 /// - 프로젝트(Settings) 첨부 목록과 섞이지 않도록 DB를 건드리지 않습니다.
 #[tauri::command]
-pub async fn preview_attachment(args: PreviewAttachmentArgs) -> CommandResult<AttachmentDto> {
-    // utils::validate_path (Blocklist 적용)
-    let path = validate_path(&args.path)?;
-
-    // 파일 크기 검증 (100MB 제한)
-    let file_size = validate_file_size(&path, MAX_ATTACHMENT_SIZE)? as i64;
-
-    let filename = path
-        .file_name()
-        .and_then(|s| s.to_str())
-        .unwrap_or("unknown")
-        .to_string();
-
-    let extension = path
-        .extension()
-        .and_then(|s| s.to_str())
-        .map(|s| s.to_lowercase())
-        .unwrap_or_default();
-
-    let extracted_text = extract_file_text(&path, &extension).ok();
-
-    let now = chrono::Utc::now().timestamp_millis();
-    Ok(AttachmentDto {
-        id: Uuid::new_v4().to_string(),
-        filename,
-        file_type: extension,
-        file_size: Some(file_size),
-        extracted_text,
-        file_path: Some(path.to_string_lossy().to_string()),
-        created_at: now,
-        updated_at: now,
+pub async fn preview_attachment(app: AppHandle, args: PreviewAttachmentArgs) -> CommandResult<AttachmentDto> {
+    crate::crash::guard(&app, "preview_attachment", async {
+        // utils::validate_path (Blocklist 적용)
+        let path = validate_path(&args.path)?;
+
+        // 파일 크기 검증 (100MB 제한)
+        let file_size = validate_file_size(&path, MAX_ATTACHMENT_SIZE)? as i64;
+
+        let filename = file_name_nfc(&path).unwrap_or_else(|| "unknown".to_string());
+
+        let extension = path
+            .extension()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_lowercase())
+            .unwrap_or_default();
+
+        let extracted_text = extract_file_text(&path, &extension).ok();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        Ok(AttachmentDto {
+            id: Uuid::new_v4().to_string(),
+            filename,
+            file_type: extension,
+            file_size: Some(file_size),
+            extracted_text,
+            file_path: Some(path.to_string_lossy().to_string()),
+            created_at: now,
+            updated_at: now,
+            language_warning: None,
+        })
     })
+    .await
 }
 
 /// 로컬 파일을 바이트로 읽습니다.
 /// - 이미지 멀티모달(vision) 입력을 위해 프론트에서 base64로 변환할 때 사용합니다.
 /// - 파일이 사라졌거나 접근 불가하면 에러를 반환합니다.
 #[tauri::command]
-pub async fn read_file_bytes(args: ReadFileBytesArgs) -> CommandResult<Vec<u8>> {
-    // utils::validate_path (Blocklist 적용)
-    let path = validate_path(&args.path)?;
+pub async fn read_file_bytes(app: AppHandle, args: ReadFileBytesArgs) -> CommandResult<Vec<u8>> {
+    crate::crash::guard(&app, "read_file_bytes", async {
+        // utils::validate_path (Blocklist 적용)
+        let path = validate_path(&args.path)?;
 
-    // 파일 크기 검증 (100MB 제한)
-    validate_file_size(&path, MAX_ATTACHMENT_SIZE)?;
+        // 파일 크기 검증 (100MB 제한)
+        validate_file_size(&path, MAX_ATTACHMENT_SIZE)?;
 
-    fs::read(&path).map_err(|e| CommandError {
-        code: "READ_ERROR".to_string(),
-        message: format!("Failed to read file: {}", e),
-        details: None,
+        fs::read(&path).map_err(|e| CommandError::new(ErrorCode::ReadError, format!("Failed to read file: {}", e)))
     })
+    .await
 }
 
 #[tauri::command]
@@ -193,11 +239,7 @@ pub fn list_attachments(
     project_id: String,
     db_state: State<'_, DbState>,
 ) -> CommandResult<Vec<AttachmentDto>> {
-    let db = db_state.0.lock().map_err(|_| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: "Failed to acquire database lock".to_string(),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|_| CommandError::new(ErrorCode::LockError, "Failed to acquire database lock"))?;
 
     let attachments = db.list_attachments(&project_id).map_err(CommandError::from)?;
     
@@ -210,19 +252,44 @@ pub fn list_attachments(
         file_path: a.file_path,
         created_at: a.created_at,
         updated_at: a.updated_at,
+        language_warning: None,
     }).collect())
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAttachmentTextArgs {
+    pub id: String,
+    /// 시작 위치 (문자 단위, 기본 0)
+    #[serde(default)]
+    pub offset: usize,
+    /// 읽을 문자 수 (기본/최대 `MAX_ATTACHMENT_TEXT_SLICE`)
+    pub limit: Option<usize>,
+}
+
+/// 첨부 추출 텍스트를 구간으로 나눠 조회 (큰 문서를 한 번에 넘기지 않기 위함)
+#[tauri::command]
+pub fn get_attachment_text(
+    args: GetAttachmentTextArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<AttachmentTextSlice> {
+    if args.limit == Some(0) {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "limit must be greater than 0"));
+    }
+
+    let db = db_state.0.lock().map_err(|_| CommandError::new(ErrorCode::LockError, "Failed to acquire database lock"))?;
+
+    db.attachment_text_slice(&args.id, args.offset, args.limit)
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::new(ErrorCode::NotFound, format!("Attachment not found: {}", args.id)))
+}
+
 #[tauri::command]
 pub fn delete_attachment(
     id: String,
     db_state: State<'_, DbState>,
 ) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|_| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: "Failed to acquire database lock".to_string(),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|_| CommandError::new(ErrorCode::LockError, "Failed to acquire database lock"))?;
 
     db.delete_attachment(&id).map_err(CommandError::from)?;
     Ok(())
@@ -230,8 +297,9 @@ pub fn delete_attachment(
 
 fn extract_file_text(path: &Path, extension: &str) -> Result<String, String> {
     match extension {
-        "md" | "txt" => {
-            fs::read_to_string(path).map_err(|e| e.to_string())
+        // 텍스트 파일은 인코딩(EUC-KR/Shift_JIS 등)을 감지해 UTF-8로 변환
+        "md" | "txt" | "csv" | "tsv" | "srt" => {
+            read_text_file(path).map(|d| d.text).map_err(|e| e.to_string())
         },
         // 이미지 파일은 텍스트 추출 대신 "첨부 허용"만 하고, 멀티모달(vision) 입력은 프론트에서 처리합니다.
         "png" | "jpg" | "jpeg" | "webp" | "gif" => Ok(String::new()),
@@ -326,61 +394,54 @@ fn extract_pptx_text(path: &Path) -> Result<String, String> {
 /// - 드래그앤드롭 또는 클립보드에서 이미지를 붙여넣을 때 사용합니다.
 /// - 프론트엔드에서 File/Blob을 바이트 배열로 변환하여 전송합니다.
 #[tauri::command]
-pub async fn save_temp_image(bytes: Vec<u8>, filename: String) -> CommandResult<String> {
-    // 이미지 크기 검증 (10MB 제한)
-    if bytes.len() > MAX_TEMP_IMAGE_SIZE {
-        return Err(CommandError {
-            code: "FILE_TOO_LARGE".to_string(),
-            message: format!(
-                "이미지 크기가 너무 큽니다: {}MB (최대 10MB)",
-                bytes.len() / (1024 * 1024)
-            ),
-            details: None,
-        });
-    }
+pub async fn save_temp_image(app: AppHandle, bytes: Vec<u8>, filename: String) -> CommandResult<String> {
+    crate::crash::guard(&app, "save_temp_image", async {
+        // 이미지 크기 검증 (10MB 제한)
+        if bytes.len() > MAX_TEMP_IMAGE_SIZE {
+            return Err(CommandError::new(
+                ErrorCode::FileTooLarge,
+                format!(
+                    "이미지 크기가 너무 큽니다: {}MB (최대 10MB)",
+                    bytes.len() / (1024 * 1024)
+                ),
+            ));
+        }
 
-    // 파일 확장자 검증 (이미지만 허용)
-    let extension = std::path::Path::new(&filename)
-        .extension()
-        .and_then(|s| s.to_str())
-        .map(|s| s.to_lowercase())
-        .unwrap_or_default();
-
-    if !is_image_extension(&extension) {
-        return Err(CommandError {
-            code: "INVALID_TYPE".to_string(),
-            message: format!("지원하지 않는 이미지 형식입니다: {}", extension),
-            details: None,
-        });
-    }
+        // 파일 확장자 검증 (이미지만 허용)
+        let extension = std::path::Path::new(&filename)
+            .extension()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_lowercase())
+            .unwrap_or_default();
+
+        if !is_image_extension(&extension) {
+            return Err(CommandError::new(ErrorCode::InvalidType, format!("지원하지 않는 이미지 형식입니다: {}", extension)));
+        }
 
-    // 임시 디렉토리 생성
-    let temp_dir = std::env::temp_dir().join("oddeyes-uploads");
-    fs::create_dir_all(&temp_dir).map_err(|e| CommandError {
-        code: "DIR_CREATE_ERROR".to_string(),
-        message: format!("임시 디렉토리 생성 실패: {}", e),
-        details: None,
-    })?;
-
-    // 고유한 파일명 생성
-    let unique_name = format!("{}_{}", Uuid::new_v4(), filename);
-    let path = temp_dir.join(&unique_name);
-
-    // 파일 저장
-    fs::write(&path, bytes).map_err(|e| CommandError {
-        code: "WRITE_ERROR".to_string(),
-        message: format!("파일 저장 실패: {}", e),
-        details: None,
-    })?;
-
-    Ok(path.to_string_lossy().to_string())
+        // 임시 디렉토리 생성
+        let temp_dir = temp_upload_dir();
+        fs::create_dir_all(&temp_dir).map_err(|e| CommandError::new(
+            ErrorCode::DirCreateError,
+            format!("임시 디렉토리 생성 실패: {}", e),
+        ))?;
+
+        // 고유한 파일명 생성
+        let unique_name = format!("{}_{}", Uuid::new_v4(), filename);
+        let path = temp_dir.join(&unique_name);
+
+        // 파일 저장
+        fs::write(&path, bytes).map_err(|e| CommandError::new(ErrorCode::WriteError, format!("파일 저장 실패: {}", e)))?;
+
+        Ok(path.to_string_lossy().to_string())
+    })
+    .await
 }
 
 /// 오래된 임시 이미지 파일을 정리합니다.
 /// - 앱 시작 시 호출하여 24시간 이상 된 임시 파일을 삭제합니다.
 #[tauri::command]
 pub fn cleanup_temp_images() -> CommandResult<u32> {
-    let temp_dir = std::env::temp_dir().join("oddeyes-uploads");
+    let temp_dir = temp_upload_dir();
 
     if !temp_dir.exists() {
         return Ok(0);
@@ -389,11 +450,10 @@ pub fn cleanup_temp_images() -> CommandResult<u32> {
     let now = std::time::SystemTime::now();
     let mut deleted_count: u32 = 0;
 
-    let entries = fs::read_dir(&temp_dir).map_err(|e| CommandError {
-        code: "READ_DIR_ERROR".to_string(),
-        message: format!("임시 디렉토리 읽기 실패: {}", e),
-        details: None,
-    })?;
+    let entries = fs::read_dir(&temp_dir).map_err(|e| CommandError::new(
+        ErrorCode::ReadDirError,
+        format!("임시 디렉토리 읽기 실패: {}", e),
+    ))?;
 
     for entry in entries.flatten() {
         let path = entry.path();
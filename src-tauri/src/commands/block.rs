@@ -2,11 +2,18 @@
 //!
 //! 블록 관리 관련 Tauri 명령어
 
-use tauri::State;
+use std::collections::HashMap;
 
-use crate::db::DbState;
-use crate::error::{CommandError, CommandResult};
-use crate::models::EditorBlock;
+use serde::Deserialize;
+use tauri::{AppHandle, State, Window};
+
+use crate::db::{BlockFilter, BlockHistoryEntry, BlockQueryHit, DbState, TagCount};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::{CapacityHint, EditorBlock};
+use crate::text::unicode::{into_nfc, nfc, split_index};
+
+use super::qa::enqueue_block_qa;
+use super::window::broadcast_block_change;
 
 /// 블록 조회
 #[tauri::command]
@@ -15,31 +22,69 @@ pub fn get_block(
     project_id: String,
     db_state: State<DbState>,
 ) -> CommandResult<EditorBlock> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.get_block(&block_id, &project_id)
         .map_err(CommandError::from)
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlocksBatchArgs {
+    pub project_id: String,
+    pub block_ids: Vec<String>,
+}
+
+/// 여러 블록 한 번에 조회 (호버/QA 등에서 반복 호출 대신 사용)
+/// - 요청 순서대로 반환하며, 없는 블록은 빠집니다.
+#[tauri::command]
+pub fn get_blocks_batch(args: GetBlocksBatchArgs, db_state: State<DbState>) -> CommandResult<Vec<EditorBlock>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.get_blocks(&args.project_id, &args.block_ids)
+        .map_err(CommandError::from)
+}
+
+/// 블록 변경 이력 (누가 언제 바꿨는지, 오래된 순)
+/// - 편집 기록, 리뷰어 수정, 히스토리 스냅샷을 합쳐 반환합니다.
+#[tauri::command]
+pub fn get_block_history(block_id: String, db_state: State<DbState>) -> CommandResult<Vec<BlockHistoryEntry>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.block_history(&block_id).map_err(CommandError::from)
+}
+
 /// 블록 업데이트
 #[tauri::command]
 pub fn update_block(
+    app: AppHandle,
+    window: Window,
     block: EditorBlock,
     project_id: String,
     db_state: State<DbState>,
 ) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
 
-    db.update_block(&block, &project_id)
-        .map_err(CommandError::from)
+        db.update_block(&block, &project_id)
+            .map_err(CommandError::from)?;
+    }
+
+    enqueue_block_qa(&app, &project_id, &block.id);
+    broadcast_block_change(&app, Some(window.label()), &project_id, vec![block.id]);
+    Ok(())
 }
 
 /// 블록 분할
@@ -50,61 +95,56 @@ pub fn split_block(
     project_id: String,
     db_state: State<DbState>,
 ) -> CommandResult<(EditorBlock, EditorBlock)> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     // 기존 블록 로드
     let original_block = db
         .get_block(&block_id, &project_id)
         .map_err(CommandError::from)?;
 
+    let author = db.local_author().map_err(CommandError::from)?;
     let now = chrono::Utc::now().timestamp_millis();
     let new_block_id = uuid::Uuid::new_v4().to_string();
 
-    // 콘텐츠 분할
-    let original_content = &original_block.content;
-    let first_part = if split_position < original_content.len() {
-        original_content[..split_position].to_string()
-    } else {
-        original_content.clone()
-    };
-    let second_part = if split_position < original_content.len() {
-        original_content[split_position..].to_string()
-    } else {
-        String::new()
-    };
+    // 콘텐츠 분할 (분할 위치는 JS 문자열 기준 UTF-16 오프셋, 음절/결합 문자 중간이면 앞으로 당김)
+    let original_content = nfc(&original_block.content);
+    let (first_part, second_part) = original_content.split_at(split_index(&original_content, split_position));
+    let (first_part, second_part) = (first_part.to_string(), second_part.to_string());
 
     // 업데이트된 원본 블록
-    let updated_original = EditorBlock {
+    let mut updated_original = EditorBlock {
         content: first_part.clone(),
         hash: format!("{:x}", md5::compute(&first_part)),
-        metadata: crate::models::BlockMetadata {
-            updated_at: now,
-            ..original_block.metadata.clone()
-        },
         ..original_block.clone()
     };
+    updated_original.metadata.stamp(author.as_deref(), now);
 
     // 새 블록
     let new_block = EditorBlock {
-        id: new_block_id,
+        id: new_block_id.clone(),
         block_type: original_block.block_type.clone(),
         content: second_part.clone(),
         hash: format!("{:x}", md5::compute(&second_part)),
         metadata: crate::models::BlockMetadata {
-            author: original_block.metadata.author.clone(),
+            author: author.or_else(|| original_block.metadata.author.clone()),
             created_at: now,
             updated_at: now,
             tags: Vec::new(),
             comments: None,
+            capacity: None,
         },
     };
 
     // TODO: 데이터베이스에 저장 및 세그먼트 업데이트
 
+    // 원본 블록을 컨텍스트로 쓰던 채팅 세션은 분할된 두 블록을 모두 참조
+    let replacements = HashMap::from([(block_id.clone(), vec![block_id, new_block_id])]);
+    db.remap_chat_context_blocks(&project_id, &replacements)
+        .map_err(CommandError::from)?;
+
     Ok((updated_original, new_block))
 }
 
@@ -116,18 +156,13 @@ pub fn merge_blocks(
     db_state: State<DbState>,
 ) -> CommandResult<EditorBlock> {
     if block_ids.len() < 2 {
-        return Err(CommandError {
-            code: "INVALID_OPERATION".to_string(),
-            message: "At least 2 blocks are required for merging".to_string(),
-            details: None,
-        });
+        return Err(CommandError::new(ErrorCode::InvalidOperation, "At least 2 blocks are required for merging"));
     }
 
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     // 모든 블록 로드
     let mut blocks = Vec::new();
@@ -139,29 +174,185 @@ pub fn merge_blocks(
     }
 
     // 콘텐츠 병합
-    let merged_content: String = blocks.iter().map(|b| b.content.clone()).collect();
+    // 경계에서 분리된 자모/결합 문자가 다시 합쳐지도록 NFC로
+    let merged_content = into_nfc(blocks.iter().map(|b| b.content.as_str()).collect());
+    let author = db.local_author().map_err(CommandError::from)?;
     let now = chrono::Utc::now().timestamp_millis();
 
     // 첫 번째 블록을 기준으로 병합된 블록 생성
-    let first_block = blocks.first().ok_or_else(|| CommandError {
-        code: "INVALID_OPERATION".to_string(),
-        message: "No blocks to merge".to_string(),
-        details: None,
-    })?;
+    let first_block = blocks.first().ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidOperation,
+        "No blocks to merge",
+    ))?;
 
-    let merged_block = EditorBlock {
+    let mut merged_block = EditorBlock {
         id: first_block.id.clone(),
         block_type: first_block.block_type.clone(),
         content: merged_content.clone(),
         hash: format!("{:x}", md5::compute(&merged_content)),
-        metadata: crate::models::BlockMetadata {
-            updated_at: now,
-            ..first_block.metadata.clone()
-        },
+        metadata: first_block.metadata.clone(),
     };
+    merged_block.metadata.stamp(author.as_deref(), now);
 
     // TODO: 데이터베이스에 저장 및 세그먼트 업데이트
 
+    // 병합되어 사라지는 블록을 컨텍스트로 쓰던 채팅 세션은 병합된 블록을 참조
+    let replacements: HashMap<String, Vec<String>> = block_ids[1..]
+        .iter()
+        .filter(|id| **id != merged_block.id)
+        .map(|id| (id.clone(), vec![merged_block.id.clone()]))
+        .collect();
+    db.remap_chat_context_blocks(&project_id, &replacements)
+        .map_err(CommandError::from)?;
+
     Ok(merged_block)
 }
 
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTagsArgs {
+    pub project_id: String,
+    pub block_ids: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBlockCapacityArgs {
+    pub project_id: String,
+    pub block_ids: Vec<String>,
+    /// None이면 제약 해제
+    pub capacity: Option<CapacityHint>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTagsArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryBlocksArgs {
+    pub project_id: String,
+    #[serde(default)]
+    pub filter: BlockFilter,
+}
+
+/// 블록에 태그 추가 (변경된 블록 수 반환)
+#[tauri::command]
+pub fn tag_blocks(
+    app: AppHandle,
+    window: Window,
+    args: BlockTagsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<usize> {
+    let changed = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.tag_blocks(&args.project_id, &args.block_ids, &args.tags)
+            .map_err(CommandError::from)?
+    };
+
+    if changed > 0 {
+        broadcast_block_change(&app, Some(window.label()), &args.project_id, args.block_ids);
+    }
+    Ok(changed)
+}
+
+/// 블록에서 태그 제거 (변경된 블록 수 반환)
+#[tauri::command]
+pub fn untag_blocks(
+    app: AppHandle,
+    window: Window,
+    args: BlockTagsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<usize> {
+    let changed = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.untag_blocks(&args.project_id, &args.block_ids, &args.tags)
+            .map_err(CommandError::from)?
+    };
+
+    if changed > 0 {
+        broadcast_block_change(&app, Some(window.label()), &args.project_id, args.block_ids);
+    }
+    Ok(changed)
+}
+
+/// 블록 분량 제약 설정/해제 (가져오기 시 원본 요소 크기 기록, 수동 지정 등)
+/// - 변경된 블록 수를 반환하고, 해당 블록은 길이 QA를 다시 실행합니다.
+#[tauri::command]
+pub fn set_block_capacity(
+    app: AppHandle,
+    window: Window,
+    args: SetBlockCapacityArgs,
+    db_state: State<DbState>,
+) -> CommandResult<usize> {
+    if let Some(capacity) = &args.capacity {
+        if !CapacityHint::KINDS.contains(&capacity.kind.as_str()) {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Unknown capacity kind: {}", capacity.kind),
+            ).with_details(format!("supported: {}", CapacityHint::KINDS.join(", "))));
+        }
+    }
+
+    let changed = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.set_block_capacity(&args.project_id, &args.block_ids, args.capacity.as_ref())
+            .map_err(CommandError::from)?
+    };
+
+    if changed > 0 {
+        for block_id in &args.block_ids {
+            enqueue_block_qa(&app, &args.project_id, block_id);
+        }
+        broadcast_block_change(&app, Some(window.label()), &args.project_id, args.block_ids);
+    }
+    Ok(changed)
+}
+
+/// 프로젝트 태그 목록 (태그별 블록 수)
+#[tauri::command]
+pub fn list_tags(args: ListTagsArgs, db_state: State<DbState>) -> CommandResult<Vec<TagCount>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_tags(&args.project_id).map_err(CommandError::from)
+}
+
+/// 태그/상태/코멘트 여부로 블록 필터 조회 (저장된 보기용)
+#[tauri::command]
+pub fn query_blocks(args: QueryBlocksArgs, db_state: State<DbState>) -> CommandResult<Vec<BlockQueryHit>> {
+    if let Some(block_type) = args.filter.block_type.as_deref() {
+        if block_type != "source" && block_type != "target" {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Unknown block type: {}", block_type),
+            ).with_details("blockType must be 'source' or 'target'"));
+        }
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.query_blocks(&args.project_id, &args.filter)
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,153 @@
+//! Quick Capture
+//!
+//! 전역 단축키(`captureShortcut` 설정)로 클립보드 텍스트를 활성 프로젝트 끝에 새 원문 세그먼트로 추가
+//! - 프로젝트 원문 로캘의 문장 분할 규칙으로 나눠 문장마다 세그먼트를 만듭니다.
+//! - 브라우저에서 자료를 조사하다가 앱으로 돌아오지 않고 바로 모을 수 있습니다.
+//! - 활성 프로젝트 = 메인 창이 연 프로젝트 (없으면 다른 프로젝트 창 중 하나, 창은 `set_window_project`로 기록)
+//! - DB에 직접 쓰지 않고 그 창에 `segment-captured` 이벤트를 보내 프론트엔드 스토어가 세그먼트를 추가합니다.
+//!   (DB에 쓰면 다음 자동 저장이 전체 블록을 다시 쓰면서 지워짐)
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+use tauri_plugin_notification::NotificationExt;
+
+use super::window::WindowProjects;
+use crate::db::DbState;
+use crate::text::locale::SegmentationRules;
+
+/// 세그먼트 캡처 이벤트
+pub const SEGMENT_CAPTURED_EVENT: &str = "segment-captured";
+
+/// 현재 등록된 캡처 단축키
+#[derive(Default)]
+pub struct CaptureShortcut(Mutex<Option<String>>);
+
+/// `segment-captured` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentCapturedEvent {
+    pub project_id: String,
+    /// 공백을 정리한 클립보드 텍스트를 문장으로 나눈 것 (문장마다 새 원문 블록)
+    pub sentences: Vec<String>,
+}
+
+/// 캡처를 받을 창 label과 그 창의 프로젝트
+fn active_project(app: &AppHandle) -> Option<(String, String)> {
+    let state = app.state::<WindowProjects>();
+    let windows = state.0.lock().ok()?;
+    windows
+        .get_key_value("main")
+        .or_else(|| windows.iter().min_by(|a, b| a.0.cmp(b.0)))
+        .map(|(label, project_id)| (label.clone(), project_id.clone()))
+}
+
+fn show_notification(app: &AppHandle, title: &str, body: &str) {
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        eprintln!("[Capture] Failed to show notification: {}", e);
+    }
+}
+
+/// 클립보드 텍스트를 활성 프로젝트에 세그먼트로 추가
+fn capture_clipboard(app: &AppHandle) {
+    let text = match app.clipboard().read_text() {
+        Ok(text) => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        Err(e) => {
+            eprintln!("[Capture] Failed to read clipboard: {}", e);
+            return;
+        }
+    };
+    if text.is_empty() {
+        show_notification(app, "Nothing to capture", "The clipboard has no text.");
+        return;
+    }
+    let Some((label, project_id)) = active_project(app) else {
+        show_notification(app, "Capture failed", "Open a project first.");
+        return;
+    };
+    let (read_only, source_locale) = {
+        let state = app.state::<DbState>();
+        let loaded = match state.0.lock() {
+            Ok(db) => {
+                let default_source = db.load_app_settings().ok().and_then(|s| s.default_source_language);
+                let source_locale = db
+                    .load_project_metadata(&project_id)
+                    .ok()
+                    .and_then(|m| m.source_language_or(default_source));
+                (db.is_project_read_only(&project_id), source_locale)
+            }
+            Err(_) => (false, None),
+        };
+        loaded
+    };
+    if read_only {
+        show_notification(app, "Capture failed", "The project is open read-only.");
+        return;
+    }
+
+    let preview: String = text.chars().take(80).collect();
+    let sentences = SegmentationRules::for_locale(source_locale.as_deref())
+        .split(&text)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let event = SegmentCapturedEvent {
+        project_id: project_id.clone(),
+        sentences,
+    };
+    match app.emit_to(label.as_str(), SEGMENT_CAPTURED_EVENT, event) {
+        Ok(()) => {
+            println!("[Capture] Sent capture to {} ({})", label, project_id);
+            show_notification(app, "Captured", &preview);
+        }
+        Err(e) => {
+            eprintln!("[Capture] Failed to send capture: {}", e);
+            show_notification(app, "Capture failed", &e.to_string());
+        }
+    }
+}
+
+/// 설정의 캡처 단축키를 (다시) 등록 (앱 setup과 설정 변경 시 호출)
+/// - 단축키 문자열이 잘못되었거나 다른 앱이 이미 쓰고 있으면 로그만 남깁니다.
+pub fn apply_capture_shortcut(app: &AppHandle) {
+    let wanted = {
+        let state = app.state::<DbState>();
+        let loaded = match state.0.lock() {
+            Ok(db) => db.load_app_settings().map(|s| s.capture_shortcut).unwrap_or_default(),
+            Err(_) => return,
+        };
+        loaded
+    };
+
+    let state = app.state::<CaptureShortcut>();
+    let Ok(mut current) = state.0.lock() else {
+        return;
+    };
+    if *current == wanted {
+        return;
+    }
+
+    let shortcuts = app.global_shortcut();
+    if let Some(old) = current.take() {
+        if let Err(e) = shortcuts.unregister(old.as_str()) {
+            eprintln!("[Capture] Failed to unregister {}: {}", old, e);
+        }
+    }
+    if let Some(shortcut) = wanted {
+        let registered = shortcuts.on_shortcut(shortcut.as_str(), |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                capture_clipboard(app);
+            }
+        });
+        match registered {
+            Ok(()) => {
+                println!("[Capture] Registered shortcut {}", shortcut);
+                *current = Some(shortcut);
+            }
+            Err(e) => eprintln!("[Capture] Failed to register {}: {}", shortcut, e),
+        }
+    }
+}
@@ -2,12 +2,20 @@
 //!
 //! 프로젝트별 채팅 세션 및 ChatPanel 설정을 DB에 저장/로드합니다.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
-use crate::db::DbState;
-use crate::error::{CommandError, CommandResult, IteError};
-use crate::models::ChatSession;
+use crate::db::{
+    ChatMessageSearchHit, ChatPruneResult, ChatRetentionPolicy, ChatSessionSummary, Citation, CitationInput, Database,
+    DbState,
+};
+use crate::error::{CommandError, CommandResult, ErrorCode, IteError};
+use crate::export::chat::{render_chat_session, ChatExportFormat};
+use crate::models::{ChatDefaults, ChatSession};
+use crate::text::strip_html;
+use crate::utils::validate_path;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,6 +30,42 @@ pub struct ChatProjectSettings {
     pub translation_context_session_id: Option<String>,
 }
 
+impl ChatProjectSettings {
+    /// 프로젝트 설정이 없을 때 전역 기본값만으로 구성
+    fn from_defaults(defaults: &ChatDefaults) -> Self {
+        Self {
+            translator_persona: defaults.translator_persona.clone(),
+            translation_rules: defaults.translation_rules.clone(),
+            project_context: String::new(),
+            composer_text: String::new(),
+            web_search_enabled: false,
+            translation_context_session_id: None,
+        }
+    }
+
+    /// 비어 있는 페르소나/규칙을 전역 기본값으로 채움 (전역 → 프로젝트 순)
+    fn with_defaults(mut self, defaults: &ChatDefaults) -> Self {
+        if self.translator_persona.trim().is_empty() {
+            self.translator_persona = defaults.translator_persona.clone();
+        }
+        if self.translation_rules.trim().is_empty() {
+            self.translation_rules = defaults.translation_rules.clone();
+        }
+        self
+    }
+
+    /// 전역 기본값과 같은 페르소나/규칙은 비워서 저장 (나중에 기본값을 바꾸면 그대로 따라가도록)
+    fn without_defaults(mut self, defaults: &ChatDefaults) -> Self {
+        if self.translator_persona == defaults.translator_persona {
+            self.translator_persona.clear();
+        }
+        if self.translation_rules == defaults.translation_rules {
+            self.translation_rules.clear();
+        }
+        self
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveCurrentChatSessionArgs {
@@ -48,6 +92,92 @@ pub struct LoadChatSessionsArgs {
     pub project_id: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveChatSessionArgs {
+    pub project_id: String,
+    pub session: ChatSession,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSessionIdArgs {
+    pub session_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameChatSessionArgs {
+    pub session_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchChatMessagesArgs {
+    pub project_id: String,
+    #[serde(deserialize_with = "crate::text::unicode::deserialize_nfc")]
+    pub query: String,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveMessageCitationsArgs {
+    pub session_id: String,
+    pub message_id: String,
+    pub citations: Vec<CitationInput>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageCitationsArgs {
+    pub message_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportChatSessionArgs {
+    pub session_id: String,
+    /// "markdown" | "html"
+    pub format: String,
+    /// 저장할 파일 경로
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatRetentionPolicyArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetChatRetentionPolicyArgs {
+    pub project_id: String,
+    pub policy: ChatRetentionPolicy,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneChatHistoryArgs {
+    /// 없으면 보관 정책이 설정된 모든 프로젝트 정리
+    pub project_id: Option<String>,
+    /// 저장된 정책 대신 이번 한 번만 적용할 정책 (projectId 필요)
+    pub policy: Option<ChatRetentionPolicy>,
+    /// true면 정리 후 VACUUM으로 DB 파일 크기까지 줄임
+    pub compact: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneChatHistoryResult {
+    #[serde(flatten)]
+    pub pruned: ChatPruneResult,
+    /// compact=true일 때 실제로 줄어든 DB 파일 크기 (bytes)
+    pub compacted_bytes: Option<i64>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveChatSettingsArgs {
@@ -67,11 +197,10 @@ pub fn save_current_chat_session(
     args: SaveCurrentChatSessionArgs,
     db_state: State<DbState>,
 ) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.save_current_chat_session(&args.project_id, &args.session)
         .map_err(CommandError::from)?;
@@ -84,89 +213,381 @@ pub fn load_current_chat_session(
     args: LoadCurrentChatSessionArgs,
     db_state: State<DbState>,
 ) -> CommandResult<Option<ChatSession>> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.load_current_chat_session(&args.project_id)
         .map_err(CommandError::from)
 }
 
-/// 프로젝트별 채팅 세션(최대 3개) 저장
+/// 프로젝트별 채팅 세션 목록 저장 (전체 교체)
 #[tauri::command]
 pub fn save_chat_sessions(
     args: SaveChatSessionsArgs,
     db_state: State<DbState>,
 ) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.save_chat_sessions(&args.project_id, &args.sessions)
         .map_err(CommandError::from)?;
     Ok(())
 }
 
-/// 프로젝트별 채팅 세션(최대 3개) 로드 (최근 활동 기준)
+/// 프로젝트별 채팅 세션 목록 로드 (최근 활동 기준)
 #[tauri::command]
 pub fn load_chat_sessions(
     args: LoadChatSessionsArgs,
     db_state: State<DbState>,
 ) -> CommandResult<Vec<ChatSession>> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.load_chat_sessions(&args.project_id)
         .map_err(CommandError::from)
 }
 
+/// 세션 1개 저장 (다른 세션은 유지)
+#[tauri::command]
+pub fn save_chat_session(
+    args: SaveChatSessionArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.save_chat_session(&args.project_id, &args.session)
+        .map_err(CommandError::from)
+}
+
+/// 세션 단건 로드 (메시지 포함)
+#[tauri::command]
+pub fn load_chat_session(
+    args: ChatSessionIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Option<ChatSession>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.load_chat_session(&args.session_id)
+        .map_err(CommandError::from)
+}
+
+/// 세션 컨텍스트 블록 (현재 내용 기준)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionContextBlock {
+    pub block_id: String,
+    pub block_type: String,
+    /// 현재 블록 평문
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionContext {
+    pub session_id: String,
+    pub project_id: String,
+    /// 저장된 순서대로, 아직 존재하는 블록만
+    pub blocks: Vec<SessionContextBlock>,
+    /// 더 이상 없는 블록 ID (세션 컨텍스트에서 제거됨)
+    pub removed_block_ids: Vec<String>,
+}
+
+/// 세션 컨텍스트 블록을 현재 텍스트로 조회
+/// - 삭제된 블록은 결과에서 빼고 세션 컨텍스트에서도 정리합니다.
+#[tauri::command]
+pub fn get_session_context(
+    args: ChatSessionIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<SessionContext> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let (project_id, block_ids) = db
+        .load_chat_session_context_ids(&args.session_id)
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::new(ErrorCode::NotFound, format!("Chat session not found: {}", args.session_id)))?;
+
+    let blocks = db.get_blocks(&project_id, &block_ids).map_err(CommandError::from)?;
+    let removed_block_ids: Vec<String> = block_ids
+        .iter()
+        .filter(|id| !blocks.iter().any(|b| &b.id == *id))
+        .cloned()
+        .collect();
+    // 지워진 블록 참조 정리는 쓰기 작업이므로 읽기 전용 프로젝트에서는 건너뜀 (조회는 계속)
+    if !removed_block_ids.is_empty() && !db.is_project_read_only(&project_id) {
+        let removed: HashMap<String, Vec<String>> =
+            removed_block_ids.iter().map(|id| (id.clone(), Vec::new())).collect();
+        db.remap_chat_context_blocks(&project_id, &removed)
+            .map_err(CommandError::from)?;
+    }
+
+    Ok(SessionContext {
+        session_id: args.session_id,
+        project_id,
+        blocks: blocks
+            .into_iter()
+            .map(|b| SessionContextBlock {
+                text: strip_html(&b.content),
+                block_id: b.id,
+                block_type: b.block_type,
+            })
+            .collect(),
+        removed_block_ids,
+    })
+}
+
+/// 프로젝트별 세션 요약 목록 (메시지 본문 제외)
+#[tauri::command]
+pub fn list_chat_sessions(
+    args: LoadChatSessionsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<ChatSessionSummary>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_chat_session_summaries(&args.project_id)
+        .map_err(CommandError::from)
+}
+
+/// 세션 이름 변경
+#[tauri::command]
+pub fn rename_chat_session(
+    args: RenameChatSessionArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let name = args.name.trim();
+    if name.is_empty() {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "세션 이름을 입력해주세요."));
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.rename_chat_session(&args.session_id, name)
+        .map_err(CommandError::from)
+}
+
+/// 세션 삭제 (메시지 포함)
+#[tauri::command]
+pub fn delete_chat_session(
+    args: ChatSessionIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.delete_chat_session(&args.session_id)
+        .map_err(CommandError::from)
+}
+
+/// 메시지 인용 기록 (웹 검색/MCP 결과를 어시스턴트에게 보여줄 때)
+/// - 저장된 메시지의 첨부 항목(kind='citation')으로 추가하며, 같은 URL은 제목/발췌만 갱신합니다.
+#[tauri::command]
+pub fn save_message_citations(
+    args: SaveMessageCitationsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<Citation>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.save_message_citations(&args.session_id, &args.message_id, &args.citations)
+        .map_err(CommandError::from)
+}
+
+/// 메시지 인용 조회 (세션을 다시 불러온 뒤 출처 표시용)
+#[tauri::command]
+pub fn get_message_citations(
+    args: MessageCitationsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<Citation>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.get_message_citations(&args.message_id)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트의 과거 채팅 메시지 전문 검색
+#[tauri::command]
+pub fn search_chat_messages(
+    args: SearchChatMessagesArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<ChatMessageSearchHit>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let limit = args.limit.unwrap_or(30).min(200);
+    db.search_chat_messages(&args.project_id, &args.query, limit)
+        .map_err(CommandError::from)
+}
+
+/// 채팅 세션을 Markdown/HTML 파일로 내보내기 (고객 공유용, 메타데이터 제외)
+#[tauri::command]
+pub fn export_chat_session(
+    args: ExportChatSessionArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let format = ChatExportFormat::parse(&args.format).ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unsupported export format: {}", args.format),
+    ).with_details("format must be 'markdown' or 'html'"))?;
+
+    // utils::validate_path (Blocklist 적용)
+    let out_path = validate_path(&args.path)?;
+
+    let session = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.load_chat_session(&args.session_id)
+            .map_err(CommandError::from)?
+    }
+    .ok_or_else(|| CommandError::new(ErrorCode::NotFound, format!("Chat session not found: {}", args.session_id)))?;
+
+    let rendered = render_chat_session(&session, format);
+    std::fs::write(&out_path, rendered).map_err(|e| CommandError::from(IteError::from(e)))?;
+    Ok(())
+}
+
+/// 프로젝트 채팅 보관 정책 조회
+#[tauri::command]
+pub fn get_chat_retention_policy(
+    args: ChatRetentionPolicyArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Option<ChatRetentionPolicy>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.get_chat_retention_policy(&args.project_id)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트 채팅 보관 정책 저장 (모든 값이 비어 있으면 정책 해제)
+#[tauri::command]
+pub fn set_chat_retention_policy(
+    args: SetChatRetentionPolicyArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_chat_retention_policy(&args.project_id, &args.policy)
+        .map_err(CommandError::from)
+}
+
+/// 보관 정책에 따라 오래된/초과 채팅 메시지를 정리하고 회수한 용량을 보고
+#[tauri::command]
+pub fn prune_chat_history(
+    args: PruneChatHistoryArgs,
+    db_state: State<DbState>,
+) -> CommandResult<PruneChatHistoryResult> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let pruned = match (&args.policy, &args.project_id) {
+        (Some(policy), Some(project_id)) => db.prune_chat_history_with_policy(project_id, policy),
+        (Some(_), None) => {
+            return Err(CommandError::new(ErrorCode::InvalidInput, "policy를 직접 지정하려면 projectId가 필요합니다."))
+        }
+        (None, project_id) => db.prune_chat_history(project_id.as_deref()),
+    }
+    .map_err(CommandError::from)?;
+
+    let compacted_bytes = if args.compact.unwrap_or(false) {
+        Some(db.vacuum().map_err(CommandError::from)?)
+    } else {
+        None
+    };
+
+    Ok(PruneChatHistoryResult {
+        pruned,
+        compacted_bytes,
+    })
+}
+
 /// 프로젝트별 채팅 설정 저장
+/// - 전역 기본값과 같은 페르소나/규칙은 프로젝트 재정의로 저장하지 않습니다.
 #[tauri::command]
 pub fn save_chat_project_settings(
     args: SaveChatSettingsArgs,
     db_state: State<DbState>,
 ) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
+    let defaults = db.load_app_settings().map_err(CommandError::from)?.default_chat;
+    let settings = args.settings.without_defaults(&defaults);
     let now = chrono::Utc::now().timestamp_millis();
-    let json = serde_json::to_string(&args.settings).map_err(|e| CommandError::from(IteError::from(e)))?;
+    let json = serde_json::to_string(&settings).map_err(|e| CommandError::from(IteError::from(e)))?;
     db.save_chat_project_settings(&args.project_id, &json, now)
         .map_err(CommandError::from)?;
     Ok(())
 }
 
 /// 프로젝트별 채팅 설정 로드
+/// - 비어 있는 페르소나/규칙은 전역 기본 채팅 설정(`AppSettings.defaultChat`)으로 채웁니다.
+/// - 프로젝트 설정도 전역 기본값도 없으면 None
 #[tauri::command]
 pub fn load_chat_project_settings(
     args: LoadChatSettingsArgs,
     db_state: State<DbState>,
 ) -> CommandResult<Option<ChatProjectSettings>> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    effective_chat_settings(&db, &args.project_id)
+}
 
+/// 전역 기본값을 채운 프로젝트 채팅 설정 (번역 프롬프트 조립에도 사용)
+pub(crate) fn effective_chat_settings(db: &Database, project_id: &str) -> CommandResult<Option<ChatProjectSettings>> {
+    let defaults = db.load_app_settings().map_err(CommandError::from)?.default_chat;
     let json = db
-        .load_chat_project_settings(&args.project_id)
+        .load_chat_project_settings(project_id)
         .map_err(CommandError::from)?;
     if let Some(s) = json {
         let parsed = serde_json::from_str::<ChatProjectSettings>(&s)
             .map_err(|e| CommandError::from(IteError::from(e)))?;
-        Ok(Some(parsed))
-    } else {
+        Ok(Some(parsed.with_defaults(&defaults)))
+    } else if defaults.translator_persona.trim().is_empty() && defaults.translation_rules.trim().is_empty() {
         Ok(None)
+    } else {
+        Ok(Some(ChatProjectSettings::from_defaults(&defaults)))
     }
 }
 
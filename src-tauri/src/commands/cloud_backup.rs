@@ -0,0 +1,372 @@
+//! Cloud Backup Commands
+//!
+//! 전체 DB를 .ite 패키지로 만들어 암호화(.itebak)한 뒤 Google Drive/Dropbox 커넥터로 업로드하고,
+//! 다른 기기에서도 같은 암호로 내려받아 복원할 수 있게 합니다.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use super::notifications::{notify_job, JobKind};
+use super::storage::{import_package_file, write_project_package, ImportProjectPackageResult};
+use crate::cloud::{self, CloudBackupFile, CLOUD_BACKUP_PREFIX};
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::network;
+use crate::package::{self, ENCRYPTED_BACKUP_EXTENSION, MIN_PASSPHRASE_LEN};
+use crate::secrets::SECRETS;
+
+/// 백업 암호 vault 키
+const VAULT_BACKUP_PASSPHRASE: &str = "backup/cloud_passphrase";
+
+/// 스케줄 확인 간격
+const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+/// 오프라인 중 요청된 백업 (다시 연결되면 스케줄러가 실행)
+const QUEUED_JOB_KIND: &str = "cloudBackup";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCloudBackupPassphraseArgs {
+    pub passphrase: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudBackupArgs {
+    pub connector_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreFromCloudArgs {
+    pub connector_id: String,
+    /// `list_cloud_backups`가 돌려준 파일 ID
+    pub backup_id: String,
+    /// 생략하면 이 기기에 저장된 암호 사용 (새 기기에서는 직접 입력)
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudBackupResult {
+    pub connector_id: String,
+    pub file: CloudBackupFile,
+    pub project_count: usize,
+    /// 원본 파일을 찾을 수 없어 포함하지 못한 첨부 ID
+    pub skipped_attachments: Vec<String>,
+    /// 보관 개수를 넘어 삭제한 오래된 백업 수
+    pub pruned: usize,
+}
+
+fn cloud_error(message: String) -> CommandError {
+    CommandError::new(ErrorCode::CloudError, message)
+}
+
+async fn load_passphrase() -> Result<Option<String>, String> {
+    SECRETS
+        .get(VAULT_BACKUP_PASSPHRASE)
+        .await
+        .map_err(|e| format!("Failed to load backup passphrase: {}", e))
+}
+
+async fn connector_token(connector_id: &str) -> CommandResult<String> {
+    super::connector::load_connector_token(connector_id)
+        .await?
+        .ok_or_else(|| CommandError::new(
+            ErrorCode::InvalidOperation,
+            format!("Connector is not connected: {}", connector_id),
+        ))
+}
+
+/// 백업 암호 저장 (vault)
+/// - 암호를 잊으면 클라우드 백업을 복원할 수 없습니다.
+#[tauri::command]
+pub async fn set_cloud_backup_passphrase(app: AppHandle, args: SetCloudBackupPassphraseArgs) -> CommandResult<()> {
+    crate::crash::guard(&app, "set_cloud_backup_passphrase", async {
+        if args.passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Passphrase must be at least {} characters", MIN_PASSPHRASE_LEN),
+            ));
+        }
+        SECRETS
+            .set(VAULT_BACKUP_PASSPHRASE, &args.passphrase)
+            .await
+            .map_err(|e| CommandError::new(ErrorCode::WriteError, format!("Failed to save backup passphrase: {}", e)))
+    })
+    .await
+}
+
+/// 백업 암호 저장 여부
+#[tauri::command]
+pub async fn has_cloud_backup_passphrase(app: AppHandle) -> CommandResult<bool> {
+    crate::crash::guard(&app, "has_cloud_backup_passphrase", async {
+        Ok(load_passphrase().await.map_err(cloud_error)?.is_some())
+    })
+    .await
+}
+
+/// 지금 클라우드에 백업
+/// - 오프라인이면 `OFFLINE` 오류를 돌려주고, 다시 연결되면 백그라운드에서 백업합니다 (details: "queued").
+#[tauri::command]
+pub async fn backup_to_cloud(
+    app: AppHandle,
+    args: CloudBackupArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<CloudBackupResult> {
+    crate::crash::guard(&app, "backup_to_cloud", async {
+        if let Err(e) = network::ensure_online("Cloud backup") {
+            network::enqueue(QUEUED_JOB_KIND, &args.connector_id);
+            return Err(e.with_details("queued"));
+        }
+        let keep_count = {
+            let db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            db.load_app_settings().map_err(CommandError::from)?.cloud_backup.keep_count
+        };
+        run_cloud_backup(&args.connector_id, keep_count, &db_state).await
+    })
+    .await
+}
+
+/// 클라우드 백업 목록 (최신 순)
+#[tauri::command]
+pub async fn list_cloud_backups(app: AppHandle, args: CloudBackupArgs) -> CommandResult<Vec<CloudBackupFile>> {
+    crate::crash::guard(&app, "list_cloud_backups", async {
+        network::ensure_online("Cloud backup")?;
+        let token = connector_token(&args.connector_id).await?;
+        cloud::list_backups(&args.connector_id, &token)
+            .await
+            .map_err(cloud_error)
+    })
+    .await
+}
+
+/// 클라우드 백업으로 DB 복원
+/// - 복원 전 현재 DB는 `import_project_package`와 같은 방식으로 자동 백업됩니다.
+#[tauri::command]
+pub async fn restore_from_cloud(
+    app: AppHandle,
+    args: RestoreFromCloudArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<ImportProjectPackageResult> {
+    crate::crash::guard(&app, "restore_from_cloud", async {
+        network::ensure_online("Cloud restore")?;
+        let passphrase = match args.passphrase {
+            Some(p) => p,
+            None => load_passphrase().await.map_err(cloud_error)?.ok_or_else(|| CommandError::new(
+                ErrorCode::InvalidInput,
+                "Backup passphrase is required",
+            ))?,
+        };
+
+        let token = connector_token(&args.connector_id).await?;
+        let data = cloud::download_backup(&args.connector_id, &token, &args.backup_id)
+            .await
+            .map_err(cloud_error)?;
+        if !package::is_encrypted_backup(&data) {
+            return Err(CommandError::new(ErrorCode::InvalidInput, "Not an encrypted OddEyes backup"));
+        }
+
+        // PBKDF2 키 유도는 CPU를 오래 쓰므로 블로킹 스레드에서 실행
+        let plain = tauri::async_runtime::spawn_blocking(move || package::decrypt_backup(&data, &passphrase))
+            .await
+            .map_err(|e| cloud_error(format!("Decryption task failed: {}", e)))?
+            .map_err(CommandError::from)?;
+
+        let temp_path = std::env::temp_dir().join(format!("ite-cloud-restore-{}.ite", uuid::Uuid::new_v4()));
+        std::fs::write(&temp_path, plain).map_err(|e| CommandError::new(
+            ErrorCode::WriteError,
+            format!("Failed to write backup file: {}", e),
+        ))?;
+        let result = import_package_file(&app, &temp_path, &db_state).await;
+        let _ = std::fs::remove_file(&temp_path);
+
+        let result = result?;
+        println!(
+            "[CloudBackup] Restored {} project(s) from {}",
+            result.project_ids.len(),
+            args.connector_id
+        );
+        Ok(result)
+    })
+    .await
+}
+
+/// 패키지 생성 → 암호화 → 업로드 → 오래된 백업 정리
+async fn run_cloud_backup(connector_id: &str, keep_count: u32, db_state: &DbState) -> CommandResult<CloudBackupResult> {
+    network::ensure_online("Cloud backup")?;
+    let passphrase = load_passphrase().await.map_err(cloud_error)?.ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidOperation,
+        "Set a backup passphrase before backing up to the cloud",
+    ))?;
+    let token = connector_token(connector_id).await?;
+
+    let temp_path = std::env::temp_dir().join(format!("ite-cloud-backup-{}.ite", uuid::Uuid::new_v4()));
+    let packaged = write_project_package(&temp_path, db_state, None).await.and_then(|exported| {
+        let plain = std::fs::read(&temp_path).map_err(|e| CommandError::new(
+            ErrorCode::WriteError,
+            format!("Failed to read backup package: {}", e),
+        ))?;
+        Ok((exported, plain))
+    });
+    let _ = std::fs::remove_file(&temp_path);
+    let (exported, plain) = packaged?;
+
+    let encrypted = tauri::async_runtime::spawn_blocking(move || package::encrypt_backup(&plain, &passphrase))
+        .await
+        .map_err(|e| cloud_error(format!("Encryption task failed: {}", e)))?
+        .map_err(CommandError::from)?;
+
+    let name = format!(
+        "{}{}.{}",
+        CLOUD_BACKUP_PREFIX,
+        chrono::Utc::now().format("%Y%m%d-%H%M%S"),
+        ENCRYPTED_BACKUP_EXTENSION
+    );
+    let file = cloud::upload_backup(connector_id, &token, &name, encrypted)
+        .await
+        .map_err(cloud_error)?;
+    // 업로드는 성공했으므로 정리 실패는 결과에 영향을 주지 않음
+    let pruned = cloud::prune_backups(connector_id, &token, keep_count as usize)
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("[CloudBackup] Failed to prune old backups: {}", e);
+            0
+        });
+
+    println!(
+        "[CloudBackup] Uploaded {} ({} bytes) to {}",
+        file.name, file.size, connector_id
+    );
+    Ok(CloudBackupResult {
+        connector_id: connector_id.to_string(),
+        file,
+        project_count: exported.project_count,
+        skipped_attachments: exported.skipped_attachments,
+        pruned,
+    })
+}
+
+/// 오프라인 중 요청된 백업 실행 (다시 연결된 뒤)
+async fn run_queued_backups(app: &AppHandle) {
+    for job in network::take_queued(QUEUED_JOB_KIND) {
+        let state = app.state::<DbState>();
+        let keep_count = match state.0.lock() {
+            Ok(db) => db.load_app_settings().map(|s| s.cloud_backup.keep_count).ok(),
+            Err(_) => None,
+        };
+        let Some(keep_count) = keep_count else {
+            network::enqueue(QUEUED_JOB_KIND, &job.target);
+            return;
+        };
+        let result = run_cloud_backup(&job.target, keep_count, &state).await;
+        if matches!(&result, Err(e) if e.code == ErrorCode::Offline) {
+            // 그새 다시 끊겼으면 다음 연결 때 재시도
+            network::enqueue(QUEUED_JOB_KIND, &job.target);
+            return;
+        }
+        match result {
+            Ok(result) => notify_job(
+                app,
+                JobKind::Backup,
+                Ok(&format!("{} uploaded to {}", result.file.name, job.target)),
+            ),
+            Err(e) => {
+                eprintln!("[CloudBackup] Queued backup failed: {}", e.message);
+                notify_job(app, JobKind::Backup, Err(&e.message));
+            }
+        }
+    }
+}
+
+/// 설정(`cloudBackup`)에 따라 주기적으로 클라우드 백업
+/// - 마지막 백업 시각은 처음 한 번 클라우드의 최신 백업에서 가져오므로, 재시작해도 주기가 유지됩니다.
+/// - 오프라인 중에는 건너뛰고(실패로 세지 않음), 다시 연결되면 대기열의 백업부터 실행합니다.
+pub fn spawn_cloud_backup_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        // (커넥터, 마지막 백업 시각 Unix ms)
+        let mut last_backup: Option<(String, i64)> = None;
+        let mut last_failure: Option<Instant> = None;
+        let mut online_rx = network::subscribe();
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(SCHEDULER_TICK) => {}
+                changed = online_rx.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                }
+            }
+            if !*online_rx.borrow_and_update() {
+                continue;
+            }
+            run_queued_backups(&app).await;
+
+            let state = app.state::<DbState>();
+            let schedule = match state.0.lock() {
+                Ok(db) => db.load_app_settings().ok().map(|s| s.cloud_backup),
+                Err(_) => None,
+            };
+            let Some(schedule) = schedule.filter(|s| s.enabled) else {
+                continue;
+            };
+            let Some(connector_id) = schedule.connector_id.clone() else {
+                continue;
+            };
+            // 실패 후에는 한 시간 뒤에 다시 시도
+            if matches!(last_failure, Some(t) if t.elapsed() < Duration::from_secs(3600)) {
+                continue;
+            }
+
+            let last_at = match &last_backup {
+                Some((id, at)) if *id == connector_id => *at,
+                _ => {
+                    let newest = match connector_token(&connector_id).await {
+                        Ok(token) => cloud::list_backups(&connector_id, &token)
+                            .await
+                            .map(|files| files.first().map(|f| f.modified_at).unwrap_or(0)),
+                        Err(e) => Err(e.message),
+                    };
+                    match newest {
+                        Ok(at) => {
+                            last_backup = Some((connector_id.clone(), at));
+                            at
+                        }
+                        Err(e) => {
+                            eprintln!("[CloudBackup] Failed to check existing backups: {}", e);
+                            last_failure = Some(Instant::now());
+                            continue;
+                        }
+                    }
+                }
+            };
+            let interval_ms = i64::from(schedule.interval_hours) * 3_600_000;
+            let now = chrono::Utc::now().timestamp_millis();
+            if now - last_at < interval_ms {
+                continue;
+            }
+
+            match run_cloud_backup(&connector_id, schedule.keep_count, &state).await {
+                Ok(result) => {
+                    notify_job(
+                        &app,
+                        JobKind::Backup,
+                        Ok(&format!("{} uploaded to {}", result.file.name, connector_id)),
+                    );
+                    last_backup = Some((connector_id, now));
+                    last_failure = None;
+                }
+                Err(e) => {
+                    eprintln!("[CloudBackup] Scheduled backup failed: {}", e.message);
+                    notify_job(&app, JobKind::Backup, Err(&e.message));
+                    last_failure = Some(Instant::now());
+                }
+            }
+        }
+    });
+}
@@ -2,9 +2,15 @@
 //!
 //! MCP OAuth 토큰을 재사용하여 Confluence REST API 직접 호출.
 //! 단어 카운팅 등 LLM 컨텍스트에 내용을 노출하지 않아야 하는 작업에 사용.
+//! 같은 토큰으로 Jira 이슈(요약/설명)도 가져옵니다 (붙여 넣은 링크 해석용).
 
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::http::RetryPolicy;
 use crate::mcp::client::MCP_CLIENT;
+use crate::text::confluence::{convert_storage, StorageConversion, StorageFormat};
+use crate::text::words::{self, WordCount};
 use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
 
 /// Confluence 페이지 콘텐츠 응답
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,6 +19,45 @@ pub struct ConfluencePageContent {
     pub title: String,
     /// storage 포맷 (HTML)
     pub body: String,
+    /// 문서 최대 크기(`http.maxDocumentKb`)를 넘어 body를 잘라냈는지
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// 평문/Markdown 변환 요청
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfluencePageTextArgs {
+    pub page_id: String,
+    /// "markdown"(기본) | "text"
+    #[serde(default)]
+    pub format: StorageFormat,
+    /// 페이지 언어 (BCP 47, 단어 수의 CJK 환산 계수 결정)
+    pub language: Option<String>,
+}
+
+/// 평문/Markdown으로 변환한 페이지
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfluencePageText {
+    pub page_id: String,
+    pub title: String,
+    pub format: StorageFormat,
+    pub content: String,
+    /// 목록 기호/코드 블록을 뺀 본문 단어 수
+    pub word_count: WordCount,
+    /// 원본 storage HTML을 문서 최대 크기에서 잘라냈는지
+    pub truncated: bool,
+}
+
+/// storage HTML 직접 변환 요청 (MCP 도구 결과 등 이미 받은 HTML)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertConfluenceStorageArgs {
+    pub html: String,
+    #[serde(default)]
+    pub format: StorageFormat,
+    pub language: Option<String>,
 }
 
 /// Confluence REST API v2 페이지 응답 구조
@@ -33,6 +78,39 @@ struct ConfluenceApiStorage {
     value: String,
 }
 
+/// Jira 이슈 (요약 + 설명 평문)
+#[derive(Debug, Serialize)]
+pub struct JiraIssueContent {
+    pub key: String,
+    pub summary: String,
+    /// 설명 (Jira wiki 마크업 그대로)
+    pub description: String,
+    pub status: Option<String>,
+    pub issue_type: Option<String>,
+    pub truncated: bool,
+}
+
+/// Jira REST API v2 이슈 응답 구조 (v2는 설명을 문자열로 반환)
+#[derive(Debug, Deserialize)]
+struct JiraApiIssueResponse {
+    key: String,
+    fields: JiraApiFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraApiFields {
+    #[serde(default)]
+    summary: String,
+    description: Option<String>,
+    status: Option<JiraApiNamed>,
+    issuetype: Option<JiraApiNamed>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraApiNamed {
+    name: String,
+}
+
 /// Accessible Resources 응답 구조
 #[derive(Debug, Deserialize)]
 struct AccessibleResource {
@@ -46,19 +124,63 @@ struct AccessibleResource {
 /// MCP OAuth 토큰을 재사용하여 Confluence REST API v2 직접 호출.
 /// 결과는 Tauri command로만 반환되어 LLM 컨텍스트에 노출되지 않음.
 #[tauri::command]
-pub async fn confluence_get_page_html(page_id: String) -> Result<ConfluencePageContent, String> {
+pub async fn confluence_get_page_html(app: AppHandle, page_id: String) -> CommandResult<ConfluencePageContent> {
+    crate::crash::guard(&app, "confluence_get_page_html", async {
+        fetch_page(&page_id, None).await
+    })
+    .await
+}
+
+/// Confluence 페이지를 평문/Markdown으로 가져오기 (인용, LLM 컨텍스트용)
+/// - 표/목록/매크로를 펼친 결과와 단어 수를 함께 반환합니다.
+#[tauri::command]
+pub async fn confluence_get_page_text(app: AppHandle, args: ConfluencePageTextArgs) -> CommandResult<ConfluencePageText> {
+    crate::crash::guard(&app, "confluence_get_page_text", async {
+        let page = fetch_page(&args.page_id, None).await?;
+        let converted = convert_storage(&page.body, args.format, words::cjk_factor(args.language.as_deref()));
+        Ok(ConfluencePageText {
+            page_id: page.page_id,
+            title: page.title,
+            format: args.format,
+            content: converted.content,
+            word_count: converted.word_count,
+            truncated: page.truncated,
+        })
+    })
+    .await
+}
+
+/// storage HTML을 평문/Markdown으로 변환 (네트워크 없이)
+#[tauri::command]
+pub fn convert_confluence_storage(args: ConvertConfluenceStorageArgs) -> CommandResult<StorageConversion> {
+    Ok(convert_storage(
+        &args.html,
+        args.format,
+        words::cjk_factor(args.language.as_deref()),
+    ))
+}
+
+/// Confluence 페이지 가져오기
+/// - `site`(예: "acme.atlassian.net")가 있으면 그 사이트의 cloudId를 사용합니다 (없으면 첫 번째 사이트).
+pub(crate) async fn fetch_page(page_id: &str, site: Option<&str>) -> CommandResult<ConfluencePageContent> {
+    crate::network::ensure_online("Confluence")?;
     println!("[Confluence REST] Getting page HTML for: {}", page_id);
 
     // 1. OAuth 토큰 가져오기
     let access_token = MCP_CLIENT
         .get_oauth_token()
         .await
-        .ok_or("Atlassian OAuth 토큰이 없습니다. Confluence에 먼저 연결해주세요.")?;
+        .ok_or_else(|| {
+            CommandError::new(
+                ErrorCode::AuthRequired,
+                "Atlassian OAuth 토큰이 없습니다. Confluence에 먼저 연결해주세요.",
+            )
+        })?;
 
     println!("[Confluence REST] Got OAuth token (length: {})", access_token.len());
 
     // 2. cloudId 가져오기 (accessible resources에서)
-    let cloud_id = match get_cloud_id(&access_token).await {
+    let cloud_id = match get_cloud_id(&access_token, site).await {
         Ok(id) => {
             println!("[Confluence REST] Got cloudId: {}", id);
             id
@@ -77,76 +199,218 @@ pub async fn confluence_get_page_html(page_id: String) -> Result<ConfluencePageC
     );
     println!("[Confluence REST] Calling API: {}", url);
 
-    let client = reqwest::Client::new();
-    let response = client
+    let client = crate::http::client().map_err(|e| CommandError::new(ErrorCode::NetworkError, e))?;
+    let request = client
         .get(&url)
         .header("Authorization", format!("Bearer {}", access_token))
-        .header("Accept", "application/json")
-        .send()
+        .header("Accept", "application/json");
+    let response = crate::http::send_with_retry(request, RetryPolicy::ATLASSIAN)
         .await
-        .map_err(|e| format!("Confluence API 요청 실패: {}", e))?;
+        .map_err(|e| request_error("Confluence API 요청 실패", &e))?;
 
     let status = response.status();
     println!("[Confluence REST] Response status: {}", status);
 
     if !status.is_success() {
-        let body = response.text().await.unwrap_or_default();
+        let body = read_error_body(response).await;
         println!("[Confluence REST] Error response: {}", body);
-        return Err(format!(
-            "Confluence API 오류 ({}): {}",
-            status, body
-        ));
+        return Err(status_error(status, format!("Confluence API 오류 ({}): {}", status, body)));
     }
 
-    let api_response: ConfluenceApiPageResponse = response
-        .json()
+    // 응답 전체를 한 번에 받지 않고 최대 크기까지만 읽음 (JSON이라 넘으면 쓸 수 없으므로 오류)
+    let limit = crate::http::max_response_bytes();
+    let raw = crate::http::read_body_limited(response, limit)
         .await
-        .map_err(|e| format!("Confluence API 응답 파싱 실패: {}", e))?;
+        .map_err(|e| request_error("Confluence API 응답 읽기 실패", &e))?;
+    if raw.truncated {
+        return Err(CommandError::new(
+            ErrorCode::FileTooLarge,
+            format!("Confluence 페이지가 너무 큽니다 (응답 {} MB 초과)", limit / (1024 * 1024)),
+        ));
+    }
+    let api_response: ConfluenceApiPageResponse = serde_json::from_slice(&raw.bytes)
+        .map_err(|e| {
+            CommandError::new(ErrorCode::ConfluenceError, format!("Confluence API 응답 파싱 실패: {}", e))
+        })?;
+    drop(raw);
 
-    let body = api_response
+    let mut body = api_response
         .body
         .and_then(|b| b.storage)
         .map(|s| s.value)
         .unwrap_or_default();
+    let truncated = crate::http::truncate_utf8(&mut body, crate::http::max_document_bytes());
 
-    println!("[Confluence REST] Success! Title: {}, Body length: {}", api_response.title, body.len());
+    println!(
+        "[Confluence REST] Success! Title: {}, Body length: {}{}",
+        api_response.title,
+        body.len(),
+        if truncated { " (truncated)" } else { "" }
+    );
 
     Ok(ConfluencePageContent {
         page_id: api_response.id,
         title: api_response.title,
         body,
+        truncated,
+    })
+}
+
+/// Jira 이슈 가져오기 (키 예: "ABC-123", `site`는 `fetch_page`와 같음)
+pub(crate) async fn fetch_jira_issue(issue_key: &str, site: Option<&str>) -> CommandResult<JiraIssueContent> {
+    crate::network::ensure_online("Jira")?;
+    println!("[Jira REST] Getting issue: {}", issue_key);
+
+    let access_token = MCP_CLIENT
+        .get_oauth_token()
+        .await
+        .ok_or_else(|| {
+            CommandError::new(
+                ErrorCode::AuthRequired,
+                "Atlassian OAuth 토큰이 없습니다. Confluence에 먼저 연결해주세요.",
+            )
+        })?;
+    let cloud_id = get_cloud_id(&access_token, site).await?;
+
+    let url = format!(
+        "https://api.atlassian.com/ex/jira/{}/rest/api/2/issue/{}?fields=summary,description,status,issuetype",
+        cloud_id,
+        urlencoding::encode(issue_key)
+    );
+    let client = crate::http::client().map_err(|e| CommandError::new(ErrorCode::NetworkError, e))?;
+    let request = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", access_token))
+        .header("Accept", "application/json");
+    let response = crate::http::send_with_retry(request, RetryPolicy::ATLASSIAN)
+        .await
+        .map_err(|e| request_error("Jira API 요청 실패", &e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = read_error_body(response).await;
+        return Err(status_error(status, format!("Jira API 오류 ({}): {}", status, body)));
+    }
+
+    let limit = crate::http::max_response_bytes();
+    let raw = crate::http::read_body_limited(response, limit)
+        .await
+        .map_err(|e| request_error("Jira API 응답 읽기 실패", &e))?;
+    if raw.truncated {
+        return Err(CommandError::new(
+            ErrorCode::FileTooLarge,
+            format!("Jira 이슈가 너무 큽니다 (응답 {} MB 초과)", limit / (1024 * 1024)),
+        ));
+    }
+    let issue: JiraApiIssueResponse = serde_json::from_slice(&raw.bytes)
+        .map_err(|e| CommandError::new(ErrorCode::ConfluenceError, format!("Jira API 응답 파싱 실패: {}", e)))?;
+
+    let mut description = issue.fields.description.unwrap_or_default();
+    let truncated = crate::http::truncate_utf8(&mut description, crate::http::max_document_bytes());
+    Ok(JiraIssueContent {
+        key: issue.key,
+        summary: issue.fields.summary,
+        description,
+        status: issue.fields.status.map(|s| s.name),
+        issue_type: issue.fields.issuetype.map(|t| t.name),
+        truncated,
     })
 }
 
 /// cloudId 가져오기 (캐시 없이 매번 조회 - 간단한 구현)
-async fn get_cloud_id(access_token: &str) -> Result<String, String> {
+/// - 토큰 하나로 여러 사이트에 접근할 수 있으므로 `site`가 있으면 그 사이트를 고릅니다.
+async fn get_cloud_id(access_token: &str, site: Option<&str>) -> CommandResult<String> {
     let url = "https://api.atlassian.com/oauth/token/accessible-resources";
 
-    let client = reqwest::Client::new();
-    let response = client
+    let client = crate::http::client().map_err(|e| CommandError::new(ErrorCode::NetworkError, e))?;
+    let request = client
         .get(url)
         .header("Authorization", format!("Bearer {}", access_token))
-        .header("Accept", "application/json")
-        .send()
+        .header("Accept", "application/json");
+    let response = crate::http::send_with_retry(request, RetryPolicy::ATLASSIAN)
         .await
-        .map_err(|e| format!("Accessible resources 요청 실패: {}", e))?;
+        .map_err(|e| request_error("Accessible resources 요청 실패", &e))?;
 
     if !response.status().is_success() {
         let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        return Err(format!(
-            "Accessible resources 오류 ({}): {}",
-            status, body
-        ));
+        let body = read_error_body(response).await;
+        return Err(status_error(status, format!("Accessible resources 오류 ({}): {}", status, body)));
     }
 
     let resources: Vec<AccessibleResource> = response
         .json()
         .await
-        .map_err(|e| format!("Accessible resources 파싱 실패: {}", e))?;
+        .map_err(|e| {
+            CommandError::new(ErrorCode::ConfluenceError, format!("Accessible resources 파싱 실패: {}", e))
+        })?;
 
-    resources
-        .first()
+    pick_resource(&resources, site)
         .map(|r| r.id.clone())
-        .ok_or_else(|| "Atlassian cloudId를 찾을 수 없습니다".to_string())
+        .ok_or_else(|| match site {
+            Some(site) => CommandError::new(
+                ErrorCode::AuthRequired,
+                format!("연결된 Atlassian 계정으로 {} 사이트에 접근할 수 없습니다", site),
+            ),
+            None => CommandError::new(ErrorCode::ConfluenceError, "Atlassian cloudId를 찾을 수 없습니다"),
+        })
+}
+
+/// 사이트 호스트와 같은 accessible resource (사이트가 없으면 첫 번째)
+fn pick_resource<'a>(resources: &'a [AccessibleResource], site: Option<&str>) -> Option<&'a AccessibleResource> {
+    let Some(site) = site else {
+        return resources.first();
+    };
+    resources.iter().find(|r| {
+        url::Url::parse(&r.url)
+            .ok()
+            .and_then(|u| u.host_str().map(|h| h.eq_ignore_ascii_case(site)))
+            .unwrap_or(false)
+    })
+}
+
+/// 오류 응답 본문 일부 (메시지에 넣을 만큼만 읽음)
+async fn read_error_body(response: reqwest::Response) -> String {
+    const MAX_ERROR_BODY_BYTES: usize = 4 * 1024;
+    match crate::http::read_body_limited(response, MAX_ERROR_BODY_BYTES).await {
+        Ok(body) => String::from_utf8_lossy(&body.bytes).into_owned(),
+        Err(_) => String::new(),
+    }
+}
+
+/// 요청 자체가 실패한 경우 (시간 초과는 `TIMEOUT`, 그 외는 `NETWORK_ERROR`)
+fn request_error(context: &str, error: &reqwest::Error) -> CommandError {
+    let code = if error.is_timeout() {
+        ErrorCode::Timeout
+    } else {
+        ErrorCode::NetworkError
+    };
+    CommandError::new(code, format!("{}: {}", context, error)).with_source(error)
+}
+
+/// HTTP 오류 상태를 레지스트리 코드로 변환 (401/403은 재로그인, 429는 재시도 안내)
+fn status_error(status: reqwest::StatusCode, message: String) -> CommandError {
+    match status.as_u16() {
+        401 | 403 => CommandError::new(ErrorCode::AuthRequired, message),
+        429 => CommandError::new(ErrorCode::RateLimited, message),
+        _ => CommandError::new(ErrorCode::ConfluenceError, message)
+            .with_retryable(status.is_server_error()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_resource_matches_site_host() {
+        let resource = |id: &str, url: &str| AccessibleResource {
+            id: id.to_string(),
+            url: url.to_string(),
+            name: id.to_string(),
+        };
+        let resources = vec![resource("a", "https://alpha.atlassian.net"), resource("b", "https://beta.atlassian.net")];
+        assert_eq!(pick_resource(&resources, Some("beta.atlassian.net")).map(|r| r.id.as_str()), Some("b"));
+        assert_eq!(pick_resource(&resources, None).map(|r| r.id.as_str()), Some("a"));
+        assert!(pick_resource(&resources, Some("gamma.atlassian.net")).is_none());
+    }
 }
@@ -3,8 +3,10 @@
 //! OpenAI 빌트인 커넥터 (Google, Dropbox, Microsoft 등)의 OAuth 토큰을 관리합니다.
 //! 토큰은 SecretManager vault에 안전하게 저장됩니다.
 
+use crate::error::{CommandError, CommandResult, ErrorCode};
 use crate::secrets::SECRETS;
 use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
 
 /// 토큰 만료 전 갱신 여유 시간 (5분)
 const TOKEN_REFRESH_MARGIN_SECS: i64 = 300;
@@ -94,7 +96,7 @@ async fn try_refresh_token(connector_id: &str, current_token: &ConnectorToken) -
     
     println!("[Connector] Attempting token refresh for {}", connector_id);
     
-    let client = reqwest::Client::new();
+    let client = crate::http::client()?;
     let response = client
         .post(config.token_url)
         .form(&[
@@ -149,33 +151,42 @@ fn get_vault_key(connector_id: &str) -> String {
 /// 커넥터 토큰 저장
 #[tauri::command]
 pub async fn connector_set_token(
+    app: AppHandle,
     connector_id: String,
     token: ConnectorToken,
-) -> Result<(), String> {
-    let key = get_vault_key(&connector_id);
-    let token_json = serde_json::to_string(&token)
-        .map_err(|e| format!("Failed to serialize token: {}", e))?;
+) -> CommandResult<()> {
+    crate::crash::guard(&app, "connector_set_token", async {
+        let key = get_vault_key(&connector_id);
+        let token_json = serde_json::to_string(&token)
+            .map_err(|e| CommandError::new(ErrorCode::SerializationError, format!("Failed to serialize token: {}", e)))?;
 
-    SECRETS
-        .set(&key, &token_json)
-        .await
-        .map_err(|e| format!("Failed to save token: {}", e))?;
+        SECRETS
+            .set(&key, &token_json)
+            .await
+            .map_err(|e| CommandError::new(ErrorCode::ConnectorError, format!("Failed to save token: {}", e)))?;
 
-    println!("[Connector] Token saved for {}", connector_id);
-    Ok(())
+        println!("[Connector] Token saved for {}", connector_id);
+        Ok(())
+    })
+    .await
 }
 
 /// 커넥터 토큰 조회 (액세스 토큰만 반환)
 /// 
 /// 토큰이 만료되었거나 곧 만료될 경우 자동으로 갱신을 시도합니다.
 #[tauri::command]
-pub async fn connector_get_token(connector_id: String) -> Result<Option<String>, String> {
-    let key = get_vault_key(&connector_id);
+pub async fn connector_get_token(app: AppHandle, connector_id: String) -> CommandResult<Option<String>> {
+    crate::crash::guard(&app, "connector_get_token", load_connector_token(&connector_id)).await
+}
+
+/// 커넥터 토큰 조회 (만료 시 자동 갱신, 다른 명령에서 쓰는 본문)
+pub(crate) async fn load_connector_token(connector_id: &str) -> CommandResult<Option<String>> {
+    let key = get_vault_key(connector_id);
 
     match SECRETS.get(&key).await {
         Ok(Some(token_json)) => {
             let mut token: ConnectorToken = serde_json::from_str(&token_json)
-                .map_err(|e| format!("Failed to parse token: {}", e))?;
+                .map_err(|e| CommandError::new(ErrorCode::SerializationError, format!("Failed to parse token: {}", e)))?;
 
             // 만료 확인 및 자동 갱신
             if token.is_expired() {
@@ -183,15 +194,25 @@ pub async fn connector_get_token(connector_id: String) -> Result<Option<String>,
                 
                 if token.can_refresh() {
                     // 자동 갱신 시도
-                    match try_refresh_token(&connector_id, &token).await {
+                    match try_refresh_token(connector_id, &token).await {
                         Ok(new_token) => {
                             // 갱신된 토큰을 vault에 저장
                             let new_token_json = serde_json::to_string(&new_token)
-                                .map_err(|e| format!("Failed to serialize refreshed token: {}", e))?;
+                                .map_err(|e| {
+                                    CommandError::new(
+                                        ErrorCode::SerializationError,
+                                        format!("Failed to serialize refreshed token: {}", e),
+                                    )
+                                })?;
                             SECRETS
                                 .set(&key, &new_token_json)
                                 .await
-                                .map_err(|e| format!("Failed to save refreshed token: {}", e))?;
+                                .map_err(|e| {
+                                    CommandError::new(
+                                        ErrorCode::ConnectorError,
+                                        format!("Failed to save refreshed token: {}", e),
+                                    )
+                                })?;
                             
                             token = new_token;
                         }
@@ -211,67 +232,83 @@ pub async fn connector_get_token(connector_id: String) -> Result<Option<String>,
             Ok(Some(token.access_token))
         }
         Ok(None) => Ok(None),
-        Err(e) => Err(format!("Failed to get token: {}", e)),
+        Err(e) => Err(CommandError::new(
+            ErrorCode::ConnectorError,
+            format!("Failed to get token: {}", e),
+        )),
     }
 }
 
 /// 커넥터 토큰 삭제
 #[tauri::command]
-pub async fn connector_delete_token(connector_id: String) -> Result<(), String> {
-    let key = get_vault_key(&connector_id);
+pub async fn connector_delete_token(app: AppHandle, connector_id: String) -> CommandResult<()> {
+    crate::crash::guard(&app, "connector_delete_token", async {
+        let key = get_vault_key(&connector_id);
 
-    SECRETS
-        .delete(&key)
-        .await
-        .map_err(|e| format!("Failed to delete token: {}", e))?;
+        SECRETS
+            .delete(&key)
+            .await
+            .map_err(|e| CommandError::new(ErrorCode::ConnectorError, format!("Failed to delete token: {}", e)))?;
 
-    println!("[Connector] Token deleted for {}", connector_id);
-    Ok(())
+        println!("[Connector] Token deleted for {}", connector_id);
+        Ok(())
+    })
+    .await
 }
 
 /// 커넥터 상태 목록 조회
 /// 
 /// SecretManager 캐시에서 조회하므로 Keychain 프롬프트 없이 빠르게 조회됩니다.
 #[tauri::command]
-pub async fn connector_list_status(connector_ids: Vec<String>) -> Result<Vec<ConnectorStatus>, String> {
-    let mut statuses = Vec::new();
+pub async fn connector_list_status(app: AppHandle, connector_ids: Vec<String>) -> CommandResult<Vec<ConnectorStatus>> {
+    crate::crash::guard(&app, "connector_list_status", async {
+        let mut statuses = Vec::new();
 
-    for connector_id in connector_ids {
-        let key = get_vault_key(&connector_id);
+        for connector_id in connector_ids {
+            let key = get_vault_key(&connector_id);
 
-        let (has_token, expires_at, is_expired) = match SECRETS.get(&key).await {
-            Ok(Some(token_json)) => {
-                if let Ok(token) = serde_json::from_str::<ConnectorToken>(&token_json) {
-                    // is_expired()는 5분 여유를 두고 확인
-                    (true, token.expires_at, token.is_expired())
-                } else {
-                    (false, None, false)
+            let (has_token, expires_at, is_expired) = match SECRETS.get(&key).await {
+                Ok(Some(token_json)) => {
+                    if let Ok(token) = serde_json::from_str::<ConnectorToken>(&token_json) {
+                        // is_expired()는 5분 여유를 두고 확인
+                        (true, token.expires_at, token.is_expired())
+                    } else {
+                        (false, None, false)
+                    }
                 }
-            }
-            Ok(None) => (false, None, false),
-            Err(_) => (false, None, false),
-        };
+                Ok(None) => (false, None, false),
+                Err(_) => (false, None, false),
+            };
 
-        statuses.push(ConnectorStatus {
-            connector_id,
-            has_token,
-            expires_at,
-            is_expired,
-        });
-    }
+            statuses.push(ConnectorStatus {
+                connector_id,
+                has_token,
+                expires_at,
+                is_expired,
+            });
+        }
 
-    Ok(statuses)
+        Ok(statuses)
+    })
+    .await
 }
 
 /// 커넥터 OAuth 플로우 시작 (TODO: Phase 2-oauth에서 구현)
 #[tauri::command]
-pub async fn connector_start_oauth(connector_id: String) -> Result<String, String> {
-    // TODO: 각 서비스별 OAuth 플로우 구현
-    // - Google: OAuth 2.0 with consent screen
-    // - Dropbox: OAuth 2.0
-    // - Microsoft: Azure AD OAuth 2.0
-    Err(format!(
-        "OAuth flow for {} is not yet implemented. Coming in Phase 2-oauth.",
-        connector_id
-    ))
+pub async fn connector_start_oauth(app: AppHandle, connector_id: String) -> CommandResult<String> {
+    crate::crash::guard(&app, "connector_start_oauth", async {
+        crate::network::ensure_online("Connector sign-in")?;
+        // TODO: 각 서비스별 OAuth 플로우 구현
+        // - Google: OAuth 2.0 with consent screen
+        // - Dropbox: OAuth 2.0
+        // - Microsoft: Azure AD OAuth 2.0
+        Err(CommandError::new(
+            ErrorCode::ConnectorError,
+            format!(
+                "OAuth flow for {} is not yet implemented. Coming in Phase 2-oauth.",
+                connector_id
+            ),
+        ))
+    })
+    .await
 }
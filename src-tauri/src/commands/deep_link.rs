@@ -0,0 +1,224 @@
+//! Deep Link Commands
+//!
+//! `ite://` URL과 OS에서 연 .ite 파일 처리 (Slack/Notion 링크, 파일 더블클릭으로 프로젝트 바로 열기)
+//! - `ite://project/<id>`: 프로젝트 열기
+//! - `ite://import?path=<경로>`: 파일 가져오기 (프론트엔드에서 사용자 확인 후 `import_project_file_safe` 호출)
+//! - .ite 파일: 이미 가져온 프로젝트면 열기, 아니면 가져오기로 변환
+//!
+//! 프론트엔드(메인 창의 `useDeepLinks`)가 시작하면서 `take_pending_deep_links`를 호출하기 전에 받은 대상은
+//! 보관해 두었다가 그때 넘기고, 그 이후에는 `deep-link-navigate` 이벤트로 보냅니다.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::storage::{inspect_ite_file, ImportDbArgs};
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 등록할 URL 스킴 (tauri.conf.json의 `plugins.deep-link`와 맞춰야 함)
+pub const DEEP_LINK_SCHEME: &str = "ite";
+
+/// 딥 링크 이동 이벤트
+pub const DEEP_LINK_EVENT: &str = "deep-link-navigate";
+
+/// 파일 연결로 여는 확장자 (tauri.conf.json의 `bundle.fileAssociations`와 맞춰야 함)
+const PROJECT_FILE_EXTENSION: &str = "ite";
+
+/// 딥 링크가 가리키는 대상
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum DeepLinkTarget {
+    #[serde(rename_all = "camelCase")]
+    OpenProject { project_id: String },
+    Import { path: String },
+}
+
+#[derive(Default)]
+struct PendingState {
+    /// 프론트엔드가 준비되었는지 (`take_pending_deep_links` 호출 이후)
+    ready: bool,
+    targets: Vec<DeepLinkTarget>,
+}
+
+/// 프론트엔드가 준비되기 전에 받은 대상
+#[derive(Default)]
+pub struct PendingDeepLinks(Mutex<PendingState>);
+
+/// `ite://` URL 해석
+pub fn parse_deep_link(raw: &str) -> Result<DeepLinkTarget, String> {
+    let url = url::Url::parse(raw).map_err(|e| format!("Invalid URL: {}", e))?;
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("Unsupported scheme: {}", url.scheme()));
+    }
+
+    match url.host_str() {
+        Some("project") => {
+            let id = url.path().trim_matches('/');
+            let id = urlencoding::decode(id).map_err(|e| format!("Invalid project id: {}", e))?;
+            if id.is_empty() || id.contains('/') {
+                return Err("Project id is missing".to_string());
+            }
+            Ok(DeepLinkTarget::OpenProject {
+                project_id: id.into_owned(),
+            })
+        }
+        Some("import") => {
+            let path = url
+                .query_pairs()
+                .find(|(k, _)| k == "path")
+                .map(|(_, v)| v.into_owned())
+                .filter(|p| !p.trim().is_empty())
+                .ok_or("Import path is missing")?;
+            Ok(DeepLinkTarget::Import { path })
+        }
+        other => Err(format!("Unknown deep link action: {}", other.unwrap_or(""))),
+    }
+}
+
+/// 실행 인자에서 .ite 파일 경로 추출 (상대 경로는 `cwd` 기준)
+/// - 첫 인자(실행 파일)와 옵션, 존재하지 않는 파일은 제외합니다.
+pub fn project_files_from_args(args: &[String], cwd: &Path) -> Vec<PathBuf> {
+    args.iter()
+        .skip(1)
+        .filter(|a| !a.starts_with('-'))
+        .map(|a| cwd.join(a))
+        .filter(|p| is_project_file(p) && p.is_file())
+        .collect()
+}
+
+fn is_project_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case(PROJECT_FILE_EXTENSION))
+}
+
+/// OS에서 연 .ite 파일을 대상으로 변환
+/// - 파일의 프로젝트가 모두 이미 DB에 있으면 첫 프로젝트 열기, 아니면 가져오기
+fn file_target(app: &AppHandle, path: &Path) -> Result<DeepLinkTarget, String> {
+    let path_str = path.to_string_lossy().to_string();
+    let info = inspect_ite_file(ImportDbArgs { path: path_str.clone() }).map_err(|e| e.message)?;
+
+    let existing = {
+        let state = app.state::<DbState>();
+        let db = state.0.lock().map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+        db.list_project_ids().map_err(|e| e.to_string())?
+    };
+    let already_imported =
+        !info.projects.is_empty() && info.projects.iter().all(|p| existing.contains(&p.id));
+
+    match info.projects.first() {
+        Some(first) if already_imported => Ok(DeepLinkTarget::OpenProject {
+            project_id: first.id.clone(),
+        }),
+        _ => Ok(DeepLinkTarget::Import { path: path_str }),
+    }
+}
+
+/// 받은 `ite://` URL들을 처리 (잘못된 링크는 로그만 남기고 무시)
+pub fn handle_deep_links(app: &AppHandle, urls: &[String]) {
+    let targets = urls
+        .iter()
+        .filter_map(|raw| match parse_deep_link(raw) {
+            Ok(target) => Some(target),
+            Err(e) => {
+                eprintln!("[DeepLink] Ignored {}: {}", raw, e);
+                None
+            }
+        })
+        .collect();
+    navigate(app, targets);
+}
+
+/// OS에서 연 .ite 파일들을 처리 (읽을 수 없는 파일은 로그만 남기고 무시)
+pub fn handle_opened_files(app: &AppHandle, paths: &[PathBuf]) {
+    let targets = paths
+        .iter()
+        .filter(|p| is_project_file(p))
+        .filter_map(|p| match file_target(app, p) {
+            Ok(target) => Some(target),
+            Err(e) => {
+                eprintln!("[DeepLink] Ignored file {}: {}", p.display(), e);
+                None
+            }
+        })
+        .collect();
+    navigate(app, targets);
+}
+
+/// 메인 창을 앞으로 가져오고, 프론트엔드가 준비되었으면 이벤트를 보내고 아니면 보관
+/// - 메인 창의 `useDeepLinks`가 시작할 때 보관한 대상을 꺼내 갑니다.
+pub(crate) fn navigate(app: &AppHandle, targets: Vec<DeepLinkTarget>) {
+    if targets.is_empty() {
+        return;
+    }
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+    {
+        let state = app.state::<PendingDeepLinks>();
+        let Ok(mut pending) = state.0.lock() else {
+            return;
+        };
+        if !pending.ready {
+            pending.targets.extend(targets);
+            return;
+        }
+    }
+
+    for target in targets {
+        println!("[DeepLink] {:?}", target);
+        let _ = app.emit_to("main", DEEP_LINK_EVENT, target);
+    }
+}
+
+/// 준비되기 전에 받은 대상 가져오기 (한 번만 반환, 이후는 이벤트로 전달)
+#[tauri::command]
+pub fn take_pending_deep_links(pending: State<PendingDeepLinks>) -> CommandResult<Vec<DeepLinkTarget>> {
+    let mut pending = pending.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire deep link lock: {}", e),
+    ))?;
+    pending.ready = true;
+    Ok(std::mem::take(&mut pending.targets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_project_and_import_links() {
+        assert_eq!(
+            parse_deep_link("ite://project/abc-123").unwrap(),
+            DeepLinkTarget::OpenProject {
+                project_id: "abc-123".to_string()
+            }
+        );
+        assert_eq!(
+            parse_deep_link("ite://import?path=%2FUsers%2Fme%2Fdoc%20a.docx").unwrap(),
+            DeepLinkTarget::Import {
+                path: "/Users/me/doc a.docx".to_string()
+            }
+        );
+        assert!(parse_deep_link("ite://project/").is_err());
+        assert!(parse_deep_link("ite://import").is_err());
+        assert!(parse_deep_link("https://project/abc").is_err());
+        assert!(parse_deep_link("ite://settings").is_err());
+    }
+
+    #[test]
+    fn picks_existing_project_files_from_args() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.ite"), b"x").unwrap();
+        std::fs::write(dir.path().join("b.txt"), b"x").unwrap();
+        let args: Vec<String> = ["oddeyes", "a.ite", "b.txt", "missing.ite", "--flag"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(project_files_from_args(&args, dir.path()), vec![dir.path().join("a.ite")]);
+    }
+}
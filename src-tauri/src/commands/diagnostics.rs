@@ -0,0 +1,39 @@
+//! Diagnostics Commands
+//!
+//! 패닉 기록(요약 + 백트레이스 로그) 조회/삭제 API
+//! - 문제 제보 시 진단 자료로 함께 보낼 수 있도록 로그 디렉토리 경로도 알려줍니다.
+
+use serde::Serialize;
+
+use crate::crash::{self, CrashMarker};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 패닉 기록 조회 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReport {
+    /// 최근 것이 앞
+    pub markers: Vec<CrashMarker>,
+    /// 백트레이스 로그 파일 경로 (기록 위치를 아직 정하지 못했으면 None)
+    pub log_path: Option<String>,
+}
+
+/// 기록된 패닉 목록
+#[tauri::command]
+pub fn get_crash_report() -> CommandResult<CrashReport> {
+    let mut markers = crash::crash_markers();
+    markers.reverse();
+    Ok(CrashReport {
+        markers,
+        log_path: crash::crash_log_path().map(|p| p.to_string_lossy().to_string()),
+    })
+}
+
+/// 패닉 기록 삭제, 지운 요약 수 반환
+#[tauri::command]
+pub fn clear_crash_report() -> CommandResult<usize> {
+    crash::clear_crash_markers().map_err(|e| CommandError::new(
+        ErrorCode::IoError,
+        format!("Failed to clear crash report: {}", e),
+    ))
+}
@@ -0,0 +1,138 @@
+//! DNT Commands
+//!
+//! 번역 금지(DNT) 용어 관리 및 LLM/MT 전송용 마스킹 API
+//! - 프론트엔드는 프로바이더 호출 전 `mask_dnt_text`, 응답 수신 후 `unmask_dnt_text`를 호출합니다.
+
+use serde::Deserialize;
+use tauri::State;
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::DntTerm;
+use crate::text::dnt::{compile_term, DntMatcher};
+use crate::text::{restore_placeholders, MaskedText, Placeholder, RestoreResult};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDntTermsArgs {
+    /// 주어지면 전역 + 해당 프로젝트 용어, 없으면 전역 용어만
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDntTermArgs {
+    /// 없으면 새 용어 생성
+    pub id: Option<String>,
+    /// 없으면 전역 용어
+    pub project_id: Option<String>,
+    pub term: String,
+    pub is_regex: Option<bool>,
+    pub case_sensitive: Option<bool>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDntTermArgs {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaskDntTextArgs {
+    pub project_id: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmaskDntTextArgs {
+    pub text: String,
+    pub placeholders: Vec<Placeholder>,
+}
+
+/// 프로젝트(또는 전역) DNT 용어로 매처 생성
+pub fn load_dnt_matcher(db_state: &State<DbState>, project_id: Option<&str>) -> CommandResult<DntMatcher> {
+    let terms = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.list_dnt_terms(project_id).map_err(CommandError::from)?
+    };
+
+    DntMatcher::new(&terms).map_err(|e| CommandError::new(
+        ErrorCode::InvalidPattern,
+        "저장된 DNT 패턴을 해석할 수 없습니다.",
+    ).with_details(e.to_string()))
+}
+
+/// DNT 용어 목록 조회
+#[tauri::command]
+pub fn list_dnt_terms(args: ListDntTermsArgs, db_state: State<DbState>) -> CommandResult<Vec<DntTerm>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_dnt_terms(args.project_id.as_deref())
+        .map_err(CommandError::from)
+}
+
+/// DNT 용어 저장 (생성 또는 수정)
+/// - 정규식 패턴은 저장 전에 컴파일해 검증합니다.
+#[tauri::command]
+pub fn save_dnt_term(args: SaveDntTermArgs, db_state: State<DbState>) -> CommandResult<DntTerm> {
+    if args.term.trim().is_empty() {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "DNT 용어를 입력해주세요."));
+    }
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let term = DntTerm {
+        id: args.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+        project_id: args.project_id,
+        term: args.term,
+        is_regex: args.is_regex.unwrap_or(false),
+        case_sensitive: args.case_sensitive.unwrap_or(true),
+        notes: args.notes.filter(|n| !n.trim().is_empty()),
+        created_at: now,
+        updated_at: now,
+    };
+
+    if let Err(e) = compile_term(&term) {
+        return Err(CommandError::new(ErrorCode::InvalidPattern, "잘못된 정규식 패턴입니다.").with_details(e.to_string()));
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.save_dnt_term(&term).map_err(CommandError::from)?;
+    Ok(term)
+}
+
+/// DNT 용어 삭제
+#[tauri::command]
+pub fn delete_dnt_term(args: DeleteDntTermArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.delete_dnt_term(&args.id).map_err(CommandError::from)
+}
+
+/// LLM/MT 전송 전 DNT 구간을 토큰으로 마스킹
+#[tauri::command]
+pub fn mask_dnt_text(args: MaskDntTextArgs, db_state: State<DbState>) -> CommandResult<MaskedText> {
+    let matcher = load_dnt_matcher(&db_state, Some(&args.project_id))?;
+    Ok(matcher.mask(&args.text))
+}
+
+/// 프로바이더 응답의 DNT 토큰을 원래 용어로 복원 (누락/중복 토큰 보고)
+#[tauri::command]
+pub fn unmask_dnt_text(args: UnmaskDntTextArgs) -> CommandResult<RestoreResult> {
+    Ok(restore_placeholders(&args.text, &args.placeholders))
+}
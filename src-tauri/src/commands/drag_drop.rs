@@ -0,0 +1,180 @@
+//! Drag & Drop Import
+//!
+//! 창에 파일을 끌어다 놓으면 확장자에 따라 알맞은 가져오기로 보냅니다.
+//! - .csv / .xlsx / .xls: 창에 열린 프로젝트의 용어집으로 가져오기
+//! - .docx / .pptx / .pdf / 텍스트 / 이미지: 창에 열린 프로젝트에 첨부 (`attach_file`)
+//! - .ite: 파일 연결과 같은 경로로 열기/가져오기 (`deep-link-navigate` 이벤트)
+//!
+//! 파일마다 결과를 `import-dropped-file` 이벤트로 드롭한 창에 보냅니다.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Window};
+
+use super::attachments::{attach_file, AttachFileArgs};
+use super::glossary::{
+    import_glossary_csv, import_glossary_excel, ImportGlossaryCsvArgs, ImportGlossaryExcelArgs,
+    ImportGlossaryResult,
+};
+use super::window::WindowProjects;
+use crate::db::DbState;
+use crate::error::{CommandResult, ErrorCode};
+use crate::models::AttachmentDto;
+
+/// 드롭한 파일 처리 결과 이벤트
+pub const IMPORT_DROPPED_FILE_EVENT: &str = "import-dropped-file";
+
+/// 확장자별 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DropRoute {
+    GlossaryCsv,
+    GlossaryExcel,
+    Attachment,
+    ProjectFile,
+}
+
+fn drop_route(path: &Path) -> Option<DropRoute> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    match ext.as_str() {
+        "csv" => Some(DropRoute::GlossaryCsv),
+        "xlsx" | "xls" => Some(DropRoute::GlossaryExcel),
+        "docx" | "pptx" | "pdf" | "md" | "txt" | "tsv" | "srt" | "png" | "jpg" | "jpeg" | "webp"
+        | "gif" => Some(DropRoute::Attachment),
+        "ite" => Some(DropRoute::ProjectFile),
+        _ => None,
+    }
+}
+
+/// 파일 1개 처리 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum DropOutcome {
+    Attached { attachment: AttachmentDto },
+    GlossaryImported { result: ImportGlossaryResult },
+    /// .ite 파일은 `deep-link-navigate` 이벤트로 이어서 처리
+    ProjectFileOpened,
+    Skipped { reason: String },
+    Failed { code: ErrorCode, message: String },
+}
+
+/// `import-dropped-file` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedFileEvent {
+    pub path: String,
+    pub project_id: Option<String>,
+    pub outcome: DropOutcome,
+}
+
+fn outcome_of<T>(result: CommandResult<T>, ok: impl FnOnce(T) -> DropOutcome) -> DropOutcome {
+    match result {
+        Ok(value) => ok(value),
+        Err(e) => DropOutcome::Failed {
+            code: e.code,
+            message: e.message,
+        },
+    }
+}
+
+async fn import_dropped_file(app: &AppHandle, project_id: Option<&str>, path: &Path) -> DropOutcome {
+    let Some(route) = drop_route(path) else {
+        return DropOutcome::Skipped {
+            reason: "Unsupported file type".to_string(),
+        };
+    };
+    if route == DropRoute::ProjectFile {
+        super::deep_link::handle_opened_files(app, &[path.to_path_buf()]);
+        return DropOutcome::ProjectFileOpened;
+    }
+    let Some(project_id) = project_id else {
+        return DropOutcome::Skipped {
+            reason: "Open a project before dropping files".to_string(),
+        };
+    };
+
+    let path_str = path.to_string_lossy().to_string();
+    let db_state = app.state::<DbState>();
+    match route {
+        DropRoute::GlossaryCsv => outcome_of(
+            import_glossary_csv(
+                ImportGlossaryCsvArgs {
+                    project_id: project_id.to_string(),
+                    path: path_str,
+                    replace_project_scope: None,
+                },
+                db_state,
+            ),
+            |result| DropOutcome::GlossaryImported { result },
+        ),
+        DropRoute::GlossaryExcel => outcome_of(
+            import_glossary_excel(
+                ImportGlossaryExcelArgs {
+                    project_id: project_id.to_string(),
+                    path: path_str,
+                    replace_project_scope: None,
+                },
+                db_state,
+            ),
+            |result| DropOutcome::GlossaryImported { result },
+        ),
+        DropRoute::Attachment => outcome_of(
+            attach_file(
+                app.clone(),
+                AttachFileArgs {
+                    project_id: project_id.to_string(),
+                    path: path_str,
+                },
+                db_state,
+            )
+            .await,
+            |attachment| DropOutcome::Attached { attachment },
+        ),
+        DropRoute::ProjectFile => DropOutcome::ProjectFileOpened,
+    }
+}
+
+/// 창에 드롭된 파일 처리 (`WindowEvent::DragDrop`에서 호출)
+/// - 대상 프로젝트는 드롭한 창이 보고 있는 프로젝트 (`set_window_project`/`open_project_window`로 기록)
+pub fn handle_file_drop(window: &Window, paths: Vec<PathBuf>) {
+    let app = window.app_handle().clone();
+    let label = window.label().to_string();
+    let project_id = app
+        .state::<WindowProjects>()
+        .0
+        .lock()
+        .ok()
+        .and_then(|windows| windows.get(&label).cloned());
+
+    tauri::async_runtime::spawn(async move {
+        for path in paths {
+            let outcome = import_dropped_file(&app, project_id.as_deref(), &path).await;
+            println!("[DragDrop] {} -> {:?}", path.display(), outcome);
+            let _ = app.emit_to(
+                label.as_str(),
+                IMPORT_DROPPED_FILE_EVENT,
+                DroppedFileEvent {
+                    path: path.to_string_lossy().to_string(),
+                    project_id: project_id.clone(),
+                    outcome,
+                },
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_by_extension() {
+        assert_eq!(drop_route(Path::new("/a/terms.CSV")), Some(DropRoute::GlossaryCsv));
+        assert_eq!(drop_route(Path::new("/a/terms.xlsx")), Some(DropRoute::GlossaryExcel));
+        assert_eq!(drop_route(Path::new("/a/deck.pptx")), Some(DropRoute::Attachment));
+        assert_eq!(drop_route(Path::new("/a/spec.docx")), Some(DropRoute::Attachment));
+        assert_eq!(drop_route(Path::new("/a/backup.ite")), Some(DropRoute::ProjectFile));
+        assert_eq!(drop_route(Path::new("/a/app.exe")), None);
+        assert_eq!(drop_route(Path::new("/a/README")), None);
+    }
+}
@@ -0,0 +1,116 @@
+//! Database Encryption Commands
+//!
+//! ite.db SQLCipher 암호화 켜기/끄기 (opt-in, `sqlcipher` feature 빌드 필요)
+//! - 키는 SecretManager vault에 보관하며, 키를 먼저 저장한 뒤 DB를 전환합니다.
+
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use crate::db::{generate_database_key, DbState, DATABASE_KEY_SECRET};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::secrets::SECRETS;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseEncryptionStatus {
+    /// SQLCipher가 포함된 빌드인지
+    pub available: bool,
+    pub encrypted: bool,
+    /// SecretManager에 DB 키가 저장되어 있는지
+    pub key_stored: bool,
+}
+
+fn map_secret_error(err: crate::secrets::manager::SecretManagerError) -> CommandError {
+    CommandError::new(ErrorCode::SecretManagerError, format!("Secret manager error: {}", err))
+}
+
+/// DB 암호화 상태 조회
+#[tauri::command]
+pub async fn get_database_encryption_status(app: AppHandle, db_state: State<'_, DbState>) -> CommandResult<DatabaseEncryptionStatus> {
+    crate::crash::guard(&app, "get_database_encryption_status", async {
+        let (available, encrypted) = {
+            let db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            (db.sqlcipher_available(), db.is_encrypted())
+        };
+        let key_stored = SECRETS.has(DATABASE_KEY_SECRET).await.unwrap_or(false);
+
+        Ok(DatabaseEncryptionStatus {
+            available,
+            encrypted,
+            key_stored,
+        })
+    })
+    .await
+}
+
+/// DB 암호화 켜기 (기존 평문 DB를 암호화된 파일로 다시 씀)
+/// - 이미 만들어진 자동 백업(ite_backups)과 내보낸 .ite 파일은 평문으로 남습니다.
+#[tauri::command]
+pub async fn enable_database_encryption(app: AppHandle, db_state: State<'_, DbState>) -> CommandResult<DatabaseEncryptionStatus> {
+    crate::crash::guard(&app, "enable_database_encryption", async {
+        {
+            let db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            if !db.sqlcipher_available() {
+                return Err(CommandError::new(ErrorCode::InvalidOperation, "This build does not include SQLCipher"));
+            }
+            if db.is_encrypted() {
+                return Err(CommandError::new(ErrorCode::InvalidOperation, "Database is already encrypted"));
+            }
+        }
+
+        // 키를 잃으면 DB를 열 수 없으므로 vault에 먼저 저장
+        let key = generate_database_key();
+        SECRETS
+            .set(DATABASE_KEY_SECRET, &key)
+            .await
+            .map_err(map_secret_error)?;
+
+        let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.change_encryption(Some(&key)).map_err(CommandError::from)?;
+
+        Ok(DatabaseEncryptionStatus {
+            available: true,
+            encrypted: db.is_encrypted(),
+            key_stored: true,
+        })
+    })
+    .await
+}
+
+/// DB 암호화 끄기 (평문 파일로 다시 쓰고 키 삭제)
+#[tauri::command]
+pub async fn disable_database_encryption(app: AppHandle, db_state: State<'_, DbState>) -> CommandResult<DatabaseEncryptionStatus> {
+    crate::crash::guard(&app, "disable_database_encryption", async {
+        {
+            let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            if !db.is_encrypted() {
+                return Err(CommandError::new(ErrorCode::InvalidOperation, "Database is not encrypted"));
+            }
+            db.change_encryption(None).map_err(CommandError::from)?;
+        }
+
+        SECRETS
+            .delete(DATABASE_KEY_SECRET)
+            .await
+            .map_err(map_secret_error)?;
+
+        Ok(DatabaseEncryptionStatus {
+            available: true,
+            encrypted: false,
+            key_stored: false,
+        })
+    })
+    .await
+}
@@ -0,0 +1,24 @@
+//! Environment Commands
+//!
+//! `.env*` 로드 결과 확인/다시 불러오기 (개발 환경 설정 문제 진단용)
+
+use crate::env::{self, EnvInfo};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 현재 프로필과 파일별로 불러온 키 목록 (비밀로 보이는 키는 값 생략)
+#[tauri::command]
+pub fn ite_env_info() -> CommandResult<EnvInfo> {
+    Ok(env::env_info())
+}
+
+/// `.env*` 파일 다시 불러오기 (개발 빌드 전용)
+#[tauri::command]
+pub fn reload_env() -> CommandResult<EnvInfo> {
+    if !cfg!(debug_assertions) {
+        return Err(CommandError::new(
+            ErrorCode::InvalidOperation,
+            "reload_env is only available in development builds",
+        ));
+    }
+    Ok(env::reload_env())
+}
@@ -6,7 +6,8 @@ use serde::{Deserialize, Serialize};
 use tauri::State;
 
 use crate::db::DbState;
-use crate::error::{CommandError, CommandResult};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::text::glossary::GlossaryHit;
 use crate::utils::validate_path;
 
 #[derive(Debug, Deserialize)]
@@ -19,7 +20,7 @@ pub struct ImportGlossaryCsvArgs {
     pub replace_project_scope: Option<bool>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportGlossaryResult {
     pub inserted: u32,
@@ -46,11 +47,10 @@ pub fn import_glossary_csv(
     // 경로 검증 (시스템 디렉토리 접근 차단)
     let validated_path = validate_path(&args.path)?;
 
-    let mut db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     let replace = args.replace_project_scope.unwrap_or(false);
     let (inserted, updated, skipped) = db
@@ -73,11 +73,10 @@ pub fn import_glossary_excel(
     // 경로 검증 (시스템 디렉토리 접근 차단)
     let validated_path = validate_path(&args.path)?;
 
-    let mut db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     let replace = args.replace_project_scope.unwrap_or(false);
     let (inserted, updated, skipped) = db
@@ -95,6 +94,7 @@ pub fn import_glossary_excel(
 #[serde(rename_all = "camelCase")]
 pub struct SearchGlossaryArgs {
     pub project_id: String,
+    #[serde(deserialize_with = "crate::text::unicode::deserialize_nfc")]
     pub query: String,
     pub limit: Option<u32>,
     pub domain: Option<String>,
@@ -119,11 +119,10 @@ pub fn search_glossary(
     args: SearchGlossaryArgs,
     db_state: State<DbState>,
 ) -> CommandResult<Vec<GlossaryEntryDto>> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     let limit = args.limit.unwrap_or(12).min(50);
     let rows = db
@@ -146,3 +145,24 @@ pub fn search_glossary(
 }
 
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockGlossaryHitsArgs {
+    pub project_id: String,
+    pub block_id: String,
+}
+
+/// 원문 블록의 용어집 매칭 구간 (미리 계산된 결과, 블록/용어집이 바뀌면 다시 계산)
+#[tauri::command]
+pub fn get_block_glossary_hits(
+    args: GetBlockGlossaryHitsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<GlossaryHit>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.block_glossary_hits(&args.project_id, &args.block_id)
+        .map_err(CommandError::from)
+}
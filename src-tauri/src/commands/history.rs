@@ -2,59 +2,228 @@
 //!
 //! 버전 히스토리 관련 Tauri 명령어
 
+use serde::Deserialize;
 use tauri::State;
 
-use crate::db::DbState;
-use crate::error::CommandResult;
-use crate::models::HistorySnapshot;
+use crate::db::{ChangePage, Database, DbState, RestoreOutcome, RestorePreview};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::export::chat::summarize_chat_session;
+use crate::export::snapshot::{build_snapshot_document, parse_snapshot_document};
+use crate::models::{HistorySnapshot, SnapshotState};
+use crate::utils::validate_path;
 
-/// 스냅샷 생성
+/// 스냅샷에 붙이는 채팅 요약 최대 길이 (문자 수)
+const CHAT_SUMMARY_MAX_CHARS: usize = 2000;
+
+/// 변경 피드 기본 페이지 크기
+const DEFAULT_CHANGES_LIMIT: usize = 500;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChangesSinceArgs {
+    /// 마지막으로 받은 `nextCursor` (처음이면 0)
+    #[serde(default)]
+    pub cursor: i64,
+    /// 최대 개수 (기본 500, 최대 1000)
+    pub limit: Option<usize>,
+    /// 지정하면 해당 프로젝트의 변경만
+    pub project_id: Option<String>,
+}
+
+/// 스냅샷 생성 (프로젝트 현재 상태 전체를 보관)
+/// - `chat_summary`가 비어 있고 `session_id`가 주어지면 그 채팅 세션의 최근 대화를 발췌해 요약으로 붙입니다.
+///   (프론트에서 LLM으로 요약한 경우 `chat_summary`로 넘기면 그대로 저장)
 #[tauri::command]
 pub fn create_snapshot(
     project_id: String,
     description: String,
     chat_summary: Option<String>,
-    _db_state: State<DbState>,
+    session_id: Option<String>,
+    db_state: State<DbState>,
 ) -> CommandResult<HistorySnapshot> {
-    let now = chrono::Utc::now().timestamp_millis();
-    let snapshot_id = uuid::Uuid::new_v4().to_string();
-
-    let snapshot = HistorySnapshot {
-        id: snapshot_id,
-        timestamp: now,
-        description,
-        block_changes: Vec::new(), // TODO: 실제 변경사항 추적
-        chat_summary,
-    };
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
-    // TODO: 데이터베이스에 스냅샷 저장
-    let _ = project_id; // 사용 예정
+    let mut chat_summary = chat_summary.filter(|s| !s.trim().is_empty());
+    if chat_summary.is_none() {
+        if let Some(session_id) = session_id.as_deref().filter(|s| !s.trim().is_empty()) {
+            let session = db.load_chat_session(session_id)?.ok_or_else(|| CommandError::new(
+                ErrorCode::NotFound,
+                format!("Chat session not found: {}", session_id),
+            ))?;
+            chat_summary = summarize_chat_session(&session, CHAT_SUMMARY_MAX_CHARS);
+        }
+    }
 
-    Ok(snapshot)
+    db.create_history_snapshot(&project_id, &description, chat_summary.as_deref())
+        .map_err(CommandError::from)
+}
+
+/// 복원 대상 스냅샷과 상태 조회 (다른 프로젝트 스냅샷/상태 없는 스냅샷은 거부)
+fn load_restorable(
+    db: &Database,
+    project_id: &str,
+    snapshot_id: &str,
+) -> CommandResult<(HistorySnapshot, SnapshotState)> {
+    let stored = db
+        .load_history_snapshot(snapshot_id)
+        .map_err(CommandError::from)?
+        .filter(|s| s.project_id == project_id)
+        .ok_or_else(|| CommandError::new(ErrorCode::NotFound, format!("Snapshot not found: {}", snapshot_id)))?;
+    let state = stored.state.ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidOperation,
+        "Snapshot has no stored project state and cannot be restored",
+    ))?;
+    Ok((stored.snapshot, state))
+}
+
+/// 스냅샷 복원 미리보기 (현재 작업을 바꾸지 않고 바뀔 블록만 계산)
+#[tauri::command]
+pub fn preview_restore(
+    project_id: String,
+    snapshot_id: String,
+    db_state: State<DbState>,
+) -> CommandResult<RestorePreview> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let (snapshot, state) = load_restorable(&db, &project_id, &snapshot_id)?;
+    db.preview_history_restore(&project_id, &snapshot, &state)
+        .map_err(CommandError::from)
 }
 
 /// 스냅샷 복원
+/// - 덮어쓰기 전에 현재 상태를 자동 스냅샷으로 남기고, 복원된 프로젝트와 함께 반환합니다.
 #[tauri::command]
 pub fn restore_snapshot(
     project_id: String,
     snapshot_id: String,
-    _db_state: State<DbState>,
-) -> CommandResult<()> {
-    // TODO: 스냅샷 복원 구현
-    let _ = (project_id, snapshot_id); // 사용 예정
+    db_state: State<DbState>,
+) -> CommandResult<RestoreOutcome> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
-    Ok(())
+    let (snapshot, state) = load_restorable(&db, &project_id, &snapshot_id)?;
+    db.restore_history_snapshot(&project_id, &snapshot, &state)
+        .map_err(CommandError::from)
 }
 
-/// 히스토리 목록 조회
+/// 히스토리 목록 조회 (최신 순)
 #[tauri::command]
 pub fn list_history(
     project_id: String,
-    _db_state: State<DbState>,
+    db_state: State<DbState>,
 ) -> CommandResult<Vec<HistorySnapshot>> {
-    // TODO: 데이터베이스에서 히스토리 로드
-    let _ = project_id; // 사용 예정
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_history_snapshots(&project_id).map_err(CommandError::from)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSnapshotArgs {
+    pub snapshot_id: String,
+    pub path: String,
+}
 
-    Ok(Vec::new())
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSnapshotArgs {
+    /// 스냅샷을 추가할 프로젝트 (내보낸 프로젝트와 달라도 됨)
+    pub project_id: String,
+    pub path: String,
 }
 
+/// 스냅샷 하나를 JSON 파일로 내보내기 (.ite 전체와 별개로 보관/전달)
+#[tauri::command]
+pub fn export_snapshot(args: ExportSnapshotArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let out_path = validate_path(&args.path)?;
+
+    let stored = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.load_history_snapshot(&args.snapshot_id)
+            .map_err(CommandError::from)?
+            .ok_or_else(|| CommandError::new(ErrorCode::NotFound, format!("Snapshot not found: {}", args.snapshot_id)))?
+    };
+    let state = stored.state.ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidOperation,
+        "Snapshot has no stored project state and cannot be exported",
+    ))?;
+
+    let doc = build_snapshot_document(
+        &stored.project_id,
+        stored.snapshot,
+        state,
+        chrono::Utc::now().timestamp_millis(),
+    );
+    let json = serde_json::to_string_pretty(&doc).map_err(|e| CommandError::new(
+        ErrorCode::InvalidOperation,
+        format!("Failed to serialize snapshot: {}", e),
+    ))?;
+    std::fs::write(&out_path, json).map_err(|e| CommandError::new(
+        ErrorCode::WriteError,
+        format!("Failed to write snapshot: {}", e),
+    ))?;
+    Ok(())
+}
+
+/// 스냅샷 JSON을 프로젝트 히스토리에 추가 (현재 작업은 건드리지 않음)
+/// - 같은 파일을 여러 번 가져와도 충돌하지 않도록 스냅샷 ID를 새로 발급합니다.
+/// - 다른 프로젝트에서 내보낸 스냅샷은 블록/세그먼트 ID도 새로 발급합니다.
+#[tauri::command]
+pub fn import_snapshot(args: ImportSnapshotArgs, db_state: State<DbState>) -> CommandResult<HistorySnapshot> {
+    let in_path = validate_path(&args.path)?;
+    let json = std::fs::read_to_string(&in_path).map_err(|e| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Failed to read snapshot: {}", e),
+    ))?;
+    let doc = parse_snapshot_document(&json).map_err(CommandError::from)?;
+
+    let mut snapshot = doc.snapshot;
+    if doc.project_id != args.project_id && !doc.project_title.trim().is_empty() {
+        snapshot.description = format!("{} (from {})", snapshot.description, doc.project_title.trim());
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    db.import_history_snapshot(&args.project_id, &doc.project_id, snapshot, doc.state)
+        .map_err(CommandError::from)
+}
+
+/// 변경 피드 조회 (cursor 이후 변경, 오래된 순)
+/// - `hasMore`가 true면 `nextCursor`로 이어서 조회합니다.
+#[tauri::command]
+pub fn get_changes_since(
+    args: GetChangesSinceArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ChangePage> {
+    if args.cursor < 0 {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "cursor must not be negative"));
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.get_changes_since(
+        args.cursor,
+        args.limit.unwrap_or(DEFAULT_CHANGES_LIMIT),
+        args.project_id.as_deref(),
+    )
+    .map_err(CommandError::from)
+}
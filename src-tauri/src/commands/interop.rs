@@ -0,0 +1,309 @@
+//! CAT Tool Interop Commands
+//!
+//! 다른 CAT 도구 프로젝트 가져오기 (OmegaT, Trados SDLXLIFF) 및 SDLXLIFF 납품 파일 내보내기
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::db::{DbState, NewGlossaryTerm, NewTmUnit, XliffSegmentStatus};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::interop::tmx::TmxUnit;
+use crate::interop::{omegat, sdlxliff};
+use crate::text::unicode::file_name_nfc;
+use crate::utils::validate_path;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOmegaTProjectArgs {
+    /// OmegaT 프로젝트 폴더 (omegat.project가 있는 폴더)
+    pub path: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOmegaTProjectResult {
+    pub project_id: String,
+    pub title: String,
+    pub source_lang: String,
+    pub target_lang: String,
+    pub source_files: Vec<String>,
+    /// 지원하지 않는 형식이라 건너뛴 원문 파일 (평문 .txt/.md만 지원)
+    pub skipped_files: Vec<String>,
+    pub segment_count: usize,
+    /// project_save.tmx로 번역문이 채워진 세그먼트 수
+    pub translated_segments: usize,
+    /// 새로 추가된 TM 단위 수
+    pub tm_units: u32,
+    pub glossary_entries: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSdlXliffArgs {
+    /// .sdlxliff 파일 또는 .sdlppx/.sdlrpx 패키지
+    pub path: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSdlXliffResult {
+    pub project_id: String,
+    pub title: String,
+    pub files: Vec<String>,
+    pub segment_count: usize,
+    pub translated_segments: usize,
+    pub locked_segments: usize,
+    /// Trados 상태별 세그먼트 수 ("Untranslated"는 상태 없음)
+    pub status_counts: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSdlXliffArgs {
+    pub project_id: String,
+    /// 파일을 쓸 폴더 (패키지 안 상대 경로 구조를 유지)
+    pub output_dir: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSdlXliffResult {
+    pub files: Vec<String>,
+    pub updated_segments: usize,
+    /// 인라인 태그 토큰이 빠져 태그 없이 내보낸 세그먼트 번호
+    pub missing_tag_segments: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectIdArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetXliffSegmentStatusArgs {
+    pub project_id: String,
+    pub segment_ids: Vec<String>,
+    /// Trados conf 값 (Draft, Translated, ApprovedTranslation, ...), 없으면 상태 제거
+    pub status: Option<String>,
+}
+
+/// OmegaT 프로젝트 폴더를 새 ITE 프로젝트로 가져오기 (TM/용어집은 프로젝트 범위로 저장)
+#[tauri::command]
+pub fn import_omegat_project(
+    args: ImportOmegaTProjectArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ImportOmegaTProjectResult> {
+    let root = validate_path(&args.path)?;
+    if !root.is_dir() {
+        return Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            "OmegaT project path must be a folder",
+        ).with_details(args.path));
+    }
+
+    // 파일 읽기는 DB 잠금 밖에서
+    let project = omegat::read_project(&root).map_err(CommandError::from)?;
+    if project.files.iter().all(|f| f.paragraphs.is_empty()) {
+        return Err(CommandError::new(
+            ErrorCode::InvalidOperation,
+            "No supported source files found in the OmegaT project",
+        ).with_details(format!("Skipped: {}", project.skipped_files.join(", "))));
+    }
+    let (ite_project, translated_segments) = omegat::build_project(&project, chrono::Utc::now().timestamp_millis());
+
+    let config = &project.config;
+    let to_tm = |units: &[TmxUnit]| -> Vec<NewTmUnit> {
+        units
+            .iter()
+            .map(|u| NewTmUnit {
+                source_lang: config.source_lang.clone(),
+                target_lang: config.target_lang.clone(),
+                source: u.source.clone(),
+                target: u.target.clone(),
+            })
+            .collect()
+    };
+    let glossary: Vec<NewGlossaryTerm> = project
+        .glossary
+        .iter()
+        .map(|g| NewGlossaryTerm {
+            source: g.source.clone(),
+            target: g.target.clone(),
+            notes: g.notes.clone(),
+            domain: None,
+            case_sensitive: false,
+            origin_key: None,
+        })
+        .collect();
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    db.save_project(&ite_project).map_err(CommandError::from)?;
+    let project_id = ite_project.id.as_str();
+    let mut tm_units = db
+        .upsert_tm_units(Some(project_id), &to_tm(&project.working_tm), Some("omegat"))
+        .map_err(CommandError::from)?;
+    tm_units += db
+        .upsert_tm_units(Some(project_id), &to_tm(&project.reference_tm), Some("omegat-tm"))
+        .map_err(CommandError::from)?;
+    let glossary_entries = db
+        .upsert_project_glossary_entries(project_id, &glossary)
+        .map_err(CommandError::from)?;
+
+    Ok(ImportOmegaTProjectResult {
+        project_id: ite_project.id.clone(),
+        title: ite_project.metadata.title.clone(),
+        source_lang: config.source_lang.clone(),
+        target_lang: config.target_lang.clone(),
+        source_files: project.files.iter().map(|f| f.path.clone()).collect(),
+        skipped_files: project.skipped_files.clone(),
+        segment_count: ite_project.segments.len(),
+        translated_segments,
+        tm_units,
+        glossary_entries,
+    })
+}
+
+/// SDLXLIFF 파일/Trados 패키지를 새 ITE 프로젝트로 가져오기 (세그먼트 상태/잠금 보존)
+#[tauri::command]
+pub fn import_sdlxliff(args: ImportSdlXliffArgs, db_state: State<DbState>) -> CommandResult<ImportSdlXliffResult> {
+    let path = validate_path(&args.path)?;
+    let documents = sdlxliff::read_documents(&path).map_err(CommandError::from)?;
+
+    let mut files = Vec::with_capacity(documents.len());
+    for doc in documents {
+        let parsed = sdlxliff::parse_sdlxliff(&doc.content)
+            .map_err(|e| CommandError::from(e).with_details(doc.file_name.clone()))?;
+        files.push((doc, parsed));
+    }
+    let title = file_name_nfc(&path)
+        .map(|n| n.split('.').next().unwrap_or(&n).to_string())
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "Trados project".to_string());
+    let (project, refs) = sdlxliff::build_project(title, &files, chrono::Utc::now().timestamp_millis());
+
+    let mut status_counts = BTreeMap::new();
+    let mut translated_segments = 0;
+    for (_, file) in &files {
+        for segment in &file.segments {
+            if !segment.target.trim().is_empty() {
+                translated_segments += 1;
+            }
+            let status = segment.status.clone().unwrap_or_else(|| "Untranslated".to_string());
+            *status_counts.entry(status).or_insert(0) += 1;
+        }
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    db.save_project(&project).map_err(CommandError::from)?;
+    let documents: Vec<_> = files.into_iter().map(|(doc, _)| doc).collect();
+    db.save_xliff_import(&project.id, &documents, &refs)
+        .map_err(CommandError::from)?;
+
+    Ok(ImportSdlXliffResult {
+        project_id: project.id.clone(),
+        title: project.metadata.title.clone(),
+        files: documents.into_iter().map(|d| d.file_name).collect(),
+        segment_count: refs.len(),
+        translated_segments,
+        locked_segments: refs.iter().filter(|r| r.locked).count(),
+        status_counts,
+    })
+}
+
+/// 번역문을 원본 SDLXLIFF에 반영해 폴더로 내보내기
+#[tauri::command]
+pub fn export_sdlxliff(args: ExportSdlXliffArgs, db_state: State<DbState>) -> CommandResult<ExportSdlXliffResult> {
+    let output_dir = validate_path(&args.output_dir)?;
+    if !output_dir.is_dir() {
+        return Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            "Output path must be an existing folder",
+        ).with_details(args.output_dir));
+    }
+
+    let (project, documents, refs, notes) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        (
+            db.load_project(&args.project_id).map_err(CommandError::from)?,
+            db.list_xliff_documents(&args.project_id).map_err(CommandError::from)?,
+            db.list_xliff_segment_refs(&args.project_id).map_err(CommandError::from)?,
+            db.segment_note_map(&args.project_id).map_err(CommandError::from)?,
+        )
+    };
+    if documents.is_empty() {
+        return Err(CommandError::new(ErrorCode::InvalidOperation, "Project was not imported from SDLXLIFF"));
+    }
+
+    let exported = sdlxliff::export_documents(&project, &documents, &refs, &notes).map_err(CommandError::from)?;
+    let mut written = Vec::with_capacity(exported.files.len());
+    for (file_name, xml) in &exported.files {
+        let Some(relative) = sdlxliff::safe_relative_name(file_name) else {
+            continue;
+        };
+        let out_path = output_dir.join(relative);
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| CommandError::new(
+                ErrorCode::WriteError,
+                format!("Failed to create folder: {}", e),
+            ))?;
+        }
+        std::fs::write(&out_path, xml).map_err(|e| CommandError::new(
+            ErrorCode::WriteError,
+            format!("Failed to write SDLXLIFF: {}", e),
+        ).with_details(file_name.clone()))?;
+        written.push(out_path.to_string_lossy().to_string());
+    }
+
+    Ok(ExportSdlXliffResult {
+        files: written,
+        updated_segments: exported.updated_segments,
+        missing_tag_segments: exported.missing_tag_segments,
+    })
+}
+
+/// SDLXLIFF 세그먼트 상태 목록
+#[tauri::command]
+pub fn list_xliff_segment_statuses(
+    args: ProjectIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<XliffSegmentStatus>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    db.list_xliff_segment_statuses(&args.project_id)
+        .map_err(CommandError::from)
+}
+
+/// SDLXLIFF 세그먼트 상태 변경 (잠긴 세그먼트 제외), 반환: 변경된 세그먼트 수
+#[tauri::command]
+pub fn set_xliff_segment_status(args: SetXliffSegmentStatusArgs, db_state: State<DbState>) -> CommandResult<usize> {
+    if let Some(status) = args.status.as_deref() {
+        if !sdlxliff::is_valid_status(status) {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Unknown segment status: {}", status),
+            ).with_details(sdlxliff::SDL_STATUSES.join(", ")));
+        }
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    db.set_xliff_segment_status(&args.project_id, &args.segment_ids, args.status.as_deref())
+        .map_err(CommandError::from)
+}
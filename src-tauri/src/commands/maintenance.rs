@@ -0,0 +1,190 @@
+//! Database Maintenance Commands
+//!
+//! DB 최적화(FTS 인덱스 병합 → ANALYZE → VACUUM)를 백그라운드 작업으로 실행합니다.
+//! - 대량 삭제 뒤에도 SQLite 파일은 줄어들지 않으므로, 빈 페이지가 기준을 넘으면 자동으로도 실행합니다.
+//! - 진행 상황과 결과는 `database-optimize-progress` 이벤트로 보냅니다.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::db::{Database, DbState};
+use crate::error::{CommandError, CommandResult, ErrorCode, IteError};
+
+/// DB 최적화 진행/완료 이벤트
+pub const DATABASE_OPTIMIZE_PROGRESS_EVENT: &str = "database-optimize-progress";
+
+/// 자동 최적화 기준: 빈 페이지 용량
+const AUTO_OPTIMIZE_FREE_BYTES: i64 = 32 * 1024 * 1024;
+
+/// 자동 최적화 기준: DB 크기 대비 빈 페이지 비율 (%)
+const AUTO_OPTIMIZE_FREE_PERCENT: i64 = 20;
+
+/// 최적화 단계 (실행 순서, VACUUM은 앞 단계에서 생긴 빈 페이지까지 회수하도록 마지막)
+const STAGES: [&str; 3] = ["fts", "analyze", "vacuum"];
+
+/// 실행 중인 최적화 작업 ID
+#[derive(Default)]
+pub struct DatabaseOptimizeJob(Mutex<Option<String>>);
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizeJob {
+    pub job_id: String,
+    /// 이미 실행 중이어서 기존 작업을 돌려준 경우
+    pub already_running: bool,
+}
+
+/// 최적화 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizeResult {
+    pub before_bytes: i64,
+    pub after_bytes: i64,
+    pub reclaimed_bytes: i64,
+    pub elapsed_ms: u128,
+}
+
+/// `database-optimize-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseOptimizeProgressEvent {
+    pub job_id: String,
+    /// "fts" | "analyze" | "vacuum" | "completed" | "failed"
+    pub stage: String,
+    /// 끝난 단계 수
+    pub completed_steps: usize,
+    pub total_steps: usize,
+    /// 빈 페이지 기준으로 자동 실행된 작업
+    pub automatic: bool,
+    pub result: Option<OptimizeResult>,
+    pub error: Option<String>,
+}
+
+fn run_stage(db: &Database, stage: &str) -> Result<(), IteError> {
+    match stage {
+        "fts" => db.optimize_fts(),
+        "analyze" => db.analyze(),
+        _ => db.vacuum().map(|_| ()),
+    }
+}
+
+/// 최적화 작업 시작 (이미 실행 중이면 그 작업 ID)
+fn start_optimize(app: &AppHandle, automatic: bool) -> CommandResult<OptimizeJob> {
+    let jobs = app.state::<DatabaseOptimizeJob>();
+    let mut running = jobs.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire optimize job lock: {}", e),
+    ))?;
+    if let Some(job_id) = running.as_ref() {
+        return Ok(OptimizeJob {
+            job_id: job_id.clone(),
+            already_running: true,
+        });
+    }
+    let job_id = uuid::Uuid::new_v4().to_string();
+    *running = Some(job_id.clone());
+    drop(running);
+
+    let app = app.clone();
+    let job = OptimizeJob {
+        job_id: job_id.clone(),
+        already_running: false,
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        let started = std::time::Instant::now();
+        let event = |stage: &str, completed_steps: usize| DatabaseOptimizeProgressEvent {
+            job_id: job_id.clone(),
+            stage: stage.to_string(),
+            completed_steps,
+            total_steps: STAGES.len(),
+            automatic,
+            result: None,
+            error: None,
+        };
+
+        // 단계마다 잠금을 다시 잡아 사이사이 다른 명령이 실행될 수 있게 함
+        let run = || -> Result<OptimizeResult, IteError> {
+            let db_state = app.state::<DbState>();
+            let lock = || {
+                db_state
+                    .0
+                    .lock()
+                    .map_err(|e| IteError::InvalidOperation(format!("Failed to acquire database lock: {}", e)))
+            };
+            let before_bytes = lock()?.database_size_bytes()?;
+            for (i, stage) in STAGES.iter().enumerate() {
+                let _ = app.emit(DATABASE_OPTIMIZE_PROGRESS_EVENT, event(stage, i));
+                run_stage(&*lock()?, stage)?;
+            }
+            let after_bytes = lock()?.database_size_bytes()?;
+            Ok(OptimizeResult {
+                before_bytes,
+                after_bytes,
+                reclaimed_bytes: (before_bytes - after_bytes).max(0),
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+        };
+
+        let done = match run() {
+            Ok(result) => {
+                println!(
+                    "[Maintenance] Optimized database in {} ms, reclaimed {} bytes",
+                    result.elapsed_ms, result.reclaimed_bytes
+                );
+                DatabaseOptimizeProgressEvent {
+                    result: Some(result),
+                    ..event("completed", STAGES.len())
+                }
+            }
+            Err(e) => {
+                eprintln!("[Maintenance] Database optimize failed: {}", e);
+                DatabaseOptimizeProgressEvent {
+                    error: Some(e.to_string()),
+                    ..event("failed", 0)
+                }
+            }
+        };
+
+        if let Ok(mut running) = app.state::<DatabaseOptimizeJob>().0.lock() {
+            *running = None;
+        }
+        let _ = app.emit(DATABASE_OPTIMIZE_PROGRESS_EVENT, done);
+    });
+
+    Ok(job)
+}
+
+/// DB 최적화 시작 (FTS 인덱스 병합, ANALYZE, VACUUM)
+/// - 백그라운드에서 실행하고 `database-optimize-progress` 이벤트로 단계별 진행과 결과를 보냅니다.
+/// - 이미 실행 중이면 그 작업 ID를 돌려줍니다.
+#[tauri::command]
+pub fn optimize_database(app: AppHandle) -> CommandResult<OptimizeJob> {
+    start_optimize(&app, false)
+}
+
+/// 빈 페이지가 기준(용량과 비율 모두)을 넘으면 최적화를 자동으로 시작 (앱 시작, 대량 삭제 뒤 호출)
+/// - 판단/시작 실패는 호출한 작업에 영향을 주지 않으므로 로그만 남깁니다.
+pub(crate) fn maybe_optimize_database(app: &AppHandle) {
+    let needed = {
+        let db_state = app.state::<DbState>();
+        let Ok(db) = db_state.0.lock() else {
+            return;
+        };
+        match (db.free_page_bytes(), db.database_size_bytes()) {
+            (Ok(free), Ok(total)) => {
+                free >= AUTO_OPTIMIZE_FREE_BYTES && free * 100 >= total * AUTO_OPTIMIZE_FREE_PERCENT
+            }
+            _ => false,
+        }
+    };
+    if !needed {
+        return;
+    }
+    match start_optimize(app, true) {
+        Ok(job) if !job.already_running => println!("[Maintenance] Started automatic database optimize"),
+        Ok(_) => {}
+        Err(e) => eprintln!("[Maintenance] Failed to start database optimize: {}", e.message),
+    }
+}
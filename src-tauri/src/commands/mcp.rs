@@ -1,56 +1,127 @@
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, State, Window};
 use uuid::Uuid;
 use std::collections::HashMap;
 use crate::db::{DbState, McpServerRow};
-use crate::mcp::{McpConnectionStatus, McpTool, McpToolResult, MCP_CLIENT, McpRegistry, McpServerId, McpRegistryStatus};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::mcp::{
+    McpConnectionStatus, McpParallelCallReport, McpRegistry, McpRegistryStatus, McpServerId, McpTool, McpToolCallRequest,
+    McpToolResult, MCP_CLIENT,
+};
+
+/// MCP 클라이언트/레지스트리의 문자열 오류를 `MCP_ERROR`로 감쌈
+fn mcp_error(message: String) -> CommandError {
+    CommandError::new(ErrorCode::McpError, message)
+}
 
 #[tauri::command]
 pub async fn save_mcp_server(
-    _app: AppHandle,
+    app: AppHandle,
     state: State<'_, DbState>,
     name: String,
     server_type: String,
     config_json: String,
     is_enabled: bool,
     id: Option<String>,
-) -> Result<String, String> {
-    let db = state.0.lock().map_err(|e| e.to_string())?;
+) -> CommandResult<String> {
+    crate::crash::guard(&app, "save_mcp_server", async {
+        let db = state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
     
-    let server_id = id.unwrap_or_else(|| Uuid::new_v4().to_string());
-    let now = chrono::Utc::now().timestamp_millis();
-
-    let server = McpServerRow {
-        id: server_id.clone(),
-        name,
-        server_type,
-        config_json,
-        is_enabled,
-        created_at: now, // 신규 생성 시에는 now, 업데이트 시에는 기존 값을 유지해야 하지만 DB 레이어에서 ON CONFLICT 시 created_at은 건드리지 않으므로 괜찮음 (단, created_at 필드는 필수이므로 넣어줌)
-        updated_at: now,
-    };
-
-    db.save_mcp_server(&server).map_err(|e| e.to_string())?;
+        let server_id = id.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let now = chrono::Utc::now().timestamp_millis();
+
+        let server = McpServerRow {
+            id: server_id.clone(),
+            name,
+            server_type,
+            config_json,
+            is_enabled,
+            created_at: now, // 신규 생성 시에는 now, 업데이트 시에는 기존 값을 유지해야 하지만 DB 레이어에서 ON CONFLICT 시 created_at은 건드리지 않으므로 괜찮음 (단, created_at 필드는 필수이므로 넣어줌)
+            updated_at: now,
+        };
+
+        db.save_mcp_server(&server)?;
     
-    Ok(server_id)
+        Ok(server_id)
+    })
+    .await
 }
 
+/// MCP 서버 목록 (지원하는 서버마다 기본 행이 없으면 먼저 만들어 둠)
 #[tauri::command]
 pub async fn list_mcp_servers(
+    app: AppHandle,
     state: State<'_, DbState>,
-) -> Result<Vec<McpServerRow>, String> {
-    let db = state.0.lock().map_err(|e| e.to_string())?;
-    let servers = db.list_mcp_servers().map_err(|e| e.to_string())?;
-    Ok(servers)
+) -> CommandResult<Vec<McpServerRow>> {
+    crate::crash::guard(&app, "list_mcp_servers", async {
+        let db = state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let builtin: Vec<(&str, &str)> = McpRegistry::supported_servers()
+            .iter()
+            .map(|server_id| (server_id.as_str(), server_id.display_name()))
+            .collect();
+        db.ensure_builtin_mcp_servers(&builtin)?;
+        let servers = db.list_mcp_servers()?;
+        Ok(servers)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn delete_mcp_server(
+    app: AppHandle,
     state: State<'_, DbState>,
     id: String,
-) -> Result<(), String> {
-    let db = state.0.lock().map_err(|e| e.to_string())?;
-    db.delete_mcp_server(&id).map_err(|e| e.to_string())?;
-    Ok(())
+) -> CommandResult<()> {
+    crate::crash::guard(&app, "delete_mcp_server", async {
+        let db = state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.delete_mcp_server(&id)?;
+        Ok(())
+    })
+    .await
+}
+
+/// 프로젝트에 연결된 MCP 서버 목록 (비어 있으면 모든 서버 사용)
+#[tauri::command]
+pub async fn get_project_mcp_servers(
+    app: AppHandle,
+    state: State<'_, DbState>,
+    project_id: String,
+) -> CommandResult<Vec<McpServerRow>> {
+    crate::crash::guard(&app, "get_project_mcp_servers", async {
+        let db = state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        Ok(db.get_project_mcp_servers(&project_id)?)
+    })
+    .await
+}
+
+/// 프로젝트의 MCP 서버 연결 교체 (빈 목록이면 모든 서버 사용)
+/// 활성 프로젝트라면 이후 `mcp_registry_set_active_project`를 다시 호출해 범위를 갱신합니다.
+#[tauri::command]
+pub async fn set_project_mcp_servers(
+    app: AppHandle,
+    state: State<'_, DbState>,
+    project_id: String,
+    server_ids: Vec<String>,
+) -> CommandResult<Vec<McpServerRow>> {
+    crate::crash::guard(&app, "set_project_mcp_servers", async {
+        let db = state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        Ok(db.set_project_mcp_servers(&project_id, &server_ids)?)
+    })
+    .await
 }
 
 // ============================================================================
@@ -60,115 +131,243 @@ pub async fn delete_mcp_server(
 /// Atlassian MCP 서버에 연결
 /// OAuth 2.1 인증이 필요한 경우 브라우저에서 인증 플로우를 시작합니다.
 #[tauri::command]
-pub async fn mcp_connect() -> Result<(), String> {
-    MCP_CLIENT.connect().await
+pub async fn mcp_connect(app: AppHandle) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_connect", async {
+        crate::network::ensure_online("MCP")?;
+        MCP_CLIENT.connect().await.map_err(mcp_error)
+    })
+    .await
 }
 
 /// MCP 서버 연결 해제
 #[tauri::command]
-pub async fn mcp_disconnect() -> Result<(), String> {
-    MCP_CLIENT.disconnect().await;
-    Ok(())
+pub async fn mcp_disconnect(app: AppHandle) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_disconnect", async {
+        MCP_CLIENT.disconnect().await;
+        Ok(())
+    })
+    .await
 }
 
 /// 현재 MCP 연결 상태 가져오기
 #[tauri::command]
-pub async fn mcp_get_status() -> Result<McpConnectionStatus, String> {
-    Ok(MCP_CLIENT.get_status().await)
+pub async fn mcp_get_status(app: AppHandle) -> CommandResult<McpConnectionStatus> {
+    crate::crash::guard(&app, "mcp_get_status", async {
+        Ok(MCP_CLIENT.get_status().await)
+    })
+    .await
 }
 
 /// MCP 도구 목록 가져오기
 #[tauri::command]
-pub async fn mcp_get_tools() -> Result<Vec<McpTool>, String> {
-    Ok(MCP_CLIENT.get_tools().await)
+pub async fn mcp_get_tools(app: AppHandle) -> CommandResult<Vec<McpTool>> {
+    crate::crash::guard(&app, "mcp_get_tools", async {
+        Ok(MCP_CLIENT.get_tools().await)
+    })
+    .await
 }
 
 /// MCP 도구 호출
 #[tauri::command]
 pub async fn mcp_call_tool(
+    app: AppHandle,
     name: String,
     arguments: Option<HashMap<String, serde_json::Value>>,
-) -> Result<McpToolResult, String> {
-    MCP_CLIENT.call_tool(&name, arguments).await
+) -> CommandResult<McpToolResult> {
+    crate::crash::guard(&app, "mcp_call_tool", async {
+        crate::network::ensure_online("MCP")?;
+        MCP_CLIENT.call_tool(&name, arguments).await.map_err(mcp_error)
+    })
+    .await
 }
 
 /// 저장된 인증 정보 확인 (앱 시작 시 호출)
 /// 키체인에서 토큰을 로드하고 유효성을 확인합니다.
 #[tauri::command]
-pub async fn mcp_check_auth() -> Result<McpConnectionStatus, String> {
-    // get_status()가 내부적으로 OAuth 초기화 및 토큰 로드를 수행
-    Ok(MCP_CLIENT.get_status().await)
+pub async fn mcp_check_auth(app: AppHandle) -> CommandResult<McpConnectionStatus> {
+    crate::crash::guard(&app, "mcp_check_auth", async {
+        // get_status()가 내부적으로 OAuth 초기화 및 토큰 로드를 수행
+        Ok(MCP_CLIENT.get_status().await)
+    })
+    .await
 }
 
 /// MCP 로그아웃 (토큰 삭제)
 /// 키체인에서 저장된 토큰을 삭제합니다.
 #[tauri::command]
-pub async fn mcp_logout() -> Result<(), String> {
-    MCP_CLIENT.logout().await;
-    Ok(())
+pub async fn mcp_logout(app: AppHandle) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_logout", async {
+        MCP_CLIENT.logout().await;
+        Ok(())
+    })
+    .await
 }
 
 // ============================================================================
 // MCP 레지스트리 커맨드 (여러 MCP 서버 통합 관리)
 // ============================================================================
 
-/// 전체 MCP 레지스트리 상태 조회
+/// 전체 MCP 레지스트리 상태 조회 (`in_scope`는 호출한 창의 프로젝트 기준)
+#[tauri::command]
+pub async fn mcp_registry_status(app: AppHandle, window: Window) -> CommandResult<McpRegistryStatus> {
+    crate::crash::guard(&app, "mcp_registry_status", async {
+        Ok(McpRegistry::get_registry_status(window.label()).await)
+    })
+    .await
+}
+
+/// 호출한 창의 활성 프로젝트 지정 (None이면 프로젝트를 닫은 상태)
+/// - 프로젝트에 연결된 서버 중 사용 중(`is_enabled`)인 서버만 그 창에 연결/노출하고,
+///   어느 창의 범위에도 없는 서버는 연결을 해제합니다.
+/// - 연결된 서버가 없는 프로젝트는 모든 서버를 사용합니다.
 #[tauri::command]
-pub async fn mcp_registry_status() -> Result<McpRegistryStatus, String> {
-    Ok(McpRegistry::get_registry_status().await)
+pub async fn mcp_registry_set_active_project(
+    app: AppHandle,
+    window: Window,
+    state: State<'_, DbState>,
+    project_id: Option<String>,
+) -> CommandResult<McpRegistryStatus> {
+    crate::crash::guard(&app, "mcp_registry_set_active_project", async {
+        let scope = match project_id {
+            Some(project_id) => {
+                let db = state.0.lock().map_err(|e| CommandError::new(
+                    ErrorCode::LockError,
+                    format!("Failed to acquire database lock: {}", e),
+                ))?;
+                let bound = db.get_project_mcp_servers(&project_id)?;
+                if bound.is_empty() {
+                    None
+                } else {
+                    let mut servers: Vec<McpServerId> = Vec::new();
+                    for server_id in bound
+                        .iter()
+                        .filter(|row| row.is_enabled)
+                        .filter_map(|row| McpServerId::parse(&row.server_type))
+                    {
+                        if !servers.contains(&server_id) {
+                            servers.push(server_id);
+                        }
+                    }
+                    Some(servers)
+                }
+            }
+            None => None,
+        };
+        McpRegistry::set_scope(window.label(), scope).await;
+        Ok(McpRegistry::get_registry_status(window.label()).await)
+    })
+    .await
 }
 
 /// 특정 MCP 서버에 연결
 #[tauri::command]
-pub async fn mcp_registry_connect(server_id: McpServerId) -> Result<(), String> {
-    McpRegistry::connect(server_id).await
+pub async fn mcp_registry_connect(app: AppHandle, window: Window, server_id: McpServerId) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_registry_connect", async {
+        crate::network::ensure_online("MCP")?;
+        McpRegistry::connect(window.label(), server_id).await.map_err(mcp_error)
+    })
+    .await
 }
 
 /// 특정 MCP 서버 연결 해제
 #[tauri::command]
-pub async fn mcp_registry_disconnect(server_id: McpServerId) -> Result<(), String> {
-    McpRegistry::disconnect(server_id).await;
-    Ok(())
+pub async fn mcp_registry_disconnect(app: AppHandle, server_id: McpServerId) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_registry_disconnect", async {
+        McpRegistry::disconnect(server_id).await;
+        Ok(())
+    })
+    .await
 }
 
 /// 특정 MCP 서버 로그아웃
 #[tauri::command]
-pub async fn mcp_registry_logout(server_id: McpServerId) -> Result<(), String> {
-    McpRegistry::logout(server_id).await;
-    Ok(())
+pub async fn mcp_registry_logout(app: AppHandle, server_id: McpServerId) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_registry_logout", async {
+        McpRegistry::logout(server_id).await;
+        Ok(())
+    })
+    .await
 }
 
 /// 특정 MCP 서버 완전 초기화 (토큰 + 클라이언트 정보 모두 삭제)
 /// Client ID mismatch 등 복구 불가능한 상태일 때 사용
 #[tauri::command]
-pub async fn mcp_registry_clear_all(server_id: McpServerId) -> Result<(), String> {
-    McpRegistry::clear_all(server_id).await;
-    Ok(())
+pub async fn mcp_registry_clear_all(app: AppHandle, server_id: McpServerId) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_registry_clear_all", async {
+        McpRegistry::clear_all(server_id).await;
+        Ok(())
+    })
+    .await
 }
 
 /// 특정 MCP 서버의 도구 목록 조회
 #[tauri::command]
-pub async fn mcp_registry_get_tools(server_id: McpServerId) -> Result<Vec<McpTool>, String> {
-    Ok(McpRegistry::get_tools(server_id).await)
+pub async fn mcp_registry_get_tools(app: AppHandle, server_id: McpServerId) -> CommandResult<Vec<McpTool>> {
+    crate::crash::guard(&app, "mcp_registry_get_tools", async {
+        Ok(McpRegistry::get_tools(server_id).await)
+    })
+    .await
 }
 
 /// MCP 도구 호출 (레지스트리 경유)
 #[tauri::command]
 pub async fn mcp_registry_call_tool(
+    app: AppHandle,
+    window: Window,
     server_id: McpServerId,
     name: String,
     arguments: Option<HashMap<String, serde_json::Value>>,
-) -> Result<McpToolResult, String> {
-    McpRegistry::call_tool(server_id, &name, arguments).await
+) -> CommandResult<McpToolResult> {
+    crate::crash::guard(&app, "mcp_registry_call_tool", async {
+        crate::network::ensure_online("MCP")?;
+        McpRegistry::call_tool(window.label(), server_id, &name, arguments)
+            .await
+            .map_err(mcp_error)
+    })
+    .await
+}
+
+/// 여러 MCP 도구 동시 호출 (서버 혼합 가능, 전체 제한 시간 공유, 부분 결과 반환)
+#[tauri::command]
+pub async fn mcp_registry_call_tools_parallel(
+    app: AppHandle,
+    window: Window,
+    requests: Vec<McpToolCallRequest>,
+    timeout_ms: Option<u64>,
+) -> CommandResult<McpParallelCallReport> {
+    crate::crash::guard(&app, "mcp_registry_call_tools_parallel", async {
+        crate::network::ensure_online("MCP")?;
+        McpRegistry::call_tools_parallel(window.label(), requests, timeout_ms)
+            .await
+            .map_err(|e| CommandError::new(ErrorCode::InvalidInput, e))
+    })
+    .await
+}
+
+/// MCP 도구 결과 캐시 비우기 (서버/도구를 지정하면 해당 항목만), 제거된 항목 수 반환
+#[tauri::command]
+pub async fn mcp_registry_clear_cache(
+    app: AppHandle,
+    server_id: Option<McpServerId>,
+    tool_name: Option<String>,
+) -> CommandResult<usize> {
+    crate::crash::guard(&app, "mcp_registry_clear_cache", async {
+        Ok(McpRegistry::clear_cache(server_id, tool_name.as_deref()))
+    })
+    .await
 }
 
 /// Notion MCP 설정 저장
 /// 로컬 MCP 서버의 URL과 Auth Token을 저장합니다.
 #[tauri::command]
 pub async fn mcp_set_notion_config(
+    app: AppHandle,
     mcp_url: Option<String>,
     auth_token: String,
-) -> Result<(), String> {
-    McpRegistry::set_notion_config(mcp_url, auth_token).await
+) -> CommandResult<()> {
+    crate::crash::guard(&app, "mcp_set_notion_config", async {
+        McpRegistry::set_notion_config(mcp_url, auth_token).await.map_err(mcp_error)
+    })
+    .await
 }
 
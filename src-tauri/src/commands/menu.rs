@@ -0,0 +1,231 @@
+//! Application Menu
+//!
+//! 네이티브 앱 메뉴 (File / Edit / Project / View / Window)
+//! - "Open Recent"는 최근 프로젝트 목록으로 채우며, 목록이 바뀌면 메뉴를 다시 만듭니다.
+//! - 메뉴 선택은 `menu-action` 이벤트로 메인 창에 보내 프론트엔드가 처리합니다.
+//! - 단축키는 에디터 키 바인딩과 겹치지 않도록 새로 지정하지 않습니다.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::menu::{Menu, MenuBuilder, MenuEvent, MenuItemBuilder, SubmenuBuilder};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::db::DbState;
+use crate::error::CommandResult;
+
+/// 메뉴 선택 이벤트
+pub const MENU_ACTION_EVENT: &str = "menu-action";
+
+/// "Open Recent"에 보여 줄 프로젝트 수
+const MENU_RECENT_PROJECTS: usize = 10;
+
+/// 최근 프로젝트 변경 확인 주기
+const RECENT_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+const MENU_RELOAD: &str = "reload";
+const MENU_ACTION_PREFIX: &str = "menu:";
+const MENU_RECENT_PREFIX: &str = "menu:recent:";
+
+/// 프론트엔드로 보내는 메뉴 동작 (메뉴 ID = `menu:<action>`)
+const MENU_ACTIONS: &[(&str, &str)] = &[
+    ("newProject", "New Project"),
+    ("openProject", "Open Project…"),
+    ("import", "Import…"),
+    ("export", "Export…"),
+    ("save", "Save"),
+    ("runQa", "Run QA Checks"),
+    ("openInNewWindow", "Open in New Window"),
+    ("projectSettings", "Project Settings…"),
+];
+
+/// 마지막으로 메뉴에 반영한 최근 프로젝트 (id, title)
+#[derive(Default)]
+pub struct RecentProjectsMenuState(Mutex<Vec<(String, String)>>);
+
+/// `menu-action` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuActionEvent {
+    pub action: String,
+    /// "openRecent"일 때만
+    pub project_id: Option<String>,
+}
+
+fn load_recent_projects(app: &AppHandle) -> Vec<(String, String)> {
+    let state = app.state::<DbState>();
+    let recent = match state.0.lock() {
+        Ok(db) => db.list_recent_projects(MENU_RECENT_PROJECTS, &[], false).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    recent.into_iter().map(|p| (p.id, p.title)).collect()
+}
+
+fn action_item(app: &AppHandle, action: &str) -> tauri::Result<tauri::menu::MenuItem<tauri::Wry>> {
+    let label = MENU_ACTIONS
+        .iter()
+        .find(|(a, _)| *a == action)
+        .map(|(_, label)| *label)
+        .unwrap_or(action);
+    MenuItemBuilder::with_id(format!("{}{}", MENU_ACTION_PREFIX, action), label).build(app)
+}
+
+/// 앱 메뉴 생성
+fn build_app_menu(app: &AppHandle, recent: &[(String, String)]) -> tauri::Result<Menu<tauri::Wry>> {
+    let app_menu = SubmenuBuilder::new(app, "OddEyes")
+        .about(None)
+        .separator()
+        .services()
+        .separator()
+        .hide()
+        .hide_others()
+        .show_all()
+        .separator()
+        .quit()
+        .build()?;
+
+    let mut recent_menu = SubmenuBuilder::new(app, "Open Recent");
+    if recent.is_empty() {
+        recent_menu = recent_menu.item(&MenuItemBuilder::new("No Recent Projects").enabled(false).build(app)?);
+    }
+    for (id, title) in recent {
+        recent_menu = recent_menu.item(&MenuItemBuilder::with_id(format!("{}{}", MENU_RECENT_PREFIX, id), title).build(app)?);
+    }
+
+    let file_menu = SubmenuBuilder::new(app, "File")
+        .item(&action_item(app, "newProject")?)
+        .item(&action_item(app, "openProject")?)
+        .item(&recent_menu.build()?)
+        .separator()
+        .item(&action_item(app, "import")?)
+        .item(&action_item(app, "export")?)
+        .separator()
+        .item(&action_item(app, "save")?)
+        .close_window()
+        .build()?;
+
+    let edit_menu = SubmenuBuilder::new(app, "Edit")
+        .undo()
+        .redo()
+        .separator()
+        .cut()
+        .copy()
+        .paste()
+        .select_all()
+        .build()?;
+
+    let project_menu = SubmenuBuilder::new(app, "Project")
+        .item(&action_item(app, "runQa")?)
+        .item(&action_item(app, "openInNewWindow")?)
+        .separator()
+        .item(&action_item(app, "projectSettings")?)
+        .build()?;
+
+    let reload_item = MenuItemBuilder::with_id(MENU_RELOAD, "Reload This Page")
+        .accelerator("CmdOrCtrl+R")
+        .build(app)?;
+
+    let view_menu = SubmenuBuilder::new(app, "View")
+        .item(&reload_item)
+        .build()?;
+
+    let window_menu = SubmenuBuilder::new(app, "Window")
+        .minimize()
+        .maximize()
+        .close_window()
+        .separator()
+        .fullscreen()
+        .build()?;
+
+    MenuBuilder::new(app)
+        .item(&app_menu)
+        .item(&file_menu)
+        .item(&edit_menu)
+        .item(&project_menu)
+        .item(&view_menu)
+        .item(&window_menu)
+        .build()
+}
+
+/// 앱 메뉴 설치 + 이벤트 핸들러 등록 + 최근 프로젝트 변경 감시 (앱 setup에서 한 번 호출)
+pub fn install_app_menu(app: &AppHandle) -> tauri::Result<()> {
+    app.manage(RecentProjectsMenuState::default());
+    let recent = load_recent_projects(app);
+    app.set_menu(build_app_menu(app, &recent)?)?;
+    if let Ok(mut current) = app.state::<RecentProjectsMenuState>().0.lock() {
+        *current = recent;
+    }
+    app.on_menu_event(handle_menu_event);
+
+    // 가져오기/MCP 등 어떤 경로로 프로젝트가 바뀌어도 반영되도록 주기적으로 비교
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(RECENT_CHECK_INTERVAL).await;
+            refresh_recent_projects(&handle);
+        }
+    });
+    Ok(())
+}
+
+/// 최근 프로젝트 목록이 바뀌었으면 앱 메뉴와 트레이 메뉴를 다시 만듦
+pub fn refresh_recent_projects(app: &AppHandle) {
+    let recent = load_recent_projects(app);
+    {
+        let state = app.state::<RecentProjectsMenuState>();
+        let Ok(mut current) = state.0.lock() else {
+            return;
+        };
+        if *current == recent {
+            return;
+        }
+        *current = recent.clone();
+    }
+
+    match build_app_menu(app, &recent) {
+        Ok(menu) => {
+            if let Err(e) = app.set_menu(menu) {
+                eprintln!("[Menu] Failed to set menu: {}", e);
+            }
+        }
+        Err(e) => eprintln!("[Menu] Failed to rebuild menu: {}", e),
+    }
+    super::tray::refresh_tray(app);
+}
+
+fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
+    let id = event.id().as_ref();
+    if id == MENU_RELOAD {
+        if let Some(window) = app.get_webview_window("main") {
+            // 현재 URL을 가져오고, 실패 시 reload 스킵 (패닉 방지)
+            if let Ok(url) = window.url() {
+                let _ = window.navigate(url);
+            }
+        }
+        return;
+    }
+
+    let payload = if let Some(project_id) = id.strip_prefix(MENU_RECENT_PREFIX) {
+        MenuActionEvent {
+            action: "openRecent".to_string(),
+            project_id: Some(project_id.to_string()),
+        }
+    } else if let Some(action) = id.strip_prefix(MENU_ACTION_PREFIX) {
+        MenuActionEvent {
+            action: action.to_string(),
+            project_id: None,
+        }
+    } else {
+        // 트레이 메뉴 등 다른 메뉴의 이벤트
+        return;
+    };
+    let _ = app.emit_to("main", MENU_ACTION_EVENT, payload);
+}
+
+/// 최근 프로젝트 메뉴 즉시 갱신 (프로젝트를 만들거나 이름을 바꾼 뒤)
+#[tauri::command]
+pub fn refresh_app_menu(app: AppHandle) -> CommandResult<()> {
+    refresh_recent_projects(&app);
+    Ok(())
+}
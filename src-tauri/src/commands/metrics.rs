@@ -0,0 +1,79 @@
+//! Usage Metrics Commands
+//!
+//! 로컬 사용 지표 기록/조회 API (설정 `usageMetrics`를 켠 경우에만 기록)
+//! - 프론트엔드 invoke 래퍼가 명령 응답 시간/오류 코드를 모아 주기적으로 보냅니다.
+//! - 보고서는 사용자가 직접 내보내 전달할 때만 밖으로 나갑니다.
+
+use serde::Deserialize;
+use tauri::State;
+
+use crate::db::{DbState, MetricSample, MetricsReport};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 한 번에 보낼 수 있는 지표 수
+const MAX_METRIC_SAMPLES: usize = 500;
+
+/// 보고서 기본 기간 (일)
+const DEFAULT_REPORT_DAYS: u32 = 7;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordMetricsArgs {
+    pub samples: Vec<MetricSample>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsReportArgs {
+    /// 최근 N일 (기본 7, 최대 30)
+    pub days: Option<u32>,
+}
+
+/// 지표 기록 (기록한 건수, 설정이 꺼져 있으면 저장하지 않고 0)
+#[tauri::command]
+pub fn record_metrics(args: RecordMetricsArgs, db_state: State<DbState>) -> CommandResult<usize> {
+    if args.samples.len() > MAX_METRIC_SAMPLES {
+        return Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            format!("Too many metric samples (max {})", MAX_METRIC_SAMPLES),
+        ));
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    if !db.load_app_settings().map_err(CommandError::from)?.usage_metrics {
+        return Ok(0);
+    }
+    db.record_metrics(&args.samples).map_err(CommandError::from)
+}
+
+/// 사용 지표 보고서 (명령별 평균/최대 응답 시간, 오류율, 기능 사용 횟수)
+#[tauri::command]
+pub fn get_metrics_report(
+    args: Option<MetricsReportArgs>,
+    db_state: State<DbState>,
+) -> CommandResult<MetricsReport> {
+    let days = args.and_then(|a| a.days).unwrap_or(DEFAULT_REPORT_DAYS);
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let enabled = db.load_app_settings().map_err(CommandError::from)?.usage_metrics;
+    db.metrics_report(days, enabled).map_err(CommandError::from)
+}
+
+/// 기록된 사용 지표 전체 삭제
+#[tauri::command]
+pub fn clear_metrics(db_state: State<DbState>) -> CommandResult<usize> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.clear_metrics().map_err(CommandError::from)
+}
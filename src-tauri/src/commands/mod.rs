@@ -3,15 +3,46 @@
 //! 프론트엔드에서 호출 가능한 Tauri 명령어 정의
 
 pub mod block;
+pub mod capture;
 pub mod chat;
+pub mod cloud_backup;
 pub mod confluence;
 pub mod connector;
+pub mod deep_link;
+pub mod diagnostics;
+pub mod dnt;
+pub mod drag_drop;
+pub mod encryption;
+pub mod env;
 pub mod glossary;
 pub mod history;
+pub mod interop;
+pub mod maintenance;
+pub mod menu;
+pub mod metrics;
+pub mod network;
 pub mod project;
+pub mod prompt_templates;
+pub mod pseudo;
+pub mod qa;
+pub mod reference;
+pub mod repetitions;
+pub mod reports;
+pub mod revisions;
+pub mod segments;
 pub mod storage;
+pub mod suggest;
+pub mod sync;
+pub mod text;
+pub mod tms;
+pub mod tray;
+pub mod updater;
+pub mod window;
 pub mod attachments;
 pub mod secure_store;
 pub mod secrets;
+pub mod settings;
+pub mod shutdown;
 pub mod mcp;
 pub mod notion;
+pub mod notifications;
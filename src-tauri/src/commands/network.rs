@@ -0,0 +1,22 @@
+//! Network Commands
+//!
+//! 연결 상태/오프라인 대기열 조회와 즉시 연결 확인
+
+use crate::error::CommandResult;
+use crate::network::{self, NetworkStatus};
+use tauri::AppHandle;
+
+/// 현재 연결 상태와 다시 연결되면 실행할 작업 목록
+#[tauri::command]
+pub fn get_network_status() -> CommandResult<NetworkStatus> {
+    Ok(network::status())
+}
+
+/// 지금 연결 확인 (다음 주기를 기다리지 않고 상태 갱신)
+#[tauri::command]
+pub async fn check_connectivity(app: AppHandle) -> CommandResult<NetworkStatus> {
+    crate::crash::guard(&app, "check_connectivity", async {
+        Ok(network::check_now().await)
+    })
+    .await
+}
@@ -0,0 +1,107 @@
+//! Job Notifications
+//!
+//! 오래 걸리는 작업(QA 검사, 사전 번역, 대용량 내보내기, 예약 백업)이 끝나거나 실패하면 OS 알림을 보냅니다.
+//! - 작업 종류별로 설정(`jobNotifications`)에서 끌 수 있습니다.
+//! - 사전 번역처럼 프론트엔드에서 진행하는 작업은 `notify_job_finished`로 알립니다.
+
+use serde::Deserialize;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::JobNotificationSettings;
+
+/// 알림 대상 작업 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobKind {
+    Qa,
+    Pretranslate,
+    Export,
+    Backup,
+}
+
+impl JobKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "qa" => Some(JobKind::Qa),
+            "pretranslate" => Some(JobKind::Pretranslate),
+            "export" => Some(JobKind::Export),
+            "backup" => Some(JobKind::Backup),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            JobKind::Qa => "QA check",
+            JobKind::Pretranslate => "Pre-translation",
+            JobKind::Export => "Export",
+            JobKind::Backup => "Backup",
+        }
+    }
+
+    fn enabled_in(self, settings: &JobNotificationSettings) -> bool {
+        match self {
+            JobKind::Qa => settings.qa,
+            JobKind::Pretranslate => settings.pretranslate,
+            JobKind::Export => settings.export,
+            JobKind::Backup => settings.backup,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyJobFinishedArgs {
+    /// "qa" | "pretranslate" | "export" | "backup"
+    pub job: String,
+    pub success: bool,
+    /// 알림 본문 (예: "120 segments translated")
+    pub message: String,
+}
+
+/// 작업 결과 알림 (설정에서 꺼져 있거나 창이 포커스되어 있으면 보내지 않음)
+/// - 알림 실패는 작업 결과에 영향을 주지 않으므로 로그만 남깁니다.
+pub(crate) fn notify_job(app: &AppHandle, kind: JobKind, result: Result<&str, &str>) {
+    let state = app.state::<DbState>();
+    let settings = match state.0.lock() {
+        Ok(db) => db.load_app_settings().map(|s| s.job_notifications).unwrap_or_default(),
+        Err(_) => return,
+    };
+    if !kind.enabled_in(&settings) {
+        return;
+    }
+    if settings.only_when_unfocused
+        && app
+            .webview_windows()
+            .values()
+            .any(|w| w.is_focused().unwrap_or(false))
+    {
+        return;
+    }
+
+    let (title, body) = match result {
+        Ok(body) => (format!("{} finished", kind.label()), body),
+        Err(body) => (format!("{} failed", kind.label()), body),
+    };
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        eprintln!("[Notification] Failed to show notification: {}", e);
+    }
+}
+
+/// 프론트엔드에서 진행한 작업 결과 알림
+#[tauri::command]
+pub fn notify_job_finished(app: AppHandle, args: NotifyJobFinishedArgs) -> CommandResult<()> {
+    let kind = JobKind::parse(&args.job).ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unknown job type: {}", args.job),
+    ).with_details("job must be 'qa', 'pretranslate', 'export' or 'backup'"))?;
+    let result = if args.success {
+        Ok(args.message.as_str())
+    } else {
+        Err(args.message.as_str())
+    };
+    notify_job(&app, kind, result);
+    Ok(())
+}
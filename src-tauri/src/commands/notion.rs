@@ -2,25 +2,51 @@
 //!
 //! Notion 검색, 페이지 조회 등의 기능을 프론트엔드에 노출합니다.
 
+use crate::error::{CommandError, CommandResult, ErrorCode};
 use crate::notion::NOTION_CLIENT;
+use tauri::AppHandle;
+
+/// 잘라낸 페이지 텍스트 끝에 붙이는 표시 (프론트엔드 도구 결과 표시와 동일)
+const TRUNCATED_MARKER: &str = "\n...[truncated]...\n";
+
+/// Notion 클라이언트의 문자열 오류를 `NOTION_ERROR`로 감쌈
+fn notion_error(message: String) -> CommandError {
+    CommandError::new(ErrorCode::NotionError, message)
+}
+
+/// 결과를 JSON 문자열로 직렬화
+fn serialize_result<T: serde::Serialize>(result: &T) -> CommandResult<String> {
+    serde_json::to_string(result).map_err(|e| {
+        CommandError::new(ErrorCode::SerializationError, format!("Failed to serialize result: {}", e))
+    })
+}
 
 /// Notion Integration Token 저장
 #[tauri::command]
-pub async fn notion_set_token(token: String) -> Result<(), String> {
-    NOTION_CLIENT.set_token(token).await
+pub async fn notion_set_token(app: AppHandle, token: String) -> CommandResult<()> {
+    crate::crash::guard(&app, "notion_set_token", async {
+        NOTION_CLIENT.set_token(token).await.map_err(notion_error)
+    })
+    .await
 }
 
 /// Notion 토큰 존재 여부 확인
 #[tauri::command]
-pub async fn notion_has_token() -> Result<bool, String> {
-    Ok(NOTION_CLIENT.has_token().await)
+pub async fn notion_has_token(app: AppHandle) -> CommandResult<bool> {
+    crate::crash::guard(&app, "notion_has_token", async {
+        Ok(NOTION_CLIENT.has_token().await)
+    })
+    .await
 }
 
 /// Notion 토큰 삭제 (로그아웃)
 #[tauri::command]
-pub async fn notion_clear_token() -> Result<(), String> {
-    NOTION_CLIENT.clear_token().await;
-    Ok(())
+pub async fn notion_clear_token(app: AppHandle) -> CommandResult<()> {
+    crate::crash::guard(&app, "notion_clear_token", async {
+        NOTION_CLIENT.clear_token().await;
+        Ok(())
+    })
+    .await
 }
 
 /// Notion 검색
@@ -31,12 +57,17 @@ pub async fn notion_clear_token() -> Result<(), String> {
 /// * `page_size` - 결과 개수 (선택, 기본값 20)
 #[tauri::command]
 pub async fn notion_search(
+    app: AppHandle,
     query: Option<String>,
     filter: Option<String>,
     page_size: Option<u32>,
-) -> Result<String, String> {
-    let result = NOTION_CLIENT.search(query, filter, page_size).await?;
-    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+) -> CommandResult<String> {
+    crate::crash::guard(&app, "notion_search", async {
+        crate::network::ensure_online("Notion")?;
+        let result = NOTION_CLIENT.search(query, filter, page_size).await.map_err(notion_error)?;
+        serialize_result(&result)
+    })
+    .await
 }
 
 /// Notion 페이지 조회
@@ -44,9 +75,13 @@ pub async fn notion_search(
 /// # Arguments
 /// * `page_id` - 페이지 ID 또는 URL
 #[tauri::command]
-pub async fn notion_get_page(page_id: String) -> Result<String, String> {
-    let result = NOTION_CLIENT.get_page(&page_id).await?;
-    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+pub async fn notion_get_page(app: AppHandle, page_id: String) -> CommandResult<String> {
+    crate::crash::guard(&app, "notion_get_page", async {
+        crate::network::ensure_online("Notion")?;
+        let result = NOTION_CLIENT.get_page(&page_id).await.map_err(notion_error)?;
+        serialize_result(&result)
+    })
+    .await
 }
 
 /// Notion 페이지 내용(블록) 조회
@@ -56,18 +91,26 @@ pub async fn notion_get_page(page_id: String) -> Result<String, String> {
 /// * `as_text` - true면 텍스트로 변환, false면 JSON
 #[tauri::command]
 pub async fn notion_get_page_content(
+    app: AppHandle,
     page_id: String,
     as_text: Option<bool>,
-) -> Result<String, String> {
-    let result = NOTION_CLIENT.get_blocks(&page_id, None).await?;
+) -> CommandResult<String> {
+    crate::crash::guard(&app, "notion_get_page_content", async {
+        crate::network::ensure_online("Notion")?;
+        let result = NOTION_CLIENT.get_blocks(&page_id, None).await.map_err(notion_error)?;
     
-    if as_text.unwrap_or(true) {
-        // 블록을 읽기 쉬운 텍스트로 변환
-        let text = crate::notion::NotionClient::blocks_to_text(&result.results);
-        Ok(text)
-    } else {
-        serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
-    }
+        if as_text.unwrap_or(true) {
+            // 블록을 읽기 쉬운 텍스트로 변환 (문서 최대 크기를 넘으면 잘라내고 표시)
+            let mut text = crate::notion::NotionClient::blocks_to_text(&result.results);
+            if crate::http::truncate_utf8(&mut text, crate::http::max_document_bytes()) {
+                text.push_str(TRUNCATED_MARKER);
+            }
+            Ok(text)
+        } else {
+            serialize_result(&result)
+        }
+    })
+    .await
 }
 
 /// Notion 데이터베이스 쿼리
@@ -78,19 +121,25 @@ pub async fn notion_get_page_content(
 /// * `page_size` - 결과 개수 (선택, 기본값 20)
 #[tauri::command]
 pub async fn notion_query_database(
+    app: AppHandle,
     database_id: String,
     filter: Option<String>,
     page_size: Option<u32>,
-) -> Result<String, String> {
-    let filter_value = filter
-        .map(|f| serde_json::from_str(&f))
-        .transpose()
-        .map_err(|e| format!("Invalid filter JSON: {}", e))?;
+) -> CommandResult<String> {
+    crate::crash::guard(&app, "notion_query_database", async {
+        crate::network::ensure_online("Notion")?;
+        let filter_value = filter
+            .map(|f| serde_json::from_str(&f))
+            .transpose()
+            .map_err(|e| CommandError::new(ErrorCode::InvalidInput, format!("Invalid filter JSON: {}", e)))?;
     
-    let result = NOTION_CLIENT
-        .query_database(&database_id, filter_value, page_size)
-        .await?;
+        let result = NOTION_CLIENT
+            .query_database(&database_id, filter_value, page_size)
+            .await
+            .map_err(notion_error)?;
     
-    serde_json::to_string(&result).map_err(|e| format!("Failed to serialize result: {}", e))
+        serialize_result(&result)
+    })
+    .await
 }
 
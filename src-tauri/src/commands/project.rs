@@ -2,18 +2,33 @@
 //!
 //! 프로젝트 관리 관련 Tauri 명령어
 
-use tauri::State;
-use serde::Deserialize;
+use std::collections::BTreeMap;
 
-use crate::db::DbState;
-use crate::error::{CommandError, CommandResult};
-use crate::models::IteProject;
+use tauri::{AppHandle, State, Window};
+use serde::{Deserialize, Serialize};
+
+use crate::db::{DbState, ProjectTemplateSummary};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::{IteProject, ProjectMetadata};
+use crate::text::lang::{detect_language, normalize_language, suggest_target_language, LanguageDetection};
+use crate::text::locale::{primary_language, LocaleProfile};
+
+use super::qa::enqueue_block_qa;
+use super::window::broadcast_block_change;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateProjectArgs {
     pub title: String,
     pub domain: String,
+    /// 없으면 앱 기본값/원문 언어 감지 결과로 자동 설정
+    pub target_language: Option<String>,
+    /// 번역 언어 자동 설정에 사용할 원문 샘플 (붙여넣은 텍스트 등)
+    pub source_text: Option<String>,
+    /// 원문 로캘 (BCP 47, 없으면 앱 기본 원문 언어 → 원문 샘플 감지 결과)
+    pub source_locale: Option<String>,
+    /// 번역 로캘 (BCP 47, 주어지면 `targetLanguage`보다 우선)
+    pub target_locale: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -21,14 +36,19 @@ pub struct CreateProjectArgs {
 pub struct LoadProjectArgs {
     #[serde(rename = "projectId")]
     pub project_id: String,
+    /// true면 읽기 전용으로 열고, false면 해제 (없으면 현재 상태 유지)
+    #[serde(default)]
+    pub read_only: Option<bool>,
 }
 
-/// 새 프로젝트 생성
-#[tauri::command]
-pub fn create_project(
-    args: CreateProjectArgs,
-    db_state: State<DbState>,
-) -> CommandResult<IteProject> {
+/// 빈 원문/번역문 블록 한 쌍으로 시작하는 새 프로젝트
+fn empty_project(
+    title: String,
+    domain: String,
+    target_language: Option<String>,
+    author: Option<String>,
+    settings: crate::models::ProjectSettings,
+) -> IteProject {
     let now = chrono::Utc::now().timestamp_millis();
     let project_id = uuid::Uuid::new_v4().to_string();
 
@@ -52,6 +72,7 @@ pub fn create_project(
             updated_at: now,
             tags: Vec::new(),
             comments: None,
+            capacity: None,
         },
     });
 
@@ -67,6 +88,7 @@ pub fn create_project(
             updated_at: now,
             tags: Vec::new(),
             comments: None,
+            capacity: None,
         },
     });
 
@@ -79,35 +101,89 @@ pub fn create_project(
         order: 0,
     }];
 
-    let project = IteProject {
-        id: project_id.clone(),
+    IteProject {
+        id: project_id,
         version: "1.0.0".to_string(),
         metadata: crate::models::ProjectMetadata {
-            title: args.title,
+            title,
             description: None,
-            domain: args.domain,
-            target_language: None,
+            domain,
+            target_language,
+            source_locale: None,
+            target_locale: None,
             created_at: now,
             updated_at: now,
-            author: None,
+            author,
             glossary_paths: None,
-            settings: crate::models::ProjectSettings {
-                strictness_level: 0.5,
-                auto_save: true,
-                auto_save_interval: 30000,
-                theme: "system".to_string(),
-            },
+            settings,
         },
         segments,
         blocks,
         history: Vec::new(),
+    }
+}
+
+/// 새 프로젝트 생성
+#[tauri::command]
+pub fn create_project(
+    args: CreateProjectArgs,
+    db_state: State<DbState>,
+) -> CommandResult<IteProject> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let settings = db.load_app_settings().map_err(CommandError::from)?;
+    let detection = args.source_text.as_deref().map(detect_language);
+    // 원문 로캘: 명시값 > 앱 기본 원문 언어 > 원문 샘플 감지 결과
+    let explicit_source = args.source_locale.filter(|l| !l.trim().is_empty());
+    let source_locale = explicit_source.clone().or_else(|| {
+        settings
+            .default_source_language
+            .clone()
+            .or_else(|| detection.as_ref().filter(|d| d.reliable).and_then(|d| d.code.clone()))
+    });
+
+    // 번역 언어: 명시값 > 앱 기본값/원문 언어 기반 추정
+    let has_target_locale = args.target_locale.as_deref().is_some_and(|l| !l.trim().is_empty());
+    let target_language = match args.target_language.filter(|l| !l.trim().is_empty()) {
+        Some(lang) => Some(lang),
+        None if has_target_locale => None,
+        None => {
+            let source = source_locale
+                .as_deref()
+                .and_then(normalize_language)
+                .map(|code| LanguageDetection {
+                    code: Some(code.to_string()),
+                    name: None,
+                    confidence: 1.0,
+                    reliable: true,
+                })
+                .or(detection);
+            suggest_target_language(source.as_ref(), settings.default_target_language.as_deref()).map(str::to_string)
+        }
     };
 
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let mut project = empty_project(
+        args.title,
+        args.domain,
+        target_language,
+        None,
+        crate::models::ProjectSettings {
+            strictness_level: 0.5,
+            auto_save: true,
+            auto_save_interval: 30000,
+            theme: "system".to_string(),
+        },
+    );
+    project.metadata.source_locale = source_locale;
+    project.metadata.target_locale = args.target_locale;
+    if explicit_source.is_none() && project.metadata.normalize_locales().is_err() {
+        // 추정한 원문 언어가 맞지 않으면(지원하지 않거나 번역 언어와 같음) 비워 둠
+        project.metadata.source_locale = None;
+    }
+    normalize_locales(&mut project.metadata)?;
 
     db.save_project(&project).map_err(CommandError::from)?;
 
@@ -117,25 +193,118 @@ pub fn create_project(
 /// 프로젝트 로드
 #[tauri::command]
 pub fn load_project(args: LoadProjectArgs, db_state: State<DbState>) -> CommandResult<IteProject> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+    if let Some(read_only) = args.read_only {
+        db.set_project_read_only(&project.id, read_only).map_err(CommandError::from)?;
+    }
+    Ok(project)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProjectReadOnlyArgs {
+    pub project_id: String,
+    pub read_only: bool,
+}
+
+/// 프로젝트 읽기 전용 설정/해제
+/// - 읽기 전용 프로젝트는 저장/블록 수정/태그/메모/리뷰 수정 등이 `READ_ONLY` 오류로 거부됩니다.
+/// - 앱을 다시 시작하면 해제되므로, 납품본을 열 때마다 `load_project`의 `readOnly`로 지정합니다.
+#[tauri::command]
+pub fn set_project_read_only(args: SetProjectReadOnlyArgs, db_state: State<DbState>) -> CommandResult<bool> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
-    db.load_project(&args.project_id).map_err(CommandError::from)
+    db.set_project_read_only(&args.project_id, args.read_only)
+        .map_err(CommandError::from)?;
+    Ok(args.read_only)
 }
 
 /// 프로젝트 저장
+/// - 로캘을 정규화해 저장합니다. 에디터에서 번역 언어를 바꿔 원문 로캘과 같아졌거나 원문 로캘이 잘못됐으면
+///   원문 로캘을 비우고 저장합니다 (자동 저장이 계속 실패하지 않도록, 언어쌍 검증은 생성 시에만).
+/// - 내용이 바뀐 블록은 같은 프로젝트를 연 다른 창에 알리고 백그라운드 QA 대기열에 넣습니다.
 #[tauri::command]
-pub fn save_project(project: IteProject, db_state: State<DbState>) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+pub fn save_project(
+    app: AppHandle,
+    window: Window,
+    mut project: IteProject,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    if project.metadata.normalize_locales().is_err() {
+        project.metadata.source_locale = None;
+        normalize_locales(&mut project.metadata)?;
+    }
+    let changed_blocks = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.save_project(&project).map_err(CommandError::from)?
+    };
+
+    for block_id in &changed_blocks {
+        enqueue_block_qa(&app, &project.id, block_id);
+    }
+    broadcast_block_change(&app, Some(window.label()), &project.id, changed_blocks);
+    Ok(())
+}
 
-    db.save_project(&project).map_err(CommandError::from)
+/// 언어쌍 정규화/검증 (잘못된 로캘, 같은 언어 쌍은 `INVALID_INPUT`)
+fn normalize_locales(metadata: &mut ProjectMetadata) -> CommandResult<()> {
+    metadata
+        .normalize_locales()
+        .map_err(|e| CommandError::new(ErrorCode::InvalidInput, e))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetProjectLocalesArgs {
+    pub project_id: String,
+}
+
+/// 프로젝트 언어쌍과 로캘별 자동 설정
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLocales {
+    /// 프로젝트 원문 로캘이 없으면 앱 기본 원문 언어
+    pub source: Option<LocaleProfile>,
+    pub target: Option<LocaleProfile>,
+}
+
+/// 프로젝트 언어쌍에 맞는 문장 분할 규칙, 맞춤법 사전, MT 언어 코드
+#[tauri::command]
+pub fn get_project_locales(args: GetProjectLocalesArgs, db_state: State<DbState>) -> CommandResult<ProjectLocales> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let mut metadata = db.load_project_metadata(&args.project_id).map_err(CommandError::from)?;
+    let default_source = db.load_app_settings().map_err(CommandError::from)?.default_source_language;
+    // 이전에 저장된 프로젝트는 로캘이 없을 수 있어 표시 이름에서 추정 (언어쌍 오류는 무시)
+    let _ = metadata.normalize_locales();
+    let source = metadata
+        .source_language_or(default_source)
+        .and_then(|l| crate::text::locale::canonicalize_locale(&l).ok())
+        .filter(|l| {
+            metadata
+                .target_locale
+                .as_deref()
+                .is_none_or(|t| primary_language(t) != primary_language(l))
+        });
+    Ok(ProjectLocales {
+        source: source.as_deref().map(LocaleProfile::new),
+        target: metadata.target_locale.as_deref().map(LocaleProfile::new),
+    })
 }
 
 #[derive(Debug, Deserialize)]
@@ -150,11 +319,10 @@ pub fn duplicate_project(
     args: DuplicateProjectArgs,
     db_state: State<DbState>,
 ) -> CommandResult<IteProject> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     let original = db.load_project(&args.project_id).map_err(CommandError::from)?;
     let now = chrono::Utc::now().timestamp_millis();
@@ -181,6 +349,7 @@ pub fn duplicate_project(
                 updated_at: now,
                 tags: block.metadata.tags.clone(),
                 comments: block.metadata.comments.clone(),
+                capacity: block.metadata.capacity.clone(),
             },
         });
     }
@@ -208,6 +377,8 @@ pub fn duplicate_project(
             description: original.metadata.description.clone(),
             domain: original.metadata.domain.clone(),
             target_language: original.metadata.target_language.clone(),
+            source_locale: original.metadata.source_locale.clone(),
+            target_locale: original.metadata.target_locale.clone(),
             created_at: now,
             updated_at: now,
             author: original.metadata.author.clone(),
@@ -226,5 +397,180 @@ pub fn duplicate_project(
         let _ = db.save_chat_project_settings(&new_project.id, &settings_json, now);
     }
 
+    // 사용자 정의 필드 복제 (고객사, PO 번호 등)
+    let _ = db.copy_project_custom_fields(&args.project_id, &new_project.id);
+
+    // MCP 서버 연결 복제
+    if let Ok(servers) = db.get_project_mcp_servers(&args.project_id) {
+        if !servers.is_empty() {
+            let server_ids: Vec<String> = servers.into_iter().map(|s| s.id).collect();
+            let _ = db.set_project_mcp_servers(&new_project.id, &server_ids);
+        }
+    }
+
     Ok(new_project)
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProjectCustomFieldArgs {
+    pub project_id: String,
+    pub key: String,
+    /// 없거나 빈 문자열이면 필드 삭제
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCustomFieldsArgs {
+    pub project_id: String,
+}
+
+/// 프로젝트 사용자 정의 필드 설정/삭제 후 전체 필드 반환
+#[tauri::command]
+pub fn set_project_custom_field(
+    args: SetProjectCustomFieldArgs,
+    db_state: State<DbState>,
+) -> CommandResult<BTreeMap<String, String>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_project_custom_field(&args.project_id, &args.key, args.value.as_deref())
+        .map_err(CommandError::from)?;
+    db.get_project_custom_fields(&args.project_id)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트 사용자 정의 필드 조회
+#[tauri::command]
+pub fn get_project_custom_fields(
+    args: ProjectCustomFieldsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<BTreeMap<String, String>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.get_project_custom_fields(&args.project_id)
+        .map_err(CommandError::from)
+}
+
+/// 사용 중인 사용자 정의 필드 키 목록 (필터 자동완성용)
+#[tauri::command]
+pub fn list_custom_field_keys(db_state: State<DbState>) -> CommandResult<Vec<String>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_custom_field_keys().map_err(CommandError::from)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveProjectAsTemplateArgs {
+    pub project_id: String,
+    /// 같은 이름의 템플릿이 있으면 덮어씀
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplateIdArgs {
+    pub template_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectFromTemplateArgs {
+    pub template_id: String,
+    /// 없으면 템플릿 이름
+    pub title: Option<String>,
+}
+
+/// 프로젝트 설정/용어집/DNT/채팅 설정을 템플릿으로 저장
+#[tauri::command]
+pub fn save_project_as_template(
+    args: SaveProjectAsTemplateArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ProjectTemplateSummary> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let template = db
+        .capture_project_template(&args.project_id, &args.name)
+        .map_err(CommandError::from)?;
+    let saved = db.save_project_template(&template).map_err(CommandError::from)?;
+
+    Ok(ProjectTemplateSummary::from(&saved))
+}
+
+/// 프로젝트 템플릿 목록
+#[tauri::command]
+pub fn list_project_templates(db_state: State<DbState>) -> CommandResult<Vec<ProjectTemplateSummary>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_project_templates().map_err(CommandError::from)
+}
+
+/// 프로젝트 템플릿 삭제 (템플릿으로 만든 프로젝트에는 영향 없음)
+#[tauri::command]
+pub fn delete_project_template(args: ProjectTemplateIdArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.delete_project_template(&args.template_id).map_err(CommandError::from)
+}
+
+/// 템플릿으로 새 프로젝트 생성 (설정/용어집/DNT/프롬프트 템플릿/채팅 설정/사용자 정의 필드 적용)
+#[tauri::command]
+pub fn create_project_from_template(
+    args: CreateProjectFromTemplateArgs,
+    db_state: State<DbState>,
+) -> CommandResult<IteProject> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let template = db
+        .get_project_template(&args.template_id)
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::new(
+            ErrorCode::NotFound,
+            format!("Project template not found: {}", args.template_id),
+        ))?;
+
+    let title = args
+        .title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| template.name.clone());
+    let mut project = empty_project(
+        title,
+        template.domain.clone(),
+        template.target_language.clone(),
+        template.author.clone(),
+        template.settings.clone(),
+    );
+    normalize_locales(&mut project.metadata)?;
+    db.save_project(&project).map_err(CommandError::from)?;
+
+    // 일부만 적용된 프로젝트가 남지 않도록 실패하면 프로젝트를 지움
+    if let Err(e) = db.apply_project_template(&project.id, &template) {
+        let _ = db.delete_project(&project.id);
+        return Err(CommandError::from(e));
+    }
+
+    Ok(project)
+}
@@ -0,0 +1,156 @@
+//! Prompt Template Commands
+//!
+//! 번역가 페르소나/번역 규칙 프리셋을 DB에 저장하고 관리하는 API
+//! (.ite 내보내기에 포함되어 프로젝트와 함께 공유됩니다)
+
+use serde::Deserialize;
+use tauri::State;
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::PromptTemplate;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPromptTemplatesArgs {
+    /// 주어지면 전역 + 해당 프로젝트 템플릿, 없으면 전역 템플릿만
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavePromptTemplateArgs {
+    /// 없으면 새 템플릿 생성
+    pub id: Option<String>,
+    pub name: String,
+    pub body: String,
+    /// 없으면 본문의 `{{name}}` 플레이스홀더에서 추출
+    pub variables: Option<Vec<String>>,
+    /// "global" | "project"
+    pub scope: String,
+    /// scope가 "project"일 때 필수
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptTemplateIdArgs {
+    pub id: String,
+}
+
+/// 본문에서 `{{name}}` 형태의 변수 이름을 등장 순서대로(중복 제거) 추출
+fn extract_template_variables(body: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !name.is_empty()
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+            && !out.iter().any(|v| v == name)
+        {
+            out.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    out
+}
+
+/// 프롬프트 템플릿 목록 조회
+#[tauri::command]
+pub fn list_prompt_templates(
+    args: ListPromptTemplatesArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<PromptTemplate>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_prompt_templates(args.project_id.as_deref())
+        .map_err(CommandError::from)
+}
+
+/// 프롬프트 템플릿 단건 조회
+#[tauri::command]
+pub fn get_prompt_template(
+    args: PromptTemplateIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Option<PromptTemplate>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.get_prompt_template(&args.id).map_err(CommandError::from)
+}
+
+/// 프롬프트 템플릿 저장 (생성 또는 수정)
+/// - 수정 시 version이 증가합니다.
+#[tauri::command]
+pub fn save_prompt_template(
+    args: SavePromptTemplateArgs,
+    db_state: State<DbState>,
+) -> CommandResult<PromptTemplate> {
+    let name = args.name.trim().to_string();
+    if name.is_empty() {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "템플릿 이름을 입력해주세요."));
+    }
+
+    let project_id = match args.scope.as_str() {
+        "global" => None,
+        "project" => match args.project_id.filter(|p| !p.trim().is_empty()) {
+            Some(p) => Some(p),
+            None => {
+                return Err(CommandError::new(ErrorCode::InvalidInput, "프로젝트 템플릿에는 projectId가 필요합니다."))
+            }
+        },
+        other => {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Unknown template scope: {}", other),
+            ).with_details("scope must be 'global' or 'project'"))
+        }
+    };
+
+    let variables = args
+        .variables
+        .unwrap_or_else(|| extract_template_variables(&args.body));
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let template = PromptTemplate {
+        id: args.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+        scope: args.scope,
+        project_id,
+        name,
+        body: args.body,
+        variables,
+        version: 1,
+        created_at: now,
+        updated_at: now,
+    };
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.save_prompt_template(&template).map_err(CommandError::from)
+}
+
+/// 프롬프트 템플릿 삭제
+#[tauri::command]
+pub fn delete_prompt_template(
+    args: PromptTemplateIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.delete_prompt_template(&args.id).map_err(CommandError::from)
+}
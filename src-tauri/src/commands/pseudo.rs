@@ -0,0 +1,94 @@
+//! Pseudo-Translation Commands
+//!
+//! 레이아웃 테스트용 의사 번역문으로 프로젝트 번역문 블록을 채우는 API
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::qa::segment_texts;
+use crate::text::pseudo::{pseudo_translate, PseudoOptions, MAX_EXPANSION_PERCENT};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PseudoTranslateProjectArgs {
+    pub project_id: String,
+    #[serde(default)]
+    pub options: PseudoOptions,
+    /// true면 이미 번역된 세그먼트도 덮어씀 (기본 false)
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PseudoTranslateResult {
+    pub updated_segments: usize,
+    pub updated_blocks: usize,
+    /// 이미 번역되어 있거나 번역문 블록이 없어 건너뛴 세그먼트 수
+    pub skipped_segments: usize,
+}
+
+/// 프로젝트 번역문을 의사 번역문으로 채우기
+/// - 원문/번역문 블록 수가 같으면 블록 단위로, 다르면 첫 번역문 블록에 원문 전체를 채우고
+///   나머지 번역문 블록은 비웁니다.
+#[tauri::command]
+pub fn pseudo_translate_project(
+    args: PseudoTranslateProjectArgs,
+    db_state: State<DbState>,
+) -> CommandResult<PseudoTranslateResult> {
+    if args.options.expansion_percent > MAX_EXPANSION_PERCENT {
+        return Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            format!("expansionPercent must be at most {}", MAX_EXPANSION_PERCENT),
+        ));
+    }
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+    let content_of = |id: &String| project.blocks.get(id).map(|b| b.content.as_str()).unwrap_or("");
+
+    let mut updates: Vec<(String, String)> = Vec::new();
+    let mut updated_segments = 0;
+    let mut skipped_segments = 0;
+
+    for segment in &project.segments {
+        let (_, target) = segment_texts(&project, segment);
+        if segment.target_ids.is_empty() || (!args.overwrite && !target.trim().is_empty()) {
+            skipped_segments += 1;
+            continue;
+        }
+
+        if segment.source_ids.len() == segment.target_ids.len() {
+            for (source_id, target_id) in segment.source_ids.iter().zip(&segment.target_ids) {
+                updates.push((target_id.clone(), pseudo_translate(content_of(source_id), &args.options)));
+            }
+        } else {
+            let joined: String = segment
+                .source_ids
+                .iter()
+                .map(|id| pseudo_translate(content_of(id), &args.options))
+                .collect();
+            for (i, target_id) in segment.target_ids.iter().enumerate() {
+                let content = if i == 0 { joined.clone() } else { "<p></p>".to_string() };
+                updates.push((target_id.clone(), content));
+            }
+        }
+        updated_segments += 1;
+    }
+
+    let updated_blocks = db
+        .update_block_contents(&args.project_id, &updates)
+        .map_err(CommandError::from)?;
+
+    Ok(PseudoTranslateResult {
+        updated_segments,
+        updated_blocks,
+        skipped_segments,
+    })
+}
@@ -0,0 +1,356 @@
+//! QA Commands
+//!
+//! 프로젝트 단위 번역 품질 검사 실행 API, 세그먼트별 MT 품질 추정(신뢰도) 계산/조회
+//! - 블록이 저장되면 해당 세그먼트만 백그라운드에서 다시 검사해 `block-qa-updated` 이벤트로 보냅니다.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::mpsc;
+
+use crate::commands::dnt::load_dnt_matcher;
+use crate::commands::notifications::{notify_job, JobKind};
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::IteProject;
+use crate::qa::consistency::ConsistencyCluster;
+use crate::qa::estimate::{estimate_project, mark_stale, SegmentQuality};
+use crate::qa::{self, QaIssue};
+use crate::text::glossary::GlossaryMatcher;
+use crate::text::words::resolve_source_language;
+
+/// 블록 단위 QA 결과 이벤트
+pub const BLOCK_QA_UPDATED_EVENT: &str = "block-qa-updated";
+
+/// 연속 입력을 한 번에 묶어 검사하기 위한 대기 시간
+const BLOCK_QA_DEBOUNCE: Duration = Duration::from_millis(400);
+
+/// 지원하는 QA 검사 목록
+pub(crate) const ALL_CHECKS: &[&str] = &[
+    qa::dnt::CHECK_ID,
+    qa::consistency::CHECK_ID,
+    qa::length::CHECK_ID,
+    qa::numeric::CHECK_ID,
+];
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunQaChecksArgs {
+    pub project_id: String,
+    /// 실행할 검사 (없으면 전체)
+    pub checks: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QaReport {
+    pub checks: Vec<String>,
+    pub checked_segments: usize,
+    pub issues: Vec<QaIssue>,
+}
+
+/// 블록 QA 대기열 (project_id, block_id)
+pub struct BlockQaQueue(mpsc::UnboundedSender<(String, String)>);
+
+/// `block-qa-updated` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockQaUpdatedEvent {
+    pub project_id: String,
+    pub block_id: String,
+    /// 블록이 세그먼트 밖에 있으면 None (이슈 없음)
+    pub segment_id: Option<String>,
+    /// 해당 세그먼트의 현재 이슈 (세그먼트 간 비교가 필요한 검사 결과는 제외)
+    pub issues: Vec<QaIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyReportArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyReport {
+    pub checked_segments: usize,
+    pub clusters: Vec<ConsistencyCluster>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateQualityArgs {
+    pub project_id: String,
+    /// 번역문 블록에 이 태그가 있는 세그먼트만 추정 (예: MT로 채운 세그먼트, 없으면 번역된 세그먼트 전체)
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityReport {
+    pub estimated_segments: usize,
+    pub average_score: Option<f64>,
+    /// 점수 낮은 순
+    pub segments: Vec<SegmentQuality>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetQualityEstimatesArgs {
+    pub project_id: String,
+    /// 이 점수 이하만 (없으면 전체)
+    pub max_score: Option<f64>,
+}
+
+/// 프로젝트를 로드하고 지정한 검사를 실행해 이슈 목록 반환 (리뷰 리포트와 공유)
+pub(crate) fn collect_qa_issues(
+    db_state: &State<DbState>,
+    project_id: &str,
+    checks: &[String],
+) -> CommandResult<(IteProject, Vec<QaIssue>)> {
+    let (project, glossary, default_source) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let project = db.load_project(project_id).map_err(CommandError::from)?;
+        let glossary = if checks.iter().any(|c| c == qa::consistency::CHECK_ID) {
+            db.list_glossary_entries(project_id).map_err(CommandError::from)?
+        } else {
+            Vec::new()
+        };
+        let default_source = db.load_app_settings().map_err(CommandError::from)?.default_source_language;
+        (project, glossary, default_source)
+    };
+
+    let mut issues = Vec::new();
+    for check in checks {
+        if check == qa::dnt::CHECK_ID {
+            let matcher = load_dnt_matcher(db_state, Some(project_id))?;
+            issues.extend(qa::dnt::check(&project, &matcher));
+        } else if check == qa::consistency::CHECK_ID {
+            issues.extend(qa::consistency::check(&project, &glossary));
+        } else if check == qa::length::CHECK_ID {
+            issues.extend(qa::length::check(&project));
+        } else if check == qa::numeric::CHECK_ID {
+            let source_locale = numeric_source_locale(&project, default_source.clone());
+            issues.extend(qa::numeric::check(&project, source_locale.as_deref()));
+        }
+    }
+
+    Ok((project, issues))
+}
+
+/// 숫자/날짜 검사의 원문 로캘 (프로젝트 로캘 > 설정 기본값 > 원문에서 감지)
+fn numeric_source_locale(project: &IteProject, default_source: Option<String>) -> Option<String> {
+    project.metadata.source_language_or(default_source).or_else(|| {
+        let texts: Vec<String> = project
+            .segments
+            .iter()
+            .map(|s| qa::segment_texts(project, s).0)
+            .collect();
+        resolve_source_language(None, texts.iter().map(String::as_str))
+    })
+}
+
+/// 프로젝트 QA 검사 실행
+#[tauri::command]
+pub fn run_qa_checks(app: AppHandle, args: RunQaChecksArgs, db_state: State<DbState>) -> CommandResult<QaReport> {
+    let checks: Vec<String> = match args.checks {
+        Some(list) if !list.is_empty() => list,
+        _ => ALL_CHECKS.iter().map(|c| c.to_string()).collect(),
+    };
+    if let Some(unknown) = checks.iter().find(|c| !ALL_CHECKS.contains(&c.as_str())) {
+        return Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            format!("Unknown QA check: {}", unknown),
+        ).with_details(format!("supported: {}", ALL_CHECKS.join(", "))));
+    }
+
+    let (project, issues) = match collect_qa_issues(&db_state, &args.project_id, &checks) {
+        Ok(collected) => collected,
+        Err(e) => {
+            notify_job(&app, JobKind::Qa, Err(&e.message));
+            return Err(e);
+        }
+    };
+    notify_job(
+        &app,
+        JobKind::Qa,
+        Ok(&format!(
+            "{}: {} issue(s) in {} segment(s)",
+            project.metadata.title,
+            issues.len(),
+            project.segments.len()
+        )),
+    );
+
+    Ok(QaReport {
+        checks,
+        checked_segments: project.segments.len(),
+        issues,
+    })
+}
+
+/// 용어/번역 일관성 리포트 (용어집 + 같은 원문 세그먼트 기준 불일치 묶음)
+#[tauri::command]
+pub fn get_consistency_report(
+    args: ConsistencyReportArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ConsistencyReport> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+    let glossary = db
+        .list_glossary_entries(&args.project_id)
+        .map_err(CommandError::from)?;
+
+    Ok(ConsistencyReport {
+        checked_segments: project.segments.len(),
+        clusters: qa::consistency::find_clusters(&project, &glossary),
+    })
+}
+
+/// 세그먼트별 MT 품질 추정 (참조 없는 휴리스틱) 후 저장
+/// - 리뷰어가 신뢰도 낮은 세그먼트부터 볼 수 있도록 점수 낮은 순으로 돌려줍니다.
+#[tauri::command]
+pub fn estimate_quality(args: EstimateQualityArgs, db_state: State<DbState>) -> CommandResult<QualityReport> {
+    let (project, glossary) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        (
+            db.load_project(&args.project_id).map_err(CommandError::from)?,
+            db.list_glossary_entries(&args.project_id).map_err(CommandError::from)?,
+        )
+    };
+
+    let tag = args.tag.as_deref().map(str::trim).filter(|t| !t.is_empty());
+    let mut segments = estimate_project(&project, &GlossaryMatcher::new(&glossary), tag);
+    {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.replace_quality_estimates(&args.project_id, &segments)
+            .map_err(CommandError::from)?;
+    }
+
+    let average_score = (!segments.is_empty())
+        .then(|| segments.iter().map(|s| s.score).sum::<f64>() / segments.len() as f64);
+    // 같은 점수는 문서 순서 유지
+    segments.sort_by(|a, b| a.score.total_cmp(&b.score));
+    println!(
+        "[QA] Estimated quality of {} segment(s) in {}",
+        segments.len(),
+        args.project_id
+    );
+    Ok(QualityReport {
+        estimated_segments: segments.len(),
+        average_score,
+        segments,
+    })
+}
+
+/// 저장된 품질 추정 결과 (점수 낮은 순, 추정 이후 번역문이 바뀐 세그먼트는 `stale`)
+#[tauri::command]
+pub fn get_quality_estimates(
+    args: GetQualityEstimatesArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<SegmentQuality>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+    let mut estimates = db
+        .list_quality_estimates(&args.project_id, args.max_score)
+        .map_err(CommandError::from)?;
+    mark_stale(&project, &mut estimates);
+    Ok(estimates)
+}
+
+/// 블록이 속한 세그먼트만 검사
+/// - 세그먼트 하나만 담은 프로젝트로 검사하므로, 같은 원문 세그먼트 간 불일치는 전체 QA에서만 보고됩니다.
+fn check_block_segment(app: &AppHandle, project_id: &str, block_id: &str) -> CommandResult<BlockQaUpdatedEvent> {
+    let db_state = app.state::<DbState>();
+    let (slice, glossary, default_source) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let slice = db.load_block_segment(project_id, block_id).map_err(CommandError::from)?;
+        let (glossary, default_source) = match &slice {
+            Some(_) => (
+                db.list_glossary_entries(project_id).map_err(CommandError::from)?,
+                db.load_app_settings().map_err(CommandError::from)?.default_source_language,
+            ),
+            None => (Vec::new(), None),
+        };
+        (slice, glossary, default_source)
+    };
+
+    let mut event = BlockQaUpdatedEvent {
+        project_id: project_id.to_string(),
+        block_id: block_id.to_string(),
+        segment_id: None,
+        issues: Vec::new(),
+    };
+    let Some(slice) = slice else {
+        return Ok(event);
+    };
+    let matcher = load_dnt_matcher(&db_state, Some(project_id))?;
+    event.segment_id = slice.segments.first().map(|s| s.group_id.clone());
+    event.issues.extend(qa::dnt::check(&slice, &matcher));
+    event.issues.extend(qa::consistency::check(&slice, &glossary));
+    event.issues.extend(qa::length::check(&slice));
+    let source_locale = numeric_source_locale(&slice, default_source);
+    event.issues.extend(qa::numeric::check(&slice, source_locale.as_deref()));
+    Ok(event)
+}
+
+/// 블록 QA 대기열 생성 + 백그라운드 검사 작업 시작 (앱 setup에서 한 번 호출)
+pub fn start_block_qa_worker(app: &AppHandle) {
+    let (tx, mut rx) = mpsc::unbounded_channel::<(String, String)>();
+    app.manage(BlockQaQueue(tx));
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(first) = rx.recv().await {
+            // 입력이 이어지는 동안 쌓인 요청을 모아 블록마다 한 번만 검사
+            tokio::time::sleep(BLOCK_QA_DEBOUNCE).await;
+            let mut pending = vec![first];
+            while let Ok(next) = rx.try_recv() {
+                if !pending.contains(&next) {
+                    pending.push(next);
+                }
+            }
+
+            for (project_id, block_id) in pending {
+                let app = handle.clone();
+                let checked =
+                    tauri::async_runtime::spawn_blocking(move || check_block_segment(&app, &project_id, &block_id))
+                        .await;
+                match checked {
+                    Ok(Ok(event)) => {
+                        let _ = handle.emit(BLOCK_QA_UPDATED_EVENT, event);
+                    }
+                    Ok(Err(e)) => eprintln!("[QA] Block check failed: {}", e.message),
+                    Err(e) => eprintln!("[QA] Block check task failed: {}", e),
+                }
+            }
+        }
+    });
+}
+
+/// 저장된 블록을 백그라운드 QA 대기열에 추가
+pub(crate) fn enqueue_block_qa(app: &AppHandle, project_id: &str, block_id: &str) {
+    if let Some(queue) = app.try_state::<BlockQaQueue>() {
+        let _ = queue.0.send((project_id.to_string(), block_id.to_string()));
+    }
+}
@@ -0,0 +1,243 @@
+//! Reference URL Commands
+//!
+//! 채팅에 붙여 넣은 Notion/Confluence/Jira 링크를 알맞은 클라이언트로 가져와
+//! 제목 + 본문(평문/Markdown)으로 정규화해 반환합니다.
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+use tauri::AppHandle;
+
+use super::confluence::{fetch_jira_issue, fetch_page};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::notion::NOTION_CLIENT;
+use crate::text::confluence::{convert_storage, StorageFormat};
+use crate::text::words::{self, WordCount};
+
+/// 링크 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferenceSource {
+    Notion,
+    Confluence,
+    Jira,
+}
+
+/// URL에서 찾은 대상
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceTarget {
+    pub source: ReferenceSource,
+    /// Notion 페이지 ID(하이픈 없음) / Confluence 페이지 ID / Jira 이슈 키
+    pub id: String,
+    /// Atlassian 사이트 호스트 (예: "acme.atlassian.net", 여러 사이트 중 cloudId 선택용)
+    pub site: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveReferenceUrlArgs {
+    pub url: String,
+    /// 본문 형식 (Confluence에만 적용, 기본 Markdown)
+    #[serde(default)]
+    pub format: StorageFormat,
+    /// 문서 언어 (BCP 47, 단어 수의 CJK 환산 계수 결정)
+    pub language: Option<String>,
+}
+
+/// 정규화한 링크 내용
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedReference {
+    pub url: String,
+    pub source: ReferenceSource,
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub word_count: WordCount,
+    /// 문서 최대 크기(`http.maxDocumentKb`)를 넘어 잘라냈는지
+    pub truncated: bool,
+}
+
+/// 경로/쿼리 끝에 붙은 32자리 hex를 Notion ID로 사용
+fn notion_id(segment: &str) -> Option<String> {
+    let compact: String = segment.chars().filter(|c| *c != '-').collect();
+    if compact.len() < 32 {
+        return None;
+    }
+    let id = &compact[compact.len() - 32..];
+    id.chars().all(|c| c.is_ascii_hexdigit()).then(|| id.to_ascii_lowercase())
+}
+
+fn is_jira_key(key: &str) -> bool {
+    let Some((project, number)) = key.split_once('-') else {
+        return false;
+    };
+    !project.is_empty()
+        && project.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && project.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// URL이 가리키는 Notion 페이지 / Confluence 페이지 / Jira 이슈 찾기 (모르는 링크는 None)
+pub fn detect_reference(raw: &str) -> Option<ReferenceTarget> {
+    let url = Url::parse(raw.trim()).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?.to_ascii_lowercase();
+    let segments: Vec<&str> = url.path_segments().map(|s| s.filter(|p| !p.is_empty()).collect()).unwrap_or_default();
+    let query = |name: &str| url.query_pairs().find(|(k, _)| k == name).map(|(_, v)| v.into_owned());
+
+    if host == "notion.so" || host.ends_with(".notion.so") || host.ends_with(".notion.site") {
+        // 데이터베이스 안에서 연 페이지(?p=)가 우선
+        let id = query("p")
+            .and_then(|p| notion_id(&p))
+            .or_else(|| segments.last().and_then(|s| notion_id(s)))?;
+        return Some(ReferenceTarget { source: ReferenceSource::Notion, id, site: None });
+    }
+
+    if !host.ends_with(".atlassian.net") && !host.ends_with(".jira.com") {
+        return None;
+    }
+    if segments.first() == Some(&"wiki") {
+        // /wiki/spaces/KEY/pages/123/Title, /wiki/pages/viewpage.action?pageId=123
+        let id = segments
+            .windows(2)
+            .find(|w| w[0] == "pages" && w[1].chars().all(|c| c.is_ascii_digit()))
+            .map(|w| w[1].to_string())
+            .or_else(|| query("pageId").filter(|id| id.chars().all(|c| c.is_ascii_digit())))?;
+        return Some(ReferenceTarget { source: ReferenceSource::Confluence, id, site: Some(host) });
+    }
+    // /browse/ABC-123, 보드/목록 화면의 ?selectedIssue=ABC-123
+    let key = segments
+        .windows(2)
+        .find(|w| w[0] == "browse")
+        .map(|w| w[1].to_string())
+        .or_else(|| query("selectedIssue"))
+        .filter(|key| is_jira_key(key))?;
+    Some(ReferenceTarget {
+        source: ReferenceSource::Jira,
+        id: key.to_ascii_uppercase(),
+        site: Some(host),
+    })
+}
+
+/// 붙여 넣은 Notion/Confluence/Jira 링크 내용 가져오기
+#[tauri::command]
+pub async fn resolve_reference_url(app: AppHandle, args: ResolveReferenceUrlArgs) -> CommandResult<ResolvedReference> {
+    crate::crash::guard(&app, "resolve_reference_url", async {
+        let target = detect_reference(&args.url).ok_or_else(|| {
+            CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Not a Notion, Confluence or Jira link: {}", args.url),
+            )
+        })?;
+        let cjk_factor = words::cjk_factor(args.language.as_deref());
+
+        let (title, content, word_count, truncated) = match target.source {
+            ReferenceSource::Notion => {
+                crate::network::ensure_online("Notion")?;
+                let notion_error = |message: String| CommandError::new(ErrorCode::NotionError, message);
+                let page = NOTION_CLIENT.get_page(&target.id).await.map_err(notion_error)?;
+                let (mut content, limited) = NOTION_CLIENT
+                    .page_text(&target.id, crate::http::max_document_bytes())
+                    .await
+                    .map_err(notion_error)?;
+                let title = page
+                    .properties
+                    .as_object()
+                    .and_then(|props| {
+                        props
+                            .values()
+                            .find(|p| p.get("type").and_then(|t| t.as_str()) == Some("title"))
+                    })
+                    .and_then(crate::sync::notion::property_text)
+                    .unwrap_or_default();
+                let truncated = crate::http::truncate_utf8(&mut content, crate::http::max_document_bytes()) || limited;
+                let word_count = words::count_text(&content, cjk_factor);
+                (title, content, word_count, truncated)
+            }
+            ReferenceSource::Confluence => {
+                let page = fetch_page(&target.id, target.site.as_deref()).await?;
+                let converted = convert_storage(&page.body, args.format, cjk_factor);
+                (page.title, converted.content, converted.word_count, page.truncated)
+            }
+            ReferenceSource::Jira => {
+                let issue = fetch_jira_issue(&target.id, target.site.as_deref()).await?;
+                let title = format!("{}: {}", issue.key, issue.summary);
+                let mut content = String::new();
+                if let Some(issue_type) = &issue.issue_type {
+                    content.push_str(&format!("Type: {}\n", issue_type));
+                }
+                if let Some(status) = &issue.status {
+                    content.push_str(&format!("Status: {}\n", status));
+                }
+                if !content.is_empty() && !issue.description.is_empty() {
+                    content.push('\n');
+                }
+                content.push_str(&issue.description);
+                let word_count = words::count_text(&format!("{}\n{}", issue.summary, issue.description), cjk_factor);
+                (title, content, word_count, issue.truncated)
+            }
+        };
+
+        Ok(ResolvedReference {
+            url: args.url.trim().to_string(),
+            source: target.source,
+            id: target.id,
+            title,
+            content,
+            word_count,
+            truncated,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(url: &str) -> Option<(ReferenceSource, String)> {
+        detect_reference(url).map(|t| (t.source, t.id))
+    }
+
+    #[test]
+    fn test_detect_notion() {
+        assert_eq!(
+            detect("https://www.notion.so/acme/Style-Guide-1234567890abcdef1234567890ABCDEF?pvs=4"),
+            Some((ReferenceSource::Notion, "1234567890abcdef1234567890abcdef".to_string()))
+        );
+        assert_eq!(
+            detect("https://acme.notion.site/db-0000?p=12345678-90ab-cdef-1234-567890abcdef&pm=s"),
+            Some((ReferenceSource::Notion, "1234567890abcdef1234567890abcdef".to_string()))
+        );
+        assert_eq!(detect("https://www.notion.so/acme"), None);
+    }
+
+    #[test]
+    fn test_detect_confluence_and_jira() {
+        assert_eq!(
+            detect("https://acme.atlassian.net/wiki/spaces/DOC/pages/123456/Release+Notes"),
+            Some((ReferenceSource::Confluence, "123456".to_string()))
+        );
+        assert_eq!(
+            detect_reference("https://Acme.atlassian.net/browse/LOC-1").and_then(|t| t.site),
+            Some("acme.atlassian.net".to_string())
+        );
+        assert_eq!(
+            detect("https://acme.atlassian.net/wiki/pages/viewpage.action?pageId=987"),
+            Some((ReferenceSource::Confluence, "987".to_string()))
+        );
+        assert_eq!(
+            detect("https://acme.atlassian.net/browse/loc-42"),
+            Some((ReferenceSource::Jira, "LOC-42".to_string()))
+        );
+        assert_eq!(
+            detect("https://acme.atlassian.net/jira/software/projects/LOC/boards/1?selectedIssue=LOC-7"),
+            Some((ReferenceSource::Jira, "LOC-7".to_string()))
+        );
+        assert_eq!(detect("https://acme.atlassian.net/wiki/x/AbCd"), None);
+        assert_eq!(detect("https://example.com/browse/LOC-1"), None);
+    }
+}
@@ -0,0 +1,95 @@
+//! Repetition Commands
+//!
+//! 반복 세그먼트(원문이 같은 세그먼트) 탐지 및 번역 자동 전파 API
+
+use serde::Deserialize;
+use tauri::{AppHandle, State, Window};
+
+use crate::db::{DbState, PropagationResult, RepetitionGroup};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+use super::window::broadcast_block_change;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindRepetitionsArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagateTranslationArgs {
+    pub project_id: String,
+    /// 확정된 번역을 가진 기준 세그먼트 (이 세그먼트의 원문으로 그룹을 결정)
+    pub source_segment_id: String,
+    /// 없으면 그룹 전체에 전파
+    pub segment_ids: Option<Vec<String>>,
+    /// true면 이미 번역된 세그먼트도 덮어씀 (기본 false)
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSegmentPropagationOptOutArgs {
+    pub project_id: String,
+    pub segment_id: String,
+    pub opt_out: bool,
+}
+
+/// 반복 세그먼트 그룹 조회
+#[tauri::command]
+pub fn find_repetitions(
+    args: FindRepetitionsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<RepetitionGroup>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.find_repetitions(&args.project_id)
+        .map_err(CommandError::from)
+}
+
+/// 기준 세그먼트의 번역을 반복 세그먼트에 전파
+#[tauri::command]
+pub fn propagate_translation(
+    app: AppHandle,
+    window: Window,
+    args: PropagateTranslationArgs,
+    db_state: State<DbState>,
+) -> CommandResult<PropagationResult> {
+    let result = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.propagate_translation(
+            &args.project_id,
+            &args.source_segment_id,
+            args.segment_ids.as_deref(),
+            args.overwrite,
+        )
+        .map_err(CommandError::from)?
+    };
+
+    broadcast_block_change(&app, Some(window.label()), &args.project_id, result.updated_block_ids.clone());
+    Ok(result)
+}
+
+/// 세그먼트별 자동 전파 제외 설정
+#[tauri::command]
+pub fn set_segment_propagation_opt_out(
+    args: SetSegmentPropagationOptOutArgs,
+    db_state: State<DbState>,
+) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_segment_propagation_opt_out(&args.project_id, &args.segment_id, args.opt_out)
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,469 @@
+//! Report Commands
+//!
+//! 편집 기록 기반 생산성(처리량) 리포트 조회 및 CSV 내보내기, 고객 전달용 리뷰 리포트/대역 DOCX/번역문 HTML 내보내기,
+//! 반복/TM 분석 기반 견적 계산 및 내보내기, 백그라운드 TM 활용도 분석
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::db::{DbState, ProductivityRow};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::commands::qa::{collect_qa_issues, ALL_CHECKS};
+use crate::export::bilingual::{collect_bilingual_rows, render_bilingual_docx, summarize, BilingualSummary};
+use crate::export::productivity::render_productivity_csv;
+use crate::export::quote::{build_quote, render_quote, Quote, QuoteFormat, QuoteRates};
+use crate::export::review::{collect_review_rows, count_rows, render_review_report, ReviewCounts, ReviewReportFormat};
+use crate::export::target::render_target_html;
+use crate::models::{IteProject, TmUnit};
+use crate::text::analysis::{analyze_project, ProjectAnalysis};
+use crate::utils::validate_path;
+
+/// 분석에 쓰는 TM 단위 최대 개수 (최근 수정 순)
+const MAX_ANALYSIS_TM_UNITS: usize = 50_000;
+
+/// TM 분석 진행/완료 이벤트
+pub const TM_ANALYSIS_PROGRESS_EVENT: &str = "tm-analysis-progress";
+
+/// 실행 중인 TM 분석 작업 (project_id → job_id)
+#[derive(Default)]
+pub struct TmAnalysisJobs(Mutex<HashMap<String, String>>);
+
+/// 분석 작업이 끝나면(패닉 포함) 실행 중 목록에서 프로젝트를 지움
+struct TmAnalysisJobGuard {
+    app: AppHandle,
+    project_id: String,
+}
+
+impl Drop for TmAnalysisJobGuard {
+    fn drop(&mut self) {
+        if let Ok(mut jobs) = self.app.state::<TmAnalysisJobs>().0.lock() {
+            jobs.remove(&self.project_id);
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductivityReportArgs {
+    /// "day" | "week" | "month" | "year" | "all" (최근 1/7/30/365일, 전체)
+    pub period: String,
+    /// 주어지면 해당 프로젝트만 집계
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProductivityReportArgs {
+    #[serde(flatten)]
+    pub report: ProductivityReportArgs,
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportReviewReportArgs {
+    pub project_id: String,
+    /// 확장자로 형식 결정 (.xlsx | .html)
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportBilingualDocxArgs {
+    pub project_id: String,
+    /// .docx 경로
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportTargetHtmlArgs {
+    pub project_id: String,
+    /// 주어지면 .html 파일로 저장
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetHtmlExport {
+    /// 인쇄용 HTML 문서 (프론트엔드가 웹뷰에서 인쇄해 PDF로 저장)
+    pub html: String,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateQuoteArgs {
+    pub project_id: String,
+    pub rates: QuoteRates,
+    /// 주어지면 확장자(.csv | .html)에 맞춰 파일로 저장
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzeProjectArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmAnalysisJob {
+    pub job_id: String,
+    /// 같은 프로젝트 분석이 이미 실행 중이어서 기존 작업을 돌려준 경우
+    pub already_running: bool,
+}
+
+/// `tm-analysis-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmAnalysisProgressEvent {
+    pub job_id: String,
+    pub project_id: String,
+    /// "analyzing" | "completed"
+    pub stage: String,
+    /// 처리한 세그먼트 수
+    pub processed: usize,
+    pub total: usize,
+    /// 완료 시 분석 결과
+    pub analysis: Option<ProjectAnalysis>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductivityReport {
+    pub period: String,
+    /// 집계 시작 (Unix epoch ms)
+    pub from: i64,
+    /// 집계 끝 (Unix epoch ms, 미포함)
+    pub to: i64,
+    pub rows: Vec<ProductivityRow>,
+    pub total_words: i64,
+    pub total_blocks_edited: i64,
+}
+
+fn period_days(period: &str) -> CommandResult<Option<i64>> {
+    match period {
+        "day" => Ok(Some(1)),
+        "week" => Ok(Some(7)),
+        "month" => Ok(Some(30)),
+        "year" => Ok(Some(365)),
+        "all" => Ok(None),
+        other => Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            format!("Unknown report period: {}", other),
+        ).with_details("period must be one of: day, week, month, year, all")),
+    }
+}
+
+fn build_productivity_report(
+    args: &ProductivityReportArgs,
+    db_state: &State<DbState>,
+) -> CommandResult<ProductivityReport> {
+    let days = period_days(&args.period)?;
+    let to = chrono::Utc::now().timestamp_millis() + 1;
+    let from = days.map(|d| to - d * 24 * 60 * 60 * 1000).unwrap_or(0);
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let rows = db
+        .productivity_rows(from, to, args.project_id.as_deref())
+        .map_err(CommandError::from)?;
+
+    Ok(ProductivityReport {
+        period: args.period.clone(),
+        from,
+        to,
+        total_words: rows.iter().map(|r| r.words_translated).sum(),
+        total_blocks_edited: rows.iter().map(|r| r.blocks_edited).sum(),
+        rows,
+    })
+}
+
+/// 기간별 생산성 리포트 (일자·프로젝트별 번역 단어 수)
+#[tauri::command]
+pub fn get_productivity_report(
+    args: ProductivityReportArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ProductivityReport> {
+    build_productivity_report(&args, &db_state)
+}
+
+/// 생산성 리포트를 CSV 파일로 내보내기
+#[tauri::command]
+pub fn export_productivity_report(
+    args: ExportProductivityReportArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ProductivityReport> {
+    let path = validate_path(&args.path)?;
+    let report = build_productivity_report(&args.report, &db_state)?;
+
+    std::fs::write(&path, render_productivity_csv(&report.rows)).map_err(|e| CommandError::new(
+        ErrorCode::WriteError,
+        format!("Failed to write report: {}", e),
+    ))?;
+
+    Ok(report)
+}
+
+/// 리뷰 리포트 내보내기 (미해결 코멘트, QA 이슈, 검토 대기 변경을 세그먼트 번호 순으로)
+#[tauri::command]
+pub fn export_review_report(
+    args: ExportReviewReportArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ReviewCounts> {
+    let path = validate_path(&args.path)?;
+    let format = ReviewReportFormat::from_path(&path).ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unsupported report format: {}", args.path),
+    ).with_details("path must end with .xlsx or .html"))?;
+
+    let checks: Vec<String> = ALL_CHECKS.iter().map(|c| c.to_string()).collect();
+    let (project, issues) = collect_qa_issues(&db_state, &args.project_id, &checks)?;
+    let revisions = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.list_revisions(&args.project_id, Some("pending"))
+            .map_err(CommandError::from)?
+    };
+
+    let rows = collect_review_rows(&project, &issues, &revisions);
+    let rendered =
+        render_review_report(&project.metadata.title, &rows, format).map_err(CommandError::from)?;
+    std::fs::write(&path, rendered).map_err(|e| CommandError::new(
+        ErrorCode::WriteError,
+        format!("Failed to write report: {}", e),
+    ))?;
+
+    Ok(count_rows(&rows))
+}
+
+/// Word 검토용 대역 DOCX 내보내기 (세그먼트 번호, 원문, 번역문, 상태, 미해결 코멘트)
+#[tauri::command]
+pub fn export_bilingual_docx(
+    args: ExportBilingualDocxArgs,
+    db_state: State<DbState>,
+) -> CommandResult<BilingualSummary> {
+    let path = validate_path(&args.path)?;
+    let is_docx = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("docx"));
+    if !is_docx {
+        return Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            format!("Unsupported document format: {}", args.path),
+        ).with_details("path must end with .docx"));
+    }
+
+    let (project, revisions, notes) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        (
+            db.load_project(&args.project_id).map_err(CommandError::from)?,
+            db.list_revisions(&args.project_id, Some("pending"))
+                .map_err(CommandError::from)?,
+            db.segment_note_map(&args.project_id).map_err(CommandError::from)?,
+        )
+    };
+
+    let rows = collect_bilingual_rows(&project, &revisions, &notes);
+    let rendered = render_bilingual_docx(&project.metadata.title, &rows).map_err(CommandError::from)?;
+    std::fs::write(&path, rendered).map_err(|e| CommandError::new(
+        ErrorCode::WriteError,
+        format!("Failed to write document: {}", e),
+    ))?;
+
+    Ok(summarize(&rows))
+}
+
+/// 번역문 인쇄용 HTML 내보내기 (메타데이터 표지 + 세그먼트 순서의 번역문 블록)
+/// - PDF는 돌려준 HTML을 웹뷰에서 인쇄해 만듭니다.
+#[tauri::command]
+pub fn export_target_html(args: ExportTargetHtmlArgs, db_state: State<DbState>) -> CommandResult<TargetHtmlExport> {
+    let path = match args.path.as_deref() {
+        Some(raw) => {
+            let path = validate_path(raw)?;
+            let is_html = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e.eq_ignore_ascii_case("html") || e.eq_ignore_ascii_case("htm"));
+            if !is_html {
+                return Err(CommandError::new(
+                    ErrorCode::InvalidInput,
+                    format!("Unsupported document format: {}", raw),
+                ).with_details("path must end with .html"));
+            }
+            Some(path)
+        }
+        None => None,
+    };
+
+    let (project, source_language, custom_fields) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+        let default_source = db.load_app_settings().map_err(CommandError::from)?.default_source_language;
+        let source_language = project.metadata.source_language_or(default_source);
+        let custom_fields = db
+            .get_project_custom_fields(&args.project_id)
+            .map_err(CommandError::from)?;
+        (project, source_language, custom_fields)
+    };
+
+    let html = render_target_html(&project, source_language.as_deref(), &custom_fields);
+    if let Some(path) = &path {
+        std::fs::write(path, &html).map_err(|e| CommandError::new(
+            ErrorCode::WriteError,
+            format!("Failed to write document: {}", e),
+        ))?;
+    }
+
+    Ok(TargetHtmlExport {
+        html,
+        path: path.map(|p| p.to_string_lossy().to_string()),
+    })
+}
+
+/// 분석 입력 (프로젝트, 원문 언어(프로젝트 로캘 > 설정 기본값), 프로젝트에 적용되는 TM 단위)
+fn load_analysis_inputs(
+    db_state: &DbState,
+    project_id: &str,
+) -> CommandResult<(IteProject, Option<String>, Vec<TmUnit>)> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    let project = db.load_project(project_id).map_err(CommandError::from)?;
+    let default_source = db.load_app_settings().map_err(CommandError::from)?.default_source_language;
+    let source_language = project.metadata.source_language_or(default_source);
+    let units = db
+        .list_tm_units(project_id, MAX_ANALYSIS_TM_UNITS)
+        .map_err(CommandError::from)?;
+    Ok((project, source_language, units))
+}
+
+/// 프로젝트 견적 계산 (신규/반복/TM 매치 구간별 단어 수 × 단가 × 가중치)
+/// - `path`가 있으면 CSV 또는 인쇄용 HTML로 저장합니다 (PDF는 HTML을 인쇄해 만듦).
+#[tauri::command]
+pub fn generate_quote(args: GenerateQuoteArgs, db_state: State<DbState>) -> CommandResult<Quote> {
+    if !args.rates.per_word.is_finite() || args.rates.per_word < 0.0 {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "perWord must be a non-negative number"));
+    }
+    let target = match args.path.as_deref() {
+        Some(raw) => {
+            let path = validate_path(raw)?;
+            let format = QuoteFormat::from_path(&path).ok_or_else(|| CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Unsupported quote format: {}", raw),
+            ).with_details("path must end with .csv or .html"))?;
+            Some((path, format))
+        }
+        None => None,
+    };
+
+    let (project, source_language, units) = load_analysis_inputs(&db_state, &args.project_id)?;
+    let analysis = analyze_project(&project, &units, source_language.as_deref(), |_, _| {});
+    let mut quote = build_quote(
+        &args.project_id,
+        &project.metadata.title,
+        project.metadata.target_language.as_deref(),
+        &analysis,
+        &args.rates,
+    );
+
+    if let Some((path, format)) = target {
+        std::fs::write(&path, render_quote(&quote, format)).map_err(|e| CommandError::new(
+            ErrorCode::WriteError,
+            format!("Failed to write quote: {}", e),
+        ))?;
+        quote.path = Some(path.to_string_lossy().to_string());
+    }
+
+    Ok(quote)
+}
+
+/// TM 활용도 분석 시작 (101% / 100% / 퍼지 구간 / 일치 없음별 세그먼트·단어 수)
+/// - 백그라운드에서 실행하고 `tm-analysis-progress` 이벤트로 진행 상황과 결과를 보냅니다.
+/// - 같은 프로젝트 분석이 이미 실행 중이면 그 작업 ID를 돌려줍니다.
+#[tauri::command]
+pub fn analyze_project_against_tm(
+    app: AppHandle,
+    args: AnalyzeProjectArgs,
+    db_state: State<DbState>,
+    jobs: State<TmAnalysisJobs>,
+) -> CommandResult<TmAnalysisJob> {
+    let mut running = jobs.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire analysis job lock: {}", e),
+    ))?;
+    if let Some(job_id) = running.get(&args.project_id) {
+        return Ok(TmAnalysisJob {
+            job_id: job_id.clone(),
+            already_running: true,
+        });
+    }
+
+    let (project, source_language, units) = load_analysis_inputs(&db_state, &args.project_id)?;
+    let job_id = uuid::Uuid::new_v4().to_string();
+    running.insert(args.project_id.clone(), job_id.clone());
+    drop(running);
+
+    let project_id = args.project_id;
+    let job = TmAnalysisJob {
+        job_id: job_id.clone(),
+        already_running: false,
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        let guard = TmAnalysisJobGuard {
+            app: app.clone(),
+            project_id: project_id.clone(),
+        };
+        let started = std::time::Instant::now();
+        let event = |stage: &str, processed: usize, total: usize| TmAnalysisProgressEvent {
+            job_id: job_id.clone(),
+            project_id: project_id.clone(),
+            stage: stage.to_string(),
+            processed,
+            total,
+            analysis: None,
+        };
+
+        // 진행 이벤트는 1% 단위로만 보냄
+        let analysis = analyze_project(&project, &units, source_language.as_deref(), |processed, total| {
+            let step = (total / 100).max(1);
+            if processed % step == 0 || processed == total {
+                let _ = app.emit(TM_ANALYSIS_PROGRESS_EVENT, event("analyzing", processed, total));
+            }
+        });
+        println!(
+            "[Analysis] Analyzed {} segments of {} against TM in {} ms",
+            analysis.segments,
+            project_id,
+            started.elapsed().as_millis()
+        );
+        let segments = analysis.segments;
+        let done = TmAnalysisProgressEvent {
+            analysis: Some(analysis),
+            ..event("completed", segments, segments)
+        };
+
+        // 완료 이벤트를 받은 화면이 바로 다시 분석할 수 있도록 먼저 목록에서 지움
+        drop(guard);
+        let _ = app.emit(TM_ANALYSIS_PROGRESS_EVENT, done);
+    });
+
+    Ok(job)
+}
@@ -0,0 +1,181 @@
+//! Revision Commands
+//!
+//! 리뷰어 변경 추적: 번역문 수정 기록, 수락/거절, 변경 내역 내보내기
+
+use serde::Deserialize;
+use tauri::{AppHandle, State, Window};
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::export::revisions::{render_revisions, RevisionExportFormat, RevisionExportRow};
+use crate::export::segment_numbers;
+use crate::models::Revision;
+use crate::utils::validate_path;
+
+use super::window::broadcast_block_change;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordRevisionArgs {
+    pub project_id: String,
+    pub block_id: String,
+    pub revised_content: String,
+    pub author: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRevisionsArgs {
+    pub project_id: String,
+    /// "pending" | "accepted" | "rejected" (없으면 전체)
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevisionIdArgs {
+    pub revision_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRevisionsArgs {
+    pub project_id: String,
+    pub path: String,
+    /// "html" | "csv"
+    pub format: String,
+    pub status: Option<String>,
+}
+
+fn validate_status(status: Option<&str>) -> CommandResult<()> {
+    match status {
+        None | Some("pending") | Some("accepted") | Some("rejected") => Ok(()),
+        Some(other) => Err(CommandError::new(
+            ErrorCode::InvalidInput,
+            format!("Unknown revision status: {}", other),
+        ).with_details("status must be one of: pending, accepted, rejected")),
+    }
+}
+
+/// 리뷰어 수정 기록 (블록에 반영 + 변경 추적 항목 생성/갱신)
+/// - 수정이 원래 내용으로 되돌아가면 항목이 사라지고 None을 반환합니다.
+#[tauri::command]
+pub fn record_revision(
+    app: AppHandle,
+    window: Window,
+    args: RecordRevisionArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Option<Revision>> {
+    let revision = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.record_revision(
+            &args.project_id,
+            &args.block_id,
+            &args.revised_content,
+            args.author.as_deref(),
+        )
+        .map_err(CommandError::from)?
+    };
+
+    broadcast_block_change(&app, Some(window.label()), &args.project_id, vec![args.block_id]);
+    Ok(revision)
+}
+
+/// 변경 추적 목록 조회
+#[tauri::command]
+pub fn list_revisions(
+    args: ListRevisionsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<Revision>> {
+    validate_status(args.status.as_deref())?;
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_revisions(&args.project_id, args.status.as_deref())
+        .map_err(CommandError::from)
+}
+
+/// 수정 수락
+#[tauri::command]
+pub fn accept_revision(args: RevisionIdArgs, db_state: State<DbState>) -> CommandResult<Revision> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.accept_revision(&args.revision_id).map_err(CommandError::from)
+}
+
+/// 수정 거절 (블록을 수정 전 내용으로 복원)
+#[tauri::command]
+pub fn reject_revision(
+    app: AppHandle,
+    window: Window,
+    args: RevisionIdArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Revision> {
+    let revision = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        db.reject_revision(&args.revision_id).map_err(CommandError::from)?
+    };
+
+    broadcast_block_change(
+        &app,
+        Some(window.label()),
+        &revision.project_id,
+        vec![revision.block_id.clone()],
+    );
+    Ok(revision)
+}
+
+/// 리뷰어 변경 내역을 HTML/CSV 파일로 내보내기 (세그먼트 순서)
+#[tauri::command]
+pub fn export_revisions(args: ExportRevisionsArgs, db_state: State<DbState>) -> CommandResult<usize> {
+    let format = RevisionExportFormat::parse(&args.format).ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unsupported export format: {}", args.format),
+    ).with_details("format must be 'html' or 'csv'"))?;
+    validate_status(args.status.as_deref())?;
+    let out_path = validate_path(&args.path)?;
+
+    let (project, revisions) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+        let revisions = db
+            .list_revisions(&args.project_id, args.status.as_deref())
+            .map_err(CommandError::from)?;
+        (project, revisions)
+    };
+
+    let numbers = segment_numbers(&project);
+    let mut rows: Vec<RevisionExportRow> = revisions
+        .iter()
+        .map(|revision| RevisionExportRow {
+            segment_no: numbers.get(&revision.block_id).copied(),
+            revision,
+        })
+        .collect();
+    rows.sort_by_key(|r| (r.segment_no.unwrap_or(usize::MAX), r.revision.created_at));
+
+    let rendered = render_revisions(&project.metadata.title, &rows, format);
+    std::fs::write(&out_path, rendered).map_err(|e| CommandError::new(
+        ErrorCode::WriteError,
+        format!("Failed to write revisions: {}", e),
+    ))?;
+
+    Ok(rows.len())
+}
@@ -4,8 +4,9 @@
 //! (기존 keyring 직접 사용에서 SecretManager로 마이그레이션됨)
 
 use serde::Deserialize;
+use tauri::AppHandle;
 
-use crate::error::{CommandError, CommandResult};
+use crate::error::{CommandError, CommandResult, ErrorCode};
 use crate::secrets::SECRETS;
 
 #[derive(Debug, Deserialize)]
@@ -16,20 +17,12 @@ pub struct SecureSecretArgs {
 }
 
 fn map_secret_error(err: crate::secrets::manager::SecretManagerError) -> CommandError {
-    CommandError {
-        code: "SECURE_STORE_ERROR".to_string(),
-        message: format!("Secure store error: {}", err),
-        details: None,
-    }
+    CommandError::new(ErrorCode::SecureStoreError, format!("Secure store error: {}", err))
 }
 
 fn validate_key(key: &str) -> Result<(), CommandError> {
     if key.trim().is_empty() {
-        return Err(CommandError {
-            code: "INVALID_KEY".to_string(),
-            message: "Secure store key must not be empty.".to_string(),
-            details: None,
-        });
+        return Err(CommandError::new(ErrorCode::InvalidKey, "Secure store key must not be empty."));
     }
     Ok(())
 }
@@ -41,33 +34,42 @@ fn to_vault_key(key: &str) -> String {
 }
 
 #[tauri::command]
-pub async fn set_secure_secret(args: SecureSecretArgs) -> CommandResult<()> {
-    validate_key(&args.key)?;
-    let vault_key = to_vault_key(&args.key);
-    SECRETS
-        .set(&vault_key, &args.value)
-        .await
-        .map_err(map_secret_error)?;
-    Ok(())
+pub async fn set_secure_secret(app: AppHandle, args: SecureSecretArgs) -> CommandResult<()> {
+    crate::crash::guard(&app, "set_secure_secret", async {
+        validate_key(&args.key)?;
+        let vault_key = to_vault_key(&args.key);
+        SECRETS
+            .set(&vault_key, &args.value)
+            .await
+            .map_err(map_secret_error)?;
+        Ok(())
+    })
+    .await
 }
 
 #[tauri::command]
-pub async fn get_secure_secret(key: String) -> CommandResult<Option<String>> {
-    validate_key(&key)?;
-    let vault_key = to_vault_key(&key);
-    SECRETS
-        .get(&vault_key)
-        .await
-        .map_err(map_secret_error)
+pub async fn get_secure_secret(app: AppHandle, key: String) -> CommandResult<Option<String>> {
+    crate::crash::guard(&app, "get_secure_secret", async {
+        validate_key(&key)?;
+        let vault_key = to_vault_key(&key);
+        SECRETS
+            .get(&vault_key)
+            .await
+            .map_err(map_secret_error)
+    })
+    .await
 }
 
 #[tauri::command]
-pub async fn delete_secure_secret(key: String) -> CommandResult<()> {
-    validate_key(&key)?;
-    let vault_key = to_vault_key(&key);
-    SECRETS
-        .delete(&vault_key)
-        .await
-        .map_err(map_secret_error)?;
-    Ok(())
+pub async fn delete_secure_secret(app: AppHandle, key: String) -> CommandResult<()> {
+    crate::crash::guard(&app, "delete_secure_secret", async {
+        validate_key(&key)?;
+        let vault_key = to_vault_key(&key);
+        SECRETS
+            .delete(&vault_key)
+            .await
+            .map_err(map_secret_error)?;
+        Ok(())
+    })
+    .await
 }
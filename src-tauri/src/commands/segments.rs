@@ -0,0 +1,151 @@
+//! Segment Commands
+//!
+//! 대형 프로젝트 가상화 그리드용 세그먼트 페이지 조회, 선택 세그먼트 클립보드 복사, 세그먼트 메모
+
+use serde::Deserialize;
+use tauri::{AppHandle, State};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::db::{DbState, SegmentFilter, SegmentNote, SegmentPage, SegmentSort, DEFAULT_SEGMENT_PAGE_SIZE};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::export::clipboard::{collect_segment_rows, render_segment_table, ClipboardFormat};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSegmentsArgs {
+    pub project_id: String,
+    #[serde(default)]
+    pub filter: SegmentFilter,
+    #[serde(default)]
+    pub sort: SegmentSort,
+    #[serde(default)]
+    pub offset: usize,
+    /// 기본 100, 최대 500
+    pub limit: Option<usize>,
+}
+
+/// 세그먼트 목록 페이지 조회 (블록 미리보기/상태 포함, load_project 불필요)
+#[tauri::command]
+pub fn list_segments(args: ListSegmentsArgs, db_state: State<DbState>) -> CommandResult<SegmentPage> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_segments(
+        &args.project_id,
+        &args.filter,
+        args.sort,
+        args.offset,
+        args.limit.unwrap_or(DEFAULT_SEGMENT_PAGE_SIZE),
+    )
+    .map_err(CommandError::from)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopySegmentsToClipboardArgs {
+    pub project_id: String,
+    /// 생략하면 전체 세그먼트
+    pub segment_ids: Option<Vec<String>>,
+    /// "tsv" | "markdown" | "html" (기본 "tsv")
+    pub format: Option<String>,
+}
+
+/// 선택한 세그먼트의 원문/번역문 쌍을 표로 클립보드에 복사 (복사한 행 수 반환)
+/// - html은 TSV를 대체 텍스트로 함께 넣어, HTML을 받지 못하는 곳에도 붙여 넣을 수 있습니다.
+#[tauri::command]
+pub fn copy_segments_to_clipboard(
+    app: AppHandle,
+    args: CopySegmentsToClipboardArgs,
+    db_state: State<DbState>,
+) -> CommandResult<usize> {
+    let format_name = args.format.as_deref().unwrap_or("tsv");
+    let format = ClipboardFormat::parse(format_name).ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unsupported clipboard format: {}", format_name),
+    ).with_details("format must be 'tsv', 'markdown' or 'html'"))?;
+
+    let project = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.load_project(&args.project_id).map_err(CommandError::from)?
+    };
+
+    let rows = collect_segment_rows(&project, args.segment_ids.as_deref());
+    let clipboard_error = |e: tauri_plugin_clipboard_manager::Error| CommandError::new(
+        ErrorCode::ClipboardError,
+        format!("Failed to write clipboard: {}", e),
+    );
+    match format {
+        ClipboardFormat::Html => {
+            let html = render_segment_table(&rows, ClipboardFormat::Html);
+            let plain = render_segment_table(&rows, ClipboardFormat::Tsv);
+            app.clipboard().write_html(html, Some(plain)).map_err(clipboard_error)?;
+        }
+        _ => {
+            app.clipboard()
+                .write_text(render_segment_table(&rows, format))
+                .map_err(clipboard_error)?;
+        }
+    }
+    Ok(rows.len())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSegmentNoteArgs {
+    pub project_id: String,
+    pub segment_id: String,
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentNoteArgs {
+    pub project_id: String,
+    pub segment_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSegmentNotesArgs {
+    pub project_id: String,
+}
+
+/// 세그먼트 번역가 메모 설정 (세그먼트당 하나, 있으면 덮어씀)
+#[tauri::command]
+pub fn set_segment_note(args: SetSegmentNoteArgs, db_state: State<DbState>) -> CommandResult<SegmentNote> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_segment_note(&args.project_id, &args.segment_id, &args.note)
+        .map_err(CommandError::from)
+}
+
+/// 세그먼트 번역가 메모 삭제 (삭제했으면 true)
+#[tauri::command]
+pub fn clear_segment_note(args: SegmentNoteArgs, db_state: State<DbState>) -> CommandResult<bool> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.clear_segment_note(&args.project_id, &args.segment_id)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트의 세그먼트 메모 목록 (세그먼트 순서)
+#[tauri::command]
+pub fn list_segment_notes(args: ListSegmentNotesArgs, db_state: State<DbState>) -> CommandResult<Vec<SegmentNote>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_segment_notes(&args.project_id).map_err(CommandError::from)
+}
@@ -0,0 +1,150 @@
+//! App Settings Commands
+//!
+//! 앱 전역 설정(기본 언어쌍, 자동 저장 간격, 백업 스케줄, 기본 검색 제공자 등) 조회/변경 API
+//! - 변경되면 `app-settings-changed` 이벤트를 발송합니다.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::AppSettings;
+
+/// 설정 변경 이벤트 이름
+pub const APP_SETTINGS_CHANGED_EVENT: &str = "app-settings-changed";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAppSettingArgs {
+    pub key: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAppSettingArgs {
+    pub key: String,
+    /// null이면 기본값으로 되돌림
+    pub value: Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAppSettingsArgs {
+    /// 변경할 설정만 담은 부분 객체
+    pub patch: Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetAppSettingsArgs {
+    /// 없으면 전체 초기화
+    pub keys: Option<Vec<String>>,
+}
+
+/// `app-settings-changed` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSettingsChangedEvent {
+    pub changed_keys: Vec<String>,
+    pub settings: AppSettings,
+}
+
+fn emit_settings_changed(app: &AppHandle, changed_keys: Vec<String>, settings: &AppSettings) {
+    if changed_keys.is_empty() {
+        return;
+    }
+    let _ = app.emit(
+        APP_SETTINGS_CHANGED_EVENT,
+        AppSettingsChangedEvent {
+            changed_keys,
+            settings: settings.clone(),
+        },
+    );
+    crate::i18n::apply_language(&settings.ui_language);
+    crate::network::set_offline_mode(settings.offline_mode);
+    crate::http::apply_settings(&settings.http);
+    crate::mcp::cache::set_ttl_secs(settings.mcp_cache_ttl_secs);
+    super::tray::refresh_tray(app);
+    super::capture::apply_capture_shortcut(app);
+}
+
+/// 전역 설정 전체 조회
+#[tauri::command]
+pub fn get_app_settings(db_state: State<DbState>) -> CommandResult<AppSettings> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.load_app_settings().map_err(CommandError::from)
+}
+
+/// 전역 설정 단일 값 조회
+#[tauri::command]
+pub fn get_app_setting(args: GetAppSettingArgs, db_state: State<DbState>) -> CommandResult<Value> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let settings = db.load_app_settings().map_err(CommandError::from)?;
+    let value = serde_json::to_value(settings).map_err(|e| CommandError::from(crate::error::IteError::from(e)))?;
+    value.get(&args.key).cloned().ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unknown setting: {}", args.key),
+    ))
+}
+
+/// 전역 설정 단일 값 변경
+#[tauri::command]
+pub fn set_app_setting(
+    args: SetAppSettingArgs,
+    app: AppHandle,
+    db_state: State<DbState>,
+) -> CommandResult<AppSettings> {
+    let mut patch = Map::new();
+    patch.insert(args.key, args.value);
+    update_app_settings(UpdateAppSettingsArgs { patch }, app, db_state)
+}
+
+/// 전역 설정 부분 변경 (여러 키를 한 번에)
+#[tauri::command]
+pub fn update_app_settings(
+    args: UpdateAppSettingsArgs,
+    app: AppHandle,
+    db_state: State<DbState>,
+) -> CommandResult<AppSettings> {
+    let (settings, changed_keys) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.update_app_settings(&args.patch).map_err(CommandError::from)?
+    };
+
+    emit_settings_changed(&app, changed_keys, &settings);
+    Ok(settings)
+}
+
+/// 전역 설정 초기화 (keys가 없으면 전체)
+#[tauri::command]
+pub fn reset_app_settings(
+    args: ResetAppSettingsArgs,
+    app: AppHandle,
+    db_state: State<DbState>,
+) -> CommandResult<AppSettings> {
+    let (settings, changed_keys) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let changed = db
+            .reset_app_settings(args.keys.as_deref())
+            .map_err(CommandError::from)?;
+        (db.load_app_settings().map_err(CommandError::from)?, changed)
+    };
+
+    emit_settings_changed(&app, changed_keys, &settings);
+    Ok(settings)
+}
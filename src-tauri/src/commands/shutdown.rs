@@ -0,0 +1,109 @@
+//! Graceful Shutdown
+//!
+//! 앱 종료 요청(`RunEvent::ExitRequested`)을 잠시 미루고 하위 시스템을 순서대로 정리한 뒤 종료합니다.
+//! 1. 프론트엔드에 `app-shutdown-requested`를 보내 대기 중인 자동 저장을 마치게 함 (`shutdown_ready`로 응답)
+//! 2. MCP 서버 SSE 연결 해제
+//! 3. vault 파일 저장
+//! 4. DB WAL 체크포인트
+//! - 프론트엔드가 응답하지 않아도 일정 시간이 지나면 다음 단계로 넘어갑니다.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, ExitRequestApi, Manager, State};
+use tokio::sync::Notify;
+
+use crate::db::DbState;
+use crate::error::CommandResult;
+use crate::mcp::McpRegistry;
+use crate::secrets::SECRETS;
+
+/// 종료 준비 요청 이벤트 (프론트엔드가 자동 저장을 마친 뒤 `shutdown_ready` 호출)
+pub const APP_SHUTDOWN_REQUESTED_EVENT: &str = "app-shutdown-requested";
+
+/// 프론트엔드 저장을 기다리는 최대 시간
+const FRONTEND_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ShutdownPhase {
+    #[default]
+    Running,
+    Flushing,
+    Done,
+}
+
+/// 종료 정리 상태
+#[derive(Default)]
+pub struct ShutdownCoordinator {
+    phase: Mutex<ShutdownPhase>,
+    frontend_ready: Notify,
+}
+
+/// 종료 요청 처리 (lib.rs의 run 이벤트 핸들러에서 호출)
+/// - 정리가 끝난 뒤의 종료 요청만 통과시킵니다.
+pub fn handle_exit_requested(app: &AppHandle, code: Option<i32>, api: &ExitRequestApi) {
+    let coordinator = app.state::<ShutdownCoordinator>();
+    {
+        let mut phase = coordinator.phase.lock().unwrap_or_else(|e| e.into_inner());
+        match *phase {
+            ShutdownPhase::Done => return,
+            ShutdownPhase::Flushing => {
+                api.prevent_exit();
+                return;
+            }
+            ShutdownPhase::Running => {
+                *phase = ShutdownPhase::Flushing;
+                api.prevent_exit();
+            }
+        }
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        run_shutdown(&app).await;
+        let coordinator = app.state::<ShutdownCoordinator>();
+        *coordinator.phase.lock().unwrap_or_else(|e| e.into_inner()) = ShutdownPhase::Done;
+        app.exit(code.unwrap_or(0));
+    });
+}
+
+async fn run_shutdown(app: &AppHandle) {
+    // 1. 프론트엔드 자동 저장 (열린 창이 있을 때만)
+    if !app.webview_windows().is_empty() {
+        let coordinator = app.state::<ShutdownCoordinator>();
+        if app.emit(APP_SHUTDOWN_REQUESTED_EVENT, ()).is_ok()
+            && tokio::time::timeout(FRONTEND_FLUSH_TIMEOUT, coordinator.frontend_ready.notified())
+                .await
+                .is_err()
+        {
+            eprintln!("[Shutdown] Frontend did not confirm autosave in time");
+        }
+    }
+
+    // 2. SSE 연결 정리
+    McpRegistry::disconnect_all().await;
+
+    // 3. vault (쓰기마다 저장하지만 마지막으로 한 번 더)
+    if let Err(e) = SECRETS.flush().await {
+        eprintln!("[Shutdown] Failed to persist vault: {}", e);
+    }
+
+    // 4. WAL 반영 (다음 실행/백업 도구가 DB 파일 하나만 보면 되도록)
+    if let Some(db_state) = app.try_state::<DbState>() {
+        match db_state.0.lock() {
+            Ok(db) => {
+                if let Err(e) = db.checkpoint() {
+                    eprintln!("[Shutdown] WAL checkpoint failed: {}", e);
+                }
+            }
+            Err(e) => eprintln!("[Shutdown] Failed to acquire database lock: {}", e),
+        }
+    }
+}
+
+/// 프론트엔드가 종료 전 저장을 마쳤음을 알림
+#[tauri::command]
+pub fn shutdown_ready(coordinator: State<ShutdownCoordinator>) -> CommandResult<()> {
+    coordinator.frontend_ready.notify_one();
+    Ok(())
+}
@@ -1,15 +1,54 @@
 //! Storage Commands (.ite Import/Export)
 //!
-//! .ite 파일은 SQLite DB 자체를 패키징한 파일로 취급합니다.
+//! .ite 파일은 SQLite DB 자체를 패키징한 파일(v1) 또는
+//! DB + 첨부 파일을 담은 zip 컨테이너(v2, `crate::package`)로 취급합니다.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 use serde::Serialize;
 use tauri::{State, AppHandle, Manager};
 
-use crate::db::DbState;
-use crate::error::{CommandError, CommandResult};
+use super::notifications::{notify_job, JobKind};
+use super::attachments::temp_upload_dir;
+use super::maintenance::maybe_optimize_database;
+use super::qa::enqueue_block_qa;
+use super::window::broadcast_block_change;
+use crate::db::{
+    self, ConsoleQueryResult, CustomFieldFilter, Database, DbPerfStats, DbState, NewGlossaryTerm, OrphanCleanup,
+    ProjectStorageUsage, RecentProjectRow, TrashedProjectRow,
+};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::export::interchange::{build_interchange_document, parse_interchange_document, project_from_interchange};
+use crate::models::IteProject;
+use crate::package::{
+    self, AttachmentSource, BackupFile, BackupPruneResult, IteFileProject, PackageKind, PackageProject,
+    ProjectImportDiff, DATABASE_ENTRY,
+};
+use crate::secrets::vault::{get_vault_path, get_vault_tmp_path};
+use crate::secrets::SECRETS;
+use crate::text::unicode::file_name_nfc;
 use crate::utils::validate_path;
 
+/// 자동 백업 폴더 (app_data_dir 기준)
+const AUTO_BACKUP_DIR: &str = "ite_backups";
+
+/// 패키지에서 풀어낸 첨부 파일 폴더 (app_data_dir 기준)
+const PACKAGE_ATTACHMENTS_DIR: &str = "package_attachments";
+
+/// 정리 제안을 띄울 최소 회수 용량 (빈 페이지, 오래된 백업)
+const SUGGEST_RECLAIM_BYTES: u64 = 10 * 1024 * 1024;
+
+/// 채팅 기록 정리를 제안할 프로젝트별 채팅 용량
+const SUGGEST_CHAT_BYTES: u64 = 50 * 1024 * 1024;
+
+/// 이 시간보다 오래된 vault 임시 파일만 정리 (진행 중인 쓰기와 겹치지 않도록)
+const STALE_VAULT_TMP_SECS: u64 = 10 * 60;
+
+/// 패키지 manifest에 이름을 기록할 시크릿 prefix (값은 내보내지 않음)
+const PACKAGE_SECRET_PREFIXES: &[&str] = &["mcp/", "connector/", "notion/"];
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportDbArgs {
@@ -22,6 +61,34 @@ pub struct ImportDbArgs {
     pub path: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectImportMergeArgs {
+    /// 새 버전이 들어 있는 .ite 파일
+    pub path: String,
+    pub project_id: String,
+    /// 병합할 번역문 블록 (없으면 바뀐 번역문 전체, 미리보기에서는 무시)
+    #[serde(default)]
+    pub block_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeTranslationsResult {
+    pub updated_blocks: usize,
+    /// 병합 전 자동 스냅샷 ID (바뀐 블록이 없으면 None)
+    pub snapshot_id: Option<String>,
+    /// 원문 변경/세그먼트 추가·삭제 등 적용하지 않은 구조 변경 수
+    pub skipped_structural_changes: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProjectJsonArgs {
+    pub project_id: String,
+    pub path: String,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportProjectFileResult {
@@ -29,12 +96,88 @@ pub struct ImportProjectFileResult {
     pub backup_path: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProjectPackageResult {
+    pub format_version: u32,
+    pub project_count: usize,
+    pub attachment_count: usize,
+    /// 원본 파일을 찾을 수 없어 포함하지 못한 첨부 ID
+    pub skipped_attachments: Vec<String>,
+    pub required_secrets: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportProjectPackageResult {
+    /// 1: SQLite DB 파일, 2+: zip 컨테이너
+    pub format_version: u32,
+    pub project_ids: Vec<String>,
+    pub backup_path: String,
+    pub restored_attachments: usize,
+    /// 패키지가 요구하지만 이 기기에 없는 시크릿 이름 (사용자가 다시 입력해야 함)
+    pub missing_secrets: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IteFileInfo {
+    /// 1: SQLite DB 파일, 2+: zip 컨테이너
+    pub format_version: u32,
+    /// DB 스키마 버전 (0: 버전 기록 이전 파일)
+    pub schema_version: i32,
+    pub projects: Vec<IteFileProject>,
+    /// 패키지에 포함된 첨부 파일 수 (v1은 0)
+    pub attachment_count: usize,
+    /// 다시 입력해야 할 수 있는 시크릿 이름 (v2만)
+    pub required_secrets: Vec<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RecentProjectInfo {
     pub id: String,
     pub title: String,
+    pub domain: String,
+    /// 원문 언어 (프로젝트별 값이 없어 앱 기본 원문 언어 사용)
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
     pub updated_at: i64,
+    pub pinned: bool,
+    /// 보관된 프로젝트 (최근 목록에서 기본으로 숨김)
+    pub archived: bool,
+    /// 사용자 정의 필드 (고객사, PO 번호 등)
+    pub custom_fields: BTreeMap<String, String>,
+    pub segment_count: usize,
+    pub translated_segments: usize,
+    /// 번역 완료율 (0~100, 세그먼트 기준)
+    pub completion_percent: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchProjectsArgs {
+    #[serde(deserialize_with = "crate::text::unicode::deserialize_nfc")]
+    pub query: String,
+    /// 기본 50, 최대 200
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinProjectArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRecentProjectsArgs {
+    /// 사용자 정의 필드 필터 (모두 만족해야 함)
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldFilter>,
+    /// 보관된 프로젝트 포함 (기본 false)
+    #[serde(default)]
+    pub include_archived: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -42,6 +185,83 @@ pub struct RecentProjectInfo {
 pub struct DeleteProjectArgs {
     #[serde(rename = "projectId")]
     pub project_id: String,
+    /// true면 휴지통을 거치지 않고 영구 삭제
+    #[serde(default)]
+    pub permanent: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreFromBackupArgs {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneAutoBackupsArgs {
+    /// 전체 백업 용량 상한 (MB, 기본 500)
+    pub max_total_mb: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreProjectArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeTrashArgs {
+    /// 삭제된 지 이 일수가 지난 항목만 비움 (없으면 휴지통 전체)
+    pub older_than_days: Option<u32>,
+}
+
+/// 정리 제안
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSuggestion {
+    /// 제안 종류 ("optimize_database" | "prune_auto_backups" | "purge_trash" | "prune_chat_history" | "cleanup_temp_images")
+    pub action: String,
+    pub message: String,
+    pub project_id: Option<String>,
+    /// 회수 가능한 용량 추정치 (bytes)
+    pub reclaimable_bytes: u64,
+}
+
+/// 디스크 사용량 리포트
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageReport {
+    pub data_dir: String,
+    /// DB 파일 (WAL/SHM 포함)
+    pub database_bytes: u64,
+    /// VACUUM으로 회수 가능한 빈 페이지
+    pub free_page_bytes: u64,
+    /// 패키지에서 풀어낸 첨부 파일 폴더
+    pub attachment_store_bytes: u64,
+    /// 첨부 원본 파일 크기 합 (원래 위치에 있어 app_data_dir 용량에는 포함하지 않음)
+    pub referenced_attachment_bytes: u64,
+    pub backup_bytes: u64,
+    pub backup_count: usize,
+    /// 임시 업로드 이미지 (시스템 임시 폴더)
+    pub temp_upload_bytes: u64,
+    /// DB + 첨부 폴더 + 백업 + 임시 업로드
+    pub total_bytes: u64,
+    /// 큰 순
+    pub projects: Vec<ProjectStorageUsage>,
+    pub suggestions: Vec<StorageSuggestion>,
+}
+
+/// 고아 데이터 정리 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanCleanupReport {
+    #[serde(flatten)]
+    pub database: OrphanCleanup,
+    /// 함께 지운 첨부 파일 (패키지에서 풀어낸 app_data_dir 안 파일만)
+    pub removed_files: Vec<String>,
+    /// 지운 vault 임시 파일
+    pub removed_temp_files: Vec<String>,
 }
 
 /// 현재 DB를 .ite 파일로 내보내기
@@ -50,54 +270,100 @@ pub fn export_project_file(args: ExportDbArgs, db_state: State<DbState>) -> Comm
     // utils::validate_path (Blocklist 적용)
     let out_path = validate_path(&args.path)?;
 
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.export_db_to_file(&out_path).map_err(CommandError::from)?;
     Ok(())
 }
 
-/// 프로젝트 삭제(연관 데이터 포함)
+/// 프로젝트 삭제 (기본: 휴지통으로 이동, permanent=true면 연관 데이터 포함 영구 삭제)
 #[tauri::command]
-pub fn delete_project(args: DeleteProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+pub fn delete_project(app: AppHandle, args: DeleteProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
+    {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
 
-    db.delete_project(&args.project_id).map_err(CommandError::from)?;
+        if !args.permanent {
+            return db.trash_project(&args.project_id).map_err(CommandError::from);
+        }
+        db.delete_project(&args.project_id).map_err(CommandError::from)?;
+    }
+    maybe_optimize_database(&app);
     Ok(())
 }
 
-/// 전체 프로젝트 삭제(연관 데이터 포함)
+/// 전체 프로젝트를 휴지통으로 이동
 #[tauri::command]
 pub fn delete_all_projects(db_state: State<DbState>) -> CommandResult<()> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
-    db.delete_all_projects().map_err(CommandError::from)?;
+    db.trash_all_projects().map_err(CommandError::from)?;
     Ok(())
 }
 
+/// 휴지통 프로젝트 목록
+#[tauri::command]
+pub fn list_trashed_projects(db_state: State<DbState>) -> CommandResult<Vec<TrashedProjectRow>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.list_trashed_projects().map_err(CommandError::from)
+}
+
+/// 휴지통 프로젝트 복원
+#[tauri::command]
+pub fn restore_project(args: RestoreProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.restore_project(&args.project_id).map_err(CommandError::from)
+}
+
+/// 휴지통 비우기 (영구 삭제된 프로젝트 수 반환)
+#[tauri::command]
+pub fn purge_trash(app: AppHandle, args: PurgeTrashArgs, db_state: State<DbState>) -> CommandResult<usize> {
+    let cutoff = args
+        .older_than_days
+        .map(|days| chrono::Utc::now().timestamp_millis() - i64::from(days) * 24 * 60 * 60 * 1000);
+
+    let purged = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.purge_trash(cutoff).map_err(CommandError::from)?
+    };
+    if purged > 0 {
+        maybe_optimize_database(&app);
+    }
+    Ok(purged)
+}
+
 /// .ite 파일을 현재 DB로 가져오기(현재 DB 내용을 덮어씀)
 /// 가져온 뒤, DB 안에 있는 projectId 리스트를 반환합니다.
 #[tauri::command]
 pub fn import_project_file(args: ImportDbArgs, db_state: State<DbState>) -> CommandResult<Vec<String>> {
     // utils::validate_path (Blocklist 적용)
     let in_path = validate_path(&args.path)?;
+    // 손상/비호환 파일은 덮어쓰기 전에 거부
+    package::inspect_database_file(&in_path).map_err(CommandError::from)?;
 
-    let mut db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.import_db_from_file(&in_path).map_err(CommandError::from)?;
     db.initialize().map_err(CommandError::from)?;
@@ -115,32 +381,548 @@ pub fn import_project_file_safe(
 ) -> CommandResult<ImportProjectFileResult> {
     // utils::validate_path (Blocklist 적용)
     let in_path = validate_path(&args.path)?;
+    // 손상/비호환 파일은 백업/덮어쓰기 전에 거부
+    package::inspect_database_file(&in_path).map_err(CommandError::from)?;
+
+    let backup_dir = auto_backup_dir(&app)?;
 
-    let backup_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| CommandError {
-            code: "PATH_ERROR".to_string(),
-            message: format!("Failed to get app data dir: {}", e),
-            details: None,
-        })?
-        .join("ite_backups");
-
-    let ts = chrono::Utc::now().timestamp_millis();
-    let backup_path = backup_dir.join(format!("backup-before-import-{}.ite", ts));
-
-    let mut db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     // backup current DB
+    let backup_path = package::backup_file_path(&backup_dir, "import");
     db.export_db_to_file(&backup_path).map_err(CommandError::from)?;
 
     // import selected .ite into current DB
     db.import_db_from_file(&in_path).map_err(CommandError::from)?;
     db.initialize().map_err(CommandError::from)?;
+    prune_auto_backups_quietly(&backup_dir);
+
+    let project_ids = db.list_project_ids().map_err(CommandError::from)?;
+    Ok(ImportProjectFileResult {
+        project_ids,
+        backup_path: backup_path.to_string_lossy().to_string(),
+    })
+}
+
+fn package_work_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("ite-package-{}", uuid::Uuid::new_v4()))
+}
+
+/// .ite 파일 검사 (import 전 미리보기)
+/// - 현재 DB는 건드리지 않으며, 손상/비호환 파일이면 INVALID_OPERATION 에러
+#[tauri::command]
+pub fn inspect_ite_file(args: ImportDbArgs) -> CommandResult<IteFileInfo> {
+    // utils::validate_path (Blocklist 적용)
+    let in_path = validate_path(&args.path)?;
+
+    let kind = package::detect_package_kind(&in_path)
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::new(ErrorCode::InvalidInput, "Not a valid .ite file"))?;
+
+    match kind {
+        PackageKind::LegacyDatabase => {
+            let info = package::inspect_database_file(&in_path).map_err(CommandError::from)?;
+            Ok(IteFileInfo {
+                format_version: 1,
+                schema_version: info.schema_version,
+                projects: info.projects,
+                attachment_count: 0,
+                required_secrets: Vec::new(),
+            })
+        }
+        PackageKind::Container => {
+            let work_dir = package_work_dir();
+            let result = package::extract_database(&in_path, &work_dir)
+                .and_then(|(manifest, db_path)| Ok((manifest, package::inspect_database_file(&db_path)?)));
+            let _ = std::fs::remove_dir_all(&work_dir);
+            let (manifest, info) = result.map_err(CommandError::from)?;
+            Ok(IteFileInfo {
+                format_version: manifest.format_version,
+                schema_version: info.schema_version,
+                projects: info.projects,
+                attachment_count: manifest.attachments.len(),
+                required_secrets: manifest.required_secrets,
+            })
+        }
+    }
+}
+
+/// .ite 파일에서 프로젝트 1개 읽기 (현재 DB는 건드리지 않음)
+/// - 임시 폴더에 DB 사본을 만들어 최신 스키마로 올린 뒤 읽고, 사본은 지웁니다.
+fn load_project_from_ite(in_path: &Path, project_id: &str) -> CommandResult<IteProject> {
+    let kind = package::detect_package_kind(in_path)
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::new(ErrorCode::InvalidInput, "Not a valid .ite file"))?;
+
+    let work_dir = package_work_dir();
+    let result = (|| {
+        let db_path = match kind {
+            PackageKind::Container => package::extract_database(in_path, &work_dir)?.1,
+            PackageKind::LegacyDatabase => {
+                std::fs::create_dir_all(&work_dir)?;
+                let copy = work_dir.join(DATABASE_ENTRY);
+                std::fs::copy(in_path, &copy)?;
+                copy
+            }
+        };
+        package::inspect_database_file(&db_path)?;
+        let incoming = Database::new(&db_path)?;
+        incoming.initialize()?;
+        incoming.load_project(project_id)
+    })();
+    let _ = std::fs::remove_dir_all(&work_dir);
+    result.map_err(CommandError::from)
+}
+
+/// .ite 파일에 든 새 버전과 로컬 프로젝트 비교 (블록 단위)
+/// - 바뀐 번역문은 `merge_project_translations`로 골라 적용할 수 있습니다.
+#[tauri::command]
+pub fn preview_project_import(
+    args: ProjectImportMergeArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ProjectImportDiff> {
+    // utils::validate_path (Blocklist 적용)
+    let in_path = validate_path(&args.path)?;
+    let incoming = load_project_from_ite(&in_path, &args.project_id)?;
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    let local = db.load_project(&args.project_id).map_err(CommandError::from)?;
+    Ok(package::diff_projects(&local, &incoming))
+}
+
+/// .ite 파일의 새 버전에서 번역문 변경만 로컬 프로젝트에 병합 (전체 교체 대신)
+/// - 원문/세그먼트 구조는 그대로 두고, 병합 전 히스토리 스냅샷을 남깁니다.
+#[tauri::command]
+pub fn merge_project_translations(
+    app: AppHandle,
+    args: ProjectImportMergeArgs,
+    db_state: State<DbState>,
+) -> CommandResult<MergeTranslationsResult> {
+    // utils::validate_path (Blocklist 적용)
+    let in_path = validate_path(&args.path)?;
+    let incoming = load_project_from_ite(&in_path, &args.project_id)?;
+
+    let (result, block_ids) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let local = db.load_project(&args.project_id).map_err(CommandError::from)?;
+        let diff = package::diff_projects(&local, &incoming);
+        let updates = package::translation_updates(&diff, args.block_ids.as_deref());
+        let skipped_structural_changes =
+            diff.source_changes.len() + diff.added_segments.len() + diff.removed_segments.len();
+        if updates.is_empty() {
+            (
+                MergeTranslationsResult {
+                    updated_blocks: 0,
+                    snapshot_id: None,
+                    skipped_structural_changes,
+                },
+                Vec::new(),
+            )
+        } else {
+            db.ensure_project_writable(&args.project_id).map_err(CommandError::from)?;
+            let file_name = file_name_nfc(&in_path).unwrap_or_default();
+            let snapshot = db
+                .create_history_snapshot(&args.project_id, &format!("Before merging translations from {}", file_name), None)
+                .map_err(CommandError::from)?;
+            let updated_blocks = db
+                .update_block_contents(&args.project_id, &updates)
+                .map_err(CommandError::from)?;
+            (
+                MergeTranslationsResult {
+                    updated_blocks,
+                    snapshot_id: Some(snapshot.id),
+                    skipped_structural_changes,
+                },
+                updates.into_iter().map(|(id, _)| id).collect(),
+            )
+        }
+    };
+
+    for block_id in &block_ids {
+        enqueue_block_qa(&app, &args.project_id, block_id);
+    }
+    broadcast_block_change(&app, None, &args.project_id, block_ids);
+    Ok(result)
+}
+
+/// 프로젝트 패키지(.ite v2) 내보내기
+/// - DB 스냅샷 + 첨부 원본 파일 + manifest
+/// - 시크릿은 값 없이 이름만 manifest에 기록
+#[tauri::command]
+pub async fn export_project_package(
+    app: AppHandle,
+    args: ExportDbArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<ExportProjectPackageResult> {
+    crate::crash::guard(&app, "export_project_package", async {
+        // utils::validate_path (Blocklist 적용)
+        let out_path = validate_path(&args.path)?;
+        let result = write_project_package(&out_path, &db_state, None).await;
+        match &result {
+            Ok(exported) => notify_job(
+                &app,
+                JobKind::Export,
+                Ok(&format!("{} project(s) exported to {}", exported.project_count, out_path.display())),
+            ),
+            Err(e) => notify_job(&app, JobKind::Export, Err(&e.message)),
+        }
+        result
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProjectsArgs {
+    pub project_ids: Vec<String>,
+    pub path: String,
+}
+
+/// 선택한 프로젝트만 .ite 패키지로 내보내기 (고객사별 전달용)
+/// - 프로젝트의 블록/히스토리/채팅/용어집/첨부는 포함하고, 다른 프로젝트 데이터와 전역 TM은 제외합니다.
+#[tauri::command]
+pub async fn export_projects(
+    app: AppHandle,
+    args: ExportProjectsArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<ExportProjectPackageResult> {
+    crate::crash::guard(&app, "export_projects", async {
+        if args.project_ids.is_empty() {
+            return Err(CommandError::new(ErrorCode::InvalidInput, "Select at least one project to export"));
+        }
+        // utils::validate_path (Blocklist 적용)
+        let out_path = validate_path(&args.path)?;
+        let result = write_project_package(&out_path, &db_state, Some(&args.project_ids)).await;
+        match &result {
+            Ok(exported) => notify_job(
+                &app,
+                JobKind::Export,
+                Ok(&format!("{} project(s) exported to {}", exported.project_count, out_path.display())),
+            ),
+            Err(e) => notify_job(&app, JobKind::Export, Err(&e.message)),
+        }
+        result
+    })
+    .await
+}
+
+/// 현재 DB를 v2 패키지로 기록 (클라우드 백업에서도 사용, `out_path`는 검증된 경로)
+/// - `project_ids`가 있으면 스냅샷 사본에서 그 프로젝트만 남깁니다 (휴지통 프로젝트는 선택 불가).
+pub(crate) async fn write_project_package(
+    out_path: &Path,
+    db_state: &DbState,
+    project_ids: Option<&[String]>,
+) -> CommandResult<ExportProjectPackageResult> {
+    let mut required_secrets = Vec::new();
+    for prefix in PACKAGE_SECRET_PREFIXES {
+        // vault 미초기화 등으로 조회할 수 없으면 목록 없이 진행
+        required_secrets.extend(SECRETS.list_keys_by_prefix(prefix).await.unwrap_or_default());
+    }
+    required_secrets.sort();
+
+    let work_dir = package_work_dir();
+    let snapshot_path = work_dir.join(DATABASE_ENTRY);
+    let (projects, attachment_rows) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        let mut projects: Vec<PackageProject> = db
+            .list_recent_projects(1000, &[], true)
+            .map_err(CommandError::from)?
+            .into_iter()
+            .map(|p| PackageProject { id: p.id, title: p.title })
+            .collect();
+        let mut attachment_rows = db.list_all_attachments().map_err(CommandError::from)?;
+        if let Some(ids) = project_ids {
+            if let Some(missing) = ids.iter().find(|id| !projects.iter().any(|p| &p.id == *id)) {
+                return Err(CommandError::new(ErrorCode::NotFound, format!("Project not found: {}", missing)));
+            }
+            projects.retain(|p| ids.contains(&p.id));
+            attachment_rows.retain(|a| ids.contains(&a.project_id));
+        }
+        db.export_db_to_file(&snapshot_path).map_err(CommandError::from)?;
+        (projects, attachment_rows)
+    };
+
+    // 잠금을 놓은 뒤 사본에서 정리 (VACUUM 포함이라 오래 걸릴 수 있음)
+    if let Some(ids) = project_ids {
+        let retained = Database::new(&snapshot_path).and_then(|copy| copy.retain_projects(ids));
+        if let Err(e) = retained {
+            let _ = std::fs::remove_dir_all(&work_dir);
+            return Err(CommandError::from(e));
+        }
+    }
+
+    let mut skipped_attachments = Vec::new();
+    let mut sources = Vec::new();
+    for a in &attachment_rows {
+        match &a.file_path {
+            Some(path) => sources.push(AttachmentSource {
+                id: &a.id,
+                project_id: &a.project_id,
+                filename: &a.filename,
+                path: Path::new(path),
+            }),
+            None => skipped_attachments.push(a.id.clone()),
+        }
+    }
+
+    let result = package::write_package(out_path, &snapshot_path, projects, &sources, required_secrets);
+    let _ = std::fs::remove_dir_all(&work_dir);
+    let (manifest, skipped) = result.map_err(CommandError::from)?;
+    skipped_attachments.extend(skipped);
+
+    Ok(ExportProjectPackageResult {
+        format_version: manifest.format_version,
+        project_count: manifest.projects.len(),
+        attachment_count: manifest.attachments.len(),
+        skipped_attachments,
+        required_secrets: manifest.required_secrets,
+    })
+}
+
+/// 프로젝트 패키지 import (v1 SQLite 파일과 v2 zip 컨테이너 모두 지원)
+/// - import 전 현재 DB를 app_data_dir/ite_backups 아래에 자동 백업
+/// - v2 첨부 파일은 app_data_dir/package_attachments 아래에 풀고 경로를 다시 연결
+#[tauri::command]
+pub async fn import_project_package(
+    app: AppHandle,
+    args: ImportDbArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<ImportProjectPackageResult> {
+    crate::crash::guard(&app, "import_project_package", async {
+        // utils::validate_path (Blocklist 적용)
+        let in_path = validate_path(&args.path)?;
+        import_package_file(&app, &in_path, &db_state).await
+    })
+    .await
+}
+
+/// .ite 파일로 현재 DB 교체 (클라우드 복원에서도 사용, `in_path`는 검증된 경로)
+pub(crate) async fn import_package_file(
+    app: &AppHandle,
+    in_path: &Path,
+    db_state: &DbState,
+) -> CommandResult<ImportProjectPackageResult> {
+    let kind = package::detect_package_kind(in_path)
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::new(ErrorCode::InvalidInput, "Not a valid .ite file"))?;
+
+    let app_data_dir = app.path().app_data_dir().map_err(|e| CommandError::new(
+        ErrorCode::PathError,
+        format!("Failed to get app data dir: {}", e),
+    ))?;
+    let backup_dir = app_data_dir.join(AUTO_BACKUP_DIR);
+    let backup_path = package::backup_file_path(&backup_dir, "import");
+
+    // 현재 DB를 건드리기 전에 압축 해제 + manifest/DB 검증
+    let work_dir = package_work_dir();
+    let extracted = match kind {
+        PackageKind::Container => {
+            let extracted = package::extract_package(in_path, &work_dir, &app_data_dir.join(PACKAGE_ATTACHMENTS_DIR))
+                .and_then(|e| package::inspect_database_file(&e.database_path).map(|_| e));
+            if extracted.is_err() {
+                let _ = std::fs::remove_dir_all(&work_dir);
+            }
+            Some(extracted.map_err(CommandError::from)?)
+        }
+        PackageKind::LegacyDatabase => {
+            package::inspect_database_file(in_path).map_err(CommandError::from)?;
+            None
+        }
+    };
+
+    let (project_ids, restored_attachments) = {
+        let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+
+        // backup current DB
+        db.export_db_to_file(&backup_path).map_err(CommandError::from)?;
+
+        let source_path = extracted.as_ref().map(|e| e.database_path.as_path()).unwrap_or(in_path);
+        let imported = db.import_db_from_file(source_path).and_then(|_| db.initialize());
+        if imported.is_err() {
+            let _ = std::fs::remove_dir_all(&work_dir);
+        }
+        imported.map_err(CommandError::from)?;
+
+        let mut restored = 0;
+        for (id, path) in extracted.iter().flat_map(|e| e.attachments.iter()) {
+            if db
+                .set_attachment_file_path(id, &path.to_string_lossy())
+                .map_err(CommandError::from)?
+            {
+                restored += 1;
+            }
+        }
+        (db.list_project_ids().map_err(CommandError::from)?, restored)
+    };
+    let _ = std::fs::remove_dir_all(&work_dir);
+    prune_auto_backups_quietly(&backup_dir);
+
+    let format_version = extracted.as_ref().map(|e| e.manifest.format_version).unwrap_or(1);
+    let mut missing_secrets = Vec::new();
+    let required = extracted.map(|e| e.manifest.required_secrets).unwrap_or_default();
+    for name in required {
+        if !SECRETS.has(&name).await.unwrap_or(false) {
+            missing_secrets.push(name);
+        }
+    }
+
+    Ok(ImportProjectPackageResult {
+        format_version,
+        project_ids,
+        backup_path: backup_path.to_string_lossy().to_string(),
+        restored_attachments,
+        missing_secrets,
+    })
+}
+
+/// 프로젝트를 interchange JSON으로 내보내기 (메타데이터, 세그먼트, 블록, 프로젝트 용어집)
+#[tauri::command]
+pub fn export_project_json(args: ExportProjectJsonArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let out_path = validate_path(&args.path)?;
+
+    let doc = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        let project = db.load_project(&args.project_id).map_err(CommandError::from)?;
+        let custom_fields = db
+            .get_project_custom_fields(&args.project_id)
+            .map_err(CommandError::from)?;
+        let glossary = db
+            .list_project_glossary_entries(&args.project_id)
+            .map_err(CommandError::from)?;
+        build_interchange_document(&project, custom_fields, &glossary, chrono::Utc::now().timestamp_millis())
+    };
+
+    let json = serde_json::to_string_pretty(&doc).map_err(|e| CommandError::new(
+        ErrorCode::InvalidOperation,
+        format!("Failed to serialize project: {}", e),
+    ))?;
+    std::fs::write(&out_path, json).map_err(|e| CommandError::new(
+        ErrorCode::WriteError,
+        format!("Failed to write project JSON: {}", e),
+    ))?;
+    Ok(())
+}
+
+/// interchange JSON을 새 프로젝트로 가져오기 (기존 프로젝트는 건드리지 않음)
+#[tauri::command]
+pub fn import_project_json(args: ImportDbArgs, db_state: State<DbState>) -> CommandResult<IteProject> {
+    let in_path = validate_path(&args.path)?;
+    let json = std::fs::read_to_string(&in_path).map_err(|e| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Failed to read project JSON: {}", e),
+    ))?;
+    let doc = parse_interchange_document(&json).map_err(CommandError::from)?;
+    let imported =
+        project_from_interchange(doc, chrono::Utc::now().timestamp_millis()).map_err(CommandError::from)?;
+
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    let project_id = imported.project.id.clone();
+    db.save_project(&imported.project).map_err(CommandError::from)?;
+    for (key, value) in &imported.custom_fields {
+        // 형식에 맞지 않는 필드는 건너뜀 (프로젝트 자체는 가져옴)
+        if let Err(e) = db.set_project_custom_field(&project_id, key, Some(value)) {
+            eprintln!("[Storage] Skipped custom field '{}': {}", key, e);
+        }
+    }
+    let glossary: Vec<_> = imported
+        .glossary
+        .into_iter()
+        .map(|g| NewGlossaryTerm {
+            source: g.source,
+            target: g.target,
+            notes: g.notes,
+            domain: g.domain,
+            case_sensitive: g.case_sensitive,
+            origin_key: None,
+        })
+        .collect();
+    db.upsert_project_glossary_entries(&project_id, &glossary)
+        .map_err(CommandError::from)?;
+
+    Ok(imported.project)
+}
+
+pub(crate) fn auto_backup_dir(app: &AppHandle) -> CommandResult<PathBuf> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| CommandError::new(
+        ErrorCode::PathError,
+        format!("Failed to get app data dir: {}", e),
+    ))?;
+    Ok(app_data_dir.join(AUTO_BACKUP_DIR))
+}
+
+/// 백업 용량 상한 초과분 정리 (실패해도 호출한 작업은 계속 진행)
+fn prune_auto_backups_quietly(backup_dir: &Path) {
+    if let Err(e) = package::prune_backups(backup_dir, package::DEFAULT_BACKUP_MAX_BYTES) {
+        eprintln!("[Storage] Failed to prune auto backups: {}", e);
+    }
+}
+
+/// 자동 백업 목록 (최신 순)
+#[tauri::command]
+pub fn list_auto_backups(app: AppHandle) -> CommandResult<Vec<BackupFile>> {
+    let backup_dir = auto_backup_dir(&app)?;
+    package::list_backups(&backup_dir).map_err(CommandError::from)
+}
+
+/// 자동 백업으로 되돌리기
+/// - 자동 백업 폴더 안의 파일만 허용
+/// - 되돌리기 전 현재 DB도 백업하므로 되돌리기 자체를 다시 취소할 수 있음
+#[tauri::command]
+pub fn restore_from_backup(
+    app: AppHandle,
+    args: RestoreFromBackupArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ImportProjectFileResult> {
+    // utils::validate_path (Blocklist 적용)
+    let in_path = validate_path(&args.path)?;
+    let backup_dir = auto_backup_dir(&app)?;
+
+    let in_backup_dir = backup_dir
+        .canonicalize()
+        .ok()
+        .is_some_and(|dir| in_path.parent() == Some(dir.as_path()));
+    let is_backup_file = in_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with(package::BACKUP_FILE_PREFIX));
+    if !in_backup_dir || !is_backup_file {
+        return Err(CommandError::new(ErrorCode::SecurityError, "Only files in the auto backup folder can be restored"));
+    }
+    package::inspect_database_file(&in_path).map_err(CommandError::from)?;
+
+    let mut db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let backup_path = package::backup_file_path(&backup_dir, "restore");
+    db.export_db_to_file(&backup_path).map_err(CommandError::from)?;
+
+    db.import_db_from_file(&in_path).map_err(CommandError::from)?;
+    db.initialize().map_err(CommandError::from)?;
+    prune_auto_backups_quietly(&backup_dir);
 
     let project_ids = db.list_project_ids().map_err(CommandError::from)?;
     Ok(ImportProjectFileResult {
@@ -149,34 +931,372 @@ pub fn import_project_file_safe(
     })
 }
 
+/// 자동 백업 정리 (오래된 것부터, 최신 3개는 유지)
+#[tauri::command]
+pub fn prune_auto_backups(app: AppHandle, args: Option<PruneAutoBackupsArgs>) -> CommandResult<BackupPruneResult> {
+    let backup_dir = auto_backup_dir(&app)?;
+    let max_bytes = args
+        .and_then(|a| a.max_total_mb)
+        .map(|mb| mb.saturating_mul(1024 * 1024))
+        .unwrap_or(package::DEFAULT_BACKUP_MAX_BYTES);
+    package::prune_backups(&backup_dir, max_bytes).map_err(CommandError::from)
+}
+
+/// 폴더 안 파일 크기 합 (하위 폴더 포함, 읽을 수 없는 항목은 건너뜀)
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+fn format_mb(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// 리포트 수치로 정리 제안 구성
+fn storage_suggestions(report: &StorageReport, backups: &[BackupFile]) -> Vec<StorageSuggestion> {
+    let mut suggestions = Vec::new();
+
+    if report.free_page_bytes >= SUGGEST_RECLAIM_BYTES {
+        suggestions.push(StorageSuggestion {
+            action: "optimize_database".to_string(),
+            message: format!("Compact the database to reclaim {} of free pages.", format_mb(report.free_page_bytes)),
+            project_id: None,
+            reclaimable_bytes: report.free_page_bytes,
+        });
+    }
+
+    let old_backups: u64 = backups.iter().skip(package::MIN_BACKUPS_KEPT).map(|b| b.size_bytes).sum();
+    if old_backups >= SUGGEST_RECLAIM_BYTES {
+        suggestions.push(StorageSuggestion {
+            action: "prune_auto_backups".to_string(),
+            message: format!(
+                "{} automatic backup(s) older than the latest {} use {}.",
+                backups.len() - package::MIN_BACKUPS_KEPT,
+                package::MIN_BACKUPS_KEPT,
+                format_mb(old_backups)
+            ),
+            project_id: None,
+            reclaimable_bytes: old_backups,
+        });
+    }
+
+    let trashed: Vec<_> = report.projects.iter().filter(|p| p.trashed).collect();
+    if !trashed.is_empty() {
+        let bytes = trashed.iter().map(|p| p.total_bytes).sum();
+        suggestions.push(StorageSuggestion {
+            action: "purge_trash".to_string(),
+            message: format!("{} project(s) in the trash use {}.", trashed.len(), format_mb(bytes)),
+            project_id: None,
+            reclaimable_bytes: bytes,
+        });
+    }
+
+    for project in report.projects.iter().filter(|p| !p.trashed && p.chat_bytes >= SUGGEST_CHAT_BYTES) {
+        suggestions.push(StorageSuggestion {
+            action: "prune_chat_history".to_string(),
+            message: format!(
+                "Chat history of '{}' uses {}. Set a retention policy to prune old messages.",
+                project.title,
+                format_mb(project.chat_bytes)
+            ),
+            project_id: Some(project.project_id.clone()),
+            reclaimable_bytes: project.chat_bytes,
+        });
+    }
+
+    if report.temp_upload_bytes > 0 {
+        suggestions.push(StorageSuggestion {
+            action: "cleanup_temp_images".to_string(),
+            message: format!("Temporary uploaded images use {}.", format_mb(report.temp_upload_bytes)),
+            project_id: None,
+            reclaimable_bytes: report.temp_upload_bytes,
+        });
+    }
+
+    suggestions.sort_by_key(|s| std::cmp::Reverse(s.reclaimable_bytes));
+    suggestions
+}
+
+/// 디스크 사용량 리포트 (DB, 첨부, 백업, 임시 파일, 프로젝트별 용량 + 정리 제안)
+/// - app_data_dir가 모르는 사이 커지는 것을 확인하기 위한 용도이며, 아무것도 지우지 않습니다.
+#[tauri::command]
+pub fn get_storage_report(app: AppHandle, db_state: State<DbState>) -> CommandResult<StorageReport> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| CommandError::new(
+        ErrorCode::PathError,
+        format!("Failed to get app data dir: {}", e),
+    ))?;
+
+    let (projects, free_page_bytes) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        (
+            db.project_storage_usage().map_err(CommandError::from)?,
+            db.free_page_bytes().map_err(CommandError::from)?,
+        )
+    };
+
+    let db_path = app_data_dir.join(db::DATABASE_FILE_NAME);
+    let database_bytes = ["", "-wal", "-shm"]
+        .iter()
+        .map(|suffix| file_size(&PathBuf::from(format!("{}{}", db_path.display(), suffix))))
+        .sum();
+    let backups = package::list_backups(&app_data_dir.join(AUTO_BACKUP_DIR)).map_err(CommandError::from)?;
+    let backup_bytes: u64 = backups.iter().map(|b| b.size_bytes).sum();
+    let attachment_store_bytes = dir_size(&app_data_dir.join(PACKAGE_ATTACHMENTS_DIR));
+    let temp_upload_bytes = dir_size(&temp_upload_dir());
+
+    let mut report = StorageReport {
+        data_dir: app_data_dir.to_string_lossy().to_string(),
+        database_bytes,
+        free_page_bytes: free_page_bytes.max(0) as u64,
+        attachment_store_bytes,
+        referenced_attachment_bytes: projects.iter().map(|p| p.attachment_file_bytes).sum(),
+        backup_bytes,
+        backup_count: backups.len(),
+        temp_upload_bytes,
+        total_bytes: database_bytes + attachment_store_bytes + backup_bytes + temp_upload_bytes,
+        projects,
+        suggestions: Vec::new(),
+    };
+    report.suggestions = storage_suggestions(&report, &backups);
+    Ok(report)
+}
+
+/// 고아 데이터 정리
+/// - 세그먼트에 속하지 않는 블록, 세션이 없는 채팅 메시지, 프로젝트가 없는 첨부, 오래된 `.vault.tmp` 파일을 지웁니다.
+/// - 첨부 원본 파일은 패키지에서 풀어낸 것(app_data_dir 안)만 지우고, 사용자 파일은 건드리지 않습니다.
+#[tauri::command]
+pub fn cleanup_orphans(app: AppHandle, db_state: State<DbState>) -> CommandResult<OrphanCleanupReport> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| CommandError::new(
+        ErrorCode::PathError,
+        format!("Failed to get app data dir: {}", e),
+    ))?;
+
+    let database = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.cleanup_orphans().map_err(CommandError::from)?
+    };
+
+    let store_dir = app_data_dir.join(PACKAGE_ATTACHMENTS_DIR).canonicalize().ok();
+    let mut removed_files = Vec::new();
+    for path in database.attachments.iter().filter_map(|a| a.file_path.as_deref()) {
+        let Ok(path) = Path::new(path).canonicalize() else {
+            continue;
+        };
+        let in_store = store_dir.as_ref().is_some_and(|dir| path.starts_with(dir));
+        if in_store && std::fs::remove_file(&path).is_ok() {
+            removed_files.push(path.to_string_lossy().to_string());
+        }
+    }
+
+    let mut removed_temp_files = Vec::new();
+    let tmp_path = get_vault_tmp_path(&get_vault_path(&app_data_dir));
+    let stale = std::fs::metadata(&tmp_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age.as_secs() > STALE_VAULT_TMP_SECS);
+    if stale && std::fs::remove_file(&tmp_path).is_ok() {
+        removed_temp_files.push(tmp_path.to_string_lossy().to_string());
+    }
+
+    maybe_optimize_database(&app);
+    println!(
+        "[Storage] Removed orphans: {} block(s), {} chat message(s), {} attachment(s), {} file(s)",
+        database.blocks,
+        database.chat_messages,
+        database.attachments.len(),
+        removed_files.len() + removed_temp_files.len()
+    );
+    Ok(OrphanCleanupReport {
+        database,
+        removed_files,
+        removed_temp_files,
+    })
+}
+
 /// DB에 저장된 프로젝트 ID 목록 조회
 #[tauri::command]
 pub fn list_project_ids(db_state: State<DbState>) -> CommandResult<Vec<String>> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
     db.list_project_ids().map_err(CommandError::from)
 }
 
-/// 최근 프로젝트 목록(간단 메타 포함)
+/// 최근 프로젝트 목록(고정 항목 우선, 언어쌍/진행률 포함, 사용자 정의 필드로 필터 가능)
 #[tauri::command]
-pub fn list_recent_projects(db_state: State<DbState>) -> CommandResult<Vec<RecentProjectInfo>> {
-    let db = db_state.0.lock().map_err(|e| CommandError {
-        code: "LOCK_ERROR".to_string(),
-        message: format!("Failed to acquire database lock: {}", e),
-        details: None,
-    })?;
+pub fn list_recent_projects(
+    args: Option<ListRecentProjectsArgs>,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<RecentProjectInfo>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
 
-    let rows = db.list_recent_projects(20).map_err(CommandError::from)?;
-    Ok(rows
-        .into_iter()
+    let (filters, include_archived) = args
+        .map(|a| (a.custom_fields, a.include_archived))
+        .unwrap_or_default();
+    let rows = db
+        .list_recent_projects(20, &filters, include_archived)
+        .map_err(CommandError::from)?;
+    Ok(to_recent_infos(&db, rows))
+}
+
+/// 프로젝트 검색 (제목/도메인/설명/사용자 정의 필드, 최근 수정 순)
+#[tauri::command]
+pub fn search_projects(
+    args: SearchProjectsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<Vec<RecentProjectInfo>> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    let limit = args.limit.unwrap_or(50).min(200);
+    let rows = db
+        .search_projects(&args.query, limit)
+        .map_err(CommandError::from)?;
+    Ok(to_recent_infos(&db, rows))
+}
+
+fn to_recent_infos(db: &Database, rows: Vec<RecentProjectRow>) -> Vec<RecentProjectInfo> {
+    let source_language = db
+        .load_app_settings()
+        .ok()
+        .and_then(|s| s.default_source_language);
+
+    rows.into_iter()
         .map(|r| RecentProjectInfo {
+            completion_percent: if r.segment_count == 0 {
+                0.0
+            } else {
+                (r.translated_segments as f64 * 1000.0 / r.segment_count as f64).round() / 10.0
+            },
             id: r.id,
             title: r.title,
+            domain: r.domain,
+            source_language: source_language.clone(),
+            target_language: r.target_language,
             updated_at: r.updated_at,
+            pinned: r.pinned,
+            archived: r.archived,
+            custom_fields: r.custom_fields,
+            segment_count: r.segment_count,
+            translated_segments: r.translated_segments,
         })
-        .collect())
+        .collect()
+}
+
+/// 프로젝트를 최근 목록 상단에 고정
+#[tauri::command]
+pub fn pin_project(args: PinProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_project_pinned(&args.project_id, true)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트 고정 해제
+#[tauri::command]
+pub fn unpin_project(args: PinProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_project_pinned(&args.project_id, false)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트 보관 (최근 목록에서 숨김, 검색/ID 목록에는 그대로 나타남)
+#[tauri::command]
+pub fn archive_project(args: PinProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_project_archived(&args.project_id, true)
+        .map_err(CommandError::from)
+}
+
+/// 프로젝트 보관 해제
+#[tauri::command]
+pub fn unarchive_project(args: PinProjectArgs, db_state: State<DbState>) -> CommandResult<()> {
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+
+    db.set_project_archived(&args.project_id, false)
+        .map_err(CommandError::from)
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbPerfStatsArgs {
+    /// 반환할 문장 수 (기본 50)
+    pub limit: Option<usize>,
+    /// 조회 후 통계 초기화
+    #[serde(default)]
+    pub reset: bool,
+}
+
+/// DB 쿼리 성능 통계 (문장별 실행 횟수/시간, 최근 느린 쿼리)
+#[tauri::command]
+pub fn db_perf_stats(args: Option<DbPerfStatsArgs>) -> CommandResult<DbPerfStats> {
+    let args = args.unwrap_or_default();
+    let stats = db::perf_stats(args.limit.unwrap_or(50));
+    if args.reset {
+        db::reset_perf_stats();
+    }
+    Ok(stats)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunReadonlyQueryArgs {
+    pub sql: String,
+    /// 반환할 최대 행 수 (기본 500, 최대 `MAX_CONSOLE_ROWS`)
+    pub max_rows: Option<usize>,
+}
+
+/// 읽기 전용 SQL 콘솔 (SELECT만, SQLite authorizer로 강제)
+#[tauri::command]
+pub fn run_readonly_query(args: RunReadonlyQueryArgs, db_state: State<DbState>) -> CommandResult<ConsoleQueryResult> {
+    if args.sql.trim().is_empty() {
+        return Err(CommandError::new(ErrorCode::InvalidInput, "sql must not be empty"));
+    }
+    let db = db_state.0.lock().map_err(|e| CommandError::new(
+        ErrorCode::LockError,
+        format!("Failed to acquire database lock: {}", e),
+    ))?;
+    db.run_readonly_query(&args.sql, args.max_rows.unwrap_or(500))
+        .map_err(CommandError::from)
 }
@@ -0,0 +1,447 @@
+//! Translation Suggestion Commands
+//!
+//! 에디터에서 원문 블록 하나를 골랐을 때 TM, 용어집, LLM 번역 후보를 한 번에 모읍니다.
+//! 여러 세그먼트 번역 프롬프트도 여기서 조립해, 프롬프트 구성과 API 키가 WebView에 드러나지 않게 합니다.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use super::chat::effective_chat_settings;
+use super::dnt::load_dnt_matcher;
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::ProjectMetadata;
+use crate::suggest::llm::{self, LlmRequest};
+use crate::suggest::prompt::{assemble, split_output, AssembledPrompt, PromptContext, TmReference};
+use crate::suggest::{
+    glossary_suggestion, terms_in_source, tm_suggestions, AppliedTerm, SuggestionEngine, TranslationSuggestion,
+};
+use crate::text::dnt::DntMatcher;
+use crate::text::locale::mt_language_codes;
+use crate::text::normalize_for_matching;
+use crate::text::protect::{restore_response, ProviderMasker};
+use crate::text::restore_placeholders;
+use crate::text::tm_match::TmMatcher;
+use crate::text::words::resolve_source_language;
+
+/// 비교할 TM 단위 수 (분석과 같은 상한)
+const MAX_TM_UNITS: usize = 50_000;
+/// 기본 TM 후보 수
+const DEFAULT_TM_MATCHES: usize = 3;
+const MAX_TM_MATCHES: usize = 10;
+/// 원문에서 찾을 용어 수
+const MAX_GLOSSARY_TERMS: u32 = 50;
+/// 프롬프트 하나에 넣는 세그먼트 수
+const MAX_PROMPT_SEGMENTS: usize = 50;
+/// 프롬프트에 넣는 TM 참고 번역 (세그먼트당 / 전체)
+const PROMPT_TM_PER_SEGMENT: usize = 2;
+const MAX_PROMPT_TM: usize = 20;
+/// 프롬프트용 용어집 검색 수
+const MAX_PROMPT_TERMS: u32 = 200;
+
+/// LLM에 넘길 번역 언어 (번역 로캘의 영어 이름 > 에디터 표시 이름)
+fn llm_target_language(metadata: &ProjectMetadata) -> String {
+    metadata
+        .target_locale
+        .as_deref()
+        .map(|locale| mt_language_codes(locale).llm)
+        .or_else(|| metadata.target_language.clone())
+        .unwrap_or_else(|| "the target language".to_string())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestionEngineRequest {
+    pub engine: SuggestionEngine,
+    /// LLM 모델 (없으면 엔진 기본 모델)
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestTranslationArgs {
+    pub project_id: String,
+    pub block_id: String,
+    /// 비어 있으면 TM + 용어집
+    #[serde(default)]
+    pub engines: Vec<SuggestionEngineRequest>,
+    /// TM 후보 수 (기본 3, 최대 10)
+    pub max_tm_matches: Option<usize>,
+}
+
+/// 엔진별 실패 (다른 엔진 후보는 그대로 반환)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestionError {
+    pub engine: SuggestionEngine,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationSuggestions {
+    pub block_id: String,
+    pub source_text: String,
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    /// 요청한 엔진 순서대로 (TM은 일치율 순)
+    pub suggestions: Vec<TranslationSuggestion>,
+    pub errors: Vec<SuggestionError>,
+}
+
+/// 원문 블록 하나에 대한 번역 후보
+/// - TM/용어집은 로컬에서 바로 계산하고, LLM 엔진은 DB 잠금을 놓은 뒤 동시에 요청합니다.
+/// - TM/용어집 검색은 평문으로 하고, LLM에는 블록 HTML을 태그 토큰으로 바꿔 보내므로 LLM 후보는 서식이 살아 있는 HTML입니다.
+/// - 한 엔진이 실패해도 나머지 후보는 반환하고, 실패는 `errors`에 담습니다.
+#[tauri::command]
+pub async fn suggest_translation(
+    app: AppHandle,
+    args: SuggestTranslationArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<TranslationSuggestions> {
+    crate::crash::guard(&app, "suggest_translation", async {
+        let mut engines: Vec<SuggestionEngineRequest> = Vec::new();
+        for request in args.engines {
+            if !engines.iter().any(|e| e.engine == request.engine) {
+                engines.push(request);
+            }
+        }
+        if engines.is_empty() {
+            engines = [SuggestionEngine::Tm, SuggestionEngine::Glossary]
+                .into_iter()
+                .map(|engine| SuggestionEngineRequest { engine, model: None })
+                .collect();
+        }
+        let max_tm_matches = args
+            .max_tm_matches
+            .unwrap_or(DEFAULT_TM_MATCHES)
+            .clamp(1, MAX_TM_MATCHES);
+        let wants = |engine: SuggestionEngine| engines.iter().any(|e| e.engine == engine);
+        let dnt = load_dnt_matcher(&db_state, Some(&args.project_id))?;
+
+        let (source_html, source_text, source_language, target_language, target_for_llm, tm, glossary_entries) = {
+            let db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            let block = db.get_block(&args.block_id, &args.project_id).map_err(CommandError::from)?;
+            let source_text = normalize_for_matching(&block.content);
+            if source_text.trim().is_empty() {
+                return Err(CommandError::new(
+                    ErrorCode::InvalidInput,
+                    format!("Block {} has no text to translate", args.block_id),
+                ));
+            }
+            let metadata = db.load_project_metadata(&args.project_id).map_err(CommandError::from)?;
+            let settings = db.load_app_settings().map_err(CommandError::from)?;
+            let source_language = resolve_source_language(
+                metadata.source_language_or(settings.default_source_language).as_deref(),
+                std::iter::once(source_text.as_str()),
+            );
+
+            let tm = if wants(SuggestionEngine::Tm) {
+                let units = db
+                    .list_tm_units(&args.project_id, MAX_TM_UNITS)
+                    .map_err(CommandError::from)?;
+                let matcher = TmMatcher::new(&units, source_language.as_deref(), metadata.target_language.as_deref());
+                tm_suggestions(&matcher.top_matches(&source_text, max_tm_matches))
+            } else {
+                Vec::new()
+            };
+            let domain = Some(metadata.domain.trim()).filter(|d| !d.is_empty());
+            let glossary_entries = db
+                .search_glossary_in_text(&args.project_id, &source_text, domain, MAX_GLOSSARY_TERMS)
+                .map_err(CommandError::from)?;
+            let target_for_llm = llm_target_language(&metadata);
+            (block.content, source_text, source_language, metadata.target_language, target_for_llm, tm, glossary_entries)
+        };
+
+        let terms = terms_in_source(&source_text, &glossary_entries);
+        let request = LlmRequest {
+            source: &source_html,
+            source_language: source_language.as_deref(),
+            target_language: &target_for_llm,
+            terms: &terms,
+        };
+        let remote = engines.iter().filter(|e| e.engine.is_remote()).map(|e| {
+            let model = llm::model_or_default(e.engine, e.model.as_deref());
+            let request = &request;
+            let dnt = &dnt;
+            async move {
+                let result = match crate::network::ensure_online(llm::display_name(e.engine)) {
+                    Ok(()) => llm::translate(e.engine, &model, request, dnt).await,
+                    Err(err) => Err(err.message),
+                };
+                (e.engine, model, result)
+            }
+        });
+        let mut remote_results = futures::future::join_all(remote).await.into_iter();
+
+        let mut tm = Some(tm);
+        let mut suggestions = Vec::new();
+        let mut errors = Vec::new();
+        for request in &engines {
+            match request.engine {
+                SuggestionEngine::Tm => suggestions.extend(tm.take().unwrap_or_default()),
+                SuggestionEngine::Glossary => suggestions.extend(glossary_suggestion(&source_text, &glossary_entries)),
+                SuggestionEngine::Openai | SuggestionEngine::Anthropic => {
+                    let Some((engine, model, result)) = remote_results.next() else {
+                        continue;
+                    };
+                    match result {
+                        Ok(text) => suggestions.push(TranslationSuggestion {
+                            engine,
+                            text,
+                            score: None,
+                            label: format!("{} {}", llm::display_name(engine), model),
+                            origin: Some(model),
+                            matched_source: None,
+                            terms: terms.clone(),
+                        }),
+                        Err(error) => errors.push(SuggestionError { engine, error }),
+                    }
+                }
+            }
+        }
+
+        Ok(TranslationSuggestions {
+            block_id: args.block_id,
+            source_text,
+            source_language,
+            target_language,
+            suggestions,
+            errors,
+        })
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildTranslationPromptArgs {
+    pub project_id: String,
+    /// 번역할 블록 (최대 50개, 순서대로 세그먼트 번호 부여)
+    pub block_ids: Vec<String>,
+    /// 주어지면 조립한 프롬프트로 바로 번역 (`openai` / `anthropic`)
+    pub execute: Option<SuggestionEngineRequest>,
+}
+
+/// 세그먼트 번역 결과 (응답에서 빠졌거나 보호 토큰이 깨졌으면 `text`가 None)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentTranslation {
+    pub block_id: String,
+    pub text: Option<String>,
+    /// 보호 토큰(태그/DNT)이 누락/중복된 경우
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptExecution {
+    pub engine: SuggestionEngine,
+    pub model: String,
+    pub translations: Vec<SegmentTranslation>,
+    /// LLM 응답 원문 (태그 누락 시 확인용)
+    pub raw: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationPrompt {
+    /// 프롬프트에 넣은 블록 (텍스트가 없는 블록은 제외, 세그먼트 번호 순)
+    pub block_ids: Vec<String>,
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub prompt: AssembledPrompt,
+    pub terms: Vec<AppliedTerm>,
+    pub dnt_terms: Vec<String>,
+    pub tm_matches: Vec<TmReference>,
+    /// `execute`를 요청했을 때만
+    pub execution: Option<PromptExecution>,
+}
+
+/// 세그먼트 번역 프롬프트 조립 (용어집, DNT, TM 매치, 프로젝트 번역 규칙 포함)
+/// - 세그먼트는 블록 HTML 그대로 넣고, TM/용어집/DNT 검색만 평문으로 합니다.
+/// - `execute`가 있으면 저장된 API 키로 바로 번역해 세그먼트별 결과를 함께 반환합니다.
+///   이때 인라인 태그와 DNT 용어는 토큰으로 바꿔 보내고 응답에서 복원합니다 (반환하는 `prompt`는 원래 텍스트).
+#[tauri::command]
+pub async fn build_translation_prompt(
+    app: AppHandle,
+    args: BuildTranslationPromptArgs,
+    db_state: State<'_, DbState>,
+) -> CommandResult<TranslationPrompt> {
+    crate::crash::guard(&app, "build_translation_prompt", async {
+        if args.block_ids.is_empty() {
+            return Err(CommandError::new(ErrorCode::InvalidInput, "blockIds must not be empty"));
+        }
+        if args.block_ids.len() > MAX_PROMPT_SEGMENTS {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("At most {} blocks can be translated at once", MAX_PROMPT_SEGMENTS),
+            ));
+        }
+        if let Some(execute) = &args.execute {
+            if !execute.engine.is_remote() {
+                return Err(CommandError::new(
+                    ErrorCode::InvalidInput,
+                    format!("{} cannot execute a prompt", execute.engine.as_str()),
+                ));
+            }
+        }
+
+        let (segments, source_language, target_language, target_for_llm, chat, terms, dnt, dnt_terms, tm_matches) = {
+            let db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            // (블록 id, 블록 HTML, 매칭용 평문)
+            let segments: Vec<(String, String, String)> = db
+                .get_blocks(&args.project_id, &args.block_ids)
+                .map_err(CommandError::from)?
+                .into_iter()
+                .map(|block| {
+                    let text = normalize_for_matching(&block.content);
+                    (block.id, block.content, text)
+                })
+                .filter(|(_, _, text)| !text.is_empty())
+                .collect();
+            if segments.is_empty() {
+                return Err(CommandError::new(ErrorCode::InvalidInput, "Selected blocks have no text to translate"));
+            }
+            let metadata = db.load_project_metadata(&args.project_id).map_err(CommandError::from)?;
+            let settings = db.load_app_settings().map_err(CommandError::from)?;
+            let source_language = resolve_source_language(
+                metadata.source_language_or(settings.default_source_language).as_deref(),
+                segments.iter().map(|(_, _, text)| text.as_str()),
+            );
+            let chat = effective_chat_settings(&db, &args.project_id)?;
+
+            let units = db
+                .list_tm_units(&args.project_id, MAX_TM_UNITS)
+                .map_err(CommandError::from)?;
+            let matcher = TmMatcher::new(&units, source_language.as_deref(), metadata.target_language.as_deref());
+            let mut tm_matches: Vec<TmReference> = Vec::new();
+            for (_, _, text) in &segments {
+                for m in matcher.top_matches(text, PROMPT_TM_PER_SEGMENT) {
+                    let reference = TmReference {
+                        source: m.unit.source.clone(),
+                        target: m.unit.target.clone(),
+                        score: m.score,
+                    };
+                    if !tm_matches.contains(&reference) {
+                        tm_matches.push(reference);
+                    }
+                }
+            }
+            tm_matches.truncate(MAX_PROMPT_TM);
+
+            let joined = segments.iter().map(|(_, _, text)| text.as_str()).collect::<Vec<_>>().join("\n");
+            let domain = Some(metadata.domain.trim()).filter(|d| !d.is_empty());
+            let glossary_entries = db
+                .search_glossary_in_text(&args.project_id, &joined, domain, MAX_PROMPT_TERMS)
+                .map_err(CommandError::from)?;
+            let terms = terms_in_source(&joined, &glossary_entries);
+
+            let dnt = DntMatcher::new(&db.list_dnt_terms(Some(&args.project_id)).map_err(CommandError::from)?)
+                .map_err(|e| CommandError::new(
+                    ErrorCode::InvalidPattern,
+                    "저장된 DNT 패턴을 해석할 수 없습니다.",
+                ).with_details(e.to_string()))?;
+            let mut dnt_terms: Vec<String> = Vec::new();
+            for (_, _, text) in &segments {
+                for m in dnt.find_all(text) {
+                    let term = text[m.start..m.end].to_string();
+                    if !dnt_terms.contains(&term) {
+                        dnt_terms.push(term);
+                    }
+                }
+            }
+
+            let target_for_llm = llm_target_language(&metadata);
+            (segments, source_language, metadata.target_language, target_for_llm, chat, terms, dnt, dnt_terms, tm_matches)
+        };
+
+        let context = PromptContext {
+            source_language: source_language.as_deref(),
+            target_language: &target_for_llm,
+            persona: chat.as_ref().map_or("", |c| c.translator_persona.as_str()),
+            rules: chat.as_ref().map_or("", |c| c.translation_rules.as_str()),
+            project_context: chat.as_ref().map_or("", |c| c.project_context.as_str()),
+            terms: &terms,
+            dnt: &dnt_terms,
+            tm: &tm_matches,
+        };
+        let prompt = assemble(&context, &segments.iter().map(|(_, html, _)| html.as_str()).collect::<Vec<_>>());
+
+        let execution = match args.execute {
+            Some(execute) => {
+                crate::network::ensure_online(llm::display_name(execute.engine))?;
+                let model = llm::model_or_default(execute.engine, execute.model.as_deref());
+
+                // 세그먼트(태그+DNT)와 지시문(DNT)을 한 토큰 공간에서 마스킹 (DNT 목록은 토큰 지시로 대신함)
+                let mut masker = ProviderMasker::new(&dnt);
+                let masked_segments: Vec<_> = segments.iter().map(|(_, html, _)| masker.source(html)).collect();
+                let sent = assemble(
+                    &PromptContext { dnt: &[], ..context },
+                    &masked_segments.iter().map(|m| m.text.as_str()).collect::<Vec<_>>(),
+                );
+                let system = masker.instructions(&sent.system);
+                let has_tokens = !system.placeholders.is_empty() || masked_segments.iter().any(|m| !m.placeholders.is_empty());
+                let raw = llm::complete(
+                    execute.engine,
+                    &model,
+                    &llm::with_token_instruction(system.text, has_tokens),
+                    &sent.user,
+                )
+                .await
+                .map_err(|e| CommandError::new(ErrorCode::NetworkError, e))?;
+
+                let translations = segments
+                    .iter()
+                    .zip(&masked_segments)
+                    .zip(split_output(&raw, segments.len()))
+                    .map(|(((block_id, _, _), masked), text)| {
+                        let restored = text.map(|t| restore_response(&t, &masked.placeholders, &system.placeholders));
+                        let error = restored.as_ref().filter(|r| !r.is_intact()).map(|r| {
+                            format!(
+                                "Inline tags or protected terms were lost (missing: [{}], duplicated: [{}])",
+                                r.missing_tokens.join(", "),
+                                r.duplicated_tokens.join(", ")
+                            )
+                        });
+                        SegmentTranslation {
+                            block_id: block_id.clone(),
+                            text: restored.filter(|r| r.is_intact()).map(|r| r.text),
+                            error,
+                        }
+                    })
+                    .collect();
+                let all_placeholders: Vec<_> = masked_segments
+                    .iter()
+                    .flat_map(|m| m.placeholders.iter().cloned())
+                    .chain(system.placeholders.iter().cloned())
+                    .collect();
+                Some(PromptExecution {
+                    engine: execute.engine,
+                    model,
+                    translations,
+                    raw: restore_placeholders(&raw, &all_placeholders).text,
+                })
+            }
+            None => None,
+        };
+
+        Ok(TranslationPrompt {
+            block_ids: segments.into_iter().map(|(id, _, _)| id).collect(),
+            source_language,
+            target_language,
+            prompt,
+            terms,
+            dnt_terms,
+            tm_matches,
+            execution,
+        })
+    })
+    .await
+}
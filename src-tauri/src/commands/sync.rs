@@ -0,0 +1,165 @@
+//! Remote Sync Commands
+//!
+//! 전역 용어집 WebDAV/S3 동기화 설정, 수동 실행, 스케줄 실행
+//! Notion 데이터베이스 → 프로젝트 용어집 가져오기 (수동 실행, 스케줄 실행)
+
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::models::NotionGlossaryMapping;
+use crate::sync::{self, GlossarySyncReport, NotionGlossarySyncReport, SyncRemote, SyncRemoteInfo};
+
+/// 스케줄 확인 간격
+const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+fn sync_error(message: String) -> CommandError {
+    CommandError::new(ErrorCode::SyncError, message)
+}
+
+/// 용어집 동기화 위치 저장 (자격 증명 포함, vault에 저장)
+#[tauri::command]
+pub async fn set_glossary_sync_remote(app: AppHandle, remote: SyncRemote) -> CommandResult<SyncRemoteInfo> {
+    crate::crash::guard(&app, "set_glossary_sync_remote", async {
+        sync::save_glossary_remote(&remote)
+            .await
+            .map_err(|message| CommandError::new(ErrorCode::InvalidInput, message))?;
+        Ok(remote.info())
+    })
+    .await
+}
+
+/// 용어집 동기화 위치 조회 (자격 증명 제외, 없으면 None)
+#[tauri::command]
+pub async fn get_glossary_sync_remote(app: AppHandle) -> CommandResult<Option<SyncRemoteInfo>> {
+    crate::crash::guard(&app, "get_glossary_sync_remote", async {
+        Ok(sync::load_glossary_remote()
+            .await
+            .map_err(sync_error)?
+            .map(|r| r.info()))
+    })
+    .await
+}
+
+/// 용어집 동기화 위치 삭제
+#[tauri::command]
+pub async fn clear_glossary_sync_remote(app: AppHandle) -> CommandResult<()> {
+    crate::crash::guard(&app, "clear_glossary_sync_remote", async {
+        sync::clear_glossary_remote().await.map_err(sync_error)
+    })
+    .await
+}
+
+/// 전역 용어집 지금 동기화
+#[tauri::command]
+pub async fn sync_glossary_now(app: AppHandle, db_state: State<'_, DbState>) -> CommandResult<GlossarySyncReport> {
+    crate::crash::guard(&app, "sync_glossary_now", async {
+        crate::network::ensure_online("Glossary sync")?;
+        sync::run_glossary_sync(&db_state).await.map_err(sync_error)
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncGlossaryFromNotionArgs {
+    pub project_id: String,
+    /// 데이터베이스 ID 또는 URL
+    pub database_id: String,
+    pub mapping: NotionGlossaryMapping,
+}
+
+/// Notion 데이터베이스를 프로젝트 용어집으로 지금 가져오기
+#[tauri::command]
+pub async fn sync_glossary_from_notion(
+    app: AppHandle,
+    db_state: State<'_, DbState>,
+    args: SyncGlossaryFromNotionArgs,
+) -> CommandResult<NotionGlossarySyncReport> {
+    crate::crash::guard(&app, "sync_glossary_from_notion", async {
+        crate::network::ensure_online("Notion")?;
+        args.mapping
+            .validate()
+            .map_err(|message| CommandError::new(ErrorCode::InvalidInput, message))?;
+        sync::run_notion_glossary_sync(&db_state, &args.project_id, &args.database_id, &args.mapping)
+            .await
+            .map_err(sync_error)
+    })
+    .await
+}
+
+/// 설정(`glossarySync`)에 따라 주기적으로 용어집 동기화
+/// - 매분 설정을 확인하므로 주기/사용 여부 변경은 재시작 없이 반영됩니다.
+/// - 오프라인 중에는 건너뛰므로 다시 연결되면 바로 동기화합니다.
+pub fn spawn_glossary_sync_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_run: Option<Instant> = None;
+        loop {
+            tokio::time::sleep(SCHEDULER_TICK).await;
+
+            let state = app.state::<DbState>();
+            let schedule = match state.0.lock() {
+                Ok(db) => db.load_app_settings().ok().map(|s| s.glossary_sync),
+                Err(_) => None,
+            };
+            let Some(schedule) = schedule.filter(|s| s.enabled) else {
+                continue;
+            };
+            if !crate::network::is_online() {
+                continue;
+            }
+            let interval = Duration::from_secs(u64::from(schedule.interval_minutes) * 60);
+            if matches!(last_run, Some(t) if t.elapsed() < interval) {
+                continue;
+            }
+            last_run = Some(Instant::now());
+
+            if let Err(e) = sync::run_glossary_sync(&state).await {
+                eprintln!("[Sync] Scheduled glossary sync failed: {}", e);
+            }
+        }
+    });
+}
+
+/// 설정(`notionGlossarySync`)에 따라 주기적으로 Notion 데이터베이스를 용어집으로 가져오기
+/// - 전역 용어집 동기화 스케줄러와 같은 방식 (매분 설정 확인, 오프라인 중에는 건너뜀)
+pub fn spawn_notion_glossary_sync_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_run: Option<Instant> = None;
+        loop {
+            tokio::time::sleep(SCHEDULER_TICK).await;
+
+            let state = app.state::<DbState>();
+            let schedule = match state.0.lock() {
+                Ok(db) => db.load_app_settings().ok().map(|s| s.notion_glossary_sync),
+                Err(_) => None,
+            };
+            let Some(schedule) = schedule.filter(|s| s.enabled && !s.sources.is_empty()) else {
+                continue;
+            };
+            if !crate::network::is_online() {
+                continue;
+            }
+            let interval = Duration::from_secs(u64::from(schedule.interval_minutes) * 60);
+            if matches!(last_run, Some(t) if t.elapsed() < interval) {
+                continue;
+            }
+            last_run = Some(Instant::now());
+
+            for source in &schedule.sources {
+                if let Err(e) =
+                    sync::run_notion_glossary_sync(&state, &source.project_id, &source.database_id, &source.mapping)
+                        .await
+                {
+                    eprintln!(
+                        "[Sync] Scheduled Notion glossary sync failed ({}): {}",
+                        source.project_id, e
+                    );
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,324 @@
+//! Text Protection Commands
+//!
+//! LLM/MT 프로바이더 왕복 전후의 텍스트 보호(인라인 태그/DNT 마스킹), 언어 감지,
+//! 텍스트 파일 읽기(인코딩 자동 감지), 단어 수 계산, 음역 API
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::dnt::load_dnt_matcher;
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::utils::validate_path;
+use crate::text::encoding::{self, DecodedText};
+use crate::text::lang::{self, language_mismatch_warning, LanguageDetection};
+use crate::text::protect::{restore_response, ProviderMasker, TOKEN_INSTRUCTION};
+use crate::text::tags::{protect_tags, restore_tags};
+use crate::text::translit::{self, TransliterationScheme};
+use crate::text::words::{self, ProjectWordStats, WordCount};
+use crate::text::{strip_html, MaskedText, Placeholder, RestoreResult};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectInlineTagsArgs {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreTextArgs {
+    pub text: String,
+    pub placeholders: Vec<Placeholder>,
+    /// true면 토큰이 누락/중복되었을 때 오류 반환
+    pub strict: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectTextForProviderArgs {
+    /// 번역할 원문 (인라인 태그 + DNT 용어 마스킹, 없으면 빈 문자열)
+    #[serde(default)]
+    pub text: String,
+    /// 같은 요청의 지시문/대화 (용어집, 프로젝트 맥락, 채팅 메시지, 도구 결과): DNT 용어만 마스킹
+    #[serde(default)]
+    pub instructions: Vec<String>,
+    /// 해당 프로젝트 DNT 용어도 마스킹 (없으면 전역 용어만)
+    pub project_id: Option<String>,
+    /// 같은 요청에서 앞서 받은 `tagTokens`/`dntTokens` (나눠서 마스킹해도 토큰 번호가 겹치지 않게)
+    #[serde(default)]
+    pub tag_tokens: usize,
+    #[serde(default)]
+    pub dnt_tokens: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreProviderTextArgs {
+    pub text: String,
+    /// 원문 토큰 (strict면 응답에 정확히 한 번씩 있어야 함)
+    pub placeholders: Vec<Placeholder>,
+    /// 지시문 토큰 (응답에 있으면 복원만 함)
+    #[serde(default)]
+    pub instruction_placeholders: Vec<Placeholder>,
+    /// true면 원문 토큰이 누락/중복되었을 때 오류 반환
+    pub strict: Option<bool>,
+}
+
+/// 프로바이더 전송용 마스킹 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderMaskedText {
+    pub text: String,
+    pub placeholders: Vec<Placeholder>,
+    /// 입력 순서대로
+    pub instructions: Vec<String>,
+    pub instruction_placeholders: Vec<Placeholder>,
+    /// 다음 호출에 넘길 누적 토큰 수
+    pub tag_tokens: usize,
+    pub dnt_tokens: usize,
+    /// 토큰이 하나라도 생기면 시스템 프롬프트에 덧붙일 지시
+    pub token_instruction: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectLanguageArgs {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckImportLanguageArgs {
+    pub project_id: String,
+    /// 붙여넣거나 가져온 원문 (HTML이면 평문으로 변환해 판단)
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransliterateArgs {
+    pub text: String,
+    /// 원문 문자 체계 ("hangul" | "kana" | "cyrillic")
+    pub scheme: TransliterationScheme,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadTextFileArgs {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountWordsArgs {
+    pub texts: Vec<String>,
+    /// 텍스트 언어 (BCP 47, CJK 환산 계수 결정)
+    pub language: Option<String>,
+    /// HTML로 보고 태그를 제외할지 (기본 true)
+    pub html: Option<bool>,
+    /// CJK 글자 → 단어 환산 계수 직접 지정 (없으면 언어별 기본값)
+    pub cjk_factor: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WordCountReport {
+    pub cjk_factor: f64,
+    pub total: WordCount,
+    /// 입력 순서대로
+    pub items: Vec<WordCount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectWordStatsArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportLanguageCheck {
+    pub detection: LanguageDetection,
+    /// 언어쌍과 맞지 않을 때의 경고 메시지
+    pub warning: Option<String>,
+}
+
+fn check_restored(result: RestoreResult, strict: bool) -> CommandResult<RestoreResult> {
+    if strict && !result.is_intact() {
+        return Err(CommandError::new(
+            ErrorCode::TokenMismatch,
+            "번역 결과에서 보호 토큰이 누락되었거나 중복되었습니다.",
+        )
+        .with_details(format!(
+                "missing: [{}], duplicated: [{}]",
+                result.missing_tokens.join(", "),
+                result.duplicated_tokens.join(", ")
+            )));
+    }
+    Ok(result)
+}
+
+/// 인라인 태그/플레이스홀더를 토큰으로 치환
+#[tauri::command]
+pub fn protect_inline_tags(args: ProtectInlineTagsArgs) -> CommandResult<MaskedText> {
+    Ok(protect_tags(&args.text))
+}
+
+/// 토큰을 원래 태그로 복원하고 누락/중복 토큰을 보고
+#[tauri::command]
+pub fn restore_inline_tags(args: RestoreTextArgs) -> CommandResult<RestoreResult> {
+    check_restored(
+        restore_tags(&args.text, &args.placeholders),
+        args.strict.unwrap_or(false),
+    )
+}
+
+/// 프로바이더 전송용 텍스트 보호 (인라인 태그 + DNT 용어)
+/// - 한 요청에 들어가는 원문과 지시문을 같은 토큰 공간에서 마스킹합니다.
+/// - 반환된 placeholders/instructionPlaceholders를 `restore_provider_text`에 그대로 넘기면 한 번에 복원됩니다.
+#[tauri::command]
+pub fn protect_text_for_provider(
+    args: ProtectTextForProviderArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ProviderMaskedText> {
+    let matcher = load_dnt_matcher(&db_state, args.project_id.as_deref())?;
+    let mut masker = ProviderMasker::resume(&matcher, args.tag_tokens, args.dnt_tokens);
+
+    let source = masker.source(&args.text);
+    let mut instructions = Vec::with_capacity(args.instructions.len());
+    let mut instruction_placeholders = Vec::new();
+    for text in &args.instructions {
+        let masked = masker.instructions(text);
+        instructions.push(masked.text);
+        instruction_placeholders.extend(masked.placeholders);
+    }
+
+    let (tag_tokens, dnt_tokens) = masker.token_counts();
+    let has_tokens = !source.placeholders.is_empty() || !instruction_placeholders.is_empty();
+    Ok(ProviderMaskedText {
+        text: source.text,
+        placeholders: source.placeholders,
+        instructions,
+        instruction_placeholders,
+        tag_tokens,
+        dnt_tokens,
+        token_instruction: has_tokens.then(|| TOKEN_INSTRUCTION.to_string()),
+    })
+}
+
+/// 프로바이더 응답 복원 (태그 + DNT 토큰)
+#[tauri::command]
+pub fn restore_provider_text(args: RestoreProviderTextArgs) -> CommandResult<RestoreResult> {
+    check_restored(
+        restore_response(&args.text, &args.placeholders, &args.instruction_placeholders),
+        args.strict.unwrap_or(false),
+    )
+}
+
+/// 텍스트 언어 감지
+#[tauri::command]
+pub fn detect_language(args: DetectLanguageArgs) -> CommandResult<LanguageDetection> {
+    Ok(lang::detect_language(&args.text))
+}
+
+/// 가져온 원문의 언어가 프로젝트 언어쌍과 맞는지 검사
+/// - 원문 언어는 앱 설정의 기본 원문 언어, 번역 언어는 프로젝트 설정을 기준으로 합니다.
+#[tauri::command]
+pub fn check_import_language(
+    args: CheckImportLanguageArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ImportLanguageCheck> {
+    let (metadata, settings) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        (
+            db.load_project_metadata(&args.project_id).map_err(CommandError::from)?,
+            db.load_app_settings().map_err(CommandError::from)?,
+        )
+    };
+
+    let detection = lang::detect_language(&strip_html(&args.text));
+    let source_language = metadata.source_language_or(settings.default_source_language);
+    let warning = language_mismatch_warning(
+        &detection,
+        source_language.as_deref(),
+        metadata.target_language.as_deref(),
+    );
+    Ok(ImportLanguageCheck { detection, warning })
+}
+
+/// 인명/용어를 라틴 문자로 음역 (한글 RR, 가나 헵번식, 키릴 문자)
+#[tauri::command]
+pub fn transliterate(args: TransliterateArgs) -> CommandResult<String> {
+    Ok(translit::transliterate(&args.text, args.scheme))
+}
+
+/// 텍스트 파일(TXT/CSV/SRT 등)을 인코딩 자동 감지 후 UTF-8로 읽기
+#[tauri::command]
+pub fn read_text_file(args: ReadTextFileArgs) -> CommandResult<DecodedText> {
+    let path = validate_path(&args.path)?;
+    encoding::read_text_file(&path).map_err(|e| CommandError::new(
+        ErrorCode::ReadError,
+        format!("Failed to read file: {}", e),
+    ))
+}
+
+/// 단어 수 계산 (CAT 도구 규칙: 태그 제외, CJK 글자는 언어별 계수로 환산)
+#[tauri::command]
+pub fn count_words(args: CountWordsArgs) -> CommandResult<WordCountReport> {
+    let factor = match args.cjk_factor {
+        Some(f) if f.is_finite() && f > 0.0 => f,
+        Some(f) => {
+            return Err(CommandError::new(
+                ErrorCode::InvalidInput,
+                format!("Invalid cjkFactor: {}", f),
+            ).with_details("cjkFactor must be a positive number"))
+        }
+        None => words::cjk_factor(args.language.as_deref()),
+    };
+    let html = args.html.unwrap_or(true);
+
+    let items: Vec<WordCount> = args
+        .texts
+        .iter()
+        .map(|t| {
+            if html {
+                words::count_text(&strip_html(t), factor)
+            } else {
+                words::count_text(t, factor)
+            }
+        })
+        .collect();
+    let mut total = WordCount::default();
+    for item in &items {
+        total.add(item);
+    }
+    Ok(WordCountReport {
+        cjk_factor: factor,
+        total,
+        items,
+    })
+}
+
+/// 프로젝트 원문/번역문 단어 통계
+/// - 원문 언어는 앱 설정의 기본 원문 언어, 없으면 원문에서 감지합니다.
+#[tauri::command]
+pub fn get_project_word_stats(
+    args: ProjectWordStatsArgs,
+    db_state: State<DbState>,
+) -> CommandResult<ProjectWordStats> {
+    let (project, settings) = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        (
+            db.load_project(&args.project_id).map_err(CommandError::from)?,
+            db.load_app_settings().map_err(CommandError::from)?,
+        )
+    };
+
+    Ok(words::project_word_stats(&project, settings.default_source_language.as_deref()))
+}
@@ -0,0 +1,264 @@
+//! TMS Connector Commands
+//!
+//! Phrase TMS / Crowdin 작업 받기/올리기. 토큰은 SecretManager vault에 저장됩니다.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use crate::db::{DbState, TmsJobLink, TmsSegmentLink};
+use crate::error::{CommandError, CommandResult, ErrorCode};
+use crate::interop::segment_target_text;
+use crate::tms::{self, PushItem, TmsCredentials, TmsJob, TmsProvider};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsProviderArgs {
+    /// "phrase" | "crowdin"
+    pub provider: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsSetCredentialsArgs {
+    pub provider: String,
+    pub token: String,
+    /// API 주소 (예: Phrase US `https://us.cloud.memsource.com/web`, Crowdin Enterprise `https://{org}.api.crowdin.com/api/v2`)
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsListJobsArgs {
+    pub provider: String,
+    /// Phrase 프로젝트 UID (필수) / Crowdin 프로젝트 ID (없으면 배정된 전체 작업)
+    pub remote_project: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsPullJobArgs {
+    pub provider: String,
+    pub remote_project: String,
+    pub job_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsPullJobResult {
+    pub project_id: String,
+    pub title: String,
+    pub segment_count: usize,
+    pub translated_segments: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsPushJobArgs {
+    pub project_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsPushJobResult {
+    pub provider: TmsProvider,
+    pub pushed_segments: usize,
+    /// 받은 뒤(또는 마지막으로 올린 뒤) 바뀌지 않아 건너뛴 세그먼트 수
+    pub unchanged_segments: usize,
+    pub errors: Vec<String>,
+}
+
+fn parse_provider(value: &str) -> CommandResult<TmsProvider> {
+    TmsProvider::parse(value).ok_or_else(|| CommandError::new(
+        ErrorCode::InvalidInput,
+        format!("Unsupported TMS provider: {}", value),
+    ).with_details("phrase, crowdin"))
+}
+
+fn remote_error(message: String) -> CommandError {
+    CommandError::new(ErrorCode::TmsError, message)
+}
+
+/// TMS API 토큰 저장
+#[tauri::command]
+pub async fn tms_set_credentials(app: AppHandle, args: TmsSetCredentialsArgs) -> CommandResult<()> {
+    crate::crash::guard(&app, "tms_set_credentials", async {
+        let provider = parse_provider(&args.provider)?;
+        let credentials = TmsCredentials {
+            token: args.token,
+            base_url: args.base_url,
+        };
+        tms::save_credentials(provider, &credentials)
+            .await
+            .map_err(|message| CommandError::new(ErrorCode::InvalidInput, message))
+    })
+    .await
+}
+
+/// TMS 토큰 존재 여부
+#[tauri::command]
+pub async fn tms_has_credentials(app: AppHandle, args: TmsProviderArgs) -> CommandResult<bool> {
+    crate::crash::guard(&app, "tms_has_credentials", async {
+        let provider = parse_provider(&args.provider)?;
+        Ok(tms::load_credentials(provider).await.map_err(remote_error)?.is_some())
+    })
+    .await
+}
+
+/// TMS 토큰 삭제
+#[tauri::command]
+pub async fn tms_clear_credentials(app: AppHandle, args: TmsProviderArgs) -> CommandResult<()> {
+    crate::crash::guard(&app, "tms_clear_credentials", async {
+        let provider = parse_provider(&args.provider)?;
+        tms::clear_credentials(provider).await.map_err(remote_error)
+    })
+    .await
+}
+
+/// 배정된 작업 목록
+#[tauri::command]
+pub async fn tms_list_jobs(app: AppHandle, args: TmsListJobsArgs) -> CommandResult<Vec<TmsJob>> {
+    crate::crash::guard(&app, "tms_list_jobs", async {
+        crate::network::ensure_online("TMS")?;
+        let provider = parse_provider(&args.provider)?;
+        let remote_project = args.remote_project.as_deref().map(str::trim).filter(|p| !p.is_empty());
+        tms::list_jobs(provider, remote_project).await.map_err(remote_error)
+    })
+    .await
+}
+
+/// 작업을 받아 새 ITE 프로젝트로 만들기
+#[tauri::command]
+pub async fn tms_pull_job(app: AppHandle, args: TmsPullJobArgs, db_state: State<'_, DbState>) -> CommandResult<TmsPullJobResult> {
+    crate::crash::guard(&app, "tms_pull_job", async {
+        crate::network::ensure_online("TMS")?;
+        let provider = parse_provider(&args.provider)?;
+        let pulled = tms::pull_job(provider, args.remote_project.trim(), args.job_id.trim())
+            .await
+            .map_err(remote_error)?;
+        if pulled.segments.is_empty() {
+            return Err(CommandError::new(
+                ErrorCode::InvalidOperation,
+                "The job has no translatable segments",
+            ).with_details(pulled.job.name));
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let (project, links) = tms::build_project(&pulled, now);
+        let translated_segments = links.iter().filter(|l| !l.base_target.is_empty()).count();
+        let job = TmsJobLink {
+            provider: provider.as_str().to_string(),
+            remote_project: pulled.job.remote_project.clone(),
+            job_id: pulled.job.id.clone(),
+            job_name: pulled.job.name.clone(),
+            target_lang: pulled.job.target_lang.clone(),
+            document: pulled.document,
+            pulled_at: now,
+            pushed_at: None,
+        };
+
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.save_project(&project).map_err(CommandError::from)?;
+        db.save_tms_job(&project.id, &job, &links)
+            .map_err(CommandError::from)?;
+
+        Ok(TmsPullJobResult {
+            project_id: project.id.clone(),
+            title: project.metadata.title.clone(),
+            segment_count: links.len(),
+            translated_segments,
+        })
+    })
+    .await
+}
+
+/// 받은 뒤 바뀐 번역문을 TMS로 올리기
+#[tauri::command]
+pub async fn tms_push_job(app: AppHandle, args: TmsPushJobArgs, db_state: State<'_, DbState>) -> CommandResult<TmsPushJobResult> {
+    crate::crash::guard(&app, "tms_push_job", async {
+        crate::network::ensure_online("TMS")?;
+        let (project, job, links) = {
+            let db = db_state.0.lock().map_err(|e| CommandError::new(
+                ErrorCode::LockError,
+                format!("Failed to acquire database lock: {}", e),
+            ))?;
+            let job = db
+                .get_tms_job(&args.project_id)
+                .map_err(CommandError::from)?
+                .ok_or_else(|| CommandError::new(ErrorCode::InvalidOperation, "Project was not pulled from a TMS"))?;
+            (
+                db.load_project(&args.project_id).map_err(CommandError::from)?,
+                job,
+                db.list_tms_segments(&args.project_id).map_err(CommandError::from)?,
+            )
+        };
+        let provider = parse_provider(&job.provider)?;
+
+        let mut changed: Vec<TmsSegmentLink> = Vec::new();
+        for link in &links {
+            let Some(segment) = project.segments.iter().find(|s| s.group_id == link.segment_id) else {
+                continue;
+            };
+            let target = segment_target_text(&project, segment);
+            if !target.is_empty() && target != link.base_target {
+                changed.push(TmsSegmentLink {
+                    base_target: target,
+                    ..link.clone()
+                });
+            }
+        }
+        let unchanged_segments = links.len() - changed.len();
+        if changed.is_empty() {
+            return Ok(TmsPushJobResult {
+                provider,
+                pushed_segments: 0,
+                unchanged_segments,
+                errors: Vec::new(),
+            });
+        }
+
+        let items: Vec<PushItem> = changed
+            .iter()
+            .map(|l| PushItem {
+                remote_id: l.remote_id.clone(),
+                target: l.base_target.clone(),
+            })
+            .collect();
+        let outcome = tms::push_job(
+            provider,
+            &job.remote_project,
+            &job.job_id,
+            &job.target_lang,
+            job.document.as_deref(),
+            &items,
+        )
+        .await
+        .map_err(remote_error)?;
+
+        let pushed: Vec<TmsSegmentLink> = changed
+            .into_iter()
+            .filter(|l| outcome.pushed.contains(&l.remote_id))
+            .collect();
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.mark_tms_pushed(
+            &args.project_id,
+            &pushed,
+            outcome.document.as_deref(),
+            chrono::Utc::now().timestamp_millis(),
+        )
+        .map_err(CommandError::from)?;
+
+        Ok(TmsPushJobResult {
+            provider,
+            pushed_segments: pushed.len(),
+            unchanged_segments,
+            errors: outcome.errors,
+        })
+    })
+    .await
+}
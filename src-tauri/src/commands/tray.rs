@@ -0,0 +1,221 @@
+//! System Tray
+//!
+//! 트레이 아이콘과 빠른 작업 메뉴 (최근 프로젝트, 새 프로젝트, 저장/백업 상태)
+//! - `keepRunningInTray` 설정이 켜져 있으면 메인 창을 닫아도 숨기기만 하므로,
+//!   백그라운드 백업과 MCP 서버가 계속 동작합니다.
+
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+use tauri::menu::{CheckMenuItemBuilder, Menu, MenuBuilder, MenuEvent, MenuItemBuilder, SubmenuBuilder};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Emitter, Manager, Window};
+
+use super::deep_link::{navigate, DeepLinkTarget};
+use super::settings::{update_app_settings, UpdateAppSettingsArgs};
+use super::storage::auto_backup_dir;
+use crate::db::DbState;
+use crate::error::CommandResult;
+use crate::package;
+
+const TRAY_ID: &str = "main-tray";
+
+/// 트레이의 "새 프로젝트" 선택 이벤트
+pub const TRAY_NEW_PROJECT_EVENT: &str = "tray-new-project";
+
+/// 트레이 메뉴에 보여 줄 최근 프로젝트 수
+const TRAY_RECENT_PROJECTS: usize = 8;
+
+/// 상태 표시 갱신 주기
+const TRAY_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+const MENU_SHOW: &str = "tray:show";
+const MENU_NEW_PROJECT: &str = "tray:new-project";
+const MENU_KEEP_RUNNING: &str = "tray:keep-running";
+const MENU_QUIT: &str = "tray:quit";
+const MENU_PROJECT_PREFIX: &str = "tray:project:";
+
+fn format_time(ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms)
+        .map(|t| t.with_timezone(&chrono::Local).format("%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+/// 현재 DB 상태로 트레이 메뉴 생성
+fn build_tray_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let (recent, settings) = {
+        let state = app.state::<DbState>();
+        let loaded = match state.0.lock() {
+            Ok(db) => (
+                db.list_recent_projects(TRAY_RECENT_PROJECTS, &[], false).unwrap_or_default(),
+                db.load_app_settings().unwrap_or_default(),
+            ),
+            Err(_) => (Vec::new(), Default::default()),
+        };
+        loaded
+    };
+
+    let mut recent_menu = SubmenuBuilder::new(app, "Recent Projects");
+    if recent.is_empty() {
+        recent_menu = recent_menu.item(&MenuItemBuilder::new("No projects").enabled(false).build(app)?);
+    }
+    for project in &recent {
+        let id = format!("{}{}", MENU_PROJECT_PREFIX, project.id);
+        recent_menu = recent_menu.item(&MenuItemBuilder::with_id(id, &project.title).build(app)?);
+    }
+
+    // 저장/백업 상태 (표시 전용)
+    let saved = match recent.first() {
+        Some(p) => format!("Last saved: {} ({})", format_time(p.updated_at), p.title),
+        None => "Last saved: -".to_string(),
+    };
+    let last_backup = auto_backup_dir(app)
+        .ok()
+        .and_then(|dir| package::list_backups(&dir).ok())
+        .and_then(|backups| backups.first().map(|b| b.created_at));
+    let backup = match last_backup {
+        Some(at) => format!("Last backup: {}", format_time(at)),
+        None => "Last backup: none".to_string(),
+    };
+    let cloud = match (settings.cloud_backup.enabled, &settings.cloud_backup.connector_id) {
+        (true, Some(connector)) => format!(
+            "Cloud backup: every {}h ({})",
+            settings.cloud_backup.interval_hours, connector
+        ),
+        _ => "Cloud backup: off".to_string(),
+    };
+
+    MenuBuilder::new(app)
+        .item(&MenuItemBuilder::with_id(MENU_SHOW, "Show OddEyes.ai").build(app)?)
+        .item(&MenuItemBuilder::with_id(MENU_NEW_PROJECT, "New Project").build(app)?)
+        .item(&recent_menu.build()?)
+        .separator()
+        .item(&MenuItemBuilder::new(saved).enabled(false).build(app)?)
+        .item(&MenuItemBuilder::new(backup).enabled(false).build(app)?)
+        .item(&MenuItemBuilder::new(cloud).enabled(false).build(app)?)
+        .separator()
+        .item(
+            &CheckMenuItemBuilder::with_id(MENU_KEEP_RUNNING, "Keep Running in Tray")
+                .checked(settings.keep_running_in_tray)
+                .build(app)?,
+        )
+        .item(&MenuItemBuilder::with_id(MENU_QUIT, "Quit").build(app)?)
+        .build()
+}
+
+fn on_tray_menu_event(app: &AppHandle, event: MenuEvent) {
+    let id = event.id().as_ref();
+    match id {
+        MENU_SHOW => show_main_window(app),
+        MENU_NEW_PROJECT => {
+            show_main_window(app);
+            let _ = app.emit_to("main", TRAY_NEW_PROJECT_EVENT, ());
+        }
+        MENU_KEEP_RUNNING => {
+            let enabled = app
+                .state::<DbState>()
+                .0
+                .lock()
+                .ok()
+                .and_then(|db| db.load_app_settings().ok())
+                .is_some_and(|s| s.keep_running_in_tray);
+            let mut patch = Map::new();
+            patch.insert("keepRunningInTray".to_string(), Value::Bool(!enabled));
+            // 설정 변경 이벤트 발송 시 트레이 메뉴도 다시 만들어짐
+            if let Err(e) = update_app_settings(UpdateAppSettingsArgs { patch }, app.clone(), app.state()) {
+                eprintln!("[Tray] Failed to update setting: {}", e.message);
+            }
+        }
+        MENU_QUIT => app.exit(0),
+        _ => {
+            if let Some(project_id) = id.strip_prefix(MENU_PROJECT_PREFIX) {
+                show_main_window(app);
+                navigate(
+                    app,
+                    vec![DeepLinkTarget::OpenProject {
+                        project_id: project_id.to_string(),
+                    }],
+                );
+            }
+        }
+    }
+}
+
+/// 트레이 아이콘 생성 (앱 setup에서 한 번 호출)
+pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("OddEyes.ai")
+        .menu(&build_tray_menu(app)?)
+        .show_menu_on_left_click(false)
+        .on_menu_event(on_tray_menu_event)
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        });
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+
+    // 저장/백업 시각 표시를 주기적으로 갱신
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(TRAY_REFRESH_INTERVAL).await;
+            refresh_tray(&handle);
+        }
+    });
+    Ok(())
+}
+
+/// 트레이 메뉴 다시 만들기 (프로젝트/설정이 바뀐 뒤)
+pub fn refresh_tray(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    match build_tray_menu(app) {
+        Ok(menu) => {
+            let _ = tray.set_menu(Some(menu));
+        }
+        Err(e) => eprintln!("[Tray] Failed to rebuild menu: {}", e),
+    }
+}
+
+/// 메인 창 닫기 요청 처리: 트레이 유지 설정이면 창을 숨기고 true 반환 (닫기 취소)
+pub fn hide_to_tray_on_close(window: &Window) -> bool {
+    if window.label() != "main" {
+        return false;
+    }
+    let keep_running = window
+        .state::<DbState>()
+        .0
+        .lock()
+        .ok()
+        .and_then(|db| db.load_app_settings().ok())
+        .is_some_and(|s| s.keep_running_in_tray);
+    if keep_running {
+        let _ = window.hide();
+    }
+    keep_running
+}
+
+/// 트레이 메뉴 갱신 (프로젝트를 만들거나 저장한 뒤 호출)
+#[tauri::command]
+pub fn refresh_tray_menu(app: AppHandle) -> CommandResult<()> {
+    refresh_tray(&app);
+    Ok(())
+}
@@ -0,0 +1,189 @@
+//! Updater Commands
+//!
+//! 앱 업데이트 확인/설치 (tauri-plugin-updater)
+//! - 릴리스 채널(stable/beta)은 설정(`updateChannel`)에 저장합니다.
+//! - 다운로드 진행 상황은 `update-progress` 이벤트로 보냅니다.
+//! - 설치가 끝나면 프론트엔드가 재시작(`@tauri-apps/plugin-process`의 relaunch)을 호출합니다.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, State};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 다운로드 진행 이벤트
+pub const UPDATE_PROGRESS_EVENT: &str = "update-progress";
+
+/// beta 채널 업데이트 매니페스트 (stable은 tauri.conf.json의 endpoints 사용)
+const BETA_UPDATE_ENDPOINT: &str =
+    "https://github.com/Hyunsang-coder/translation-editor/releases/download/beta/latest.json";
+
+/// 진행 이벤트 최소 간격 (bytes)
+const PROGRESS_EMIT_STEP: u64 = 256 * 1024;
+
+/// 확인한 업데이트 (설치 전까지 보관)
+#[derive(Default)]
+pub struct PendingUpdate(Mutex<Option<Update>>);
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckForUpdateArgs {
+    /// 생략하면 설정의 채널 사용
+    pub channel: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInfo {
+    pub channel: String,
+    pub version: String,
+    pub current_version: String,
+    pub notes: Option<String>,
+    /// 릴리스 시각 (Unix ms)
+    pub published_at: Option<i64>,
+}
+
+/// `update-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProgressEvent {
+    /// "downloading" | "installing" | "installed"
+    pub stage: String,
+    pub downloaded: u64,
+    /// 서버가 크기를 알려주지 않으면 None
+    pub content_length: Option<u64>,
+}
+
+fn update_error(e: impl std::fmt::Display) -> CommandError {
+    CommandError::new(ErrorCode::UpdateError, e.to_string())
+}
+
+fn lock_error(e: impl std::fmt::Display) -> CommandError {
+    CommandError::new(ErrorCode::LockError, format!("Failed to acquire update lock: {}", e))
+}
+
+/// 업데이트 확인
+/// - 새 버전이 없으면 None
+#[tauri::command]
+pub async fn check_for_update(
+    app: AppHandle,
+    args: Option<CheckForUpdateArgs>,
+    db_state: State<'_, DbState>,
+    pending: State<'_, PendingUpdate>,
+) -> CommandResult<Option<UpdateInfo>> {
+    crate::crash::guard(&app, "check_for_update", async {
+        crate::network::ensure_online("Update check")?;
+        let channel = match args.unwrap_or_default().channel {
+            Some(channel) => channel,
+            None => {
+                let db = db_state.0.lock().map_err(|e| CommandError::new(
+                    ErrorCode::LockError,
+                    format!("Failed to acquire database lock: {}", e),
+                ))?;
+                db.load_app_settings().map_err(CommandError::from)?.update_channel
+            }
+        };
+
+        let mut builder = app.updater_builder();
+        match channel.as_str() {
+            "stable" => {}
+            "beta" => {
+                let url = url::Url::parse(BETA_UPDATE_ENDPOINT).map_err(update_error)?;
+                builder = builder.endpoints(vec![url]).map_err(update_error)?;
+            }
+            other => {
+                return Err(CommandError::new(
+                    ErrorCode::InvalidInput,
+                    format!("Unknown update channel: {}", other),
+                ).with_details("channel must be 'stable' or 'beta'"))
+            }
+        }
+        let update = builder
+            .build()
+            .map_err(update_error)?
+            .check()
+            .await
+            .map_err(update_error)?;
+
+        let info = update.as_ref().map(|u| UpdateInfo {
+            channel: channel.clone(),
+            version: u.version.clone(),
+            current_version: u.current_version.clone(),
+            notes: u.body.clone(),
+            published_at: u.date.map(|d| d.unix_timestamp() * 1000),
+        });
+        *pending.0.lock().map_err(lock_error)? = update;
+
+        if let Some(info) = &info {
+            println!(
+                "[Updater] {} available on {} (current {})",
+                info.version, info.channel, info.current_version
+            );
+        }
+        Ok(info)
+    })
+    .await
+}
+
+/// 확인한 업데이트 다운로드 + 설치
+/// - `check_for_update`가 먼저 호출되어 있어야 합니다.
+#[tauri::command]
+pub async fn install_update(app: AppHandle, pending: State<'_, PendingUpdate>) -> CommandResult<String> {
+    crate::crash::guard(&app, "install_update", async {
+        crate::network::ensure_online("Update")?;
+        let update = pending.0.lock().map_err(lock_error)?.take().ok_or_else(|| CommandError::new(
+            ErrorCode::InvalidOperation,
+            "No update to install; call check_for_update first",
+        ))?;
+
+        let emit = |stage: &str, downloaded: u64, content_length: Option<u64>| {
+            let _ = app.emit(
+                UPDATE_PROGRESS_EVENT,
+                UpdateProgressEvent {
+                    stage: stage.to_string(),
+                    downloaded,
+                    content_length,
+                },
+            );
+        };
+
+        // 두 콜백이 함께 참조하므로 atomic으로 기록 (content_length 0 = 알 수 없음)
+        let downloaded = AtomicU64::new(0);
+        let total = AtomicU64::new(0);
+        let known = |n: u64| (n > 0).then_some(n);
+        let mut last_emitted: u64 = 0;
+        update
+            .download_and_install(
+                |chunk, content_length| {
+                    let now = downloaded.fetch_add(chunk as u64, Ordering::Relaxed) + chunk as u64;
+                    total.store(content_length.unwrap_or(0), Ordering::Relaxed);
+                    if now - last_emitted >= PROGRESS_EMIT_STEP || Some(now) == content_length {
+                        last_emitted = now;
+                        emit("downloading", now, content_length);
+                    }
+                },
+                || {
+                    emit(
+                        "installing",
+                        downloaded.load(Ordering::Relaxed),
+                        known(total.load(Ordering::Relaxed)),
+                    )
+                },
+            )
+            .await
+            .map_err(update_error)?;
+
+        emit(
+            "installed",
+            downloaded.load(Ordering::Relaxed),
+            known(total.load(Ordering::Relaxed)),
+        );
+        println!("[Updater] Installed {}", update.version);
+        Ok(update.version)
+    })
+    .await
+}
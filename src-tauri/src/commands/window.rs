@@ -0,0 +1,196 @@
+//! Window Commands
+//!
+//! 프로젝트를 별도 창으로 열기 (예: 한 창은 원문 참고, 다른 창은 번역)
+//! - 창마다 보고 있는 프로젝트를 따로 기록해, 창별 상태가 섞이지 않게 합니다.
+//! - 블록이 바뀌면 같은 프로젝트를 연 다른 창에 `block-changed` 이벤트를 보냅니다.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder, Window, WindowEvent};
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 블록 변경 알림 이벤트
+pub const BLOCK_CHANGED_EVENT: &str = "block-changed";
+
+/// 프로젝트 창 label 접두사 (capabilities의 `project-*`와 맞춰야 함)
+const PROJECT_WINDOW_PREFIX: &str = "project-";
+
+/// 창 label → 보고 있는 프로젝트 ID
+#[derive(Default)]
+pub struct WindowProjects(pub Mutex<HashMap<String, String>>);
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenProjectWindowArgs {
+    pub project_id: String,
+    /// 프론트엔드에 그대로 전달되는 보기 종류 (예: "source-reference")
+    pub view: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetWindowProjectArgs {
+    /// None이면 창이 프로젝트를 닫은 상태
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectWindowInfo {
+    pub label: String,
+    pub project_id: String,
+}
+
+/// `block-changed` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockChangedEvent {
+    pub project_id: String,
+    pub block_ids: Vec<String>,
+    /// 변경을 일으킨 창 (없으면 백엔드 작업)
+    pub source_window: Option<String>,
+}
+
+fn lock_error(e: impl std::fmt::Display) -> CommandError {
+    CommandError::new(ErrorCode::LockError, format!("Failed to acquire window state lock: {}", e))
+}
+
+/// 같은 프로젝트를 연 다른 창에 블록 변경 알림
+/// - 알림 실패는 저장 결과에 영향을 주지 않으므로 무시합니다.
+pub(crate) fn broadcast_block_change(
+    app: &AppHandle,
+    source_window: Option<&str>,
+    project_id: &str,
+    block_ids: Vec<String>,
+) {
+    if block_ids.is_empty() {
+        return;
+    }
+    let targets: Vec<String> = match app.state::<WindowProjects>().0.lock() {
+        Ok(windows) => windows
+            .iter()
+            .filter(|(label, pid)| pid.as_str() == project_id && Some(label.as_str()) != source_window)
+            .map(|(label, _)| label.clone())
+            .collect(),
+        Err(_) => return,
+    };
+    let event = BlockChangedEvent {
+        project_id: project_id.to_string(),
+        block_ids,
+        source_window: source_window.map(str::to_string),
+    };
+    for label in targets {
+        let _ = app.emit_to(label.as_str(), BLOCK_CHANGED_EVENT, event.clone());
+    }
+}
+
+/// 프로젝트를 새 창으로 열기
+/// - 같은 프로젝트도 여러 창으로 열 수 있으며, 새 창 label을 반환합니다.
+/// - 프론트엔드는 URL의 `projectId`/`view` 쿼리 또는 `get_window_project`로 초기 상태를 정합니다.
+#[tauri::command]
+pub fn open_project_window(
+    app: AppHandle,
+    args: OpenProjectWindowArgs,
+    db_state: State<DbState>,
+    windows: State<WindowProjects>,
+) -> CommandResult<ProjectWindowInfo> {
+    let title = {
+        let db = db_state.0.lock().map_err(|e| CommandError::new(
+            ErrorCode::LockError,
+            format!("Failed to acquire database lock: {}", e),
+        ))?;
+        db.load_project_metadata(&args.project_id)
+            .map_err(CommandError::from)?
+            .title
+    };
+
+    let label = format!(
+        "{}{}",
+        PROJECT_WINDOW_PREFIX,
+        uuid::Uuid::new_v4().simple()
+    );
+    let mut url = format!(
+        "index.html?projectId={}&windowLabel={}",
+        urlencoding::encode(&args.project_id),
+        label
+    );
+    if let Some(view) = &args.view {
+        url.push_str(&format!("&view={}", urlencoding::encode(view)));
+    }
+
+    let window = WebviewWindowBuilder::new(&app, &label, WebviewUrl::App(url.into()))
+        .title(format!("{} - OddEyes.ai", title))
+        .inner_size(1200.0, 800.0)
+        .min_inner_size(800.0, 500.0)
+        .build()
+        .map_err(|e| CommandError::new(ErrorCode::InvalidOperation, format!("Failed to open window: {}", e)))?;
+
+    windows
+        .0
+        .lock()
+        .map_err(lock_error)?
+        .insert(label.clone(), args.project_id.clone());
+
+    // 창이 닫히면 기록 정리
+    let app_handle = app.clone();
+    let closed_label = label.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            if let Ok(mut windows) = app_handle.state::<WindowProjects>().0.lock() {
+                windows.remove(&closed_label);
+            }
+        }
+    });
+
+    println!("[Window] Opened {} for project {}", label, args.project_id);
+    Ok(ProjectWindowInfo {
+        label,
+        project_id: args.project_id,
+    })
+}
+
+/// 호출한 창이 보고 있는 프로젝트 (없으면 None)
+#[tauri::command]
+pub fn get_window_project(window: Window, windows: State<WindowProjects>) -> CommandResult<Option<String>> {
+    Ok(windows.0.lock().map_err(lock_error)?.get(window.label()).cloned())
+}
+
+/// 호출한 창이 보고 있는 프로젝트 기록 (메인 창에서 프로젝트를 열거나 닫을 때)
+#[tauri::command]
+pub fn set_window_project(
+    window: Window,
+    args: SetWindowProjectArgs,
+    windows: State<WindowProjects>,
+) -> CommandResult<()> {
+    let mut windows = windows.0.lock().map_err(lock_error)?;
+    match args.project_id {
+        Some(project_id) => {
+            windows.insert(window.label().to_string(), project_id);
+        }
+        None => {
+            windows.remove(window.label());
+        }
+    }
+    Ok(())
+}
+
+/// 프로젝트를 보고 있는 창 목록
+#[tauri::command]
+pub fn list_project_windows(windows: State<WindowProjects>) -> CommandResult<Vec<ProjectWindowInfo>> {
+    let mut out: Vec<ProjectWindowInfo> = windows
+        .0
+        .lock()
+        .map_err(lock_error)?
+        .iter()
+        .map(|(label, project_id)| ProjectWindowInfo {
+            label: label.clone(),
+            project_id: project_id.clone(),
+        })
+        .collect();
+    out.sort_by(|a, b| a.label.cmp(&b.label));
+    Ok(out)
+}
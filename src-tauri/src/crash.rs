@@ -0,0 +1,226 @@
+//! Panic Guard & Crash Markers
+//!
+//! 명령 처리 중 패닉이 나도 앱이 죽거나 요청이 응답 없이 끝나지 않도록 막고, 흔적을 남깁니다.
+//! - 동기 명령: invoke 핸들러를 `catch_invoke`로 감싸 `INTERNAL_PANIC` 오류로 응답합니다.
+//! - 비동기 명령: Tauri가 본문을 별도 태스크로 띄워 invoke 핸들러에서는 잡을 수 없으므로,
+//!   모든 `async` 명령 본문을 `guard`로 감쌉니다 (새 비동기 명령도 같은 방식으로 추가).
+//! - 모든 패닉은 백트레이스를 `crash.log`에, 요약을 `crash-markers.jsonl`에 남깁니다 (진단 자료용).
+
+use std::cell::RefCell;
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use tauri::ipc::Invoke;
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::db::DbState;
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 백트레이스 로그 파일 이름
+pub const CRASH_LOG_FILE: &str = "crash.log";
+
+/// 패닉 요약 기록 파일 이름 (JSON Lines)
+pub const CRASH_MARKERS_FILE: &str = "crash-markers.jsonl";
+
+/// 보관할 최대 패닉 요약 수 (오래된 것부터 삭제)
+const MAX_CRASH_MARKERS: usize = 100;
+
+/// 이 크기를 넘으면 `crash.log`를 `crash.log.1`로 돌림
+const MAX_CRASH_LOG_BYTES: u64 = 2 * 1024 * 1024;
+
+static CRASH_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+thread_local! {
+    /// 이 스레드에서 처리 중인 명령 (패닉 기록에 함께 남김)
+    static CURRENT_COMMAND: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// 패닉 요약 1건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashMarker {
+    /// 발생 시각 (Unix ms)
+    pub at: i64,
+    /// 처리 중이던 명령 (알 수 있는 경우)
+    pub command: Option<String>,
+    pub thread: Option<String>,
+    pub message: String,
+    /// 패닉 위치 ("file:line:col")
+    pub location: Option<String>,
+}
+
+/// 패닉 훅 설치 (앱 시작 시 한 번, `dir`는 로그 디렉토리)
+/// - 기존 훅(stderr 출력)은 그대로 호출합니다.
+pub fn install(dir: PathBuf) {
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("[Crash] Failed to create log dir: {}", e);
+    }
+    if CRASH_DIR.set(dir).is_err() {
+        return;
+    }
+
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let marker = CrashMarker {
+            at: chrono::Utc::now().timestamp_millis(),
+            command: CURRENT_COMMAND.with(|c| c.borrow().clone()),
+            thread: std::thread::current().name().map(str::to_string),
+            message: payload_message(info.payload()),
+            location: info.location().map(|l| l.to_string()),
+        };
+        let backtrace = std::backtrace::Backtrace::force_capture();
+        if let Some(dir) = CRASH_DIR.get() {
+            if let Err(e) = write_crash(dir, &marker, &backtrace.to_string()) {
+                eprintln!("[Crash] Failed to record panic: {}", e);
+            }
+        }
+        previous(info);
+    }));
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+fn write_crash(dir: &Path, marker: &CrashMarker, backtrace: &str) -> std::io::Result<()> {
+    let log_path = dir.join(CRASH_LOG_FILE);
+    if fs::metadata(&log_path).is_ok_and(|m| m.len() > MAX_CRASH_LOG_BYTES) {
+        let _ = fs::rename(&log_path, dir.join(format!("{}.1", CRASH_LOG_FILE)));
+    }
+    let mut log = OpenOptions::new().create(true).append(true).open(&log_path)?;
+    writeln!(
+        log,
+        "=== panic at {} (command: {}, thread: {}) ===\n{}\nlocation: {}\n{}\n",
+        chrono::DateTime::from_timestamp_millis(marker.at)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default(),
+        marker.command.as_deref().unwrap_or("-"),
+        marker.thread.as_deref().unwrap_or("-"),
+        marker.message,
+        marker.location.as_deref().unwrap_or("-"),
+        backtrace,
+    )?;
+
+    let mut markers = read_markers(dir);
+    markers.push(marker.clone());
+    let start = markers.len().saturating_sub(MAX_CRASH_MARKERS);
+    let mut out = String::new();
+    for m in &markers[start..] {
+        if let Ok(line) = serde_json::to_string(m) {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    fs::write(dir.join(CRASH_MARKERS_FILE), out)
+}
+
+fn read_markers(dir: &Path) -> Vec<CrashMarker> {
+    fs::read_to_string(dir.join(CRASH_MARKERS_FILE))
+        .map(|text| {
+            text.lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 기록된 패닉 요약 (최근 것이 뒤)
+pub fn crash_markers() -> Vec<CrashMarker> {
+    CRASH_DIR.get().map(|dir| read_markers(dir)).unwrap_or_default()
+}
+
+/// 백트레이스 로그 파일 경로
+pub fn crash_log_path() -> Option<PathBuf> {
+    CRASH_DIR.get().map(|dir| dir.join(CRASH_LOG_FILE))
+}
+
+/// 패닉 요약/백트레이스 로그 삭제, 지운 요약 수 반환
+pub fn clear_crash_markers() -> std::io::Result<usize> {
+    let Some(dir) = CRASH_DIR.get() else {
+        return Ok(0);
+    };
+    let count = read_markers(dir).len();
+    for name in [CRASH_MARKERS_FILE, CRASH_LOG_FILE] {
+        match fs::remove_file(dir.join(name)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(count)
+}
+
+fn panic_error(command: &str, payload: &(dyn std::any::Any + Send)) -> CommandError {
+    CommandError::new(
+        ErrorCode::InternalPanic,
+        format!("Command '{}' panicked: {}", command, payload_message(payload)),
+    )
+}
+
+/// 패닉 중 DB 잠금을 잡고 있었다면 poison 표시를 지움
+/// - 트랜잭션은 커밋 전이면 롤백되므로, 이후 명령이 모두 `LOCK_ERROR`로 실패하는 것만 막습니다.
+fn recover_db_lock<R: Runtime, M: Manager<R>>(manager: &M) {
+    if let Some(state) = manager.try_state::<DbState>() {
+        state.0.clear_poison();
+    }
+}
+
+/// invoke 핸들러 감싸기: 명령 처리 중 패닉을 `INTERNAL_PANIC` 오류 응답으로 바꿈
+pub fn catch_invoke<R, F>(handler: F) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static
+where
+    R: Runtime,
+    F: Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+{
+    move |invoke: Invoke<R>| {
+        let command = invoke.message.command().to_string();
+        let webview = invoke.message.webview();
+        let resolver = invoke.resolver.clone();
+
+        let previous = CURRENT_COMMAND.with(|c| c.borrow_mut().replace(command.clone()));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| handler(invoke)));
+        CURRENT_COMMAND.with(|c| *c.borrow_mut() = previous);
+
+        match result {
+            Ok(handled) => handled,
+            Err(payload) => {
+                recover_db_lock(&webview);
+                resolver.reject(panic_error(&command, payload.as_ref()));
+                true
+            }
+        }
+    }
+}
+
+/// 비동기 명령 본문 감싸기: 패닉을 `INTERNAL_PANIC` 오류로 바꿈
+/// - 태스크가 스레드를 옮겨 다니므로 현재 명령 표시는 poll 할 때마다 설정합니다.
+pub async fn guard<R, T, F>(app: &AppHandle<R>, command: &str, future: F) -> CommandResult<T>
+where
+    R: Runtime,
+    F: Future<Output = CommandResult<T>>,
+{
+    let mut future = std::pin::pin!(AssertUnwindSafe(future).catch_unwind());
+    let result = std::future::poll_fn(|cx| {
+        let previous = CURRENT_COMMAND.with(|c| c.borrow_mut().replace(command.to_string()));
+        let poll = future.as_mut().poll(cx);
+        CURRENT_COMMAND.with(|c| *c.borrow_mut() = previous);
+        poll
+    })
+    .await;
+
+    result.unwrap_or_else(|payload| {
+        recover_db_lock(app);
+        Err(panic_error(command, payload.as_ref()))
+    })
+}
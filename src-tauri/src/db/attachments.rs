@@ -0,0 +1,117 @@
+//! Attachment Text Cache
+//!
+//! 첨부 파일 추출 텍스트 재사용/부분 조회
+//! - 원본 파일 SHA-256(`content_hash`)이 같으면 이미 추출한 텍스트를 다시 씁니다.
+//! - 추출 텍스트가 매우 클 수 있어 문자 단위 구간으로 나눠 읽을 수 있게 합니다.
+
+use rusqlite::OptionalExtension;
+use serde::Serialize;
+
+use super::Database;
+use crate::error::IteError;
+use crate::models::Attachment;
+
+/// 한 번에 읽을 수 있는 최대 문자 수
+pub const MAX_ATTACHMENT_TEXT_SLICE: usize = 100_000;
+
+/// 첨부 조회 컬럼 (`row_to_attachment` 순서)
+pub(super) const ATTACHMENT_COLUMNS: &str =
+    "id, project_id, filename, file_type, file_path, extracted_text, file_size, created_at, updated_at, content_hash";
+
+/// 추출 텍스트 구간
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentTextSlice {
+    pub id: String,
+    /// 시작 위치 (문자 단위, 0부터)
+    pub offset: usize,
+    pub text: String,
+    /// 전체 추출 텍스트 길이 (문자 수)
+    pub total_chars: usize,
+    /// 다음 구간 시작 위치
+    pub next_offset: usize,
+    pub has_more: bool,
+}
+
+pub(super) fn row_to_attachment(row: &rusqlite::Row) -> rusqlite::Result<Attachment> {
+    Ok(Attachment {
+        id: row.get(0)?,
+        project_id: row.get(1)?,
+        filename: row.get(2)?,
+        file_type: row.get(3)?,
+        file_path: row.get(4)?,
+        extracted_text: row.get(5)?,
+        file_size: row.get(6)?,
+        created_at: row.get(7)?,
+        updated_at: row.get(8)?,
+        content_hash: row.get(9)?,
+    })
+}
+
+impl Database {
+    /// 프로젝트에 같은 파일(해시)로 이미 첨부된 항목 (가장 최근 것)
+    pub fn find_attachment_by_hash(&self, project_id: &str, content_hash: &str) -> Result<Option<Attachment>, IteError> {
+        Ok(self
+            .conn
+            .query_row(
+                &format!(
+                    "SELECT {ATTACHMENT_COLUMNS} FROM attachments
+                     WHERE project_id = ?1 AND content_hash = ?2
+                     ORDER BY updated_at DESC LIMIT 1"
+                ),
+                [project_id, content_hash],
+                row_to_attachment,
+            )
+            .optional()?)
+    }
+
+    /// 같은 파일(해시)에서 이미 추출한 텍스트 (프로젝트 무관)
+    pub fn cached_attachment_text(&self, content_hash: &str) -> Result<Option<String>, IteError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT extracted_text FROM attachments
+                 WHERE content_hash = ?1 AND extracted_text IS NOT NULL
+                 ORDER BY updated_at DESC LIMIT 1",
+                [content_hash],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// 추출 텍스트 구간 조회 (문자 단위, `limit`는 `MAX_ATTACHMENT_TEXT_SLICE`로 제한)
+    /// - 첨부가 없으면 None, 추출 텍스트가 없는 첨부(이미지 등)면 빈 구간입니다.
+    pub fn attachment_text_slice(
+        &self,
+        id: &str,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<Option<AttachmentTextSlice>, IteError> {
+        let limit = limit.unwrap_or(MAX_ATTACHMENT_TEXT_SLICE).min(MAX_ATTACHMENT_TEXT_SLICE);
+        // SQLite substr/length는 TEXT를 문자 단위로 다룹니다 (1부터 시작)
+        let row: Option<(Option<String>, i64)> = self
+            .conn
+            .query_row(
+                "SELECT substr(extracted_text, ?2, ?3), COALESCE(length(extracted_text), 0)
+                 FROM attachments WHERE id = ?1",
+                (id, offset as i64 + 1, limit as i64),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((text, total_chars)) = row else {
+            return Ok(None);
+        };
+
+        let text = text.unwrap_or_default();
+        let total_chars = total_chars.max(0) as usize;
+        let end = offset.saturating_add(text.chars().count());
+        Ok(Some(AttachmentTextSlice {
+            id: id.to_string(),
+            offset,
+            text,
+            total_chars,
+            next_offset: end,
+            has_more: end < total_chars,
+        }))
+    }
+}
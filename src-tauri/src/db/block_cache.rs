@@ -0,0 +1,134 @@
+//! Block Cache
+//!
+//! `get_block` 결과를 메모리에 보관하는 LRU 캐시 (호버/QA 등 반복 조회용)
+//! - 항목을 SQLite rowid로도 찾을 수 있게 해, update hook이 알려 주는 행 변경으로 바로 무효화합니다.
+//! - hook이 호출되지 않는 경로(WHERE 없는 DELETE, Backup API 가져오기)와 롤백 뒤에는 `clear`로 비웁니다.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::EditorBlock;
+
+/// 캐시 최대 블록 수
+pub const BLOCK_CACHE_CAPACITY: usize = 4096;
+
+/// (project_id, block_id)
+type BlockKey = (String, String);
+
+struct Entry {
+    block: EditorBlock,
+    rowid: i64,
+    stamp: u64,
+}
+
+/// LRU 블록 캐시
+pub struct BlockCache {
+    capacity: usize,
+    entries: HashMap<BlockKey, Entry>,
+    /// 사용 순서 (stamp 오름차순 = 오래된 순)
+    order: BTreeMap<u64, BlockKey>,
+    by_rowid: HashMap<i64, BlockKey>,
+    next_stamp: u64,
+}
+
+impl BlockCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            by_rowid: HashMap::new(),
+            next_stamp: 0,
+        }
+    }
+
+    fn bump(&mut self) -> u64 {
+        self.next_stamp += 1;
+        self.next_stamp
+    }
+
+    /// 조회 (찾으면 최근 사용으로 갱신)
+    pub fn get(&mut self, project_id: &str, block_id: &str) -> Option<EditorBlock> {
+        let key = (project_id.to_string(), block_id.to_string());
+        let stamp = self.bump();
+        let entry = self.entries.get_mut(&key)?;
+        self.order.remove(&entry.stamp);
+        entry.stamp = stamp;
+        self.order.insert(stamp, key);
+        Some(entry.block.clone())
+    }
+
+    /// 추가 (용량을 넘으면 가장 오래 쓰지 않은 항목 제거)
+    pub fn insert(&mut self, project_id: &str, block: EditorBlock, rowid: i64) {
+        let key = (project_id.to_string(), block.id.clone());
+        self.remove(&key);
+        while self.entries.len() >= self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            if let Some(entry) = self.entries.remove(&oldest) {
+                self.by_rowid.remove(&entry.rowid);
+            }
+        }
+        let stamp = self.bump();
+        self.order.insert(stamp, key.clone());
+        self.by_rowid.insert(rowid, key.clone());
+        self.entries.insert(key, Entry { block, rowid, stamp });
+    }
+
+    fn remove(&mut self, key: &BlockKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.stamp);
+            self.by_rowid.remove(&entry.rowid);
+        }
+    }
+
+    /// 행이 바뀌거나 삭제된 블록 제거
+    pub fn invalidate_rowid(&mut self, rowid: i64) {
+        if let Some(key) = self.by_rowid.remove(&rowid) {
+            self.remove(&key);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.by_rowid.clear();
+    }
+}
+
+impl Default for BlockCache {
+    fn default() -> Self {
+        Self::new(BLOCK_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(id: &str) -> EditorBlock {
+        serde_json::from_value(serde_json::json!({
+            "id": id, "type": "source", "content": "<p>x</p>", "hash": "h",
+            "metadata": {"author": null, "createdAt": 0, "updatedAt": 0, "tags": [], "comments": null}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn evicts_least_recently_used_and_invalidates_by_rowid() {
+        let mut cache = BlockCache::new(2);
+        cache.insert("p", block("a"), 1);
+        cache.insert("p", block("b"), 2);
+        assert!(cache.get("p", "a").is_some());
+        cache.insert("p", block("c"), 3);
+        assert!(cache.get("p", "b").is_none());
+        assert!(cache.get("p", "a").is_some());
+        assert!(cache.get("other", "a").is_none());
+
+        cache.invalidate_rowid(1);
+        assert!(cache.get("p", "a").is_none());
+        assert!(cache.get("p", "c").is_some());
+        cache.clear();
+        assert!(cache.get("p", "c").is_none());
+    }
+}
@@ -0,0 +1,211 @@
+//! Change Feed
+//!
+//! 모든 변경을 `changes` 테이블에 추가 전용(append-only)으로 기록합니다.
+//! 변경과 같은 트랜잭션 안에서 기록하므로, 롤백되면 기록도 남지 않습니다.
+//! - 동기화/협업의 기반이며, 감사(audit) 로그로도 사용합니다.
+//! - 여러 행을 한 번에 지우는 작업은 `entity_id`에 프로젝트 ID(전역이면 `*`)를 기록합니다.
+
+use std::collections::HashMap;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use super::Database;
+use crate::error::IteError;
+
+/// 한 번에 조회할 수 있는 최대 변경 수
+pub const MAX_CHANGES_PAGE: usize = 1000;
+
+/// 변경 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ChangeOp {
+    Insert,
+    Update,
+    /// 삽입/갱신을 구분하지 않는 저장 (ON CONFLICT DO UPDATE)
+    Upsert,
+    Delete,
+}
+
+impl ChangeOp {
+    fn as_str(self) -> &'static str {
+        match self {
+            ChangeOp::Insert => "insert",
+            ChangeOp::Update => "update",
+            ChangeOp::Upsert => "upsert",
+            ChangeOp::Delete => "delete",
+        }
+    }
+}
+
+/// 기록할 변경 1건
+pub(super) struct Change<'a> {
+    project_id: Option<&'a str>,
+    /// 변경 대상 종류 (예: "project", "block", "glossary_entry")
+    entity: &'a str,
+    entity_id: &'a str,
+    op: ChangeOp,
+    /// 변경 후 내용 (SHA-256만 저장, 삭제면 None)
+    payload: Option<&'a str>,
+    actor: Option<&'a str>,
+}
+
+impl<'a> Change<'a> {
+    pub fn new(entity: &'a str, entity_id: &'a str, op: ChangeOp) -> Self {
+        Self {
+            project_id: None,
+            entity,
+            entity_id,
+            op,
+            payload: None,
+            actor: None,
+        }
+    }
+
+    pub fn project(mut self, project_id: &'a str) -> Self {
+        self.project_id = Some(project_id);
+        self
+    }
+
+    pub fn payload(mut self, payload: &'a str) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    pub fn actor(mut self, actor: Option<&'a str>) -> Self {
+        self.actor = actor;
+        self
+    }
+}
+
+/// 변경 기록 1행
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeRow {
+    /// 단조 증가 커서
+    pub seq: i64,
+    pub project_id: Option<String>,
+    pub entity: String,
+    pub entity_id: String,
+    pub op: String,
+    pub payload_hash: Option<String>,
+    pub actor: Option<String>,
+    pub changed_at: i64,
+}
+
+/// `get_changes_since` 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePage {
+    pub changes: Vec<ChangeRow>,
+    /// 다음 조회에 넘길 커서 (변경이 없으면 요청한 커서 그대로)
+    pub next_cursor: i64,
+    pub has_more: bool,
+}
+
+pub(super) fn payload_hash(payload: &str) -> String {
+    Sha256::digest(payload.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// 블록 변경 판별용 payload (콘텐츠 + 메타데이터)
+pub(super) fn block_payload(content: &str, metadata_json: &str) -> String {
+    format!("{}\n{}", content, metadata_json)
+}
+
+/// 프로젝트의 현재 블록 payload 해시 (block_id → hash)
+pub(super) fn load_block_payload_hashes(conn: &Connection, project_id: &str) -> Result<HashMap<String, String>, IteError> {
+    let mut stmt = conn.prepare("SELECT id, content, metadata_json FROM blocks WHERE project_id = ?1")?;
+    let iter = stmt.query_map([project_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+    })?;
+
+    let mut out = HashMap::new();
+    for r in iter {
+        let (id, content, metadata_json) = r?;
+        out.insert(id, payload_hash(&block_payload(&content, &metadata_json)));
+    }
+    Ok(out)
+}
+
+/// 대상의 마지막 변경과 payload가 같은지 (같으면 기록하지 않기 위함)
+pub(super) fn is_unchanged(conn: &Connection, change: &Change) -> Result<bool, IteError> {
+    let Some(payload) = change.payload else {
+        return Ok(false);
+    };
+    let last: Option<Option<String>> = conn
+        .query_row(
+            "SELECT payload_hash FROM changes
+             WHERE project_id IS ?1 AND entity = ?2 AND entity_id = ?3
+             ORDER BY seq DESC LIMIT 1",
+            (change.project_id, change.entity, change.entity_id),
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(last.flatten().as_deref() == Some(payload_hash(payload).as_str()))
+}
+
+/// 변경 기록 추가 (호출자의 트랜잭션 안에서 실행)
+pub(super) fn record_change(conn: &Connection, change: &Change) -> Result<(), IteError> {
+    conn.prepare_cached(
+        "INSERT INTO changes (project_id, entity, entity_id, op, payload_hash, actor, changed_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?
+    .execute((
+        change.project_id,
+        change.entity,
+        change.entity_id,
+        change.op.as_str(),
+        change.payload.map(payload_hash),
+        change.actor,
+        chrono::Utc::now().timestamp_millis(),
+    ))?;
+    Ok(())
+}
+
+impl Database {
+    /// `cursor` 이후의 변경 (seq 오름차순)
+    /// - cursor 0이면 처음부터, project_id를 주면 해당 프로젝트의 변경만
+    pub fn get_changes_since(
+        &self,
+        cursor: i64,
+        limit: usize,
+        project_id: Option<&str>,
+    ) -> Result<ChangePage, IteError> {
+        let limit = limit.clamp(1, MAX_CHANGES_PAGE);
+        let mut stmt = self.conn.prepare(
+            "SELECT seq, project_id, entity, entity_id, op, payload_hash, actor, changed_at
+             FROM changes
+             WHERE seq > ?1 AND (?2 IS NULL OR project_id = ?2)
+             ORDER BY seq
+             LIMIT ?3",
+        )?;
+        let iter = stmt.query_map((cursor, project_id, limit as i64 + 1), |row| {
+            Ok(ChangeRow {
+                seq: row.get(0)?,
+                project_id: row.get(1)?,
+                entity: row.get(2)?,
+                entity_id: row.get(3)?,
+                op: row.get(4)?,
+                payload_hash: row.get(5)?,
+                actor: row.get(6)?,
+                changed_at: row.get(7)?,
+            })
+        })?;
+
+        let mut changes = Vec::new();
+        for r in iter {
+            changes.push(r?);
+        }
+        let has_more = changes.len() > limit;
+        changes.truncate(limit);
+        let next_cursor = changes.last().map(|c| c.seq).unwrap_or(cursor);
+        Ok(ChangePage {
+            changes,
+            next_cursor,
+            has_more,
+        })
+    }
+}
@@ -0,0 +1,892 @@
+//! Chat Session Storage
+//!
+//! 프로젝트별 다중 채팅 세션 저장/조회 및 메시지 전문 검색(FTS5)
+
+use std::collections::HashMap;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use super::changes::{self, record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::{ChatArtifactKind, ChatMessage, ChatMessageArtifact, ChatSession};
+use crate::text::unicode::nfc;
+
+/// 프로젝트당 보관하는 최대 세션 수
+pub(super) const MAX_CHAT_SESSIONS: usize = 50;
+
+/// 세션당 보관하는 최대 메시지 수 (최근 메시지 우선)
+pub(super) const MAX_MESSAGES_PER_SESSION: usize = 100;
+
+/// trigram 토크나이저는 3글자 미만 검색어를 매칭하지 못하므로, 그보다 짧으면 LIKE 스캔으로 대체합니다.
+const FTS_MIN_TERM_CHARS: usize = 3;
+
+/// 세션 목록 요약 (메시지 본문 제외)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatSessionSummary {
+    pub id: String,
+    pub name: String,
+    pub created_at: i64,
+    pub last_activity_at: i64,
+    pub message_count: i64,
+}
+
+/// 메시지 검색 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageSearchHit {
+    pub message_id: String,
+    pub session_id: String,
+    pub session_name: String,
+    pub role: String,
+    /// 매칭 구간을 `[` `]`로 감싼 발췌
+    pub snippet: String,
+    pub timestamp: i64,
+}
+
+/// 세션 1개와 메시지를 삽입 (기존 row는 호출자가 미리 정리)
+/// - `kept_citations`: 다시 쓰기 전에 보관한 인용 (메시지에 같은 URL이 없으면 덧붙임)
+pub(super) fn insert_chat_session(
+    conn: &Connection,
+    project_id: &str,
+    session: &ChatSession,
+    kept_citations: &HashMap<String, Vec<ChatMessageArtifact>>,
+) -> Result<(), IteError> {
+    conn.execute(
+        "INSERT INTO chat_sessions (id, project_id, name, created_at, context_block_ids, confluence_search_enabled)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        (
+            &session.id,
+            project_id,
+            &session.name,
+            session.created_at,
+            serde_json::to_string(&session.context_block_ids)?,
+            session.confluence_search_enabled,
+        ),
+    )?;
+
+    // 메시지를 timestamp 기준으로 정렬 후 최근 MAX_MESSAGES_PER_SESSION개만 저장
+    let mut messages: Vec<&ChatMessage> = session.messages.iter().collect();
+    messages.sort_by_key(|m| m.timestamp);
+    let messages_to_save = if messages.len() > MAX_MESSAGES_PER_SESSION {
+        &messages[messages.len() - MAX_MESSAGES_PER_SESSION..]
+    } else {
+        &messages[..]
+    };
+
+    for m in messages_to_save {
+        let meta_json: Option<String> = match &m.metadata {
+            Some(meta) => Some(serde_json::to_string(meta)?),
+            None => None,
+        };
+        conn.execute(
+            "INSERT INTO chat_messages (id, session_id, role, content, timestamp, metadata_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            (&m.id, &session.id, &m.role, nfc(&m.content), m.timestamp, meta_json),
+        )?;
+        match kept_citations.get(&m.id) {
+            Some(citations) => {
+                let mut artifacts = m.artifacts.clone();
+                for citation in citations {
+                    let known = artifacts
+                        .iter()
+                        .any(|a| a.kind == ChatArtifactKind::Citation && a.reference == citation.reference);
+                    if !known {
+                        artifacts.push(citation.clone());
+                    }
+                }
+                insert_message_artifacts(conn, &m.id, &artifacts)?;
+            }
+            None => insert_message_artifacts(conn, &m.id, &m.artifacts)?,
+        }
+    }
+
+    let payload = serde_json::to_string(session)?;
+    let change = Change::new("chat_session", &session.id, ChangeOp::Upsert)
+        .project(project_id)
+        .payload(&payload);
+    if !changes::is_unchanged(conn, &change)? {
+        record_change(conn, &change)?;
+    }
+    Ok(())
+}
+
+/// 메시지 첨부 항목 삽입 (메시지 안 순서 유지)
+fn insert_message_artifacts(
+    conn: &Connection,
+    message_id: &str,
+    artifacts: &[ChatMessageArtifact],
+) -> Result<(), IteError> {
+    if artifacts.is_empty() {
+        return Ok(());
+    }
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO chat_message_artifacts (message_id, position, kind, reference, title, content, data_json)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    for (position, artifact) in artifacts.iter().enumerate() {
+        let data_json: Option<String> = match &artifact.data {
+            Some(data) => Some(serde_json::to_string(data)?),
+            None => None,
+        };
+        stmt.execute((
+            message_id,
+            position as i64,
+            artifact.kind.as_str(),
+            &artifact.reference,
+            &artifact.title,
+            &artifact.content,
+            data_json,
+        ))?;
+    }
+    Ok(())
+}
+
+/// 세션을 다시 쓰기 전에 보관할 인용 (message_id → 인용 항목, `session_id`가 없으면 프로젝트 전체)
+/// - `save_message_citations`로 추가한 인용이 아직 프론트엔드 메시지에 없어도 세션 저장으로 사라지지 않게 합니다.
+pub(super) fn kept_citations(
+    conn: &Connection,
+    project_id: &str,
+    session_id: Option<&str>,
+) -> Result<HashMap<String, Vec<ChatMessageArtifact>>, IteError> {
+    let mut stmt = conn.prepare(
+        "SELECT a.message_id, a.reference, a.title, a.content, a.data_json
+         FROM chat_message_artifacts a
+         JOIN chat_messages m ON m.id = a.message_id
+         JOIN chat_sessions s ON s.id = m.session_id
+         WHERE s.project_id = ?1 AND (?2 IS NULL OR s.id = ?2) AND a.kind = ?3
+         ORDER BY a.message_id, a.position",
+    )?;
+    let iter = stmt.query_map((project_id, session_id, ChatArtifactKind::Citation.as_str()), |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            ChatMessageArtifact {
+                kind: ChatArtifactKind::Citation,
+                reference: row.get(1)?,
+                title: row.get(2)?,
+                content: row.get(3)?,
+                data: row
+                    .get::<_, Option<String>>(4)?
+                    .as_deref()
+                    .and_then(|s| serde_json::from_str(s).ok()),
+            },
+        ))
+    })?;
+
+    let mut out: HashMap<String, Vec<ChatMessageArtifact>> = HashMap::new();
+    for r in iter {
+        let (message_id, artifact) = r?;
+        out.entry(message_id).or_default().push(artifact);
+    }
+    Ok(out)
+}
+
+/// 세션의 메시지 첨부 항목 (message_id → 항목, 메시지 안 순서)
+fn load_message_artifacts(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<HashMap<String, Vec<ChatMessageArtifact>>, IteError> {
+    let mut stmt = conn.prepare(
+        "SELECT a.message_id, a.kind, a.reference, a.title, a.content, a.data_json
+         FROM chat_message_artifacts a
+         JOIN chat_messages m ON m.id = a.message_id
+         WHERE m.session_id = ?1
+         ORDER BY a.message_id, a.position",
+    )?;
+
+    let iter = stmt.query_map([session_id], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<String>>(3)?,
+            row.get::<_, Option<String>>(4)?,
+            row.get::<_, Option<String>>(5)?,
+        ))
+    })?;
+
+    let mut out: HashMap<String, Vec<ChatMessageArtifact>> = HashMap::new();
+    for r in iter {
+        let (message_id, kind, reference, title, content, data_json) = r?;
+        // 이후 버전에서 추가된 종류는 건너뜀
+        let Some(kind) = ChatArtifactKind::parse(&kind) else {
+            continue;
+        };
+        out.entry(message_id).or_default().push(ChatMessageArtifact {
+            kind,
+            reference,
+            title,
+            content,
+            data: data_json.as_deref().and_then(|s| serde_json::from_str(s).ok()),
+        });
+    }
+    Ok(out)
+}
+
+/// 조건에 맞는 세션 삭제 + 변경 피드 기록 (메시지는 호출자가 정리)
+pub(super) fn delete_chat_sessions_where(
+    conn: &Connection,
+    project_id: &str,
+    where_clause: &str,
+    params: impl rusqlite::Params,
+) -> Result<usize, IteError> {
+    let mut stmt = conn.prepare(&format!("DELETE FROM chat_sessions WHERE {} RETURNING id", where_clause))?;
+    let ids: Vec<String> = stmt
+        .query_map(params, |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    for id in &ids {
+        record_change(conn, &Change::new("chat_session", id, ChangeOp::Delete).project(project_id))?;
+    }
+    Ok(ids.len())
+}
+
+/// 프로젝트 세션들의 컨텍스트 블록 ID 치환 (`replacements`: 기존 ID → 새 ID 목록, 비어 있으면 제거)
+/// - 순서를 유지하고 중복은 한 번만 남깁니다. 바뀐 세션 수를 반환합니다.
+pub(super) fn remap_context_blocks(
+    conn: &Connection,
+    project_id: &str,
+    replacements: &HashMap<String, Vec<String>>,
+) -> Result<usize, IteError> {
+    if replacements.is_empty() {
+        return Ok(0);
+    }
+    let sessions: Vec<(String, String)> = {
+        let mut stmt = conn.prepare("SELECT id, context_block_ids FROM chat_sessions WHERE project_id = ?1")?;
+        let rows = stmt.query_map([project_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<_, _>>()?
+    };
+
+    let mut changed = 0;
+    for (session_id, ids_json) in sessions {
+        let ids: Vec<String> = serde_json::from_str(&ids_json).unwrap_or_default();
+        if !ids.iter().any(|id| replacements.contains_key(id)) {
+            continue;
+        }
+        let mut next: Vec<String> = Vec::with_capacity(ids.len());
+        for id in &ids {
+            let mapped = match replacements.get(id) {
+                Some(new_ids) => new_ids.as_slice(),
+                None => std::slice::from_ref(id),
+            };
+            for new_id in mapped {
+                if !next.contains(new_id) {
+                    next.push(new_id.clone());
+                }
+            }
+        }
+        if next == ids {
+            continue;
+        }
+        let next_json = serde_json::to_string(&next)?;
+        conn.execute(
+            "UPDATE chat_sessions SET context_block_ids = ?1 WHERE id = ?2",
+            (&next_json, &session_id),
+        )?;
+        record_change(
+            conn,
+            &Change::new("chat_session", &session_id, ChangeOp::Update)
+                .project(project_id)
+                .payload(&next_json),
+        )?;
+        changed += 1;
+    }
+    Ok(changed)
+}
+
+/// 세션의 메시지를 시간순으로 로드
+pub(super) fn load_chat_messages(
+    conn: &Connection,
+    session_id: &str,
+) -> Result<Vec<ChatMessage>, IteError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, role, content, timestamp, metadata_json
+         FROM chat_messages WHERE session_id = ?1
+         ORDER BY timestamp ASC",
+    )?;
+
+    let mut artifacts = load_message_artifacts(conn, session_id)?;
+    let iter = stmt.query_map([session_id], |row| {
+        let metadata_json: Option<String> = row.get(4)?;
+        let metadata: Option<serde_json::Value> = metadata_json
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok());
+        let id: String = row.get(0)?;
+        Ok(ChatMessage {
+            artifacts: artifacts.remove(&id).unwrap_or_default(),
+            id,
+            role: row.get(1)?,
+            content: row.get(2)?,
+            timestamp: row.get(3)?,
+            metadata,
+        })
+    })?;
+
+    let mut messages = Vec::new();
+    for m in iter {
+        messages.push(m?);
+    }
+    Ok(messages)
+}
+
+/// 채팅 보관 정책 (프로젝트별)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatRetentionPolicy {
+    /// 프로젝트 전체에서 보관할 최대 메시지 수 (최근 메시지 우선)
+    pub max_messages: Option<u32>,
+    /// 이 기간(일)보다 오래된 메시지는 삭제
+    pub max_age_days: Option<u32>,
+}
+
+impl ChatRetentionPolicy {
+    fn is_empty(&self) -> bool {
+        self.max_messages.is_none() && self.max_age_days.is_none()
+    }
+}
+
+/// 채팅 기록 정리 결과
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatPruneResult {
+    pub projects_pruned: u32,
+    pub deleted_messages: u32,
+    pub deleted_sessions: u32,
+    /// 삭제된 메시지 본문+메타데이터 크기 합계 (bytes)
+    pub reclaimed_bytes: i64,
+}
+
+/// FTS5 MATCH 구문으로 안전하게 변환 (각 검색어를 phrase로 감싸 AND 결합)
+fn to_fts_query(terms: &[&str]) -> String {
+    terms
+        .iter()
+        .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// LIKE 스캔 결과용 발췌 생성 (첫 매칭 주변 문자 일부)
+fn make_snippet(content: &str, term: &str) -> String {
+    const CONTEXT_CHARS: usize = 24;
+
+    let lower = content.to_lowercase();
+    let needle = term.to_lowercase();
+    // 대소문자 변환으로 길이가 달라지는 문자가 있으면 원문 앞부분만 사용
+    let byte_pos = if lower.len() == content.len() {
+        lower.find(&needle).filter(|&p| {
+            content.is_char_boundary(p) && content.is_char_boundary(p + needle.len())
+        })
+    } else {
+        None
+    };
+
+    let Some(byte_pos) = byte_pos else {
+        let head: String = content.chars().take(CONTEXT_CHARS * 2).collect();
+        return if head.len() < content.len() { format!("{}…", head) } else { head };
+    };
+
+    let before: Vec<char> = content[..byte_pos].chars().collect();
+    let matched = &content[byte_pos..byte_pos + needle.len()];
+    let after: Vec<char> = content[byte_pos + needle.len()..].chars().collect();
+
+    let start = before.len().saturating_sub(CONTEXT_CHARS);
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.extend(&before[start..]);
+    out.push('[');
+    out.push_str(matched);
+    out.push(']');
+    out.extend(after.iter().take(CONTEXT_CHARS));
+    if after.len() > CONTEXT_CHARS {
+        out.push('…');
+    }
+    out
+}
+
+impl Database {
+    /// 세션 1개 저장 (Insert or Replace)
+    /// - 같은 프로젝트의 다른 세션은 건드리지 않습니다.
+    /// - 세션 수가 MAX_CHAT_SESSIONS를 넘으면 가장 오래 활동이 없던 세션부터 정리합니다.
+    pub fn save_chat_session(&self, project_id: &str, session: &ChatSession) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+
+        let kept = kept_citations(&tx, project_id, Some(&session.id))?;
+        tx.execute("DELETE FROM chat_messages WHERE session_id = ?1", [&session.id])?;
+        tx.execute("DELETE FROM chat_sessions WHERE id = ?1", [&session.id])?;
+        insert_chat_session(&tx, project_id, session, &kept)?;
+
+        delete_chat_sessions_where(
+            &tx,
+            project_id,
+            "id IN (
+                SELECT s.id FROM chat_sessions s
+                WHERE s.project_id = ?1
+                ORDER BY COALESCE((SELECT MAX(m.timestamp) FROM chat_messages m WHERE m.session_id = s.id), s.created_at) DESC
+                LIMIT -1 OFFSET ?2
+             )",
+            (project_id, MAX_CHAT_SESSIONS as i64),
+        )?;
+        tx.execute(
+            "DELETE FROM chat_messages WHERE session_id NOT IN (SELECT id FROM chat_sessions)",
+            [],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 세션 단건 로드
+    pub fn load_chat_session(&self, session_id: &str) -> Result<Option<ChatSession>, IteError> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT id, name, created_at, context_block_ids, confluence_search_enabled
+                 FROM chat_sessions WHERE id = ?1",
+                [session_id],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, String>(3)?,
+                        row.get::<_, bool>(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        let Some((id, name, created_at, context_block_ids_json, confluence_search_enabled)) = row else {
+            return Ok(None);
+        };
+
+        let messages = load_chat_messages(&self.conn, &id)?;
+        Ok(Some(ChatSession {
+            id,
+            name,
+            created_at,
+            messages,
+            context_block_ids: serde_json::from_str(&context_block_ids_json).unwrap_or_default(),
+            confluence_search_enabled,
+        }))
+    }
+
+    /// 세션의 프로젝트 ID와 컨텍스트 블록 ID (메시지 제외)
+    pub fn load_chat_session_context_ids(&self, session_id: &str) -> Result<Option<(String, Vec<String>)>, IteError> {
+        let row: Option<(String, String)> = self
+            .conn
+            .query_row(
+                "SELECT project_id, context_block_ids FROM chat_sessions WHERE id = ?1",
+                [session_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(row.map(|(project_id, ids_json)| (project_id, serde_json::from_str(&ids_json).unwrap_or_default())))
+    }
+
+    /// 블록 분할/병합/삭제를 세션 컨텍스트에 반영 (기존 ID → 새 ID 목록, 비어 있으면 제거)
+    /// - 바뀐 세션 수를 반환합니다.
+    pub fn remap_chat_context_blocks(
+        &self,
+        project_id: &str,
+        replacements: &HashMap<String, Vec<String>>,
+    ) -> Result<usize, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let changed = remap_context_blocks(&tx, project_id, replacements)?;
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// 프로젝트의 세션 요약 목록 (최근 활동 순)
+    pub fn list_chat_session_summaries(&self, project_id: &str) -> Result<Vec<ChatSessionSummary>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.id, s.name, s.created_at,
+                    COALESCE(MAX(m.timestamp), s.created_at) AS last_ts,
+                    COUNT(m.id)
+             FROM chat_sessions s
+             LEFT JOIN chat_messages m ON m.session_id = s.id
+             WHERE s.project_id = ?1
+             GROUP BY s.id
+             ORDER BY last_ts DESC",
+        )?;
+
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(ChatSessionSummary {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                created_at: row.get(2)?,
+                last_activity_at: row.get(3)?,
+                message_count: row.get(4)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 세션 이름 변경
+    pub fn rename_chat_session(&self, session_id: &str, name: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let project_id: Option<String> = tx
+            .query_row(
+                "UPDATE chat_sessions SET name = ?1 WHERE id = ?2 RETURNING project_id",
+                (name, session_id),
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(project_id) = project_id else {
+            return Err(IteError::InvalidOperation(format!(
+                "Chat session not found: {}",
+                session_id
+            )));
+        };
+        self.ensure_project_writable(&project_id)?;
+        record_change(
+            &tx,
+            &Change::new("chat_session", session_id, ChangeOp::Update)
+                .project(&project_id)
+                .payload(name),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 세션 삭제 (메시지 포함)
+    pub fn delete_chat_session(&self, session_id: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM chat_messages WHERE session_id = ?1", [session_id])?;
+        let project_id: Option<String> = tx
+            .query_row(
+                "DELETE FROM chat_sessions WHERE id = ?1 RETURNING project_id",
+                [session_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(project_id) = project_id {
+            self.ensure_project_writable(&project_id)?;
+            record_change(&tx, &Change::new("chat_session", session_id, ChangeOp::Delete).project(&project_id))?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 프로젝트의 채팅 메시지 전문 검색
+    /// - 공백으로 구분된 모든 검색어를 포함하는 메시지를 찾습니다(AND).
+    /// - 3글자 이상 검색어는 FTS5(trigram) 인덱스를, 더 짧은 검색어가 섞이면 LIKE 스캔을 사용합니다.
+    pub fn search_chat_messages(
+        &self,
+        project_id: &str,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<ChatMessageSearchHit>, IteError> {
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let use_fts = terms.iter().all(|t| t.chars().count() >= FTS_MIN_TERM_CHARS);
+        let mut out = Vec::new();
+
+        if use_fts {
+            let mut stmt = self.conn.prepare(
+                "SELECT m.id, m.session_id, s.name, m.role,
+                        snippet(chat_messages_fts, 0, '[', ']', '…', 16), m.timestamp
+                 FROM chat_messages_fts
+                 JOIN chat_messages m ON m.rowid = chat_messages_fts.rowid
+                 JOIN chat_sessions s ON s.id = m.session_id
+                 WHERE chat_messages_fts MATCH ?1 AND s.project_id = ?2
+                 ORDER BY rank
+                 LIMIT ?3",
+            )?;
+            let iter = stmt.query_map((to_fts_query(&terms), project_id, limit as i64), |row| {
+                Ok(ChatMessageSearchHit {
+                    message_id: row.get(0)?,
+                    session_id: row.get(1)?,
+                    session_name: row.get(2)?,
+                    role: row.get(3)?,
+                    snippet: row.get(4)?,
+                    timestamp: row.get(5)?,
+                })
+            })?;
+            for r in iter {
+                out.push(r?);
+            }
+            return Ok(out);
+        }
+
+        // 짧은 검색어(예: 2글자 한국어 단어)는 trigram으로 찾을 수 없으므로 instr 스캔
+        let mut sql = String::from(
+            "SELECT m.id, m.session_id, s.name, m.role, m.content, m.timestamp
+             FROM chat_messages m
+             JOIN chat_sessions s ON s.id = m.session_id
+             WHERE s.project_id = ?1",
+        );
+        let lowered: Vec<String> = terms.iter().map(|t| t.to_lowercase()).collect();
+        for i in 0..lowered.len() {
+            sql.push_str(&format!(" AND instr(lower(m.content), ?{}) > 0", i + 2));
+        }
+        sql.push_str(&format!(" ORDER BY m.timestamp DESC LIMIT ?{}", lowered.len() + 2));
+
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&project_id];
+        for t in &lowered {
+            params.push(t);
+        }
+        let limit = limit as i64;
+        params.push(&limit);
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let iter = stmt.query_map(params.as_slice(), |row| {
+            let content: String = row.get(4)?;
+            Ok(ChatMessageSearchHit {
+                message_id: row.get(0)?,
+                session_id: row.get(1)?,
+                session_name: row.get(2)?,
+                role: row.get(3)?,
+                snippet: make_snippet(&content, terms[0]),
+                timestamp: row.get(5)?,
+            })
+        })?;
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 프로젝트 채팅 보관 정책 조회
+    pub fn get_chat_retention_policy(&self, project_id: &str) -> Result<Option<ChatRetentionPolicy>, IteError> {
+        let policy = self
+            .conn
+            .query_row(
+                "SELECT max_messages, max_age_days FROM chat_retention_policies WHERE project_id = ?1",
+                [project_id],
+                |row| {
+                    Ok(ChatRetentionPolicy {
+                        max_messages: row.get(0)?,
+                        max_age_days: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(policy)
+    }
+
+    /// 프로젝트 채팅 보관 정책 저장 (모든 기준이 비어 있으면 정책 삭제)
+    pub fn set_chat_retention_policy(
+        &self,
+        project_id: &str,
+        policy: &ChatRetentionPolicy,
+    ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        if policy.is_empty() {
+            tx.execute(
+                "DELETE FROM chat_retention_policies WHERE project_id = ?1",
+                [project_id],
+            )?;
+            record_change(
+                &tx,
+                &Change::new("chat_retention_policy", project_id, ChangeOp::Delete).project(project_id),
+            )?;
+            tx.commit()?;
+            return Ok(());
+        }
+
+        tx.execute(
+            "INSERT INTO chat_retention_policies (project_id, max_messages, max_age_days, updated_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(project_id) DO UPDATE SET
+                max_messages = excluded.max_messages,
+                max_age_days = excluded.max_age_days,
+                updated_at = excluded.updated_at",
+            (
+                project_id,
+                policy.max_messages,
+                policy.max_age_days,
+                chrono::Utc::now().timestamp_millis(),
+            ),
+        )?;
+        let payload = serde_json::to_string(policy)?;
+        record_change(
+            &tx,
+            &Change::new("chat_retention_policy", project_id, ChangeOp::Upsert)
+                .project(project_id)
+                .payload(&payload),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 저장된 보관 정책에 따라 채팅 기록 정리
+    /// - project_id가 None이면 정책이 설정된 모든 프로젝트를 정리합니다.
+    pub fn prune_chat_history(&self, project_id: Option<&str>) -> Result<ChatPruneResult, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT project_id, max_messages, max_age_days FROM chat_retention_policies
+             WHERE ?1 IS NULL OR project_id = ?1",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                ChatRetentionPolicy {
+                    max_messages: row.get(1)?,
+                    max_age_days: row.get(2)?,
+                },
+            ))
+        })?;
+        let mut policies = Vec::new();
+        for r in iter {
+            policies.push(r?);
+        }
+
+        let mut total = ChatPruneResult::default();
+        for (pid, policy) in policies {
+            let r = self.prune_chat_history_with_policy(&pid, &policy)?;
+            if r.deleted_messages > 0 || r.deleted_sessions > 0 {
+                total.projects_pruned += 1;
+            }
+            total.deleted_messages += r.deleted_messages;
+            total.deleted_sessions += r.deleted_sessions;
+            total.reclaimed_bytes += r.reclaimed_bytes;
+        }
+        Ok(total)
+    }
+
+    /// 주어진 정책으로 프로젝트 채팅 기록 정리
+    /// - 메시지가 모두 삭제되어 비게 된 세션도 함께 삭제합니다.
+    pub fn prune_chat_history_with_policy(
+        &self,
+        project_id: &str,
+        policy: &ChatRetentionPolicy,
+    ) -> Result<ChatPruneResult, IteError> {
+        let mut result = ChatPruneResult::default();
+        if policy.is_empty() {
+            return Ok(result);
+        }
+
+        let cutoff_ts: Option<i64> = policy
+            .max_age_days
+            .map(|days| chrono::Utc::now().timestamp_millis() - i64::from(days) * 86_400_000);
+
+        let tx = self.conn.unchecked_transaction()?;
+
+        // 삭제 대상 메시지 선별 (나이 초과 OR 최근 max_messages개 밖)
+        tx.execute_batch(
+            "CREATE TEMP TABLE IF NOT EXISTS chat_prune_targets (id TEXT PRIMARY KEY, session_id TEXT NOT NULL);
+             DELETE FROM chat_prune_targets;",
+        )?;
+        if let Some(cutoff) = cutoff_ts {
+            tx.execute(
+                "INSERT OR IGNORE INTO chat_prune_targets (id, session_id)
+                 SELECT m.id, m.session_id FROM chat_messages m
+                 JOIN chat_sessions s ON s.id = m.session_id
+                 WHERE s.project_id = ?1 AND m.timestamp < ?2",
+                (project_id, cutoff),
+            )?;
+        }
+        if let Some(max_messages) = policy.max_messages {
+            tx.execute(
+                "INSERT OR IGNORE INTO chat_prune_targets (id, session_id)
+                 SELECT m.id, m.session_id FROM chat_messages m
+                 JOIN chat_sessions s ON s.id = m.session_id
+                 WHERE s.project_id = ?1
+                 ORDER BY m.timestamp DESC
+                 LIMIT -1 OFFSET ?2",
+                (project_id, i64::from(max_messages)),
+            )?;
+        }
+
+        result.reclaimed_bytes = tx.query_row(
+            "SELECT COALESCE(SUM(length(CAST(m.content AS BLOB)) + COALESCE(length(CAST(m.metadata_json AS BLOB)), 0)), 0)
+             FROM chat_messages m WHERE m.id IN (SELECT id FROM chat_prune_targets)",
+            [],
+            |row| row.get(0),
+        )?;
+
+        result.deleted_messages = tx.execute(
+            "DELETE FROM chat_messages WHERE id IN (SELECT id FROM chat_prune_targets)",
+            [],
+        )? as u32;
+
+        result.deleted_sessions = delete_chat_sessions_where(
+            &tx,
+            project_id,
+            "id IN (SELECT DISTINCT session_id FROM chat_prune_targets)
+               AND NOT EXISTS (SELECT 1 FROM chat_messages m WHERE m.session_id = chat_sessions.id)",
+            [],
+        )? as u32;
+        if result.deleted_messages > 0 {
+            record_change(&tx, &Change::new("chat_message", project_id, ChangeOp::Delete).project(project_id))?;
+        }
+
+        tx.execute("DELETE FROM chat_prune_targets", [])?;
+        tx.commit()?;
+
+        if result.deleted_messages > 0 || result.deleted_sessions > 0 {
+            result.projects_pruned = 1;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::path::Path;
+
+    #[test]
+    fn test_message_artifacts_roundtrip() {
+        let db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO projects (id, version, metadata_json, created_at, updated_at) VALUES ('p1', '1', '{}', 0, 0)",
+                [],
+            )
+            .unwrap();
+
+        let artifacts = json!([
+            { "kind": "block", "reference": "b1", "title": "source", "content": "Hello" },
+            {
+                "kind": "citation",
+                "reference": "https://example.com/a",
+                "title": "Example",
+                "data": { "source": "web", "toolName": "web_search" }
+            },
+            {
+                "kind": "tool_result",
+                "reference": "notion_search",
+                "content": "{\"results\":[]}",
+                "data": { "status": "success", "args": { "query": "hi" } }
+            }
+        ]);
+        let session: ChatSession = serde_json::from_value(json!({
+            "id": "s1",
+            "name": "Chat",
+            "createdAt": 1,
+            "contextBlockIds": ["b1"],
+            "messages": [
+                { "id": "m1", "role": "user", "content": "q", "timestamp": 2, "metadata": null, "artifacts": artifacts },
+                { "id": "m2", "role": "assistant", "content": "a", "timestamp": 3, "metadata": null }
+            ]
+        }))
+        .unwrap();
+        db.save_chat_session("p1", &session).unwrap();
+        let expected = serde_json::to_value(&session.messages[0].artifacts).unwrap();
+
+        let loaded = db.load_chat_session("s1").unwrap().unwrap();
+        assert_eq!(serde_json::to_value(&loaded.messages[0].artifacts).unwrap(), expected);
+        assert!(loaded.messages[1].artifacts.is_empty());
+
+        // 다시 저장해도 순서/내용이 그대로
+        db.save_chat_session("p1", &loaded).unwrap();
+        let reloaded = db.load_chat_session("s1").unwrap().unwrap();
+        assert_eq!(serde_json::to_value(&reloaded.messages[0].artifacts).unwrap(), expected);
+    }
+}
@@ -0,0 +1,298 @@
+//! Chat Citations
+//!
+//! 어시스턴트에게 보여준 웹 검색/MCP 결과의 출처(URL, 발췌)를 메시지 첨부 항목으로 보관합니다.
+//! - `chat_message_artifacts`의 kind='citation' 행입니다 (reference=URL, title, content=발췌).
+//! - 출처 종류, 툴 이름, 가져온 시각은 `data_json`에 두고, 메시지를 지우면 트리거로 함께 정리됩니다.
+
+use rusqlite::OptionalExtension;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::ChatArtifactKind;
+
+/// 메시지당 최대 인용 수
+pub const MAX_CITATIONS_PER_MESSAGE: usize = 50;
+
+/// 발췌 최대 길이 (문자 수, 넘으면 잘라서 저장)
+pub const MAX_CITATION_SNIPPET_CHARS: usize = 1000;
+
+/// URL 최대 길이
+const MAX_CITATION_URL_LEN: usize = 2048;
+
+/// 인용 출처 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CitationSource {
+    Web,
+    Mcp,
+    Notion,
+    Confluence,
+}
+
+impl CitationSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Web => "web",
+            Self::Mcp => "mcp",
+            Self::Notion => "notion",
+            Self::Confluence => "confluence",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "web" => Some(Self::Web),
+            "mcp" => Some(Self::Mcp),
+            "notion" => Some(Self::Notion),
+            "confluence" => Some(Self::Confluence),
+            _ => None,
+        }
+    }
+}
+
+/// 기록할 인용 1건
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CitationInput {
+    pub source: CitationSource,
+    /// URL 또는 문서 ID
+    pub url: String,
+    pub title: Option<String>,
+    pub snippet: Option<String>,
+    /// 결과를 가져온 툴 이름 (MCP 툴 등)
+    pub tool_name: Option<String>,
+}
+
+/// 저장된 인용
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Citation {
+    pub message_id: String,
+    pub session_id: String,
+    pub position: i64,
+    pub source: CitationSource,
+    pub url: String,
+    pub title: Option<String>,
+    pub snippet: Option<String>,
+    pub tool_name: Option<String>,
+    pub retrieved_at: i64,
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+impl Database {
+    /// 메시지 인용 추가 (같은 URL은 제목/발췌만 갱신), 메시지의 전체 인용을 반환
+    /// - 메시지가 먼저 저장되어 있어야 합니다.
+    pub fn save_message_citations(
+        &self,
+        session_id: &str,
+        message_id: &str,
+        citations: &[CitationInput],
+    ) -> Result<Vec<Citation>, IteError> {
+        if session_id.trim().is_empty() || message_id.trim().is_empty() {
+            return Err(IteError::InvalidOperation(
+                "sessionId and messageId must not be empty".to_string(),
+            ));
+        }
+        for citation in citations {
+            let url = citation.url.trim();
+            if url.is_empty() || url.len() > MAX_CITATION_URL_LEN {
+                return Err(IteError::InvalidOperation(format!(
+                    "Citation URL must be 1-{} bytes",
+                    MAX_CITATION_URL_LEN
+                )));
+            }
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let tx = self.conn.unchecked_transaction()?;
+        let project_id: Option<String> = tx
+            .query_row(
+                "SELECT s.project_id FROM chat_messages m
+                 JOIN chat_sessions s ON s.id = m.session_id
+                 WHERE m.id = ?1 AND m.session_id = ?2",
+                (message_id, session_id),
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(project_id) = project_id else {
+            return Err(IteError::InvalidOperation(format!(
+                "Chat message not found: {}",
+                message_id
+            )));
+        };
+        self.ensure_project_writable(&project_id)?;
+        let kind = ChatArtifactKind::Citation.as_str();
+        let (mut count, mut next_position): (i64, i64) = tx.query_row(
+            "SELECT COALESCE(SUM(kind = ?2), 0), COALESCE(MAX(position) + 1, 0)
+             FROM chat_message_artifacts WHERE message_id = ?1",
+            (message_id, kind),
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        let mut changed = false;
+        for citation in citations {
+            let url = citation.url.trim();
+            let snippet = non_empty(&citation.snippet)
+                .map(|s| s.chars().take(MAX_CITATION_SNIPPET_CHARS).collect::<String>());
+            let updated = tx.execute(
+                "UPDATE chat_message_artifacts SET
+                    title = COALESCE(?4, title),
+                    content = COALESCE(?5, content),
+                    data_json = json_set(
+                        COALESCE(data_json, '{}'),
+                        '$.toolName', COALESCE(?6, json_extract(data_json, '$.toolName')),
+                        '$.retrievedAt', ?7
+                    )
+                 WHERE message_id = ?1 AND kind = ?2 AND reference = ?3",
+                (
+                    message_id,
+                    kind,
+                    url,
+                    non_empty(&citation.title),
+                    &snippet,
+                    non_empty(&citation.tool_name),
+                    now,
+                ),
+            )?;
+            if updated > 0 {
+                changed = true;
+                continue;
+            }
+            if count as usize >= MAX_CITATIONS_PER_MESSAGE {
+                continue;
+            }
+            let data = json!({
+                "source": citation.source.as_str(),
+                "toolName": non_empty(&citation.tool_name),
+                "retrievedAt": now,
+            });
+            tx.execute(
+                "INSERT INTO chat_message_artifacts (message_id, position, kind, reference, title, content, data_json)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                (
+                    message_id,
+                    next_position,
+                    kind,
+                    url,
+                    non_empty(&citation.title),
+                    &snippet,
+                    data.to_string(),
+                ),
+            )?;
+            changed = true;
+            count += 1;
+            next_position += 1;
+        }
+        if changed {
+            record_change(&tx, &Change::new("citation", message_id, ChangeOp::Upsert).project(&project_id))?;
+        }
+        tx.commit()?;
+
+        self.get_message_citations(message_id)
+    }
+
+    /// 메시지 인용 (기록한 순서)
+    pub fn get_message_citations(&self, message_id: &str) -> Result<Vec<Citation>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT a.message_id, m.session_id, a.position, a.reference, a.title, a.content, a.data_json, m.timestamp
+             FROM chat_message_artifacts a
+             JOIN chat_messages m ON m.id = a.message_id
+             WHERE a.message_id = ?1 AND a.kind = ?2
+             ORDER BY a.position",
+        )?;
+        let iter = stmt.query_map((message_id, ChatArtifactKind::Citation.as_str()), |row| {
+            Ok((
+                Citation {
+                    message_id: row.get(0)?,
+                    session_id: row.get(1)?,
+                    position: row.get(2)?,
+                    source: CitationSource::Web,
+                    url: row.get(3)?,
+                    title: row.get(4)?,
+                    snippet: row.get(5)?,
+                    tool_name: None,
+                    retrieved_at: row.get(7)?,
+                },
+                row.get::<_, Option<String>>(6)?,
+            ))
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            let (mut citation, data_json) = r?;
+            let data: Value = data_json
+                .as_deref()
+                .and_then(|s| serde_json::from_str(s).ok())
+                .unwrap_or(Value::Null);
+            // 출처 종류가 없으면(프론트엔드가 채운 항목) 웹 검색, 이후 버전에서 추가된 종류는 건너뜀
+            if let Some(source) = data.get("source").and_then(Value::as_str) {
+                let Some(source) = CitationSource::parse(source) else {
+                    continue;
+                };
+                citation.source = source;
+            }
+            citation.tool_name = data.get("toolName").and_then(Value::as_str).map(str::to_string);
+            if let Some(retrieved_at) = data.get("retrievedAt").and_then(Value::as_i64) {
+                citation.retrieved_at = retrieved_at;
+            }
+            out.push(citation);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ChatSession;
+    use std::path::Path;
+
+    #[test]
+    fn test_citations_are_message_artifacts() {
+        let db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO projects (id, version, metadata_json, created_at, updated_at) VALUES ('p1', '1', '{}', 0, 0)",
+                [],
+            )
+            .unwrap();
+        let session: ChatSession = serde_json::from_value(json!({
+            "id": "s1", "name": "Chat", "createdAt": 0, "contextBlockIds": [],
+            "messages": [{ "id": "m1", "role": "assistant", "content": "답변", "timestamp": 1, "metadata": null }]
+        }))
+        .unwrap();
+        db.save_chat_session("p1", &session).unwrap();
+
+        let input = |title: &str| CitationInput {
+            source: CitationSource::Mcp,
+            url: "https://example.com/doc".to_string(),
+            title: Some(title.to_string()),
+            snippet: Some("발췌".to_string()),
+            tool_name: Some("search".to_string()),
+        };
+        assert!(db.save_message_citations("s1", "missing", &[input("Doc")]).is_err());
+        db.save_message_citations("s1", "m1", &[input("Doc")]).unwrap();
+        let citations = db.save_message_citations("s1", "m1", &[input("Doc v2")]).unwrap();
+        assert_eq!(citations.len(), 1);
+        assert_eq!(citations[0].source, CitationSource::Mcp);
+        assert_eq!(citations[0].title.as_deref(), Some("Doc v2"));
+        assert_eq!(citations[0].tool_name.as_deref(), Some("search"));
+
+        // 세션을 불러오면 같은 행이 메시지 첨부 항목으로 보임
+        let loaded = db.load_chat_session("s1").unwrap().unwrap();
+        let artifact = &loaded.messages[0].artifacts[0];
+        assert_eq!(artifact.kind, ChatArtifactKind::Citation);
+        assert_eq!(artifact.reference, "https://example.com/doc");
+        assert_eq!(artifact.content.as_deref(), Some("발췌"));
+
+        let changes = db.get_changes_since(0, 100, Some("p1")).unwrap().changes;
+        assert_eq!(changes.iter().filter(|c| c.entity == "citation").count(), 2);
+    }
+}
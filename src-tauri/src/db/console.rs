@@ -0,0 +1,222 @@
+//! Read-only Query Console
+//!
+//! 고급 사용자/지원 담당자가 앱 안에서 자기 데이터를 SQL로 조회하는 콘솔
+//! - SQLite authorizer로 SELECT 읽기만 허용합니다. 쓰기, ATTACH, 트랜잭션, 스키마 변경, 값을 바꾸는 PRAGMA는 준비 단계에서 거부됩니다.
+//! - 한 번에 한 문장만 실행하고, 결과는 `max_rows`행까지, `QUERY_TIMEOUT`을 넘으면 중단합니다.
+
+use std::time::{Duration, Instant};
+
+use rusqlite::hooks::{AuthAction, AuthContext, Authorization};
+use rusqlite::types::ValueRef;
+use rusqlite::{Batch, ErrorCode};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::Database;
+use crate::error::IteError;
+
+/// 결과 최대 행 수 (요청값 상한)
+pub const MAX_CONSOLE_ROWS: usize = 5_000;
+
+/// 이보다 오래 걸리면 중단
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 진행 콜백 간격 (SQLite 가상 머신 명령 수)
+const PROGRESS_OPS: i32 = 10_000;
+
+/// 스키마 확인용 PRAGMA (모두 읽기 전용, 인자는 테이블/인덱스 이름)
+const INTROSPECTION_PRAGMAS: &[&str] = &[
+    "table_info",
+    "table_xinfo",
+    "table_list",
+    "index_list",
+    "index_info",
+    "index_xinfo",
+    "foreign_key_list",
+];
+
+/// 콘솔 조회 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsoleQueryResult {
+    pub columns: Vec<String>,
+    /// 행별 값 (BLOB은 크기만 표시)
+    pub rows: Vec<Vec<Value>>,
+    /// `max_rows`에서 잘렸는지
+    pub truncated: bool,
+    pub elapsed_ms: f64,
+}
+
+/// SELECT/읽기만 허용
+fn authorize(context: AuthContext<'_>) -> Authorization {
+    match context.action {
+        AuthAction::Select | AuthAction::Read { .. } | AuthAction::Recursive | AuthAction::Function { .. } => {
+            Authorization::Allow
+        }
+        AuthAction::Pragma { pragma_name, .. }
+            if INTROSPECTION_PRAGMAS.contains(&pragma_name.to_ascii_lowercase().as_str()) =>
+        {
+            Authorization::Allow
+        }
+        _ => Authorization::Deny,
+    }
+}
+
+fn json_value(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(i) => Value::from(i),
+        ValueRef::Real(f) => serde_json::Number::from_f64(f).map_or(Value::Null, Value::Number),
+        ValueRef::Text(bytes) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+        ValueRef::Blob(bytes) => Value::String(format!("<BLOB {} bytes>", bytes.len())),
+    }
+}
+
+/// 거부/중단 오류를 콘솔용 메시지로
+fn console_error(error: rusqlite::Error) -> IteError {
+    match &error {
+        rusqlite::Error::SqliteFailure(e, message) if e.code == ErrorCode::AuthorizationForStatementDenied => {
+            IteError::InvalidOperation(format!(
+                "Only read-only SELECT statements are allowed ({})",
+                message.as_deref().unwrap_or("not authorized")
+            ))
+        }
+        rusqlite::Error::SqliteFailure(e, _) if e.code == ErrorCode::OperationInterrupted => {
+            IteError::InvalidOperation(format!("Query timed out after {}s", QUERY_TIMEOUT.as_secs()))
+        }
+        _ => IteError::Database(error),
+    }
+}
+
+impl Database {
+    /// 읽기 전용 SQL 실행 (authorizer/진행 콜백은 이 조회 동안만 걸어 둠)
+    pub fn run_readonly_query(&self, sql: &str, max_rows: usize) -> Result<ConsoleQueryResult, IteError> {
+        let started = Instant::now();
+        let deadline = started + QUERY_TIMEOUT;
+        self.conn.authorizer(Some(authorize));
+        self.conn.progress_handler(PROGRESS_OPS, Some(move || Instant::now() > deadline));
+
+        let result = self.query_console(sql, max_rows.min(MAX_CONSOLE_ROWS));
+
+        self.conn.authorizer(None::<fn(AuthContext<'_>) -> Authorization>);
+        self.conn.progress_handler(0, None::<fn() -> bool>);
+        let mut result = result?;
+        result.elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+        Ok(result)
+    }
+
+    fn query_console(&self, sql: &str, max_rows: usize) -> Result<ConsoleQueryResult, IteError> {
+        let mut batch = Batch::new(&self.conn, sql);
+        let mut stmt = batch
+            .next()
+            .map_err(console_error)?
+            .ok_or_else(|| IteError::InvalidOperation("No statement to run".to_string()))?;
+        // 뒤따르는 문장은 authorizer 거부로 준비에 실패해도 문장 수 오류로 보고
+        if !matches!(batch.next(), Ok(None)) {
+            return Err(IteError::InvalidOperation("Only one statement can be run at a time".to_string()));
+        }
+        // authorizer를 통과했어도 한 번 더 확인 (SQLite가 읽기 전용으로 판정한 문장만)
+        if !stmt.readonly() {
+            return Err(IteError::InvalidOperation(
+                "Only read-only SELECT statements are allowed".to_string(),
+            ));
+        }
+        let columns: Vec<String> = stmt.column_names().into_iter().map(str::to_string).collect();
+
+        let mut rows = stmt.query([]).map_err(console_error)?;
+        let mut out = Vec::new();
+        let mut truncated = false;
+        while let Some(row) = rows.next().map_err(console_error)? {
+            if out.len() == max_rows {
+                truncated = true;
+                break;
+            }
+            let values = (0..columns.len())
+                .map(|i| row.get_ref(i).map(json_value))
+                .collect::<Result<Vec<_>, _>>()?;
+            out.push(values);
+        }
+        Ok(ConsoleQueryResult {
+            columns,
+            rows: out,
+            truncated,
+            elapsed_ms: 0.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DntTerm;
+    use std::path::Path;
+
+    fn db() -> Database {
+        let db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db
+    }
+
+    fn rejected(db: &Database, sql: &str) -> String {
+        match db.run_readonly_query(sql, 10) {
+            Err(IteError::InvalidOperation(message)) => message,
+            other => panic!("{:?} should be rejected, got {:?}", sql, other),
+        }
+    }
+
+    #[test]
+    fn runs_select() {
+        let db = db();
+        let result = db.run_readonly_query("SELECT 1 AS one, 'a' AS two UNION ALL SELECT 2, NULL", 1).unwrap();
+        assert_eq!(result.columns, vec!["one", "two"]);
+        assert_eq!(result.rows, vec![vec![Value::from(1), Value::from("a")]]);
+        assert!(result.truncated);
+        assert!(db.run_readonly_query("SELECT COUNT(*) FROM projects", 10).is_ok());
+    }
+
+    #[test]
+    fn rejects_writes_and_multiple_statements() {
+        let db = db();
+        for sql in [
+            "INSERT INTO projects (id, version, metadata_json, created_at, updated_at) VALUES ('p', '1', '{}', 0, 0)",
+            "UPDATE projects SET version = '2'",
+            "DELETE FROM projects",
+            "DROP TABLE projects",
+            "ATTACH DATABASE ':memory:' AS other",
+            "PRAGMA user_version = 1",
+            "BEGIN",
+        ] {
+            let message = rejected(&db, sql);
+            assert!(message.starts_with("Only read-only SELECT"), "{}: {}", sql, message);
+        }
+        for sql in ["SELECT 1; DELETE FROM projects", "SELECT 1; SELECT 2"] {
+            assert_eq!(rejected(&db, sql), "Only one statement can be run at a time", "{}", sql);
+        }
+        assert_eq!(db.run_readonly_query("SELECT 1; -- trailing comment", 10).unwrap().rows.len(), 1);
+    }
+
+    #[test]
+    fn times_out_and_resets_hooks() {
+        let db = db();
+        let message = rejected(
+            &db,
+            "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c) SELECT COUNT(*) FROM c",
+        );
+        assert!(message.starts_with("Query timed out"), "{}", message);
+        assert!(rejected(&db, "DELETE FROM projects").starts_with("Only read-only SELECT"));
+
+        // 거부/중단 뒤에도 일반 쓰기는 authorizer/진행 콜백 없이 동작
+        db.save_dnt_term(&DntTerm {
+            id: "t1".to_string(),
+            project_id: None,
+            term: "OddEyes".to_string(),
+            is_regex: false,
+            case_sensitive: true,
+            notes: None,
+            created_at: 0,
+            updated_at: 0,
+        })
+        .unwrap();
+        assert_eq!(db.list_dnt_terms(None).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,175 @@
+//! Project Custom Fields
+//!
+//! 프로젝트별 사용자 정의 key/value 메타데이터(고객사, PO 번호, 납기일 등) 저장/조회
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+
+/// 필드 키 최대 길이 (문자 수)
+pub const MAX_FIELD_KEY_LEN: usize = 64;
+/// 필드 값 최대 길이 (문자 수)
+pub const MAX_FIELD_VALUE_LEN: usize = 1000;
+
+/// 최근 프로젝트 필터 조건 (값이 비어 있으면 키 존재 여부만 확인, 대소문자 무시 일치)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldFilter {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+fn validate_key(key: &str) -> Result<&str, IteError> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(IteError::InvalidOperation("Field key must not be empty".to_string()));
+    }
+    if key.chars().count() > MAX_FIELD_KEY_LEN {
+        return Err(IteError::InvalidOperation(format!(
+            "Field key is too long (max {} characters)",
+            MAX_FIELD_KEY_LEN
+        )));
+    }
+    Ok(key)
+}
+
+impl Database {
+    /// 프로젝트 사용자 정의 필드 설정 (value가 None이거나 빈 문자열이면 삭제)
+    pub fn set_project_custom_field(
+        &self,
+        project_id: &str,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let key = validate_key(key)?;
+        let value = value.map(str::trim).filter(|v| !v.is_empty());
+
+        let Some(value) = value else {
+            let tx = self.conn.unchecked_transaction()?;
+            tx.execute(
+                "DELETE FROM project_custom_fields WHERE project_id = ?1 AND field_key = ?2",
+                [project_id, key],
+            )?;
+            record_change(&tx, &Change::new("custom_field", key, ChangeOp::Delete).project(project_id))?;
+            tx.commit()?;
+            return Ok(());
+        };
+        if value.chars().count() > MAX_FIELD_VALUE_LEN {
+            return Err(IteError::InvalidOperation(format!(
+                "Field value is too long (max {} characters)",
+                MAX_FIELD_VALUE_LEN
+            )));
+        }
+
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?1)",
+            [project_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO project_custom_fields (project_id, field_key, value, updated_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(project_id, field_key) DO UPDATE SET
+                value = excluded.value,
+                updated_at = excluded.updated_at",
+            (project_id, key, value, chrono::Utc::now().timestamp_millis()),
+        )?;
+        record_change(
+            &tx,
+            &Change::new("custom_field", key, ChangeOp::Upsert)
+                .project(project_id)
+                .payload(value),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 프로젝트 사용자 정의 필드 전체 (키 순)
+    pub fn get_project_custom_fields(&self, project_id: &str) -> Result<BTreeMap<String, String>, IteError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT field_key, value FROM project_custom_fields WHERE project_id = ?1")?;
+        let iter = stmt.query_map([project_id], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+
+        let mut out = BTreeMap::new();
+        for r in iter {
+            let (key, value) = r?;
+            out.insert(key, value);
+        }
+        Ok(out)
+    }
+
+    /// 사용 중인 필드 키 목록 (필터 UI 자동완성용, 키 순)
+    pub fn list_custom_field_keys(&self) -> Result<Vec<String>, IteError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT DISTINCT field_key FROM project_custom_fields ORDER BY field_key")?;
+        let iter = stmt.query_map([], |row| row.get::<_, String>(0))?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 모든 프로젝트의 사용자 정의 필드 (project_id → 필드)
+    pub(super) fn load_all_custom_fields(&self) -> Result<HashMap<String, BTreeMap<String, String>>, IteError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT project_id, field_key, value FROM project_custom_fields")?;
+        let iter = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut out: HashMap<String, BTreeMap<String, String>> = HashMap::new();
+        for r in iter {
+            let (project_id, key, value) = r?;
+            out.entry(project_id).or_default().insert(key, value);
+        }
+        Ok(out)
+    }
+
+    /// 사용자 정의 필드 복사 (프로젝트 복제용)
+    pub fn copy_project_custom_fields(&self, from_project_id: &str, to_project_id: &str) -> Result<(), IteError> {
+        self.ensure_project_writable(to_project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let copied: Vec<(String, String)> = {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO project_custom_fields (project_id, field_key, value, updated_at)
+                 SELECT ?2, field_key, value, ?3 FROM project_custom_fields WHERE project_id = ?1
+                 RETURNING field_key, value",
+            )?;
+            let rows = stmt.query_map(
+                (from_project_id, to_project_id, chrono::Utc::now().timestamp_millis()),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (key, value) in &copied {
+            record_change(
+                &tx,
+                &Change::new("custom_field", key, ChangeOp::Upsert)
+                    .project(to_project_id)
+                    .payload(value),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
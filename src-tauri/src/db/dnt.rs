@@ -0,0 +1,120 @@
+//! DNT Term Storage
+//!
+//! 번역 금지(DNT) 용어/패턴 저장소
+
+use rusqlite::OptionalExtension;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::DntTerm;
+use crate::text::unicode::into_nfc;
+
+impl Database {
+    /// DNT 용어 목록 조회
+    /// - project_id가 주어지면 전역 + 해당 프로젝트 용어를, None이면 전역 용어만 반환합니다.
+    pub fn list_dnt_terms(&self, project_id: Option<&str>) -> Result<Vec<DntTerm>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, project_id, term, is_regex, case_sensitive, notes, created_at, updated_at
+             FROM dnt_terms
+             WHERE project_id IS NULL OR project_id = ?1
+             ORDER BY length(term) DESC, term",
+        )?;
+
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(DntTerm {
+                id: row.get(0)?,
+                project_id: row.get(1)?,
+                term: row.get(2)?,
+                is_regex: row.get(3)?,
+                case_sensitive: row.get(4)?,
+                notes: row.get(5)?,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// DNT 용어 저장 (Insert or Update, created_at은 기존 유지)
+    pub fn save_dnt_term(&self, term: &DntTerm) -> Result<(), IteError> {
+        // 새 프로젝트뿐 아니라 기존에 속한 프로젝트도 확인 (프로젝트 간 이동/전역 전환)
+        if let Some(project_id) = &term.project_id {
+            self.ensure_project_writable(project_id)?;
+        }
+        if let Some(project_id) = self.dnt_term_project(&term.id)? {
+            self.ensure_project_writable(&project_id)?;
+        }
+        // 저장/동기화 payload 모두 NFC로 맞춤
+        let term = &DntTerm {
+            term: into_nfc(term.term.clone()),
+            notes: term.notes.clone().map(into_nfc),
+            ..term.clone()
+        };
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO dnt_terms (
+                id, project_id, term, is_regex, case_sensitive, notes, created_at, updated_at
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(id) DO UPDATE SET
+                project_id = excluded.project_id,
+                term = excluded.term,
+                is_regex = excluded.is_regex,
+                case_sensitive = excluded.case_sensitive,
+                notes = excluded.notes,
+                updated_at = excluded.updated_at",
+            (
+                &term.id,
+                &term.project_id,
+                &term.term,
+                term.is_regex,
+                term.case_sensitive,
+                &term.notes,
+                term.created_at,
+                term.updated_at,
+            ),
+        )?;
+        let payload = serde_json::to_string(term)?;
+        let mut change = Change::new("dnt_term", &term.id, ChangeOp::Upsert).payload(&payload);
+        if let Some(project_id) = &term.project_id {
+            change = change.project(project_id);
+        }
+        record_change(&tx, &change)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// DNT 용어 삭제
+    pub fn delete_dnt_term(&self, id: &str) -> Result<(), IteError> {
+        if let Some(project_id) = self.dnt_term_project(id)? {
+            self.ensure_project_writable(&project_id)?;
+        }
+        let tx = self.conn.unchecked_transaction()?;
+        let deleted: Option<Option<String>> = tx
+            .query_row("DELETE FROM dnt_terms WHERE id = ?1 RETURNING project_id", [id], |row| row.get(0))
+            .optional()?;
+        if let Some(project_id) = deleted {
+            let mut change = Change::new("dnt_term", id, ChangeOp::Delete);
+            if let Some(project_id) = &project_id {
+                change = change.project(project_id);
+            }
+            record_change(&tx, &change)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 저장된 DNT 용어가 속한 프로젝트 (없거나 전역이면 None)
+    fn dnt_term_project(&self, id: &str) -> Result<Option<String>, IteError> {
+        let project_id: Option<Option<String>> = self
+            .conn
+            .query_row("SELECT project_id FROM dnt_terms WHERE id = ?1", [id], |row| row.get(0))
+            .optional()?;
+        Ok(project_id.flatten())
+    }
+}
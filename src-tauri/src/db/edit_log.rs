@@ -0,0 +1,261 @@
+//! Block Edit Log
+//!
+//! 블록 내용이 바뀔 때마다 편집 기록을 남기고, 이를 집계해 생산성 리포트를 만듭니다.
+//! - 블록별 변경 이력(blame)은 편집 기록 + 리뷰어 수정 + 히스토리 스냅샷을 시간순으로 합칩니다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::Serialize;
+
+use super::Database;
+use crate::error::IteError;
+use crate::models::BlockChange;
+use crate::text::words::count_html_words;
+
+/// 일자·프로젝트별 번역 실적
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductivityRow {
+    /// 로컬 날짜 (YYYY-MM-DD)
+    pub date: String,
+    pub project_id: String,
+    pub project_title: String,
+    /// 번역문 블록에서 늘어난 단어 수 합계
+    pub words_translated: i64,
+    /// 편집된 번역문 블록 수
+    pub blocks_edited: i64,
+}
+
+/// 블록 변경 이력 항목 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockHistorySource {
+    /// 저장 시 기록된 편집
+    Edit,
+    /// 리뷰어 수정 제안
+    Revision,
+    /// 히스토리 스냅샷에 담긴 변경
+    Snapshot,
+}
+
+/// 블록 변경 이력 1건
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHistoryEntry {
+    pub source: BlockHistorySource,
+    pub timestamp: i64,
+    pub author: Option<String>,
+    /// 변경 전 콘텐츠 (첫 편집 기록처럼 알 수 없으면 None)
+    pub previous_content: Option<String>,
+    /// 변경 후 콘텐츠 (삭제면 빈 문자열)
+    pub content: String,
+    /// 리뷰어 수정 ID 또는 스냅샷 ID
+    pub reference: Option<String>,
+    /// 리뷰어 수정 상태 또는 스냅샷 설명
+    pub note: Option<String>,
+}
+
+/// 프로젝트의 현재 블록 콘텐츠 (block_id → content)
+pub(super) fn load_block_contents(conn: &Connection, project_id: &str) -> Result<HashMap<String, String>, IteError> {
+    let mut stmt = conn.prepare("SELECT id, content FROM blocks WHERE project_id = ?1")?;
+    let iter = stmt.query_map([project_id], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+
+    let mut out = HashMap::new();
+    for r in iter {
+        let (id, content) = r?;
+        out.insert(id, content);
+    }
+    Ok(out)
+}
+
+/// 기록할 블록 편집 1건
+pub(super) struct BlockEdit<'a> {
+    pub block_id: &'a str,
+    pub block_type: &'a str,
+    pub author: Option<&'a str>,
+    pub before: &'a str,
+    pub after: &'a str,
+}
+
+/// 블록 편집 기록 추가 (내용이 같으면 기록하지 않음)
+pub(super) fn record_block_edit(
+    conn: &Connection,
+    project_id: &str,
+    edit: &BlockEdit,
+    edited_at: i64,
+) -> Result<(), IteError> {
+    if edit.before == edit.after {
+        return Ok(());
+    }
+    conn.prepare_cached(
+        "INSERT INTO block_edits (project_id, block_id, block_type, edited_at, author, words_before, words_after, content)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?
+    .execute((
+        project_id,
+        edit.block_id,
+        edit.block_type,
+        edited_at,
+        edit.author,
+        count_html_words(edit.before) as i64,
+        count_html_words(edit.after) as i64,
+        edit.after,
+    ))?;
+    Ok(())
+}
+
+impl Database {
+    /// 기간 내 번역문 편집 기록을 로컬 날짜·프로젝트별로 집계
+    /// - from/to: Unix epoch(ms), to는 포함하지 않음
+    pub fn productivity_rows(
+        &self,
+        from: i64,
+        to: i64,
+        project_id: Option<&str>,
+    ) -> Result<Vec<ProductivityRow>, IteError> {
+        use chrono::TimeZone;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT e.project_id, COALESCE(json_extract(p.metadata_json, '$.title'), ''), e.block_id,
+                    e.edited_at, e.words_before, e.words_after
+             FROM block_edits e
+             JOIN projects p ON p.id = e.project_id
+             WHERE e.block_type = 'target'
+               AND e.edited_at >= ?1 AND e.edited_at < ?2
+               AND (?3 IS NULL OR e.project_id = ?3)
+             ORDER BY e.edited_at",
+        )?;
+        let iter = stmt.query_map((from, to, project_id), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, i64>(3)?,
+                row.get::<_, i64>(4)?,
+                row.get::<_, i64>(5)?,
+            ))
+        })?;
+
+        // (date, project_id) → (title, words, edited blocks)
+        let mut grouped: BTreeMap<(String, String), (String, i64, HashSet<String>)> = BTreeMap::new();
+        for r in iter {
+            let (project_id, title, block_id, edited_at, words_before, words_after) = r?;
+            let date = chrono::Local
+                .timestamp_millis_opt(edited_at)
+                .single()
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            let entry = grouped
+                .entry((date, project_id))
+                .or_insert_with(|| (title, 0, HashSet::new()));
+            entry.1 += (words_after - words_before).max(0);
+            entry.2.insert(block_id);
+        }
+
+        Ok(grouped
+            .into_iter()
+            .map(|((date, project_id), (project_title, words_translated, blocks))| ProductivityRow {
+                date,
+                project_id,
+                project_title,
+                words_translated,
+                blocks_edited: blocks.len() as i64,
+            })
+            .collect())
+    }
+
+    /// 블록 변경 이력 (오래된 순)
+    /// - 블록이 지금 없어도 기록이 남아 있으면 조회됩니다. 기록도 블록도 없으면 BlockNotFound.
+    pub fn block_history(&self, block_id: &str) -> Result<Vec<BlockHistoryEntry>, IteError> {
+        let project_id: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT project_id FROM blocks WHERE id = ?1
+                 UNION ALL SELECT project_id FROM block_edits WHERE block_id = ?1
+                 UNION ALL SELECT project_id FROM revisions WHERE block_id = ?1
+                 LIMIT 1",
+                [block_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(project_id) = project_id else {
+            return Err(IteError::BlockNotFound(block_id.to_string()));
+        };
+
+        let mut entries = Vec::new();
+
+        let mut stmt = self.conn.prepare(
+            "SELECT edited_at, author, content FROM block_edits
+             WHERE block_id = ?1 AND project_id = ?2
+             ORDER BY edited_at, id",
+        )?;
+        let iter = stmt.query_map((block_id, &project_id), |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, Option<String>>(1)?, row.get::<_, String>(2)?))
+        })?;
+        let mut previous: Option<String> = None;
+        for r in iter {
+            let (timestamp, author, content) = r?;
+            entries.push(BlockHistoryEntry {
+                source: BlockHistorySource::Edit,
+                timestamp,
+                author,
+                previous_content: previous.replace(content.clone()),
+                content,
+                reference: None,
+                note: None,
+            });
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, author, status, base_content, revised_content, created_at FROM revisions
+             WHERE block_id = ?1 AND project_id = ?2",
+        )?;
+        let iter = stmt.query_map((block_id, &project_id), |row| {
+            Ok(BlockHistoryEntry {
+                source: BlockHistorySource::Revision,
+                timestamp: row.get(5)?,
+                author: row.get(1)?,
+                previous_content: Some(row.get(3)?),
+                content: row.get(4)?,
+                reference: Some(row.get(0)?),
+                note: Some(row.get(2)?),
+            })
+        })?;
+        for r in iter {
+            entries.push(r?);
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT h.id, h.timestamp, h.description, h.changes_json FROM history h
+             WHERE h.project_id = ?2
+               AND EXISTS (SELECT 1 FROM json_each(h.changes_json) WHERE json_extract(value, '$.blockId') = ?1)",
+        )?;
+        let iter = stmt.query_map((block_id, &project_id), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+        for r in iter {
+            let (id, timestamp, description, changes_json) = r?;
+            let changes: Vec<BlockChange> = serde_json::from_str(&changes_json).unwrap_or_default();
+            for change in changes.into_iter().filter(|c| c.block_id == block_id) {
+                entries.push(BlockHistoryEntry {
+                    source: BlockHistorySource::Snapshot,
+                    timestamp,
+                    author: None,
+                    previous_content: (change.change_type != "create").then_some(change.previous_content),
+                    content: change.new_content,
+                    reference: Some(id.clone()),
+                    note: Some(description.clone()),
+                });
+            }
+        }
+
+        entries.sort_by_key(|e| e.timestamp);
+        Ok(entries)
+    }
+}
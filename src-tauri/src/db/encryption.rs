@@ -0,0 +1,193 @@
+//! Database Encryption (SQLCipher)
+//!
+//! `sqlcipher` feature로 빌드하면 ite.db를 SQLCipher로 암호화할 수 있습니다 (opt-in).
+//! - 키: 32바이트 랜덤 raw key (hex), SecretManager에 `DATABASE_KEY_SECRET`으로 보관
+//! - 암호화 여부는 파일 헤더로 판별 (평문 SQLite는 "SQLite format 3\0"으로 시작)
+//! - 전환(평문 ↔ 암호화)과 암호화 DB로의 import는 `sqlcipher_export`로 새 파일을 만든 뒤 교체합니다.
+//! - .ite 내보내기/자동 백업은 공유를 위해 항상 평문입니다.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+
+use super::Database;
+use crate::error::IteError;
+
+/// DB 암호화 키 시크릿 이름
+pub const DATABASE_KEY_SECRET: &str = "db/encryption_key";
+/// raw key 길이 (bytes)
+const DATABASE_KEY_LEN: usize = 32;
+
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+
+/// 새 암호화 키 생성 (hex)
+pub fn generate_database_key() -> String {
+    use rand::Rng;
+    let bytes: [u8; DATABASE_KEY_LEN] = rand::thread_rng().gen();
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// PRAGMA/ATTACH에 넣을 raw key 표현 (`x'...'`, None이면 평문)
+/// - PRAGMA는 파라미터 바인딩이 안 되므로 hex 형식만 허용합니다.
+fn raw_key_literal(key_hex: Option<&str>) -> Result<String, IteError> {
+    let Some(key_hex) = key_hex else {
+        return Ok(String::new());
+    };
+    if key_hex.len() != DATABASE_KEY_LEN * 2 || !key_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(IteError::InvalidOperation("Invalid database key format".to_string()));
+    }
+    Ok(format!("x'{}'", key_hex))
+}
+
+/// 암호화된 DB 파일인지 (파일이 없거나 비어 있으면 false)
+pub fn is_encrypted_database_file(path: &Path) -> bool {
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return false;
+    };
+    let mut header = [0u8; 16];
+    match file.read(&mut header) {
+        Ok(0) | Err(_) => false,
+        Ok(n) => !header[..n].starts_with(SQLITE_HEADER),
+    }
+}
+
+/// conn의 main DB를 dst 파일로 복사 (dst_key가 None이면 평문)
+fn export_copy(conn: &Connection, dst: &Path, dst_key: Option<&str>) -> Result<(), IteError> {
+    if dst.exists() {
+        std::fs::remove_file(dst)?;
+    }
+    conn.execute(
+        "ATTACH DATABASE ?1 AS exported KEY ?2",
+        (dst.to_string_lossy().as_ref(), raw_key_literal(dst_key)?.as_str()),
+    )?;
+    let exported = conn.query_row("SELECT sqlcipher_export('exported')", [], |_| Ok(()));
+    conn.execute_batch("DETACH DATABASE exported;")?;
+    if let Err(e) = exported {
+        let _ = std::fs::remove_file(dst);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// DB 파일 열기 + 스키마 초기화 (키가 있으면 암호화 DB로 엶)
+fn open_database_file(path: &Path, key: Option<&str>) -> Result<Database, IteError> {
+    let db = match key {
+        Some(k) => Database::open_encrypted(path, k)?,
+        None => Database::new(path)?,
+    };
+    db.initialize()?;
+    Ok(db)
+}
+
+impl Database {
+    /// 암호화된 DB 열기 (키가 틀리면 에러)
+    pub fn open_encrypted(path: &Path, key_hex: &str) -> Result<Self, IteError> {
+        let conn = Connection::open(path)?;
+        // 키는 다른 어떤 쿼리보다 먼저 설정해야 합니다.
+        conn.execute_batch(&format!("PRAGMA key = \"{}\";", raw_key_literal(Some(key_hex))?))?;
+        conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| row.get::<_, i64>(0))
+            .map_err(|e| IteError::InvalidOperation(format!("Failed to unlock encrypted database: {}", e)))?;
+        Self::configure(conn, Some(key_hex.to_string()))
+    }
+
+    /// SQLCipher가 포함된 빌드인지
+    pub fn sqlcipher_available(&self) -> bool {
+        self.conn
+            .query_row("PRAGMA cipher_version", [], |row| row.get::<_, String>(0))
+            .is_ok()
+    }
+
+    /// 현재 DB가 암호화되어 있는지
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption_key.is_some()
+    }
+
+    /// 현재 DB 파일 경로 (메모리 DB면 None)
+    fn file_path(&self) -> Option<PathBuf> {
+        self.conn.path().filter(|p| !p.is_empty()).map(PathBuf::from)
+    }
+
+    fn require_sqlcipher(&self) -> Result<PathBuf, IteError> {
+        if !self.sqlcipher_available() {
+            return Err(IteError::InvalidOperation(
+                "This build does not include SQLCipher".to_string(),
+            ));
+        }
+        self.file_path()
+            .ok_or_else(|| IteError::InvalidOperation("In-memory database cannot be encrypted".to_string()))
+    }
+
+    /// 평문 사본 내보내기 (암호화 DB의 .ite 내보내기/백업용)
+    pub(super) fn export_plaintext_copy(&self, out_path: &Path) -> Result<(), IteError> {
+        self.require_sqlcipher()?;
+        export_copy(&self.conn, out_path, None)
+    }
+
+    /// 준비된 새 파일로 현재 DB 파일 교체 후 다시 열기
+    /// - 새 파일을 열 때까지 기존 파일은 `.previous`로 남겨 두고, 교체나 열기에 실패하면
+    ///   기존 파일을 되돌려 기존 키로 다시 엽니다 (메모리 DB에 머물러 이후 저장을 잃지 않도록).
+    fn replace_database_file(&mut self, path: &Path, new_file: &Path, key: Option<&str>) -> Result<(), IteError> {
+        let current_key = self.encryption_key.clone();
+        let backup = PathBuf::from(format!("{}.previous", path.display()));
+
+        // 기존 연결을 닫아야(WAL checkpoint) 파일을 교체할 수 있습니다.
+        let old = std::mem::replace(self, Database::new(Path::new(":memory:"))?);
+        drop(old);
+
+        let swapped = std::fs::rename(path, &backup).and_then(|_| {
+            std::fs::rename(new_file, path).inspect_err(|_| {
+                let _ = std::fs::rename(&backup, path);
+            })
+        });
+        let error = match swapped {
+            Ok(()) => {
+                for suffix in ["-wal", "-shm"] {
+                    let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+                }
+                match open_database_file(path, key) {
+                    Ok(db) => {
+                        *self = db;
+                        let _ = std::fs::remove_file(&backup);
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        let _ = std::fs::remove_file(path);
+                        let _ = std::fs::rename(&backup, path);
+                        e
+                    }
+                }
+            }
+            Err(e) => {
+                let _ = std::fs::remove_file(new_file);
+                e.into()
+            }
+        };
+
+        *self = open_database_file(path, current_key.as_deref())?;
+        Err(error)
+    }
+
+    /// 암호화 전환 (평문 → 암호화, 암호화 → 평문, 키 교체)
+    /// - new_key가 None이면 평문으로 되돌립니다.
+    /// - 새 파일을 완성한 뒤 교체하므로, 실패해도 기존 DB는 그대로 남습니다.
+    pub fn change_encryption(&mut self, new_key: Option<&str>) -> Result<(), IteError> {
+        let path = self.require_sqlcipher()?;
+        let tmp_path = PathBuf::from(format!("{}.migrating", path.display()));
+
+        export_copy(&self.conn, &tmp_path, new_key)?;
+        self.replace_database_file(&path, &tmp_path, new_key)
+    }
+
+    /// 평문 .ite 파일을 현재 키로 암호화해 현재 DB로 교체 (암호화 모드의 import)
+    pub(super) fn import_plaintext_file(&mut self, in_path: &Path) -> Result<(), IteError> {
+        let path = self.require_sqlcipher()?;
+        let tmp_path = PathBuf::from(format!("{}.importing", path.display()));
+        let key = self.encryption_key.clone();
+
+        let in_conn = Connection::open(in_path)?;
+        export_copy(&in_conn, &tmp_path, key.as_deref())?;
+        drop(in_conn);
+        self.replace_database_file(&path, &tmp_path, key.as_deref())
+    }
+}
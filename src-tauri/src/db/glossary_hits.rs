@@ -0,0 +1,80 @@
+//! Block Glossary Hits
+//!
+//! 원문 블록별 용어집 매칭 결과를 미리 계산해 보관합니다 (키 입력마다 `search_glossary`를 돌리지 않도록).
+//! - 블록 내용이 캐시 당시와 다르면 다시 계산합니다.
+//! - 용어집(`glossary_entries`)이 바뀌면 update hook이 캐시 전체를 비웁니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::block_cache::BLOCK_CACHE_CAPACITY;
+use super::Database;
+use crate::error::IteError;
+use crate::text::glossary::{GlossaryHit, GlossaryMatcher};
+use crate::text::strip_html;
+
+struct CachedHits {
+    /// 계산 당시 블록 콘텐츠
+    content: String,
+    hits: Vec<GlossaryHit>,
+}
+
+/// 프로젝트별 컴파일된 용어집 + 블록별 매칭 결과
+#[derive(Default)]
+pub struct GlossaryHitCache {
+    matchers: HashMap<String, Arc<GlossaryMatcher>>,
+    /// (project_id, block_id) → 매칭 결과
+    hits: HashMap<(String, String), CachedHits>,
+}
+
+impl GlossaryHitCache {
+    pub fn clear(&mut self) {
+        self.matchers.clear();
+        self.hits.clear();
+    }
+}
+
+impl Database {
+    /// 블록의 용어집 매칭 구간 (캐시 우선)
+    pub fn block_glossary_hits(&self, project_id: &str, block_id: &str) -> Result<Vec<GlossaryHit>, IteError> {
+        let block = self.get_block(block_id, project_id)?;
+        let key = (project_id.to_string(), block_id.to_string());
+
+        // 캐시 잠금 중에는 쿼리하지 않음 (update hook도 같은 잠금을 씀)
+        let matcher = {
+            let cache = self
+                .glossary_hits
+                .lock()
+                .map_err(|e| IteError::InvalidOperation(format!("Glossary cache lock poisoned: {}", e)))?;
+            if let Some(cached) = cache.hits.get(&key).filter(|c| c.content == block.content) {
+                return Ok(cached.hits.clone());
+            }
+            cache.matchers.get(project_id).cloned()
+        };
+        let matcher = match matcher {
+            Some(matcher) => matcher,
+            None => Arc::new(GlossaryMatcher::new(&self.list_glossary_entries(project_id)?)),
+        };
+
+        let hits = if matcher.is_empty() {
+            Vec::new()
+        } else {
+            matcher.find_all(&strip_html(&block.content))
+        };
+
+        if let Ok(mut cache) = self.glossary_hits.lock() {
+            cache.matchers.entry(project_id.to_string()).or_insert(matcher);
+            if cache.hits.len() >= BLOCK_CACHE_CAPACITY {
+                cache.hits.clear();
+            }
+            cache.hits.insert(
+                key,
+                CachedHits {
+                    content: block.content,
+                    hits: hits.clone(),
+                },
+            );
+        }
+        Ok(hits)
+    }
+}
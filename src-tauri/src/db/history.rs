@@ -0,0 +1,504 @@
+//! History Snapshot Storage
+//!
+//! 프로젝트 버전 스냅샷 저장/조회
+//! - 스냅샷마다 그 시점의 프로젝트 상태 전체(`SnapshotState`)를 보관해 복원/내보내기에 씁니다.
+//! - `block_changes`는 직전 스냅샷 대비 바뀐 블록입니다 (첫 스냅샷이면 모든 블록이 create).
+//! - 복원은 블록/세그먼트만 되돌리고, 같은 트랜잭션에서 현재 상태를 자동 스냅샷으로 남깁니다.
+//! - 파일에서 가져온 스냅샷(`imported`)은 변경 비교 기준으로 쓰지 않습니다.
+
+use std::collections::HashMap;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::{write_project, Database};
+use crate::error::IteError;
+use crate::models::{BlockChange, EditorBlock, HistorySnapshot, IteProject, SegmentGroup, SnapshotState};
+use crate::text::strip_html;
+
+/// 저장된 스냅샷 (상태 포함)
+#[derive(Debug, Clone)]
+pub struct StoredSnapshot {
+    pub project_id: String,
+    pub snapshot: HistorySnapshot,
+    /// 상태 보관 이전에 만든 스냅샷이면 None
+    pub state: Option<SnapshotState>,
+}
+
+/// 복원 시 바뀌는 블록 1건
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBlockChange {
+    pub block_id: String,
+    pub block_type: String,
+    /// "create"(복원하면 다시 생김) | "update" | "delete"(복원하면 사라짐)
+    pub change_type: String,
+    /// 현재 텍스트 길이 (문자 수, 태그 제외)
+    pub current_chars: usize,
+    /// 복원 후 텍스트 길이
+    pub restored_chars: usize,
+}
+
+/// 스냅샷 복원 미리보기 (아무것도 바꾸지 않음)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestorePreview {
+    pub snapshot_id: String,
+    pub snapshot_timestamp: i64,
+    pub description: String,
+    pub created: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    pub current_segments: usize,
+    pub restored_segments: usize,
+    /// 문서 순서
+    pub changes: Vec<RestoreBlockChange>,
+}
+
+/// 스냅샷 복원 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreOutcome {
+    /// 복원 직전 상태를 담은 자동 스냅샷 (복원을 되돌릴 때 사용)
+    pub pre_restore_snapshot: HistorySnapshot,
+    pub project: IteProject,
+}
+
+fn text_chars(block: Option<&EditorBlock>) -> usize {
+    block.map(|b| strip_html(&b.content).chars().count()).unwrap_or(0)
+}
+
+/// 블록 ID → 문서 위치 (세그먼트 순서, 원문 → 번역문)
+fn block_positions(segments: &[SegmentGroup]) -> HashMap<&str, usize> {
+    let mut ordered: Vec<&SegmentGroup> = segments.iter().collect();
+    ordered.sort_by_key(|s| s.order);
+    let mut positions = HashMap::new();
+    for id in ordered.iter().flat_map(|s| s.source_ids.iter().chain(&s.target_ids)) {
+        let next = positions.len();
+        positions.entry(id.as_str()).or_insert(next);
+    }
+    positions
+}
+
+/// 두 상태 사이의 블록 변경 (문서 순서, 삭제된 블록은 이전 상태의 위치 기준으로 뒤에)
+pub(crate) fn diff_block_states(
+    previous: &HashMap<String, EditorBlock>,
+    previous_segments: &[SegmentGroup],
+    current: &HashMap<String, EditorBlock>,
+    current_segments: &[SegmentGroup],
+) -> Vec<BlockChange> {
+    let current_positions = block_positions(current_segments);
+    let previous_positions = block_positions(previous_segments);
+
+    let mut changes: Vec<((usize, usize), BlockChange)> = Vec::new();
+    for (id, block) in current {
+        let position = (0, current_positions.get(id.as_str()).copied().unwrap_or(usize::MAX));
+        match previous.get(id) {
+            None => changes.push((
+                position,
+                BlockChange {
+                    block_id: id.clone(),
+                    previous_content: String::new(),
+                    new_content: block.content.clone(),
+                    change_type: "create".to_string(),
+                },
+            )),
+            Some(old) if old.content != block.content => changes.push((
+                position,
+                BlockChange {
+                    block_id: id.clone(),
+                    previous_content: old.content.clone(),
+                    new_content: block.content.clone(),
+                    change_type: "update".to_string(),
+                },
+            )),
+            Some(_) => {}
+        }
+    }
+    for (id, old) in previous {
+        if current.contains_key(id) {
+            continue;
+        }
+        changes.push((
+            (1, previous_positions.get(id.as_str()).copied().unwrap_or(usize::MAX)),
+            BlockChange {
+                block_id: id.clone(),
+                previous_content: old.content.clone(),
+                new_content: String::new(),
+                change_type: "delete".to_string(),
+            },
+        ));
+    }
+
+    changes.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.block_id.cmp(&b.1.block_id)));
+    changes.into_iter().map(|(_, change)| change).collect()
+}
+
+fn row_to_stored(row: &rusqlite::Row) -> rusqlite::Result<(String, HistorySnapshot, Option<String>)> {
+    let changes_json: String = row.get(4)?;
+    Ok((
+        row.get(1)?,
+        HistorySnapshot {
+            id: row.get(0)?,
+            timestamp: row.get(2)?,
+            description: row.get(3)?,
+            block_changes: serde_json::from_str(&changes_json).unwrap_or_default(),
+            chat_summary: row.get(5)?,
+        },
+        row.get(6)?,
+    ))
+}
+
+/// 스냅샷 1건 저장 + 변경 피드 기록 (호출자의 트랜잭션 안에서 실행)
+fn write_snapshot(
+    conn: &Connection,
+    project_id: &str,
+    snapshot: &HistorySnapshot,
+    state: Option<&SnapshotState>,
+    imported: bool,
+) -> Result<(), IteError> {
+    let changes_json = serde_json::to_string(&snapshot.block_changes)?;
+    let state_json = state.map(serde_json::to_string).transpose()?;
+    conn.execute(
+        "INSERT INTO history (id, project_id, timestamp, description, changes_json, chat_summary, state_json, imported)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        (
+            &snapshot.id,
+            project_id,
+            snapshot.timestamp,
+            &snapshot.description,
+            &changes_json,
+            &snapshot.chat_summary,
+            &state_json,
+            imported,
+        ),
+    )?;
+    let payload = serde_json::to_string(snapshot)?;
+    record_change(
+        conn,
+        &Change::new("history_snapshot", &snapshot.id, ChangeOp::Insert)
+            .project(project_id)
+            .payload(&payload),
+    )?;
+    Ok(())
+}
+
+/// 스냅샷의 블록/세그먼트 ID를 새로 발급 (세그먼트 참조와 변경 기록도 같이 바꿈)
+fn remap_snapshot_ids(snapshot: &mut HistorySnapshot, state: &mut SnapshotState) {
+    let mut ids: HashMap<String, String> = HashMap::new();
+    let mut remap = |id: &str| -> String {
+        ids.entry(id.to_string())
+            .or_insert_with(|| uuid::Uuid::new_v4().to_string())
+            .clone()
+    };
+
+    state.blocks = std::mem::take(&mut state.blocks)
+        .into_values()
+        .map(|mut block| {
+            block.id = remap(&block.id);
+            (block.id.clone(), block)
+        })
+        .collect();
+    for segment in &mut state.segments {
+        segment.group_id = uuid::Uuid::new_v4().to_string();
+        for id in segment.source_ids.iter_mut().chain(segment.target_ids.iter_mut()) {
+            *id = remap(id);
+        }
+    }
+    for change in &mut snapshot.block_changes {
+        change.block_id = remap(&change.block_id);
+    }
+}
+
+impl Database {
+    /// 프로젝트 현재 상태로 스냅샷 생성
+    pub fn create_history_snapshot(
+        &self,
+        project_id: &str,
+        description: &str,
+        chat_summary: Option<&str>,
+    ) -> Result<HistorySnapshot, IteError> {
+        let project = self.load_project(project_id)?;
+        let (snapshot, state) = self.build_snapshot(project, description, chat_summary)?;
+        self.insert_history_snapshot(project_id, &snapshot, Some(&state))?;
+        Ok(snapshot)
+    }
+
+    /// 프로젝트 상태로 스냅샷 구성 (직전 스냅샷 대비 변경 포함, 저장하지 않음)
+    fn build_snapshot(
+        &self,
+        project: IteProject,
+        description: &str,
+        chat_summary: Option<&str>,
+    ) -> Result<(HistorySnapshot, SnapshotState), IteError> {
+        let previous = self.latest_snapshot_state(&project.id)?;
+        let block_changes = match &previous {
+            Some(prev) => diff_block_states(&prev.blocks, &prev.segments, &project.blocks, &project.segments),
+            None => diff_block_states(&HashMap::new(), &[], &project.blocks, &project.segments),
+        };
+
+        let snapshot = HistorySnapshot {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            description: description.to_string(),
+            block_changes,
+            chat_summary: chat_summary.map(str::to_string),
+        };
+        let state = SnapshotState {
+            metadata: project.metadata,
+            segments: project.segments,
+            blocks: project.blocks,
+        };
+        Ok((snapshot, state))
+    }
+
+    /// 스냅샷 저장
+    pub fn insert_history_snapshot(
+        &self,
+        project_id: &str,
+        snapshot: &HistorySnapshot,
+        state: Option<&SnapshotState>,
+    ) -> Result<(), IteError> {
+        self.ensure_project_exists(project_id)?;
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        write_snapshot(&tx, project_id, snapshot, state, false)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 내보낸 스냅샷 가져오기 (새 스냅샷 ID로 히스토리에 추가)
+    /// - 다른 프로젝트의 스냅샷이면 블록/세그먼트 ID를 새로 발급합니다. (복원 시 원래 프로젝트 블록과 충돌 방지)
+    /// - 가져온 스냅샷은 다음 스냅샷의 변경 비교 기준으로 쓰지 않습니다.
+    pub fn import_history_snapshot(
+        &self,
+        project_id: &str,
+        source_project_id: &str,
+        mut snapshot: HistorySnapshot,
+        mut state: SnapshotState,
+    ) -> Result<HistorySnapshot, IteError> {
+        self.ensure_project_exists(project_id)?;
+        self.ensure_project_writable(project_id)?;
+        snapshot.id = uuid::Uuid::new_v4().to_string();
+        if source_project_id != project_id {
+            remap_snapshot_ids(&mut snapshot, &mut state);
+        }
+        let tx = self.conn.unchecked_transaction()?;
+        write_snapshot(&tx, project_id, &snapshot, Some(&state), true)?;
+        tx.commit()?;
+        Ok(snapshot)
+    }
+
+    fn ensure_project_exists(&self, project_id: &str) -> Result<(), IteError> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?1)",
+            [project_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// 프로젝트 스냅샷 목록 (최신 순, 상태 제외)
+    pub fn list_history_snapshots(&self, project_id: &str) -> Result<Vec<HistorySnapshot>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, project_id, timestamp, description, changes_json, chat_summary, NULL
+             FROM history WHERE project_id = ?1
+             ORDER BY timestamp DESC",
+        )?;
+        let iter = stmt.query_map([project_id], row_to_stored)?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?.1);
+        }
+        Ok(out)
+    }
+
+    /// 스냅샷 단건 조회 (상태 포함)
+    pub fn load_history_snapshot(&self, snapshot_id: &str) -> Result<Option<StoredSnapshot>, IteError> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT id, project_id, timestamp, description, changes_json, chat_summary, state_json
+                 FROM history WHERE id = ?1",
+                [snapshot_id],
+                row_to_stored,
+            )
+            .optional()?;
+        let Some((project_id, snapshot, state_json)) = row else {
+            return Ok(None);
+        };
+        let state = state_json.map(|json| serde_json::from_str(&json)).transpose()?;
+        Ok(Some(StoredSnapshot {
+            project_id,
+            snapshot,
+            state,
+        }))
+    }
+
+    /// 스냅샷 상태로 복원했을 때 바뀌는 블록 (dry-run)
+    pub fn preview_history_restore(
+        &self,
+        project_id: &str,
+        snapshot: &HistorySnapshot,
+        state: &SnapshotState,
+    ) -> Result<RestorePreview, IteError> {
+        let project = self.load_project(project_id)?;
+        let diff = diff_block_states(&project.blocks, &project.segments, &state.blocks, &state.segments);
+
+        let mut preview = RestorePreview {
+            snapshot_id: snapshot.id.clone(),
+            snapshot_timestamp: snapshot.timestamp,
+            description: snapshot.description.clone(),
+            created: 0,
+            updated: 0,
+            deleted: 0,
+            current_segments: project.segments.len(),
+            restored_segments: state.segments.len(),
+            changes: Vec::with_capacity(diff.len()),
+        };
+        for change in diff {
+            match change.change_type.as_str() {
+                "create" => preview.created += 1,
+                "update" => preview.updated += 1,
+                _ => preview.deleted += 1,
+            }
+            let current = project.blocks.get(&change.block_id);
+            let restored = state.blocks.get(&change.block_id);
+            preview.changes.push(RestoreBlockChange {
+                block_type: restored.or(current).map(|b| b.block_type.clone()).unwrap_or_default(),
+                current_chars: text_chars(current),
+                restored_chars: text_chars(restored),
+                block_id: change.block_id,
+                change_type: change.change_type,
+            });
+        }
+        Ok(preview)
+    }
+
+    /// 스냅샷 상태로 블록/세그먼트 복원
+    /// - 복원 전에 현재 상태를 자동 스냅샷으로 남깁니다.
+    /// - 메타데이터(제목/설정 등)는 현재 값을 유지합니다.
+    pub fn restore_history_snapshot(
+        &self,
+        project_id: &str,
+        snapshot: &HistorySnapshot,
+        state: &SnapshotState,
+    ) -> Result<RestoreOutcome, IteError> {
+        // 읽기 전용이면 복원 전 스냅샷도 남기지 않음
+        self.ensure_project_writable(project_id)?;
+        let author = self.local_author()?;
+        let current = self.load_project(project_id)?;
+        let mut project = current.clone();
+        let (pre_restore_snapshot, pre_restore_state) =
+            self.build_snapshot(current, &format!("Before restoring: {}", snapshot.description), None)?;
+
+        project.segments = state.segments.clone();
+        project.blocks = state.blocks.clone();
+        project.metadata.updated_at = chrono::Utc::now().timestamp_millis();
+
+        // 복원이 실패하면 복원 전 스냅샷도 남지 않도록 한 트랜잭션으로 처리
+        let tx = self.conn.unchecked_transaction()?;
+        write_snapshot(&tx, project_id, &pre_restore_snapshot, Some(&pre_restore_state), false)?;
+        write_project(&tx, &project, author.as_deref())?;
+        tx.commit()?;
+
+        Ok(RestoreOutcome {
+            pre_restore_snapshot,
+            project: self.load_project(project_id)?,
+        })
+    }
+
+    /// 가장 최근 스냅샷의 상태 (상태가 있고 가져오지 않은 스냅샷 중)
+    fn latest_snapshot_state(&self, project_id: &str) -> Result<Option<SnapshotState>, IteError> {
+        let state_json: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT state_json FROM history
+                 WHERE project_id = ?1 AND state_json IS NOT NULL AND imported = 0
+                 ORDER BY timestamp DESC LIMIT 1",
+                [project_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(state_json.map(|json| serde_json::from_str(&json)).transpose()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::path::Path;
+
+    fn project(id: &str, target: &str) -> IteProject {
+        let block = |block_id: String, block_type: &str, content: &str| {
+            json!({
+                "id": block_id, "type": block_type, "content": content, "hash": "",
+                "metadata": { "author": null, "createdAt": 0, "updatedAt": 0, "tags": [], "comments": null }
+            })
+        };
+        serde_json::from_value(json!({
+            "id": id,
+            "version": "1.0.0",
+            "metadata": {
+                "title": id, "description": null, "domain": "general", "targetLanguage": "한국어",
+                "createdAt": 0, "updatedAt": 0, "author": null, "glossaryPaths": null,
+                "settings": { "strictnessLevel": 0.5, "autoSave": true, "autoSaveInterval": 30000, "theme": "system" }
+            },
+            "segments": [{ "groupId": format!("{id}-g"), "sourceIds": [format!("{id}-s")], "targetIds": [format!("{id}-t")], "isAligned": true, "order": 0 }],
+            "blocks": {
+                format!("{id}-s"): block(format!("{id}-s"), "source", "<p>Hello</p>"),
+                format!("{id}-t"): block(format!("{id}-t"), "target", target),
+            },
+            "history": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn imported_snapshot_from_other_project_restores_with_new_ids() {
+        let db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db.save_project(&project("a", "<p>안녕</p>")).unwrap();
+        db.save_project(&project("b", "<p>반가워</p>")).unwrap();
+        let own = db.create_history_snapshot("b", "own", None).unwrap();
+
+        let exported = db.create_history_snapshot("a", "v1", None).unwrap();
+        let state = db.load_history_snapshot(&exported.id).unwrap().unwrap().state.unwrap();
+        let imported = db.import_history_snapshot("b", "a", exported, state).unwrap();
+        let stored = db.load_history_snapshot(&imported.id).unwrap().unwrap();
+        let state = stored.state.unwrap();
+        assert!(state.blocks.keys().all(|id| !id.starts_with("a-")));
+        assert!(imported.block_changes.iter().all(|c| state.blocks.contains_key(&c.block_id)));
+
+        // 가져온 스냅샷은 변경 비교 기준이 아님
+        let next = db.create_history_snapshot("b", "next", None).unwrap();
+        assert!(next.block_changes.is_empty());
+
+        let outcome = db.restore_history_snapshot("b", &imported, &state).unwrap();
+        assert_eq!(outcome.project.blocks.len(), 2);
+        assert!(outcome.project.blocks.values().any(|b| b.content == "<p>안녕</p>"));
+        assert_eq!(db.load_project("a").unwrap().blocks["a-t"].content, "<p>안녕</p>");
+        assert_ne!(own.id, outcome.pre_restore_snapshot.id);
+    }
+
+    #[test]
+    fn failed_restore_leaves_no_pre_restore_snapshot() {
+        let db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db.save_project(&project("a", "<p>안녕</p>")).unwrap();
+        db.save_project(&project("b", "<p>반가워</p>")).unwrap();
+        let snapshot = db.create_history_snapshot("a", "v1", None).unwrap();
+        let state = db.load_history_snapshot(&snapshot.id).unwrap().unwrap().state.unwrap();
+
+        // ID를 바꾸지 않은 다른 프로젝트 상태 → blocks.id 충돌
+        assert!(db.restore_history_snapshot("b", &snapshot, &state).is_err());
+        assert!(db.list_history_snapshots("b").unwrap().is_empty());
+        assert_eq!(db.load_project("b").unwrap().blocks["b-t"].content, "<p>반가워</p>");
+    }
+}
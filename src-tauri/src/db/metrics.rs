@@ -0,0 +1,188 @@
+//! Local Usage Metrics
+//!
+//! 명령 응답 시간/오류율/기능 사용 횟수를 하루 단위 합계로 보관합니다.
+//! - 설정(`usageMetrics`)을 켠 경우에만 기록하며, 어디로도 보내지 않습니다 (보고서를 직접 내보낼 때만 밖으로 나감).
+//! - "느리다"는 제보를 숫자로 확인하기 위한 용도라 개별 호출 기록/인자는 남기지 않습니다.
+
+use serde::{Deserialize, Serialize};
+
+use super::Database;
+use crate::error::IteError;
+
+/// 보관 기간 (일, 지난 합계는 기록할 때 정리)
+pub const METRICS_RETENTION_DAYS: u32 = 30;
+
+/// 이보다 오래 걸린 명령은 느린 호출로 셈
+const SLOW_COMMAND_MS: f64 = 1_000.0;
+
+/// 지표 이름 최대 길이
+const MAX_METRIC_NAME_LEN: usize = 100;
+
+/// 기록할 지표 1건 (프론트엔드에서 모아 보냄)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSample {
+    /// "command" | "feature"
+    pub kind: String,
+    /// 명령 이름 또는 기능 이름
+    pub name: String,
+    /// 명령 응답 시간 (기능 사용이면 None)
+    pub duration_ms: Option<f64>,
+    /// 실패한 명령의 오류 코드
+    pub error_code: Option<String>,
+}
+
+/// 명령별 합계
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandMetric {
+    pub name: String,
+    pub count: u64,
+    pub error_count: u64,
+    /// 오류율 (0~100)
+    pub error_percent: f64,
+    pub slow_count: u64,
+    pub avg_ms: f64,
+    pub max_ms: f64,
+    pub last_error_code: Option<String>,
+}
+
+/// 기능별 사용 횟수
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureUsage {
+    pub name: String,
+    pub count: u64,
+}
+
+/// 사용 지표 보고서
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsReport {
+    pub enabled: bool,
+    /// 집계 시작일 (UTC, 'YYYY-MM-DD')
+    pub since_day: String,
+    pub days: u32,
+    /// 평균 응답 시간이 긴 순
+    pub commands: Vec<CommandMetric>,
+    /// 많이 쓴 순
+    pub features: Vec<FeatureUsage>,
+}
+
+fn day_offset(days_ago: u32) -> String {
+    (chrono::Utc::now() - chrono::Duration::days(days_ago as i64))
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+impl Database {
+    /// 지표를 오늘 합계에 더하고 보관 기간이 지난 합계를 정리, 기록한 건수 반환
+    /// - 형식이 맞지 않는 항목(알 수 없는 kind, 빈/긴 이름)은 건너뜁니다.
+    pub fn record_metrics(&self, samples: &[MetricSample]) -> Result<usize, IteError> {
+        let today = day_offset(0);
+        let tx = self.conn.unchecked_transaction()?;
+        let mut recorded = 0;
+        {
+            let mut upsert = tx.prepare_cached(
+                "INSERT INTO usage_metrics (day, kind, name, count, error_count, slow_count, total_ms, max_ms, last_error_code)
+                 VALUES (?1, ?2, ?3, 1, ?4, ?5, ?6, ?6, ?7)
+                 ON CONFLICT(day, kind, name) DO UPDATE SET
+                    count = count + 1,
+                    error_count = error_count + excluded.error_count,
+                    slow_count = slow_count + excluded.slow_count,
+                    total_ms = total_ms + excluded.total_ms,
+                    max_ms = MAX(max_ms, excluded.max_ms),
+                    last_error_code = COALESCE(excluded.last_error_code, last_error_code)",
+            )?;
+            for sample in samples {
+                let name = sample.name.trim();
+                if !matches!(sample.kind.as_str(), "command" | "feature")
+                    || name.is_empty()
+                    || name.len() > MAX_METRIC_NAME_LEN
+                {
+                    continue;
+                }
+                let duration = sample.duration_ms.filter(|d| d.is_finite()).unwrap_or(0.0).max(0.0);
+                upsert.execute((
+                    &today,
+                    &sample.kind,
+                    name,
+                    sample.error_code.is_some() as i64,
+                    (duration >= SLOW_COMMAND_MS) as i64,
+                    duration,
+                    sample.error_code.as_deref(),
+                ))?;
+                recorded += 1;
+            }
+        }
+        tx.execute(
+            "DELETE FROM usage_metrics WHERE day < ?1",
+            [day_offset(METRICS_RETENTION_DAYS)],
+        )?;
+        tx.commit()?;
+        Ok(recorded)
+    }
+
+    /// 최근 `days`일(오늘 포함) 합계 보고서
+    pub fn metrics_report(&self, days: u32, enabled: bool) -> Result<MetricsReport, IteError> {
+        let days = days.clamp(1, METRICS_RETENTION_DAYS);
+        let since_day = day_offset(days - 1);
+
+        let mut stmt = self.conn.prepare(
+            "SELECT name, SUM(count), SUM(error_count), SUM(slow_count), SUM(total_ms), MAX(max_ms),
+                    (SELECT m2.last_error_code FROM usage_metrics m2
+                     WHERE m2.kind = 'command' AND m2.name = m.name AND m2.day >= ?1 AND m2.last_error_code IS NOT NULL
+                     ORDER BY m2.day DESC LIMIT 1)
+             FROM usage_metrics m WHERE kind = 'command' AND day >= ?1
+             GROUP BY name",
+        )?;
+        let iter = stmt.query_map([&since_day], |row| {
+            let count: i64 = row.get(1)?;
+            let error_count: i64 = row.get(2)?;
+            let total_ms: f64 = row.get(4)?;
+            let count = count.max(0) as u64;
+            let error_count = error_count.max(0) as u64;
+            Ok(CommandMetric {
+                name: row.get(0)?,
+                count,
+                error_count,
+                error_percent: if count == 0 {
+                    0.0
+                } else {
+                    (error_count as f64 * 1000.0 / count as f64).round() / 10.0
+                },
+                slow_count: row.get::<_, i64>(3)?.max(0) as u64,
+                avg_ms: if count == 0 { 0.0 } else { total_ms / count as f64 },
+                max_ms: row.get(5)?,
+                last_error_code: row.get(6)?,
+            })
+        })?;
+        let mut commands: Vec<CommandMetric> = iter.collect::<Result<_, _>>()?;
+        commands.sort_by(|a, b| b.avg_ms.total_cmp(&a.avg_ms).then_with(|| a.name.cmp(&b.name)));
+
+        let mut stmt = self.conn.prepare(
+            "SELECT name, SUM(count) FROM usage_metrics WHERE kind = 'feature' AND day >= ?1
+             GROUP BY name ORDER BY SUM(count) DESC, name",
+        )?;
+        let iter = stmt.query_map([&since_day], |row| {
+            Ok(FeatureUsage {
+                name: row.get(0)?,
+                count: row.get::<_, i64>(1)?.max(0) as u64,
+            })
+        })?;
+        let features = iter.collect::<Result<_, _>>()?;
+
+        Ok(MetricsReport {
+            enabled,
+            since_day,
+            days,
+            commands,
+            features,
+        })
+    }
+
+    /// 기록된 지표 전체 삭제, 지운 행 수 반환
+    pub fn clear_metrics(&self) -> Result<usize, IteError> {
+        Ok(self.conn.execute("DELETE FROM usage_metrics", [])?)
+    }
+}
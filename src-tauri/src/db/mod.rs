@@ -2,16 +2,82 @@
 //!
 //! SQLite 데이터베이스 관리
 
+mod attachments;
+mod block_cache;
+mod changes;
+mod chat;
+mod citations;
+mod console;
+mod custom_fields;
+mod dnt;
+mod edit_log;
+mod glossary_hits;
+mod history;
+mod metrics;
+mod encryption;
+mod orphans;
+mod perf;
+mod project_mcp;
+mod project_templates;
+mod prompt_templates;
+mod quality;
+mod read_only;
+mod retain;
+mod repetitions;
+mod revisions;
 mod schema;
-
+mod segment_notes;
+mod segments;
+mod settings;
+mod tags;
+mod tm;
+mod tms;
+mod trash;
+mod usage;
+mod xliff;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-use rusqlite::Connection;
+use rusqlite::{Connection, OptionalExtension};
 use rusqlite::backup::Backup;
+use rusqlite::hooks::Action;
 
+use block_cache::BlockCache;
+use glossary_hits::GlossaryHitCache;
 use crate::error::IteError;
-use crate::models::{ChatSession, EditorBlock, IteProject, SegmentGroup};
+use crate::text::unicode::{into_nfc, nfc};
+use crate::models::{CapacityHint, ChatSession, EditorBlock, IteProject, ProjectMetadata, SegmentGroup};
+use changes::{record_change, Change, ChangeOp};
+use edit_log::BlockEdit;
+
+pub use attachments::{AttachmentTextSlice, MAX_ATTACHMENT_TEXT_SLICE};
+pub use changes::{ChangePage, ChangeRow, MAX_CHANGES_PAGE};
+pub use chat::{ChatMessageSearchHit, ChatPruneResult, ChatRetentionPolicy, ChatSessionSummary};
+pub use citations::{Citation, CitationInput, CitationSource, MAX_CITATIONS_PER_MESSAGE};
+pub use console::{ConsoleQueryResult, MAX_CONSOLE_ROWS};
+pub use custom_fields::CustomFieldFilter;
+pub use edit_log::{BlockHistoryEntry, BlockHistorySource, ProductivityRow};
+pub use history::{RestoreBlockChange, RestoreOutcome, RestorePreview, StoredSnapshot};
+pub use metrics::{CommandMetric, FeatureUsage, MetricSample, MetricsReport, METRICS_RETENTION_DAYS};
+pub use encryption::{generate_database_key, is_encrypted_database_file, DATABASE_KEY_SECRET};
+pub use orphans::{OrphanCleanup, RemovedAttachment};
+pub use perf::{perf_stats, reset_perf_stats, DbPerfStats, SlowQuery, StatementStats};
+pub use project_templates::ProjectTemplateSummary;
+pub use repetitions::{PropagationResult, PropagationSkip, RepetitionGroup, RepetitionMember};
+pub use schema::SCHEMA_VERSION;
+pub use segment_notes::{SegmentNote, MAX_SEGMENT_NOTE_LEN};
+pub use segments::{
+    SegmentFilter, SegmentListItem, SegmentPage, SegmentSort, SegmentSortField, SegmentStatus,
+    DEFAULT_SEGMENT_PAGE_SIZE,
+};
+pub use tags::{BlockFilter, BlockQueryHit, BlockStatus, TagCount, MAX_TAG_LEN};
+pub use tm::NewTmUnit;
+pub use tms::{TmsJobLink, TmsSegmentLink};
+pub use trash::TrashedProjectRow;
+pub use usage::ProjectStorageUsage;
+pub use xliff::{XliffDocument, XliffSegmentRef, XliffSegmentStatus};
 
 #[derive(Debug, Clone)]
 pub struct GlossaryEntryRow {
@@ -25,11 +91,42 @@ pub struct GlossaryEntryRow {
     pub updated_at: i64,
 }
 
+/// 용어 변경 피드 payload
+fn glossary_payload(
+    source: &str,
+    target: &str,
+    notes: Option<&str>,
+    domain: Option<&str>,
+    case_sensitive: bool,
+) -> String {
+    serde_json::json!([source, target, notes, domain, case_sensitive]).to_string()
+}
+
+/// 추가할 프로젝트 용어 (id/시각은 DB에서 정함)
+#[derive(Debug, Clone)]
+pub struct NewGlossaryTerm {
+    pub source: String,
+    pub target: String,
+    pub notes: Option<String>,
+    pub domain: Option<String>,
+    pub case_sensitive: bool,
+    /// 가져온 원본 행 식별자 (예: Notion 페이지 id)
+    /// - 있으면 source/target 대신 이 값으로 id를 정해, 원본에서 번역을 고쳐도 같은 항목이 갱신됨
+    pub origin_key: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct RecentProjectRow {
     pub id: String,
     pub title: String,
+    pub domain: String,
+    pub target_language: Option<String>,
     pub updated_at: i64,
+    pub pinned: bool,
+    pub archived: bool,
+    pub custom_fields: BTreeMap<String, String>,
+    pub segment_count: usize,
+    pub translated_segments: usize,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -43,25 +140,328 @@ pub struct McpServerRow {
     pub updated_at: i64,
 }
 
+/// DB 파일 이름 (app_data_dir 기준)
+pub const DATABASE_FILE_NAME: &str = "ite.db";
+
+/// prepared statement 캐시 크기 (`prepare_cached`로 재사용하는 문장 수)
+const PREPARED_STATEMENT_CACHE_CAPACITY: usize = 64;
+
 /// 데이터베이스 상태 (Tauri 앱 상태로 관리)
 pub struct DbState(pub Mutex<Database>);
 
 /// 데이터베이스 래퍼
 pub struct Database {
     conn: Connection,
+    /// SQLCipher 키 (hex, 평문 DB면 None)
+    encryption_key: Option<String>,
+    /// 블록 조회 캐시 (update hook과 공유)
+    block_cache: Arc<Mutex<BlockCache>>,
+    /// 블록별 용어집 매칭 캐시 (update hook과 공유)
+    glossary_hits: Arc<Mutex<GlossaryHitCache>>,
+    /// 읽기 전용으로 연 프로젝트 ID (앱 실행 중에만 유지)
+    read_only_projects: Mutex<HashSet<String>>,
+}
+
+/// 블록 콘텐츠 교체 + 편집 기록 (호출자의 트랜잭션 안에서 실행)
+/// - 존재하지 않는 블록은 건너뛰며, 실제로 갱신된 블록 수를 반환합니다.
+fn write_block_contents(
+    conn: &Connection,
+    project_id: &str,
+    updates: &[(String, String)],
+    author: Option<&str>,
+) -> Result<usize, IteError> {
+    let now = chrono::Utc::now().timestamp_millis();
+    let mut updated = 0;
+    for (block_id, content) in updates {
+        let current: Option<(String, String)> = conn
+            .prepare_cached("SELECT block_type, content FROM blocks WHERE id = ?1 AND project_id = ?2")?
+            .query_row(
+                [block_id, project_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((block_type, before)) = current else {
+            continue;
+        };
+        edit_log::record_block_edit(
+            conn,
+            project_id,
+            &BlockEdit {
+                block_id,
+                block_type: &block_type,
+                author,
+                before: &before,
+                after: content,
+            },
+            now,
+        )?;
+        let metadata_json: Option<String> = conn
+            .prepare_cached(
+                "UPDATE blocks SET content = ?1, hash = ?2,
+                    metadata_json = json_set(metadata_json, '$.updatedAt', ?3,
+                        '$.author', COALESCE(?6, json_extract(metadata_json, '$.author')))
+                 WHERE id = ?4 AND project_id = ?5
+                 RETURNING metadata_json",
+            )?
+            .query_row(
+                (content, crate::text::content_hash(content), now, block_id, project_id, author),
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(metadata_json) = metadata_json else {
+            continue;
+        };
+        updated += 1;
+        record_change(
+            conn,
+            &Change::new("block", block_id, ChangeOp::Update)
+                .project(project_id)
+                .payload(&changes::block_payload(content, &metadata_json))
+                .actor(author),
+        )?;
+    }
+    Ok(updated)
+}
+
+/// 프로젝트와 연관 데이터 삭제 (호출자가 트랜잭션을 엶)
+/// - foreign_keys=ON이면 CASCADE로도 처리되지만, 환경 차이를 고려해 명시적으로 정리합니다.
+fn delete_project_rows(conn: &Connection, project_id: &str) -> Result<(), IteError> {
+    // chat_messages -> chat_sessions 순으로 제거(세션 FK)
+    conn.execute(
+        "DELETE FROM chat_messages WHERE session_id IN (SELECT id FROM chat_sessions WHERE project_id = ?1)",
+        [project_id],
+    )?;
+    conn.execute("DELETE FROM chat_sessions WHERE project_id = ?1", [project_id])?;
+    conn.execute(
+        "DELETE FROM chat_project_settings WHERE project_id = ?1",
+        [project_id],
+    )?;
+    conn.execute(
+        "DELETE FROM chat_retention_policies WHERE project_id = ?1",
+        [project_id],
+    )?;
+
+    conn.execute("DELETE FROM history WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM block_edits WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM revisions WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM glossary_entries WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM prompt_templates WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM dnt_terms WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM tm_units WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM xliff_segments WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM xliff_documents WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM tms_segments WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM tms_jobs WHERE project_id = ?1", [project_id])?;
+    conn.execute(
+        "DELETE FROM segment_propagation_opt_outs WHERE project_id = ?1",
+        [project_id],
+    )?;
+    conn.execute(
+        "DELETE FROM project_custom_fields WHERE project_id = ?1",
+        [project_id],
+    )?;
+    conn.execute("DELETE FROM project_mcp_servers WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM segment_quality WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM segment_notes WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM segments WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM blocks WHERE project_id = ?1", [project_id])?;
+    conn.execute("DELETE FROM projects WHERE id = ?1", [project_id])?;
+    // 변경 피드는 추가 전용이라 삭제하지 않음
+    record_change(conn, &Change::new("project", project_id, ChangeOp::Delete).project(project_id))?;
+    Ok(())
+}
+
+/// 프로젝트 메타데이터/블록/세그먼트 전체 교체 (호출자의 트랜잭션 안에서 실행)
+/// - 내용이 바뀐 블록 ID(새 블록, 지운 블록 포함)를 반환합니다.
+fn write_project(conn: &Connection, project: &IteProject, author: Option<&str>) -> Result<Vec<String>, IteError> {
+    // 프로젝트 메타데이터 저장
+    // INSERT OR REPLACE는 row를 삭제후 재생성하므로, CASCADE DELETE가 설정된 자식 테이블(chat_project_settings 등)이
+    // 의도치 않게 삭제될 수 있습니다. 이를 방지하기 위해 UPSERT를 사용합니다.
+    conn.execute(
+        "INSERT INTO projects (id, version, metadata_json, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(id) DO UPDATE SET
+            version = excluded.version,
+            metadata_json = excluded.metadata_json,
+            updated_at = excluded.updated_at",
+        (
+            &project.id,
+            &project.version,
+            serde_json::to_string(&project.metadata)?,
+            project.metadata.created_at,
+            project.metadata.updated_at,
+        ),
+    )?;
+
+    // 편집 기록/변경 피드용: 덮어쓰기 전 블록 콘텐츠
+    let previous = edit_log::load_block_contents(conn, &project.id)?;
+    let mut previous_hashes = changes::load_block_payload_hashes(conn, &project.id)?;
+    let now = chrono::Utc::now().timestamp_millis();
+
+    // 기존 데이터 삭제
+    conn.execute("DELETE FROM blocks WHERE project_id = ?1", [&project.id])?;
+    conn.execute("DELETE FROM segments WHERE project_id = ?1", [&project.id])?;
+
+    let mut changed_blocks: Vec<String> = Vec::new();
+
+    // 블록 저장
+    for (_, block) in &project.blocks {
+        if previous.get(&block.id) != Some(&block.content) {
+            changed_blocks.push(block.id.clone());
+        }
+        // 내용이 바뀐 블록은 로컬 사용자가 편집한 것으로 기록 (가져온 새 블록은 원래 작성자 유지)
+        let mut metadata = block.metadata.clone();
+        match previous.get(&block.id) {
+            Some(before) if *before != block.content => metadata.stamp(author, now),
+            None if metadata.author.is_none() => metadata.author = author.map(str::to_string),
+            _ => {}
+        }
+        edit_log::record_block_edit(
+            conn,
+            &project.id,
+            &BlockEdit {
+                block_id: &block.id,
+                block_type: &block.block_type,
+                author: metadata.author.as_deref(),
+                before: previous.get(&block.id).map(String::as_str).unwrap_or(""),
+                after: &block.content,
+            },
+            now,
+        )?;
+        let metadata_json = serde_json::to_string(&metadata)?;
+        conn.prepare_cached(
+            "INSERT INTO blocks (id, project_id, block_type, content, hash, metadata_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?
+        .execute((
+            &block.id,
+            &project.id,
+            &block.block_type,
+            &block.content,
+            &block.hash,
+            &metadata_json,
+        ))?;
+
+        // 내용/메타데이터가 바뀐 블록만 변경 피드에 기록
+        let payload = changes::block_payload(&block.content, &metadata_json);
+        let op = match previous_hashes.remove(&block.id) {
+            None => ChangeOp::Insert,
+            Some(hash) if hash != changes::payload_hash(&payload) => ChangeOp::Update,
+            Some(_) => continue,
+        };
+        record_change(
+            conn,
+            &Change::new("block", &block.id, op)
+                .project(&project.id)
+                .payload(&payload)
+                .actor(metadata.author.as_deref()),
+        )?;
+    }
+    for removed in previous_hashes.keys() {
+        record_change(conn, &Change::new("block", removed, ChangeOp::Delete).project(&project.id))?;
+        changed_blocks.push(removed.clone());
+    }
+    // 지워진 블록은 채팅 세션 컨텍스트에서도 제거
+    let removed: HashMap<String, Vec<String>> =
+        previous_hashes.into_keys().map(|id| (id, Vec::new())).collect();
+    chat::remap_context_blocks(conn, &project.id, &removed)?;
+
+    // 세그먼트 저장
+    for segment in &project.segments {
+        conn.prepare_cached(
+            "INSERT INTO segments (id, project_id, source_ids, target_ids, is_aligned, segment_order)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?
+        .execute((
+            &segment.group_id,
+            &project.id,
+            serde_json::to_string(&segment.source_ids)?,
+            serde_json::to_string(&segment.target_ids)?,
+            segment.is_aligned,
+            segment.order,
+        ))?;
+    }
+
+    let project_payload = format!(
+        "{}\n{}",
+        serde_json::to_string(&project.metadata)?,
+        serde_json::to_string(&project.segments)?
+    );
+    let change = Change::new("project", &project.id, ChangeOp::Upsert)
+        .project(&project.id)
+        .payload(&project_payload)
+        .actor(project.metadata.author.as_deref());
+    if !changes::is_unchanged(conn, &change)? {
+        record_change(conn, &change)?;
+    }
+
+    Ok(changed_blocks)
 }
 
 impl Database {
     /// 새 데이터베이스 연결 생성
     pub fn new(path: &Path) -> Result<Self, IteError> {
-        let conn = Connection::open(path)?;
+        Self::configure(Connection::open(path)?, None)
+    }
+
+    /// 연결 공통 설정 (평문/암호화 DB 공용)
+    fn configure(mut conn: Connection, encryption_key: Option<String>) -> Result<Self, IteError> {
         // WAL 모드: 동시 읽기/쓰기 성능 향상, 크래시 복구 개선
         conn.pragma_update(None, "journal_mode", "WAL")?;
         conn.pragma_update(None, "synchronous", "NORMAL")?;
         // SQLite는 기본적으로 foreign_keys가 OFF일 수 있어, ON DELETE CASCADE가 동작하지 않을 수 있습니다.
         // (프로젝트 삭제/정리 안정성을 위해 명시적으로 활성화)
         conn.pragma_update(None, "foreign_keys", true)?;
-        Ok(Self { conn })
+
+        // 문장별 실행 시간 집계 + 반복 쓰기 경로의 prepared statement 재사용
+        conn.profile(Some(perf::record_statement));
+        conn.set_prepared_statement_cache_capacity(PREPARED_STATEMENT_CACHE_CAPACITY);
+
+        // 블록/용어집 행이 바뀌면 캐시 무효화 (어떤 쓰기 경로든 같은 연결을 거치므로 누락이 없음)
+        let block_cache = Arc::new(Mutex::new(BlockCache::default()));
+        let glossary_hits = Arc::new(Mutex::new(GlossaryHitCache::default()));
+        let (blocks, glossary) = (Arc::clone(&block_cache), Arc::clone(&glossary_hits));
+        conn.update_hook(Some(move |_: Action, _: &str, table: &str, rowid: i64| match table {
+            "blocks" => {
+                if let Ok(mut cache) = blocks.lock() {
+                    cache.invalidate_rowid(rowid);
+                }
+            }
+            "glossary_entries" => {
+                if let Ok(mut cache) = glossary.lock() {
+                    cache.clear();
+                }
+            }
+            _ => {}
+        }));
+        // 트랜잭션 안에서 읽어 캐시한 값이 롤백으로 무효가 될 수 있음
+        let (blocks, glossary) = (Arc::clone(&block_cache), Arc::clone(&glossary_hits));
+        conn.rollback_hook(Some(move || {
+            if let Ok(mut cache) = blocks.lock() {
+                cache.clear();
+            }
+            if let Ok(mut cache) = glossary.lock() {
+                cache.clear();
+            }
+        }));
+
+        Ok(Self {
+            conn,
+            encryption_key,
+            block_cache,
+            glossary_hits,
+            read_only_projects: Mutex::new(HashSet::new()),
+        })
+    }
+
+    /// 블록/용어집 캐시 비우기 (update hook이 호출되지 않는 대량 교체 뒤)
+    pub fn invalidate_caches(&self) {
+        if let Ok(mut cache) = self.block_cache.lock() {
+            cache.clear();
+        }
+        if let Ok(mut cache) = self.glossary_hits.lock() {
+            cache.clear();
+        }
     }
 
     /// 데이터베이스 스키마 초기화
@@ -83,6 +483,102 @@ impl Database {
                 "ALTER TABLE chat_sessions ADD COLUMN confluence_search_enabled INTEGER NOT NULL DEFAULT 1;"
             )?;
         }
+
+        // projects.deleted_at 컬럼 추가 (휴지통/soft delete, 기존 DB 호환)
+        let has_deleted_at: bool = self
+            .conn
+            .prepare("SELECT deleted_at FROM projects LIMIT 0")
+            .is_ok();
+        if !has_deleted_at {
+            self.conn.execute_batch("ALTER TABLE projects ADD COLUMN deleted_at INTEGER;")?;
+        }
+
+        // projects.pinned_at 컬럼 추가 (최근 프로젝트 고정, 기존 DB 호환)
+        let has_pinned_at: bool = self
+            .conn
+            .prepare("SELECT pinned_at FROM projects LIMIT 0")
+            .is_ok();
+        if !has_pinned_at {
+            self.conn.execute_batch("ALTER TABLE projects ADD COLUMN pinned_at INTEGER;")?;
+        }
+
+        // projects.archived_at 컬럼 추가 (끝난 프로젝트 보관, 기존 DB 호환)
+        let has_archived_at: bool = self
+            .conn
+            .prepare("SELECT archived_at FROM projects LIMIT 0")
+            .is_ok();
+        if !has_archived_at {
+            self.conn.execute_batch("ALTER TABLE projects ADD COLUMN archived_at INTEGER;")?;
+        }
+
+        // history.state_json 컬럼 추가 (스냅샷 복원/내보내기, 기존 DB 호환)
+        let has_state_json: bool = self
+            .conn
+            .prepare("SELECT state_json FROM history LIMIT 0")
+            .is_ok();
+        if !has_state_json {
+            self.conn.execute_batch("ALTER TABLE history ADD COLUMN state_json TEXT;")?;
+        }
+
+        // history.imported 컬럼 추가 (가져온 스냅샷 구분, 기존 DB 호환)
+        let has_imported: bool = self
+            .conn
+            .prepare("SELECT imported FROM history LIMIT 0")
+            .is_ok();
+        if !has_imported {
+            self.conn.execute_batch("ALTER TABLE history ADD COLUMN imported INTEGER NOT NULL DEFAULT 0;")?;
+        }
+
+        // attachments.content_hash 컬럼 추가 (추출 텍스트 재사용, 기존 DB 호환)
+        let has_content_hash: bool = self
+            .conn
+            .prepare("SELECT content_hash FROM attachments LIMIT 0")
+            .is_ok();
+        if !has_content_hash {
+            self.conn.execute_batch("ALTER TABLE attachments ADD COLUMN content_hash TEXT;")?;
+        }
+        self.conn.execute_batch(
+            "CREATE INDEX IF NOT EXISTS idx_attachments_hash ON attachments(content_hash);"
+        )?;
+
+        // chat_messages 전문 검색 인덱스(FTS5) 생성 + 기존 메시지 색인
+        let has_chat_fts: bool = self
+            .conn
+            .prepare("SELECT rowid FROM chat_messages_fts LIMIT 0")
+            .is_ok();
+        if !has_chat_fts {
+            self.conn.execute_batch(schema::CREATE_CHAT_FTS)?;
+            self.conn.execute_batch(
+                "INSERT INTO chat_messages_fts(chat_messages_fts) VALUES ('rebuild');"
+            )?;
+        }
+
+        // 별도 citations 테이블(v9) → chat_message_artifacts의 kind='citation' 행으로 옮김
+        let has_citations_table: bool = self
+            .conn
+            .prepare("SELECT url FROM citations LIMIT 0")
+            .is_ok();
+        if has_citations_table {
+            let tx = self.conn.unchecked_transaction()?;
+            tx.execute_batch(
+                "INSERT INTO chat_message_artifacts (message_id, position, kind, reference, title, content, data_json)
+                 SELECT c.message_id,
+                        COALESCE((SELECT MAX(a.position) + 1 FROM chat_message_artifacts a WHERE a.message_id = c.message_id), 0)
+                            + c.position,
+                        'citation', c.url, c.title, c.snippet,
+                        json_object('source', c.source, 'toolName', c.tool_name, 'retrievedAt', c.retrieved_at)
+                 FROM citations c
+                 JOIN chat_messages m ON m.id = c.message_id
+                 WHERE NOT EXISTS (
+                     SELECT 1 FROM chat_message_artifacts a
+                     WHERE a.message_id = c.message_id AND a.kind = 'citation' AND a.reference = c.url
+                 );
+                 DROP TABLE citations;"
+            )?;
+            tx.commit()?;
+        }
+
+        self.conn.pragma_update(None, "user_version", schema::SCHEMA_VERSION)?;
         Ok(())
     }
 
@@ -92,9 +588,12 @@ impl Database {
             std::fs::create_dir_all(parent)?;
         }
 
-        // 백업 수행은 scope로 감싸 out_conn을 확실히 drop(=flush) 한 뒤 파일 크기 검증을 합니다.
-        // (일부 환경에선 connection이 살아있는 동안 metadata.len()이 0으로 보일 수 있음)
-        {
+        // 암호화 DB는 Backup API로 평문 파일에 복사할 수 없어 sqlcipher_export 사용 (.ite는 항상 평문)
+        if self.encryption_key.is_some() {
+            self.export_plaintext_copy(out_path)?;
+        } else {
+            // 백업 수행은 scope로 감싸 out_conn을 확실히 drop(=flush) 한 뒤 파일 크기 검증을 합니다.
+            // (일부 환경에선 connection이 살아있는 동안 metadata.len()이 0으로 보일 수 있음)
             let mut out_conn = Connection::open(out_path)?;
             // 스키마가 없어도 백업이 전체 DB를 복제하지만,
             // 일부 환경에서의 안정성을 위해 명시적으로 초기화합니다.
@@ -115,63 +614,107 @@ impl Database {
         Ok(())
     }
 
-    /// 프로젝트 삭제(연관 데이터 포함)
-    /// - foreign_keys=ON이면 CASCADE로도 처리되지만, 환경 차이를 고려해 명시적으로 정리합니다.
-    pub fn delete_project(&self, project_id: &str) -> Result<(), IteError> {
-        let tx = self.conn.unchecked_transaction()?;
+    /// 현재 DB 파일 크기(bytes) = page_count * page_size
+    pub fn database_size_bytes(&self) -> Result<i64, IteError> {
+        let page_count: i64 = self.conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let page_size: i64 = self.conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        Ok(page_count * page_size)
+    }
 
-        // chat_messages -> chat_sessions 순으로 제거(세션 FK)
-        tx.execute(
-            "DELETE FROM chat_messages WHERE session_id IN (SELECT id FROM chat_sessions WHERE project_id = ?1)",
-            [project_id],
-        )?;
-        tx.execute("DELETE FROM chat_sessions WHERE project_id = ?1", [project_id])?;
-        tx.execute(
-            "DELETE FROM chat_project_settings WHERE project_id = ?1",
-            [project_id],
-        )?;
+    /// ANALYZE로 쿼리 플래너 통계 갱신
+    pub fn analyze(&self) -> Result<(), IteError> {
+        self.conn.execute_batch("ANALYZE;")?;
+        Ok(())
+    }
 
-        tx.execute("DELETE FROM history WHERE project_id = ?1", [project_id])?;
-        tx.execute("DELETE FROM glossary_entries WHERE project_id = ?1", [project_id])?;
-        tx.execute("DELETE FROM segments WHERE project_id = ?1", [project_id])?;
-        tx.execute("DELETE FROM blocks WHERE project_id = ?1", [project_id])?;
-        tx.execute("DELETE FROM projects WHERE id = ?1", [project_id])?;
+    /// 채팅 전문 검색 인덱스(FTS5) 세그먼트 병합
+    pub fn optimize_fts(&self) -> Result<(), IteError> {
+        self.conn
+            .execute_batch("INSERT INTO chat_messages_fts(chat_messages_fts) VALUES ('optimize');")?;
+        Ok(())
+    }
+
+    /// VACUUM으로 빈 페이지를 정리하고 줄어든 크기(bytes)를 반환
+    pub fn vacuum(&self) -> Result<i64, IteError> {
+        let before = self.database_size_bytes()?;
+        self.conn.execute_batch("VACUUM;")?;
+        let after = self.database_size_bytes()?;
+        Ok((before - after).max(0))
+    }
 
+    /// WAL 내용을 본 DB 파일에 반영하고 WAL 파일 비우기 (종료 시)
+    pub fn checkpoint(&self) -> Result<(), IteError> {
+        self.conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        Ok(())
+    }
+
+    /// 프로젝트 삭제(연관 데이터 포함)
+    pub fn delete_project(&self, project_id: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        delete_project_rows(&tx, project_id)?;
         tx.commit()?;
         Ok(())
     }
 
     /// 모든 프로젝트 삭제(연관 데이터 포함)
-    /// - 전역 용어집(project_id IS NULL)은 유지합니다.
+    /// - 전역 용어집/프롬프트 템플릿/DNT 용어(project_id IS NULL)은 유지합니다.
     pub fn delete_all_projects(&self) -> Result<(), IteError> {
         let tx = self.conn.unchecked_transaction()?;
 
         tx.execute("DELETE FROM chat_messages", [])?;
         tx.execute("DELETE FROM chat_sessions", [])?;
         tx.execute("DELETE FROM chat_project_settings", [])?;
+        tx.execute("DELETE FROM chat_retention_policies", [])?;
         tx.execute("DELETE FROM history", [])?;
+        tx.execute("DELETE FROM block_edits", [])?;
+        tx.execute("DELETE FROM revisions", [])?;
         tx.execute("DELETE FROM glossary_entries WHERE project_id IS NOT NULL", [])?;
+        tx.execute("DELETE FROM prompt_templates WHERE project_id IS NOT NULL", [])?;
+        tx.execute("DELETE FROM dnt_terms WHERE project_id IS NOT NULL", [])?;
+        tx.execute("DELETE FROM tm_units WHERE project_id IS NOT NULL", [])?;
+        tx.execute("DELETE FROM xliff_segments", [])?;
+        tx.execute("DELETE FROM xliff_documents", [])?;
+        tx.execute("DELETE FROM tms_segments", [])?;
+        tx.execute("DELETE FROM tms_jobs", [])?;
+        tx.execute("DELETE FROM segment_propagation_opt_outs", [])?;
+        tx.execute("DELETE FROM project_custom_fields", [])?;
+        tx.execute("DELETE FROM project_mcp_servers", [])?;
+        tx.execute("DELETE FROM segment_quality", [])?;
+        tx.execute("DELETE FROM segment_notes", [])?;
         tx.execute("DELETE FROM segments", [])?;
         tx.execute("DELETE FROM blocks", [])?;
         tx.execute("DELETE FROM projects", [])?;
+        record_change(&tx, &Change::new("project", "*", ChangeOp::Delete))?;
 
         tx.commit()?;
+        // WHERE 없는 DELETE는 update hook을 거치지 않음
+        self.invalidate_caches();
         Ok(())
     }
 
     /// 파일(.ite)을 현재 DB로 가져오기 (현재 DB 내용을 덮어씀)
     pub fn import_db_from_file(&mut self, in_path: &Path) -> Result<(), IteError> {
+        // 암호화 DB는 평문 파일을 Backup API로 덮어쓸 수 없어, 암호화 사본을 만든 뒤 파일을 교체
+        if self.encryption_key.is_some() {
+            return self.import_plaintext_file(in_path);
+        }
+
         let in_conn = Connection::open(in_path)?;
 
         // 현재 연결을 새 DB 파일로 덮어쓰기(backup)
         let backup = Backup::new(&in_conn, &mut self.conn)?;
         backup.run_to_completion(5, std::time::Duration::from_millis(10), None)?;
+        drop(backup);
+        // Backup API는 페이지 단위로 덮어써 update hook을 거치지 않음
+        self.invalidate_caches();
         Ok(())
     }
 
-    /// 저장된 프로젝트 ID 목록 조회
+    /// 저장된 프로젝트 ID 목록 조회 (휴지통 제외)
     pub fn list_project_ids(&self) -> Result<Vec<String>, IteError> {
-        let mut stmt = self.conn.prepare("SELECT id FROM projects ORDER BY updated_at DESC LIMIT 1000")?;
+        let mut stmt = self.conn.prepare(
+            "SELECT id FROM projects WHERE deleted_at IS NULL ORDER BY updated_at DESC LIMIT 1000",
+        )?;
         let iter = stmt.query_map([], |row| row.get::<_, String>(0))?;
         let mut ids = Vec::new();
         for id in iter {
@@ -180,96 +723,169 @@ impl Database {
         Ok(ids)
     }
 
-    /// 최근 프로젝트 목록(간단 메타 포함)
-    pub fn list_recent_projects(&self, limit: usize) -> Result<Vec<RecentProjectRow>, IteError> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, metadata_json, updated_at FROM projects ORDER BY updated_at DESC LIMIT ?1",
-        )?;
+    /// 최근 프로젝트 목록(간단 메타 + 사용자 정의 필드 + 세그먼트 진행률, 휴지통 제외, 고정 항목 우선)
+    /// - filters가 있으면 모든 조건을 만족하는 프로젝트만 반환합니다.
+    /// - 보관된 프로젝트는 `include_archived`일 때만 포함합니다.
+    pub fn list_recent_projects(
+        &self,
+        limit: usize,
+        filters: &[CustomFieldFilter],
+        include_archived: bool,
+    ) -> Result<Vec<RecentProjectRow>, IteError> {
+        let mut sql = String::from(
+            "SELECT id, metadata_json, updated_at, pinned_at IS NOT NULL, archived_at IS NOT NULL
+             FROM projects WHERE deleted_at IS NULL",
+        );
+        if !include_archived {
+            sql.push_str(" AND archived_at IS NULL");
+        }
+        let mut params: Vec<String> = Vec::new();
+        for filter in filters {
+            sql.push_str(" AND ");
+            // 값이 비어 있으면 키 존재 여부만 확인
+            sql.push_str(&format!(
+                "EXISTS (SELECT 1 FROM project_custom_fields f
+                         WHERE f.project_id = projects.id AND f.field_key = ?{0}
+                           AND (?{1} = '' OR f.value = ?{1} COLLATE NOCASE))",
+                params.len() + 1,
+                params.len() + 2
+            ));
+            params.push(filter.key.trim().to_string());
+            params.push(filter.value.trim().to_string());
+        }
+        // 고정된 프로젝트 먼저, 그 안에서는 최근 수정 순
+        sql.push_str(&format!(
+            " ORDER BY (pinned_at IS NULL), updated_at DESC LIMIT {}",
+            limit
+        ));
+
+        self.query_recent_project_rows(&sql, &params)
+    }
+
+    /// 프로젝트 검색 (제목/도메인/설명/사용자 정의 필드 부분 일치, 최근 수정 순, 휴지통 제외)
+    /// - 공백으로 나뉜 검색어는 모두 포함해야 합니다(AND). 대소문자 무시는 ASCII 기준입니다.
+    pub fn search_projects(&self, query: &str, limit: usize) -> Result<Vec<RecentProjectRow>, IteError> {
+        let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut sql = String::from(
+            "SELECT id, metadata_json, updated_at, pinned_at IS NOT NULL, archived_at IS NOT NULL
+             FROM projects WHERE deleted_at IS NULL",
+        );
+        for i in 1..=terms.len() {
+            sql.push_str(&format!(
+                " AND (instr(lower(COALESCE(json_extract(metadata_json, '$.title'), '')), ?{0}) > 0
+                   OR instr(lower(COALESCE(json_extract(metadata_json, '$.domain'), '')), ?{0}) > 0
+                   OR instr(lower(COALESCE(json_extract(metadata_json, '$.description'), '')), ?{0}) > 0
+                   OR EXISTS (SELECT 1 FROM project_custom_fields f
+                              WHERE f.project_id = projects.id
+                                AND (instr(lower(f.value), ?{0}) > 0 OR instr(lower(f.field_key), ?{0}) > 0)))",
+                i
+            ));
+        }
+        sql.push_str(&format!(" ORDER BY updated_at DESC LIMIT {}", limit));
+
+        self.query_recent_project_rows(&sql, &terms)
+    }
 
-        let iter = stmt.query_map([limit as i64], |row| {
+    /// 목록 조회 SQL(id, metadata_json, updated_at, pinned, archived) 실행 + 사용자 정의 필드/진행률 채우기
+    fn query_recent_project_rows(&self, sql: &str, params: &[String]) -> Result<Vec<RecentProjectRow>, IteError> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let iter = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
             let id: String = row.get(0)?;
             let metadata_json: String = row.get(1)?;
             let updated_at: i64 = row.get(2)?;
+            let pinned: bool = row.get(3)?;
+            let archived: bool = row.get(4)?;
 
-            // metadata_json에서 title만 안전하게 추출
-            let title = serde_json::from_str::<serde_json::Value>(&metadata_json)
-                .ok()
-                .and_then(|v| v.get("title").and_then(|t| t.as_str()).map(|s| s.to_string()))
-                .unwrap_or_else(|| "Untitled Project".to_string());
+            // metadata_json에서 목록 표시용 필드만 안전하게 추출
+            let metadata = serde_json::from_str::<serde_json::Value>(&metadata_json).unwrap_or_default();
+            let field = |key: &str| metadata.get(key).and_then(|t| t.as_str()).map(|s| s.to_string());
 
-            Ok(RecentProjectRow { id, title, updated_at })
+            Ok(RecentProjectRow {
+                title: field("title").unwrap_or_else(|| "Untitled Project".to_string()),
+                domain: field("domain").unwrap_or_default(),
+                target_language: field("targetLanguage"),
+                id,
+                updated_at,
+                pinned,
+                archived,
+                custom_fields: BTreeMap::new(),
+                segment_count: 0,
+                translated_segments: 0,
+            })
         })?;
 
         let mut out = Vec::new();
         for row in iter {
             out.push(row?);
         }
+
+        let mut fields = self.load_all_custom_fields()?;
+        for row in &mut out {
+            if let Some(f) = fields.remove(&row.id) {
+                row.custom_fields = f;
+            }
+            (row.segment_count, row.translated_segments) = self.segment_progress(&row.id)?;
+        }
         Ok(out)
     }
 
-    /// 프로젝트 저장
-    pub fn save_project(&self, project: &IteProject) -> Result<(), IteError> {
+    /// 최근 프로젝트 목록 상단 고정/해제
+    pub fn set_project_pinned(&self, project_id: &str, pinned: bool) -> Result<(), IteError> {
+        let pinned_at = pinned.then(|| chrono::Utc::now().timestamp_millis());
         let tx = self.conn.unchecked_transaction()?;
-
-        // 프로젝트 메타데이터 저장
-        // INSERT OR REPLACE는 row를 삭제후 재생성하므로, CASCADE DELETE가 설정된 자식 테이블(chat_project_settings 등)이
-        // 의도치 않게 삭제될 수 있습니다. 이를 방지하기 위해 UPSERT를 사용합니다.
-        tx.execute(
-            "INSERT INTO projects (id, version, metadata_json, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5)
-             ON CONFLICT(id) DO UPDATE SET
-                version = excluded.version,
-                metadata_json = excluded.metadata_json,
-                updated_at = excluded.updated_at",
-            (
-                &project.id,
-                &project.version,
-                serde_json::to_string(&project.metadata)?,
-                project.metadata.created_at,
-                project.metadata.updated_at,
-            ),
+        let changed = tx.execute(
+            "UPDATE projects SET pinned_at = ?1 WHERE id = ?2",
+            (pinned_at, project_id),
         )?;
-
-        // 기존 데이터 삭제
-        tx.execute("DELETE FROM blocks WHERE project_id = ?1", [&project.id])?;
-        tx.execute("DELETE FROM segments WHERE project_id = ?1", [&project.id])?;
-
-        // 블록 저장
-        for (_, block) in &project.blocks {
-            tx.execute(
-                "INSERT INTO blocks (id, project_id, block_type, content, hash, metadata_json)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                (
-                    &block.id,
-                    &project.id,
-                    &block.block_type,
-                    &block.content,
-                    &block.hash,
-                    serde_json::to_string(&block.metadata)?,
-                ),
-            )?;
+        if changed == 0 {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
         }
+        record_change(
+            &tx,
+            &Change::new("project", project_id, ChangeOp::Update)
+                .project(project_id)
+                .payload(if pinned { "pinned" } else { "unpinned" }),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
 
-        // 세그먼트 저장
-        for segment in &project.segments {
-            tx.execute(
-                "INSERT INTO segments (id, project_id, source_ids, target_ids, is_aligned, segment_order)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                (
-                    &segment.group_id,
-                    &project.id,
-                    serde_json::to_string(&segment.source_ids)?,
-                    serde_json::to_string(&segment.target_ids)?,
-                    segment.is_aligned,
-                    segment.order,
-                ),
-            )?;
+    /// 프로젝트 보관/해제 (보관된 프로젝트는 최근 목록에서 기본으로 숨김)
+    pub fn set_project_archived(&self, project_id: &str, archived: bool) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        // 이미 보관된 프로젝트는 처음 보관한 시각을 유지
+        let changed = tx.execute(
+            "UPDATE projects SET archived_at = CASE WHEN ?1 THEN COALESCE(archived_at, ?2) END WHERE id = ?3",
+            (archived, chrono::Utc::now().timestamp_millis(), project_id),
+        )?;
+        if changed == 0 {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
         }
-
+        record_change(
+            &tx,
+            &Change::new("project", project_id, ChangeOp::Update)
+                .project(project_id)
+                .payload(if archived { "archived" } else { "unarchived" }),
+        )?;
         tx.commit()?;
         Ok(())
     }
 
+    /// 프로젝트 저장
+    /// - 내용이 바뀐 블록 ID(새 블록, 지운 블록 포함)를 반환합니다. (다른 창 알림, 블록 단위 QA용)
+    pub fn save_project(&self, project: &IteProject) -> Result<Vec<String>, IteError> {
+        self.ensure_project_writable(&project.id)?;
+        let author = self.local_author()?;
+        let tx = self.conn.unchecked_transaction()?;
+        let changed_blocks = write_project(&tx, project, author.as_deref())?;
+        tx.commit()?;
+        Ok(changed_blocks)
+    }
+
     /// 현재 채팅 세션(1개)을 프로젝트에 저장
     /// - 요구사항: 프로젝트별 "현재 세션 1개만" 저장
     pub fn save_current_chat_session(
@@ -282,24 +898,32 @@ impl Database {
         self.save_chat_sessions(project_id, std::slice::from_ref(session))
     }
 
-    /// 채팅 세션을 프로젝트에 저장 (최대 5개 유지)
-    /// - 정책: 최근 활동(마지막 메시지 timestamp) 기준으로 정렬 후 상위 5개만 저장
-    /// - 세션당 메시지는 최근 30개만 저장 (스토리지 부담 방지)
+    /// 채팅 세션 목록을 프로젝트에 저장 (전체 교체)
+    /// - 정책: 최근 활동(마지막 메시지 timestamp) 기준으로 정렬 후 상위 MAX_CHAT_SESSIONS개만 저장
+    /// - 세션당 메시지는 최근 MAX_MESSAGES_PER_SESSION개만 저장 (스토리지 부담 방지)
     pub fn save_chat_sessions(
         &self,
         project_id: &str,
         sessions: &[ChatSession],
     ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
         let tx = self.conn.unchecked_transaction()?;
 
-        // 기존 세션/메시지 제거(프로젝트당 1개만 유지)
+        let mut removed: Vec<String> = {
+            let mut stmt = tx.prepare("SELECT id FROM chat_sessions WHERE project_id = ?1")?;
+            let ids = stmt.query_map([project_id], |row| row.get(0))?;
+            ids.collect::<Result<_, _>>()?
+        };
+
+        // 기존 세션/메시지 제거 후 전달받은 세션 목록으로 교체
+        let kept = chat::kept_citations(&tx, project_id, None)?;
         tx.execute(
             "DELETE FROM chat_messages WHERE session_id IN (SELECT id FROM chat_sessions WHERE project_id = ?1)",
             [project_id],
         )?;
         tx.execute("DELETE FROM chat_sessions WHERE project_id = ?1", [project_id])?;
 
-        // 최근 활동 기준으로 정렬 후 최대 5개만 저장
+        // 최근 활동 기준으로 정렬 후 최대 MAX_CHAT_SESSIONS개만 저장
         let mut sorted: Vec<&ChatSession> = sessions.iter().collect();
         sorted.sort_by(|a, b| {
             let a_last = a
@@ -317,50 +941,12 @@ impl Database {
             b_last.cmp(&a_last)
         });
 
-        const MAX_SESSIONS: usize = 5;
-        const MAX_MESSAGES_PER_SESSION: usize = 100;
-
-        for session in sorted.into_iter().take(MAX_SESSIONS) {
-            tx.execute(
-                "INSERT INTO chat_sessions (id, project_id, name, created_at, context_block_ids, confluence_search_enabled)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                (
-                    &session.id,
-                    project_id,
-                    &session.name,
-                    session.created_at,
-                    serde_json::to_string(&session.context_block_ids)?,
-                    session.confluence_search_enabled,
-                ),
-            )?;
-
-            // 메시지를 timestamp 기준으로 정렬 후 최근 MAX_MESSAGES_PER_SESSION개만 저장
-            let mut messages: Vec<&crate::models::ChatMessage> = session.messages.iter().collect();
-            messages.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
-            let messages_to_save = if messages.len() > MAX_MESSAGES_PER_SESSION {
-                &messages[messages.len() - MAX_MESSAGES_PER_SESSION..]
-            } else {
-                &messages[..]
-            };
-
-            for m in messages_to_save {
-                let meta_json: Option<String> = match &m.metadata {
-                    Some(meta) => Some(serde_json::to_string(meta)?),
-                    None => None,
-                };
-                tx.execute(
-                    "INSERT INTO chat_messages (id, session_id, role, content, timestamp, metadata_json)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                    (
-                        &m.id,
-                        &session.id,
-                        &m.role,
-                        &m.content,
-                        m.timestamp,
-                        meta_json,
-                    ),
-                )?;
-            }
+        for session in sorted.into_iter().take(chat::MAX_CHAT_SESSIONS) {
+            chat::insert_chat_session(&tx, project_id, session, &kept)?;
+            removed.retain(|id| *id != session.id);
+        }
+        for id in &removed {
+            record_change(&tx, &Change::new("chat_session", id, ChangeOp::Delete).project(project_id))?;
         }
 
         tx.commit()?;
@@ -374,7 +960,7 @@ impl Database {
         Ok(sessions.into_iter().next())
     }
 
-    /// 채팅 세션 목록 로드 (최근 활동 기준, 최대 MAX_SESSIONS개)
+    /// 채팅 세션 목록 로드 (최근 활동 기준, 최대 MAX_CHAT_SESSIONS개)
     pub fn load_chat_sessions(&self, project_id: &str) -> Result<Vec<ChatSession>, IteError> {
         let mut stmt = self.conn.prepare(
             "SELECT s.id, s.name, s.created_at, s.context_block_ids, s.confluence_search_enabled,
@@ -382,10 +968,10 @@ impl Database {
              FROM chat_sessions s
              WHERE s.project_id = ?1
              ORDER BY last_ts DESC
-             LIMIT 5",
+             LIMIT ?2",
         )?;
 
-        let iter = stmt.query_map([project_id], |row| {
+        let iter = stmt.query_map((project_id, chat::MAX_CHAT_SESSIONS as i64), |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
@@ -400,31 +986,7 @@ impl Database {
             let (session_id, name, created_at, context_block_ids_json, confluence_search_enabled) = r?;
             let context_block_ids: Vec<String> =
                 serde_json::from_str(&context_block_ids_json).unwrap_or_default();
-
-            let mut msg_stmt = self.conn.prepare(
-                "SELECT id, role, content, timestamp, metadata_json
-                 FROM chat_messages WHERE session_id = ?1
-                 ORDER BY timestamp ASC",
-            )?;
-
-            let msg_iter = msg_stmt.query_map([&session_id], |row| {
-                let metadata_json: Option<String> = row.get(4)?;
-                let metadata: Option<serde_json::Value> = metadata_json
-                    .as_deref()
-                    .and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok());
-                Ok(crate::models::ChatMessage {
-                    id: row.get(0)?,
-                    role: row.get(1)?,
-                    content: row.get(2)?,
-                    timestamp: row.get(3)?,
-                    metadata,
-                })
-            })?;
-
-            let mut messages = Vec::new();
-            for m in msg_iter {
-                messages.push(m?);
-            }
+            let messages = chat::load_chat_messages(&self.conn, &session_id)?;
 
             sessions.push(ChatSession {
                 id: session_id,
@@ -446,11 +1008,20 @@ impl Database {
         settings_json: &str,
         updated_at: i64,
     ) -> Result<(), IteError> {
-        self.conn.execute(
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
             "INSERT OR REPLACE INTO chat_project_settings (project_id, settings_json, updated_at)
              VALUES (?1, ?2, ?3)",
             (project_id, settings_json, updated_at),
         )?;
+        let change = Change::new("chat_settings", project_id, ChangeOp::Upsert)
+            .project(project_id)
+            .payload(settings_json);
+        if !changes::is_unchanged(&tx, &change)? {
+            record_change(&tx, &change)?;
+        }
+        tx.commit()?;
         Ok(())
     }
 
@@ -467,6 +1038,19 @@ impl Database {
         }
     }
 
+    /// 프로젝트 메타데이터만 로드 (블록/세그먼트 제외)
+    pub fn load_project_metadata(&self, project_id: &str) -> Result<ProjectMetadata, IteError> {
+        let metadata_json: String = self
+            .conn
+            .query_row(
+                "SELECT metadata_json FROM projects WHERE id = ?1",
+                [project_id],
+                |row| row.get(0),
+            )
+            .map_err(|_| IteError::ProjectNotFound(project_id.to_string()))?;
+        Ok(serde_json::from_str(&metadata_json)?)
+    }
+
     /// 프로젝트 로드
     pub fn load_project(&self, project_id: &str) -> Result<IteProject, IteError> {
         // 프로젝트 메타데이터 로드
@@ -539,38 +1123,181 @@ impl Database {
 
     /// 블록 업데이트
     pub fn update_block(&self, block: &EditorBlock, project_id: &str) -> Result<(), IteError> {
-        self.conn.execute(
+        self.ensure_project_writable(project_id)?;
+        let author = self.local_author()?;
+        let now = chrono::Utc::now().timestamp_millis();
+        let tx = self.conn.unchecked_transaction()?;
+        let before: Option<String> = tx
+            .prepare_cached("SELECT content FROM blocks WHERE id = ?1 AND project_id = ?2")?
+            .query_row(
+                [&block.id, project_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        let mut metadata = block.metadata.clone();
+        if before.as_deref().is_some_and(|b| b != block.content) {
+            metadata.stamp(author.as_deref(), now);
+        }
+        let metadata_json = serde_json::to_string(&metadata)?;
+        tx.execute(
             "UPDATE blocks SET content = ?1, hash = ?2, metadata_json = ?3 
              WHERE id = ?4 AND project_id = ?5",
             (
                 &block.content,
                 &block.hash,
-                serde_json::to_string(&block.metadata)?,
+                &metadata_json,
                 &block.id,
                 project_id,
             ),
         )?;
+
+        if let Some(before) = before {
+            record_change(
+                &tx,
+                &Change::new("block", &block.id, ChangeOp::Update)
+                    .project(project_id)
+                    .payload(&changes::block_payload(&block.content, &metadata_json))
+                    .actor(metadata.author.as_deref()),
+            )?;
+            edit_log::record_block_edit(
+                &tx,
+                project_id,
+                &BlockEdit {
+                    block_id: &block.id,
+                    block_type: &block.block_type,
+                    author: metadata.author.as_deref(),
+                    before: &before,
+                    after: &block.content,
+                },
+                now,
+            )?;
+        }
+        tx.commit()?;
         Ok(())
     }
 
-    /// 블록 조회
+    /// 여러 블록의 콘텐츠를 한 트랜잭션으로 교체 (hash/metadata.updatedAt/author 갱신)
+    /// - updates: (block_id, content)
+    pub fn update_block_contents(
+        &self,
+        project_id: &str,
+        updates: &[(String, String)],
+    ) -> Result<usize, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let author = self.local_author()?;
+        let tx = self.conn.unchecked_transaction()?;
+        let updated = write_block_contents(&tx, project_id, updates, author.as_deref())?;
+        tx.commit()?;
+        Ok(updated)
+    }
+
+    /// 블록들의 분량 제약(`metadata.capacity`) 설정/해제 (None이면 해제)
+    /// - 값이 그대로인 블록은 건너뛰고, 실제로 바뀐 블록 수를 반환합니다.
+    pub fn set_block_capacity(
+        &self,
+        project_id: &str,
+        block_ids: &[String],
+        capacity: Option<&CapacityHint>,
+    ) -> Result<usize, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let capacity_value = capacity.map(serde_json::to_value).transpose()?;
+        let tx = self.conn.unchecked_transaction()?;
+        let mut changed = 0;
+        {
+            let mut select = tx.prepare("SELECT content, metadata_json FROM blocks WHERE id = ?1 AND project_id = ?2")?;
+            let mut update = tx.prepare("UPDATE blocks SET metadata_json = ?1 WHERE id = ?2 AND project_id = ?3")?;
+
+            for block_id in block_ids {
+                let (content, metadata_json): (String, String) = select
+                    .query_row([block_id, project_id], |row| Ok((row.get(0)?, row.get(1)?)))
+                    .map_err(|e| match e {
+                        rusqlite::Error::QueryReturnedNoRows => IteError::BlockNotFound(block_id.clone()),
+                        other => other.into(),
+                    })?;
+                let mut metadata: serde_json::Value = serde_json::from_str(&metadata_json)?;
+                let Some(obj) = metadata.as_object_mut() else {
+                    continue;
+                };
+                let previous = match &capacity_value {
+                    Some(value) => obj.insert("capacity".to_string(), value.clone()),
+                    None => obj.remove("capacity"),
+                };
+                if previous == capacity_value {
+                    continue;
+                }
+
+                let metadata_json = serde_json::to_string(&metadata)?;
+                update.execute((&metadata_json, block_id, project_id))?;
+                record_change(
+                    &tx,
+                    &Change::new("block", block_id, ChangeOp::Update)
+                        .project(project_id)
+                        .payload(&changes::block_payload(&content, &metadata_json)),
+                )?;
+                changed += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// 블록 조회 (캐시 우선)
     pub fn get_block(&self, block_id: &str, project_id: &str) -> Result<EditorBlock, IteError> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, block_type, content, hash, metadata_json 
+        if let Some(block) = self.cached_block(project_id, block_id) {
+            return Ok(block);
+        }
+        self.query_block(block_id, project_id)?
+            .ok_or_else(|| IteError::BlockNotFound(block_id.to_string()))
+    }
+
+    /// 여러 블록 한 번에 조회 (요청 순서 유지, 없는 블록은 건너뜀)
+    pub fn get_blocks(&self, project_id: &str, block_ids: &[String]) -> Result<Vec<EditorBlock>, IteError> {
+        let mut blocks = Vec::with_capacity(block_ids.len());
+        for block_id in block_ids {
+            let block = match self.cached_block(project_id, block_id) {
+                Some(block) => Some(block),
+                None => self.query_block(block_id, project_id)?,
+            };
+            blocks.extend(block);
+        }
+        Ok(blocks)
+    }
+
+    fn cached_block(&self, project_id: &str, block_id: &str) -> Option<EditorBlock> {
+        self.block_cache.lock().ok()?.get(project_id, block_id)
+    }
+
+    /// DB에서 블록을 읽어 캐시에 넣음
+    fn query_block(&self, block_id: &str, project_id: &str) -> Result<Option<EditorBlock>, IteError> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT rowid, id, block_type, content, hash, metadata_json 
              FROM blocks WHERE id = ?1 AND project_id = ?2",
         )?;
 
-        stmt.query_row([block_id, project_id], |row| {
-            let metadata_json: String = row.get(4)?;
-            Ok(EditorBlock {
-                id: row.get(0)?,
-                block_type: row.get(1)?,
-                content: row.get(2)?,
-                hash: row.get(3)?,
-                metadata: serde_json::from_str(&metadata_json).unwrap_or_default(),
+        let row = stmt
+            .query_row([block_id, project_id], |row| {
+                let metadata_json: String = row.get(5)?;
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    EditorBlock {
+                        id: row.get(1)?,
+                        block_type: row.get(2)?,
+                        content: row.get(3)?,
+                        hash: row.get(4)?,
+                        metadata: serde_json::from_str(&metadata_json).unwrap_or_default(),
+                    },
+                ))
             })
-        })
-        .map_err(|_| IteError::BlockNotFound(block_id.to_string()))
+            .optional()?;
+        let Some((rowid, block)) = row else {
+            return Ok(None);
+        };
+        // 쿼리가 끝난 뒤에 잠금 (hook도 같은 잠금을 씀)
+        if let Ok(mut cache) = self.block_cache.lock() {
+            cache.insert(project_id, block.clone(), rowid);
+        }
+        Ok(Some(block))
     }
 
     /// CSV 글로서리 임포트(project scope)
@@ -584,13 +1311,15 @@ impl Database {
         path: &str,
         replace_project_scope: bool,
     ) -> Result<(u32, u32, u32), IteError> {
+        self.ensure_project_writable(project_id)?;
         // ────────────────────────────────────────────────────────────────────
         // Phase 1: Read and parse OUTSIDE transaction
         // ────────────────────────────────────────────────────────────────────
-        let text = std::fs::read_to_string(path)?;
+        // EUC-KR/Shift_JIS 등으로 저장된 CSV도 UTF-8로 변환해 읽음
+        let text = crate::text::encoding::read_text_file(Path::new(path))?.text;
 
         // 간단 CSV 파서(외부 크레이트 없이 동작)
-        // - 기본: UTF-8 CSV
+        // - 인코딩은 자동 감지(UTF-8/BOM/레거시 인코딩)
         // - 따옴표(") 내부의 콤마는 필드로 취급
         // - "" 는 " 로 이스케이프
         fn parse_csv_row(line: &str) -> Vec<String> {
@@ -682,8 +1411,9 @@ impl Database {
         let mut skipped: u32 = 0;
 
         for record in data_rows {
-            let source = record.get(idx_source).map(|s| s.trim()).unwrap_or("");
-            let target = record.get(idx_target).map(|s| s.trim()).unwrap_or("");
+            // macOS에서 만든 파일(NFD)도 같은 용어 id가 되도록 NFC로 맞춤
+            let source = nfc(record.get(idx_source).map(|s| s.trim()).unwrap_or(""));
+            let target = nfc(record.get(idx_target).map(|s| s.trim()).unwrap_or(""));
 
             if source.is_empty() || target.is_empty() {
                 skipped += 1;
@@ -692,12 +1422,12 @@ impl Database {
 
             let notes = idx_notes
                 .and_then(|i| record.get(i))
-                .map(|s| s.trim().to_string())
+                .map(|s| into_nfc(s.trim().to_string()))
                 .filter(|s| !s.is_empty());
 
             let domain = idx_domain
                 .and_then(|i| record.get(i))
-                .map(|s| s.trim().to_string())
+                .map(|s| into_nfc(s.trim().to_string()))
                 .filter(|s| !s.is_empty());
 
             let case_sensitive = idx_case
@@ -735,6 +1465,7 @@ impl Database {
                 "DELETE FROM glossary_entries WHERE project_id = ?1",
                 [project_id],
             )?;
+            record_change(&tx, &Change::new("glossary_entry", project_id, ChangeOp::Delete).project(project_id))?;
             tx.commit()?;
         }
 
@@ -778,6 +1509,18 @@ impl Database {
                         now,
                     ),
                 )?;
+                let payload = glossary_payload(
+                    &rec.source,
+                    &rec.target,
+                    rec.notes.as_deref(),
+                    rec.domain.as_deref(),
+                    rec.case_sensitive,
+                );
+                let op = if exists { ChangeOp::Update } else { ChangeOp::Insert };
+                record_change(
+                    &tx,
+                    &Change::new("glossary_entry", &rec.id, op).project(project_id).payload(&payload),
+                )?;
 
                 if exists {
                     updated += 1;
@@ -846,6 +1589,204 @@ impl Database {
         Ok(out)
     }
 
+    /// 프로젝트에 적용되는 용어집 전체 조회 (전역 + 프로젝트, 긴 용어 우선)
+    pub fn list_glossary_entries(&self, project_id: &str) -> Result<Vec<GlossaryEntryRow>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, source, target, notes, domain, case_sensitive, created_at, updated_at
+             FROM glossary_entries
+             WHERE project_id IS NULL OR project_id = ?1
+             ORDER BY length(source) DESC, source",
+        )?;
+
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(GlossaryEntryRow {
+                id: row.get(0)?,
+                source: row.get(1)?,
+                target: row.get(2)?,
+                notes: row.get(3)?,
+                domain: row.get(4)?,
+                case_sensitive: {
+                    let v: i64 = row.get(5)?;
+                    v == 1
+                },
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 프로젝트 전용 용어집만 조회 (전역 용어 제외, source 순)
+    pub fn list_project_glossary_entries(&self, project_id: &str) -> Result<Vec<GlossaryEntryRow>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, source, target, notes, domain, case_sensitive, created_at, updated_at
+             FROM glossary_entries
+             WHERE project_id = ?1
+             ORDER BY source",
+        )?;
+
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(GlossaryEntryRow {
+                id: row.get(0)?,
+                source: row.get(1)?,
+                target: row.get(2)?,
+                notes: row.get(3)?,
+                domain: row.get(4)?,
+                case_sensitive: row.get::<_, i64>(5)? == 1,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 전역 용어집만 조회 (source 순)
+    pub fn list_global_glossary_entries(&self) -> Result<Vec<GlossaryEntryRow>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, source, target, notes, domain, case_sensitive, created_at, updated_at
+             FROM glossary_entries
+             WHERE project_id IS NULL
+             ORDER BY source",
+        )?;
+
+        let iter = stmt.query_map([], |row| {
+            Ok(GlossaryEntryRow {
+                id: row.get(0)?,
+                source: row.get(1)?,
+                target: row.get(2)?,
+                notes: row.get(3)?,
+                domain: row.get(4)?,
+                case_sensitive: row.get::<_, i64>(5)? == 1,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 원격에서 받은 전역 용어 반영 (id 기준 덮어쓰기, 시각은 원격 값 유지)
+    /// - 반환: 반영한 항목 수
+    pub fn apply_global_glossary_entries(&self, entries: &[GlossaryEntryRow]) -> Result<u32, IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut count = 0u32;
+        for entry in entries {
+            let source = nfc(&entry.source);
+            let target = nfc(&entry.target);
+            let notes = entry.notes.as_deref().map(nfc);
+            let domain = entry.domain.as_deref().map(nfc);
+            tx.execute(
+                "INSERT INTO glossary_entries (
+                    id, project_id, source, target, notes, domain, case_sensitive, created_at, updated_at
+                 ) VALUES (?1, NULL, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT(id) DO UPDATE SET
+                    source = excluded.source,
+                    target = excluded.target,
+                    notes = excluded.notes,
+                    domain = excluded.domain,
+                    case_sensitive = excluded.case_sensitive,
+                    updated_at = excluded.updated_at
+                 WHERE glossary_entries.project_id IS NULL",
+                (
+                    &entry.id,
+                    source.as_ref(),
+                    target.as_ref(),
+                    notes.as_deref(),
+                    domain.as_deref(),
+                    if entry.case_sensitive { 1 } else { 0 },
+                    entry.created_at,
+                    entry.updated_at,
+                ),
+            )?;
+            let payload = glossary_payload(
+                &source,
+                &target,
+                notes.as_deref(),
+                domain.as_deref(),
+                entry.case_sensitive,
+            );
+            record_change(&tx, &Change::new("glossary_entry", &entry.id, ChangeOp::Upsert).payload(&payload))?;
+            count += 1;
+        }
+        tx.commit()?;
+        Ok(count)
+    }
+
+    /// 프로젝트 용어 추가 (origin_key 또는 source/target 기준 id, 이미 있으면 갱신)
+    /// - 반환: 처리한 항목 수
+    pub fn upsert_project_glossary_entries(
+        &self,
+        project_id: &str,
+        entries: &[NewGlossaryTerm],
+    ) -> Result<u32, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let now = chrono::Utc::now().timestamp_millis();
+        let tx = self.conn.unchecked_transaction()?;
+        let mut count = 0u32;
+        for entry in entries {
+            let source = nfc(&entry.source);
+            let target = nfc(&entry.target);
+            let notes = entry.notes.as_deref().map(nfc);
+            let domain = entry.domain.as_deref().map(nfc);
+            let key = match &entry.origin_key {
+                Some(origin) => format!("{}|origin|{}", project_id, origin),
+                None => format!("{}|{}|{}", project_id, source, target),
+            };
+            let id = format!("{:x}", md5::compute(key));
+            tx.execute(
+                "INSERT INTO glossary_entries (
+                    id, project_id, source, target, notes, domain, case_sensitive, created_at, updated_at
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 ON CONFLICT(id) DO UPDATE SET
+                    source = excluded.source,
+                    target = excluded.target,
+                    notes = excluded.notes,
+                    domain = excluded.domain,
+                    case_sensitive = excluded.case_sensitive,
+                    updated_at = excluded.updated_at",
+                (
+                    &id,
+                    project_id,
+                    source.as_ref(),
+                    target.as_ref(),
+                    notes.as_deref(),
+                    domain.as_deref(),
+                    if entry.case_sensitive { 1 } else { 0 },
+                    now,
+                    now,
+                ),
+            )?;
+            let payload = glossary_payload(
+                &source,
+                &target,
+                notes.as_deref(),
+                domain.as_deref(),
+                entry.case_sensitive,
+            );
+            record_change(
+                &tx,
+                &Change::new("glossary_entry", &id, ChangeOp::Upsert).project(project_id).payload(&payload),
+            )?;
+            count += 1;
+        }
+        tx.commit()?;
+        Ok(count)
+    }
+
     /// Excel(.xlsx/.xls) 글로서리 임포트(project scope)
     /// - 첫 번째 시트(또는 첫 sheet_names())를 읽습니다.
     /// - 첫 행이 source/target 헤더로 보이면 헤더로 취급합니다.
@@ -860,6 +1801,7 @@ impl Database {
     ) -> Result<(u32, u32, u32), IteError> {
         use calamine::{open_workbook_auto, Data, Reader};
 
+        self.ensure_project_writable(project_id)?;
         let now = chrono::Utc::now().timestamp_millis();
         let tx = self.conn.unchecked_transaction()?;
 
@@ -868,6 +1810,7 @@ impl Database {
                 "DELETE FROM glossary_entries WHERE project_id = ?1",
                 [project_id],
             )?;
+            record_change(&tx, &Change::new("glossary_entry", project_id, ChangeOp::Delete).project(project_id))?;
         }
 
         let mut workbook =
@@ -928,8 +1871,9 @@ impl Database {
         let mut skipped: u32 = 0;
 
         for record in data_rows {
-            let source = record.get(idx_source).map(|s| s.trim()).unwrap_or("");
-            let target = record.get(idx_target).map(|s| s.trim()).unwrap_or("");
+            // macOS에서 만든 파일(NFD)도 같은 용어 id가 되도록 NFC로 맞춤
+            let source = nfc(record.get(idx_source).map(|s| s.trim()).unwrap_or(""));
+            let target = nfc(record.get(idx_target).map(|s| s.trim()).unwrap_or(""));
             if source.is_empty() || target.is_empty() {
                 skipped += 1;
                 continue;
@@ -937,11 +1881,11 @@ impl Database {
 
             let notes = idx_notes
                 .and_then(|i| record.get(i))
-                .map(|s| s.trim().to_string())
+                .map(|s| into_nfc(s.trim().to_string()))
                 .filter(|s| !s.is_empty());
             let domain = idx_domain
                 .and_then(|i| record.get(i))
-                .map(|s| s.trim().to_string())
+                .map(|s| into_nfc(s.trim().to_string()))
                 .filter(|s| !s.is_empty());
             let case_sensitive = idx_case
                 .and_then(|i| record.get(i))
@@ -977,8 +1921,8 @@ impl Database {
                 (
                     &id,
                     project_id,
-                    source,
-                    target,
+                    source.as_ref(),
+                    target.as_ref(),
                     notes.as_deref(),
                     domain.as_deref(),
                     if case_sensitive { 1 } else { 0 },
@@ -986,6 +1930,9 @@ impl Database {
                     now,
                 ),
             )?;
+            let payload = glossary_payload(&source, &target, notes.as_deref(), domain.as_deref(), case_sensitive);
+            let op = if exists { ChangeOp::Update } else { ChangeOp::Insert };
+            record_change(&tx, &Change::new("glossary_entry", &id, op).project(project_id).payload(&payload))?;
 
             if exists {
                 updated += 1;
@@ -1000,17 +1947,21 @@ impl Database {
 
     /// 첨부 파일 저장
     pub fn save_attachment(&self, a: &crate::models::Attachment) -> Result<(), IteError> {
-        self.conn.execute(
+        self.ensure_project_writable(&a.project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
             "INSERT INTO attachments (
-                id, project_id, filename, file_type, file_path, extracted_text, file_size, created_at, updated_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                id, project_id, filename, file_type, file_path, extracted_text, file_size, created_at, updated_at,
+                content_hash
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
             ON CONFLICT(id) DO UPDATE SET
                 filename = excluded.filename,
                 file_type = excluded.file_type,
                 file_path = excluded.file_path,
                 extracted_text = excluded.extracted_text,
                 file_size = excluded.file_size,
-                updated_at = excluded.updated_at",
+                updated_at = excluded.updated_at,
+                content_hash = excluded.content_hash",
             (
                 &a.id,
                 &a.project_id,
@@ -1021,31 +1972,44 @@ impl Database {
                 a.file_size,
                 a.created_at,
                 a.updated_at,
+                &a.content_hash,
             ),
         )?;
+        let payload = serde_json::to_string(a)?;
+        record_change(
+            &tx,
+            &Change::new("attachment", &a.id, ChangeOp::Upsert)
+                .project(&a.project_id)
+                .payload(&payload),
+        )?;
+        tx.commit()?;
         Ok(())
     }
 
     /// 프로젝트별 첨부 파일 목록 조회
     pub fn list_attachments(&self, project_id: &str) -> Result<Vec<crate::models::Attachment>, IteError> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, project_id, filename, file_type, file_path, extracted_text, file_size, created_at, updated_at
-             FROM attachments WHERE project_id = ?1 ORDER BY created_at ASC",
-        )?;
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM attachments WHERE project_id = ?1 ORDER BY created_at ASC",
+            attachments::ATTACHMENT_COLUMNS
+        ))?;
 
-        let iter = stmt.query_map([project_id], |row| {
-            Ok(crate::models::Attachment {
-                id: row.get(0)?,
-                project_id: row.get(1)?,
-                filename: row.get(2)?,
-                file_type: row.get(3)?,
-                file_path: row.get(4)?,
-                extracted_text: row.get(5)?,
-                file_size: row.get(6)?,
-                created_at: row.get(7)?,
-                updated_at: row.get(8)?,
-            })
-        })?;
+        let iter = stmt.query_map([project_id], attachments::row_to_attachment)?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 전체 프로젝트 첨부 파일 목록 (패키지 내보내기용)
+    pub fn list_all_attachments(&self) -> Result<Vec<crate::models::Attachment>, IteError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM attachments ORDER BY created_at ASC",
+            attachments::ATTACHMENT_COLUMNS
+        ))?;
+
+        let iter = stmt.query_map([], attachments::row_to_attachment)?;
 
         let mut out = Vec::new();
         for r in iter {
@@ -1054,15 +2018,47 @@ impl Database {
         Ok(out)
     }
 
+    /// 첨부 파일 경로 변경 (패키지에서 풀어낸 파일로 재연결), 해당 첨부가 있으면 true
+    pub fn set_attachment_file_path(&self, id: &str, file_path: &str) -> Result<bool, IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let project_id: Option<String> = tx
+            .query_row(
+                "UPDATE attachments SET file_path = ?1 WHERE id = ?2 RETURNING project_id",
+                [file_path, id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(project_id) = &project_id {
+            self.ensure_project_writable(project_id)?;
+            record_change(
+                &tx,
+                &Change::new("attachment", id, ChangeOp::Update)
+                    .project(project_id)
+                    .payload(file_path),
+            )?;
+        }
+        tx.commit()?;
+        Ok(project_id.is_some())
+    }
+
     /// 첨부 파일 삭제
     pub fn delete_attachment(&self, id: &str) -> Result<(), IteError> {
-        self.conn.execute("DELETE FROM attachments WHERE id = ?1", [id])?;
+        let tx = self.conn.unchecked_transaction()?;
+        let project_id: Option<String> = tx
+            .query_row("DELETE FROM attachments WHERE id = ?1 RETURNING project_id", [id], |row| row.get(0))
+            .optional()?;
+        if let Some(project_id) = &project_id {
+            self.ensure_project_writable(project_id)?;
+            record_change(&tx, &Change::new("attachment", id, ChangeOp::Delete).project(project_id))?;
+        }
+        tx.commit()?;
         Ok(())
     }
 
     /// MCP 서버 저장 (Insert or Update)
     pub fn save_mcp_server(&self, server: &McpServerRow) -> Result<(), IteError> {
-        self.conn.execute(
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
             "INSERT INTO mcp_servers (
                 id, name, server_type, config_json, is_enabled, created_at, updated_at
             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
@@ -1082,6 +2078,9 @@ impl Database {
                 server.updated_at,
             ),
         )?;
+        let payload = serde_json::to_string(server)?;
+        record_change(&tx, &Change::new("mcp_server", &server.id, ChangeOp::Upsert).payload(&payload))?;
+        tx.commit()?;
         Ok(())
     }
 
@@ -1114,7 +2113,11 @@ impl Database {
 
     /// MCP 서버 삭제
     pub fn delete_mcp_server(&self, id: &str) -> Result<(), IteError> {
-        self.conn.execute("DELETE FROM mcp_servers WHERE id = ?1", [id])?;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM project_mcp_servers WHERE server_id = ?1", [id])?;
+        tx.execute("DELETE FROM mcp_servers WHERE id = ?1", [id])?;
+        record_change(&tx, &Change::new("mcp_server", id, ChangeOp::Delete))?;
+        tx.commit()?;
         Ok(())
     }
 }
@@ -1127,6 +2130,7 @@ impl Default for crate::models::BlockMetadata {
             updated_at: chrono::Utc::now().timestamp_millis(),
             tags: Vec::new(),
             comments: None,
+            capacity: None,
         }
     }
 }
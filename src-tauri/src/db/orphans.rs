@@ -0,0 +1,90 @@
+//! Orphan Cleanup
+//!
+//! 더 이상 어디에서도 참조하지 않는 행 정리
+//! - 어떤 세그먼트에도 속하지 않는 블록, 세션이 없는 채팅 메시지, 메시지가 없는 첨부 항목(인용 등), 프로젝트가 없는 첨부가 대상입니다.
+//! - 휴지통에 있는 프로젝트는 복원할 수 있으므로 건드리지 않습니다.
+
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+
+/// 정리된 첨부 (원본 파일 정리는 호출자가 판단)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedAttachment {
+    pub id: String,
+    pub project_id: String,
+    pub filename: String,
+    pub file_path: Option<String>,
+}
+
+/// DB 고아 데이터 정리 결과
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanCleanup {
+    pub blocks: usize,
+    pub chat_messages: usize,
+    pub chat_message_artifacts: usize,
+    pub attachments: Vec<RemovedAttachment>,
+}
+
+impl Database {
+    /// 고아 블록/채팅 메시지/첨부를 한 트랜잭션으로 삭제
+    pub fn cleanup_orphans(&self) -> Result<OrphanCleanup, IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut result = OrphanCleanup::default();
+
+        let blocks: Vec<(String, String)> = {
+            let mut stmt = tx.prepare(
+                "DELETE FROM blocks WHERE id NOT IN (
+                    SELECT j.value FROM segments s, json_each(s.source_ids) j
+                    UNION
+                    SELECT j.value FROM segments s, json_each(s.target_ids) j
+                 )
+                 RETURNING id, project_id",
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (block_id, project_id) in &blocks {
+            record_change(&tx, &Change::new("block", block_id, ChangeOp::Delete).project(project_id))?;
+        }
+        result.blocks = blocks.len();
+
+        result.chat_messages = tx.execute(
+            "DELETE FROM chat_messages WHERE session_id NOT IN (SELECT id FROM chat_sessions)",
+            [],
+        )?;
+        result.chat_message_artifacts = tx.execute(
+            "DELETE FROM chat_message_artifacts WHERE message_id NOT IN (SELECT id FROM chat_messages)",
+            [],
+        )?;
+
+        result.attachments = {
+            let mut stmt = tx.prepare(
+                "DELETE FROM attachments WHERE project_id NOT IN (SELECT id FROM projects)
+                 RETURNING id, project_id, filename, file_path",
+            )?;
+            let rows = stmt.query_map([], |row| {
+                Ok(RemovedAttachment {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    filename: row.get(2)?,
+                    file_path: row.get(3)?,
+                })
+            })?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for attachment in &result.attachments {
+            record_change(
+                &tx,
+                &Change::new("attachment", &attachment.id, ChangeOp::Delete).project(&attachment.project_id),
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(result)
+    }
+}
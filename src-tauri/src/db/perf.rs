@@ -0,0 +1,190 @@
+//! Query Profiling
+//!
+//! SQLite profile 콜백으로 문장별 실행 횟수/시간을 모으고, 느린 쿼리를 기록합니다.
+//! - rusqlite의 profile 콜백은 fn 포인터만 받으므로 통계는 프로세스 전역으로 보관합니다.
+//! - 현장에서 `db_perf_stats`로 반복 쿼리/느린 쿼리 같은 패턴을 확인하는 용도입니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// 이보다 오래 걸린 문장은 느린 쿼리로 기록
+pub const SLOW_QUERY_THRESHOLD: Duration = Duration::from_millis(200);
+
+/// 보관할 느린 쿼리 수 (오래된 것부터 버림)
+const MAX_SLOW_QUERIES: usize = 50;
+
+/// 따로 집계할 문장 수 (넘으면 `OTHER_STATEMENTS`로 합침)
+const MAX_TRACKED_STATEMENTS: usize = 500;
+
+const OTHER_STATEMENTS: &str = "<other>";
+
+/// 통계에 남길 SQL 최대 길이
+const MAX_SQL_LEN: usize = 300;
+
+#[derive(Default)]
+struct StatementCounter {
+    count: u64,
+    total: Duration,
+    max: Duration,
+}
+
+struct PerfState {
+    since: i64,
+    statements: HashMap<String, StatementCounter>,
+    slow: VecDeque<SlowQuery>,
+}
+
+impl PerfState {
+    fn new() -> Self {
+        Self {
+            since: chrono::Utc::now().timestamp_millis(),
+            statements: HashMap::new(),
+            slow: VecDeque::new(),
+        }
+    }
+}
+
+static PERF: Lazy<Mutex<PerfState>> = Lazy::new(|| Mutex::new(PerfState::new()));
+
+/// 문장별 통계
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementStats {
+    pub sql: String,
+    pub count: u64,
+    pub total_ms: f64,
+    pub avg_ms: f64,
+    pub max_ms: f64,
+}
+
+/// 느린 쿼리 기록
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowQuery {
+    pub sql: String,
+    pub duration_ms: f64,
+    /// 기록 시각 (Unix ms)
+    pub at: i64,
+}
+
+/// DB 성능 통계
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbPerfStats {
+    /// 집계 시작 시각 (앱 시작 또는 마지막 초기화, Unix ms)
+    pub since: i64,
+    pub total_queries: u64,
+    pub total_ms: f64,
+    pub slow_threshold_ms: u64,
+    /// 총 소요 시간이 긴 순
+    pub statements: Vec<StatementStats>,
+    /// 최근 것부터
+    pub slow_queries: Vec<SlowQuery>,
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// 공백을 정리하고 길이를 제한한 SQL (같은 문장을 하나로 묶기 위한 키)
+fn normalize_sql(sql: &str) -> String {
+    let collapsed = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    match collapsed.char_indices().nth(MAX_SQL_LEN) {
+        Some((idx, _)) => format!("{}…", &collapsed[..idx]),
+        None => collapsed,
+    }
+}
+
+/// `Connection::profile`에 등록하는 콜백
+pub(super) fn record_statement(sql: &str, elapsed: Duration) {
+    let sql = normalize_sql(sql);
+    if elapsed >= SLOW_QUERY_THRESHOLD {
+        eprintln!("[DB] Slow query ({:.1} ms): {}", ms(elapsed), sql);
+    }
+    let Ok(mut state) = PERF.lock() else {
+        return;
+    };
+    if elapsed >= SLOW_QUERY_THRESHOLD {
+        if state.slow.len() >= MAX_SLOW_QUERIES {
+            state.slow.pop_front();
+        }
+        state.slow.push_back(SlowQuery {
+            sql: sql.clone(),
+            duration_ms: ms(elapsed),
+            at: chrono::Utc::now().timestamp_millis(),
+        });
+    }
+    let key = if state.statements.contains_key(&sql) || state.statements.len() < MAX_TRACKED_STATEMENTS {
+        sql
+    } else {
+        OTHER_STATEMENTS.to_string()
+    };
+    let counter = state.statements.entry(key).or_default();
+    counter.count += 1;
+    counter.total += elapsed;
+    counter.max = counter.max.max(elapsed);
+}
+
+/// 현재 통계 (총 소요 시간 상위 `limit`개 문장)
+pub fn perf_stats(limit: usize) -> DbPerfStats {
+    let Ok(state) = PERF.lock() else {
+        return DbPerfStats {
+            since: 0,
+            total_queries: 0,
+            total_ms: 0.0,
+            slow_threshold_ms: SLOW_QUERY_THRESHOLD.as_millis() as u64,
+            statements: Vec::new(),
+            slow_queries: Vec::new(),
+        };
+    };
+    let mut statements: Vec<StatementStats> = state
+        .statements
+        .iter()
+        .map(|(sql, c)| StatementStats {
+            sql: sql.clone(),
+            count: c.count,
+            total_ms: ms(c.total),
+            avg_ms: ms(c.total) / c.count.max(1) as f64,
+            max_ms: ms(c.max),
+        })
+        .collect();
+    let total_queries = statements.iter().map(|s| s.count).sum();
+    let total_ms = statements.iter().map(|s| s.total_ms).sum();
+    statements.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+    statements.truncate(limit);
+
+    DbPerfStats {
+        since: state.since,
+        total_queries,
+        total_ms,
+        slow_threshold_ms: SLOW_QUERY_THRESHOLD.as_millis() as u64,
+        statements,
+        slow_queries: state.slow.iter().rev().cloned().collect(),
+    }
+}
+
+/// 통계 초기화
+pub fn reset_perf_stats() {
+    if let Ok(mut state) = PERF.lock() {
+        *state = PerfState::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_whitespace_and_truncates() {
+        assert_eq!(
+            normalize_sql("SELECT id\n             FROM blocks  WHERE id = ?1"),
+            "SELECT id FROM blocks WHERE id = ?1"
+        );
+        let long = "x".repeat(MAX_SQL_LEN + 10);
+        assert_eq!(normalize_sql(&long).chars().count(), MAX_SQL_LEN + 1);
+    }
+}
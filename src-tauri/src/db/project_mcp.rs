@@ -0,0 +1,123 @@
+//! Project MCP Server Bindings
+//!
+//! 프로젝트별로 사용할 MCP 서버(`mcp_servers` 행)를 지정합니다.
+//! - 연결된 서버가 하나도 없으면 모든 서버를 사용합니다 (기존 동작).
+//! - 프로젝트/서버 삭제 시 함께 정리합니다.
+//! - 지원 서버(Atlassian, Notion)의 기본 행은 서버 목록을 처음 읽을 때 만듭니다.
+
+use std::collections::HashSet;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::{Database, McpServerRow};
+use crate::error::IteError;
+
+impl Database {
+    /// 지원 서버 종류마다 `mcp_servers` 행이 없으면 기본 행 생성 (id = 서버 종류)
+    /// - 프로젝트 MCP 서버 연결은 이 행을 가리킵니다.
+    pub fn ensure_builtin_mcp_servers(&self, servers: &[(&str, &str)]) -> Result<(), IteError> {
+        for (server_type, name) in servers {
+            let exists: bool = self.conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM mcp_servers WHERE server_type = ?1)",
+                [server_type],
+                |row| row.get(0),
+            )?;
+            if exists {
+                continue;
+            }
+            let now = chrono::Utc::now().timestamp_millis();
+            self.save_mcp_server(&McpServerRow {
+                id: server_type.to_string(),
+                name: name.to_string(),
+                server_type: server_type.to_string(),
+                config_json: "{}".to_string(),
+                is_enabled: true,
+                created_at: now,
+                updated_at: now,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 프로젝트에 연결된 MCP 서버 목록 (등록 순서)
+    pub fn get_project_mcp_servers(&self, project_id: &str) -> Result<Vec<McpServerRow>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.id, s.name, s.server_type, s.config_json, s.is_enabled, s.created_at, s.updated_at
+             FROM project_mcp_servers p
+             JOIN mcp_servers s ON s.id = p.server_id
+             WHERE p.project_id = ?1
+             ORDER BY s.created_at ASC",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            let is_enabled: i64 = row.get(4)?;
+            Ok(McpServerRow {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                server_type: row.get(2)?,
+                config_json: row.get(3)?,
+                is_enabled: is_enabled == 1,
+                created_at: row.get(5)?,
+                updated_at: row.get(6)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 프로젝트의 MCP 서버 연결을 통째로 교체 (빈 목록이면 연결 해제 = 모든 서버 사용)
+    pub fn set_project_mcp_servers(
+        &self,
+        project_id: &str,
+        server_ids: &[String],
+    ) -> Result<Vec<McpServerRow>, IteError> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?1)",
+            [project_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
+        }
+        self.ensure_project_writable(project_id)?;
+
+        let mut seen = HashSet::new();
+        let server_ids: Vec<&str> = server_ids
+            .iter()
+            .map(|id| id.trim())
+            .filter(|id| seen.insert(*id))
+            .collect();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let tx = self.conn.unchecked_transaction()?;
+        for server_id in &server_ids {
+            let known: bool = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM mcp_servers WHERE id = ?1)",
+                [server_id],
+                |row| row.get(0),
+            )?;
+            if !known {
+                return Err(IteError::InvalidOperation(format!("MCP server not found: {}", server_id)));
+            }
+        }
+        tx.execute("DELETE FROM project_mcp_servers WHERE project_id = ?1", [project_id])?;
+        for server_id in &server_ids {
+            tx.execute(
+                "INSERT INTO project_mcp_servers (project_id, server_id, created_at) VALUES (?1, ?2, ?3)",
+                (project_id, server_id, now),
+            )?;
+        }
+        let payload = serde_json::to_string(&server_ids)?;
+        record_change(
+            &tx,
+            &Change::new("project_mcp_servers", project_id, ChangeOp::Update)
+                .project(project_id)
+                .payload(&payload),
+        )?;
+        tx.commit()?;
+
+        self.get_project_mcp_servers(project_id)
+    }
+}
@@ -0,0 +1,264 @@
+//! Project Template Storage
+//!
+//! 프로젝트 템플릿(고객사별 설정 묶음) 저장소
+//! - 템플릿 전체를 JSON 한 덩어리로 보관합니다. 프로젝트를 지워도 템플릿은 남습니다.
+//! - 이름은 대소문자 무시 고유값이며, 같은 이름으로 다시 저장하면 덮어씁니다 (id/created_at 유지).
+
+use rusqlite::OptionalExtension;
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::{Database, NewGlossaryTerm};
+use crate::error::IteError;
+use crate::models::{
+    DntTerm, ProjectTemplate, PromptTemplate, TemplateDntTerm, TemplateGlossaryTerm, TemplatePrompt,
+};
+
+/// 템플릿 이름 최대 길이 (문자 수)
+pub const MAX_TEMPLATE_NAME_LEN: usize = 100;
+
+/// 템플릿 목록 항목 (용어집 등 본문 제외)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplateSummary {
+    pub id: String,
+    pub name: String,
+    pub domain: String,
+    pub target_language: Option<String>,
+    pub glossary_terms: usize,
+    pub dnt_terms: usize,
+    pub prompt_templates: usize,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<&ProjectTemplate> for ProjectTemplateSummary {
+    fn from(template: &ProjectTemplate) -> Self {
+        Self {
+            id: template.id.clone(),
+            name: template.name.clone(),
+            domain: template.domain.clone(),
+            target_language: template.target_language.clone(),
+            glossary_terms: template.glossary.len(),
+            dnt_terms: template.dnt_terms.len(),
+            prompt_templates: template.prompt_templates.len(),
+            created_at: template.created_at,
+            updated_at: template.updated_at,
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<&str, IteError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(IteError::InvalidOperation("Template name must not be empty".to_string()));
+    }
+    if name.chars().count() > MAX_TEMPLATE_NAME_LEN {
+        return Err(IteError::InvalidOperation(format!(
+            "Template name is too long (max {} characters)",
+            MAX_TEMPLATE_NAME_LEN
+        )));
+    }
+    Ok(name)
+}
+
+impl Database {
+    /// 프로젝트의 현재 설정으로 템플릿 구성 (저장하지 않음)
+    /// - 메타데이터(도메인/번역 언어/번역가/설정), 채팅 설정, 프로젝트 전용 용어집/DNT/프롬프트 템플릿,
+    ///   사용자 정의 필드를 담습니다.
+    pub fn capture_project_template(&self, project_id: &str, name: &str) -> Result<ProjectTemplate, IteError> {
+        let name = validate_name(name)?;
+        let metadata = self.load_project_metadata(project_id)?;
+        let now = chrono::Utc::now().timestamp_millis();
+
+        let glossary = self
+            .list_project_glossary_entries(project_id)?
+            .into_iter()
+            .map(|e| TemplateGlossaryTerm {
+                source: e.source,
+                target: e.target,
+                notes: e.notes,
+                domain: e.domain,
+                case_sensitive: e.case_sensitive,
+            })
+            .collect();
+        let dnt_terms = self
+            .list_dnt_terms(Some(project_id))?
+            .into_iter()
+            .filter(|t| t.project_id.is_some())
+            .map(|t| TemplateDntTerm {
+                term: t.term,
+                is_regex: t.is_regex,
+                case_sensitive: t.case_sensitive,
+                notes: t.notes,
+            })
+            .collect();
+        let prompt_templates = self
+            .list_prompt_templates(Some(project_id))?
+            .into_iter()
+            .filter(|t| t.project_id.is_some())
+            .map(|t| TemplatePrompt {
+                name: t.name,
+                body: t.body,
+                variables: t.variables,
+            })
+            .collect();
+
+        Ok(ProjectTemplate {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            domain: metadata.domain,
+            target_language: metadata.target_language,
+            author: metadata.author,
+            settings: metadata.settings,
+            chat_settings: self.load_chat_project_settings(project_id)?,
+            glossary,
+            dnt_terms,
+            prompt_templates,
+            custom_fields: self.get_project_custom_fields(project_id)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 템플릿 저장 (같은 이름이 있으면 덮어씀)
+    /// - 저장된 최종 상태를 반환합니다.
+    pub fn save_project_template(&self, template: &ProjectTemplate) -> Result<ProjectTemplate, IteError> {
+        let mut template = template.clone();
+        template.name = validate_name(&template.name)?.to_string();
+
+        let tx = self.conn.unchecked_transaction()?;
+        let existing: Option<(String, i64)> = tx
+            .query_row(
+                "SELECT id, created_at FROM project_templates WHERE name = ?1",
+                [&template.name],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        if let Some((id, created_at)) = existing {
+            template.id = id;
+            template.created_at = created_at;
+        }
+
+        let template_json = serde_json::to_string(&template)?;
+        tx.execute(
+            "INSERT INTO project_templates (id, name, template_json, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                template_json = excluded.template_json,
+                updated_at = excluded.updated_at",
+            (
+                &template.id,
+                &template.name,
+                &template_json,
+                template.created_at,
+                template.updated_at,
+            ),
+        )?;
+        record_change(
+            &tx,
+            &Change::new("project_template", &template.id, ChangeOp::Upsert).payload(&template_json),
+        )?;
+        tx.commit()?;
+        Ok(template)
+    }
+
+    /// 템플릿 목록 (이름 순)
+    pub fn list_project_templates(&self) -> Result<Vec<ProjectTemplateSummary>, IteError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT template_json FROM project_templates ORDER BY name COLLATE NOCASE")?;
+        let iter = stmt.query_map([], |row| row.get::<_, String>(0))?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            let template: ProjectTemplate = serde_json::from_str(&r?)?;
+            out.push(ProjectTemplateSummary::from(&template));
+        }
+        Ok(out)
+    }
+
+    /// 템플릿 단건 조회
+    pub fn get_project_template(&self, id: &str) -> Result<Option<ProjectTemplate>, IteError> {
+        let template_json: Option<String> = self
+            .conn
+            .query_row("SELECT template_json FROM project_templates WHERE id = ?1", [id], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        template_json
+            .map(|json| serde_json::from_str(&json).map_err(IteError::from))
+            .transpose()
+    }
+
+    /// 템플릿 삭제
+    pub fn delete_project_template(&self, id: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let deleted = tx.execute("DELETE FROM project_templates WHERE id = ?1", [id])?;
+        if deleted > 0 {
+            record_change(&tx, &Change::new("project_template", id, ChangeOp::Delete))?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 템플릿의 용어집/DNT/프롬프트 템플릿/채팅 설정/사용자 정의 필드를 프로젝트에 추가
+    /// - 프로젝트 메타데이터(도메인/설정 등)는 호출자가 프로젝트를 만들 때 채웁니다.
+    pub fn apply_project_template(&self, project_id: &str, template: &ProjectTemplate) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let now = chrono::Utc::now().timestamp_millis();
+
+        let glossary: Vec<NewGlossaryTerm> = template
+            .glossary
+            .iter()
+            .map(|g| NewGlossaryTerm {
+                source: g.source.clone(),
+                target: g.target.clone(),
+                notes: g.notes.clone(),
+                domain: g.domain.clone(),
+                case_sensitive: g.case_sensitive,
+                origin_key: None,
+            })
+            .collect();
+        if !glossary.is_empty() {
+            self.upsert_project_glossary_entries(project_id, &glossary)?;
+        }
+
+        for t in &template.dnt_terms {
+            self.save_dnt_term(&DntTerm {
+                id: uuid::Uuid::new_v4().to_string(),
+                project_id: Some(project_id.to_string()),
+                term: t.term.clone(),
+                is_regex: t.is_regex,
+                case_sensitive: t.case_sensitive,
+                notes: t.notes.clone(),
+                created_at: now,
+                updated_at: now,
+            })?;
+        }
+
+        for p in &template.prompt_templates {
+            self.save_prompt_template(&PromptTemplate {
+                id: uuid::Uuid::new_v4().to_string(),
+                project_id: Some(project_id.to_string()),
+                name: p.name.clone(),
+                body: p.body.clone(),
+                variables: p.variables.clone(),
+                scope: "project".to_string(),
+                version: 1,
+                created_at: now,
+                updated_at: now,
+            })?;
+        }
+
+        if let Some(settings_json) = &template.chat_settings {
+            self.save_chat_project_settings(project_id, settings_json, now)?;
+        }
+
+        for (key, value) in &template.custom_fields {
+            self.set_project_custom_field(project_id, key, Some(value))?;
+        }
+        Ok(())
+    }
+}
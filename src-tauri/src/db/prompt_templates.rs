@@ -0,0 +1,118 @@
+//! Prompt Template Storage
+//!
+//! 프롬프트 템플릿(번역가 페르소나/번역 규칙 프리셋) 저장소
+
+use rusqlite::OptionalExtension;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::PromptTemplate;
+
+const SELECT_COLUMNS: &str =
+    "SELECT id, project_id, name, body, variables_json, version, created_at, updated_at FROM prompt_templates";
+
+fn row_to_template(row: &rusqlite::Row) -> rusqlite::Result<PromptTemplate> {
+    let project_id: Option<String> = row.get(1)?;
+    let variables_json: String = row.get(4)?;
+    Ok(PromptTemplate {
+        id: row.get(0)?,
+        scope: if project_id.is_some() { "project" } else { "global" }.to_string(),
+        project_id,
+        name: row.get(2)?,
+        body: row.get(3)?,
+        variables: serde_json::from_str(&variables_json).unwrap_or_default(),
+        version: row.get(5)?,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+    })
+}
+
+impl Database {
+    /// 프롬프트 템플릿 목록 조회
+    /// - project_id가 주어지면 전역 템플릿 + 해당 프로젝트 템플릿을 함께 반환합니다.
+    /// - None이면 전역 템플릿만 반환합니다.
+    pub fn list_prompt_templates(
+        &self,
+        project_id: Option<&str>,
+    ) -> Result<Vec<PromptTemplate>, IteError> {
+        let sql = format!(
+            "{} WHERE project_id IS NULL OR project_id = ?1 ORDER BY project_id IS NULL, name COLLATE NOCASE",
+            SELECT_COLUMNS
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let iter = stmt.query_map([project_id], row_to_template)?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 프롬프트 템플릿 단건 조회
+    pub fn get_prompt_template(&self, id: &str) -> Result<Option<PromptTemplate>, IteError> {
+        let sql = format!("{} WHERE id = ?1", SELECT_COLUMNS);
+        let template = self
+            .conn
+            .query_row(&sql, [id], row_to_template)
+            .optional()?;
+        Ok(template)
+    }
+
+    /// 프롬프트 템플릿 저장 (Insert or Update)
+    /// - 기존 템플릿을 덮어쓰면 version이 1 증가하고 created_at은 유지됩니다.
+    /// - 저장된 최종 상태를 반환합니다.
+    pub fn save_prompt_template(&self, template: &PromptTemplate) -> Result<PromptTemplate, IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO prompt_templates (
+                id, project_id, name, body, variables_json, version, created_at, updated_at
+             ) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7)
+             ON CONFLICT(id) DO UPDATE SET
+                project_id = excluded.project_id,
+                name = excluded.name,
+                body = excluded.body,
+                variables_json = excluded.variables_json,
+                version = prompt_templates.version + 1,
+                updated_at = excluded.updated_at",
+            (
+                &template.id,
+                &template.project_id,
+                &template.name,
+                &template.body,
+                serde_json::to_string(&template.variables)?,
+                template.created_at,
+                template.updated_at,
+            ),
+        )?;
+        let payload = serde_json::to_string(template)?;
+        let mut change = Change::new("prompt_template", &template.id, ChangeOp::Upsert).payload(&payload);
+        if let Some(project_id) = &template.project_id {
+            change = change.project(project_id);
+        }
+        record_change(&tx, &change)?;
+        tx.commit()?;
+
+        self.get_prompt_template(&template.id)?.ok_or_else(|| {
+            IteError::InvalidOperation(format!("Prompt template not found after save: {}", template.id))
+        })
+    }
+
+    /// 프롬프트 템플릿 삭제
+    pub fn delete_prompt_template(&self, id: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let deleted: Option<Option<String>> = tx
+            .query_row("DELETE FROM prompt_templates WHERE id = ?1 RETURNING project_id", [id], |row| row.get(0))
+            .optional()?;
+        if let Some(project_id) = deleted {
+            let mut change = Change::new("prompt_template", id, ChangeOp::Delete);
+            if let Some(project_id) = &project_id {
+                change = change.project(project_id);
+            }
+            record_change(&tx, &change)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
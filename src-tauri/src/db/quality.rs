@@ -0,0 +1,75 @@
+//! Segment Quality Estimates
+//!
+//! `estimate_quality`로 계산한 세그먼트별 MT 신뢰도 저장/조회.
+//! - 프로젝트 추정을 다시 돌리면 이전 결과를 통째로 바꿉니다.
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::qa::estimate::{QualitySignal, SegmentQuality};
+
+impl Database {
+    /// 프로젝트의 품질 추정 결과 교체
+    pub fn replace_quality_estimates(&self, project_id: &str, estimates: &[SegmentQuality]) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM segment_quality WHERE project_id = ?1", [project_id])?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO segment_quality (project_id, segment_id, score, signals_json, target_hash, estimated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for estimate in estimates {
+                stmt.execute((
+                    project_id,
+                    &estimate.segment_id,
+                    estimate.score,
+                    serde_json::to_string(&estimate.signals)?,
+                    &estimate.target_hash,
+                    estimate.estimated_at,
+                ))?;
+            }
+        }
+        record_change(
+            &tx,
+            &Change::new("project", project_id, ChangeOp::Update)
+                .project(project_id)
+                .payload("quality-estimated"),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 저장된 품질 추정 결과 (점수 낮은 순, 지금 없는 세그먼트는 제외)
+    /// - `max_score`가 주어지면 그 이하만
+    pub fn list_quality_estimates(
+        &self,
+        project_id: &str,
+        max_score: Option<f64>,
+    ) -> Result<Vec<SegmentQuality>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT q.segment_id, q.score, q.signals_json, q.target_hash, q.estimated_at
+             FROM segment_quality q
+             JOIN segments s ON s.id = q.segment_id AND s.project_id = q.project_id
+             WHERE q.project_id = ?1 AND (?2 IS NULL OR q.score <= ?2)
+             ORDER BY q.score ASC, s.segment_order ASC",
+        )?;
+
+        let iter = stmt.query_map((project_id, max_score), |row| {
+            let signals_json: String = row.get(2)?;
+            Ok(SegmentQuality {
+                segment_id: row.get(0)?,
+                score: row.get(1)?,
+                signals: serde_json::from_str::<Vec<QualitySignal>>(&signals_json).unwrap_or_default(),
+                target_hash: row.get(3)?,
+                estimated_at: row.get(4)?,
+                stale: false,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,108 @@
+//! Read-only Projects
+//!
+//! 납품/공유받은 프로젝트를 읽기 전용으로 열어 실수로 고치지 않게 합니다.
+//! - 플래그는 앱 실행 중에만 유지되며, 콘텐츠를 바꾸는 DB 경로가 `ensure_project_writable`로 확인합니다.
+//! - 휴지통 이동/영구 삭제는 프로젝트 단위 관리 작업이므로 막지 않습니다.
+
+use super::Database;
+use crate::error::IteError;
+
+impl Database {
+    /// 프로젝트 읽기 전용 설정/해제 (상태가 바뀌었으면 true)
+    pub fn set_project_read_only(&self, project_id: &str, read_only: bool) -> Result<bool, IteError> {
+        if read_only {
+            let exists: bool = self.conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?1)",
+                [project_id],
+                |row| row.get(0),
+            )?;
+            if !exists {
+                return Err(IteError::ProjectNotFound(project_id.to_string()));
+            }
+        }
+        let mut projects = self
+            .read_only_projects
+            .lock()
+            .map_err(|e| IteError::InvalidOperation(format!("Failed to acquire read-only lock: {}", e)))?;
+        Ok(if read_only {
+            projects.insert(project_id.to_string())
+        } else {
+            projects.remove(project_id)
+        })
+    }
+
+    /// 읽기 전용으로 열린 프로젝트인지
+    pub fn is_project_read_only(&self, project_id: &str) -> bool {
+        self.read_only_projects
+            .lock()
+            .map(|projects| projects.contains(project_id))
+            .unwrap_or(false)
+    }
+
+    /// 읽기 전용 프로젝트면 `ReadOnlyProject` 오류
+    pub(crate) fn ensure_project_writable(&self, project_id: &str) -> Result<(), IteError> {
+        if self.is_project_read_only(project_id) {
+            return Err(IteError::ReadOnlyProject(project_id.to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn read_only_project_rejects_xliff_status_change() {
+        let db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db.conn
+            .execute_batch(
+                "INSERT INTO projects (id, version, metadata_json, created_at, updated_at)
+                 VALUES ('p1', '1', '{}', 0, 0);
+                 INSERT INTO xliff_segments (project_id, segment_id, file_name, unit_id, mid, status, placeholders_json)
+                 VALUES ('p1', 'seg1', 'a.sdlxliff', 'u1', '1', 'Draft', '[]');",
+            )
+            .unwrap();
+        let segment_ids = vec!["seg1".to_string()];
+
+        db.set_project_read_only("p1", true).unwrap();
+        assert!(matches!(
+            db.set_xliff_segment_status("p1", &segment_ids, Some("Translated")),
+            Err(IteError::ReadOnlyProject(_))
+        ));
+        assert_eq!(db.list_xliff_segment_statuses("p1").unwrap()[0].status.as_deref(), Some("Draft"));
+
+        db.set_project_read_only("p1", false).unwrap();
+        assert_eq!(db.set_xliff_segment_status("p1", &segment_ids, Some("Translated")).unwrap(), 1);
+    }
+
+    #[test]
+    fn read_only_project_rejects_glossary_import_and_chat_save() {
+        let mut db = Database::new(Path::new(":memory:")).unwrap();
+        db.initialize().unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO projects (id, version, metadata_json, created_at, updated_at)
+                 VALUES ('p1', '1', '{}', 0, 0)",
+                [],
+            )
+            .unwrap();
+        let csv = std::env::temp_dir().join(format!("ro-glossary-{}.csv", uuid::Uuid::new_v4()));
+        std::fs::write(&csv, "source,target\nApple,사과\n").unwrap();
+        let session: crate::models::ChatSession = serde_json::from_value(serde_json::json!({
+            "id": "s1", "name": "Chat", "createdAt": 0, "messages": [], "contextBlockIds": []
+        }))
+        .unwrap();
+
+        db.set_project_read_only("p1", true).unwrap();
+        let imported = db.import_glossary_csv("p1", csv.to_str().unwrap(), false);
+        assert!(matches!(imported, Err(IteError::ReadOnlyProject(_))));
+        assert!(matches!(db.save_chat_session("p1", &session), Err(IteError::ReadOnlyProject(_))));
+
+        db.set_project_read_only("p1", false).unwrap();
+        assert_eq!(db.import_glossary_csv("p1", csv.to_str().unwrap(), false).unwrap().0, 1);
+        std::fs::remove_file(&csv).ok();
+    }
+}
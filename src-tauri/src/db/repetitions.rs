@@ -0,0 +1,260 @@
+//! Repetition Detection & Propagation
+//!
+//! 원문이 같은 세그먼트(반복)를 묶고, 확정된 번역을 나머지 반복 세그먼트에 일괄 전파합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::{IteProject, SegmentGroup};
+use crate::text::normalize_for_matching;
+
+/// 반복 그룹에 속한 세그먼트
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepetitionMember {
+    pub segment_id: String,
+    pub order: i32,
+    /// 번역문 평문 (여러 블록은 줄바꿈으로 연결)
+    pub target_text: String,
+    pub is_translated: bool,
+    /// 자동 전파 제외 여부
+    pub propagation_opt_out: bool,
+}
+
+/// 원문이 같은 세그먼트 묶음 (2개 이상)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepetitionGroup {
+    /// 정규화된 원문의 해시 (그룹 식별자)
+    pub key: String,
+    pub source_text: String,
+    pub segments: Vec<RepetitionMember>,
+}
+
+/// 전파에서 제외된 세그먼트와 사유
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationSkip {
+    pub segment_id: String,
+    /// "not_repetition" | "opted_out" | "already_translated" | "no_target_block" | "block_mismatch"
+    pub reason: String,
+}
+
+/// 번역 전파 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationResult {
+    pub updated_segment_ids: Vec<String>,
+    /// 내용을 바꾼 번역문 블록
+    pub updated_block_ids: Vec<String>,
+    pub skipped: Vec<PropagationSkip>,
+}
+
+fn join_texts(project: &IteProject, ids: &[String]) -> String {
+    ids.iter()
+        .filter_map(|id| project.blocks.get(id))
+        .map(|b| crate::text::strip_html(&b.content))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn normalized_source(project: &IteProject, segment: &SegmentGroup) -> String {
+    segment
+        .source_ids
+        .iter()
+        .filter_map(|id| project.blocks.get(id))
+        .map(|b| normalize_for_matching(&b.content))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn repetition_key(normalized: &str) -> String {
+    format!("{:x}", md5::compute(normalized.as_bytes()))
+}
+
+impl Database {
+    /// 자동 전파 제외 세그먼트 ID 목록
+    fn load_propagation_opt_outs(&self, project_id: &str) -> Result<HashSet<String>, IteError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT segment_id FROM segment_propagation_opt_outs WHERE project_id = ?1")?;
+        let iter = stmt.query_map([project_id], |row| row.get::<_, String>(0))?;
+
+        let mut out = HashSet::new();
+        for r in iter {
+            out.insert(r?);
+        }
+        Ok(out)
+    }
+
+    /// 세그먼트의 자동 전파 제외 여부 설정
+    pub fn set_segment_propagation_opt_out(
+        &self,
+        project_id: &str,
+        segment_id: &str,
+        opt_out: bool,
+    ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let changed = if opt_out {
+            tx.execute(
+                "INSERT OR IGNORE INTO segment_propagation_opt_outs (project_id, segment_id, created_at)
+                 VALUES (?1, ?2, ?3)",
+                (project_id, segment_id, chrono::Utc::now().timestamp_millis()),
+            )?
+        } else {
+            tx.execute(
+                "DELETE FROM segment_propagation_opt_outs WHERE project_id = ?1 AND segment_id = ?2",
+                [project_id, segment_id],
+            )?
+        };
+        if changed > 0 {
+            let op = if opt_out { ChangeOp::Insert } else { ChangeOp::Delete };
+            record_change(
+                &tx,
+                &Change::new("propagation_opt_out", segment_id, op).project(project_id),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 원문이 같은 세그먼트를 그룹으로 묶어 반환 (문서 순서 기준, 빈 원문 제외)
+    pub fn find_repetitions(&self, project_id: &str) -> Result<Vec<RepetitionGroup>, IteError> {
+        let project = self.load_project(project_id)?;
+        let opt_outs = self.load_propagation_opt_outs(project_id)?;
+
+        let mut order: Vec<String> = Vec::new();
+        let mut groups: HashMap<String, (String, Vec<RepetitionMember>)> = HashMap::new();
+
+        for segment in &project.segments {
+            let normalized = normalized_source(&project, segment);
+            if normalized.is_empty() {
+                continue;
+            }
+            let target_text = join_texts(&project, &segment.target_ids);
+            let member = RepetitionMember {
+                segment_id: segment.group_id.clone(),
+                order: segment.order,
+                is_translated: !target_text.trim().is_empty(),
+                target_text,
+                propagation_opt_out: opt_outs.contains(&segment.group_id),
+            };
+
+            let source_text = join_texts(&project, &segment.source_ids);
+            groups
+                .entry(normalized.clone())
+                .or_insert_with(|| {
+                    order.push(normalized);
+                    (source_text, Vec::new())
+                })
+                .1
+                .push(member);
+        }
+
+        Ok(order
+            .into_iter()
+            .filter_map(|normalized| {
+                let (source_text, segments) = groups.remove(&normalized)?;
+                (segments.len() > 1).then(|| RepetitionGroup {
+                    key: repetition_key(&normalized),
+                    source_text,
+                    segments,
+                })
+            })
+            .collect())
+    }
+
+    /// 기준 세그먼트의 번역을 같은 원문을 가진 세그먼트에 복사 (단일 트랜잭션)
+    /// - segment_ids가 None이면 그룹 전체가 대상입니다.
+    /// - 전파 제외 세그먼트는 건너뛰며, overwrite=false면 이미 번역된 세그먼트도 건너뜁니다.
+    /// - 번역문 블록 수가 같을 때만 블록 단위로 복사합니다.
+    pub fn propagate_translation(
+        &self,
+        project_id: &str,
+        source_segment_id: &str,
+        segment_ids: Option<&[String]>,
+        overwrite: bool,
+    ) -> Result<PropagationResult, IteError> {
+        let project = self.load_project(project_id)?;
+        let opt_outs = self.load_propagation_opt_outs(project_id)?;
+
+        let donor = project
+            .segments
+            .iter()
+            .find(|s| s.group_id == source_segment_id)
+            .ok_or_else(|| IteError::InvalidOperation(format!("Segment not found: {}", source_segment_id)))?;
+        if join_texts(&project, &donor.target_ids).trim().is_empty() {
+            return Err(IteError::InvalidOperation(
+                "Source segment has no translation to propagate".to_string(),
+            ));
+        }
+        let donor_key = normalized_source(&project, donor);
+
+        let group: Vec<&SegmentGroup> = project
+            .segments
+            .iter()
+            .filter(|s| s.group_id != donor.group_id && normalized_source(&project, s) == donor_key)
+            .collect();
+
+        let mut skipped: Vec<PropagationSkip> = Vec::new();
+        let skip = |segment_id: &str, reason: &str| PropagationSkip {
+            segment_id: segment_id.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let targets: Vec<&SegmentGroup> = match segment_ids {
+            Some(ids) => {
+                for id in ids {
+                    if !group.iter().any(|s| &s.group_id == id) {
+                        skipped.push(skip(id, "not_repetition"));
+                    }
+                }
+                group.into_iter().filter(|s| ids.contains(&s.group_id)).collect()
+            }
+            None => group,
+        };
+
+        let mut updated_segment_ids = Vec::new();
+        let mut updates: Vec<(String, String)> = Vec::new();
+
+        for segment in targets {
+            if opt_outs.contains(&segment.group_id) {
+                skipped.push(skip(&segment.group_id, "opted_out"));
+                continue;
+            }
+            if segment.target_ids.is_empty() {
+                skipped.push(skip(&segment.group_id, "no_target_block"));
+                continue;
+            }
+            if !overwrite && !join_texts(&project, &segment.target_ids).trim().is_empty() {
+                skipped.push(skip(&segment.group_id, "already_translated"));
+                continue;
+            }
+            if segment.target_ids.len() != donor.target_ids.len() {
+                skipped.push(skip(&segment.group_id, "block_mismatch"));
+                continue;
+            }
+
+            for (from_id, to_id) in donor.target_ids.iter().zip(&segment.target_ids) {
+                if let Some(from) = project.blocks.get(from_id) {
+                    updates.push((to_id.clone(), from.content.clone()));
+                }
+            }
+            updated_segment_ids.push(segment.group_id.clone());
+        }
+
+        // 모든 블록을 한 트랜잭션으로 갱신 (편집 기록 포함)
+        self.update_block_contents(project_id, &updates)?;
+
+        Ok(PropagationResult {
+            updated_segment_ids,
+            updated_block_ids: updates.into_iter().map(|(id, _)| id).collect(),
+            skipped,
+        })
+    }
+}
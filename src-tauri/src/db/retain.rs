@@ -0,0 +1,50 @@
+//! Project Subset Export
+//!
+//! DB 스냅샷 사본에서 선택한 프로젝트만 남기기 (고객사별 전달용 .ite)
+//! - 다른 프로젝트의 블록/히스토리/채팅/용어집/TM/첨부 행은 `delete_project`로 지웁니다.
+//! - 모든 프로젝트 번역이 섞인 전역 TM과 변경 피드, 로컬 사용 지표도 함께 지웁니다.
+//! - 프로젝트 템플릿, MCP 서버 설정, 앱 설정은 보내는 사람의 환경이라 지웁니다 (서버 주소/개인 설정 유출 방지).
+//! - 전역 용어집/DNT/프롬프트 템플릿은 사용자 자료라 유지합니다.
+//! - 지운 내용이 빈 페이지에 남지 않도록 마지막에 VACUUM 합니다.
+
+use super::Database;
+use crate::error::IteError;
+
+impl Database {
+    /// `keep`에 없는 프로젝트(휴지통 포함)를 모두 지우고 지운 프로젝트 수를 반환
+    /// - 내보내기용 사본에만 사용하세요. 현재 DB에서 호출하면 다른 프로젝트가 영구 삭제됩니다.
+    pub fn retain_projects(&self, keep: &[String]) -> Result<usize, IteError> {
+        let keep_json = serde_json::to_string(keep)?;
+        let others: Vec<String> = {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT id FROM projects WHERE id NOT IN (SELECT value FROM json_each(?1))")?;
+            let rows = stmt.query_map([&keep_json], |row| row.get(0))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for project_id in &others {
+            self.delete_project(project_id)?;
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM tm_units WHERE project_id IS NULL", [])?;
+        tx.execute("DELETE FROM usage_metrics", [])?;
+        tx.execute("DELETE FROM project_templates", [])?;
+        tx.execute("DELETE FROM project_mcp_servers", [])?;
+        tx.execute("DELETE FROM mcp_servers", [])?;
+        tx.execute("DELETE FROM app_settings", [])?;
+        tx.execute(
+            "DELETE FROM changes WHERE project_id IS NULL OR project_id NOT IN (SELECT value FROM json_each(?1))",
+            [&keep_json],
+        )?;
+        tx.execute(
+            "DELETE FROM attachments WHERE project_id NOT IN (SELECT value FROM json_each(?1))",
+            [&keep_json],
+        )?;
+        tx.commit()?;
+
+        self.optimize_fts()?;
+        self.vacuum()?;
+        Ok(others.len())
+    }
+}
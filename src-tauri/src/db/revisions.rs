@@ -0,0 +1,265 @@
+//! Revision (Track Changes) Storage
+//!
+//! 리뷰어가 번역문 블록을 수정하면 수정 전/후 콘텐츠와 단어 단위 diff를 기록하고,
+//! 수락(accept)/거절(reject)로 변경을 확정하거나 되돌립니다.
+//! - 블록당 검토 대기(pending) 수정은 하나만 유지하며, 이어진 수정은 같은 항목에 합쳐집니다.
+
+use rusqlite::{Connection, OptionalExtension, Row};
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::{write_block_contents, Database};
+use crate::error::IteError;
+use crate::models::Revision;
+use crate::text::diff::diff_words;
+use crate::text::strip_html;
+
+const REVISION_COLUMNS: &str = "id, project_id, block_id, author, status, base_content, revised_content, diff_json,
+     created_at, updated_at, resolved_at";
+
+fn row_to_revision(row: &Row) -> rusqlite::Result<Revision> {
+    let diff_json: String = row.get(7)?;
+    Ok(Revision {
+        id: row.get(0)?,
+        project_id: row.get(1)?,
+        block_id: row.get(2)?,
+        author: row.get(3)?,
+        status: row.get(4)?,
+        base_content: row.get(5)?,
+        revised_content: row.get(6)?,
+        diff: serde_json::from_str(&diff_json).unwrap_or_default(),
+        created_at: row.get(8)?,
+        updated_at: row.get(9)?,
+        resolved_at: row.get(10)?,
+    })
+}
+
+fn get_revision(conn: &Connection, id: &str) -> Result<Revision, IteError> {
+    conn.query_row(
+        &format!("SELECT {} FROM revisions WHERE id = ?1", REVISION_COLUMNS),
+        [id],
+        row_to_revision,
+    )
+    .optional()?
+    .ok_or_else(|| IteError::InvalidOperation(format!("Revision not found: {}", id)))
+}
+
+fn pending_revision(conn: &Connection, id: &str) -> Result<Revision, IteError> {
+    let revision = get_revision(conn, id)?;
+    if revision.status != "pending" {
+        return Err(IteError::InvalidOperation(format!(
+            "Revision already {}: {}",
+            revision.status, id
+        )));
+    }
+    Ok(revision)
+}
+
+fn diff_json(base: &str, revised: &str) -> Result<String, IteError> {
+    Ok(serde_json::to_string(&diff_words(&strip_html(base), &strip_html(revised)))?)
+}
+
+impl Database {
+    /// 리뷰어 수정 기록 + 블록에 반영
+    /// - 같은 블록에 검토 대기 수정이 있으면 그 항목을 갱신합니다(기준 콘텐츠 유지).
+    /// - 수정 결과가 기준 콘텐츠와 같아지면 항목을 삭제하고 None을 반환합니다.
+    /// - `author`가 없으면 설정된 로컬 사용자로 기록합니다.
+    pub fn record_revision(
+        &self,
+        project_id: &str,
+        block_id: &str,
+        revised_content: &str,
+        author: Option<&str>,
+    ) -> Result<Option<Revision>, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let local_author = match author {
+            Some(_) => None,
+            None => self.local_author()?,
+        };
+        let author = author.or(local_author.as_deref());
+        let tx = self.conn.unchecked_transaction()?;
+
+        let (block_type, current): (String, String) = tx
+            .query_row(
+                "SELECT block_type, content FROM blocks WHERE id = ?1 AND project_id = ?2",
+                [block_id, project_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?
+            .ok_or_else(|| IteError::BlockNotFound(block_id.to_string()))?;
+        if block_type != "target" {
+            return Err(IteError::InvalidOperation(
+                "Only target blocks can be revised".to_string(),
+            ));
+        }
+
+        let existing: Option<Revision> = tx
+            .query_row(
+                &format!(
+                    "SELECT {} FROM revisions WHERE project_id = ?1 AND block_id = ?2 AND status = 'pending'",
+                    REVISION_COLUMNS
+                ),
+                [project_id, block_id],
+                row_to_revision,
+            )
+            .optional()?;
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let revision_id = match existing {
+            Some(rev) if rev.base_content == revised_content => {
+                tx.execute("DELETE FROM revisions WHERE id = ?1", [&rev.id])?;
+                record_change(
+                    &tx,
+                    &Change::new("revision", &rev.id, ChangeOp::Delete)
+                        .project(project_id)
+                        .actor(author),
+                )?;
+                None
+            }
+            Some(rev) => {
+                tx.execute(
+                    "UPDATE revisions SET revised_content = ?1, diff_json = ?2, author = COALESCE(?3, author), updated_at = ?4
+                     WHERE id = ?5",
+                    (
+                        revised_content,
+                        diff_json(&rev.base_content, revised_content)?,
+                        author,
+                        now,
+                        &rev.id,
+                    ),
+                )?;
+                record_change(
+                    &tx,
+                    &Change::new("revision", &rev.id, ChangeOp::Update)
+                        .project(project_id)
+                        .payload(revised_content)
+                        .actor(author),
+                )?;
+                Some(rev.id)
+            }
+            None if current == revised_content => None,
+            None => {
+                let id = uuid::Uuid::new_v4().to_string();
+                tx.execute(
+                    "INSERT INTO revisions (id, project_id, block_id, author, status, base_content, revised_content, diff_json, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, 'pending', ?5, ?6, ?7, ?8, ?8)",
+                    (
+                        &id,
+                        project_id,
+                        block_id,
+                        author,
+                        &current,
+                        revised_content,
+                        diff_json(&current, revised_content)?,
+                        now,
+                    ),
+                )?;
+                record_change(
+                    &tx,
+                    &Change::new("revision", &id, ChangeOp::Insert)
+                        .project(project_id)
+                        .payload(revised_content)
+                        .actor(author),
+                )?;
+                Some(id)
+            }
+        };
+
+        write_block_contents(
+            &tx,
+            project_id,
+            &[(block_id.to_string(), revised_content.to_string())],
+            author,
+        )?;
+
+        let revision = revision_id.map(|id| get_revision(&tx, &id)).transpose()?;
+        tx.commit()?;
+        Ok(revision)
+    }
+
+    /// 변경 추적 목록 (status가 None이면 전체, 오래된 순)
+    pub fn list_revisions(&self, project_id: &str, status: Option<&str>) -> Result<Vec<Revision>, IteError> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM revisions
+             WHERE project_id = ?1 AND (?2 IS NULL OR status = ?2)
+             ORDER BY created_at, id",
+            REVISION_COLUMNS
+        ))?;
+        let iter = stmt.query_map((project_id, status), row_to_revision)?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 수정 수락 (블록 콘텐츠는 이미 반영되어 있으므로 상태만 확정)
+    pub fn accept_revision(&self, id: &str) -> Result<Revision, IteError> {
+        let author = self.local_author()?;
+        let tx = self.conn.unchecked_transaction()?;
+        let revision = pending_revision(&tx, id)?;
+        self.ensure_project_writable(&revision.project_id)?;
+
+        let now = chrono::Utc::now().timestamp_millis();
+        tx.execute(
+            "UPDATE revisions SET status = 'accepted', resolved_at = ?1, updated_at = ?1 WHERE id = ?2",
+            (now, id),
+        )?;
+        record_change(
+            &tx,
+            &Change::new("revision", id, ChangeOp::Update)
+                .project(&revision.project_id)
+                .payload("accepted")
+                .actor(author.as_deref()),
+        )?;
+        let revision = get_revision(&tx, id)?;
+        tx.commit()?;
+        Ok(revision)
+    }
+
+    /// 수정 거절 (블록을 수정 전 콘텐츠로 되돌림)
+    /// - 수정 이후 블록이 다른 경로로 바뀌었다면 덮어쓰지 않고 오류를 반환합니다.
+    pub fn reject_revision(&self, id: &str) -> Result<Revision, IteError> {
+        let author = self.local_author()?;
+        let tx = self.conn.unchecked_transaction()?;
+        let revision = pending_revision(&tx, id)?;
+        self.ensure_project_writable(&revision.project_id)?;
+
+        let current: Option<String> = tx
+            .query_row(
+                "SELECT content FROM blocks WHERE id = ?1 AND project_id = ?2",
+                [&revision.block_id, &revision.project_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(current) = current {
+            if current != revision.revised_content {
+                return Err(IteError::InvalidOperation(
+                    "Block was modified after the revision; resolve manually".to_string(),
+                ));
+            }
+            write_block_contents(
+                &tx,
+                &revision.project_id,
+                &[(revision.block_id.clone(), revision.base_content.clone())],
+                author.as_deref(),
+            )?;
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        tx.execute(
+            "UPDATE revisions SET status = 'rejected', resolved_at = ?1, updated_at = ?1 WHERE id = ?2",
+            (now, id),
+        )?;
+        record_change(
+            &tx,
+            &Change::new("revision", id, ChangeOp::Update)
+                .project(&revision.project_id)
+                .payload("rejected")
+                .actor(author.as_deref()),
+        )?;
+        let revision = get_revision(&tx, id)?;
+        tx.commit()?;
+        Ok(revision)
+    }
+}
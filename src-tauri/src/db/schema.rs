@@ -2,6 +2,10 @@
 //!
 //! SQLite 테이블 스키마 정의
 
+/// 스키마 버전 (`PRAGMA user_version`에 기록)
+/// - 테이블/컬럼을 추가하면 올립니다. 0은 버전 기록 이전 DB입니다.
+pub const SCHEMA_VERSION: i32 = 11;
+
 /// 데이터베이스 스키마 생성 SQL
 pub const CREATE_SCHEMA: &str = r#"
 -- 프로젝트 테이블
@@ -10,7 +14,10 @@ CREATE TABLE IF NOT EXISTS projects (
     version TEXT NOT NULL,
     metadata_json TEXT NOT NULL,
     created_at INTEGER NOT NULL,
-    updated_at INTEGER NOT NULL
+    updated_at INTEGER NOT NULL,
+    deleted_at INTEGER,  -- 휴지통으로 이동한 시각 (NULL이면 활성 프로젝트)
+    pinned_at INTEGER,   -- 최근 목록 상단 고정 시각 (NULL이면 고정 안 됨)
+    archived_at INTEGER  -- 보관 처리 시각 (NULL이면 최근 목록에 표시)
 );
 
 -- 블록 테이블
@@ -43,6 +50,64 @@ CREATE TABLE IF NOT EXISTS segments (
 CREATE INDEX IF NOT EXISTS idx_segments_project ON segments(project_id);
 CREATE INDEX IF NOT EXISTS idx_segments_order ON segments(segment_order);
 
+-- 반복 세그먼트 자동 전파 제외 목록 (행이 있으면 해당 세그먼트는 전파 대상에서 제외)
+-- segments는 저장 시 통째로 다시 쓰이므로 별도 테이블로 유지
+CREATE TABLE IF NOT EXISTS segment_propagation_opt_outs (
+    project_id TEXT NOT NULL,
+    segment_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, segment_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 세그먼트별 번역가 메모 (리뷰 토론용 코멘트와 별개, XLIFF <note>/대역 문서로 내보냄)
+-- segments는 저장 시 통째로 다시 쓰이므로 별도 테이블로 유지
+CREATE TABLE IF NOT EXISTS segment_notes (
+    project_id TEXT NOT NULL,
+    segment_id TEXT NOT NULL,
+    note TEXT NOT NULL,
+    author TEXT,
+    updated_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, segment_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 세그먼트별 MT 품질 추정 (참조 없는 휴리스틱 점수, 리뷰 우선순위용)
+-- target_hash로 추정 이후 번역문이 바뀌었는지 판별
+CREATE TABLE IF NOT EXISTS segment_quality (
+    project_id TEXT NOT NULL,
+    segment_id TEXT NOT NULL,
+    score REAL NOT NULL,
+    signals_json TEXT NOT NULL,  -- JSON Array
+    target_hash TEXT NOT NULL,
+    estimated_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, segment_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_quality_score ON segment_quality(project_id, score);
+
+-- 프로젝트 사용자 정의 메타데이터 (고객사, PO 번호, 납기일 등 key/value)
+CREATE TABLE IF NOT EXISTS project_custom_fields (
+    project_id TEXT NOT NULL,
+    field_key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    updated_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, field_key),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_custom_fields_key ON project_custom_fields(field_key, value);
+
+-- 프로젝트 템플릿 (설정/용어집/DNT/채팅 설정 묶음, 프로젝트와 독립적으로 보관)
+CREATE TABLE IF NOT EXISTS project_templates (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    template_json TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
 -- 히스토리 테이블
 CREATE TABLE IF NOT EXISTS history (
     id TEXT PRIMARY KEY,
@@ -51,6 +116,8 @@ CREATE TABLE IF NOT EXISTS history (
     description TEXT NOT NULL,
     changes_json TEXT NOT NULL,
     chat_summary TEXT,
+    state_json TEXT,  -- SnapshotState (블록 전체 내용, 복원/내보내기용)
+    imported INTEGER NOT NULL DEFAULT 0,  -- 파일에서 가져온 스냅샷 (변경 비교 기준에서 제외)
     FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
 );
 
@@ -58,6 +125,61 @@ CREATE TABLE IF NOT EXISTS history (
 CREATE INDEX IF NOT EXISTS idx_history_project ON history(project_id);
 CREATE INDEX IF NOT EXISTS idx_history_timestamp ON history(timestamp);
 
+-- 블록 편집 기록 (저장 시 내용이 바뀐 블록마다 1행, 생산성 리포트/변경 이력에 사용)
+CREATE TABLE IF NOT EXISTS block_edits (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id TEXT NOT NULL,
+    block_id TEXT NOT NULL,
+    block_type TEXT NOT NULL,
+    edited_at INTEGER NOT NULL,
+    author TEXT,
+    words_before INTEGER NOT NULL,
+    words_after INTEGER NOT NULL,
+    content TEXT NOT NULL,  -- 편집 후 콘텐츠
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 블록 편집 기록 인덱스
+CREATE INDEX IF NOT EXISTS idx_block_edits_project ON block_edits(project_id, edited_at);
+CREATE INDEX IF NOT EXISTS idx_block_edits_block ON block_edits(block_id);
+
+-- 변경 피드 (추가 전용, 모든 변경을 같은 트랜잭션에서 기록)
+-- op: insert | update | upsert | delete
+CREATE TABLE IF NOT EXISTS changes (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,  -- get_changes_since 커서
+    project_id TEXT,        -- 전역 데이터(전역 용어집, 설정 등)면 NULL
+    entity TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    op TEXT NOT NULL,
+    payload_hash TEXT,      -- 변경 후 내용의 SHA-256 (삭제면 NULL)
+    actor TEXT,
+    changed_at INTEGER NOT NULL
+);
+
+-- 변경 피드 인덱스
+CREATE INDEX IF NOT EXISTS idx_changes_project ON changes(project_id, seq);
+
+-- 리뷰 변경 추적 (번역문 블록별 수정 제안)
+-- status: pending(검토 대기) | accepted | rejected
+CREATE TABLE IF NOT EXISTS revisions (
+    id TEXT PRIMARY KEY,
+    project_id TEXT NOT NULL,
+    block_id TEXT NOT NULL,
+    author TEXT,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'accepted', 'rejected')),
+    base_content TEXT NOT NULL,     -- 수정 전 콘텐츠 (거절 시 복원)
+    revised_content TEXT NOT NULL,  -- 수정 후 콘텐츠
+    diff_json TEXT NOT NULL,        -- JSON Array (단어 단위 삽입/삭제)
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    resolved_at INTEGER,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 리뷰 변경 추적 인덱스
+CREATE INDEX IF NOT EXISTS idx_revisions_project ON revisions(project_id, status);
+CREATE INDEX IF NOT EXISTS idx_revisions_block ON revisions(block_id);
+
 -- 채팅 세션 테이블
 CREATE TABLE IF NOT EXISTS chat_sessions (
     id TEXT PRIMARY KEY,
@@ -85,6 +207,37 @@ CREATE INDEX IF NOT EXISTS idx_chat_sessions_project ON chat_sessions(project_id
 CREATE INDEX IF NOT EXISTS idx_chat_messages_session ON chat_messages(session_id);
 CREATE INDEX IF NOT EXISTS idx_chat_messages_timestamp ON chat_messages(timestamp);
 
+-- 채팅 메시지 첨부 항목 (참조 블록, 검색 인용, 툴 결과)
+-- kind: 'block' | 'citation' | 'tool_result' (인용의 출처 종류/툴 이름/가져온 시각은 data_json)
+CREATE TABLE IF NOT EXISTS chat_message_artifacts (
+    message_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    reference TEXT NOT NULL,
+    title TEXT,
+    content TEXT,
+    data_json TEXT,
+    PRIMARY KEY (message_id, position),
+    FOREIGN KEY (message_id) REFERENCES chat_messages(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_chat_message_artifacts_reference ON chat_message_artifacts(kind, reference);
+
+-- 메시지를 지우는 곳이 많아 트리거로 함께 정리 (foreign_keys가 꺼져 있어도 동작)
+CREATE TRIGGER IF NOT EXISTS chat_message_artifacts_ad AFTER DELETE ON chat_messages BEGIN
+    DELETE FROM chat_message_artifacts WHERE message_id = old.id;
+END;
+
+-- 채팅 보관 정책(프로젝트별)
+-- 값이 NULL이면 해당 기준은 적용하지 않음
+CREATE TABLE IF NOT EXISTS chat_retention_policies (
+    project_id TEXT PRIMARY KEY,
+    max_messages INTEGER,
+    max_age_days INTEGER,
+    updated_at INTEGER NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
 -- 채팅 설정(프로젝트별)
 -- ChatPanel의 systemPromptOverlay/referenceNotes/projectContext/include flags 등을 JSON으로 저장
 CREATE TABLE IF NOT EXISTS chat_project_settings (
@@ -112,6 +265,90 @@ CREATE TABLE IF NOT EXISTS glossary_entries (
 CREATE INDEX IF NOT EXISTS idx_glossary_project ON glossary_entries(project_id);
 CREATE INDEX IF NOT EXISTS idx_glossary_source ON glossary_entries(source);
 
+-- 번역 메모리(TM) 테이블 (외부 CAT 도구/TMX에서 가져온 번역 단위)
+CREATE TABLE IF NOT EXISTS tm_units (
+    id TEXT PRIMARY KEY,
+    project_id TEXT,  -- NULL이면 전역 TM
+    source_lang TEXT NOT NULL,
+    target_lang TEXT NOT NULL,
+    source TEXT NOT NULL,
+    target TEXT NOT NULL,
+    origin TEXT,  -- 가져온 곳 (예: "omegat")
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- TM 인덱스
+CREATE INDEX IF NOT EXISTS idx_tm_units_project ON tm_units(project_id);
+CREATE INDEX IF NOT EXISTS idx_tm_units_source ON tm_units(source);
+
+-- 가져온 XLIFF(SDLXLIFF) 원본 파일 (내보내기 때 번역문을 다시 써 넣을 골격)
+CREATE TABLE IF NOT EXISTS xliff_documents (
+    project_id TEXT NOT NULL,
+    file_name TEXT NOT NULL,  -- 패키지 안 상대 경로
+    format TEXT NOT NULL,  -- "sdlxliff"
+    content TEXT NOT NULL,
+    imported_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, file_name),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 세그먼트 ↔ XLIFF 세그먼트 연결 (상태/잠금/인라인 태그 보존)
+-- segments는 저장 시 통째로 다시 쓰이므로 별도 테이블로 유지
+CREATE TABLE IF NOT EXISTS xliff_segments (
+    project_id TEXT NOT NULL,
+    segment_id TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    unit_id TEXT NOT NULL,
+    mid TEXT NOT NULL,
+    status TEXT,  -- Trados conf 값 (NULL이면 미번역)
+    locked INTEGER NOT NULL DEFAULT 0,
+    placeholders_json TEXT NOT NULL,  -- JSON Array (인라인 태그 토큰 → 원래 XML)
+    PRIMARY KEY (project_id, segment_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 외부 TMS(Phrase/Crowdin) 작업 ↔ 프로젝트 연결
+CREATE TABLE IF NOT EXISTS tms_jobs (
+    project_id TEXT PRIMARY KEY,
+    provider TEXT NOT NULL,  -- "phrase" | "crowdin"
+    remote_project TEXT NOT NULL,
+    job_id TEXT NOT NULL,
+    job_name TEXT NOT NULL,
+    target_lang TEXT NOT NULL,
+    document TEXT,  -- Phrase 이중 언어 파일(MXLIFF) 원본, 올리기용
+    pulled_at INTEGER NOT NULL,
+    pushed_at INTEGER,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 세그먼트 ↔ TMS 원격 세그먼트 연결
+CREATE TABLE IF NOT EXISTS tms_segments (
+    project_id TEXT NOT NULL,
+    segment_id TEXT NOT NULL,
+    remote_id TEXT NOT NULL,  -- Phrase trans-unit id / Crowdin string id
+    base_target TEXT NOT NULL,  -- 마지막으로 받거나 올린 번역문 (바뀐 세그먼트만 올리기)
+    PRIMARY KEY (project_id, segment_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 번역 금지(DNT) 용어 테이블
+CREATE TABLE IF NOT EXISTS dnt_terms (
+    id TEXT PRIMARY KEY,
+    project_id TEXT,  -- NULL이면 전역 DNT 용어
+    term TEXT NOT NULL,
+    is_regex INTEGER NOT NULL DEFAULT 0,
+    case_sensitive INTEGER NOT NULL DEFAULT 1,
+    notes TEXT,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- DNT 용어 인덱스
+CREATE INDEX IF NOT EXISTS idx_dnt_terms_project ON dnt_terms(project_id);
+
 -- 첨부 파일 테이블
 CREATE TABLE IF NOT EXISTS attachments (
     id TEXT PRIMARY KEY,
@@ -123,10 +360,11 @@ CREATE TABLE IF NOT EXISTS attachments (
     file_size INTEGER,
     created_at INTEGER NOT NULL,
     updated_at INTEGER NOT NULL,
+    content_hash TEXT,
     FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
 );
 
--- 첨부 파일 인덱스
+-- 첨부 파일 인덱스 (content_hash 인덱스는 마이그레이션에서 생성)
 CREATE INDEX IF NOT EXISTS idx_attachments_project ON attachments(project_id);
 
 -- MCP 서버 설정 테이블
@@ -139,5 +377,80 @@ CREATE TABLE IF NOT EXISTS mcp_servers (
     created_at INTEGER NOT NULL,
     updated_at INTEGER NOT NULL
 );
+
+-- 프로젝트별 MCP 서버 연결 (행이 없으면 모든 서버 사용)
+CREATE TABLE IF NOT EXISTS project_mcp_servers (
+    project_id TEXT NOT NULL,
+    server_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (project_id, server_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (server_id) REFERENCES mcp_servers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_mcp_servers_server ON project_mcp_servers(server_id);
+
+-- 앱 전역 설정 테이블 (key = AppSettings 필드명, value = JSON)
+CREATE TABLE IF NOT EXISTS app_settings (
+    key TEXT PRIMARY KEY,
+    value_json TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+-- 프롬프트 템플릿 테이블 (번역가 페르소나/번역 규칙 프리셋)
+CREATE TABLE IF NOT EXISTS prompt_templates (
+    id TEXT PRIMARY KEY,
+    project_id TEXT,  -- NULL이면 전역 템플릿
+    name TEXT NOT NULL,
+    body TEXT NOT NULL,
+    variables_json TEXT NOT NULL,  -- JSON Array (템플릿 변수 이름)
+    version INTEGER NOT NULL DEFAULT 1,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- 프롬프트 템플릿 인덱스
+CREATE INDEX IF NOT EXISTS idx_prompt_templates_project ON prompt_templates(project_id);
+
+-- 로컬 사용 지표 (설정에서 켠 경우에만 기록, 외부로 보내지 않음)
+-- 하루(UTC) 단위로 명령/기능별 합계만 보관
+CREATE TABLE IF NOT EXISTS usage_metrics (
+    day TEXT NOT NULL,       -- 'YYYY-MM-DD'
+    kind TEXT NOT NULL,      -- 'command' | 'feature'
+    name TEXT NOT NULL,
+    count INTEGER NOT NULL DEFAULT 0,
+    error_count INTEGER NOT NULL DEFAULT 0,
+    slow_count INTEGER NOT NULL DEFAULT 0,
+    total_ms REAL NOT NULL DEFAULT 0,
+    max_ms REAL NOT NULL DEFAULT 0,
+    last_error_code TEXT,
+    PRIMARY KEY (day, kind, name)
+);
 "#;
 
+
+/// 채팅 메시지 전문 검색(FTS5) 인덱스
+/// - external content 테이블로 chat_messages를 참조하고, 트리거로 동기화합니다.
+/// - trigram 토크나이저: 띄어쓰기가 불규칙한 한국어/일본어에서도 부분 문자열 검색 가능
+pub const CREATE_CHAT_FTS: &str = r#"
+CREATE VIRTUAL TABLE IF NOT EXISTS chat_messages_fts USING fts5(
+    content,
+    content = 'chat_messages',
+    content_rowid = 'rowid',
+    tokenize = 'trigram'
+);
+
+CREATE TRIGGER IF NOT EXISTS chat_messages_fts_ai AFTER INSERT ON chat_messages BEGIN
+    INSERT INTO chat_messages_fts(rowid, content) VALUES (new.rowid, new.content);
+END;
+
+CREATE TRIGGER IF NOT EXISTS chat_messages_fts_ad AFTER DELETE ON chat_messages BEGIN
+    INSERT INTO chat_messages_fts(chat_messages_fts, rowid, content) VALUES ('delete', old.rowid, old.content);
+END;
+
+CREATE TRIGGER IF NOT EXISTS chat_messages_fts_au AFTER UPDATE OF content ON chat_messages BEGIN
+    INSERT INTO chat_messages_fts(chat_messages_fts, rowid, content) VALUES ('delete', old.rowid, old.content);
+    INSERT INTO chat_messages_fts(rowid, content) VALUES (new.rowid, new.content);
+END;
+"#;
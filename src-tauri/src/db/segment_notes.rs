@@ -0,0 +1,132 @@
+//! Segment Notes
+//!
+//! 세그먼트별 번역가 메모 (한 세그먼트에 하나, 리뷰 토론용 블록 코멘트와 별개)
+//! - 조회 시 지금 없는 세그먼트의 메모는 제외합니다.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+
+/// 메모 최대 길이 (문자 수)
+pub const MAX_SEGMENT_NOTE_LEN: usize = 2000;
+
+/// 세그먼트 메모
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentNote {
+    pub segment_id: String,
+    pub note: String,
+    pub author: Option<String>,
+    pub updated_at: i64,
+}
+
+impl Database {
+    /// 세그먼트 메모 설정 (있으면 덮어씀, 작성자는 로컬 사용자)
+    pub fn set_segment_note(&self, project_id: &str, segment_id: &str, note: &str) -> Result<SegmentNote, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let note = note.trim();
+        if note.is_empty() {
+            return Err(IteError::InvalidOperation(
+                "Note must not be empty (use clear_segment_note to remove it)".to_string(),
+            ));
+        }
+        if note.chars().count() > MAX_SEGMENT_NOTE_LEN {
+            return Err(IteError::InvalidOperation(format!(
+                "Note is too long (max {} characters)",
+                MAX_SEGMENT_NOTE_LEN
+            )));
+        }
+
+        let author = self.local_author()?;
+        let tx = self.conn.unchecked_transaction()?;
+        let exists: bool = tx.query_row(
+            "SELECT EXISTS(SELECT 1 FROM segments WHERE id = ?1 AND project_id = ?2)",
+            [segment_id, project_id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(IteError::SegmentNotFound(segment_id.to_string()));
+        }
+
+        let saved = SegmentNote {
+            segment_id: segment_id.to_string(),
+            note: note.to_string(),
+            author,
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        };
+        tx.execute(
+            "INSERT INTO segment_notes (project_id, segment_id, note, author, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(project_id, segment_id) DO UPDATE SET
+                note = excluded.note,
+                author = excluded.author,
+                updated_at = excluded.updated_at",
+            (project_id, segment_id, &saved.note, &saved.author, saved.updated_at),
+        )?;
+        record_change(
+            &tx,
+            &Change::new("segment_note", segment_id, ChangeOp::Upsert)
+                .project(project_id)
+                .payload(&saved.note)
+                .actor(saved.author.as_deref()),
+        )?;
+        tx.commit()?;
+        Ok(saved)
+    }
+
+    /// 세그먼트 메모 삭제 (삭제했으면 true)
+    pub fn clear_segment_note(&self, project_id: &str, segment_id: &str) -> Result<bool, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let deleted = tx.execute(
+            "DELETE FROM segment_notes WHERE project_id = ?1 AND segment_id = ?2",
+            [project_id, segment_id],
+        )?;
+        if deleted > 0 {
+            record_change(
+                &tx,
+                &Change::new("segment_note", segment_id, ChangeOp::Delete).project(project_id),
+            )?;
+        }
+        tx.commit()?;
+        Ok(deleted > 0)
+    }
+
+    /// 프로젝트의 세그먼트 메모 (세그먼트 순서)
+    pub fn list_segment_notes(&self, project_id: &str) -> Result<Vec<SegmentNote>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT n.segment_id, n.note, n.author, n.updated_at
+             FROM segment_notes n
+             JOIN segments s ON s.id = n.segment_id AND s.project_id = n.project_id
+             WHERE n.project_id = ?1
+             ORDER BY s.segment_order, s.id",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(SegmentNote {
+                segment_id: row.get(0)?,
+                note: row.get(1)?,
+                author: row.get(2)?,
+                updated_at: row.get(3)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// segment_id → 메모 (내보내기용)
+    pub fn segment_note_map(&self, project_id: &str) -> Result<HashMap<String, String>, IteError> {
+        Ok(self
+            .list_segment_notes(project_id)?
+            .into_iter()
+            .map(|n| (n.segment_id, n.note))
+            .collect())
+    }
+}
@@ -0,0 +1,348 @@
+//! Paged Segment Listing
+//!
+//! 대형 프로젝트의 가상화 그리드용 세그먼트 목록 조회.
+//! `load_project` 없이 세그먼트/블록 행만 읽어 미리보기·상태를 계산하고, 필터/정렬 후 페이지 단위로 반환합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use rusqlite::OptionalExtension;
+use serde::{Deserialize, Serialize};
+
+use super::tags::{block_status, blocks_in_review, metadata_tags, open_comment_count, preview_text, BlockStatus};
+use super::Database;
+use crate::error::IteError;
+use crate::models::{IteProject, SegmentGroup};
+use crate::text::strip_html;
+
+/// 한 페이지 기본 크기
+pub const DEFAULT_SEGMENT_PAGE_SIZE: usize = 100;
+/// 한 페이지 최대 크기
+pub const MAX_SEGMENT_PAGE_SIZE: usize = 500;
+
+/// 세그먼트 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentStatus {
+    /// 번역문 없음
+    Untranslated,
+    /// 번역문 블록 일부만 작성
+    Partial,
+    /// 모든 번역문 블록 작성
+    Translated,
+    /// 리뷰어 수정 검토 대기
+    InReview,
+}
+
+/// 세그먼트 목록 필터 (지정한 조건을 모두 만족해야 함)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentFilter {
+    pub status: Option<SegmentStatus>,
+    /// 원문/번역문 평문 부분 일치 (대소문자 무시)
+    pub query: Option<String>,
+    /// 세그먼트 블록 중 하나라도 가진 태그
+    pub tag: Option<String>,
+    /// true: 미해결 코멘트가 있는 세그먼트만
+    pub has_comments: Option<bool>,
+}
+
+/// 정렬 기준
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SegmentSortField {
+    #[default]
+    Order,
+    Status,
+    SourceLength,
+    TargetLength,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentSort {
+    #[serde(default)]
+    pub field: SegmentSortField,
+    #[serde(default)]
+    pub descending: bool,
+}
+
+/// 그리드 한 행
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentListItem {
+    pub segment_id: String,
+    pub order: i32,
+    /// 문서 순서 기준 세그먼트 번호 (1부터, 필터와 무관)
+    pub number: usize,
+    pub is_aligned: bool,
+    pub source_ids: Vec<String>,
+    pub target_ids: Vec<String>,
+    pub source_preview: String,
+    pub target_preview: String,
+    /// 원문/번역문 평문 길이 (문자 수)
+    pub source_length: usize,
+    pub target_length: usize,
+    pub status: SegmentStatus,
+    pub tags: Vec<String>,
+    pub open_comments: usize,
+    /// 번역가 메모
+    pub note: Option<String>,
+}
+
+/// 페이지 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentPage {
+    /// 필터 적용 후 전체 개수
+    pub total: usize,
+    pub offset: usize,
+    pub items: Vec<SegmentListItem>,
+}
+
+struct BlockRow {
+    text: String,
+    tags: Vec<String>,
+    open_comments: usize,
+    status: BlockStatus,
+}
+
+fn segment_status(targets: &[&BlockRow]) -> SegmentStatus {
+    if targets.iter().any(|b| b.status == BlockStatus::InReview) {
+        return SegmentStatus::InReview;
+    }
+    let filled = targets.iter().filter(|b| b.status == BlockStatus::Filled).count();
+    if filled == 0 {
+        SegmentStatus::Untranslated
+    } else if filled < targets.len() {
+        SegmentStatus::Partial
+    } else {
+        SegmentStatus::Translated
+    }
+}
+
+fn join_preview(blocks: &[&BlockRow]) -> String {
+    preview_text(
+        &blocks
+            .iter()
+            .map(|b| b.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+impl Database {
+    /// (전체 세그먼트 수, 모든 번역문 블록이 채워진 세그먼트 수)
+    pub fn segment_progress(&self, project_id: &str) -> Result<(usize, usize), IteError> {
+        let mut filled: HashSet<String> = HashSet::new();
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT id, content FROM blocks WHERE project_id = ?1 AND block_type = 'target'")?;
+            let iter = stmt.query_map([project_id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for r in iter {
+                let (id, content) = r?;
+                if !strip_html(&content).trim().is_empty() {
+                    filled.insert(id);
+                }
+            }
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT target_ids FROM segments WHERE project_id = ?1")?;
+        let iter = stmt.query_map([project_id], |row| row.get::<_, String>(0))?;
+
+        let (mut total, mut translated) = (0, 0);
+        for r in iter {
+            let target_ids: Vec<String> = serde_json::from_str(&r?)?;
+            total += 1;
+            if !target_ids.is_empty() && target_ids.iter().all(|id| filled.contains(id)) {
+                translated += 1;
+            }
+        }
+        Ok((total, translated))
+    }
+
+    /// 세그먼트 목록 (필터 → 정렬 → offset/limit)
+    pub fn list_segments(
+        &self,
+        project_id: &str,
+        filter: &SegmentFilter,
+        sort: SegmentSort,
+        offset: usize,
+        limit: usize,
+    ) -> Result<SegmentPage, IteError> {
+        let in_review = blocks_in_review(&self.conn, project_id)?;
+        let mut notes = self.segment_note_map(project_id)?;
+
+        let mut blocks: HashMap<String, BlockRow> = HashMap::new();
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT id, content, metadata_json FROM blocks WHERE project_id = ?1")?;
+            let iter = stmt.query_map([project_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?;
+            for r in iter {
+                let (id, content, metadata_json) = r?;
+                let metadata: serde_json::Value = serde_json::from_str(&metadata_json).unwrap_or_default();
+                let status = block_status(&content, in_review.contains(&id));
+                blocks.insert(
+                    id,
+                    BlockRow {
+                        text: strip_html(&content),
+                        tags: metadata_tags(&metadata),
+                        open_comments: open_comment_count(&metadata),
+                        status,
+                    },
+                );
+            }
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, source_ids, target_ids, is_aligned, segment_order
+             FROM segments WHERE project_id = ?1 ORDER BY segment_order, id",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, bool>(3)?,
+                row.get::<_, i32>(4)?,
+            ))
+        })?;
+
+        let query = filter
+            .query
+            .as_deref()
+            .map(|q| q.trim().to_lowercase())
+            .filter(|q| !q.is_empty());
+        let tag = filter.tag.as_deref().map(str::trim).filter(|t| !t.is_empty());
+
+        let mut items = Vec::new();
+        for (index, r) in iter.enumerate() {
+            let (segment_id, source_json, target_json, is_aligned, order) = r?;
+            let source_ids: Vec<String> = serde_json::from_str(&source_json)?;
+            let target_ids: Vec<String> = serde_json::from_str(&target_json)?;
+
+            let sources: Vec<&BlockRow> = source_ids.iter().filter_map(|id| blocks.get(id)).collect();
+            let targets: Vec<&BlockRow> = target_ids.iter().filter_map(|id| blocks.get(id)).collect();
+            let all = || sources.iter().chain(targets.iter());
+
+            let status = segment_status(&targets);
+            if filter.status.is_some_and(|s| s != status) {
+                continue;
+            }
+            let open_comments: usize = all().map(|b| b.open_comments).sum();
+            if let Some(has) = filter.has_comments {
+                if has != (open_comments > 0) {
+                    continue;
+                }
+            }
+            let mut tags: Vec<String> = Vec::new();
+            for t in all().flat_map(|b| b.tags.iter()) {
+                if !tags.contains(t) {
+                    tags.push(t.clone());
+                }
+            }
+            if tag.is_some_and(|t| !tags.iter().any(|x| x == t)) {
+                continue;
+            }
+            if let Some(q) = &query {
+                if !all().any(|b| b.text.to_lowercase().contains(q.as_str())) {
+                    continue;
+                }
+            }
+
+            let length = |list: &[&BlockRow]| list.iter().map(|b| b.text.trim().chars().count()).sum();
+            items.push(SegmentListItem {
+                number: index + 1,
+                note: notes.remove(&segment_id),
+                source_preview: join_preview(&sources),
+                target_preview: join_preview(&targets),
+                source_length: length(&sources),
+                target_length: length(&targets),
+                segment_id,
+                order,
+                is_aligned,
+                source_ids,
+                target_ids,
+                status,
+                tags,
+                open_comments,
+            });
+        }
+
+        // 같은 값끼리는 문서 순서 유지 (안정 정렬)
+        items.sort_by(|a, b| {
+            let ord = match sort.field {
+                SegmentSortField::Order => a.number.cmp(&b.number),
+                SegmentSortField::Status => a.status.cmp(&b.status),
+                SegmentSortField::SourceLength => a.source_length.cmp(&b.source_length),
+                SegmentSortField::TargetLength => a.target_length.cmp(&b.target_length),
+            };
+            if sort.descending {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+
+        let total = items.len();
+        let limit = limit.clamp(1, MAX_SEGMENT_PAGE_SIZE);
+        let items = items.into_iter().skip(offset).take(limit).collect();
+        Ok(SegmentPage { total, offset, items })
+    }
+
+    /// 블록이 속한 세그먼트 하나만 담은 프로젝트 (블록 단위 QA용)
+    /// - 세그먼트 밖 블록이면 None
+    pub fn load_block_segment(&self, project_id: &str, block_id: &str) -> Result<Option<IteProject>, IteError> {
+        let row: Option<(String, String, String, bool, i32)> = self
+            .conn
+            .prepare_cached(
+                "SELECT s.id, s.source_ids, s.target_ids, s.is_aligned, s.segment_order
+                 FROM segments s
+                 WHERE s.project_id = ?1
+                   AND (EXISTS (SELECT 1 FROM json_each(s.source_ids) j WHERE j.value = ?2)
+                     OR EXISTS (SELECT 1 FROM json_each(s.target_ids) j WHERE j.value = ?2))
+                 LIMIT 1",
+            )?
+            .query_row([project_id, block_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+            })
+            .optional()?;
+        let Some((group_id, source_ids, target_ids, is_aligned, order)) = row else {
+            return Ok(None);
+        };
+
+        let segment = SegmentGroup {
+            group_id,
+            source_ids: serde_json::from_str(&source_ids)?,
+            target_ids: serde_json::from_str(&target_ids)?,
+            is_aligned,
+            order,
+        };
+        let ids: Vec<String> = segment.source_ids.iter().chain(&segment.target_ids).cloned().collect();
+        let blocks = self
+            .get_blocks(project_id, &ids)?
+            .into_iter()
+            .map(|b| (b.id.clone(), b))
+            .collect();
+
+        Ok(Some(IteProject {
+            id: project_id.to_string(),
+            version: String::new(),
+            metadata: self.load_project_metadata(project_id)?,
+            segments: vec![segment],
+            blocks,
+            history: Vec::new(),
+        }))
+    }
+}
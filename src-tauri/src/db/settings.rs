@@ -0,0 +1,128 @@
+//! App Settings Storage
+//!
+//! 앱 전역 설정(key-value) 저장소
+
+use serde_json::{Map, Value};
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::AppSettings;
+
+impl Database {
+    /// 저장된 전역 설정 원본(key → JSON 값) 조회
+    fn load_app_settings_map(&self) -> Result<Map<String, Value>, IteError> {
+        let mut stmt = self.conn.prepare("SELECT key, value_json FROM app_settings")?;
+        let iter = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+
+        let mut map = Map::new();
+        for r in iter {
+            let (key, value_json) = r?;
+            if let Ok(v) = serde_json::from_str::<Value>(&value_json) {
+                map.insert(key, v);
+            }
+        }
+        Ok(map)
+    }
+
+    /// 전역 설정 로드 (저장되지 않았거나 형식이 잘못된 값은 기본값 사용)
+    pub fn load_app_settings(&self) -> Result<AppSettings, IteError> {
+        let stored = self.load_app_settings_map()?;
+
+        let mut merged = match serde_json::to_value(AppSettings::default())? {
+            Value::Object(m) => m,
+            _ => Map::new(),
+        };
+
+        // 값 하나가 깨져 있어도 나머지 설정은 살리기 위해 키 단위로 검증하며 병합
+        for (key, value) in stored {
+            if !merged.contains_key(&key) {
+                continue;
+            }
+            let mut candidate = merged.clone();
+            candidate.insert(key, value);
+            if serde_json::from_value::<AppSettings>(Value::Object(candidate.clone())).is_ok() {
+                merged = candidate;
+            }
+        }
+
+        Ok(serde_json::from_value(Value::Object(merged))?)
+    }
+
+    /// 설정된 로컬 사용자 (편집/리뷰어 수정에 author로 기록, 미설정이면 None)
+    pub fn local_author(&self) -> Result<Option<String>, IteError> {
+        Ok(self.load_app_settings()?.identity.author())
+    }
+
+    /// 전역 설정 부분 업데이트
+    /// - patch의 각 키는 AppSettings의 최상위 필드명(camelCase)이어야 합니다.
+    /// - 값이 null이면 해당 설정을 기본값으로 되돌립니다.
+    /// - (업데이트된 설정, 실제로 값이 바뀐 키 목록)을 반환합니다.
+    pub fn update_app_settings(
+        &self,
+        patch: &Map<String, Value>,
+    ) -> Result<(AppSettings, Vec<String>), IteError> {
+        let current = serde_json::to_value(self.load_app_settings()?)?;
+        let defaults = serde_json::to_value(AppSettings::default())?;
+        let (Value::Object(mut next), Value::Object(defaults)) = (current.clone(), defaults) else {
+            return Err(IteError::InvalidOperation("Settings must be a JSON object".to_string()));
+        };
+
+        for (key, value) in patch {
+            let Some(default_value) = defaults.get(key) else {
+                return Err(IteError::InvalidOperation(format!("Unknown setting: {}", key)));
+            };
+            let value = if value.is_null() { default_value.clone() } else { value.clone() };
+            next.insert(key.clone(), value);
+        }
+
+        // 타입 검증 (예: autoSaveInterval에 문자열이 들어오면 거부)
+        let settings: AppSettings = serde_json::from_value(Value::Object(next.clone()))
+            .map_err(|e| IteError::InvalidOperation(format!("Invalid setting value: {}", e)))?;
+        settings.validate().map_err(IteError::InvalidOperation)?;
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut changed = Vec::new();
+        let tx = self.conn.unchecked_transaction()?;
+        for key in patch.keys() {
+            let new_value = &next[key];
+            if current.get(key) == Some(new_value) {
+                continue;
+            }
+            if patch[key].is_null() {
+                tx.execute("DELETE FROM app_settings WHERE key = ?1", [key])?;
+                record_change(&tx, &Change::new("app_setting", key, ChangeOp::Delete))?;
+            } else {
+                let value_json = serde_json::to_string(new_value)?;
+                tx.execute(
+                    "INSERT INTO app_settings (key, value_json, updated_at) VALUES (?1, ?2, ?3)
+                     ON CONFLICT(key) DO UPDATE SET value_json = excluded.value_json, updated_at = excluded.updated_at",
+                    (key, &value_json, now),
+                )?;
+                record_change(&tx, &Change::new("app_setting", key, ChangeOp::Upsert).payload(&value_json))?;
+            }
+            changed.push(key.clone());
+        }
+        tx.commit()?;
+
+        Ok((settings, changed))
+    }
+
+    /// 전역 설정 초기화 (keys가 None이면 전체)
+    /// - 초기화된 키 목록을 반환합니다.
+    pub fn reset_app_settings(&self, keys: Option<&[String]>) -> Result<Vec<String>, IteError> {
+        let stored = self.load_app_settings_map()?;
+        let targets: Vec<String> = match keys {
+            Some(keys) => keys.iter().filter(|k| stored.contains_key(*k)).cloned().collect(),
+            None => stored.keys().cloned().collect(),
+        };
+
+        let tx = self.conn.unchecked_transaction()?;
+        for key in &targets {
+            tx.execute("DELETE FROM app_settings WHERE key = ?1", [key])?;
+            record_change(&tx, &Change::new("app_setting", key, ChangeOp::Delete))?;
+        }
+        tx.commit()?;
+        Ok(targets)
+    }
+}
@@ -0,0 +1,320 @@
+//! Block Tags & Filtered Block Queries
+//!
+//! 블록 메타데이터(`metadata_json.tags`)의 태그 추가/제거와,
+//! 태그·상태·코멘트 여부로 블록을 거르는 조회(에디터 저장된 보기용)를 제공합니다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::changes::{self, record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::text::strip_html;
+
+/// 태그 최대 길이 (문자 수)
+pub const MAX_TAG_LEN: usize = 50;
+/// 미리보기 평문 최대 길이 (문자 수)
+pub(super) const PREVIEW_CHARS: usize = 120;
+
+/// 블록 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockStatus {
+    /// 내용 없음 (번역문 미작성)
+    Empty,
+    /// 내용 있음
+    Filled,
+    /// 리뷰어 수정이 검토 대기 중
+    InReview,
+}
+
+/// 블록 조회 필터 (지정한 조건을 모두 만족해야 함)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockFilter {
+    /// 모두 가진 블록만 (비어 있으면 무시)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// "source" | "target"
+    pub block_type: Option<String>,
+    pub status: Option<BlockStatus>,
+    /// true: 미해결 코멘트가 있는 블록, false: 없는 블록
+    pub has_comments: Option<bool>,
+}
+
+/// 필터 조회 결과 항목
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockQueryHit {
+    pub block_id: String,
+    pub block_type: String,
+    /// 블록이 속한 세그먼트 (없으면 None)
+    pub segment_id: Option<String>,
+    pub segment_order: Option<i32>,
+    pub status: BlockStatus,
+    pub tags: Vec<String>,
+    pub open_comments: usize,
+    pub preview: String,
+}
+
+/// 태그별 사용 블록 수
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCount {
+    pub tag: String,
+    pub count: usize,
+}
+
+/// 입력 태그 정리 (앞뒤 공백 제거, 빈 값/중복 제거, 길이 검사)
+fn normalize_tags(tags: &[String]) -> Result<Vec<String>, IteError> {
+    let mut out: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim();
+        if tag.is_empty() || out.iter().any(|t| t == tag) {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LEN {
+            return Err(IteError::InvalidOperation(format!(
+                "Tag is too long (max {} characters): {}",
+                MAX_TAG_LEN, tag
+            )));
+        }
+        out.push(tag.to_string());
+    }
+    Ok(out)
+}
+
+pub(super) fn metadata_tags(metadata: &serde_json::Value) -> Vec<String> {
+    metadata
+        .get("tags")
+        .and_then(|t| t.as_array())
+        .map(|arr| arr.iter().filter_map(|t| t.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default()
+}
+
+pub(super) fn open_comment_count(metadata: &serde_json::Value) -> usize {
+    metadata
+        .get("comments")
+        .and_then(|c| c.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter(|c| !c.get("resolved").and_then(|r| r.as_bool()).unwrap_or(false))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// 평문 미리보기 (공백 정리 후 앞부분)
+pub(super) fn preview_text(html: &str) -> String {
+    let text = strip_html(html).split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() > PREVIEW_CHARS {
+        let mut out: String = text.chars().take(PREVIEW_CHARS).collect();
+        out.push('…');
+        out
+    } else {
+        text
+    }
+}
+
+/// 검토 대기 수정이 있는 블록 ID
+pub(super) fn blocks_in_review(conn: &Connection, project_id: &str) -> Result<HashSet<String>, IteError> {
+    let mut stmt =
+        conn.prepare("SELECT DISTINCT block_id FROM revisions WHERE project_id = ?1 AND status = 'pending'")?;
+    let iter = stmt.query_map([project_id], |row| row.get::<_, String>(0))?;
+
+    let mut out = HashSet::new();
+    for r in iter {
+        out.insert(r?);
+    }
+    Ok(out)
+}
+
+/// 블록 상태 판정 (검토 대기 > 내용 유무)
+pub(super) fn block_status(content: &str, in_review: bool) -> BlockStatus {
+    if in_review {
+        BlockStatus::InReview
+    } else if strip_html(content).trim().is_empty() {
+        BlockStatus::Empty
+    } else {
+        BlockStatus::Filled
+    }
+}
+
+impl Database {
+    /// 블록 태그 일괄 수정 (add=true면 추가, false면 제거), 변경된 블록 수 반환
+    fn modify_block_tags(
+        &self,
+        project_id: &str,
+        block_ids: &[String],
+        tags: &[String],
+        add: bool,
+    ) -> Result<usize, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tags = normalize_tags(tags)?;
+        if tags.is_empty() || block_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        let mut changed = 0;
+        {
+            let mut select = tx.prepare("SELECT content, metadata_json FROM blocks WHERE id = ?1 AND project_id = ?2")?;
+            let mut update = tx.prepare("UPDATE blocks SET metadata_json = ?1 WHERE id = ?2 AND project_id = ?3")?;
+
+            for block_id in block_ids {
+                let (content, metadata_json): (String, String) = select
+                    .query_row([block_id, project_id], |row| Ok((row.get(0)?, row.get(1)?)))
+                    .map_err(|e| match e {
+                        rusqlite::Error::QueryReturnedNoRows => IteError::BlockNotFound(block_id.clone()),
+                        other => other.into(),
+                    })?;
+                let mut metadata: serde_json::Value = serde_json::from_str(&metadata_json)?;
+
+                let mut current = metadata_tags(&metadata);
+                let before = current.len();
+                if add {
+                    for tag in &tags {
+                        if !current.contains(tag) {
+                            current.push(tag.clone());
+                        }
+                    }
+                } else {
+                    current.retain(|t| !tags.contains(t));
+                }
+                if current.len() == before {
+                    continue;
+                }
+
+                if let Some(obj) = metadata.as_object_mut() {
+                    obj.insert("tags".to_string(), serde_json::json!(current));
+                }
+                let metadata_json = serde_json::to_string(&metadata)?;
+                update.execute((&metadata_json, block_id, project_id))?;
+                record_change(
+                    &tx,
+                    &Change::new("block", block_id, ChangeOp::Update)
+                        .project(project_id)
+                        .payload(&changes::block_payload(&content, &metadata_json)),
+                )?;
+                changed += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// 블록에 태그 추가 (이미 있는 태그는 무시)
+    pub fn tag_blocks(&self, project_id: &str, block_ids: &[String], tags: &[String]) -> Result<usize, IteError> {
+        self.modify_block_tags(project_id, block_ids, tags, true)
+    }
+
+    /// 블록에서 태그 제거
+    pub fn untag_blocks(&self, project_id: &str, block_ids: &[String], tags: &[String]) -> Result<usize, IteError> {
+        self.modify_block_tags(project_id, block_ids, tags, false)
+    }
+
+    /// 프로젝트에서 사용 중인 태그와 블록 수 (태그 이름 순)
+    pub fn list_tags(&self, project_id: &str) -> Result<Vec<TagCount>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT t.value, COUNT(DISTINCT b.id)
+             FROM blocks b, json_each(b.metadata_json, '$.tags') t
+             WHERE b.project_id = ?1 AND t.type = 'text'
+             GROUP BY t.value",
+        )?;
+        let iter = stmt.query_map([project_id], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?;
+
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for r in iter {
+            let (tag, count) = r?;
+            counts.insert(tag, count as usize);
+        }
+        Ok(counts.into_iter().map(|(tag, count)| TagCount { tag, count }).collect())
+    }
+
+    /// 블록 ID → (세그먼트 ID, 순서)
+    pub(super) fn block_segment_index(&self, project_id: &str) -> Result<HashMap<String, (String, i32)>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.id, s.segment_order, j.value
+             FROM segments s, json_each(s.source_ids) j
+             WHERE s.project_id = ?1
+             UNION ALL
+             SELECT s.id, s.segment_order, j.value
+             FROM segments s, json_each(s.target_ids) j
+             WHERE s.project_id = ?1",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i32>(1)?, row.get::<_, String>(2)?))
+        })?;
+
+        let mut out = HashMap::new();
+        for r in iter {
+            let (segment_id, order, block_id) = r?;
+            out.insert(block_id, (segment_id, order));
+        }
+        Ok(out)
+    }
+
+    /// 필터 조건에 맞는 블록 목록 (세그먼트 순서 기준, 세그먼트 밖 블록은 마지막)
+    pub fn query_blocks(&self, project_id: &str, filter: &BlockFilter) -> Result<Vec<BlockQueryHit>, IteError> {
+        let required_tags = normalize_tags(&filter.tags)?;
+        let in_review = blocks_in_review(&self.conn, project_id)?;
+        let segment_index = self.block_segment_index(project_id)?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, block_type, content, metadata_json FROM blocks
+             WHERE project_id = ?1 AND (?2 IS NULL OR block_type = ?2)",
+        )?;
+        let iter = stmt.query_map((project_id, filter.block_type.as_deref()), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            let (block_id, block_type, content, metadata_json) = r?;
+            let metadata: serde_json::Value = serde_json::from_str(&metadata_json).unwrap_or_default();
+
+            let tags = metadata_tags(&metadata);
+            if !required_tags.iter().all(|t| tags.contains(t)) {
+                continue;
+            }
+            let open_comments = open_comment_count(&metadata);
+            if let Some(has) = filter.has_comments {
+                if has != (open_comments > 0) {
+                    continue;
+                }
+            }
+            let status = block_status(&content, in_review.contains(&block_id));
+            if filter.status.is_some_and(|s| s != status) {
+                continue;
+            }
+
+            let segment = segment_index.get(&block_id);
+            out.push(BlockQueryHit {
+                segment_id: segment.map(|(id, _)| id.clone()),
+                segment_order: segment.map(|(_, order)| *order),
+                block_id,
+                block_type,
+                status,
+                tags,
+                open_comments,
+                preview: preview_text(&content),
+            });
+        }
+
+        out.sort_by(|a, b| {
+            let key = |h: &BlockQueryHit| (h.segment_order.is_none(), h.segment_order.unwrap_or(0));
+            key(a)
+                .cmp(&key(b))
+                .then_with(|| (a.block_type != "source").cmp(&(b.block_type != "source")))
+                .then_with(|| a.block_id.cmp(&b.block_id))
+        });
+        Ok(out)
+    }
+}
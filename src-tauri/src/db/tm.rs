@@ -0,0 +1,128 @@
+//! Translation Memory Storage
+//!
+//! 외부 CAT 도구(OmegaT 등)나 TMX에서 가져온 번역 단위 저장소.
+//! 같은 (프로젝트, 언어쌍, 원문, 번역문)은 한 번만 저장합니다.
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::models::TmUnit;
+use crate::text::unicode::into_nfc;
+
+/// 추가할 TM 단위 (id/시각은 DB에서 정함)
+#[derive(Debug, Clone)]
+pub struct NewTmUnit {
+    pub source_lang: String,
+    pub target_lang: String,
+    pub source: String,
+    pub target: String,
+}
+
+fn tm_unit_id(project_id: Option<&str>, unit: &NewTmUnit) -> String {
+    format!(
+        "{:x}",
+        md5::compute(format!(
+            "{}|{}|{}|{}|{}",
+            project_id.unwrap_or(""),
+            unit.source_lang.to_lowercase(),
+            unit.target_lang.to_lowercase(),
+            unit.source,
+            unit.target
+        ))
+    )
+}
+
+impl Database {
+    /// TM 단위 추가 (이미 있으면 updated_at/origin만 갱신)
+    /// - 반환: 새로 추가된 단위 수
+    pub fn upsert_tm_units(
+        &self,
+        project_id: Option<&str>,
+        units: &[NewTmUnit],
+        origin: Option<&str>,
+    ) -> Result<u32, IteError> {
+        if let Some(project_id) = project_id {
+            self.ensure_project_writable(project_id)?;
+        }
+        let now = chrono::Utc::now().timestamp_millis();
+        let tx = self.conn.unchecked_transaction()?;
+        let mut inserted = 0u32;
+        for unit in units {
+            if unit.source.trim().is_empty() || unit.target.trim().is_empty() {
+                continue;
+            }
+            // 외부 TMX/CAT 파일이 NFD여도 같은 단위로 합쳐지도록 NFC로 맞춤
+            let unit = NewTmUnit {
+                source: into_nfc(unit.source.clone()),
+                target: into_nfc(unit.target.clone()),
+                ..unit.clone()
+            };
+            let id = tm_unit_id(project_id, &unit);
+            let changed = tx.execute(
+                "INSERT INTO tm_units (
+                    id, project_id, source_lang, target_lang, source, target, origin, created_at, updated_at
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)
+                 ON CONFLICT(id) DO NOTHING",
+                (
+                    &id,
+                    project_id,
+                    &unit.source_lang,
+                    &unit.target_lang,
+                    &unit.source,
+                    &unit.target,
+                    origin,
+                    now,
+                ),
+            )?;
+            let op = if changed > 0 {
+                inserted += 1;
+                ChangeOp::Insert
+            } else {
+                tx.execute(
+                    "UPDATE tm_units SET origin = COALESCE(?2, origin), updated_at = ?3 WHERE id = ?1",
+                    (&id, origin, now),
+                )?;
+                ChangeOp::Update
+            };
+            let payload = format!("{}\t{}\t{}\t{}", unit.source_lang, unit.target_lang, unit.source, unit.target);
+            let mut change = Change::new("tm_unit", &id, op).payload(&payload);
+            if let Some(project_id) = project_id {
+                change = change.project(project_id);
+            }
+            record_change(&tx, &change)?;
+        }
+        tx.commit()?;
+        Ok(inserted)
+    }
+
+    /// 프로젝트에 적용되는 TM 단위 조회 (전역 + 프로젝트, 최근 수정 순)
+    pub fn list_tm_units(&self, project_id: &str, limit: usize) -> Result<Vec<TmUnit>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, project_id, source_lang, target_lang, source, target, origin, created_at, updated_at
+             FROM tm_units
+             WHERE project_id IS NULL OR project_id = ?1
+             ORDER BY updated_at DESC, source
+             LIMIT ?2",
+        )?;
+
+        let iter = stmt.query_map((project_id, limit as i64), |row| {
+            Ok(TmUnit {
+                id: row.get(0)?,
+                project_id: row.get(1)?,
+                source_lang: row.get(2)?,
+                target_lang: row.get(3)?,
+                source: row.get(4)?,
+                target: row.get(5)?,
+                origin: row.get(6)?,
+                created_at: row.get(7)?,
+                updated_at: row.get(8)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,148 @@
+//! TMS Job Links
+//!
+//! Phrase/Crowdin에서 받아 온 프로젝트를 다시 올리기 위한 연결 정보.
+//! - `tms_jobs`: 프로젝트 ↔ 원격 작업 (프로젝트당 1개)
+//! - `tms_segments`: ITE 세그먼트 ↔ 원격 세그먼트, 마지막으로 맞춘 번역문
+
+use rusqlite::OptionalExtension;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+
+/// 프로젝트 ↔ 원격 작업
+#[derive(Debug, Clone)]
+pub struct TmsJobLink {
+    pub provider: String,
+    pub remote_project: String,
+    pub job_id: String,
+    pub job_name: String,
+    pub target_lang: String,
+    /// Phrase MXLIFF 원본 (Crowdin은 None)
+    pub document: Option<String>,
+    pub pulled_at: i64,
+    pub pushed_at: Option<i64>,
+}
+
+/// 세그먼트 ↔ 원격 세그먼트
+#[derive(Debug, Clone)]
+pub struct TmsSegmentLink {
+    pub segment_id: String,
+    pub remote_id: String,
+    pub base_target: String,
+}
+
+impl Database {
+    /// 받아 온 작업 연결 저장 (기존 연결은 교체)
+    pub fn save_tms_job(
+        &self,
+        project_id: &str,
+        job: &TmsJobLink,
+        segments: &[TmsSegmentLink],
+    ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM tms_segments WHERE project_id = ?1", [project_id])?;
+        tx.execute(
+            "INSERT OR REPLACE INTO tms_jobs (
+                project_id, provider, remote_project, job_id, job_name, target_lang, document, pulled_at, pushed_at
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            (
+                project_id,
+                &job.provider,
+                &job.remote_project,
+                &job.job_id,
+                &job.job_name,
+                &job.target_lang,
+                job.document.as_deref(),
+                job.pulled_at,
+                job.pushed_at,
+            ),
+        )?;
+        for s in segments {
+            tx.execute(
+                "INSERT INTO tms_segments (project_id, segment_id, remote_id, base_target)
+                 VALUES (?1, ?2, ?3, ?4)",
+                (project_id, &s.segment_id, &s.remote_id, &s.base_target),
+            )?;
+        }
+        let payload = format!("{}\t{}\t{}", job.provider, job.remote_project, job.job_id);
+        record_change(
+            &tx,
+            &Change::new("tms_job", project_id, ChangeOp::Upsert)
+                .project(project_id)
+                .payload(&payload),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 프로젝트의 원격 작업 연결 (없으면 None)
+    pub fn get_tms_job(&self, project_id: &str) -> Result<Option<TmsJobLink>, IteError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT provider, remote_project, job_id, job_name, target_lang, document, pulled_at, pushed_at
+                 FROM tms_jobs WHERE project_id = ?1",
+                [project_id],
+                |row| {
+                    Ok(TmsJobLink {
+                        provider: row.get(0)?,
+                        remote_project: row.get(1)?,
+                        job_id: row.get(2)?,
+                        job_name: row.get(3)?,
+                        target_lang: row.get(4)?,
+                        document: row.get(5)?,
+                        pulled_at: row.get(6)?,
+                        pushed_at: row.get(7)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    /// 프로젝트의 세그먼트 연결
+    pub fn list_tms_segments(&self, project_id: &str) -> Result<Vec<TmsSegmentLink>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT segment_id, remote_id, base_target FROM tms_segments WHERE project_id = ?1",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(TmsSegmentLink {
+                segment_id: row.get(0)?,
+                remote_id: row.get(1)?,
+                base_target: row.get(2)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 올리기 완료 기록 (올린 세그먼트의 기준 번역문 갱신)
+    pub fn mark_tms_pushed(
+        &self,
+        project_id: &str,
+        pushed: &[TmsSegmentLink],
+        document: Option<&str>,
+        pushed_at: i64,
+    ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        for s in pushed {
+            tx.execute(
+                "UPDATE tms_segments SET base_target = ?3 WHERE project_id = ?1 AND segment_id = ?2",
+                (project_id, &s.segment_id, &s.base_target),
+            )?;
+        }
+        tx.execute(
+            "UPDATE tms_jobs SET pushed_at = ?2, document = COALESCE(?3, document) WHERE project_id = ?1",
+            (project_id, pushed_at, document),
+        )?;
+        record_change(&tx, &Change::new("tms_job", project_id, ChangeOp::Update).project(project_id))?;
+        tx.commit()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Project Trash (Soft Delete)
+//!
+//! 프로젝트 삭제는 먼저 휴지통으로 이동(`projects.deleted_at` 기록)하고,
+//! 복원하거나 비우기(purge)로 영구 삭제합니다.
+
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::{delete_project_rows, Database};
+use crate::error::IteError;
+
+/// 휴지통 프로젝트 항목
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedProjectRow {
+    pub id: String,
+    pub title: String,
+    pub updated_at: i64,
+    pub deleted_at: i64,
+}
+
+impl Database {
+    /// 프로젝트를 휴지통으로 이동 (이미 휴지통에 있으면 그대로)
+    pub fn trash_project(&self, project_id: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let changed = tx.execute(
+            "UPDATE projects SET deleted_at = COALESCE(deleted_at, ?1) WHERE id = ?2",
+            (chrono::Utc::now().timestamp_millis(), project_id),
+        )?;
+        if changed == 0 {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
+        }
+        record_change(
+            &tx,
+            &Change::new("project", project_id, ChangeOp::Update)
+                .project(project_id)
+                .payload("trashed"),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 모든 활성 프로젝트를 휴지통으로 이동, 이동한 프로젝트 수 반환
+    pub fn trash_all_projects(&self) -> Result<usize, IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let ids: Vec<String> = {
+            let mut stmt = tx.prepare("UPDATE projects SET deleted_at = ?1 WHERE deleted_at IS NULL RETURNING id")?;
+            let rows = stmt.query_map([chrono::Utc::now().timestamp_millis()], |row| row.get(0))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for id in &ids {
+            record_change(&tx, &Change::new("project", id, ChangeOp::Update).project(id).payload("trashed"))?;
+        }
+        tx.commit()?;
+        Ok(ids.len())
+    }
+
+    /// 휴지통 프로젝트 목록 (최근 삭제 순)
+    pub fn list_trashed_projects(&self) -> Result<Vec<TrashedProjectRow>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, COALESCE(json_extract(metadata_json, '$.title'), 'Untitled Project'), updated_at, deleted_at
+             FROM projects WHERE deleted_at IS NOT NULL
+             ORDER BY deleted_at DESC",
+        )?;
+        let iter = stmt.query_map([], |row| {
+            Ok(TrashedProjectRow {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                updated_at: row.get(2)?,
+                deleted_at: row.get(3)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 휴지통 프로젝트 복원
+    pub fn restore_project(&self, project_id: &str) -> Result<(), IteError> {
+        let tx = self.conn.unchecked_transaction()?;
+        let changed = tx.execute(
+            "UPDATE projects SET deleted_at = NULL WHERE id = ?1 AND deleted_at IS NOT NULL",
+            [project_id],
+        )?;
+        if changed == 0 {
+            return Err(IteError::ProjectNotFound(project_id.to_string()));
+        }
+        record_change(
+            &tx,
+            &Change::new("project", project_id, ChangeOp::Update)
+                .project(project_id)
+                .payload("restored"),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 휴지통 비우기: cutoff(Unix epoch ms) 이전에 삭제된 프로젝트를 영구 삭제
+    /// - cutoff가 None이면 휴지통 전체를 비웁니다. 삭제한 프로젝트 수를 반환합니다.
+    pub fn purge_trash(&self, cutoff: Option<i64>) -> Result<usize, IteError> {
+        // 중간에 실패하면 전부 되돌림 (일부 연관 데이터만 남지 않도록)
+        let tx = self.conn.unchecked_transaction()?;
+        let ids: Vec<String> = {
+            let mut stmt = tx.prepare(
+                "SELECT id FROM projects WHERE deleted_at IS NOT NULL AND (?1 IS NULL OR deleted_at < ?1)",
+            )?;
+            let iter = stmt.query_map([cutoff], |row| row.get::<_, String>(0))?;
+            let mut out = Vec::new();
+            for r in iter {
+                out.push(r?);
+            }
+            out
+        };
+
+        for id in &ids {
+            delete_project_rows(&tx, id)?;
+        }
+        tx.commit()?;
+        Ok(ids.len())
+    }
+}
@@ -0,0 +1,144 @@
+//! Storage Usage
+//!
+//! 프로젝트별 DB 사용량 집계와 VACUUM으로 회수할 수 있는 빈 페이지 크기
+//! - 주요 텍스트 컬럼의 바이트 길이 합이라 인덱스/페이지 오버헤드는 포함하지 않습니다 (대략치).
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::Database;
+use crate::error::IteError;
+
+/// 프로젝트 1개의 저장 용량 (bytes)
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStorageUsage {
+    pub project_id: String,
+    pub title: String,
+    /// 휴지통에 있는 프로젝트
+    pub trashed: bool,
+    /// 블록/세그먼트
+    pub document_bytes: u64,
+    /// 히스토리 스냅샷
+    pub history_bytes: u64,
+    /// 편집 기록/리뷰 수정/TM
+    pub log_bytes: u64,
+    pub chat_bytes: u64,
+    /// 첨부 추출 텍스트
+    pub attachment_text_bytes: u64,
+    pub attachments: usize,
+    /// 첨부 원본 파일 크기 합 (DB 밖, 원래 위치에 있는 파일)
+    pub attachment_file_bytes: u64,
+    /// DB 안에서 차지하는 용량 합 (원본 파일 제외)
+    pub total_bytes: u64,
+}
+
+impl Database {
+    /// 프로젝트별 저장 용량 (큰 순)
+    pub fn project_storage_usage(&self) -> Result<Vec<ProjectStorageUsage>, IteError> {
+        let mut usage: HashMap<String, ProjectStorageUsage> = HashMap::new();
+        {
+            let mut stmt = self.conn.prepare(
+                "SELECT id, COALESCE(json_extract(metadata_json, '$.title'), ''), deleted_at IS NOT NULL FROM projects",
+            )?;
+            let iter = stmt.query_map([], |row| {
+                Ok(ProjectStorageUsage {
+                    project_id: row.get(0)?,
+                    title: row.get(1)?,
+                    trashed: row.get(2)?,
+                    ..Default::default()
+                })
+            })?;
+            for r in iter {
+                let project = r?;
+                usage.insert(project.project_id.clone(), project);
+            }
+        }
+
+        // (집계 쿼리, 더할 필드) — 각 쿼리는 project_id별 바이트 합을 돌려줌
+        type Field = fn(&mut ProjectStorageUsage) -> &mut u64;
+        let queries: [(&str, Field); 6] = [
+            (
+                "SELECT project_id, SUM(length(CAST(content AS BLOB)) + length(CAST(metadata_json AS BLOB)))
+                 FROM blocks GROUP BY project_id",
+                |u| &mut u.document_bytes,
+            ),
+            (
+                "SELECT project_id, SUM(length(CAST(source_ids AS BLOB)) + length(CAST(target_ids AS BLOB)))
+                 FROM segments GROUP BY project_id",
+                |u| &mut u.document_bytes,
+            ),
+            (
+                "SELECT project_id, SUM(length(CAST(changes_json AS BLOB)) + COALESCE(length(CAST(state_json AS BLOB)), 0)
+                        + COALESCE(length(CAST(chat_summary AS BLOB)), 0))
+                 FROM history GROUP BY project_id",
+                |u| &mut u.history_bytes,
+            ),
+            (
+                "SELECT project_id, SUM(n) FROM (
+                    SELECT project_id, length(CAST(content AS BLOB)) AS n FROM block_edits
+                    UNION ALL
+                    SELECT project_id, length(CAST(base_content AS BLOB)) + length(CAST(revised_content AS BLOB))
+                           + length(CAST(diff_json AS BLOB)) FROM revisions
+                    UNION ALL
+                    SELECT project_id, length(CAST(source AS BLOB)) + length(CAST(target AS BLOB))
+                    FROM tm_units WHERE project_id IS NOT NULL
+                 ) GROUP BY project_id",
+                |u| &mut u.log_bytes,
+            ),
+            (
+                "SELECT s.project_id, SUM(length(CAST(m.content AS BLOB)) + COALESCE(length(CAST(m.metadata_json AS BLOB)), 0))
+                 FROM chat_messages m JOIN chat_sessions s ON s.id = m.session_id
+                 GROUP BY s.project_id",
+                |u| &mut u.chat_bytes,
+            ),
+            (
+                "SELECT project_id, SUM(COALESCE(length(CAST(extracted_text AS BLOB)), 0))
+                 FROM attachments GROUP BY project_id",
+                |u| &mut u.attachment_text_bytes,
+            ),
+        ];
+        for (sql, field) in queries {
+            let mut stmt = self.conn.prepare(sql)?;
+            let iter = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<i64>>(1)?)))?;
+            for r in iter {
+                let (project_id, bytes) = r?;
+                if let Some(project) = usage.get_mut(&project_id) {
+                    *field(project) += bytes.unwrap_or(0).max(0) as u64;
+                }
+            }
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT project_id, COUNT(*), COALESCE(SUM(file_size), 0) FROM attachments GROUP BY project_id")?;
+        let iter = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, i64>(2)?))
+        })?;
+        for r in iter {
+            let (project_id, count, file_bytes) = r?;
+            if let Some(project) = usage.get_mut(&project_id) {
+                project.attachments = count as usize;
+                project.attachment_file_bytes = file_bytes.max(0) as u64;
+            }
+        }
+
+        let mut out: Vec<ProjectStorageUsage> = usage
+            .into_values()
+            .map(|mut u| {
+                u.total_bytes = u.document_bytes + u.history_bytes + u.log_bytes + u.chat_bytes + u.attachment_text_bytes;
+                u
+            })
+            .collect();
+        out.sort_by(|a, b| b.total_bytes.cmp(&a.total_bytes).then_with(|| a.title.cmp(&b.title)));
+        Ok(out)
+    }
+
+    /// 빈 페이지 크기 (VACUUM으로 회수 가능한 bytes)
+    pub fn free_page_bytes(&self) -> Result<i64, IteError> {
+        let free_pages: i64 = self.conn.query_row("PRAGMA freelist_count", [], |row| row.get(0))?;
+        let page_size: i64 = self.conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        Ok(free_pages * page_size)
+    }
+}
@@ -0,0 +1,192 @@
+//! XLIFF Round-trip Storage
+//!
+//! SDLXLIFF로 가져온 프로젝트를 다시 내보내기 위한 원본 파일과 세그먼트 연결 정보.
+//! - `xliff_documents`: 원본 파일 내용 (내보내기 골격)
+//! - `xliff_segments`: ITE 세그먼트 ↔ (파일, trans-unit id, mid), Trados 상태/잠금, 인라인 태그
+
+use serde::Serialize;
+
+use super::changes::{record_change, Change, ChangeOp};
+use super::Database;
+use crate::error::IteError;
+use crate::text::Placeholder;
+
+/// 가져온 XLIFF 원본 파일
+#[derive(Debug, Clone)]
+pub struct XliffDocument {
+    /// 패키지 안 상대 경로 (단일 파일이면 파일 이름)
+    pub file_name: String,
+    pub format: String,
+    pub content: String,
+}
+
+/// ITE 세그먼트 ↔ XLIFF 세그먼트 연결
+#[derive(Debug, Clone)]
+pub struct XliffSegmentRef {
+    pub segment_id: String,
+    pub file_name: String,
+    pub unit_id: String,
+    pub mid: String,
+    pub status: Option<String>,
+    pub locked: bool,
+    pub placeholders: Vec<Placeholder>,
+}
+
+/// 세그먼트 상태 (에디터 표시용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XliffSegmentStatus {
+    pub segment_id: String,
+    pub file_name: String,
+    /// Trados conf 값 (None이면 미번역)
+    pub status: Option<String>,
+    pub locked: bool,
+}
+
+impl Database {
+    /// 가져온 XLIFF 파일과 세그먼트 연결 저장 (같은 파일 이름은 덮어씀)
+    pub fn save_xliff_import(
+        &self,
+        project_id: &str,
+        documents: &[XliffDocument],
+        refs: &[XliffSegmentRef],
+    ) -> Result<(), IteError> {
+        self.ensure_project_writable(project_id)?;
+        let now = chrono::Utc::now().timestamp_millis();
+        let tx = self.conn.unchecked_transaction()?;
+        for doc in documents {
+            tx.execute(
+                "INSERT INTO xliff_documents (project_id, file_name, format, content, imported_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT(project_id, file_name) DO UPDATE SET
+                    format = excluded.format,
+                    content = excluded.content,
+                    imported_at = excluded.imported_at",
+                (project_id, &doc.file_name, &doc.format, &doc.content, now),
+            )?;
+            record_change(
+                &tx,
+                &Change::new("xliff_document", &doc.file_name, ChangeOp::Upsert)
+                    .project(project_id)
+                    .payload(&doc.content),
+            )?;
+        }
+        for r in refs {
+            tx.execute(
+                "INSERT INTO xliff_segments (
+                    project_id, segment_id, file_name, unit_id, mid, status, locked, placeholders_json
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT(project_id, segment_id) DO UPDATE SET
+                    file_name = excluded.file_name,
+                    unit_id = excluded.unit_id,
+                    mid = excluded.mid,
+                    status = excluded.status,
+                    locked = excluded.locked,
+                    placeholders_json = excluded.placeholders_json",
+                (
+                    project_id,
+                    &r.segment_id,
+                    &r.file_name,
+                    &r.unit_id,
+                    &r.mid,
+                    r.status.as_deref(),
+                    r.locked,
+                    serde_json::to_string(&r.placeholders)?,
+                ),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 프로젝트의 XLIFF 원본 파일 (파일 이름 순)
+    pub fn list_xliff_documents(&self, project_id: &str) -> Result<Vec<XliffDocument>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT file_name, format, content FROM xliff_documents
+             WHERE project_id = ?1 ORDER BY file_name",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            Ok(XliffDocument {
+                file_name: row.get(0)?,
+                format: row.get(1)?,
+                content: row.get(2)?,
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 프로젝트의 세그먼트 연결 정보
+    pub fn list_xliff_segment_refs(&self, project_id: &str) -> Result<Vec<XliffSegmentRef>, IteError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT segment_id, file_name, unit_id, mid, status, locked, placeholders_json
+             FROM xliff_segments WHERE project_id = ?1",
+        )?;
+        let iter = stmt.query_map([project_id], |row| {
+            let placeholders_json: String = row.get(6)?;
+            Ok(XliffSegmentRef {
+                segment_id: row.get(0)?,
+                file_name: row.get(1)?,
+                unit_id: row.get(2)?,
+                mid: row.get(3)?,
+                status: row.get(4)?,
+                locked: row.get(5)?,
+                placeholders: serde_json::from_str(&placeholders_json).unwrap_or_default(),
+            })
+        })?;
+
+        let mut out = Vec::new();
+        for r in iter {
+            out.push(r?);
+        }
+        Ok(out)
+    }
+
+    /// 세그먼트 상태 목록
+    pub fn list_xliff_segment_statuses(&self, project_id: &str) -> Result<Vec<XliffSegmentStatus>, IteError> {
+        Ok(self
+            .list_xliff_segment_refs(project_id)?
+            .into_iter()
+            .map(|r| XliffSegmentStatus {
+                segment_id: r.segment_id,
+                file_name: r.file_name,
+                status: r.status,
+                locked: r.locked,
+            })
+            .collect())
+    }
+
+    /// 세그먼트 상태 변경 (잠긴 세그먼트는 제외), 반환: 변경된 세그먼트 수
+    pub fn set_xliff_segment_status(
+        &self,
+        project_id: &str,
+        segment_ids: &[String],
+        status: Option<&str>,
+    ) -> Result<usize, IteError> {
+        self.ensure_project_writable(project_id)?;
+        let tx = self.conn.unchecked_transaction()?;
+        let mut changed = 0;
+        for segment_id in segment_ids {
+            let updated = tx.execute(
+                "UPDATE xliff_segments SET status = ?3
+                 WHERE project_id = ?1 AND segment_id = ?2 AND locked = 0",
+                (project_id, segment_id, status),
+            )?;
+            if updated > 0 {
+                record_change(
+                    &tx,
+                    &Change::new("xliff_segment", segment_id, ChangeOp::Update)
+                        .project(project_id)
+                        .payload(status.unwrap_or("")),
+                )?;
+            }
+            changed += updated;
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+}
@@ -0,0 +1,272 @@
+//! Environment Profiles
+//!
+//! 개발/배포 프로필에 맞는 `.env*` 파일을 찾아 환경 변수로 불러옵니다 (Brave Search API 등 백엔드 전용 키).
+//! - 프로필: `ITE_ENV`("development" | "production"), 없으면 debug 빌드는 development, release는 production
+//! - 우선순위 (Vite와 동일): `.env.{profile}.local` > `.env.{profile}` > `.env.local` > `.env`
+//! - 이미 값이 있는(비어 있지 않은) 환경 변수는 덮어쓰지 않습니다.
+//! - 파일이 markdown(코드펜스 등)을 포함하면 dotenvy(strict)가 실패할 수 있어, lenient(KEY=VALUE 라인만)로 다시 읽습니다.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+/// 프로필 지정 환경 변수
+pub const PROFILE_ENV_VAR: &str = "ITE_ENV";
+
+/// 지원 프로필
+pub const PROFILES: &[&str] = &["development", "production"];
+
+/// 이름에 이 단어가 들어간 키는 값을 보여주지 않음
+const SECRET_KEY_MARKERS: &[&str] = &["KEY", "SECRET", "TOKEN", "PASSWORD", "PASS", "CREDENTIAL", "PRIVATE"];
+
+/// 불러온 키 1개
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvKeyInfo {
+    pub key: String,
+    /// 값을 가져온 파일
+    pub source: String,
+    /// 비밀로 보이는 키면 None
+    pub value: Option<String>,
+    pub secret: bool,
+}
+
+/// 후보 파일 1개
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvFileInfo {
+    pub name: String,
+    /// 찾은 경로 (없으면 None)
+    pub path: Option<String>,
+    /// 이 파일에서 새로 설정한 키 수 (우선순위가 높은 파일/기존 환경 변수에 가려진 키 제외)
+    pub loaded_keys: usize,
+    /// strict 파서가 실패해 lenient로 읽은 경우
+    pub lenient: bool,
+}
+
+/// 환경 설정 진단 정보
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvInfo {
+    pub profile: String,
+    /// 우선순위 순
+    pub files: Vec<EnvFileInfo>,
+    pub keys: Vec<EnvKeyInfo>,
+}
+
+#[derive(Default)]
+struct LoadedEnv {
+    profile: String,
+    files: Vec<EnvFileInfo>,
+    /// (키, 파일 이름) - 다시 불러올 때 지울 대상
+    keys: Vec<(String, String)>,
+}
+
+static LOADED: Mutex<Option<LoadedEnv>> = Mutex::new(None);
+
+/// 현재 프로필
+pub fn current_profile() -> String {
+    match std::env::var(PROFILE_ENV_VAR) {
+        Ok(p) if PROFILES.contains(&p.trim()) => p.trim().to_string(),
+        _ if cfg!(debug_assertions) => "development".to_string(),
+        _ => "production".to_string(),
+    }
+}
+
+/// 프로필의 후보 파일 이름 (우선순위 순)
+pub fn env_file_names(profile: &str) -> Vec<String> {
+    vec![
+        format!(".env.{}.local", profile),
+        format!(".env.{}", profile),
+        ".env.local".to_string(),
+        ".env".to_string(),
+    ]
+}
+
+fn is_valid_env_key(key: &str) -> bool {
+    if key.is_empty() {
+        return false;
+    }
+    // 관례적으로 ENV 키는 A-Z0-9_ 로 제한 (VITE_*, BRAVE_* 등)
+    key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn is_secret_key(key: &str) -> bool {
+    SECRET_KEY_MARKERS.iter().any(|m| key.contains(m))
+}
+
+/// lenient 파서: 주석/코드펜스/설명 라인은 무시하고 KEY=VALUE만 읽음
+fn parse_env_lenient(text: &str) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    for raw_line in text.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("```") {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line).trim();
+        let Some((k, v)) = line.split_once('=') else {
+            continue;
+        };
+        let key = k.trim();
+        if !is_valid_env_key(key) {
+            continue;
+        }
+
+        let mut value = v.trim().to_string();
+        // 간단한 quote 제거 ("..." / '...')
+        if value.len() >= 2
+            && ((value.starts_with('"') && value.ends_with('"')) || (value.starts_with('\'') && value.ends_with('\'')))
+        {
+            value = value[1..value.len() - 1].to_string();
+        }
+        out.push((key.to_string(), value));
+    }
+    out
+}
+
+/// 파일 읽기 (strict 우선, 실패하면 lenient), (키/값 목록, lenient 여부)
+fn read_env_file(path: &Path) -> Option<(Vec<(String, String)>, bool)> {
+    if let Ok(iter) = dotenvy::from_path_iter(path) {
+        if let Ok(pairs) = iter.collect::<Result<Vec<_>, _>>() {
+            return Some((pairs, false));
+        }
+    }
+    let text = std::fs::read_to_string(path).ok()?;
+    Some((parse_env_lenient(&text), true))
+}
+
+fn find_upwards(start: PathBuf, filename: &str, max_hops: usize) -> Option<PathBuf> {
+    let mut cur = start;
+    for _ in 0..=max_hops {
+        let candidate = cur.join(filename);
+        if candidate.exists() {
+            return Some(candidate);
+        }
+        if !cur.pop() {
+            break;
+        }
+    }
+    None
+}
+
+/// 파일 위치 찾기: CWD에서 위로, 그다음 실행 파일 위치에서 위로 (cargo run/tauri dev 환경 대응)
+fn locate(filename: &str) -> Option<PathBuf> {
+    if let Ok(cwd) = std::env::current_dir() {
+        if let Some(p) = find_upwards(cwd, filename, 6) {
+            return Some(p);
+        }
+    }
+    let exe = std::env::current_exe().ok()?;
+    find_upwards(exe.parent()?.to_path_buf(), filename, 8)
+}
+
+fn has_value(key: &str) -> bool {
+    std::env::var(key).is_ok_and(|v| !v.trim().is_empty())
+}
+
+/// 프로필에 맞는 `.env*` 파일 불러오기 (앱 시작 시)
+pub fn load_env() {
+    let profile = current_profile();
+    let mut loaded = LoadedEnv {
+        profile: profile.clone(),
+        ..Default::default()
+    };
+
+    for name in env_file_names(&profile) {
+        let path = locate(&name);
+        let mut file = EnvFileInfo {
+            name: name.clone(),
+            path: path.as_ref().map(|p| p.to_string_lossy().to_string()),
+            loaded_keys: 0,
+            lenient: false,
+        };
+        if let Some((pairs, lenient)) = path.as_deref().and_then(read_env_file) {
+            file.lenient = lenient;
+            for (key, value) in pairs {
+                if has_value(&key) {
+                    continue;
+                }
+                std::env::set_var(&key, value);
+                loaded.keys.push((key, name.clone()));
+                file.loaded_keys += 1;
+            }
+        }
+        loaded.files.push(file);
+    }
+
+    *LOADED.lock().unwrap_or_else(|e| e.into_inner()) = Some(loaded);
+}
+
+/// 파일에서 불러온 키를 지우고 다시 불러오기 (개발 중 `.env*` 수정 반영)
+/// - 앱 밖에서 설정된 환경 변수는 그대로 둡니다.
+pub fn reload_env() -> EnvInfo {
+    if let Some(previous) = LOADED.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        for (key, _) in previous.keys {
+            std::env::remove_var(key);
+        }
+    }
+    load_env();
+    env_info()
+}
+
+/// 불러온 파일/키 목록 (비밀로 보이는 키는 값 생략)
+pub fn env_info() -> EnvInfo {
+    let guard = LOADED.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(loaded) = guard.as_ref() else {
+        return EnvInfo {
+            profile: current_profile(),
+            files: Vec::new(),
+            keys: Vec::new(),
+        };
+    };
+
+    let mut keys: Vec<EnvKeyInfo> = loaded
+        .keys
+        .iter()
+        .map(|(key, source)| {
+            let secret = is_secret_key(key);
+            EnvKeyInfo {
+                key: key.clone(),
+                source: source.clone(),
+                value: if secret { None } else { std::env::var(key).ok() },
+                secret,
+            }
+        })
+        .collect();
+    keys.sort_by(|a, b| a.key.cmp(&b.key));
+
+    EnvInfo {
+        profile: loaded.profile.clone(),
+        files: loaded.files.clone(),
+        keys,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_files_in_priority_order() {
+        assert_eq!(
+            env_file_names("production"),
+            vec![".env.production.local", ".env.production", ".env.local", ".env"]
+        );
+    }
+
+    #[test]
+    fn test_lenient_parser_skips_markdown() {
+        let text = "# Setup\n```\nexport BRAVE_API_KEY=\"abc\"\nnot a pair\nlower=x\nVITE_MODE='dev'\n```";
+        assert_eq!(
+            parse_env_lenient(text),
+            vec![
+                ("BRAVE_API_KEY".to_string(), "abc".to_string()),
+                ("VITE_MODE".to_string(), "dev".to_string()),
+            ]
+        );
+        assert!(is_secret_key("BRAVE_API_KEY"));
+        assert!(!is_secret_key("VITE_MODE"));
+    }
+}
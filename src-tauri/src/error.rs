@@ -28,36 +28,293 @@ pub enum IteError {
 
     #[error("Invalid operation: {0}")]
     InvalidOperation(String),
+
+    #[error("Project is read-only: {0}")]
+    ReadOnlyProject(String),
+}
+
+/// 명령 오류 코드 레지스트리 (모든 모듈 공용)
+/// - 프론트엔드는 메시지 문자열 대신 이 코드로 오류 UX(재시도, 재로그인 안내 등)를 고릅니다.
+/// - 직렬화 값은 `as_str()`의 SCREAMING_SNAKE_CASE 문자열입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    // 공통
+    LockError,
+    DbError,
+    IoError,
+    SerializationError,
+    InvalidInput,
+    InvalidOperation,
+    NotFound,
+    ProjectNotFound,
+    BlockNotFound,
+    SegmentNotFound,
+    ReadOnly,
+    // 파일/경로
+    PathError,
+    SecurityError,
+    ReadError,
+    WriteError,
+    ReadDirError,
+    DirCreateError,
+    FileError,
+    FileTooLarge,
+    ExtractError,
+    InvalidType,
+    // 입력 형식
+    InvalidPattern,
+    InvalidKey,
+    TokenMismatch,
+    // 외부 연동 공통
+    NetworkError,
+    Timeout,
+    AuthRequired,
+    RateLimited,
+    /// 오프라인 모드이거나 연결이 없어 요청하지 않음
+    Offline,
+    // 모듈별
+    QaError,
+    McpError,
+    NotionError,
+    ConnectorError,
+    ConfluenceError,
+    CloudError,
+    TmsError,
+    SyncError,
+    UpdateError,
+    SecretManagerError,
+    SecureStoreError,
+    ClipboardError,
+    // 내부
+    InternalPanic,
+}
+
+impl ErrorCode {
+    /// 등록된 전체 코드 (프론트엔드 매핑 점검용)
+    pub const ALL: &'static [ErrorCode] = &[
+        ErrorCode::LockError,
+        ErrorCode::DbError,
+        ErrorCode::IoError,
+        ErrorCode::SerializationError,
+        ErrorCode::InvalidInput,
+        ErrorCode::InvalidOperation,
+        ErrorCode::NotFound,
+        ErrorCode::ProjectNotFound,
+        ErrorCode::BlockNotFound,
+        ErrorCode::SegmentNotFound,
+        ErrorCode::ReadOnly,
+        ErrorCode::PathError,
+        ErrorCode::SecurityError,
+        ErrorCode::ReadError,
+        ErrorCode::WriteError,
+        ErrorCode::ReadDirError,
+        ErrorCode::DirCreateError,
+        ErrorCode::FileError,
+        ErrorCode::FileTooLarge,
+        ErrorCode::ExtractError,
+        ErrorCode::InvalidType,
+        ErrorCode::InvalidPattern,
+        ErrorCode::InvalidKey,
+        ErrorCode::TokenMismatch,
+        ErrorCode::NetworkError,
+        ErrorCode::Timeout,
+        ErrorCode::AuthRequired,
+        ErrorCode::RateLimited,
+        ErrorCode::Offline,
+        ErrorCode::QaError,
+        ErrorCode::McpError,
+        ErrorCode::NotionError,
+        ErrorCode::ConnectorError,
+        ErrorCode::ConfluenceError,
+        ErrorCode::CloudError,
+        ErrorCode::TmsError,
+        ErrorCode::SyncError,
+        ErrorCode::UpdateError,
+        ErrorCode::SecretManagerError,
+        ErrorCode::SecureStoreError,
+        ErrorCode::ClipboardError,
+        ErrorCode::InternalPanic,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::LockError => "LOCK_ERROR",
+            ErrorCode::DbError => "DB_ERROR",
+            ErrorCode::IoError => "IO_ERROR",
+            ErrorCode::SerializationError => "SERIALIZATION_ERROR",
+            ErrorCode::InvalidInput => "INVALID_INPUT",
+            ErrorCode::InvalidOperation => "INVALID_OPERATION",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::ProjectNotFound => "PROJECT_NOT_FOUND",
+            ErrorCode::BlockNotFound => "BLOCK_NOT_FOUND",
+            ErrorCode::SegmentNotFound => "SEGMENT_NOT_FOUND",
+            ErrorCode::ReadOnly => "READ_ONLY",
+            ErrorCode::PathError => "PATH_ERROR",
+            ErrorCode::SecurityError => "SECURITY_ERROR",
+            ErrorCode::ReadError => "READ_ERROR",
+            ErrorCode::WriteError => "WRITE_ERROR",
+            ErrorCode::ReadDirError => "READ_DIR_ERROR",
+            ErrorCode::DirCreateError => "DIR_CREATE_ERROR",
+            ErrorCode::FileError => "FILE_ERROR",
+            ErrorCode::FileTooLarge => "FILE_TOO_LARGE",
+            ErrorCode::ExtractError => "EXTRACT_ERROR",
+            ErrorCode::InvalidType => "INVALID_TYPE",
+            ErrorCode::InvalidPattern => "INVALID_PATTERN",
+            ErrorCode::InvalidKey => "INVALID_KEY",
+            ErrorCode::TokenMismatch => "TOKEN_MISMATCH",
+            ErrorCode::NetworkError => "NETWORK_ERROR",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::AuthRequired => "AUTH_REQUIRED",
+            ErrorCode::RateLimited => "RATE_LIMITED",
+            ErrorCode::Offline => "OFFLINE",
+            ErrorCode::QaError => "QA_ERROR",
+            ErrorCode::McpError => "MCP_ERROR",
+            ErrorCode::NotionError => "NOTION_ERROR",
+            ErrorCode::ConnectorError => "CONNECTOR_ERROR",
+            ErrorCode::ConfluenceError => "CONFLUENCE_ERROR",
+            ErrorCode::CloudError => "CLOUD_ERROR",
+            ErrorCode::TmsError => "TMS_ERROR",
+            ErrorCode::SyncError => "SYNC_ERROR",
+            ErrorCode::UpdateError => "UPDATE_ERROR",
+            ErrorCode::SecretManagerError => "SECRET_MANAGER_ERROR",
+            ErrorCode::SecureStoreError => "SECURE_STORE_ERROR",
+            ErrorCode::ClipboardError => "CLIPBOARD_ERROR",
+            ErrorCode::InternalPanic => "INTERNAL_PANIC",
+        }
+    }
+
+    /// 같은 요청을 잠시 뒤 다시 보내면 성공할 수 있는 코드 (기본값, 오류별로 덮어쓸 수 있음)
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::LockError | ErrorCode::NetworkError | ErrorCode::Timeout | ErrorCode::RateLimited
+        )
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
 /// Tauri 명령 응답용 직렬화 가능한 에러
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CommandError {
-    pub code: String,
+    pub code: ErrorCode,
     pub message: String,
     pub details: Option<String>,
+    /// 다시 시도하면 성공할 수 있는 오류 (잠금 경합, 네트워크, DB busy 등)
+    pub retryable: bool,
+    /// 원인 오류 메시지 (바깥 → 안쪽, 진단용)
+    pub source_chain: Vec<String>,
+    /// 코드별 사용자용 문구 (앱 설정 언어)
+    pub localized_message: String,
+}
+
+impl CommandError {
+    /// 코드 기본 재시도 여부로 생성
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            details: None,
+            retryable: code.is_retryable(),
+            source_chain: Vec::new(),
+            localized_message: crate::i18n::error_message(code, crate::i18n::current_locale()).to_string(),
+        }
+    }
+
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// 원인 오류와 그 `source()` 체인을 `source_chain`에 추가
+    pub fn with_source(mut self, source: &(dyn std::error::Error + 'static)) -> Self {
+        let mut current = Some(source);
+        while let Some(e) = current {
+            self.source_chain.push(e.to_string());
+            current = e.source();
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
 }
 
 impl From<IteError> for CommandError {
     fn from(error: IteError) -> Self {
         let code = match &error {
-            IteError::Database(_) => "DB_ERROR",
-            IteError::Io(_) => "IO_ERROR",
-            IteError::Serialization(_) => "SERIALIZATION_ERROR",
-            IteError::ProjectNotFound(_) => "PROJECT_NOT_FOUND",
-            IteError::BlockNotFound(_) => "BLOCK_NOT_FOUND",
-            IteError::SegmentNotFound(_) => "SEGMENT_NOT_FOUND",
-            IteError::InvalidOperation(_) => "INVALID_OPERATION",
+            IteError::Database(_) => ErrorCode::DbError,
+            IteError::Io(_) => ErrorCode::IoError,
+            IteError::Serialization(_) => ErrorCode::SerializationError,
+            IteError::ProjectNotFound(_) => ErrorCode::ProjectNotFound,
+            IteError::BlockNotFound(_) => ErrorCode::BlockNotFound,
+            IteError::SegmentNotFound(_) => ErrorCode::SegmentNotFound,
+            IteError::InvalidOperation(_) => ErrorCode::InvalidOperation,
+            IteError::ReadOnlyProject(_) => ErrorCode::ReadOnly,
         };
+        // 다른 연결/프로세스가 쓰는 중이면 잠시 뒤 재시도로 해결됨
+        let busy = matches!(
+            &error,
+            IteError::Database(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(e.code, rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+        );
 
-        CommandError {
-            code: code.to_string(),
-            message: error.to_string(),
-            details: None,
+        let mut command_error = CommandError::new(code, error.to_string());
+        if let Some(source) = std::error::Error::source(&error) {
+            command_error = command_error.with_source(source);
         }
+        command_error.retryable |= busy;
+        command_error
     }
 }
 
 /// Tauri 명령 결과 타입
 pub type CommandResult<T> = Result<T, CommandError>;
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_error_serializes_registry_code() {
+        let error = CommandError::from(IteError::Database(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+            None,
+        )));
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], "DB_ERROR");
+        assert_eq!(json["retryable"], true);
+        assert_eq!(json["sourceChain"].as_array().unwrap().len(), 2);
+
+        let json = serde_json::to_value(CommandError::new(ErrorCode::NotFound, "x").with_details("y")).unwrap();
+        assert_eq!(json["code"], "NOT_FOUND");
+        assert_eq!(json["retryable"], false);
+        assert_eq!(json["details"], "y");
+    }
+
+    #[test]
+    fn test_registry_codes_are_unique() {
+        let codes: std::collections::HashSet<&str> = ErrorCode::ALL.iter().map(|c| c.as_str()).collect();
+        assert_eq!(codes.len(), ErrorCode::ALL.len());
+    }
+}
@@ -0,0 +1,211 @@
+//! Bilingual Review DOCX Export
+//!
+//! Word로 검토하는 고객용 대역 문서: 세그먼트 번호 · 원문 · 번역문 · 상태 · 메모/코멘트를 표 한 행씩 렌더링합니다.
+//! - 가로 A4, 머리글 행에 배경색을 넣고 여러 줄 텍스트는 셀 안에서 문단으로 나눕니다.
+
+use std::collections::{HashMap, HashSet};
+
+use docx_rs::{
+    Docx, PageOrientationType, Paragraph, Run, Shading, ShdType, Table, TableCell, TableRow, WidthType,
+};
+use serde::Serialize;
+
+use super::format_timestamp;
+use crate::error::IteError;
+use crate::models::{IteProject, Revision};
+use crate::qa::segment_texts;
+
+/// 가로 A4 (twips)
+const PAGE_WIDTH: u32 = 16838;
+const PAGE_HEIGHT: u32 = 11906;
+
+/// 열 너비 (twips, #/원문/번역문/상태/메모·코멘트) — 기본 여백 기준 본문 너비에 맞춤
+const COLUMN_WIDTHS: [usize; 5] = [700, 4500, 4500, 1300, 2436];
+
+const HEADER_FILL: &str = "F2F2F2";
+
+/// 세그먼트 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentStatus {
+    /// 번역문 없음
+    Empty,
+    Translated,
+    /// 번역문 블록에 검토 대기 중인 리뷰어 수정이 있음
+    InReview,
+}
+
+impl SegmentStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Empty => "Not translated",
+            Self::Translated => "Translated",
+            Self::InReview => "In review",
+        }
+    }
+}
+
+/// 대역 문서 1행
+#[derive(Debug, Clone)]
+pub struct BilingualRow {
+    pub segment_no: usize,
+    pub source_text: String,
+    pub target_text: String,
+    pub status: SegmentStatus,
+    /// 번역가 메모
+    pub note: Option<String>,
+    /// 미해결 코멘트 ("작성자: 내용")
+    pub comments: Vec<String>,
+}
+
+/// 내보낸 문서 요약
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BilingualSummary {
+    pub segments: usize,
+    pub translated: usize,
+    pub in_review: usize,
+    pub notes: usize,
+    pub comments: usize,
+}
+
+/// 세그먼트 순서대로 행 수집 (`revisions`는 검토 대기 중인 수정, `notes`는 segment_id → 메모)
+pub fn collect_bilingual_rows(
+    project: &IteProject,
+    revisions: &[Revision],
+    notes: &HashMap<String, String>,
+) -> Vec<BilingualRow> {
+    let pending: HashSet<&str> = revisions
+        .iter()
+        .filter(|r| r.status == "pending")
+        .map(|r| r.block_id.as_str())
+        .collect();
+
+    let mut segments: Vec<_> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+
+    segments
+        .into_iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let (source_text, target_text) = segment_texts(project, segment);
+            let status = if segment.target_ids.iter().any(|id| pending.contains(id.as_str())) {
+                SegmentStatus::InReview
+            } else if target_text.trim().is_empty() {
+                SegmentStatus::Empty
+            } else {
+                SegmentStatus::Translated
+            };
+            let mut comments: Vec<_> = segment
+                .source_ids
+                .iter()
+                .chain(&segment.target_ids)
+                .filter_map(|id| project.blocks.get(id))
+                .flat_map(|b| b.metadata.comments.iter().flatten())
+                .filter(|c| !c.resolved)
+                .collect();
+            comments.sort_by_key(|c| c.created_at);
+
+            BilingualRow {
+                segment_no: i + 1,
+                source_text,
+                target_text,
+                status,
+                note: notes.get(&segment.group_id).cloned(),
+                comments: comments
+                    .into_iter()
+                    .map(|c| format!("{}: {}", c.author, c.content))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+pub fn summarize(rows: &[BilingualRow]) -> BilingualSummary {
+    let mut summary = BilingualSummary {
+        segments: rows.len(),
+        ..Default::default()
+    };
+    for row in rows {
+        match row.status {
+            SegmentStatus::Translated => summary.translated += 1,
+            SegmentStatus::InReview => summary.in_review += 1,
+            SegmentStatus::Empty => {}
+        }
+        summary.notes += usize::from(row.note.is_some());
+        summary.comments += row.comments.len();
+    }
+    summary
+}
+
+/// 줄마다 문단 하나인 셀
+fn text_cell(text: &str, width: usize, bold: bool) -> TableCell {
+    let mut cell = TableCell::new().width(width, WidthType::Dxa);
+    for line in text.split('\n') {
+        let mut run = Run::new().add_text(line);
+        if bold {
+            run = run.bold();
+        }
+        cell = cell.add_paragraph(Paragraph::new().add_run(run));
+    }
+    cell
+}
+
+/// 대역 문서를 DOCX 바이트로 렌더링
+pub fn render_bilingual_docx(title: &str, rows: &[BilingualRow]) -> Result<Vec<u8>, IteError> {
+    let header = TableRow::new(
+        ["#", "Source", "Target", "Status", "Notes / Comments"]
+            .iter()
+            .zip(COLUMN_WIDTHS)
+            .map(|(label, width)| {
+                text_cell(label, width, true).shading(Shading::new().shd_type(ShdType::Clear).fill(HEADER_FILL))
+            })
+            .collect(),
+    );
+    let mut table_rows = vec![header];
+    for row in rows {
+        let notes: Vec<String> = row
+            .note
+            .iter()
+            .map(|n| format!("Note: {}", n))
+            .chain(row.comments.iter().cloned())
+            .collect();
+        let cells = [
+            row.segment_no.to_string(),
+            row.source_text.clone(),
+            row.target_text.clone(),
+            row.status.label().to_string(),
+            notes.join("\n"),
+        ];
+        table_rows.push(TableRow::new(
+            cells
+                .iter()
+                .zip(COLUMN_WIDTHS)
+                .map(|(text, width)| text_cell(text, width, false))
+                .collect(),
+        ));
+    }
+
+    let summary = summarize(rows);
+    let meta = format!(
+        "Exported {} · {} segments · {} translated · {} in review · {} notes · {} comments",
+        format_timestamp(chrono::Utc::now().timestamp_millis()),
+        summary.segments,
+        summary.translated,
+        summary.in_review,
+        summary.notes,
+        summary.comments
+    );
+
+    let mut buf = std::io::Cursor::new(Vec::new());
+    Docx::new()
+        .page_size(PAGE_WIDTH, PAGE_HEIGHT)
+        .page_orient(PageOrientationType::Landscape)
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text(title).bold().size(32)))
+        .add_paragraph(Paragraph::new().add_run(Run::new().add_text(meta).size(18).color("656D76")))
+        .add_table(Table::new(table_rows).set_grid(COLUMN_WIDTHS.to_vec()))
+        .build()
+        .pack(&mut buf)
+        .map_err(|e| IteError::Io(std::io::Error::other(e)))?;
+    Ok(buf.into_inner())
+}
@@ -0,0 +1,202 @@
+//! Chat Session Export
+//!
+//! 채팅 세션을 고객 공유용 Markdown/HTML 문서로 렌더링합니다.
+//! - 메시지 메타데이터(버튼 상태, suggestion 등 UI 정보)는 포함하지 않습니다.
+
+use super::{escape_html, format_timestamp};
+use crate::models::ChatSession;
+
+/// 채팅 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ChatExportFormat {
+    /// "markdown"/"md", "html"/"htm" 문자열 파싱
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "markdown" | "md" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+fn role_label(role: &str) -> &str {
+    match role {
+        "user" => "User",
+        "assistant" => "Assistant",
+        "system" => "System",
+        other => other,
+    }
+}
+
+/// 세션을 지정한 형식의 문서 문자열로 렌더링
+pub fn render_chat_session(session: &ChatSession, format: ChatExportFormat) -> String {
+    match format {
+        ChatExportFormat::Markdown => render_markdown(session),
+        ChatExportFormat::Html => render_html(session),
+    }
+}
+
+fn render_markdown(session: &ChatSession) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("# {}\n\n", session.name));
+    out.push_str(&format!(
+        "_Exported {} · {} messages_\n",
+        format_timestamp(chrono::Utc::now().timestamp_millis()),
+        session.messages.len()
+    ));
+
+    for m in &session.messages {
+        out.push_str("\n---\n\n");
+        out.push_str(&format!(
+            "**{}** · {}\n\n",
+            role_label(&m.role),
+            format_timestamp(m.timestamp)
+        ));
+        out.push_str(m.content.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+fn render_html(session: &ChatSession) -> String {
+    let title = escape_html(&session.name);
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", title));
+    out.push_str(
+        "<style>\n\
+         body { font-family: -apple-system, 'Segoe UI', 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; max-width: 820px; margin: 2rem auto; padding: 0 1rem; color: #1f2328; }\n\
+         .meta { color: #656d76; font-size: 0.85rem; }\n\
+         .message { border-top: 1px solid #d0d7de; padding: 0.75rem 0; }\n\
+         .role { font-weight: 600; }\n\
+         .role.user { color: #0969da; }\n\
+         .role.assistant { color: #1a7f37; }\n\
+         .content { white-space: pre-wrap; word-break: break-word; margin-top: 0.4rem; }\n\
+         </style>\n",
+    );
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", title));
+    out.push_str(&format!(
+        "<p class=\"meta\">Exported {} · {} messages</p>\n",
+        format_timestamp(chrono::Utc::now().timestamp_millis()),
+        session.messages.len()
+    ));
+
+    for m in &session.messages {
+        let role_class = escape_html(&m.role);
+        out.push_str("<div class=\"message\">\n");
+        out.push_str(&format!(
+            "<div><span class=\"role {}\">{}</span> <span class=\"meta\">{}</span></div>\n",
+            role_class,
+            escape_html(role_label(&m.role)),
+            format_timestamp(m.timestamp)
+        ));
+        out.push_str(&format!(
+            "<div class=\"content\">{}</div>\n",
+            escape_html(m.content.trim_end())
+        ));
+        out.push_str("</div>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// 스냅샷 요약에서 메시지 하나의 최대 길이 (문자 수)
+const SUMMARY_MESSAGE_CHARS: usize = 280;
+
+/// 한 줄로 접고 길면 말줄임
+fn summary_line(text: &str) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= SUMMARY_MESSAGE_CHARS {
+        return collapsed;
+    }
+    let cut: String = collapsed.chars().take(SUMMARY_MESSAGE_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// 스냅샷에 붙일 채팅 요약 (최근 사용자/어시스턴트 대화를 `max_chars` 안에서 발췌)
+/// - 앞쪽에서 잘린 메시지 수를 첫 줄에 적습니다. 대화가 없으면 None.
+pub fn summarize_chat_session(session: &ChatSession, max_chars: usize) -> Option<String> {
+    let turns: Vec<_> = session
+        .messages
+        .iter()
+        .filter(|m| matches!(m.role.as_str(), "user" | "assistant"))
+        .filter(|m| !m.content.trim().is_empty())
+        .collect();
+
+    let mut lines = Vec::new();
+    let mut used = 0;
+    for m in turns.iter().rev() {
+        let line = format!("- {}: {}", role_label(&m.role), summary_line(&m.content));
+        let len = line.chars().count() + 1;
+        if used + len > max_chars && !lines.is_empty() {
+            break;
+        }
+        used += len;
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        return None;
+    }
+
+    let omitted = turns.len() - lines.len();
+    lines.reverse();
+    let mut out = format!("Chat: {}\n", session.name);
+    if omitted > 0 {
+        out.push_str(&format!("({} earlier messages omitted)\n", omitted));
+    }
+    out.push_str(&lines.join("\n"));
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ChatMessage;
+
+    fn message(role: &str, content: &str) -> ChatMessage {
+        ChatMessage {
+            id: content.to_string(),
+            role: role.to_string(),
+            content: content.to_string(),
+            timestamp: 0,
+            metadata: None,
+            artifacts: vec![],
+        }
+    }
+
+    #[test]
+    fn summary_keeps_latest_turns_within_budget() {
+        let session = ChatSession {
+            id: "s".to_string(),
+            name: "Terms".to_string(),
+            created_at: 0,
+            messages: vec![
+                message("user", "first question"),
+                message("system", "ignored"),
+                message("assistant", "first   answer\nwith lines"),
+                message("user", "keep \"Dashboard\" in English?"),
+                message("assistant", "Yes, it is a UI label."),
+            ],
+            context_block_ids: vec![],
+            confluence_search_enabled: true,
+        };
+
+        assert_eq!(
+            summarize_chat_session(&session, 80).as_deref(),
+            Some(
+                "Chat: Terms\n(2 earlier messages omitted)\n\
+                 - User: keep \"Dashboard\" in English?\n- Assistant: Yes, it is a UI label."
+            )
+        );
+        let full = summarize_chat_session(&session, 1000).unwrap();
+        assert!(full.contains("- Assistant: first answer with lines"));
+        assert!(!full.contains("ignored"));
+    }
+}
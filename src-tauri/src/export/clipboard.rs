@@ -0,0 +1,131 @@
+//! Clipboard Tables
+//!
+//! 선택한 세그먼트의 원문/번역문 쌍을 클립보드용 표(TSV/Markdown/HTML)로 렌더링
+//! - TSV는 스프레드시트에 바로 붙여 넣을 수 있도록 탭/줄바꿈/따옴표가 있는 셀을 따옴표로 감쌉니다.
+
+use std::collections::HashSet;
+
+use super::{escape_html, segment_numbers};
+use crate::models::IteProject;
+use crate::qa::segment_texts;
+
+/// 클립보드 표 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardFormat {
+    Tsv,
+    Markdown,
+    /// HTML 표 (붙여 넣을 곳이 HTML을 받지 못하면 TSV로 대체)
+    Html,
+}
+
+impl ClipboardFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "tsv" => Some(Self::Tsv),
+            "markdown" | "md" => Some(Self::Markdown),
+            "html" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+/// 표 1행 (세그먼트 번호, 원문, 번역문)
+pub type SegmentRow = (usize, String, String);
+
+/// 세그먼트 행 수집 (문서 순서), `segment_ids`가 None이면 전체
+pub fn collect_segment_rows(project: &IteProject, segment_ids: Option<&[String]>) -> Vec<SegmentRow> {
+    let wanted: Option<HashSet<&str>> = segment_ids.map(|ids| ids.iter().map(String::as_str).collect());
+    let numbers = segment_numbers(project);
+
+    let mut segments: Vec<_> = project
+        .segments
+        .iter()
+        .filter(|s| wanted.as_ref().is_none_or(|w| w.contains(s.group_id.as_str())))
+        .collect();
+    segments.sort_by_key(|s| s.order);
+
+    segments
+        .into_iter()
+        .map(|segment| {
+            let no = segment
+                .source_ids
+                .iter()
+                .chain(&segment.target_ids)
+                .find_map(|id| numbers.get(id).copied())
+                .unwrap_or(0);
+            let (source, target) = segment_texts(project, segment);
+            (no, source, target)
+        })
+        .collect()
+}
+
+fn escape_tsv(cell: &str) -> String {
+    if cell.contains(['\t', '\n', '\r', '"']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn escape_markdown(cell: &str) -> String {
+    cell.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// 행을 표 문자열로 렌더링 (첫 행은 헤더)
+pub fn render_segment_table(rows: &[SegmentRow], format: ClipboardFormat) -> String {
+    let mut out = String::new();
+    match format {
+        ClipboardFormat::Tsv => {
+            out.push_str("#\tSource\tTarget\n");
+            for (no, source, target) in rows {
+                out.push_str(&format!("{}\t{}\t{}\n", no, escape_tsv(source), escape_tsv(target)));
+            }
+        }
+        ClipboardFormat::Markdown => {
+            out.push_str("| # | Source | Target |\n| --- | --- | --- |\n");
+            for (no, source, target) in rows {
+                out.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    no,
+                    escape_markdown(source),
+                    escape_markdown(target)
+                ));
+            }
+        }
+        ClipboardFormat::Html => {
+            out.push_str("<table><thead><tr><th>#</th><th>Source</th><th>Target</th></tr></thead><tbody>");
+            for (no, source, target) in rows {
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    no,
+                    escape_html(source).replace('\n', "<br>"),
+                    escape_html(target).replace('\n', "<br>")
+                ));
+            }
+            out.push_str("</tbody></table>");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_cells_per_format() {
+        let rows = vec![(1, "a\tb".to_string(), "say \"hi\"".to_string()), (2, "x|y\nz".to_string(), String::new())];
+
+        assert_eq!(
+            render_segment_table(&rows, ClipboardFormat::Tsv),
+            "#\tSource\tTarget\n1\t\"a\tb\"\t\"say \"\"hi\"\"\"\n2\t\"x|y\nz\"\t\n"
+        );
+        assert_eq!(
+            render_segment_table(&rows, ClipboardFormat::Markdown),
+            "| # | Source | Target |\n| --- | --- | --- |\n| 1 | a\tb | say \"hi\" |\n| 2 | x\\|y<br>z |  |\n"
+        );
+    }
+}
@@ -0,0 +1,471 @@
+//! Project Interchange JSON
+//!
+//! SQLite 없이 외부 스크립트/CI에서 읽고 쓸 수 있는 프로젝트 JSON 문서 (`ite-project-json`).
+//!
+//! 문서 구조 (formatVersion 1, 키는 camelCase):
+//! - `format`, `formatVersion`, `appVersion`, `exportedAt` (Unix epoch ms)
+//! - `project`: id, title, description, domain, targetLanguage, author, createdAt, updatedAt,
+//!   settings, customFields
+//! - `segments`: 문서 순서대로 정렬된 세그먼트. sourceIds/targetIds와 편의용 평문(sourceText/targetText)
+//! - `blocks`: 세그먼트 순서(원문 → 번역문)로 정렬된 블록. content(HTML)와 평문 text
+//! - `glossary`: 프로젝트 용어집 (전역 용어집은 포함하지 않음)
+//!
+//! 가져오기는 content만 사용하며 평문 필드(sourceText/targetText/text)는 무시합니다.
+//! 필드를 추가하는 변경은 같은 버전을 유지하고, 의미가 바뀌는 변경에만 formatVersion을 올립니다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::GlossaryEntryRow;
+use crate::error::IteError;
+use crate::models::{
+    BlockComment, BlockMetadata, EditorBlock, IteProject, ProjectMetadata, ProjectSettings, SegmentGroup,
+};
+use crate::text::strip_html;
+
+/// 문서 형식 식별자
+pub const INTERCHANGE_FORMAT: &str = "ite-project-json";
+/// 현재 문서 버전
+pub const INTERCHANGE_FORMAT_VERSION: u32 = 1;
+
+/// Interchange JSON 문서
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeDocument {
+    pub format: String,
+    pub format_version: u32,
+    #[serde(default)]
+    pub app_version: String,
+    #[serde(default)]
+    pub exported_at: i64,
+    pub project: InterchangeProject,
+    pub segments: Vec<InterchangeSegment>,
+    pub blocks: Vec<InterchangeBlock>,
+    #[serde(default)]
+    pub glossary: Vec<InterchangeGlossaryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeProject {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub domain: String,
+    #[serde(default)]
+    pub target_language: Option<String>,
+    #[serde(default)]
+    pub source_locale: Option<String>,
+    #[serde(default)]
+    pub target_locale: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+    /// 없으면 기본 설정으로 가져옵니다.
+    #[serde(default)]
+    pub settings: Option<ProjectSettings>,
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeSegment {
+    pub id: String,
+    pub order: i32,
+    #[serde(default)]
+    pub is_aligned: bool,
+    pub source_ids: Vec<String>,
+    pub target_ids: Vec<String>,
+    #[serde(default)]
+    pub source_text: String,
+    #[serde(default)]
+    pub target_text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeBlock {
+    pub id: String,
+    /// "source" | "target"
+    #[serde(rename = "type")]
+    pub block_type: String,
+    pub content: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub comments: Vec<BlockComment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeGlossaryEntry {
+    pub source: String,
+    pub target: String,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub domain: Option<String>,
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+/// 가져오기 결과 (DB 저장 전)
+#[derive(Debug, Clone)]
+pub struct ImportedInterchange {
+    pub project: IteProject,
+    pub custom_fields: BTreeMap<String, String>,
+    pub glossary: Vec<InterchangeGlossaryEntry>,
+}
+
+fn default_settings() -> ProjectSettings {
+    ProjectSettings {
+        strictness_level: 0.5,
+        auto_save: true,
+        auto_save_interval: 30000,
+        theme: "system".to_string(),
+    }
+}
+
+/// 프로젝트 → interchange 문서
+/// - glossary에는 프로젝트 용어집만 넘겨야 합니다 (전역 용어는 호출자가 제외).
+pub fn build_interchange_document(
+    project: &IteProject,
+    custom_fields: BTreeMap<String, String>,
+    glossary: &[GlossaryEntryRow],
+    exported_at: i64,
+) -> InterchangeDocument {
+    let mut segments: Vec<&SegmentGroup> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+
+    let plain = |ids: &[String]| {
+        ids.iter()
+            .filter_map(|id| project.blocks.get(id))
+            .map(|b| strip_html(&b.content))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let to_block = |b: &EditorBlock| InterchangeBlock {
+        id: b.id.clone(),
+        block_type: b.block_type.clone(),
+        content: b.content.clone(),
+        text: strip_html(&b.content),
+        author: b.metadata.author.clone(),
+        created_at: b.metadata.created_at,
+        updated_at: b.metadata.updated_at,
+        tags: b.metadata.tags.clone(),
+        comments: b.metadata.comments.clone().unwrap_or_default(),
+    };
+
+    // 블록: 세그먼트 순서대로, 세그먼트에 속하지 않은 블록은 뒤에 id 순으로
+    let mut seen = HashSet::new();
+    let mut blocks = Vec::with_capacity(project.blocks.len());
+    for segment in &segments {
+        for id in segment.source_ids.iter().chain(&segment.target_ids) {
+            if let Some(block) = project.blocks.get(id) {
+                if seen.insert(id.as_str()) {
+                    blocks.push(to_block(block));
+                }
+            }
+        }
+    }
+    let mut rest: Vec<&EditorBlock> = project
+        .blocks
+        .values()
+        .filter(|b| !seen.contains(b.id.as_str()))
+        .collect();
+    rest.sort_by(|a, b| a.id.cmp(&b.id));
+    blocks.extend(rest.into_iter().map(to_block));
+
+    let meta = &project.metadata;
+    InterchangeDocument {
+        format: INTERCHANGE_FORMAT.to_string(),
+        format_version: INTERCHANGE_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at,
+        project: InterchangeProject {
+            id: project.id.clone(),
+            title: meta.title.clone(),
+            description: meta.description.clone(),
+            domain: meta.domain.clone(),
+            target_language: meta.target_language.clone(),
+            source_locale: meta.source_locale.clone(),
+            target_locale: meta.target_locale.clone(),
+            author: meta.author.clone(),
+            created_at: meta.created_at,
+            updated_at: meta.updated_at,
+            settings: Some(meta.settings.clone()),
+            custom_fields,
+        },
+        segments: segments
+            .iter()
+            .map(|s| InterchangeSegment {
+                id: s.group_id.clone(),
+                order: s.order,
+                is_aligned: s.is_aligned,
+                source_ids: s.source_ids.clone(),
+                target_ids: s.target_ids.clone(),
+                source_text: plain(&s.source_ids),
+                target_text: plain(&s.target_ids),
+            })
+            .collect(),
+        blocks,
+        glossary: glossary
+            .iter()
+            .map(|g| InterchangeGlossaryEntry {
+                source: g.source.clone(),
+                target: g.target.clone(),
+                notes: g.notes.clone(),
+                domain: g.domain.clone(),
+                case_sensitive: g.case_sensitive,
+            })
+            .collect(),
+    }
+}
+
+/// JSON 문자열 파싱 + 형식/버전 확인
+pub fn parse_interchange_document(json: &str) -> Result<InterchangeDocument, IteError> {
+    let doc: InterchangeDocument = serde_json::from_str(json)
+        .map_err(|e| IteError::InvalidOperation(format!("Invalid project JSON: {}", e)))?;
+    if doc.format != INTERCHANGE_FORMAT {
+        return Err(IteError::InvalidOperation(format!(
+            "Unsupported document format: {}",
+            doc.format
+        )));
+    }
+    if doc.format_version > INTERCHANGE_FORMAT_VERSION {
+        return Err(IteError::InvalidOperation(format!(
+            "Project JSON version {} is newer than supported version {}",
+            doc.format_version, INTERCHANGE_FORMAT_VERSION
+        )));
+    }
+    Ok(doc)
+}
+
+/// interchange 문서 → 새 프로젝트
+/// - 같은 파일을 여러 번 가져와도 충돌하지 않도록 프로젝트/세그먼트/블록 ID를 모두 새로 발급합니다.
+/// - 세그먼트가 없는 블록을 참조하면 에러입니다.
+pub fn project_from_interchange(doc: InterchangeDocument, now: i64) -> Result<ImportedInterchange, IteError> {
+    let title = doc.project.title.trim().to_string();
+    if title.is_empty() {
+        return Err(IteError::InvalidOperation("Project title is empty".to_string()));
+    }
+
+    let mut id_map: HashMap<String, String> = HashMap::new();
+    let mut blocks = HashMap::with_capacity(doc.blocks.len());
+    for block in doc.blocks {
+        if block.block_type != "source" && block.block_type != "target" {
+            return Err(IteError::InvalidOperation(format!(
+                "Invalid block type '{}' for block {}",
+                block.block_type, block.id
+            )));
+        }
+        let new_id = uuid::Uuid::new_v4().to_string();
+        if id_map.insert(block.id.clone(), new_id.clone()).is_some() {
+            return Err(IteError::InvalidOperation(format!("Duplicate block id: {}", block.id)));
+        }
+        let created_at = if block.created_at > 0 { block.created_at } else { now };
+        blocks.insert(
+            new_id.clone(),
+            EditorBlock {
+                id: new_id,
+                block_type: block.block_type,
+                hash: format!("{:x}", md5::compute(&block.content)),
+                content: block.content,
+                metadata: BlockMetadata {
+                    author: block.author,
+                    created_at,
+                    updated_at: block.updated_at.max(created_at),
+                    tags: block.tags,
+                    comments: (!block.comments.is_empty()).then_some(block.comments),
+                    capacity: None,
+                },
+            },
+        );
+    }
+
+    let remap = |ids: &[String]| -> Result<Vec<String>, IteError> {
+        ids.iter()
+            .map(|id| {
+                id_map
+                    .get(id)
+                    .cloned()
+                    .ok_or_else(|| IteError::InvalidOperation(format!("Segment references unknown block: {}", id)))
+            })
+            .collect()
+    };
+
+    let mut segments = Vec::with_capacity(doc.segments.len());
+    for segment in &doc.segments {
+        segments.push(SegmentGroup {
+            group_id: uuid::Uuid::new_v4().to_string(),
+            source_ids: remap(&segment.source_ids)?,
+            target_ids: remap(&segment.target_ids)?,
+            is_aligned: segment.is_aligned,
+            order: segment.order,
+        });
+    }
+    segments.sort_by_key(|s| s.order);
+
+    let p = doc.project;
+    let created_at = if p.created_at > 0 { p.created_at } else { now };
+    let project = IteProject {
+        id: uuid::Uuid::new_v4().to_string(),
+        version: "1.0.0".to_string(),
+        metadata: ProjectMetadata {
+            title,
+            description: p.description,
+            domain: p.domain,
+            target_language: p.target_language,
+            source_locale: p.source_locale,
+            target_locale: p.target_locale,
+            created_at,
+            updated_at: now,
+            author: p.author,
+            glossary_paths: None,
+            settings: p.settings.unwrap_or_else(default_settings),
+        },
+        segments,
+        blocks,
+        history: Vec::new(),
+    };
+
+    Ok(ImportedInterchange {
+        project,
+        custom_fields: p.custom_fields,
+        glossary: doc
+            .glossary
+            .into_iter()
+            .filter(|g| !g.source.trim().is_empty() && !g.target.trim().is_empty())
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(id: &str, block_type: &str, content: &str) -> EditorBlock {
+        EditorBlock {
+            id: id.to_string(),
+            block_type: block_type.to_string(),
+            content: content.to_string(),
+            hash: String::new(),
+            metadata: BlockMetadata {
+                author: None,
+                created_at: 1,
+                updated_at: 2,
+                tags: vec!["t".to_string()],
+                comments: None,
+                capacity: None,
+            },
+        }
+    }
+
+    fn sample_project() -> IteProject {
+        let blocks = [
+            block("s2", "source", "<p>Second</p>"),
+            block("t2", "target", "<p>두 번째</p>"),
+            block("s1", "source", "<p>First &amp; one</p>"),
+            block("t1", "target", "<p>첫 번째</p>"),
+        ];
+        let segment = |id: &str, s: &str, t: &str, order| SegmentGroup {
+            group_id: id.to_string(),
+            source_ids: vec![s.to_string()],
+            target_ids: vec![t.to_string()],
+            is_aligned: true,
+            order,
+        };
+        IteProject {
+            id: "p1".to_string(),
+            version: "1.0.0".to_string(),
+            metadata: ProjectMetadata {
+                title: "Doc".to_string(),
+                description: None,
+                domain: "general".to_string(),
+                target_language: Some("ko".to_string()),
+                source_locale: None,
+                target_locale: None,
+                created_at: 10,
+                updated_at: 20,
+                author: None,
+                glossary_paths: None,
+                settings: default_settings(),
+            },
+            segments: vec![segment("g2", "s2", "t2", 1), segment("g1", "s1", "t1", 0)],
+            blocks: blocks.into_iter().map(|b| (b.id.clone(), b)).collect(),
+            history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_document_orders_segments_and_blocks() {
+        let doc = build_interchange_document(&sample_project(), BTreeMap::new(), &[], 100);
+        let ids: Vec<&str> = doc.segments.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["g1", "g2"]);
+        let block_ids: Vec<&str> = doc.blocks.iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(block_ids, vec!["s1", "t1", "s2", "t2"]);
+        assert_eq!(doc.segments[0].source_text, "First & one");
+        assert_eq!(doc.blocks[1].text, "첫 번째");
+    }
+
+    #[test]
+    fn test_roundtrip_remaps_ids() {
+        let mut fields = BTreeMap::new();
+        fields.insert("client".to_string(), "ACME".to_string());
+        let glossary = vec![GlossaryEntryRow {
+            id: "x".to_string(),
+            source: "First".to_string(),
+            target: "첫".to_string(),
+            notes: None,
+            domain: None,
+            case_sensitive: false,
+            created_at: 0,
+            updated_at: 0,
+        }];
+        let doc = build_interchange_document(&sample_project(), fields, &glossary, 100);
+        let json = serde_json::to_string(&doc).unwrap();
+
+        let imported = project_from_interchange(parse_interchange_document(&json).unwrap(), 500).unwrap();
+        let project = imported.project;
+        assert_ne!(project.id, "p1");
+        assert_eq!(project.blocks.len(), 4);
+        assert_eq!(project.segments.len(), 2);
+        assert!(!project.blocks.contains_key("s1"));
+        let first = &project.segments[0];
+        assert_eq!(project.blocks[&first.source_ids[0]].content, "<p>First &amp; one</p>");
+        assert_eq!(project.metadata.updated_at, 500);
+        assert_eq!(imported.custom_fields["client"], "ACME");
+        assert_eq!(imported.glossary.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_format_and_dangling_ids() {
+        let doc = build_interchange_document(&sample_project(), BTreeMap::new(), &[], 100);
+        let mut value = serde_json::to_value(&doc).unwrap();
+        value["formatVersion"] = serde_json::json!(INTERCHANGE_FORMAT_VERSION + 1);
+        assert!(parse_interchange_document(&value.to_string()).is_err());
+        value["formatVersion"] = serde_json::json!(1);
+        value["format"] = serde_json::json!("other");
+        assert!(parse_interchange_document(&value.to_string()).is_err());
+
+        let mut doc = doc;
+        doc.segments[0].target_ids.push("missing".to_string());
+        assert!(project_from_interchange(doc, 0).is_err());
+    }
+}
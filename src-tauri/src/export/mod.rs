@@ -0,0 +1,65 @@
+//! Export Module
+//!
+//! 프로젝트 데이터를 공유용 문서(Markdown/HTML 등)로 렌더링
+
+pub mod bilingual;
+pub mod chat;
+pub mod clipboard;
+pub mod interchange;
+pub mod productivity;
+pub mod quote;
+pub mod review;
+pub mod revisions;
+pub mod sanitize;
+pub mod snapshot;
+pub mod target;
+pub mod xlsx;
+
+/// HTML 특수문자 이스케이프
+pub fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// CSV 필드 이스케이프 (콤마/따옴표/줄바꿈이 있으면 따옴표로 감쌈)
+pub fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Unix epoch(ms) → 로컬 시간 문자열 (YYYY-MM-DD HH:MM)
+pub fn format_timestamp(ts_millis: i64) -> String {
+    use chrono::TimeZone;
+    chrono::Local
+        .timestamp_millis_opt(ts_millis)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// 블록 ID → 세그먼트 번호 (문서 순서 기준, 1부터)
+pub fn segment_numbers(project: &crate::models::IteProject) -> std::collections::HashMap<String, usize> {
+    let mut segments: Vec<_> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+
+    let mut out = std::collections::HashMap::new();
+    for (i, segment) in segments.into_iter().enumerate() {
+        for id in segment.source_ids.iter().chain(&segment.target_ids) {
+            out.insert(id.clone(), i + 1);
+        }
+    }
+    out
+}
@@ -0,0 +1,22 @@
+//! Productivity Report Export
+//!
+//! 일자·프로젝트별 번역 실적을 CSV로 렌더링합니다.
+
+use super::escape_csv;
+use crate::db::ProductivityRow;
+
+/// 실적 행을 CSV 문자열로 렌더링 (Excel 호환을 위해 UTF-8 BOM 포함)
+pub fn render_productivity_csv(rows: &[ProductivityRow]) -> String {
+    let mut out = String::from("\u{FEFF}date,project_id,project_title,words_translated,blocks_edited\n");
+    for r in rows {
+        out.push_str(&format!(
+            "{},{},{},{},{}\n",
+            r.date,
+            escape_csv(&r.project_id),
+            escape_csv(&r.project_title),
+            r.words_translated,
+            r.blocks_edited
+        ));
+    }
+    out
+}
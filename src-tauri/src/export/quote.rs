@@ -0,0 +1,311 @@
+//! Quote Export
+//!
+//! 프로젝트 분석(반복/TM 매치 구간별 단어 수)에 단어당 단가와 구간별 가중치를 적용해 견적을 계산하고,
+//! CSV 또는 인쇄용 HTML로 렌더링합니다.
+//! - PDF는 HTML을 웹뷰에서 인쇄(PDF로 저장)해 만듭니다. 페이지 크기/여백은 `@page`로 지정해 둡니다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{escape_csv, escape_html, format_timestamp};
+use crate::text::analysis::ProjectAnalysis;
+use crate::text::tm_match::MatchBand;
+
+/// 견적 파일 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteFormat {
+    Csv,
+    Html,
+}
+
+impl QuoteFormat {
+    /// 파일 확장자로 형식 결정 (.csv, .html/.htm)
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "csv" => Some(Self::Csv),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+fn default_currency() -> String {
+    "USD".to_string()
+}
+
+/// 견적 단가
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteRates {
+    /// 신규 단어 1개당 단가
+    pub per_word: f64,
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    /// 구간별 가중치 (신규 단가 대비 %, 생략한 구간은 기본값)
+    #[serde(default)]
+    pub weights: HashMap<MatchBand, f64>,
+    /// 최소 청구 금액
+    pub minimum_fee: Option<f64>,
+}
+
+/// 구간별 기본 가중치 (%)
+pub fn default_weight(band: MatchBand) -> f64 {
+    match band {
+        MatchBand::Context => 10.0,
+        MatchBand::Repetition => 25.0,
+        MatchBand::Exact => 25.0,
+        MatchBand::Fuzzy95 => 50.0,
+        MatchBand::Fuzzy85 => 70.0,
+        MatchBand::Fuzzy75 | MatchBand::Fuzzy50 | MatchBand::NoMatch => 100.0,
+    }
+}
+
+/// 견적 1행
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteLine {
+    pub band: MatchBand,
+    pub label: &'static str,
+    pub segments: usize,
+    pub words: usize,
+    /// 적용 가중치 (%)
+    pub weight: f64,
+    pub weighted_words: f64,
+    pub amount: f64,
+}
+
+/// 견적
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quote {
+    pub project_id: String,
+    pub project_title: String,
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub currency: String,
+    pub per_word: f64,
+    pub lines: Vec<QuoteLine>,
+    pub total_words: usize,
+    pub weighted_words: f64,
+    pub subtotal: f64,
+    pub minimum_fee: Option<f64>,
+    /// 소계와 최소 청구 금액 중 큰 값
+    pub total: f64,
+    /// Unix epoch ms
+    pub generated_at: i64,
+    /// 저장한 파일 경로 (파일로 내보낸 경우)
+    pub path: Option<String>,
+}
+
+fn round_cents(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// 분석 결과로 견적 계산
+pub fn build_quote(
+    project_id: &str,
+    project_title: &str,
+    target_language: Option<&str>,
+    analysis: &ProjectAnalysis,
+    rates: &QuoteRates,
+) -> Quote {
+    let per_word = rates.per_word.max(0.0);
+    let lines: Vec<QuoteLine> = analysis
+        .bands
+        .iter()
+        .map(|stats| {
+            let weight = rates
+                .weights
+                .get(&stats.band)
+                .copied()
+                .unwrap_or_else(|| default_weight(stats.band))
+                .max(0.0);
+            let weighted_words = stats.words as f64 * weight / 100.0;
+            QuoteLine {
+                band: stats.band,
+                label: stats.label,
+                segments: stats.segments,
+                words: stats.words,
+                weight,
+                weighted_words,
+                amount: round_cents(weighted_words * per_word),
+            }
+        })
+        .collect();
+
+    let subtotal = round_cents(lines.iter().map(|l| l.amount).sum());
+    let minimum_fee = rates.minimum_fee.filter(|fee| *fee > 0.0);
+    Quote {
+        project_id: project_id.to_string(),
+        project_title: project_title.to_string(),
+        source_language: analysis.source_language.clone(),
+        target_language: target_language.map(str::to_string),
+        currency: rates.currency.trim().to_uppercase(),
+        per_word,
+        total_words: analysis.words,
+        weighted_words: lines.iter().map(|l| l.weighted_words).sum(),
+        lines,
+        subtotal,
+        minimum_fee,
+        total: minimum_fee.map_or(subtotal, |fee| subtotal.max(fee)),
+        generated_at: chrono::Utc::now().timestamp_millis(),
+        path: None,
+    }
+}
+
+/// 견적을 파일 바이트로 렌더링
+pub fn render_quote(quote: &Quote, format: QuoteFormat) -> Vec<u8> {
+    match format {
+        QuoteFormat::Csv => render_quote_csv(quote).into_bytes(),
+        QuoteFormat::Html => render_quote_html(quote).into_bytes(),
+    }
+}
+
+/// CSV (Excel 호환을 위해 UTF-8 BOM 포함, 마지막에 합계 행)
+fn render_quote_csv(quote: &Quote) -> String {
+    let mut out = String::from("\u{FEFF}band,segments,words,weight_percent,weighted_words,rate,amount,currency\n");
+    for line in &quote.lines {
+        out.push_str(&format!(
+            "{},{},{},{},{:.2},{},{:.2},{}\n",
+            escape_csv(line.label),
+            line.segments,
+            line.words,
+            line.weight,
+            line.weighted_words,
+            quote.per_word,
+            line.amount,
+            escape_csv(&quote.currency)
+        ));
+    }
+    out.push_str(&format!(
+        "Subtotal,,{},,{:.2},,{:.2},{}\n",
+        quote.total_words,
+        quote.weighted_words,
+        quote.subtotal,
+        escape_csv(&quote.currency)
+    ));
+    if let Some(fee) = quote.minimum_fee {
+        out.push_str(&format!("Minimum fee,,,,,,{:.2},{}\n", fee, escape_csv(&quote.currency)));
+    }
+    out.push_str(&format!("Total,,,,,,{:.2},{}\n", quote.total, escape_csv(&quote.currency)));
+    out
+}
+
+fn render_quote_html(quote: &Quote) -> String {
+    let title = escape_html(&quote.project_title);
+    let currency = escape_html(&quote.currency);
+    let languages = match (&quote.source_language, &quote.target_language) {
+        (None, None) => String::new(),
+        (source, target) => format!(
+            " · {} → {}",
+            escape_html(source.as_deref().unwrap_or("?")),
+            escape_html(target.as_deref().unwrap_or("?"))
+        ),
+    };
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{} - Quote</title>\n", title));
+    out.push_str(
+        "<style>\n\
+         @page { size: A4; margin: 18mm; }\n\
+         body { font-family: -apple-system, 'Segoe UI', 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; margin: 2rem; color: #1f2328; }\n\
+         table { border-collapse: collapse; width: 100%; }\n\
+         th, td { border: 1px solid #d0d7de; padding: 0.4rem 0.6rem; text-align: right; }\n\
+         th:first-child, td:first-child { text-align: left; }\n\
+         th { background: #f6f8fa; }\n\
+         tfoot td { font-weight: 600; }\n\
+         .meta { color: #656d76; font-size: 0.85rem; }\n\
+         @media print { body { margin: 0; } }\n\
+         </style>\n",
+    );
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", title));
+    out.push_str(&format!(
+        "<p class=\"meta\">Quote {}{} · {} words · {} {} per word</p>\n",
+        format_timestamp(quote.generated_at),
+        languages,
+        quote.total_words,
+        quote.per_word,
+        currency
+    ));
+    out.push_str(
+        "<table>\n<thead><tr><th>Match</th><th>Segments</th><th>Words</th><th>Weight</th><th>Weighted words</th><th>Amount</th></tr></thead>\n<tbody>\n",
+    );
+    for line in &quote.lines {
+        out.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}%</td><td>{:.2}</td><td>{:.2} {}</td></tr>\n",
+            escape_html(line.label),
+            line.segments,
+            line.words,
+            line.weight,
+            line.weighted_words,
+            line.amount,
+            currency
+        ));
+    }
+    out.push_str("</tbody>\n<tfoot>\n");
+    out.push_str(&format!(
+        "<tr><td>Subtotal</td><td></td><td>{}</td><td></td><td>{:.2}</td><td>{:.2} {}</td></tr>\n",
+        quote.total_words, quote.weighted_words, quote.subtotal, currency
+    ));
+    if let Some(fee) = quote.minimum_fee {
+        out.push_str(&format!(
+            "<tr><td>Minimum fee</td><td></td><td></td><td></td><td></td><td>{:.2} {}</td></tr>\n",
+            fee, currency
+        ));
+    }
+    out.push_str(&format!(
+        "<tr><td>Total</td><td></td><td></td><td></td><td></td><td>{:.2} {}</td></tr>\n",
+        quote.total, currency
+    ));
+    out.push_str("</tfoot>\n</table>\n</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::analysis::BandStats;
+
+    #[test]
+    fn applies_weights_and_minimum_fee() {
+        let counted = [MatchBand::Repetition, MatchBand::Fuzzy95, MatchBand::NoMatch];
+        let analysis = ProjectAnalysis {
+            source_language: Some("en".to_string()),
+            segments: 3,
+            words: 300,
+            bands: MatchBand::ALL
+                .iter()
+                .map(|&band| {
+                    let hit = counted.contains(&band);
+                    BandStats {
+                        band,
+                        label: band.label(),
+                        segments: usize::from(hit),
+                        words: if hit { 100 } else { 0 },
+                    }
+                })
+                .collect(),
+        };
+        let rates = QuoteRates {
+            per_word: 0.1,
+            currency: "eur".to_string(),
+            weights: HashMap::from([(MatchBand::Fuzzy95, 60.0)]),
+            minimum_fee: Some(50.0),
+        };
+
+        let quote = build_quote("p1", "Manual", Some("ko"), &analysis, &rates);
+        let amounts: Vec<f64> = quote.lines.iter().filter(|l| l.words > 0).map(|l| l.amount).collect();
+        assert_eq!(amounts, vec![2.5, 6.0, 10.0]);
+        assert_eq!(quote.subtotal, 18.5);
+        assert_eq!(quote.total, 50.0);
+        assert_eq!(quote.currency, "EUR");
+
+        let csv = render_quote_csv(&quote);
+        assert!(csv.contains("95–99%,1,100,60,60.00,0.1,6.00,EUR\n"));
+        assert!(csv.ends_with("Total,,,,,,50.00,EUR\n"));
+    }
+}
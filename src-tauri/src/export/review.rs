@@ -0,0 +1,279 @@
+//! Review Report Export
+//!
+//! 고객 전달용 리뷰 리포트: 미해결 코멘트, QA 이슈, 리뷰어 변경 내역을 세그먼트 번호 순으로 정리해
+//! XLSX 또는 HTML로 렌더링합니다.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::revisions::diff_to_html;
+use super::xlsx::render_xlsx;
+use super::{escape_html, format_timestamp};
+use crate::error::IteError;
+use crate::models::{IteProject, Revision, SegmentGroup};
+use crate::qa::{segment_texts, QaIssue, QaSeverity};
+use crate::text::diff::{DiffKind, DiffOp};
+
+/// 리뷰 리포트 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewReportFormat {
+    Xlsx,
+    Html,
+}
+
+impl ReviewReportFormat {
+    /// 파일 확장자로 형식 결정 (.xlsx, .html/.htm)
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "xlsx" => Some(Self::Xlsx),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+/// 리뷰 항목 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReviewItemKind {
+    Comment,
+    Qa,
+    Change,
+}
+
+impl ReviewItemKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Comment => "Comment",
+            Self::Qa => "QA",
+            Self::Change => "Change",
+        }
+    }
+}
+
+/// 리포트 1행
+#[derive(Debug, Clone)]
+pub struct ReviewRow {
+    /// 세그먼트 번호 (문서 순서 기준 1부터, 세그먼트 밖 블록이면 None)
+    pub segment_no: Option<usize>,
+    pub kind: ReviewItemKind,
+    pub source_text: String,
+    pub target_text: String,
+    /// 작성자 또는 QA 심각도
+    pub author: String,
+    pub detail: String,
+    /// 변경 항목의 단어 단위 diff
+    pub diff: Vec<DiffOp>,
+    pub timestamp: Option<i64>,
+}
+
+/// 리포트 요약 (항목 종류별 개수)
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewCounts {
+    pub comments: usize,
+    pub qa_issues: usize,
+    pub changes: usize,
+}
+
+fn severity_label(severity: QaSeverity) -> &'static str {
+    match severity {
+        QaSeverity::Error => "error",
+        QaSeverity::Warning => "warning",
+        QaSeverity::Info => "info",
+    }
+}
+
+fn diff_to_text(diff: &[DiffOp]) -> String {
+    let mut out = String::new();
+    for op in diff {
+        match op.kind {
+            DiffKind::Equal => out.push_str(&op.text),
+            DiffKind::Insert => out.push_str(&format!("[+{}+]", op.text)),
+            DiffKind::Delete => out.push_str(&format!("[-{}-]", op.text)),
+        }
+    }
+    out
+}
+
+/// 미해결 코멘트 + QA 이슈 + 검토 대기 변경을 세그먼트 번호 순 행 목록으로 수집
+pub fn collect_review_rows(project: &IteProject, issues: &[QaIssue], revisions: &[Revision]) -> Vec<ReviewRow> {
+    let mut segments: Vec<&SegmentGroup> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+
+    // 세그먼트 ID/블록 ID → (세그먼트 번호, 세그먼트)
+    let mut lookup: HashMap<&str, (usize, &SegmentGroup)> = HashMap::new();
+    for (i, segment) in segments.iter().enumerate() {
+        lookup.insert(segment.group_id.as_str(), (i + 1, segment));
+        for id in segment.source_ids.iter().chain(&segment.target_ids) {
+            lookup.insert(id.as_str(), (i + 1, segment));
+        }
+    }
+    let locate = |id: Option<&str>| -> (Option<usize>, String, String) {
+        match id.and_then(|id| lookup.get(id)) {
+            Some((no, segment)) => {
+                let (source, target) = segment_texts(project, segment);
+                (Some(*no), source, target)
+            }
+            None => (None, String::new(), String::new()),
+        }
+    };
+
+    let mut rows = Vec::new();
+
+    let mut block_ids: Vec<&String> = project.blocks.keys().collect();
+    block_ids.sort();
+    for block_id in block_ids {
+        let block = &project.blocks[block_id];
+        for comment in block.metadata.comments.iter().flatten().filter(|c| !c.resolved) {
+            let (segment_no, source_text, target_text) = locate(Some(block_id));
+            rows.push(ReviewRow {
+                segment_no,
+                kind: ReviewItemKind::Comment,
+                source_text,
+                target_text,
+                author: comment.author.clone(),
+                detail: comment.content.clone(),
+                diff: Vec::new(),
+                timestamp: Some(comment.created_at),
+            });
+        }
+    }
+
+    for issue in issues {
+        let (segment_no, source_text, target_text) =
+            locate(issue.block_id.as_deref().or(issue.segment_id.as_deref()));
+        let detail = match &issue.details {
+            Some(details) => format!("[{}] {} ({})", issue.check, issue.message, details),
+            None => format!("[{}] {}", issue.check, issue.message),
+        };
+        rows.push(ReviewRow {
+            segment_no,
+            kind: ReviewItemKind::Qa,
+            source_text,
+            target_text,
+            author: severity_label(issue.severity).to_string(),
+            detail,
+            diff: Vec::new(),
+            timestamp: None,
+        });
+    }
+
+    for revision in revisions {
+        let (segment_no, source_text, target_text) = locate(Some(&revision.block_id));
+        rows.push(ReviewRow {
+            segment_no,
+            kind: ReviewItemKind::Change,
+            source_text,
+            target_text,
+            author: revision.author.clone().unwrap_or_default(),
+            detail: diff_to_text(&revision.diff),
+            diff: revision.diff.clone(),
+            timestamp: Some(revision.updated_at),
+        });
+    }
+
+    // 세그먼트 번호 순 (세그먼트 밖 항목은 마지막), 같은 세그먼트 안에서는 종류·시간 순
+    rows.sort_by_key(|r| (r.segment_no.unwrap_or(usize::MAX), r.kind, r.timestamp.unwrap_or(0)));
+    rows
+}
+
+/// 종류별 항목 수
+pub fn count_rows(rows: &[ReviewRow]) -> ReviewCounts {
+    let mut counts = ReviewCounts::default();
+    for row in rows {
+        match row.kind {
+            ReviewItemKind::Comment => counts.comments += 1,
+            ReviewItemKind::Qa => counts.qa_issues += 1,
+            ReviewItemKind::Change => counts.changes += 1,
+        }
+    }
+    counts
+}
+
+fn segment_label(segment_no: Option<usize>) -> String {
+    segment_no.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+/// 리뷰 리포트를 파일 바이트로 렌더링
+pub fn render_review_report(title: &str, rows: &[ReviewRow], format: ReviewReportFormat) -> Result<Vec<u8>, IteError> {
+    match format {
+        ReviewReportFormat::Xlsx => render_review_xlsx(rows),
+        ReviewReportFormat::Html => Ok(render_review_html(title, rows).into_bytes()),
+    }
+}
+
+fn render_review_xlsx(rows: &[ReviewRow]) -> Result<Vec<u8>, IteError> {
+    let mut cells = vec![["Segment", "Type", "Source", "Target", "Author", "Detail", "Date"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()];
+    for row in rows {
+        cells.push(vec![
+            segment_label(row.segment_no),
+            row.kind.label().to_string(),
+            row.source_text.clone(),
+            row.target_text.clone(),
+            row.author.clone(),
+            row.detail.clone(),
+            row.timestamp.map(format_timestamp).unwrap_or_default(),
+        ]);
+    }
+    render_xlsx("Review", &cells, &[9.0, 10.0, 45.0, 45.0, 14.0, 50.0, 17.0])
+}
+
+fn render_review_html(title: &str, rows: &[ReviewRow]) -> String {
+    let counts = count_rows(rows);
+    let title = escape_html(title);
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{} - Review Report</title>\n", title));
+    out.push_str(
+        "<style>\n\
+         body { font-family: -apple-system, 'Segoe UI', 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; margin: 2rem; color: #1f2328; }\n\
+         table { border-collapse: collapse; width: 100%; }\n\
+         th, td { border: 1px solid #d0d7de; padding: 0.4rem 0.6rem; vertical-align: top; text-align: left; white-space: pre-wrap; }\n\
+         th { background: #f6f8fa; }\n\
+         ins { background: #dafbe1; text-decoration: none; }\n\
+         del { background: #ffebe9; }\n\
+         .kind-Comment { color: #0969da; }\n\
+         .kind-QA { color: #cf222e; }\n\
+         .kind-Change { color: #1a7f37; }\n\
+         .meta { color: #656d76; font-size: 0.85rem; }\n\
+         </style>\n",
+    );
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", title));
+    out.push_str(&format!(
+        "<p class=\"meta\">Exported {} · {} comments · {} QA issues · {} changes</p>\n",
+        format_timestamp(chrono::Utc::now().timestamp_millis()),
+        counts.comments,
+        counts.qa_issues,
+        counts.changes
+    ));
+    out.push_str(
+        "<table>\n<tr><th>#</th><th>Type</th><th>Source</th><th>Target</th><th>Author</th><th>Detail</th><th>Date</th></tr>\n",
+    );
+    for row in rows {
+        let label = row.kind.label();
+        let detail = if row.kind == ReviewItemKind::Change {
+            diff_to_html(&row.diff)
+        } else {
+            escape_html(&row.detail)
+        };
+        out.push_str(&format!(
+            "<tr><td>{}</td><td class=\"kind-{}\">{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            segment_label(row.segment_no),
+            label,
+            label,
+            escape_html(&row.source_text),
+            escape_html(&row.target_text),
+            escape_html(&row.author),
+            detail,
+            row.timestamp.map(format_timestamp).unwrap_or_default()
+        ));
+    }
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
@@ -0,0 +1,115 @@
+//! Revision Export
+//!
+//! 리뷰어 변경 내역(변경 추적)을 HTML(삽입/삭제 표시) 또는 CSV로 렌더링합니다.
+
+use super::{escape_csv, escape_html, format_timestamp};
+use crate::models::Revision;
+use crate::text::diff::{DiffKind, DiffOp};
+use crate::text::strip_html;
+
+/// 변경 내역 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionExportFormat {
+    Html,
+    Csv,
+}
+
+impl RevisionExportFormat {
+    /// "html"/"htm", "csv" 문자열 파싱
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "html" | "htm" => Some(Self::Html),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// 내보낼 변경 항목 (세그먼트 번호는 문서 순서 기준 1부터)
+pub struct RevisionExportRow<'a> {
+    pub segment_no: Option<usize>,
+    pub revision: &'a Revision,
+}
+
+/// 변경 내역을 지정한 형식의 문서 문자열로 렌더링
+pub fn render_revisions(title: &str, rows: &[RevisionExportRow], format: RevisionExportFormat) -> String {
+    match format {
+        RevisionExportFormat::Html => render_html(title, rows),
+        RevisionExportFormat::Csv => render_csv(rows),
+    }
+}
+
+/// diff를 `<ins>`/`<del>` 마크업으로 변환
+pub fn diff_to_html(diff: &[DiffOp]) -> String {
+    let mut out = String::new();
+    for op in diff {
+        let text = escape_html(&op.text);
+        match op.kind {
+            DiffKind::Equal => out.push_str(&text),
+            DiffKind::Insert => out.push_str(&format!("<ins>{}</ins>", text)),
+            DiffKind::Delete => out.push_str(&format!("<del>{}</del>", text)),
+        }
+    }
+    out
+}
+
+fn segment_label(segment_no: Option<usize>) -> String {
+    segment_no.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+fn render_html(title: &str, rows: &[RevisionExportRow]) -> String {
+    let title = escape_html(title);
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{} - Reviewer Changes</title>\n", title));
+    out.push_str(
+        "<style>\n\
+         body { font-family: -apple-system, 'Segoe UI', 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; margin: 2rem; color: #1f2328; }\n\
+         table { border-collapse: collapse; width: 100%; }\n\
+         th, td { border: 1px solid #d0d7de; padding: 0.4rem 0.6rem; vertical-align: top; text-align: left; }\n\
+         th { background: #f6f8fa; }\n\
+         ins { background: #dafbe1; text-decoration: none; }\n\
+         del { background: #ffebe9; }\n\
+         .meta { color: #656d76; font-size: 0.85rem; }\n\
+         </style>\n",
+    );
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", title));
+    out.push_str(&format!(
+        "<p class=\"meta\">Exported {} · {} changes</p>\n",
+        format_timestamp(chrono::Utc::now().timestamp_millis()),
+        rows.len()
+    ));
+    out.push_str("<table>\n<tr><th>#</th><th>Status</th><th>Author</th><th>Date</th><th>Change</th></tr>\n");
+    for row in rows {
+        let r = row.revision;
+        out.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            segment_label(row.segment_no),
+            escape_html(&r.status),
+            escape_html(r.author.as_deref().unwrap_or("")),
+            format_timestamp(r.updated_at),
+            diff_to_html(&r.diff)
+        ));
+    }
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
+
+fn render_csv(rows: &[RevisionExportRow]) -> String {
+    let mut out = String::from("\u{FEFF}segment,block_id,status,author,updated_at,before,after\n");
+    for row in rows {
+        let r = row.revision;
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            segment_label(row.segment_no),
+            escape_csv(&r.block_id),
+            r.status,
+            escape_csv(r.author.as_deref().unwrap_or("")),
+            format_timestamp(r.updated_at),
+            escape_csv(&strip_html(&r.base_content)),
+            escape_csv(&strip_html(&r.revised_content))
+        ));
+    }
+    out
+}
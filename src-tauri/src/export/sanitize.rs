@@ -0,0 +1,216 @@
+//! HTML Sanitizer
+//!
+//! 내보내는 문서에 넣을 블록 HTML을 허용 목록(allowlist) 기준으로 다시 씁니다.
+//! - html5ever 토크나이저로 브라우저와 같은 방식으로 태그/속성을 읽고, 허용한 태그와 속성만 다시 직렬화합니다.
+//! - 허용하지 않은 태그는 태그만 버리고 안의 텍스트는 남깁니다 (script/style/iframe 등은 내용까지 버림).
+//! - 링크/이미지 주소는 http(s)/mailto/상대 경로와 래스터 이미지 data URL만 허용합니다.
+//! - 텍스트와 속성 값은 모두 다시 이스케이프하므로 주석/CDATA/잘린 태그로 필터를 우회할 수 없습니다.
+
+use std::cell::{Cell, RefCell};
+
+use html5ever::tokenizer::states::RawKind;
+use html5ever::tokenizer::{
+    BufferQueue, Tag, TagKind, Token, TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts,
+};
+
+use super::escape_html;
+
+/// 그대로 남기는 태그 (에디터가 만드는 서식/표/목록/이미지)
+const ALLOWED_TAGS: &[&str] = &[
+    "a", "b", "blockquote", "br", "caption", "code", "col", "colgroup", "del", "div", "em", "figcaption",
+    "figure", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "i", "img", "ins", "li", "mark", "ol", "p", "pre",
+    "s", "span", "strike", "strong", "sub", "sup", "table", "tbody", "td", "tfoot", "th", "thead", "tr",
+    "u", "ul",
+];
+
+/// 내용 없이 쓰는 태그 (닫는 태그를 쓰지 않음)
+const VOID_TAGS: &[&str] = &["br", "col", "hr", "img"];
+
+/// 내용까지 버리는 태그
+const DROPPED_TAGS: &[&str] = &[
+    "embed", "iframe", "math", "noembed", "noframes", "noscript", "object", "script", "style", "svg",
+    "template", "textarea", "title", "xmp",
+];
+
+/// 모든 허용 태그에 쓸 수 있는 속성
+const GLOBAL_ATTRS: &[&str] = &["class", "dir", "lang", "title"];
+
+/// 태그별 추가 허용 속성
+fn tag_attrs(tag: &str) -> &'static [&'static str] {
+    match tag {
+        "a" => &["href"],
+        "img" => &["src", "alt", "width", "height"],
+        "td" | "th" => &["colspan", "rowspan"],
+        "col" | "colgroup" => &["span"],
+        "ol" => &["start"],
+        _ => &[],
+    }
+}
+
+/// 링크 주소 허용 여부 (스크립트 실행 가능한 스킴 차단)
+fn is_safe_url(value: &str, allow_image_data: bool) -> bool {
+    // 브라우저는 스킴 안의 탭/줄바꿈/제어 문자를 무시하므로 지우고 판단
+    let normalized: String = value
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let scheme_end = normalized.find([':', '/', '?', '#']);
+    match scheme_end {
+        Some(i) if normalized[i..].starts_with(':') => {
+            let scheme = &normalized[..i];
+            match scheme {
+                "http" | "https" | "mailto" => true,
+                "data" => {
+                    allow_image_data
+                        && ["data:image/png", "data:image/jpeg", "data:image/gif", "data:image/webp"]
+                            .iter()
+                            .any(|prefix| normalized.starts_with(prefix))
+                }
+                _ => false,
+            }
+        }
+        // 스킴 없는 상대 경로/앵커
+        _ => true,
+    }
+}
+
+struct Sink {
+    out: RefCell<String>,
+    /// 내용까지 버리는 중인 태그 (닫는 태그가 나오면 해제)
+    dropping: RefCell<Option<String>>,
+    /// 버리는 태그 안에서 같은 이름이 다시 열린 횟수
+    depth: Cell<usize>,
+}
+
+impl Sink {
+    fn write_start(&self, tag: &Tag, name: &str) {
+        let mut out = self.out.borrow_mut();
+        out.push('<');
+        out.push_str(name);
+        let extra = tag_attrs(name);
+        for attr in &tag.attrs {
+            let attr_name = attr.name.local.as_ref();
+            if !GLOBAL_ATTRS.contains(&attr_name) && !extra.contains(&attr_name) {
+                continue;
+            }
+            let value = attr.value.as_ref();
+            if matches!(attr_name, "href" | "src") && !is_safe_url(value, attr_name == "src") {
+                continue;
+            }
+            out.push(' ');
+            out.push_str(attr_name);
+            out.push_str("=\"");
+            out.push_str(&escape_html(value));
+            out.push('"');
+        }
+        out.push('>');
+    }
+
+    fn process_tag(&self, tag: Tag) -> TokenSinkResult<()> {
+        let name = tag.name.as_ref().to_ascii_lowercase();
+        let is_start = tag.kind == TagKind::StartTag;
+
+        let dropping = self.dropping.borrow().clone();
+        if let Some(dropped) = dropping {
+            if name == dropped {
+                if is_start {
+                    self.depth.set(self.depth.get() + 1);
+                } else if self.depth.get() == 0 {
+                    *self.dropping.borrow_mut() = None;
+                } else {
+                    self.depth.set(self.depth.get() - 1);
+                }
+            }
+            return TokenSinkResult::Continue;
+        }
+
+        if DROPPED_TAGS.contains(&name.as_str()) {
+            if !is_start || tag.self_closing {
+                return TokenSinkResult::Continue;
+            }
+            *self.dropping.borrow_mut() = Some(name.clone());
+            self.depth.set(0);
+            // 브라우저처럼 본문을 원시 텍스트로 읽어야 안의 가짜 태그에 속지 않음
+            return match name.as_str() {
+                "script" => TokenSinkResult::RawData(RawKind::ScriptData),
+                "textarea" | "title" => TokenSinkResult::RawData(RawKind::Rcdata),
+                "style" | "iframe" | "noembed" | "noframes" | "xmp" | "noscript" => {
+                    TokenSinkResult::RawData(RawKind::Rawtext)
+                }
+                _ => TokenSinkResult::Continue,
+            };
+        }
+
+        if !ALLOWED_TAGS.contains(&name.as_str()) {
+            return TokenSinkResult::Continue;
+        }
+        if is_start {
+            self.write_start(&tag, &name);
+        } else if !VOID_TAGS.contains(&name.as_str()) {
+            let mut out = self.out.borrow_mut();
+            out.push_str("</");
+            out.push_str(&name);
+            out.push('>');
+        }
+        TokenSinkResult::Continue
+    }
+}
+
+impl TokenSink for Sink {
+    type Handle = ();
+
+    fn process_token(&self, token: Token, _line_number: u64) -> TokenSinkResult<()> {
+        match token {
+            Token::TagToken(tag) => self.process_tag(tag),
+            Token::CharacterTokens(text) => {
+                if self.dropping.borrow().is_none() {
+                    self.out.borrow_mut().push_str(&escape_html(&text));
+                }
+                TokenSinkResult::Continue
+            }
+            // 주석, DOCTYPE, NUL, 파싱 오류는 버림
+            _ => TokenSinkResult::Continue,
+        }
+    }
+}
+
+/// 블록 HTML을 허용 목록 기준으로 정리
+pub fn sanitize_html(html: &str) -> String {
+    let sink = Sink {
+        out: RefCell::new(String::with_capacity(html.len())),
+        dropping: RefCell::new(None),
+        depth: Cell::new(0),
+    };
+    let tokenizer = Tokenizer::new(sink, TokenizerOpts::default());
+    let input = BufferQueue::default();
+    input.push_back(html.into());
+    let _ = tokenizer.feed(&input);
+    tokenizer.end();
+    tokenizer.sink.out.take()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_html_keeps_formatting_and_drops_active_content() {
+        assert_eq!(
+            sanitize_html("<p onclick=\"x()\">Hi <strong>there</strong><script>alert(1)</script></p><img src=a.png onerror=y>"),
+            "<p>Hi <strong>there</strong></p><img src=\"a.png\">"
+        );
+        // 원시 텍스트 안의 가짜 닫는 태그, 주석, 잘린 태그
+        assert_eq!(sanitize_html("<style>a{}</p><script>x</style>ok"), "ok");
+        assert_eq!(sanitize_html("<!--<script>-->x<scr<script>ipt>y"), "xipt&gt;y");
+        assert_eq!(sanitize_html("<svg><script>alert(1)</script></svg><p>z</p>"), "<p>z</p>");
+        // 위험한 URL 스킴 (엔티티/공백으로 숨긴 경우 포함)
+        assert_eq!(
+            sanitize_html("<a href=\"jav&#x09;ascript:alert(1)\">a</a><a href=\"https://e.com/?q=1&amp;b\">b</a>"),
+            "<a>a</a><a href=\"https://e.com/?q=1&amp;b\">b</a>"
+        );
+        assert_eq!(sanitize_html("<img src=\"data:image/svg+xml,<svg onload=x>\">"), "<img>");
+        // 허용하지 않은 태그는 태그만 버림, 텍스트는 다시 이스케이프
+        assert_eq!(sanitize_html("<form><input value=1>a &lt;b&gt;</form>"), "a &lt;b&gt;");
+    }
+}
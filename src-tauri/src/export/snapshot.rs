@@ -0,0 +1,86 @@
+//! History Snapshot JSON
+//!
+//! 스냅샷 하나(검토를 마친 특정 시점 등)를 .ite 전체와 별개로 보관/전달하기 위한 JSON 문서 (`ite-snapshot-json`).
+//!
+//! 문서 구조 (formatVersion 1, 키는 camelCase):
+//! - `format`, `formatVersion`, `appVersion`, `exportedAt` (Unix epoch ms)
+//! - `projectId`, `projectTitle`: 내보낸 프로젝트 (가져올 때는 참고용)
+//! - `snapshot`: 스냅샷 정보 (설명, 시각, 블록 변경, 채팅 요약)
+//! - `state`: 스냅샷 시점의 프로젝트 메타데이터/세그먼트/블록 전체
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::IteError;
+use crate::models::{HistorySnapshot, SnapshotState};
+
+/// 문서 형식 식별자
+pub const SNAPSHOT_FORMAT: &str = "ite-snapshot-json";
+/// 현재 문서 버전
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// 스냅샷 JSON 문서
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDocument {
+    pub format: String,
+    pub format_version: u32,
+    #[serde(default)]
+    pub app_version: String,
+    #[serde(default)]
+    pub exported_at: i64,
+    #[serde(default)]
+    pub project_id: String,
+    #[serde(default)]
+    pub project_title: String,
+    pub snapshot: HistorySnapshot,
+    pub state: SnapshotState,
+}
+
+pub fn build_snapshot_document(
+    project_id: &str,
+    snapshot: HistorySnapshot,
+    state: SnapshotState,
+    now: i64,
+) -> SnapshotDocument {
+    SnapshotDocument {
+        format: SNAPSHOT_FORMAT.to_string(),
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: now,
+        project_id: project_id.to_string(),
+        project_title: state.metadata.title.clone(),
+        snapshot,
+        state,
+    }
+}
+
+/// JSON 문자열 파싱 + 형식/버전 확인
+pub fn parse_snapshot_document(json: &str) -> Result<SnapshotDocument, IteError> {
+    let doc: SnapshotDocument = serde_json::from_str(json)
+        .map_err(|e| IteError::InvalidOperation(format!("Invalid snapshot JSON: {}", e)))?;
+    if doc.format != SNAPSHOT_FORMAT {
+        return Err(IteError::InvalidOperation(format!(
+            "Unsupported document format: {}",
+            doc.format
+        )));
+    }
+    if doc.format_version > SNAPSHOT_FORMAT_VERSION {
+        return Err(IteError::InvalidOperation(format!(
+            "Snapshot JSON version {} is newer than supported version {}",
+            doc.format_version, SNAPSHOT_FORMAT_VERSION
+        )));
+    }
+    let missing = doc
+        .state
+        .segments
+        .iter()
+        .flat_map(|s| s.source_ids.iter().chain(&s.target_ids))
+        .find(|id| !doc.state.blocks.contains_key(*id));
+    if let Some(id) = missing {
+        return Err(IteError::InvalidOperation(format!(
+            "Snapshot segment references missing block: {}",
+            id
+        )));
+    }
+    Ok(doc)
+}
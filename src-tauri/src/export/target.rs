@@ -0,0 +1,108 @@
+//! Target Document Export
+//!
+//! 번역문 블록을 세그먼트 순서대로 이어 붙인 인쇄용 HTML 문서를 만듭니다 (납품/고객 전달용).
+//! - 첫 페이지는 프로젝트 메타데이터(제목, 설명, 언어, 작성자, 사용자 정의 필드 등) 표지입니다.
+//! - PDF는 이 HTML을 웹뷰에서 인쇄(PDF로 저장)해 만듭니다. 페이지 크기/여백은 `@page`로 지정해 둡니다.
+//! - 블록 HTML은 허용 목록 기준으로 다시 써서 넣습니다 (`sanitize_html`, 가져온 문서의 스크립트/이벤트 속성 제거).
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::sanitize::sanitize_html;
+use super::{escape_html, format_timestamp};
+use crate::models::IteProject;
+use crate::text::lang::display_name;
+use crate::text::words::project_word_stats;
+
+fn language_label(code: Option<&str>) -> String {
+    match code {
+        Some(code) => match display_name(code) {
+            Some(name) => format!("{} ({})", name, code),
+            None => code.to_string(),
+        },
+        None => "-".to_string(),
+    }
+}
+
+/// 세그먼트 순서대로 번역문 블록 HTML (N:M 매핑으로 같은 블록이 여러 번 나오면 한 번만)
+fn target_blocks(project: &IteProject) -> Vec<String> {
+    let mut segments: Vec<_> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+
+    let mut seen = HashSet::new();
+    segments
+        .iter()
+        .flat_map(|s| s.target_ids.iter())
+        .filter(|id| seen.insert(id.as_str()))
+        .filter_map(|id| project.blocks.get(id))
+        .filter(|b| !b.content.trim().is_empty())
+        .map(|b| sanitize_html(&b.content))
+        .collect()
+}
+
+/// 번역문 인쇄용 HTML (표지 + 본문)
+/// - `source_language`: 표지에 표시할 원문 언어 (없으면 원문에서 감지)
+pub fn render_target_html(
+    project: &IteProject,
+    source_language: Option<&str>,
+    custom_fields: &BTreeMap<String, String>,
+) -> String {
+    let meta = &project.metadata;
+    let stats = project_word_stats(project, source_language);
+    let title = escape_html(&meta.title);
+
+    let mut cover = vec![
+        ("Source language", language_label(stats.source_language.as_deref())),
+        ("Target language", language_label(stats.target_language.as_deref())),
+        ("Domain", meta.domain.clone()),
+    ];
+    if let Some(author) = meta.author.as_deref().filter(|a| !a.trim().is_empty()) {
+        cover.push(("Translator", author.to_string()));
+    }
+    cover.push(("Created", format_timestamp(meta.created_at)));
+    cover.push(("Last updated", format_timestamp(meta.updated_at)));
+    cover.push((
+        "Segments",
+        format!("{} ({} translated)", stats.segments, stats.translated_segments),
+    ));
+    cover.push(("Words", format!("{} source · {} target", stats.source.words, stats.target.words)));
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", title));
+    out.push_str(
+        "<style>\n\
+         @page { size: A4; margin: 20mm; }\n\
+         body { font-family: -apple-system, 'Segoe UI', 'Apple SD Gothic Neo', 'Malgun Gothic', sans-serif; margin: 2rem auto; max-width: 46rem; color: #1f2328; line-height: 1.6; }\n\
+         .cover { page-break-after: always; break-after: page; padding-top: 20vh; }\n\
+         .cover h1 { font-size: 2rem; margin-bottom: 0.5rem; }\n\
+         .cover .description { color: #4b535d; margin-bottom: 2rem; }\n\
+         .cover table { border-collapse: collapse; }\n\
+         .cover th, .cover td { padding: 0.3rem 1.2rem 0.3rem 0; text-align: left; vertical-align: top; }\n\
+         .cover th { color: #656d76; font-weight: 500; }\n\
+         img { max-width: 100%; }\n\
+         table { border-collapse: collapse; }\n\
+         @media print { body { margin: 0; max-width: none; } }\n\
+         </style>\n",
+    );
+    out.push_str("</head>\n<body>\n<section class=\"cover\">\n");
+    out.push_str(&format!("<h1>{}</h1>\n", title));
+    if let Some(description) = meta.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        out.push_str(&format!("<p class=\"description\">{}</p>\n", escape_html(description)));
+    }
+    out.push_str("<table>\n");
+    let custom = custom_fields.iter().map(|(k, v)| (k.as_str(), v.clone()));
+    for (label, value) in cover.into_iter().chain(custom) {
+        out.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>\n",
+            escape_html(label),
+            escape_html(&value)
+        ));
+    }
+    out.push_str("</table>\n</section>\n<main>\n");
+    for block in target_blocks(project) {
+        out.push_str(&block);
+        out.push('\n');
+    }
+    out.push_str("</main>\n</body>\n</html>\n");
+    out
+}
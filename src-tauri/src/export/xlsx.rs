@@ -0,0 +1,196 @@
+//! Minimal XLSX Writer
+//!
+//! 단일 시트, 문자열 셀만 가진 최소 구성의 Office Open XML 스프레드시트를 생성합니다.
+//! - 셀은 inline string으로 기록하므로 sharedStrings 파트가 필요 없습니다.
+//! - 첫 행은 헤더로 간주해 굵게 표시합니다.
+
+use std::io::{Cursor, Write};
+
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use crate::error::IteError;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>
+<Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>
+</Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>
+</Relationships>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>
+<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+</Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+<fonts count="2"><font><sz val="11"/><name val="Calibri"/></font><font><b/><sz val="11"/><name val="Calibri"/></font></fonts>
+<fills count="1"><fill><patternFill patternType="none"/></fill></fills>
+<borders count="1"><border/></borders>
+<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>
+<cellXfs count="3">
+<xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+<xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/>
+<xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0" applyAlignment="1"><alignment wrapText="1" vertical="top"/></xf>
+</cellXfs>
+</styleSheet>"#;
+
+/// 시트 이름 정리 (Excel 금지 문자 제거, 최대 31자)
+fn sheet_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '\\' | '/' | '?' | '*' | '[' | ']' | ':'))
+        .take(31)
+        .collect();
+    if cleaned.trim().is_empty() {
+        "Sheet1".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// XML 텍스트 이스케이프 (XML 1.0에서 허용되지 않는 제어 문자는 제거)
+fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(ch),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// 0부터 시작하는 열 번호 → 열 문자 (A, B, ..., Z, AA, ...)
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+fn worksheet_xml(rows: &[Vec<String>], column_widths: &[f64]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">\n",
+    );
+    // 헤더 행 고정
+    out.push_str(
+        "<sheetViews><sheetView workbookViewId=\"0\"><pane ySplit=\"1\" topLeftCell=\"A2\" activePane=\"bottomLeft\" state=\"frozen\"/></sheetView></sheetViews>\n",
+    );
+    if !column_widths.is_empty() {
+        out.push_str("<cols>");
+        for (i, width) in column_widths.iter().enumerate() {
+            out.push_str(&format!(
+                "<col min=\"{0}\" max=\"{0}\" width=\"{1}\" customWidth=\"1\"/>",
+                i + 1,
+                width
+            ));
+        }
+        out.push_str("</cols>\n");
+    }
+    out.push_str("<sheetData>\n");
+    for (r, row) in rows.iter().enumerate() {
+        let style = if r == 0 { 1 } else { 2 };
+        out.push_str(&format!("<row r=\"{}\">", r + 1));
+        for (c, value) in row.iter().enumerate() {
+            out.push_str(&format!(
+                "<c r=\"{}{}\" s=\"{}\" t=\"inlineStr\"><is><t xml:space=\"preserve\">{}</t></is></c>",
+                column_name(c),
+                r + 1,
+                style,
+                escape_xml(value)
+            ));
+        }
+        out.push_str("</row>\n");
+    }
+    out.push_str("</sheetData>\n</worksheet>");
+    out
+}
+
+/// 행 목록을 XLSX 파일 바이트로 생성 (rows[0]은 헤더)
+pub fn render_xlsx(sheet: &str, rows: &[Vec<String>], column_widths: &[f64]) -> Result<Vec<u8>, IteError> {
+    let workbook = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <workbook xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" \
+         xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\">\
+         <sheets><sheet name=\"{}\" sheetId=\"1\" r:id=\"rId1\"/></sheets></workbook>",
+        escape_xml(&sheet_name(sheet))
+    );
+
+    let parts: [(&str, String); 6] = [
+        ("[Content_Types].xml", CONTENT_TYPES.to_string()),
+        ("_rels/.rels", ROOT_RELS.to_string()),
+        ("xl/workbook.xml", workbook),
+        ("xl/_rels/workbook.xml.rels", WORKBOOK_RELS.to_string()),
+        ("xl/styles.xml", STYLES.to_string()),
+        ("xl/worksheets/sheet1.xml", worksheet_xml(rows, column_widths)),
+    ];
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (name, content) in parts {
+        zip.start_file(name, options)
+            .map_err(|e| IteError::Io(std::io::Error::other(e)))?;
+        zip.write_all(content.as_bytes())?;
+    }
+    let cursor = zip
+        .finish()
+        .map_err(|e| IteError::Io(std::io::Error::other(e)))?;
+    Ok(cursor.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_name() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(27), "AB");
+        assert_eq!(column_name(701), "ZZ");
+        assert_eq!(column_name(702), "AAA");
+    }
+
+    #[test]
+    fn test_render_xlsx_roundtrip() {
+        let rows = vec![
+            vec!["#".to_string(), "Text".to_string()],
+            vec!["1".to_string(), "a < b & \"c\"\u{1}".to_string()],
+        ];
+        let bytes = render_xlsx("Review: [1]", &rows, &[6.0, 40.0]).unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let mut sheet = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("xl/worksheets/sheet1.xml").unwrap(), &mut sheet)
+            .unwrap();
+        assert!(sheet.contains("a &lt; b &amp; &quot;c&quot;</t>"));
+        assert!(sheet.contains("r=\"B2\""));
+
+        let mut workbook = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("xl/workbook.xml").unwrap(), &mut workbook).unwrap();
+        assert!(workbook.contains("name=\"Review 1\""));
+    }
+}
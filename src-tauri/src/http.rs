@@ -0,0 +1,359 @@
+//! Shared HTTP Client
+//!
+//! 외부 API를 호출하는 모듈(MCP, Notion, Confluence, 커넥터, 클라우드 백업, TMS, 동기화)이 같은 설정의 reqwest 클라이언트를 씁니다.
+//! - 프록시: 시스템(HTTP(S)_PROXY/NO_PROXY 환경 변수 등) / 사용 안 함 / 직접 지정
+//! - 사내 CA: PEM 파일의 인증서를 기본 루트 인증서에 추가 (TLS 검사 프록시 환경)
+//! - 타임아웃: 연결/요청 제한 시간 (SSE처럼 오래 열려 있는 연결은 `streaming_client`)
+//! - 설정(`http`)이 바뀌면 다음 요청부터 새 클라이언트를 씁니다.
+//! - 재시도: 연결 실패/타임아웃/429/5xx는 제공자별 정책(`RetryPolicy`)에 따라 지수 백오프(jitter 포함)로 다시 보냅니다.
+//!   서버 상태를 바꿀 수 있는 요청은 재시도하지 않습니다 (GET/HEAD만, 읽기 전용 POST는 `send_read_with_retry`).
+//! - 응답 크기: 본문은 조각 단위로 읽고 최대 크기(`maxResponseMb`)를 넘으면 멈춥니다 (큰 페이지로 메모리가 바닥나지 않도록).
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{Certificate, Client, ClientBuilder, Method, NoProxy, Proxy, RequestBuilder, Response, StatusCode};
+
+use crate::models::HttpSettings;
+
+#[derive(Default)]
+struct State {
+    settings: HttpSettings,
+    /// 만들어 둔 클라이언트 (일반, 스트리밍), 설정이 바뀌면 비움
+    client: Option<Client>,
+    streaming: Option<Client>,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+/// 설정 적용 (앱 시작 시, 설정 변경 시)
+pub fn apply_settings(settings: &HttpSettings) {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let state = guard.get_or_insert_with(State::default);
+    if state.settings != *settings {
+        *state = State {
+            settings: settings.clone(),
+            ..Default::default()
+        };
+    }
+}
+
+fn cached(streaming: bool) -> Result<Client, String> {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let state = guard.get_or_insert_with(State::default);
+    let slot = if streaming { &mut state.streaming } else { &mut state.client };
+    if let Some(client) = slot {
+        return Ok(client.clone());
+    }
+    let client = builder(&state.settings, streaming)?
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+    *slot = Some(client.clone());
+    Ok(client)
+}
+
+/// 일반 요청용 클라이언트 (요청 제한 시간 적용)
+pub fn client() -> Result<Client, String> {
+    cached(false)
+}
+
+/// 스트리밍(SSE) 연결용 클라이언트 (연결 제한 시간만 적용)
+pub fn streaming_client() -> Result<Client, String> {
+    cached(true)
+}
+
+/// 설정으로 클라이언트 빌더 구성
+fn builder(settings: &HttpSettings, streaming: bool) -> Result<ClientBuilder, String> {
+    let mut builder = Client::builder()
+        .user_agent(concat!("OddEyes/", env!("CARGO_PKG_VERSION")))
+        .connect_timeout(Duration::from_secs(settings.connect_timeout_secs));
+    if !streaming {
+        builder = builder.timeout(Duration::from_secs(settings.request_timeout_secs));
+    }
+
+    match settings.proxy_mode.as_str() {
+        "none" => builder = builder.no_proxy(),
+        "custom" => {
+            let url = settings
+                .proxy_url
+                .as_deref()
+                .map(str::trim)
+                .filter(|u| !u.is_empty())
+                .ok_or("Proxy URL is not set")?;
+            let proxy = Proxy::all(url)
+                .map_err(|e| format!("Invalid proxy URL: {}", e))?
+                .no_proxy(settings.no_proxy.as_deref().and_then(NoProxy::from_string));
+            builder = builder.proxy(proxy);
+        }
+        // "system": reqwest 기본 동작 (환경 변수의 프록시 사용)
+        _ => {}
+    }
+
+    if let Some(path) = settings.ca_cert_path.as_deref().map(str::trim).filter(|p| !p.is_empty()) {
+        for cert in load_certificates(path)? {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    Ok(builder)
+}
+
+fn load_certificates(path: &str) -> Result<Vec<Certificate>, String> {
+    let pem = std::fs::read(path).map_err(|e| format!("Failed to read CA certificate {}: {}", path, e))?;
+    let certs = Certificate::from_pem_bundle(&pem)
+        .map_err(|e| format!("Invalid CA certificate {}: {}", path, e))?;
+    if certs.is_empty() {
+        return Err(format!("No certificates found in {}", path));
+    }
+    Ok(certs)
+}
+
+fn current_settings<T>(f: impl FnOnce(&HttpSettings) -> T) -> T {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    f(&guard.get_or_insert_with(State::default).settings)
+}
+
+/// 응답 본문 최대 크기 (bytes)
+pub fn max_response_bytes() -> usize {
+    current_settings(|s| s.max_response_mb as usize * 1024 * 1024)
+}
+
+/// 앱으로 가져오는 문서 내용 최대 크기 (bytes)
+pub fn max_document_bytes() -> usize {
+    current_settings(|s| s.max_document_kb as usize * 1024)
+}
+
+/// 최대 크기까지 읽은 응답 본문
+#[derive(Debug, Default)]
+pub struct LimitedBody {
+    pub bytes: Vec<u8>,
+    /// 최대 크기를 넘어 나머지를 읽지 않음
+    pub truncated: bool,
+}
+
+/// 응답 본문을 `limit` bytes까지 조각 단위로 읽음 (넘으면 연결을 끊고 `truncated`)
+pub async fn read_body_limited(mut response: Response, limit: usize) -> Result<LimitedBody, reqwest::Error> {
+    let mut body = LimitedBody::default();
+    if let Some(len) = response.content_length() {
+        body.bytes.reserve((len as usize).min(limit));
+    }
+    while let Some(chunk) = response.chunk().await? {
+        let room = limit - body.bytes.len();
+        if chunk.len() > room {
+            body.bytes.extend_from_slice(&chunk[..room]);
+            body.truncated = true;
+            break;
+        }
+        body.bytes.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// 응답 본문을 문자열로 읽음 (최대 크기를 넘으면 오류, JSON처럼 잘리면 쓸 수 없는 응답용)
+pub async fn read_text(response: Response) -> Result<String, String> {
+    let limit = max_response_bytes();
+    let body = read_body_limited(response, limit)
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    if body.truncated {
+        return Err(format!("Response is larger than {} MB", limit / (1024 * 1024)));
+    }
+    Ok(String::from_utf8_lossy(&body.bytes).into_owned())
+}
+
+/// 문자열을 `max_bytes` 이하로 자름 (UTF-8 문자 경계 기준), 잘렸으면 true
+pub fn truncate_utf8(text: &mut String, max_bytes: usize) -> bool {
+    if text.len() <= max_bytes {
+        return false;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    true
+}
+
+/// 오류 메시지에 넣을 응답 본문 일부
+pub fn preview(body: &str) -> &str {
+    const MAX_PREVIEW_BYTES: usize = 500;
+    let mut end = body.len().min(MAX_PREVIEW_BYTES);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    &body[..end]
+}
+
+/// 제공자별 재시도 정책
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// 처음 요청 포함 최대 시도 횟수
+    pub max_attempts: u32,
+    /// 첫 재시도 전 대기 시간 (시도마다 두 배)
+    pub base_delay: Duration,
+    /// 대기 시간 상한 (Retry-After 포함)
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Notion: 평균 초당 3회 제한이라 429가 잦음
+    pub const NOTION: RetryPolicy = RetryPolicy::new(4, 500, 8_000);
+    /// Confluence REST / Atlassian API
+    pub const ATLASSIAN: RetryPolicy = RetryPolicy::new(3, 500, 5_000);
+    /// Google Drive / Dropbox
+    pub const CLOUD: RetryPolicy = RetryPolicy::new(3, 1_000, 10_000);
+    /// Crowdin / Phrase
+    pub const TMS: RetryPolicy = RetryPolicy::new(3, 1_000, 10_000);
+    /// 용어집 동기화 원격 파일
+    pub const SYNC: RetryPolicy = RetryPolicy::new(3, 500, 5_000);
+    /// OpenAI / Anthropic 번역 제안
+    pub const LLM: RetryPolicy = RetryPolicy::new(3, 1_000, 10_000);
+
+    const fn new(max_attempts: u32, base_delay_ms: u64, max_delay_ms: u64) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(base_delay_ms),
+            max_delay: Duration::from_millis(max_delay_ms),
+        }
+    }
+
+    /// `attempt`번째(1부터) 실패 뒤 대기 시간
+    /// - Retry-After가 있으면 그 값을, 없으면 `base * 2^(attempt-1)` 범위 안에서 무작위(full jitter)
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>, jitter: f64) -> Duration {
+        if let Some(after) = retry_after {
+            return after.min(self.max_delay);
+        }
+        let exp = self
+            .base_delay
+            .saturating_mul(1u32 << attempt.saturating_sub(1).min(16))
+            .min(self.max_delay);
+        exp.mul_f64(jitter.clamp(0.0, 1.0))
+    }
+}
+
+/// 다시 보내 볼 만한 응답 상태
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// 다시 보내 볼 만한 전송 오류 (연결 실패, 타임아웃)
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout()
+}
+
+/// Retry-After 헤더 (초 단위만 지원)
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// 요청 전송 (GET/HEAD만 정책에 따라 재시도)
+pub async fn send_with_retry(request: RequestBuilder, policy: RetryPolicy) -> Result<Response, reqwest::Error> {
+    let idempotent = request
+        .try_clone()
+        .and_then(|r| r.build().ok())
+        .is_some_and(|r| matches!(*r.method(), Method::GET | Method::HEAD));
+    if idempotent {
+        send_read_with_retry(request, policy).await
+    } else {
+        request.send().await
+    }
+}
+
+/// 읽기 전용 요청 전송 (Notion 검색처럼 POST지만 서버 상태를 바꾸지 않는 요청, 메서드와 관계없이 재시도)
+/// - 본문이 스트림이라 복제할 수 없는 요청은 한 번만 보냅니다.
+pub async fn send_read_with_retry(request: RequestBuilder, policy: RetryPolicy) -> Result<Response, reqwest::Error> {
+    let mut attempt = 1;
+    loop {
+        let Some(next) = (attempt < policy.max_attempts).then(|| request.try_clone()).flatten() else {
+            return request.send().await;
+        };
+        let wait = match next.send().await {
+            Ok(response) if is_retryable_status(response.status()) => {
+                println!(
+                    "[HTTP] {} returned {}, retrying ({}/{})",
+                    response.url().path(),
+                    response.status(),
+                    attempt,
+                    policy.max_attempts - 1
+                );
+                retry_after(&response)
+            }
+            Ok(response) => return Ok(response),
+            Err(e) if is_retryable_error(&e) => {
+                println!("[HTTP] Request failed, retrying ({}/{}): {}", attempt, policy.max_attempts - 1, e);
+                None
+            }
+            Err(e) => return Err(e),
+        };
+        let delay = policy.delay(attempt, wait, rand::thread_rng().gen::<f64>());
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_rejects_invalid_settings() {
+        let custom = HttpSettings {
+            proxy_mode: "custom".to_string(),
+            ..Default::default()
+        };
+        assert!(builder(&custom, false).is_err());
+
+        let missing_ca = HttpSettings {
+            ca_cert_path: Some("/nonexistent/ca.pem".to_string()),
+            ..Default::default()
+        };
+        assert!(builder(&missing_ca, false).is_err());
+
+        let proxied = HttpSettings {
+            proxy_mode: "custom".to_string(),
+            proxy_url: Some("http://proxy.corp:8080".to_string()),
+            no_proxy: Some("localhost,.corp.example".to_string()),
+            ..Default::default()
+        };
+        assert!(builder(&proxied, false).unwrap().build().is_ok());
+    }
+
+    #[test]
+    fn test_truncate_utf8_keeps_char_boundary() {
+        let mut text = "가나다".to_string();
+        assert!(truncate_utf8(&mut text, 4));
+        assert_eq!(text, "가");
+        assert!(!truncate_utf8(&mut text, 10));
+        assert_eq!(preview("abc"), "abc");
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_and_caps() {
+        let policy = RetryPolicy::new(5, 500, 3_000);
+        assert_eq!(policy.delay(1, None, 1.0), Duration::from_millis(500));
+        assert_eq!(policy.delay(2, None, 1.0), Duration::from_millis(1_000));
+        assert_eq!(policy.delay(4, None, 1.0), Duration::from_millis(3_000));
+        assert_eq!(policy.delay(2, None, 0.5), Duration::from_millis(500));
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(60)), 1.0), Duration::from_millis(3_000));
+
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    }
+}
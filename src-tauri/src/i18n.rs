@@ -0,0 +1,180 @@
+//! Backend Localization
+//!
+//! 백엔드가 사용자에게 보여줄 문구(오류 메시지)의 언어 선택
+//! - 언어는 앱 설정(`uiLanguage`)을 따르며, 설정이 바뀌면 `apply_language`로 갱신합니다.
+//! - 오류 원문(`CommandError.message`)은 진단용으로 그대로 두고, 화면에는 코드별 문구를 씁니다.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::error::ErrorCode;
+
+/// 지원 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    Ko,
+    En,
+}
+
+impl Locale {
+    /// 지원하는 언어 코드
+    pub const CODES: &'static [&'static str] = &["ko", "en"];
+
+    /// 언어 코드 파싱 ("en-US"처럼 지역이 붙어도 허용, 모르는 언어는 None)
+    pub fn parse(code: &str) -> Option<Self> {
+        let primary = code.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        match primary.as_str() {
+            "ko" => Some(Locale::Ko),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::Ko => "ko",
+            Locale::En => "en",
+        }
+    }
+}
+
+/// 현재 언어 (0 = ko, 1 = en), 기본값은 프론트엔드와 같은 한국어
+static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(0);
+
+pub fn current_locale() -> Locale {
+    match CURRENT_LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::En,
+        _ => Locale::Ko,
+    }
+}
+
+pub fn set_locale(locale: Locale) {
+    let value = match locale {
+        Locale::Ko => 0,
+        Locale::En => 1,
+    };
+    CURRENT_LOCALE.store(value, Ordering::Relaxed);
+}
+
+/// 설정의 언어 코드 적용 (지원하지 않는 언어면 그대로 둠)
+pub fn apply_language(code: &str) {
+    if let Some(locale) = Locale::parse(code) {
+        set_locale(locale);
+    }
+}
+
+/// 오류 코드별 사용자용 문구
+pub fn error_message(code: ErrorCode, locale: Locale) -> &'static str {
+    let (ko, en) = match code {
+        ErrorCode::LockError => (
+            "다른 작업이 데이터베이스를 사용 중입니다. 잠시 후 다시 시도해주세요.",
+            "The database is busy with another task. Please try again shortly.",
+        ),
+        ErrorCode::DbError => (
+            "데이터베이스 작업에 실패했습니다.",
+            "A database operation failed.",
+        ),
+        ErrorCode::IoError => ("파일 입출력 중 오류가 발생했습니다.", "A file I/O error occurred."),
+        ErrorCode::SerializationError => (
+            "데이터 형식을 처리하지 못했습니다.",
+            "The data could not be processed.",
+        ),
+        ErrorCode::InvalidInput => ("입력값이 올바르지 않습니다.", "The input is not valid."),
+        ErrorCode::InvalidOperation => (
+            "지금은 이 작업을 할 수 없습니다.",
+            "This action is not allowed right now.",
+        ),
+        ErrorCode::NotFound => ("요청한 항목을 찾을 수 없습니다.", "The requested item was not found."),
+        ErrorCode::ProjectNotFound => ("프로젝트를 찾을 수 없습니다.", "The project was not found."),
+        ErrorCode::BlockNotFound => ("블록을 찾을 수 없습니다.", "The block was not found."),
+        ErrorCode::SegmentNotFound => ("세그먼트를 찾을 수 없습니다.", "The segment was not found."),
+        ErrorCode::ReadOnly => (
+            "읽기 전용으로 열린 프로젝트는 수정할 수 없습니다.",
+            "This project is open read-only and cannot be changed.",
+        ),
+        ErrorCode::PathError => ("파일 경로가 올바르지 않습니다.", "The file path is not valid."),
+        ErrorCode::SecurityError => (
+            "허용되지 않은 위치에 접근하려고 했습니다.",
+            "Access to this location is not allowed.",
+        ),
+        ErrorCode::ReadError => ("파일을 읽지 못했습니다.", "The file could not be read."),
+        ErrorCode::WriteError => ("파일을 저장하지 못했습니다.", "The file could not be saved."),
+        ErrorCode::ReadDirError => ("폴더를 읽지 못했습니다.", "The folder could not be read."),
+        ErrorCode::DirCreateError => ("폴더를 만들지 못했습니다.", "The folder could not be created."),
+        ErrorCode::FileError => ("파일 처리 중 오류가 발생했습니다.", "The file could not be processed."),
+        ErrorCode::FileTooLarge => ("파일이 너무 큽니다.", "The file is too large."),
+        ErrorCode::ExtractError => ("파일 내용을 추출하지 못했습니다.", "The file contents could not be extracted."),
+        ErrorCode::InvalidType => ("지원하지 않는 파일 형식입니다.", "This file type is not supported."),
+        ErrorCode::InvalidPattern => ("검색 패턴이 올바르지 않습니다.", "The search pattern is not valid."),
+        ErrorCode::InvalidKey => ("키 형식이 올바르지 않습니다.", "The key is not valid."),
+        ErrorCode::TokenMismatch => (
+            "다른 곳에서 먼저 변경되었습니다. 새로고침 후 다시 시도해주세요.",
+            "It was changed elsewhere first. Refresh and try again.",
+        ),
+        ErrorCode::NetworkError => (
+            "네트워크 연결에 실패했습니다. 연결 상태를 확인한 뒤 다시 시도해주세요.",
+            "The network request failed. Check your connection and try again.",
+        ),
+        ErrorCode::Timeout => (
+            "응답 시간이 초과되었습니다. 다시 시도해주세요.",
+            "The request timed out. Please try again.",
+        ),
+        ErrorCode::AuthRequired => (
+            "로그인이 필요합니다. 연결을 다시 설정해주세요.",
+            "Sign-in is required. Please reconnect.",
+        ),
+        ErrorCode::RateLimited => (
+            "요청이 너무 많습니다. 잠시 후 다시 시도해주세요.",
+            "Too many requests. Please wait a moment and try again.",
+        ),
+        ErrorCode::Offline => (
+            "오프라인 상태라 이 작업을 할 수 없습니다. 연결되면 다시 시도해주세요.",
+            "You are offline, so this action is unavailable. Try again once you are connected.",
+        ),
+        ErrorCode::QaError => ("품질 검사에 실패했습니다.", "The quality check failed."),
+        ErrorCode::McpError => ("MCP 서버 요청에 실패했습니다.", "The MCP server request failed."),
+        ErrorCode::NotionError => ("Notion 요청에 실패했습니다.", "The Notion request failed."),
+        ErrorCode::ConnectorError => ("커넥터 작업에 실패했습니다.", "The connector operation failed."),
+        ErrorCode::ConfluenceError => ("Confluence 요청에 실패했습니다.", "The Confluence request failed."),
+        ErrorCode::CloudError => ("클라우드 백업 작업에 실패했습니다.", "The cloud backup operation failed."),
+        ErrorCode::TmsError => ("TMS 연동에 실패했습니다.", "The TMS request failed."),
+        ErrorCode::SyncError => ("동기화에 실패했습니다.", "Sync failed."),
+        ErrorCode::UpdateError => ("업데이트를 진행하지 못했습니다.", "The update could not be completed."),
+        ErrorCode::SecretManagerError => (
+            "보안 저장소에 접근하지 못했습니다.",
+            "The secure vault could not be accessed.",
+        ),
+        ErrorCode::SecureStoreError => (
+            "보안 저장소에 접근하지 못했습니다.",
+            "The secure store could not be accessed.",
+        ),
+        ErrorCode::ClipboardError => ("클립보드에 접근하지 못했습니다.", "The clipboard could not be accessed."),
+        ErrorCode::InternalPanic => (
+            "예기치 않은 내부 오류가 발생했습니다. 문제가 계속되면 진단 정보를 보내주세요.",
+            "An unexpected internal error occurred. If it keeps happening, please send the diagnostics.",
+        ),
+    };
+    match locale {
+        Locale::Ko => ko,
+        Locale::En => en,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale_accepts_region_tags() {
+        assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+        assert_eq!(Locale::parse("KO"), Some(Locale::Ko));
+        assert_eq!(Locale::parse("ja"), None);
+    }
+
+    #[test]
+    fn test_every_code_has_both_messages() {
+        for code in ErrorCode::ALL {
+            assert!(!error_message(*code, Locale::Ko).is_empty());
+            assert!(!error_message(*code, Locale::En).is_empty());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! CAT Tool Interop
+//!
+//! 다른 CAT 도구의 프로젝트/번역 메모리 형식을 읽어 ITE 프로젝트로 옮기고,
+//! 납품 형식(SDLXLIFF)으로 다시 써 냅니다.
+
+pub mod omegat;
+pub mod sdlxliff;
+pub mod tmx;
+
+use std::collections::HashMap;
+
+use crate::export::escape_html;
+use crate::models::{BlockMetadata, EditorBlock, IteProject, ProjectMetadata, ProjectSettings, SegmentGroup};
+use crate::text::lang::{display_name, normalize_language};
+use crate::text::locale::{canonicalize_locale, validate_language_pair};
+use crate::text::strip_html;
+
+/// 평문 한 문단짜리 블록 (원본 파일 이름 태그 포함)
+pub(crate) fn paragraph_block(block_type: &str, text: &str, tag: &str, now: i64) -> EditorBlock {
+    let content = format!("<p>{}</p>", escape_html(text));
+    EditorBlock {
+        id: uuid::Uuid::new_v4().to_string(),
+        block_type: block_type.to_string(),
+        hash: format!("{:x}", md5::compute(&content)),
+        content,
+        metadata: BlockMetadata {
+            author: None,
+            created_at: now,
+            updated_at: now,
+            tags: vec![tag.to_string()],
+            comments: None,
+            capacity: None,
+        },
+    }
+}
+
+/// 세그먼트 번역문 평문 (번역 블록이 여러 개면 공백으로 이어 붙임)
+pub(crate) fn segment_target_text(project: &IteProject, segment: &SegmentGroup) -> String {
+    segment
+        .target_ids
+        .iter()
+        .filter_map(|id| project.blocks.get(id))
+        .map(|b| strip_html(&b.content).trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 가져온 프로젝트를 쌓는 도우미 (원문/번역문 1:1 세그먼트)
+pub(crate) struct ProjectBuilder {
+    now: i64,
+    blocks: HashMap<String, EditorBlock>,
+    segments: Vec<SegmentGroup>,
+}
+
+impl ProjectBuilder {
+    pub(crate) fn new(now: i64) -> Self {
+        Self {
+            now,
+            blocks: HashMap::new(),
+            segments: Vec::new(),
+        }
+    }
+
+    /// 세그먼트 추가, 반환: 세그먼트 ID
+    pub(crate) fn push(&mut self, source: &str, target: &str, tag: &str) -> String {
+        let source_block = paragraph_block("source", source, tag, self.now);
+        let target_block = paragraph_block("target", target, tag, self.now);
+        let group_id = uuid::Uuid::new_v4().to_string();
+        self.segments.push(SegmentGroup {
+            group_id: group_id.clone(),
+            source_ids: vec![source_block.id.clone()],
+            target_ids: vec![target_block.id.clone()],
+            is_aligned: true,
+            order: self.segments.len() as i32,
+        });
+        self.blocks.insert(source_block.id.clone(), source_block);
+        self.blocks.insert(target_block.id.clone(), target_block);
+        group_id
+    }
+
+    /// - 파일의 언어 코드는 로캘로도 기록합니다 (모르는 언어이거나 원문/번역 언어가 같으면 비움).
+    pub(crate) fn finish(
+        self,
+        title: String,
+        description: String,
+        source_lang: Option<&str>,
+        target_lang: Option<&str>,
+    ) -> IteProject {
+        let locale = |lang: Option<&str>| lang.and_then(|l| canonicalize_locale(l).ok());
+        let (mut source_locale, target_locale) = (locale(source_lang), locale(target_lang));
+        if validate_language_pair(source_locale.as_deref(), target_locale.as_deref()).is_err() {
+            source_locale = None;
+        }
+        IteProject {
+            id: uuid::Uuid::new_v4().to_string(),
+            version: "1.0.0".to_string(),
+            metadata: ProjectMetadata {
+                title,
+                description: Some(description),
+                domain: "general".to_string(),
+                target_language: target_lang.map(target_language_name),
+                source_locale,
+                target_locale,
+                created_at: self.now,
+                updated_at: self.now,
+                author: None,
+                glossary_paths: None,
+                settings: ProjectSettings {
+                    strictness_level: 0.5,
+                    auto_save: true,
+                    auto_save_interval: 30000,
+                    theme: "system".to_string(),
+                },
+            },
+            segments: self.segments,
+            blocks: self.blocks,
+            history: Vec::new(),
+        }
+    }
+}
+
+/// 외부 언어 코드("KO", "ko-KR") → 에디터 표시 이름 (모르는 언어는 그대로)
+fn target_language_name(lang: &str) -> String {
+    normalize_language(lang)
+        .and_then(display_name)
+        .map(str::to_string)
+        .unwrap_or_else(|| lang.to_string())
+}
+
+/// 태그 길이 제한에 맞춘 파일 이름 태그
+pub(crate) fn file_tag(path: &str) -> String {
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    name.chars().take(crate::db::MAX_TAG_LEN).collect()
+}
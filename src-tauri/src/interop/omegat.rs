@@ -0,0 +1,441 @@
+//! OmegaT Project Import
+//!
+//! OmegaT 프로젝트 폴더를 읽어 ITE 프로젝트로 옮깁니다.
+//! - `omegat.project`: 언어쌍과 폴더 위치 (`__DEFAULT__`이면 기본 폴더)
+//! - `source/`: 원문 파일 (평문 .txt/.text/.md만, 줄 단위 = OmegaT 텍스트 필터 기본값)
+//! - `omegat/project_save.tmx`: 작업 TM → 프로젝트 TM + 일치하는 원문의 번역문
+//! - `tm/**/*.tmx`: 참조 TM → 프로젝트 TM (번역문 채우기에는 쓰지 않음)
+//! - `glossary/*.txt|.tab|.utf8`: 탭 구분 용어집 (원문, 번역문, 설명) → 프로젝트 용어집
+//!
+//! 프로젝트 폴더 밖을 가리키는 폴더 설정은 무시합니다.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+use super::tmx::{parse_tmx, TmxUnit};
+use super::{file_tag, ProjectBuilder};
+use crate::error::IteError;
+use crate::models::IteProject;
+use crate::text::encoding::read_text_file;
+use crate::text::locale::SegmentationRules;
+use crate::text::unicode::file_name_nfc;
+
+/// OmegaT 프로젝트 설정 파일
+pub const PROJECT_FILE: &str = "omegat.project";
+/// 작업 TM (프로젝트 폴더 기준)
+const PROJECT_SAVE_TMX: &str = "omegat/project_save.tmx";
+/// `omegat.project`에서 기본 위치를 뜻하는 값
+const DEFAULT_MARKER: &str = "__DEFAULT__";
+
+/// 가져올 원문 파일 확장자
+const SOURCE_EXTENSIONS: &[&str] = &["txt", "text", "md"];
+/// 용어집 파일 확장자
+const GLOSSARY_EXTENSIONS: &[&str] = &["txt", "tab", "utf8"];
+/// 폴더별 최대 파일 수 (잘못된 폴더 선택 방지)
+const MAX_FILES: usize = 1000;
+
+/// `omegat.project` 설정
+#[derive(Debug, Clone)]
+pub struct OmegaTConfig {
+    pub source_lang: String,
+    pub target_lang: String,
+    pub source_dir: PathBuf,
+    pub tm_dir: PathBuf,
+    pub glossary_dir: PathBuf,
+    /// 쓰기용 용어집 파일 (보통 glossary_dir/glossary.txt)
+    pub glossary_file: PathBuf,
+}
+
+/// 원문 파일 (프로젝트 폴더 기준 상대 경로, 줄 단위 문단)
+#[derive(Debug, Clone)]
+pub struct OmegaTSourceFile {
+    pub path: String,
+    pub paragraphs: Vec<String>,
+}
+
+/// OmegaT 용어집 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OmegaTGlossaryEntry {
+    pub source: String,
+    pub target: String,
+    pub notes: Option<String>,
+}
+
+/// 읽어 온 OmegaT 프로젝트
+#[derive(Debug, Clone)]
+pub struct OmegaTProject {
+    pub title: String,
+    pub config: OmegaTConfig,
+    pub files: Vec<OmegaTSourceFile>,
+    /// 지원하지 않는 형식이라 건너뛴 원문 파일 (상대 경로)
+    pub skipped_files: Vec<String>,
+    /// project_save.tmx
+    pub working_tm: Vec<TmxUnit>,
+    /// tm/ 폴더의 참조 TM
+    pub reference_tm: Vec<TmxUnit>,
+    pub glossary: Vec<OmegaTGlossaryEntry>,
+}
+
+/// `<project>` 아래 설정 값 (요소 이름 → 텍스트)
+fn parse_project_xml(xml: &str) -> Result<HashMap<String, String>, IteError> {
+    let mut reader = Reader::from_str(xml);
+    let mut values = HashMap::new();
+    let mut in_project = false;
+    let mut current: Option<String> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "project" {
+                    in_project = true;
+                } else if in_project {
+                    current = Some(name);
+                }
+            }
+            Ok(Event::Text(t)) => {
+                if let Some(name) = current.as_ref() {
+                    let text = t
+                        .unescape()
+                        .map_err(|e| IteError::InvalidOperation(format!("Invalid {}: {}", PROJECT_FILE, e)))?;
+                    values.insert(name.clone(), text.trim().to_string());
+                }
+            }
+            Ok(Event::End(e)) => {
+                if e.name().as_ref() == b"project" {
+                    in_project = false;
+                }
+                current = None;
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(IteError::InvalidOperation(format!("Invalid {}: {}", PROJECT_FILE, e)));
+            }
+            _ => {}
+        }
+    }
+    Ok(values)
+}
+
+/// 설정 값 → 프로젝트 폴더 안의 경로 (기본값/폴더 밖 경로면 default 사용)
+fn resolve_dir(root: &Path, value: Option<&String>, default: &str) -> PathBuf {
+    let fallback = root.join(default);
+    let Some(value) = value.map(|v| v.trim()).filter(|v| !v.is_empty() && *v != DEFAULT_MARKER) else {
+        return fallback;
+    };
+    let candidate = root.join(value);
+    let inside = match (candidate.canonicalize(), root.canonicalize()) {
+        (Ok(c), Ok(r)) => c.starts_with(r),
+        _ => false,
+    };
+    if inside {
+        candidate
+    } else {
+        eprintln!("[OmegaT] Ignoring folder outside the project: {}", value);
+        fallback
+    }
+}
+
+/// `omegat.project` 읽기
+pub fn read_config(root: &Path) -> Result<OmegaTConfig, IteError> {
+    let project_file = root.join(PROJECT_FILE);
+    if !project_file.is_file() {
+        return Err(IteError::InvalidOperation(format!(
+            "Not an OmegaT project folder ({} not found)",
+            PROJECT_FILE
+        )));
+    }
+    let xml = read_text_file(&project_file)?.text;
+    let values = parse_project_xml(&xml)?;
+
+    let lang = |key: &str| {
+        values
+            .get(key)
+            .filter(|v| !v.is_empty())
+            .cloned()
+            .ok_or_else(|| IteError::InvalidOperation(format!("{} has no <{}>", PROJECT_FILE, key)))
+    };
+    let glossary_dir = resolve_dir(root, values.get("glossary_dir"), "glossary");
+    let glossary_file = match values.get("glossary_file").map(|v| v.trim()) {
+        None | Some("") | Some(DEFAULT_MARKER) => glossary_dir.join("glossary.txt"),
+        Some(_) => resolve_dir(root, values.get("glossary_file"), "glossary/glossary.txt"),
+    };
+
+    Ok(OmegaTConfig {
+        source_lang: lang("source_lang")?,
+        target_lang: lang("target_lang")?,
+        source_dir: resolve_dir(root, values.get("source_dir"), "source"),
+        tm_dir: resolve_dir(root, values.get("tm_dir"), "tm"),
+        glossary_dir,
+        glossary_file,
+    })
+}
+
+/// 폴더 아래 파일 목록 (재귀, 경로 순, 심볼릭 링크 제외)
+fn collect_files(dir: &Path) -> Result<Vec<PathBuf>, IteError> {
+    let mut out = Vec::new();
+    if !dir.is_dir() {
+        return Ok(out);
+    }
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                stack.push(entry.path());
+            } else if file_type.is_file() {
+                out.push(entry.path());
+                if out.len() > MAX_FILES {
+                    return Err(IteError::InvalidOperation(format!(
+                        "Too many files in {} (max {})",
+                        dir.display(),
+                        MAX_FILES
+                    )));
+                }
+            }
+        }
+    }
+    out.sort();
+    Ok(out)
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| extensions.contains(&e.to_lowercase().as_str()))
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// OmegaT 용어집(탭 구분) 파싱
+/// - `#`으로 시작하는 줄과 원문/번역문이 비어 있는 줄은 건너뜁니다.
+pub fn parse_glossary(text: &str) -> Vec<OmegaTGlossaryEntry> {
+    text.lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| {
+            let mut cols = line.split('\t').map(str::trim);
+            let source = cols.next().filter(|s| !s.is_empty())?;
+            let target = cols.next().filter(|s| !s.is_empty())?;
+            let notes = cols.next().filter(|s| !s.is_empty()).map(str::to_string);
+            Some(OmegaTGlossaryEntry {
+                source: source.to_string(),
+                target: target.to_string(),
+                notes,
+            })
+        })
+        .collect()
+}
+
+/// 프로젝트 폴더 읽기
+pub fn read_project(root: &Path) -> Result<OmegaTProject, IteError> {
+    let config = read_config(root)?;
+
+    let mut files = Vec::new();
+    let mut skipped_files = Vec::new();
+    for path in collect_files(&config.source_dir)? {
+        let rel = relative_path(root, &path);
+        if !has_extension(&path, SOURCE_EXTENSIONS) {
+            skipped_files.push(rel);
+            continue;
+        }
+        let paragraphs = read_text_file(&path)?
+            .text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect();
+        files.push(OmegaTSourceFile { path: rel, paragraphs });
+    }
+
+    let read_tmx = |path: &Path| -> Result<Vec<TmxUnit>, IteError> {
+        let xml = read_text_file(path)?.text;
+        parse_tmx(&xml, &config.source_lang, &config.target_lang)
+            .map_err(|e| IteError::InvalidOperation(format!("{}: {}", relative_path(root, path), e)))
+    };
+
+    let working_path = root.join(PROJECT_SAVE_TMX);
+    let working_tm = if working_path.is_file() { read_tmx(&working_path)? } else { Vec::new() };
+
+    let mut reference_tm = Vec::new();
+    for path in collect_files(&config.tm_dir)? {
+        if has_extension(&path, &["tmx"]) {
+            reference_tm.extend(read_tmx(&path)?);
+        }
+    }
+
+    let mut glossary_paths: Vec<PathBuf> = collect_files(&config.glossary_dir)?
+        .into_iter()
+        .filter(|p| has_extension(p, GLOSSARY_EXTENSIONS))
+        .collect();
+    if config.glossary_file.is_file() && !glossary_paths.contains(&config.glossary_file) {
+        glossary_paths.push(config.glossary_file.clone());
+    }
+    let mut glossary = Vec::new();
+    for path in glossary_paths {
+        glossary.extend(parse_glossary(&read_text_file(&path)?.text));
+    }
+
+    let title = file_name_nfc(root)
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "OmegaT project".to_string());
+
+    Ok(OmegaTProject {
+        title,
+        config,
+        files,
+        skipped_files,
+        working_tm,
+        reference_tm,
+        glossary,
+    })
+}
+
+/// 문단 번역 찾기: 문단 전체가 일치하거나, 모든 문장이 TM에 있으면 이어 붙임
+/// - 원문은 원문 언어 규칙으로 나누고, 번역문은 번역 언어 규칙으로 이어 붙입니다.
+fn lookup_translation(
+    paragraph: &str,
+    tm: &HashMap<&str, &str>,
+    source_rules: &SegmentationRules,
+    target_rules: &SegmentationRules,
+) -> Option<String> {
+    if let Some(target) = tm.get(paragraph) {
+        return Some(target.to_string());
+    }
+    let sentences = source_rules.split(paragraph);
+    if sentences.len() < 2 {
+        return None;
+    }
+    let parts: Option<Vec<&str>> = sentences.iter().map(|s| tm.get(s).copied()).collect();
+    parts.map(|p| p.join(target_rules.joiner))
+}
+
+/// 읽어 온 프로젝트 → ITE 프로젝트
+/// - 원문 한 줄 = 세그먼트 1개, 블록에는 원문 파일 이름 태그를 붙입니다.
+/// - 반환: (프로젝트, 번역문이 채워진 세그먼트 수)
+pub fn build_project(omegat: &OmegaTProject, now: i64) -> (IteProject, usize) {
+    // 기본 번역 우선, 없으면 대체 번역 중 먼저 나온 것
+    let mut tm: HashMap<&str, &str> = HashMap::new();
+    for unit in omegat.working_tm.iter().filter(|u| !u.alternative) {
+        tm.insert(unit.source.as_str(), unit.target.as_str());
+    }
+    for unit in omegat.working_tm.iter().filter(|u| u.alternative) {
+        tm.entry(unit.source.as_str()).or_insert(unit.target.as_str());
+    }
+
+    let config = &omegat.config;
+    let source_rules = SegmentationRules::for_locale(Some(&config.source_lang));
+    let target_rules = SegmentationRules::for_locale(Some(&config.target_lang));
+
+    let mut builder = ProjectBuilder::new(now);
+    let mut translated = 0usize;
+    for file in &omegat.files {
+        let tag = file_tag(&file.path);
+        for paragraph in &file.paragraphs {
+            let target = lookup_translation(paragraph, &tm, &source_rules, &target_rules);
+            if target.is_some() {
+                translated += 1;
+            }
+            builder.push(paragraph, target.as_deref().unwrap_or(""), &tag);
+        }
+    }
+
+    let description = format!("Imported from OmegaT ({} → {})", config.source_lang, config.target_lang);
+    let project = builder.finish(
+        omegat.title.clone(),
+        description,
+        Some(&config.source_lang),
+        Some(&config.target_lang),
+    );
+    (project, translated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROJECT_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<omegat>
+  <project version="1.0">
+    <source_dir>__DEFAULT__</source_dir>
+    <tm_dir>__DEFAULT__</tm_dir>
+    <glossary_dir>__DEFAULT__</glossary_dir>
+    <glossary_file>__DEFAULT__</glossary_file>
+    <source_lang>EN-US</source_lang>
+    <target_lang>KO</target_lang>
+  </project>
+</omegat>"#;
+
+    const PROJECT_SAVE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tmx version="1.4"><header srclang="EN-US"/><body>
+  <tu><tuv lang="EN-US"><seg>Hello world.</seg></tuv><tuv lang="KO"><seg>안녕 세계.</seg></tuv></tu>
+  <tu><tuv lang="EN-US"><seg>Bye.</seg></tuv><tuv lang="KO"><seg>잘 가.</seg></tuv></tu>
+  <tu><tuv lang="EN-US"><seg>Fish &amp; chips</seg></tuv><tuv lang="KO"><seg>피시 앤 칩스</seg></tuv></tu>
+</body></tmx>"#;
+
+    fn write_project(root: &Path) {
+        std::fs::create_dir_all(root.join("source/docs")).unwrap();
+        std::fs::create_dir_all(root.join("omegat")).unwrap();
+        std::fs::create_dir_all(root.join("glossary")).unwrap();
+        std::fs::write(root.join(PROJECT_FILE), PROJECT_XML).unwrap();
+        std::fs::write(root.join("source/docs/intro.txt"), "Hello world. Bye.\n\nFish & chips\nNew line\n").unwrap();
+        std::fs::write(root.join("source/logo.png"), [0u8; 4]).unwrap();
+        std::fs::write(root.join(PROJECT_SAVE_TMX), PROJECT_SAVE).unwrap();
+        std::fs::write(root.join("glossary/glossary.txt"), "# comment\nworld\t세계\tnoun\nempty\t\n").unwrap();
+    }
+
+    #[test]
+    fn test_read_and_build_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("Manual");
+        write_project(&root);
+
+        let omegat = read_project(&root).unwrap();
+        assert_eq!(omegat.title, "Manual");
+        assert_eq!(omegat.config.target_lang, "KO");
+        assert_eq!(omegat.files.len(), 1);
+        assert_eq!(omegat.files[0].path, "source/docs/intro.txt");
+        assert_eq!(omegat.skipped_files, vec!["source/logo.png".to_string()]);
+        assert_eq!(omegat.working_tm.len(), 3);
+        assert_eq!(
+            omegat.glossary,
+            vec![OmegaTGlossaryEntry {
+                source: "world".to_string(),
+                target: "세계".to_string(),
+                notes: Some("noun".to_string()),
+            }]
+        );
+
+        let (project, translated) = build_project(&omegat, 1);
+        assert_eq!(project.segments.len(), 3);
+        assert_eq!(translated, 2);
+        assert_eq!(project.metadata.target_language.as_deref(), Some("한국어"));
+        let target = |i: usize| &project.blocks[&project.segments[i].target_ids[0]];
+        assert_eq!(target(0).content, "<p>안녕 세계. 잘 가.</p>");
+        assert_eq!(target(1).content, "<p>피시 앤 칩스</p>");
+        assert_eq!(target(2).content, "<p></p>");
+        assert_eq!(target(0).metadata.tags, vec!["intro.txt".to_string()]);
+    }
+
+    #[test]
+    fn test_missing_project_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(read_project(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        let rules = SegmentationRules::for_locale(Some("EN-US"));
+        assert_eq!(rules.split("A b. C? v1.2 ok"), vec!["A b.", "C?", "v1.2 ok"]);
+        assert_eq!(rules.split("좋아요。はい！"), vec!["좋아요。", "はい！"]);
+    }
+}
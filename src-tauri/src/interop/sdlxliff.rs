@@ -0,0 +1,765 @@
+//! SDLXLIFF (Trados) Reader/Writer
+//!
+//! Trados Studio의 `.sdlxliff`를 세그먼트 단위로 읽고, 번역문과 상태를 원본 파일에 다시 써 넣습니다.
+//! - 세그먼트: `<seg-source>`/`<target>` 안의 `<mrk mtype="seg" mid="…">` (trans-unit id + mid로 식별)
+//! - 상태: `<sdl:seg-defs>`의 `<sdl:seg id="mid" conf="…" locked="true">`
+//! - 인라인 태그(`<g>`, `<x/>`, `<bx/>`, `<ph>`, 다른 `<mrk>` 등)는 `⟦X1⟧` 토큰으로 바꾸고
+//!   원래 XML은 세그먼트별 `Placeholder`로 보관합니다.
+//!
+//! 내보내기는 원본 XML을 그대로 흘려 쓰면서 대상 세그먼트의 `<target>` mrk 내용과 `conf`만 바꿉니다.
+//! `<target>`이 없는 trans-unit은 `<seg-source>`를 본떠 새로 만듭니다.
+//! 세그먼트 메모는 XLIFF 1.2 순서대로 `<sdl:seg-defs>` 앞(없으면 trans-unit 끝)에 `<note from="translator">`로 넣습니다.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::{Component, Path};
+
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::reader::Reader;
+use quick_xml::Writer;
+
+use super::{file_tag, segment_target_text, ProjectBuilder};
+use crate::db::{XliffDocument, XliffSegmentRef};
+use crate::error::IteError;
+use crate::models::IteProject;
+use crate::text::encoding::decode_bytes;
+use crate::text::unicode::file_name_nfc;
+use crate::text::{make_token, restore_placeholders, Placeholder};
+
+/// `xliff_documents.format` 값
+pub const SDLXLIFF_FORMAT: &str = "sdlxliff";
+
+/// 인라인 태그 토큰 접두사 (`⟦X1⟧`, `⟦X2⟧`, ...)
+const TAG_TOKEN_PREFIX: &str = "X";
+
+/// Trados 세그먼트 확인 상태 (`conf` 값)
+pub const SDL_STATUSES: &[&str] = &[
+    "Draft",
+    "Translated",
+    "RejectedTranslation",
+    "ApprovedTranslation",
+    "RejectedSignOff",
+    "ApprovedSignOff",
+];
+
+/// SDLXLIFF 세그먼트
+#[derive(Debug, Clone)]
+pub struct SdlSegment {
+    pub unit_id: String,
+    pub mid: String,
+    /// 인라인 태그가 토큰으로 바뀐 평문
+    pub source: String,
+    pub target: String,
+    /// 토큰 → 원래 인라인 태그 XML (원문/번역문 공용)
+    pub placeholders: Vec<Placeholder>,
+    /// `conf` 값 (없으면 미번역)
+    pub status: Option<String>,
+    pub locked: bool,
+}
+
+/// 읽어 온 SDLXLIFF 파일
+#[derive(Debug, Clone, Default)]
+pub struct SdlXliffFile {
+    /// 원본 문서 이름 (`<file original>`)
+    pub original: Option<String>,
+    pub source_lang: Option<String>,
+    pub target_lang: Option<String>,
+    pub segments: Vec<SdlSegment>,
+}
+
+/// 내보낼 세그먼트 내용
+#[derive(Debug, Clone)]
+pub struct SegmentUpdate {
+    /// `<mrk>` 안에 들어갈 XML (escape 완료, `target_to_xml` 결과)
+    pub target_xml: String,
+    /// 바꿀 `conf` 값 (None이면 그대로)
+    pub status: Option<String>,
+}
+
+/// (trans-unit id, mid) → 내보낼 내용
+pub type SegmentUpdates = HashMap<(String, String), SegmentUpdate>;
+
+/// trans-unit id → 덧붙일 번역가 메모
+pub type UnitNotes = HashMap<String, Vec<String>>;
+
+pub fn is_valid_status(status: &str) -> bool {
+    SDL_STATUSES.contains(&status)
+}
+
+fn xml_error(e: impl std::fmt::Display) -> IteError {
+    IteError::InvalidOperation(format!("Invalid SDLXLIFF: {}", e))
+}
+
+fn attr(e: &BytesStart, name: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == name)
+        .and_then(|a| a.unescape_value().ok().map(|v| v.to_string()))
+}
+
+fn is_seg_mrk(e: &BytesStart) -> bool {
+    e.name().as_ref() == b"mrk" && attr(e, b"mtype").as_deref() == Some("seg")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    SegSource,
+    Target,
+}
+
+/// 읽는 중인 `<mrk mtype="seg">`
+struct Capture {
+    mid: String,
+    text: String,
+    /// 중첩된 `<mrk>` 깊이
+    depth: usize,
+    /// 이 mrk에서 이미 쓴 placeholder 위치 (같은 태그가 반복되면 다음 토큰 사용)
+    used: Vec<usize>,
+}
+
+#[derive(Default)]
+struct UnitState {
+    id: String,
+    translatable: bool,
+    sources: Vec<(String, String)>,
+    targets: HashMap<String, String>,
+    defs: HashMap<String, (Option<String>, bool)>,
+    placeholders: HashMap<String, Vec<Placeholder>>,
+}
+
+/// 인라인 태그 XML → 토큰 (원문에서 본 태그면 같은 토큰 재사용)
+fn tag_token(placeholders: &mut Vec<Placeholder>, used: &mut Vec<usize>, original: String) -> String {
+    if let Some(i) = (0..placeholders.len()).find(|i| placeholders[*i].original == original && !used.contains(i)) {
+        used.push(i);
+        return placeholders[i].token.clone();
+    }
+    let token = make_token(TAG_TOKEN_PREFIX, placeholders.len() + 1);
+    placeholders.push(Placeholder {
+        token: token.clone(),
+        original,
+    });
+    used.push(placeholders.len() - 1);
+    token
+}
+
+/// SDLXLIFF 파싱 (translate="no" trans-unit은 제외)
+pub fn parse_sdlxliff(xml: &str) -> Result<SdlXliffFile, IteError> {
+    let mut reader = Reader::from_str(xml);
+    let mut file = SdlXliffFile::default();
+    let mut unit: Option<UnitState> = None;
+    let mut section: Option<Section> = None;
+    let mut capture: Option<Capture> = None;
+
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            xml_error(format!("{} (position {})", e, reader.buffer_position()))
+        })?;
+
+        if let (Some(cap), Some(u)) = (capture.as_mut(), unit.as_mut()) {
+            let placeholders = u.placeholders.entry(cap.mid.clone()).or_default();
+            match event {
+                Event::Start(e) => {
+                    if e.name().as_ref() == b"mrk" {
+                        cap.depth += 1;
+                    }
+                    let original = format!("<{}>", String::from_utf8_lossy(&e));
+                    cap.text.push_str(&tag_token(placeholders, &mut cap.used, original));
+                }
+                Event::Empty(e) => {
+                    let original = format!("<{}/>", String::from_utf8_lossy(&e));
+                    cap.text.push_str(&tag_token(placeholders, &mut cap.used, original));
+                }
+                Event::End(e) => {
+                    if e.name().as_ref() == b"mrk" && cap.depth == 0 {
+                        let done = capture.take().expect("capture in progress");
+                        match section {
+                            Some(Section::SegSource) => u.sources.push((done.mid, done.text)),
+                            Some(Section::Target) => {
+                                u.targets.insert(done.mid, done.text);
+                            }
+                            None => {}
+                        }
+                        continue;
+                    }
+                    if e.name().as_ref() == b"mrk" {
+                        cap.depth -= 1;
+                    }
+                    let original = format!("</{}>", String::from_utf8_lossy(&e));
+                    cap.text.push_str(&tag_token(placeholders, &mut cap.used, original));
+                }
+                Event::Text(t) => cap.text.push_str(&t.unescape().map_err(xml_error)?),
+                Event::CData(t) => cap.text.push_str(&String::from_utf8_lossy(&t)),
+                Event::Eof => return Err(xml_error("unexpected end of file")),
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref() == b"sdl:seg" => {
+                if let (Some(u), Some(id)) = (unit.as_mut(), attr(&e, b"id")) {
+                    let locked = attr(&e, b"locked").as_deref() == Some("true");
+                    u.defs.insert(id, (attr(&e, b"conf"), locked));
+                }
+            }
+            Event::Start(e) => match e.name().as_ref() {
+                b"file" => {
+                    file.original = file.original.take().or_else(|| attr(&e, b"original"));
+                    file.source_lang = file.source_lang.take().or_else(|| attr(&e, b"source-language"));
+                    file.target_lang = file.target_lang.take().or_else(|| attr(&e, b"target-language"));
+                }
+                b"trans-unit" => {
+                    unit = Some(UnitState {
+                        id: attr(&e, b"id").unwrap_or_default(),
+                        translatable: attr(&e, b"translate").as_deref() != Some("no"),
+                        ..Default::default()
+                    });
+                }
+                b"seg-source" if unit.is_some() => section = Some(Section::SegSource),
+                b"target" if unit.is_some() => section = Some(Section::Target),
+                b"mrk" if section.is_some() && is_seg_mrk(&e) => {
+                    capture = Some(Capture {
+                        mid: attr(&e, b"mid").unwrap_or_default(),
+                        text: String::new(),
+                        depth: 0,
+                        used: Vec::new(),
+                    });
+                }
+                _ => {}
+            },
+            Event::End(e) => match e.name().as_ref() {
+                b"seg-source" | b"target" => section = None,
+                b"trans-unit" => {
+                    let Some(mut u) = unit.take() else { continue };
+                    if !u.translatable {
+                        continue;
+                    }
+                    for (mid, source) in std::mem::take(&mut u.sources) {
+                        let (status, locked) = u.defs.remove(&mid).unwrap_or((None, false));
+                        file.segments.push(SdlSegment {
+                            unit_id: u.id.clone(),
+                            target: u.targets.remove(&mid).unwrap_or_default(),
+                            placeholders: u.placeholders.remove(&mid).unwrap_or_default(),
+                            mid,
+                            source,
+                            status,
+                            locked,
+                        });
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if file.segments.is_empty() && file.source_lang.is_none() {
+        return Err(xml_error("no <file> element found"));
+    }
+    Ok(file)
+}
+
+/// 번역문 평문(토큰 포함) → `<mrk>` 내부 XML
+/// - 반환: (XML, 번역문에서 사라진 토큰)
+pub fn target_to_xml(text: &str, placeholders: &[Placeholder]) -> (String, Vec<String>) {
+    let escaped = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    let restored = restore_placeholders(&escaped, placeholders);
+    (restored.text, restored.missing_tokens)
+}
+
+/// `<target>` 안의 seg mrk 내용을 바꾸며 이벤트를 씀
+struct MrkReplacer<'u> {
+    updates: &'u SegmentUpdates,
+    /// 갱신이 없는 mrk도 비울지 (`<seg-source>`를 본떠 `<target>`을 만들 때)
+    blank_unmatched: bool,
+    /// 건너뛰는 중인 mrk 내부 깊이
+    skipping: Option<usize>,
+}
+
+impl MrkReplacer<'_> {
+    fn feed(&mut self, writer: &mut Writer<Vec<u8>>, unit_id: &str, event: Event) -> Result<(), IteError> {
+        if let Some(depth) = self.skipping {
+            match &event {
+                Event::Start(e) if e.name().as_ref() == b"mrk" => self.skipping = Some(depth + 1),
+                Event::End(e) if e.name().as_ref() == b"mrk" => {
+                    if depth == 0 {
+                        self.skipping = None;
+                        writer.write_event(event).map_err(xml_error)?;
+                    } else {
+                        self.skipping = Some(depth - 1);
+                    }
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let update = match &event {
+            Event::Start(e) | Event::Empty(e) if is_seg_mrk(e) => {
+                let key = (unit_id.to_string(), attr(e, b"mid").unwrap_or_default());
+                Some(self.updates.get(&key))
+            }
+            _ => None,
+        };
+        match (event, update) {
+            (Event::Start(e), Some(Some(u))) => {
+                writer.write_event(Event::Start(e)).map_err(xml_error)?;
+                writer.get_mut().extend_from_slice(u.target_xml.as_bytes());
+                self.skipping = Some(0);
+            }
+            (Event::Start(e), Some(None)) if self.blank_unmatched => {
+                writer.write_event(Event::Start(e)).map_err(xml_error)?;
+                self.skipping = Some(0);
+            }
+            (Event::Empty(e), Some(Some(u))) => {
+                writer.write_event(Event::Start(e)).map_err(xml_error)?;
+                writer.get_mut().extend_from_slice(u.target_xml.as_bytes());
+                writer.write_event(Event::End(BytesEnd::new("mrk"))).map_err(xml_error)?;
+            }
+            (event, _) => writer.write_event(event).map_err(xml_error)?,
+        }
+        Ok(())
+    }
+}
+
+/// `conf`를 바꾼 `<sdl:seg>` 시작 태그
+fn with_status(e: &BytesStart, status: &str) -> BytesStart<'static> {
+    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+    let mut out = BytesStart::new(name);
+    for a in e.attributes().flatten().filter(|a| a.key.as_ref() != b"conf") {
+        out.push_attribute((a.key.as_ref(), a.value.as_ref()));
+    }
+    out.push_attribute(("conf", status));
+    out
+}
+
+/// `<target>`이 있는 trans-unit id
+fn units_with_target(xml: &str) -> Result<HashSet<String>, IteError> {
+    let mut reader = Reader::from_str(xml);
+    let mut out = HashSet::new();
+    let mut unit: Option<String> = None;
+    loop {
+        match reader.read_event().map_err(xml_error)? {
+            Event::Start(e) if e.name().as_ref() == b"trans-unit" => unit = attr(&e, b"id"),
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref() == b"target" => {
+                if let Some(id) = unit.clone() {
+                    out.insert(id);
+                }
+            }
+            Event::End(e) if e.name().as_ref() == b"trans-unit" => unit = None,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(out)
+}
+
+/// trans-unit 메모를 `<note from="translator">`로 씀
+fn write_notes(writer: &mut Writer<Vec<u8>>, notes: &[String]) -> Result<(), IteError> {
+    for note in notes {
+        let mut start = BytesStart::new("note");
+        start.push_attribute(("from", "translator"));
+        writer.write_event(Event::Start(start)).map_err(xml_error)?;
+        writer.write_event(Event::Text(BytesText::new(note))).map_err(xml_error)?;
+        writer.write_event(Event::End(BytesEnd::new("note"))).map_err(xml_error)?;
+    }
+    Ok(())
+}
+
+/// 원본 SDLXLIFF에 번역문/상태/메모 반영
+pub fn apply_updates(xml: &str, updates: &SegmentUpdates, notes: &UnitNotes) -> Result<String, IteError> {
+    let has_target = units_with_target(xml)?;
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len() + xml.len() / 4));
+
+    let mut unit_id = String::new();
+    let mut notes_written = false;
+    let mut section: Option<Section> = None;
+    let mut seg_source: Vec<Event<'static>> = Vec::new();
+    let mut replacer = MrkReplacer {
+        updates,
+        blank_unmatched: false,
+        skipping: None,
+    };
+
+    loop {
+        let event = reader.read_event().map_err(xml_error)?;
+        match (&event, section) {
+            (Event::Eof, _) => break,
+            (Event::End(e), Some(Section::SegSource)) if e.name().as_ref() == b"seg-source" => {
+                section = None;
+                writer.write_event(event).map_err(xml_error)?;
+                // <target>이 없으면 seg-source 구조를 본떠 새로 만듦
+                if !has_target.contains(&unit_id) {
+                    let mut synth = MrkReplacer {
+                        updates,
+                        blank_unmatched: true,
+                        skipping: None,
+                    };
+                    writer
+                        .write_event(Event::Start(BytesStart::new("target")))
+                        .map_err(xml_error)?;
+                    for ev in seg_source.drain(..) {
+                        synth.feed(&mut writer, &unit_id, ev)?;
+                    }
+                    writer
+                        .write_event(Event::End(BytesEnd::new("target")))
+                        .map_err(xml_error)?;
+                }
+                seg_source.clear();
+            }
+            (_, Some(Section::SegSource)) => {
+                seg_source.push(event.clone().into_owned());
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            (Event::End(e), Some(Section::Target)) if e.name().as_ref() == b"target" && replacer.skipping.is_none() => {
+                section = None;
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            (_, Some(Section::Target)) => replacer.feed(&mut writer, &unit_id, event)?,
+            (Event::Start(e), None) if e.name().as_ref() == b"trans-unit" => {
+                unit_id = attr(e, b"id").unwrap_or_default();
+                notes_written = false;
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            // <note>는 XLIFF 요소이므로 확장 요소(sdl:seg-defs)보다 앞에 와야 함
+            (Event::Start(e) | Event::Empty(e), None) if e.name().as_ref() == b"sdl:seg-defs" && !notes_written => {
+                write_notes(&mut writer, notes.get(&unit_id).map_or(&[], Vec::as_slice))?;
+                notes_written = true;
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            (Event::End(e), None) if e.name().as_ref() == b"trans-unit" => {
+                if !notes_written {
+                    write_notes(&mut writer, notes.get(&unit_id).map_or(&[], Vec::as_slice))?;
+                }
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            (Event::Start(e), None) if e.name().as_ref() == b"seg-source" => {
+                section = Some(Section::SegSource);
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            (Event::Start(e), None) if e.name().as_ref() == b"target" => {
+                section = Some(Section::Target);
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            (Event::Start(e) | Event::Empty(e), None) if e.name().as_ref() == b"sdl:seg" => {
+                let key = (unit_id.clone(), attr(e, b"id").unwrap_or_default());
+                match updates.get(&key).and_then(|u| u.status.as_deref()) {
+                    Some(status) => {
+                        let start = with_status(e, status);
+                        let rewritten = if matches!(event, Event::Empty(_)) {
+                            Event::Empty(start)
+                        } else {
+                            Event::Start(start)
+                        };
+                        writer.write_event(rewritten).map_err(xml_error)?;
+                    }
+                    None => writer.write_event(event).map_err(xml_error)?,
+                }
+            }
+            _ => writer.write_event(event).map_err(xml_error)?,
+        }
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(xml_error)
+}
+
+/// 패키지 안 경로가 안전한 상대 경로인지 (`..`/절대 경로 거부), 구분자는 `/`로 통일
+pub fn safe_relative_name(name: &str) -> Option<String> {
+    let normalized = name.replace('\\', "/");
+    let path = Path::new(&normalized);
+    let ok = path.components().all(|c| matches!(c, Component::Normal(_)));
+    (ok && !normalized.is_empty()).then_some(normalized)
+}
+
+/// `.sdlxliff` 파일 또는 `.sdlppx`/`.sdlrpx` 패키지 안의 모든 `.sdlxliff` 읽기
+pub fn read_documents(path: &Path) -> Result<Vec<XliffDocument>, IteError> {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "sdlxliff" => {
+            let file_name = file_name_nfc(path)
+                .unwrap_or_else(|| "document.sdlxliff".to_string());
+            Ok(vec![XliffDocument {
+                file_name,
+                format: SDLXLIFF_FORMAT.to_string(),
+                content: decode_bytes(&std::fs::read(path)?).text,
+            }])
+        }
+        "sdlppx" | "sdlrpx" => {
+            let mut archive = zip::ZipArchive::new(std::fs::File::open(path)?)
+                .map_err(|e| IteError::InvalidOperation(format!("Invalid Trados package: {}", e)))?;
+            let mut out = Vec::new();
+            for i in 0..archive.len() {
+                let mut entry = archive
+                    .by_index(i)
+                    .map_err(|e| IteError::InvalidOperation(format!("Invalid Trados package: {}", e)))?;
+                if !entry.is_file() || !entry.name().to_lowercase().ends_with(".sdlxliff") {
+                    continue;
+                }
+                let Some(file_name) = safe_relative_name(entry.name()) else {
+                    eprintln!("[Interop] Skipping unsafe package entry: {}", entry.name());
+                    continue;
+                };
+                let mut bytes = Vec::new();
+                entry.read_to_end(&mut bytes)?;
+                out.push(XliffDocument {
+                    file_name,
+                    format: SDLXLIFF_FORMAT.to_string(),
+                    content: decode_bytes(&bytes).text,
+                });
+            }
+            out.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+            if out.is_empty() {
+                return Err(IteError::InvalidOperation(
+                    "Trados package contains no .sdlxliff files".to_string(),
+                ));
+            }
+            Ok(out)
+        }
+        _ => Err(IteError::InvalidOperation(format!(
+            "Unsupported Trados file: {}",
+            path.display()
+        ))),
+    }
+}
+
+/// 읽어 온 SDLXLIFF 파일들 → ITE 프로젝트 (mrk 세그먼트 1개 = 세그먼트 1개)
+/// - 반환: (프로젝트, 세그먼트 연결 정보)
+pub fn build_project(
+    title: String,
+    files: &[(XliffDocument, SdlXliffFile)],
+    now: i64,
+) -> (IteProject, Vec<XliffSegmentRef>) {
+    let mut builder = ProjectBuilder::new(now);
+    let mut refs = Vec::new();
+    for (doc, file) in files {
+        let tag = file_tag(file.original.as_deref().unwrap_or(&doc.file_name));
+        for segment in &file.segments {
+            let segment_id = builder.push(&segment.source, &segment.target, &tag);
+            refs.push(XliffSegmentRef {
+                segment_id,
+                file_name: doc.file_name.clone(),
+                unit_id: segment.unit_id.clone(),
+                mid: segment.mid.clone(),
+                status: segment.status.clone(),
+                locked: segment.locked,
+                placeholders: segment.placeholders.clone(),
+            });
+        }
+    }
+
+    let langs = files.iter().find_map(|(_, f)| Some((f.source_lang.as_deref()?, f.target_lang.as_deref()?)));
+    let description = match langs {
+        Some((source, target)) => format!("Imported from Trados ({} → {})", source, target),
+        None => "Imported from Trados".to_string(),
+    };
+    (builder.finish(title, description, langs.map(|(s, _)| s), langs.map(|(_, t)| t)), refs)
+}
+
+/// 내보내기 결과
+#[derive(Debug, Clone, Default)]
+pub struct ExportedDocuments {
+    /// (파일 이름, XML)
+    pub files: Vec<(String, String)>,
+    /// 번역문을 써 넣은 세그먼트 수
+    pub updated_segments: usize,
+    /// 인라인 태그가 빠진 세그먼트 번호 (문서 순서 기준 1부터)
+    pub missing_tag_segments: Vec<usize>,
+}
+
+/// 프로젝트 번역문을 원본 SDLXLIFF에 반영
+/// - 잠긴 세그먼트와 번역문이 빈 세그먼트는 원본 그대로 둡니다.
+/// - 상태가 없거나 Draft인 세그먼트는 번역문을 쓰면서 Translated로 바꿉니다.
+/// - `notes`(segment_id → 메모)는 잠김/번역 여부와 관계없이 해당 trans-unit에 붙입니다.
+pub fn export_documents(
+    project: &IteProject,
+    documents: &[XliffDocument],
+    refs: &[XliffSegmentRef],
+    notes: &HashMap<String, String>,
+) -> Result<ExportedDocuments, IteError> {
+    let mut ordered: Vec<_> = project.segments.iter().collect();
+    ordered.sort_by_key(|s| s.order);
+    let positions: HashMap<&str, (usize, &crate::models::SegmentGroup)> = ordered
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.group_id.as_str(), (i + 1, *s)))
+        .collect();
+
+    let mut result = ExportedDocuments::default();
+    let mut notes_by_file: HashMap<&str, UnitNotes> = HashMap::new();
+    for r in refs {
+        if let Some(note) = notes.get(&r.segment_id) {
+            notes_by_file
+                .entry(r.file_name.as_str())
+                .or_default()
+                .entry(r.unit_id.clone())
+                .or_default()
+                .push(note.clone());
+        }
+    }
+    let mut updates_by_file: HashMap<&str, SegmentUpdates> = HashMap::new();
+    for r in refs.iter().filter(|r| !r.locked) {
+        let Some((number, segment)) = positions.get(r.segment_id.as_str()) else {
+            continue;
+        };
+        let target = segment_target_text(project, segment);
+        if target.is_empty() {
+            continue;
+        }
+
+        let (target_xml, missing) = target_to_xml(&target, &r.placeholders);
+        if !missing.is_empty() {
+            result.missing_tag_segments.push(*number);
+        }
+        let status = match r.status.as_deref() {
+            None | Some("Draft") => Some("Translated".to_string()),
+            Some(_) => None,
+        };
+        updates_by_file
+            .entry(r.file_name.as_str())
+            .or_default()
+            .insert((r.unit_id.clone(), r.mid.clone()), SegmentUpdate { target_xml, status });
+        result.updated_segments += 1;
+    }
+    result.missing_tag_segments.sort_unstable();
+
+    let (no_updates, no_notes) = (SegmentUpdates::new(), UnitNotes::new());
+    for doc in documents {
+        let updates = updates_by_file.get(doc.file_name.as_str()).unwrap_or(&no_updates);
+        let notes = notes_by_file.get(doc.file_name.as_str()).unwrap_or(&no_notes);
+        result
+            .files
+            .push((doc.file_name.clone(), apply_updates(&doc.content, updates, notes)?));
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<xliff xmlns:sdl="http://sdl.com/FileTypes/SdlXliff/1.0" xmlns="urn:oasis:names:tc:xliff:document:1.2" version="1.2" sdl:version="1.0">
+<file original="C:\docs\manual.docx" datatype="x-sdlfilterframework2" source-language="en-US" target-language="ko-KR">
+<header/>
+<body>
+<trans-unit id="u0" translate="no"><source><x id="0"/></source></trans-unit>
+<trans-unit id="u1"><source>Press <g id="5">Save</g>. Then exit &amp; relax.</source><seg-source><mrk mtype="seg" mid="1">Press <g id="5">Save</g>.</mrk> <mrk mtype="seg" mid="2">Then exit &amp; relax.</mrk></seg-source><target><mrk mtype="seg" mid="1"><g id="5">저장</g>을 누르세요.</mrk> <mrk mtype="seg" mid="2"/></target><sdl:seg-defs><sdl:seg id="1" conf="ApprovedTranslation" origin="tm" percent="100"/><sdl:seg id="2"/></sdl:seg-defs></trans-unit>
+<trans-unit id="u2"><source>Logo<x id="7"/></source><seg-source><mrk mtype="seg" mid="3">Logo<x id="7"/></mrk></seg-source><sdl:seg-defs><sdl:seg id="3" locked="true" conf="Draft"/></sdl:seg-defs></trans-unit>
+</body>
+</file>
+</xliff>"#;
+
+    #[test]
+    fn test_parse_segments_statuses_and_tags() {
+        let file = parse_sdlxliff(SAMPLE).unwrap();
+        assert_eq!(file.source_lang.as_deref(), Some("en-US"));
+        assert_eq!(file.target_lang.as_deref(), Some("ko-KR"));
+        assert_eq!(file.segments.len(), 3);
+
+        let s1 = &file.segments[0];
+        assert_eq!(s1.source, "Press ⟦X1⟧Save⟦X2⟧.");
+        assert_eq!(s1.target, "⟦X1⟧저장⟦X2⟧을 누르세요.");
+        assert_eq!(s1.placeholders[0].original, r#"<g id="5">"#);
+        assert_eq!(s1.status.as_deref(), Some("ApprovedTranslation"));
+
+        let s2 = &file.segments[1];
+        assert_eq!(s2.source, "Then exit & relax.");
+        assert_eq!(s2.target, "");
+        assert_eq!(s2.status, None);
+
+        let s3 = &file.segments[2];
+        assert_eq!((s3.unit_id.as_str(), s3.mid.as_str()), ("u2", "3"));
+        assert!(s3.locked);
+    }
+
+    #[test]
+    fn test_apply_updates_roundtrip() {
+        let file = parse_sdlxliff(SAMPLE).unwrap();
+        let mut updates = SegmentUpdates::new();
+        let (xml, missing) = target_to_xml("이후 종료 & 휴식.", &file.segments[1].placeholders);
+        assert!(missing.is_empty());
+        updates.insert(
+            ("u1".to_string(), "2".to_string()),
+            SegmentUpdate {
+                target_xml: xml,
+                status: Some("Translated".to_string()),
+            },
+        );
+        let (xml, missing) = target_to_xml("로고⟦X1⟧", &file.segments[2].placeholders);
+        assert!(missing.is_empty());
+        updates.insert(
+            ("u2".to_string(), "3".to_string()),
+            SegmentUpdate {
+                target_xml: xml,
+                status: None,
+            },
+        );
+
+        let out = apply_updates(SAMPLE, &updates, &UnitNotes::new()).unwrap();
+        assert!(out.contains(r#"<mrk mtype="seg" mid="2">이후 종료 &amp; 휴식.</mrk>"#));
+        assert!(out.contains(r#"<sdl:seg id="2" conf="Translated"/>"#));
+        // 갱신하지 않은 세그먼트와 상태는 그대로
+        assert!(out.contains(r#"<mrk mtype="seg" mid="1"><g id="5">저장</g>을 누르세요.</mrk>"#));
+        assert!(out.contains(r#"conf="ApprovedTranslation""#));
+        // target이 없던 trans-unit은 seg-source를 본떠 생성
+        assert!(out.contains(r#"</seg-source><target><mrk mtype="seg" mid="3">로고<x id="7"/></mrk></target>"#));
+
+        let reparsed = parse_sdlxliff(&out).unwrap();
+        assert_eq!(reparsed.segments[1].target, "이후 종료 & 휴식.");
+        assert_eq!(reparsed.segments[1].status.as_deref(), Some("Translated"));
+        assert_eq!(reparsed.segments[2].target, "로고⟦X1⟧");
+    }
+
+    #[test]
+    fn test_build_and_export_project() {
+        let doc = XliffDocument {
+            file_name: "ko-KR/manual.docx.sdlxliff".to_string(),
+            format: SDLXLIFF_FORMAT.to_string(),
+            content: SAMPLE.to_string(),
+        };
+        let file = parse_sdlxliff(SAMPLE).unwrap();
+        let (mut project, refs) = build_project("manual".to_string(), &[(doc.clone(), file)], 1);
+        assert_eq!(project.segments.len(), 3);
+        assert_eq!(project.metadata.target_language.as_deref(), Some("한국어"));
+        assert_eq!(refs[0].status.as_deref(), Some("ApprovedTranslation"));
+        let tags = &project.blocks[&project.segments[0].source_ids[0]].metadata.tags;
+        assert_eq!(tags, &vec!["manual.docx".to_string()]);
+
+        // 2번 세그먼트 번역, 3번(잠김)도 수정했지만 내보내지 않음
+        let target_of = |p: &IteProject, i: usize| p.segments[i].target_ids[0].clone();
+        let t2 = target_of(&project, 1);
+        project.blocks.get_mut(&t2).unwrap().content = "<p>이후 종료 &amp; 휴식.</p>".to_string();
+        let t3 = target_of(&project, 2);
+        project.blocks.get_mut(&t3).unwrap().content = "<p>로고</p>".to_string();
+
+        // 잠긴 세그먼트의 메모도 내보냄
+        let notes = HashMap::from([(project.segments[2].group_id.clone(), "Keep <logo> as is".to_string())]);
+        let exported = export_documents(&project, &[doc], &refs, &notes).unwrap();
+        // 1번(기존 번역) + 2번
+        assert_eq!(exported.updated_segments, 2);
+        assert!(exported.missing_tag_segments.is_empty());
+        let reparsed = parse_sdlxliff(&exported.files[0].1).unwrap();
+        assert_eq!(reparsed.segments[0].target, "⟦X1⟧저장⟦X2⟧을 누르세요.");
+        assert_eq!(reparsed.segments[0].status.as_deref(), Some("ApprovedTranslation"));
+        assert_eq!(reparsed.segments[1].target, "이후 종료 & 휴식.");
+        assert_eq!(reparsed.segments[1].status.as_deref(), Some("Translated"));
+        assert_eq!(reparsed.segments[2].target, "");
+        assert!(exported.files[0]
+            .1
+            .contains(r#"<note from="translator">Keep &lt;logo&gt; as is</note><sdl:seg-defs>"#));
+    }
+
+    #[test]
+    fn test_safe_relative_name() {
+        assert_eq!(safe_relative_name("ko-KR\\a.sdlxliff").as_deref(), Some("ko-KR/a.sdlxliff"));
+        assert_eq!(safe_relative_name("../a.sdlxliff"), None);
+        assert_eq!(safe_relative_name("/etc/a.sdlxliff"), None);
+    }
+}
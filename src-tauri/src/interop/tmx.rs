@@ -0,0 +1,161 @@
+//! TMX Reader
+//!
+//! TMX(1.4) 번역 메모리에서 지정한 언어쌍의 번역 단위를 평문으로 읽습니다.
+//! - `<seg>` 안의 서식 태그(`<hi>` 등)는 글자만 남기고, 원본 코드(`<bpt>`, `<ept>`, `<ph>`, `<it>`, `<ut>`)는 버립니다.
+//! - `<prop type="file">`/`<prop type="id">`가 있는 단위는 OmegaT의 대체 번역(alternative)으로 표시합니다.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+
+use crate::error::IteError;
+
+/// TMX 번역 단위
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TmxUnit {
+    pub source: String,
+    pub target: String,
+    /// 특정 위치에만 쓰이는 대체 번역인지 (OmegaT)
+    pub alternative: bool,
+}
+
+/// 원본 코드 요소 (내용을 번역문에 포함하지 않음)
+const NATIVE_CODE_ELEMENTS: &[&[u8]] = &[b"bpt", b"ept", b"ph", b"it", b"ut"];
+
+/// 언어 코드 비교 (대소문자 무시, 정확히 같지 않으면 기본 언어만 비교: "EN-US" ~ "en")
+pub fn language_matches(a: &str, b: &str) -> bool {
+    let primary = |s: &str| s.split(['-', '_']).next().unwrap_or(s).to_ascii_lowercase();
+    a.eq_ignore_ascii_case(b) || primary(a) == primary(b)
+}
+
+fn attr(e: &BytesStart, names: &[&[u8]]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| names.contains(&a.key.as_ref()))
+        .and_then(|a| a.unescape_value().ok().map(|v| v.to_string()))
+}
+
+/// TMX 문자열에서 source_lang → target_lang 단위 추출 (한쪽 언어가 비어 있는 단위는 제외)
+pub fn parse_tmx(xml: &str, source_lang: &str, target_lang: &str) -> Result<Vec<TmxUnit>, IteError> {
+    let mut reader = Reader::from_str(xml);
+    let mut out = Vec::new();
+
+    // 현재 <tu>: (lang, seg) 목록과 대체 번역 여부
+    let mut tuvs: Vec<(String, String)> = Vec::new();
+    let mut alternative = false;
+    let mut lang: Option<String> = None;
+    let mut seg: Option<String> = None;
+    let mut skip_depth = 0usize;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.name().as_ref() {
+                b"tu" => {
+                    tuvs.clear();
+                    alternative = false;
+                }
+                b"tuv" => lang = attr(&e, &[b"xml:lang", b"lang"]),
+                b"seg" => seg = Some(String::new()),
+                b"prop" => {
+                    if matches!(attr(&e, &[b"type"]).as_deref(), Some("file") | Some("id")) {
+                        alternative = true;
+                    }
+                }
+                name if seg.is_some() && NATIVE_CODE_ELEMENTS.contains(&name) => skip_depth += 1,
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.name().as_ref() {
+                b"seg" => {
+                    if let (Some(l), Some(s)) = (lang.clone(), seg.take()) {
+                        tuvs.push((l, s));
+                    }
+                    skip_depth = 0;
+                }
+                b"tuv" => lang = None,
+                b"tu" => {
+                    let find = |want: &str| {
+                        tuvs.iter()
+                            .find(|(l, _)| l.eq_ignore_ascii_case(want))
+                            .or_else(|| tuvs.iter().find(|(l, _)| language_matches(l, want)))
+                            .map(|(_, s)| s.trim().to_string())
+                    };
+                    if let (Some(source), Some(target)) = (find(source_lang), find(target_lang)) {
+                        if !source.is_empty() && !target.is_empty() {
+                            out.push(TmxUnit {
+                                source,
+                                target,
+                                alternative,
+                            });
+                        }
+                    }
+                }
+                name if seg.is_some() && NATIVE_CODE_ELEMENTS.contains(&name) => {
+                    skip_depth = skip_depth.saturating_sub(1)
+                }
+                _ => {}
+            },
+            Ok(Event::Text(t)) => {
+                if let Some(s) = seg.as_mut() {
+                    if skip_depth == 0 {
+                        let text = t
+                            .unescape()
+                            .map_err(|e| IteError::InvalidOperation(format!("Invalid TMX text: {}", e)))?;
+                        s.push_str(&text);
+                    }
+                }
+            }
+            Ok(Event::CData(t)) => {
+                if let Some(s) = seg.as_mut() {
+                    if skip_depth == 0 {
+                        s.push_str(&String::from_utf8_lossy(&t));
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(IteError::InvalidOperation(format!(
+                    "Invalid TMX at position {}: {}",
+                    reader.buffer_position(),
+                    e
+                )))
+            }
+            _ => {}
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tmx_pairs_and_inline_codes() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tmx version="1.4"><header srclang="EN-US"/><body>
+  <tu>
+    <tuv xml:lang="EN-US"><seg>Press <bpt i="1">&lt;b&gt;</bpt>Save<ept i="1">&lt;/b&gt;</ept> &amp; exit.</seg></tuv>
+    <tuv xml:lang="KO"><seg>저장 &amp; 종료를 누르세요.</seg></tuv>
+  </tu>
+  <tu>
+    <prop type="file">a.txt</prop>
+    <tuv lang="en"><seg>Hello</seg></tuv>
+    <tuv lang="ko-KR"><seg>안녕</seg></tuv>
+  </tu>
+  <tu>
+    <tuv xml:lang="EN-US"><seg>Untranslated</seg></tuv>
+  </tu>
+</body></tmx>"#;
+        let units = parse_tmx(xml, "en-US", "ko").unwrap();
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].source, "Press Save & exit.");
+        assert_eq!(units[0].target, "저장 & 종료를 누르세요.");
+        assert!(!units[0].alternative);
+        assert_eq!(units[1].target, "안녕");
+        assert!(units[1].alternative);
+    }
+
+    #[test]
+    fn test_parse_tmx_rejects_malformed_xml() {
+        assert!(parse_tmx("<tmx><body><tu></body></tmx>", "en", "ko").is_err());
+    }
+}
@@ -3,138 +3,64 @@
 //!
 //! Rust 백엔드 라이브러리로, 파일 I/O, SQLite 관리, 시스템 연동을 담당합니다.
 
+pub mod cloud;
 pub mod commands;
+pub mod crash;
 pub mod db;
+pub mod env;
 pub mod error;
+pub mod export;
+pub mod http;
+pub mod i18n;
+pub mod interop;
 pub mod mcp;
 pub mod models;
+pub mod network;
 pub mod notion;
+pub mod oauth;
+pub mod package;
+pub mod qa;
 pub mod secrets;
+pub mod suggest;
+pub mod sync;
+pub mod text;
+pub mod tms;
 pub mod utils;
 
-use std::path::{Path, PathBuf};
-use tauri::menu::{MenuBuilder, MenuItemBuilder, SubmenuBuilder};
 use tauri::Manager;
 
-fn is_valid_env_key(key: &str) -> bool {
-    if key.is_empty() {
-        return false;
-    }
-    // 관례적으로 ENV 키는 A-Z0-9_ 로 제한 (VITE_*, BRAVE_* 등)
-    key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
-}
-
-fn try_load_env_lenient(path: &Path) -> std::io::Result<usize> {
-    let text = std::fs::read_to_string(path)?;
-    let mut loaded = 0usize;
-
-    for raw_line in text.lines() {
-        let line = raw_line.trim();
-        if line.is_empty() {
-            continue;
-        }
-        // dotenvy는 markdown 같은 "KEY=VALUE" 외 라인에서 실패할 수 있으므로,
-        // lenient 모드에서는 주석/코드펜스/설명 라인을 최대한 무시합니다.
-        if line.starts_with('#') || line.starts_with("```") {
-            continue;
-        }
-
-        let line = line.strip_prefix("export ").unwrap_or(line).trim();
-        let Some((k, v)) = line.split_once('=') else {
-            continue;
-        };
-        let key = k.trim();
-        if !is_valid_env_key(key) {
-            continue;
-        }
-        // 이미 설정된 값이 "비어있지 않으면" 덮어쓰지 않음.
-        // (특정 런처/환경에서 빈 문자열로 미리 주입되는 케이스를 방지)
-        if let Ok(existing) = std::env::var(key) {
-            if !existing.trim().is_empty() {
-                continue;
-            }
-        }
-
-        let mut value = v.trim().to_string();
-        // 간단한 quote 제거 ("..." / '...')
-        if (value.starts_with('"') && value.ends_with('"')) || (value.starts_with('\'') && value.ends_with('\'')) {
-            value = value[1..value.len().saturating_sub(1)].to_string();
-        }
-
-        std::env::set_var(key, value);
-        loaded += 1;
-    }
-
-    Ok(loaded)
-}
-
-fn find_upwards(start: PathBuf, filename: &str, max_hops: usize) -> Option<PathBuf> {
-    let mut cur = start;
-    for _ in 0..=max_hops {
-        let candidate = cur.join(filename);
-        if candidate.exists() {
-            return Some(candidate);
-        }
-        if !cur.pop() {
-            break;
-        }
-    }
-    None
-}
-
-fn load_env_for_tauri_dev() {
-    // 1) 가장 단순한 케이스: CWD 기준 (.env.local)
-    if dotenvy::from_filename(".env.local").is_ok() {
-        return;
-    }
-
-    // 2) CWD가 프로젝트 루트가 아닐 수 있으니, 상위로 올라가며 탐색
-    let mut candidates: Vec<PathBuf> = vec![];
-    if let Ok(cwd) = std::env::current_dir() {
-        if let Some(p) = find_upwards(cwd, ".env.local", 6) {
-            candidates.push(p);
-        }
-    }
-
-    // 3) 실행 파일 위치 기준으로도 탐색 (cargo run/tauri dev 환경 대응)
-    if let Ok(exe) = std::env::current_exe() {
-        if let Some(dir) = exe.parent() {
-            if let Some(p) = find_upwards(dir.to_path_buf(), ".env.local", 8) {
-                candidates.push(p);
-            }
-        }
-    }
-
-    // 후보 중 하나라도 성공하면 OK
-    for p in candidates {
-        // strict 파서 우선
-        if dotenvy::from_path(&p).is_ok() {
-            return;
-        }
-        // strict 파서가 실패하면(예: markdown 포함), lenient 로더로 보강
-        if let Ok(loaded) = try_load_env_lenient(&p) {
-            if loaded > 0 {
-                return;
-            }
-        }
-    }
-}
-
 /// Tauri 앱 실행
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // 단일 인스턴스: 이미 실행 중이면 새 프로세스의 딥 링크를 기존 창으로 넘김 (가장 먼저 등록)
+        .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+            // 실행 중에 .ite 파일을 더블클릭한 경우 (Windows/Linux)
+            let files = commands::deep_link::project_files_from_args(&args, std::path::Path::new(&cwd));
+            commands::deep_link::handle_opened_files(app, &files);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .setup(|app| {
-            // Dev 환경에서 .env.local 을 로드 (Brave Search API 등 비밀키는 프론트에 노출하지 않고 백엔드에서 사용)
-            // - .env.local이 markdown(코드펜스 등)을 포함하면 dotenvy(strict)가 실패할 수 있어,
-            //   strict 실패 시 lenient(KEY=VALUE 라인만) 로더로 보강합니다.
+            // 프로필별 .env* 로드 (Brave Search API 등 비밀키는 프론트에 노출하지 않고 백엔드에서 사용)
             // - production에서는 파일이 없을 수 있으므로 실패해도 무시합니다.
-            load_env_for_tauri_dev();
-            let _ = dotenvy::dotenv();
+            env::load_env();
+
+            // 패닉 기록 (백트레이스 로그 + 진단용 요약)
+            match app.path().app_log_dir() {
+                Ok(dir) => crash::install(dir),
+                Err(e) => eprintln!("[startup] Failed to get app log dir: {}", e),
+            }
 
             // 데이터베이스 초기화
             let app_handle = app.handle();
@@ -143,29 +69,105 @@ pub fn run() {
                 .app_data_dir()
                 .map_err(|e| format!("Failed to get app data dir: {}", e))?;
 
-            let db_path = app_data_dir.join("ite.db");
+            let db_path = app_data_dir.join(db::DATABASE_FILE_NAME);
 
             // DB 디렉토리 생성
             if let Some(parent) = db_path.parent() {
                 std::fs::create_dir_all(parent)?;
             }
 
+            // SecretManager에 app_data_dir 설정 (Vault 경로용)
+            // 동기 실행: 프론트엔드의 initializeSecrets()보다 먼저 완료되어야 함
+            tauri::async_runtime::block_on(async {
+                secrets::SECRETS.set_app_data_dir(app_data_dir.clone()).await;
+            });
+
             // 데이터베이스 연결 및 초기화
-            let db = db::Database::new(&db_path)?;
+            // - 암호화된 DB면 SecretManager를 먼저 초기화해 키를 읽음 (Keychain 프롬프트 발생 가능)
+            let db = if db::is_encrypted_database_file(&db_path) {
+                let key = tauri::async_runtime::block_on(async {
+                    secrets::SECRETS.initialize().await?;
+                    secrets::SECRETS.get(db::DATABASE_KEY_SECRET).await
+                })
+                .map_err(|e| format!("Failed to load database key: {}", e))?
+                .ok_or("Database is encrypted but no key is stored")?;
+                db::Database::open_encrypted(&db_path, &key)?
+            } else {
+                db::Database::new(&db_path)?
+            };
             db.initialize()?;
 
+            // 채팅 보관 정책이 설정된 프로젝트의 오래된 메시지 정리
+            match db.prune_chat_history(None) {
+                Ok(r) if r.deleted_messages > 0 => {
+                    eprintln!(
+                        "[startup] Pruned {} chat message(s) ({} bytes)",
+                        r.deleted_messages, r.reclaimed_bytes
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("[startup] Chat history pruning failed: {}", e),
+            }
+
+            // 오류 메시지 언어, 오프라인 모드, HTTP 프록시/타임아웃, MCP 캐시 (설정을 읽지 못하면 기본값 유지)
+            if let Ok(settings) = db.load_app_settings() {
+                i18n::apply_language(&settings.ui_language);
+                network::set_offline_mode(settings.offline_mode);
+                http::apply_settings(&settings.http);
+                mcp::cache::set_ttl_secs(settings.mcp_cache_ttl_secs);
+            }
+
             // 앱 상태로 데이터베이스 관리
             app.manage(db::DbState(std::sync::Mutex::new(db)));
+            app.manage(commands::window::WindowProjects::default());
+            app.manage(commands::deep_link::PendingDeepLinks::default());
+            app.manage(commands::updater::PendingUpdate::default());
+            app.manage(commands::capture::CaptureShortcut::default());
+            app.manage(commands::reports::TmAnalysisJobs::default());
+            app.manage(commands::maintenance::DatabaseOptimizeJob::default());
+            app.manage(commands::shutdown::ShutdownCoordinator::default());
+
+            // 딥 링크 (ite://): 프론트엔드가 준비되기 전이면 보관, 이후에는 이벤트로 전달
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+
+                // Linux/Windows 개발 빌드는 설치 과정이 없으므로 실행 시 스킴 등록
+                #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+                if let Err(e) = app.deep_link().register_all() {
+                    eprintln!("[DeepLink] Failed to register scheme: {}", e);
+                }
 
-            // SecretManager에 app_data_dir 설정 (Vault 경로용)
-            // 동기 실행: 프론트엔드의 initializeSecrets()보다 먼저 완료되어야 함
-            tauri::async_runtime::block_on(async {
-                secrets::SECRETS.set_app_data_dir(app_data_dir.clone()).await;
-            });
+                if let Ok(Some(urls)) = app.deep_link().get_current() {
+                    let urls: Vec<String> = urls.iter().map(|u| u.to_string()).collect();
+                    commands::deep_link::handle_deep_links(app.handle(), &urls);
+                }
+                let handle = app.handle().clone();
+                app.deep_link().on_open_url(move |event| {
+                    let urls: Vec<String> = event.urls().iter().map(|u| u.to_string()).collect();
+                    commands::deep_link::handle_deep_links(&handle, &urls);
+                });
+            }
+
+            // .ite 파일로 실행된 경우 (Windows/Linux 파일 연결은 실행 인자로 전달)
+            if let Ok(cwd) = std::env::current_dir() {
+                let args: Vec<String> = std::env::args().collect();
+                let files = commands::deep_link::project_files_from_args(&args, &cwd);
+                commands::deep_link::handle_opened_files(app.handle(), &files);
+            }
 
             // MCP 모듈에 AppHandle 설정 (상태 변경 이벤트 발송용)
             mcp::set_app_handle(app.handle().clone());
 
+            // 연결 상태 확인 (오프라인이면 네트워크 명령을 바로 실패시키고 작업을 대기열로)
+            network::spawn_connectivity_monitor(app.handle().clone());
+
+            // 전역 용어집 원격 동기화 / Notion 용어집 가져오기 스케줄 (설정에서 켠 경우에만 실행)
+            commands::sync::spawn_glossary_sync_scheduler(app.handle().clone());
+            commands::sync::spawn_notion_glossary_sync_scheduler(app.handle().clone());
+
+            // 클라우드 암호화 백업 스케줄 (설정에서 켠 경우에만 실행)
+            commands::cloud_backup::spawn_cloud_backup_scheduler(app.handle().clone());
+
             // 앱 시작 시 오래된 임시 이미지 파일 정리 (24시간 이상 경과된 파일)
             if let Ok(deleted) = commands::attachments::cleanup_temp_images() {
                 if deleted > 0 {
@@ -173,98 +175,225 @@ pub fn run() {
                 }
             }
 
-            // 커스텀 메뉴 생성
-            let app_menu = SubmenuBuilder::new(app, "OddEyes")
-                .about(None)
-                .separator()
-                .services()
-                .separator()
-                .hide()
-                .hide_others()
-                .show_all()
-                .separator()
-                .quit()
-                .build()?;
-
-            let edit_menu = SubmenuBuilder::new(app, "Edit")
-                .undo()
-                .redo()
-                .separator()
-                .cut()
-                .copy()
-                .paste()
-                .select_all()
-                .build()?;
-
-            let reload_item = MenuItemBuilder::with_id("reload", "Reload This Page")
-                .accelerator("CmdOrCtrl+R")
-                .build(app)?;
+            // 네이티브 앱 메뉴 (최근 프로젝트 목록 포함)
+            commands::menu::install_app_menu(app.handle())?;
 
-            let view_menu = SubmenuBuilder::new(app, "View")
-                .item(&reload_item)
-                .build()?;
+            // 시스템 트레이 (최근 프로젝트, 새 프로젝트, 저장/백업 상태)
+            commands::tray::create_tray(app.handle())?;
 
-            let window_menu = SubmenuBuilder::new(app, "Window")
-                .minimize()
-                .maximize()
-                .close_window()
-                .separator()
-                .fullscreen()
-                .build()?;
+            // 빈 페이지가 많이 쌓였으면 DB 최적화 (백그라운드)
+            commands::maintenance::maybe_optimize_database(app.handle());
 
-            let menu = MenuBuilder::new(app)
-                .item(&app_menu)
-                .item(&edit_menu)
-                .item(&view_menu)
-                .item(&window_menu)
-                .build()?;
+            // 블록 저장 시 해당 세그먼트만 다시 검사하는 백그라운드 QA
+            commands::qa::start_block_qa_worker(app.handle());
 
-            app.set_menu(menu)?;
-
-            // 메뉴 이벤트 핸들러
-            app.on_menu_event(move |app_handle, event| {
-                if event.id().as_ref() == "reload" {
-                    if let Some(window) = app_handle.get_webview_window("main") {
-                        // 현재 URL을 가져오고, 실패 시 reload 스킵 (패닉 방지)
-                        if let Ok(url) = window.url() {
-                            let _ = window.navigate(url);
-                        }
-                    }
-                }
-            });
+            // 빠른 캡처 전역 단축키 (설정에 지정된 경우)
+            commands::capture::apply_capture_shortcut(app.handle());
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
+        .on_window_event(|window, event| match event {
+            // 창에 파일을 끌어다 놓으면 확장자에 따라 가져오기/첨부
+            tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) => {
+                commands::drag_drop::handle_file_drop(window, paths.clone());
+            }
+            // 닫힌 창의 MCP 서버 범위 정리
+            tauri::WindowEvent::Destroyed => {
+                let label = window.label().to_string();
+                tauri::async_runtime::spawn(async move {
+                    mcp::McpRegistry::forget_window(&label).await;
+                });
+            }
+            // 트레이 유지 설정이면 메인 창을 닫지 않고 숨김
+            tauri::WindowEvent::CloseRequested { api, .. }
+                if commands::tray::hide_to_tray_on_close(window) =>
+            {
+                api.prevent_close();
+            }
+            _ => {}
+        })
+        .invoke_handler(crash::catch_invoke(tauri::generate_handler![
             commands::project::create_project,
             commands::project::load_project,
             commands::project::save_project,
+            commands::project::get_project_locales,
+            commands::project::set_project_read_only,
             commands::project::duplicate_project,
+            commands::project::set_project_custom_field,
+            commands::project::get_project_custom_fields,
+            commands::project::list_custom_field_keys,
+            commands::project::save_project_as_template,
+            commands::project::list_project_templates,
+            commands::project::delete_project_template,
+            commands::project::create_project_from_template,
             commands::block::get_block,
+            commands::block::get_blocks_batch,
+            commands::block::get_block_history,
             commands::block::update_block,
             commands::block::split_block,
             commands::block::merge_blocks,
+            commands::block::tag_blocks,
+            commands::block::untag_blocks,
+            commands::block::set_block_capacity,
+            commands::block::list_tags,
+            commands::block::query_blocks,
+            commands::segments::list_segments,
+            commands::segments::copy_segments_to_clipboard,
+            commands::segments::set_segment_note,
+            commands::segments::clear_segment_note,
+            commands::segments::list_segment_notes,
             commands::chat::save_current_chat_session,
             commands::chat::load_current_chat_session,
             commands::chat::save_chat_sessions,
             commands::chat::load_chat_sessions,
+            commands::chat::save_chat_session,
+            commands::chat::load_chat_session,
+            commands::chat::get_session_context,
+            commands::chat::list_chat_sessions,
+            commands::chat::rename_chat_session,
+            commands::chat::delete_chat_session,
+            commands::chat::search_chat_messages,
+            commands::chat::save_message_citations,
+            commands::chat::get_message_citations,
+            commands::chat::export_chat_session,
+            commands::chat::get_chat_retention_policy,
+            commands::chat::set_chat_retention_policy,
+            commands::chat::prune_chat_history,
             commands::chat::save_chat_project_settings,
             commands::chat::load_chat_project_settings,
+            // 앱 전역 설정
+            commands::settings::get_app_settings,
+            commands::settings::get_app_setting,
+            commands::settings::set_app_setting,
+            commands::settings::update_app_settings,
+            commands::settings::reset_app_settings,
+            // 프롬프트 템플릿 (페르소나/번역 규칙 프리셋)
+            commands::prompt_templates::list_prompt_templates,
+            commands::prompt_templates::get_prompt_template,
+            commands::prompt_templates::save_prompt_template,
+            commands::prompt_templates::delete_prompt_template,
             commands::glossary::import_glossary_csv,
             commands::glossary::import_glossary_excel,
             commands::glossary::search_glossary,
+            commands::glossary::get_block_glossary_hits,
+            // 번역 금지(DNT) 용어
+            commands::dnt::list_dnt_terms,
+            commands::dnt::save_dnt_term,
+            commands::dnt::delete_dnt_term,
+            commands::dnt::mask_dnt_text,
+            commands::dnt::unmask_dnt_text,
+            // LLM/MT 전송용 텍스트 보호
+            commands::text::protect_inline_tags,
+            commands::text::restore_inline_tags,
+            commands::text::protect_text_for_provider,
+            commands::text::restore_provider_text,
+            // 언어 감지 / 텍스트 파일 읽기(인코딩 자동 감지)
+            commands::text::detect_language,
+            commands::text::check_import_language,
+            commands::text::transliterate,
+            commands::text::read_text_file,
+            commands::text::count_words,
+            commands::text::get_project_word_stats,
+            // QA
+            commands::qa::run_qa_checks,
+            commands::qa::get_consistency_report,
+            commands::qa::estimate_quality,
+            commands::qa::get_quality_estimates,
+            // 반복 세그먼트 / 번역 전파
+            commands::repetitions::find_repetitions,
+            commands::repetitions::propagate_translation,
+            commands::repetitions::set_segment_propagation_opt_out,
+            // 의사 번역 (레이아웃 테스트)
+            commands::pseudo::pseudo_translate_project,
+            // 리포트
+            commands::reports::get_productivity_report,
+            commands::reports::export_productivity_report,
+            commands::reports::export_review_report,
+            commands::reports::export_bilingual_docx,
+            commands::reports::export_target_html,
+            commands::reports::generate_quote,
+            commands::reports::analyze_project_against_tm,
+            // 리뷰 변경 추적
+            commands::revisions::record_revision,
+            commands::revisions::list_revisions,
+            commands::revisions::accept_revision,
+            commands::revisions::reject_revision,
+            commands::revisions::export_revisions,
             commands::history::create_snapshot,
             commands::history::restore_snapshot,
+            commands::history::preview_restore,
             commands::history::list_history,
+            commands::history::export_snapshot,
+            commands::history::import_snapshot,
+            commands::history::get_changes_since,
+            // 멀티 윈도우 (프로젝트 창)
+            commands::window::open_project_window,
+            commands::window::get_window_project,
+            commands::window::set_window_project,
+            commands::window::list_project_windows,
+            // 딥 링크
+            commands::deep_link::take_pending_deep_links,
+            // 시스템 트레이
+            commands::tray::refresh_tray_menu,
+            // 앱 메뉴
+            commands::menu::refresh_app_menu,
+            // 앱 업데이트
+            commands::updater::check_for_update,
+            commands::updater::install_update,
+            // 작업 완료 알림
+            commands::notifications::notify_job_finished,
             commands::storage::export_project_file,
             commands::storage::delete_project,
             commands::storage::delete_all_projects,
+            commands::storage::list_trashed_projects,
+            commands::storage::restore_project,
+            commands::storage::purge_trash,
             commands::storage::import_project_file,
             commands::storage::import_project_file_safe,
+            commands::storage::export_project_package,
+            commands::storage::export_projects,
+            commands::storage::import_project_package,
+            commands::storage::export_project_json,
+            commands::storage::import_project_json,
+            commands::interop::import_omegat_project,
+            commands::interop::import_sdlxliff,
+            commands::interop::export_sdlxliff,
+            commands::interop::list_xliff_segment_statuses,
+            commands::interop::set_xliff_segment_status,
+            commands::storage::inspect_ite_file,
+            commands::storage::preview_project_import,
+            commands::storage::merge_project_translations,
+            commands::storage::list_auto_backups,
+            commands::storage::restore_from_backup,
+            commands::storage::prune_auto_backups,
+            commands::storage::get_storage_report,
+            commands::storage::cleanup_orphans,
+            commands::maintenance::optimize_database,
+            commands::encryption::get_database_encryption_status,
+            commands::encryption::enable_database_encryption,
+            commands::encryption::disable_database_encryption,
             commands::storage::list_project_ids,
             commands::storage::list_recent_projects,
+            commands::storage::pin_project,
+            commands::storage::unpin_project,
+            commands::storage::archive_project,
+            commands::storage::unarchive_project,
+            commands::storage::db_perf_stats,
+            commands::storage::run_readonly_query,
+            commands::metrics::record_metrics,
+            commands::metrics::get_metrics_report,
+            commands::metrics::clear_metrics,
+            commands::diagnostics::get_crash_report,
+            commands::diagnostics::clear_crash_report,
+            commands::shutdown::shutdown_ready,
+            commands::env::ite_env_info,
+            commands::env::reload_env,
+            commands::network::get_network_status,
+            commands::network::check_connectivity,
+            commands::storage::search_projects,
             commands::attachments::attach_file,
             commands::attachments::list_attachments,
+            commands::attachments::get_attachment_text,
             commands::attachments::delete_attachment,
             commands::attachments::preview_attachment,
             commands::attachments::read_file_bytes,
@@ -276,6 +405,8 @@ pub fn run() {
             commands::mcp::save_mcp_server,
             commands::mcp::list_mcp_servers,
             commands::mcp::delete_mcp_server,
+            commands::mcp::get_project_mcp_servers,
+            commands::mcp::set_project_mcp_servers,
             // MCP SSE 클라이언트 (Rust 네이티브)
             commands::mcp::mcp_connect,
             commands::mcp::mcp_disconnect,
@@ -292,6 +423,9 @@ pub fn run() {
             commands::mcp::mcp_registry_clear_all,
             commands::mcp::mcp_registry_get_tools,
             commands::mcp::mcp_registry_call_tool,
+            commands::mcp::mcp_registry_call_tools_parallel,
+            commands::mcp::mcp_registry_clear_cache,
+            commands::mcp::mcp_registry_set_active_project,
             commands::mcp::mcp_set_notion_config,
             // 커넥터 (OpenAI 빌트인 + MCP)
             commands::connector::connector_set_token,
@@ -301,6 +435,11 @@ pub fn run() {
             commands::connector::connector_start_oauth,
             // Confluence REST API (MCP OAuth 토큰 재사용)
             commands::confluence::confluence_get_page_html,
+            commands::confluence::confluence_get_page_text,
+            commands::confluence::convert_confluence_storage,
+            commands::reference::resolve_reference_url,
+            commands::suggest::suggest_translation,
+            commands::suggest::build_translation_prompt,
             // Notion REST API
             commands::notion::notion_set_token,
             commands::notion::notion_has_token,
@@ -309,6 +448,25 @@ pub fn run() {
             commands::notion::notion_get_page,
             commands::notion::notion_get_page_content,
             commands::notion::notion_query_database,
+            // 전역 용어집 원격 동기화 (WebDAV / S3)
+            commands::sync::set_glossary_sync_remote,
+            commands::sync::get_glossary_sync_remote,
+            commands::sync::clear_glossary_sync_remote,
+            commands::sync::sync_glossary_now,
+            commands::sync::sync_glossary_from_notion,
+            // 클라우드 암호화 백업 (Google Drive / Dropbox 커넥터)
+            commands::cloud_backup::set_cloud_backup_passphrase,
+            commands::cloud_backup::has_cloud_backup_passphrase,
+            commands::cloud_backup::backup_to_cloud,
+            commands::cloud_backup::list_cloud_backups,
+            commands::cloud_backup::restore_from_cloud,
+            // TMS 연동 (Phrase / Crowdin)
+            commands::tms::tms_set_credentials,
+            commands::tms::tms_has_credentials,
+            commands::tms::tms_clear_credentials,
+            commands::tms::tms_list_jobs,
+            commands::tms::tms_pull_job,
+            commands::tms::tms_push_job,
             // Secret Manager
             commands::secrets::secrets_initialize,
             commands::secrets::secrets_get,
@@ -319,7 +477,25 @@ pub fn run() {
             commands::secrets::secrets_has,
             commands::secrets::secrets_list_keys,
             commands::secrets::secrets_migrate_legacy,
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+            commands::secrets::secrets_backend_info,
+        ]))
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, _event| {
+            // 종료 전 자동 저장/연결/vault/DB 정리 (commands::shutdown)
+            if let tauri::RunEvent::ExitRequested { code, api, .. } = &_event {
+                commands::shutdown::handle_exit_requested(_app, *code, api);
+            }
+
+            // macOS 파일 연결은 실행 인자가 아니라 Opened 이벤트로 전달
+            #[cfg(target_os = "macos")]
+            if let tauri::RunEvent::Opened { urls } = &_event {
+                let files: Vec<std::path::PathBuf> = urls
+                    .iter()
+                    .filter(|u| u.scheme() == "file")
+                    .filter_map(|u| u.to_file_path().ok())
+                    .collect();
+                commands::deep_link::handle_opened_files(_app, &files);
+            }
+        });
 }
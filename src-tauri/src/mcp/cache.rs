@@ -0,0 +1,208 @@
+//! MCP 도구 결과 캐시
+//!
+//! 한 대화에서 같은 페이지를 여러 번 가져오는 것처럼 동일한 호출을 짧은 시간 동안 재사용합니다.
+//! - 키: 서버 + 도구 이름 + 인자 해시 (인자 키 순서와 무관)
+//! - 조회성 도구의 성공 결과만 저장하며, 쓰기성 도구를 호출하면 해당 서버의 캐시를 비웁니다.
+//! - 연결 해제/로그아웃 시에도 해당 서버의 캐시를 비웁니다.
+
+use crate::mcp::registry::McpServerId;
+use crate::mcp::types::McpToolResult;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 기본 캐시 유지 시간 (초, 0이면 캐시 사용 안 함)
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 120;
+
+/// 최대 캐시 유지 시간 (초)
+pub const MAX_CACHE_TTL_SECS: u64 = 3_600;
+
+/// 최대 보관 항목 수 (넘으면 가장 오래된 항목부터 제거)
+const MAX_ENTRIES: usize = 200;
+
+/// 이름에 포함되면 조회성 도구로 보는 단어
+const READ_WORDS: &[&str] = &["get", "search", "fetch", "list", "read", "query", "retrieve", "find", "lookup"];
+
+/// 이름에 포함되면 쓰기성 도구로 보는 단어 (조회성 단어보다 우선)
+const WRITE_WORDS: &[&str] = &[
+    "create", "update", "delete", "remove", "add", "edit", "write", "move", "set", "post", "comment", "upload",
+    "archive", "publish",
+];
+
+struct Entry {
+    server_id: McpServerId,
+    tool_name: String,
+    result: McpToolResult,
+    stored_at: Instant,
+}
+
+static TTL_SECS: AtomicU64 = AtomicU64::new(DEFAULT_CACHE_TTL_SECS);
+static ENTRIES: Lazy<Mutex<HashMap<String, Entry>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 도구 이름 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolKind {
+    Read,
+    Write,
+    /// 알 수 없음 (캐시하지 않고 무효화도 하지 않음)
+    Other,
+}
+
+/// 도구 이름을 단어 단위로 나눠 조회/쓰기 여부 판단 (예: "notion-update-page", "getConfluencePage")
+pub fn classify_tool(name: &str) -> ToolKind {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    if words.iter().any(|w| WRITE_WORDS.contains(&w.as_str())) {
+        ToolKind::Write
+    } else if words.iter().any(|w| READ_WORDS.contains(&w.as_str())) {
+        ToolKind::Read
+    } else {
+        ToolKind::Other
+    }
+}
+
+/// 캐시 키 (인자는 키 순서를 정렬한 JSON의 SHA-256)
+pub fn cache_key(
+    server_id: McpServerId,
+    name: &str,
+    arguments: Option<&HashMap<String, serde_json::Value>>,
+) -> String {
+    let sorted: BTreeMap<&String, &serde_json::Value> = arguments.into_iter().flatten().collect();
+    let json = serde_json::to_string(&sorted).unwrap_or_default();
+    let hash: String = Sha256::digest(json.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}:{}:{}", server_id.as_str(), name, hash)
+}
+
+/// 유지 시간 설정 (앱 시작 시, 설정 변경 시)
+pub fn set_ttl_secs(secs: u64) {
+    let secs = secs.min(MAX_CACHE_TTL_SECS);
+    TTL_SECS.store(secs, Ordering::Relaxed);
+    if secs == 0 {
+        clear(None, None);
+    }
+}
+
+fn ttl() -> Option<Duration> {
+    match TTL_SECS.load(Ordering::Relaxed) {
+        0 => None,
+        secs => Some(Duration::from_secs(secs)),
+    }
+}
+
+/// 유효한 캐시 결과 조회 (만료된 항목은 제거)
+pub fn get(key: &str) -> Option<McpToolResult> {
+    let ttl = ttl()?;
+    let mut entries = ENTRIES.lock().unwrap_or_else(|e| e.into_inner());
+    match entries.get(key) {
+        Some(entry) if entry.stored_at.elapsed() < ttl => Some(entry.result.clone()),
+        Some(_) => {
+            entries.remove(key);
+            None
+        }
+        None => None,
+    }
+}
+
+/// 호출 결과 반영: 조회성 도구의 성공 결과는 저장, 쓰기성 도구는 해당 서버 캐시를 비움
+pub fn record(key: String, server_id: McpServerId, name: &str, result: &McpToolResult) {
+    match classify_tool(name) {
+        ToolKind::Write => {
+            clear(Some(server_id), None);
+        }
+        ToolKind::Read if !result.is_error => {
+            let Some(ttl) = ttl() else {
+                return;
+            };
+            let mut entries = ENTRIES.lock().unwrap_or_else(|e| e.into_inner());
+            entries.retain(|_, entry| entry.stored_at.elapsed() < ttl);
+            while entries.len() >= MAX_ENTRIES {
+                let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.stored_at)
+                    .map(|(key, _)| key.clone())
+                else {
+                    break;
+                };
+                entries.remove(&oldest);
+            }
+            entries.insert(
+                key,
+                Entry {
+                    server_id,
+                    tool_name: name.to_string(),
+                    result: result.clone(),
+                    stored_at: Instant::now(),
+                },
+            );
+        }
+        _ => {}
+    }
+}
+
+/// 캐시 비우기 (서버/도구를 지정하면 해당 항목만), 제거된 항목 수 반환
+pub fn clear(server_id: Option<McpServerId>, tool_name: Option<&str>) -> usize {
+    let mut entries = ENTRIES.lock().unwrap_or_else(|e| e.into_inner());
+    let before = entries.len();
+    entries.retain(|_, entry| {
+        !(server_id.is_none_or(|s| s == entry.server_id) && tool_name.is_none_or(|t| t == entry.tool_name))
+    });
+    before - entries.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_classify_tool() {
+        assert_eq!(classify_tool("getConfluencePage"), ToolKind::Read);
+        assert_eq!(classify_tool("notion-search"), ToolKind::Read);
+        assert_eq!(classify_tool("notion-update-page"), ToolKind::Write);
+        assert_eq!(classify_tool("createConfluencePage"), ToolKind::Write);
+        assert_eq!(classify_tool("addCommentToPage"), ToolKind::Write);
+        assert_eq!(classify_tool("atlassianUserInfo"), ToolKind::Other);
+    }
+
+    #[test]
+    fn test_cache_key_ignores_argument_order() {
+        let a: HashMap<String, serde_json::Value> =
+            [("pageId".to_string(), json!("1")), ("cloudId".to_string(), json!("x"))].into();
+        let b: HashMap<String, serde_json::Value> =
+            [("cloudId".to_string(), json!("x")), ("pageId".to_string(), json!("1"))].into();
+        let key = cache_key(McpServerId::Atlassian, "getConfluencePage", Some(&a));
+        assert_eq!(key, cache_key(McpServerId::Atlassian, "getConfluencePage", Some(&b)));
+        assert_ne!(key, cache_key(McpServerId::Notion, "getConfluencePage", Some(&a)));
+        assert_ne!(key, cache_key(McpServerId::Atlassian, "getConfluencePage", None));
+    }
+
+    #[test]
+    fn test_error_results_are_not_cached() {
+        let key = cache_key(McpServerId::Notion, "notion-search", None);
+        let error = McpToolResult {
+            content: Vec::new(),
+            is_error: true,
+        };
+        record(key.clone(), McpServerId::Notion, "notion-search", &error);
+        assert!(get(&key).is_none());
+    }
+}
@@ -5,9 +5,8 @@
 use crate::mcp::oauth::AtlassianOAuth;
 use crate::mcp::types::*;
 use crate::mcp::emit_mcp_status_changed;
-use futures::StreamExt;
+use crate::mcp::sse::{SseEvent, SseParser};
 use rand::Rng;
-use reqwest_eventsource::{Event, EventSource};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
@@ -17,6 +16,47 @@ use tokio::sync::{mpsc, oneshot, Mutex, RwLock};
 const MCP_SSE_URL: &str = "https://mcp.atlassian.com/v1/sse";
 const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
 
+/// SSE 이벤트 처리 (`endpoint`: 메시지 전송 주소, `message`: JSON-RPC 응답)
+async fn handle_sse_event(
+    msg: SseEvent,
+    message_endpoint: &RwLock<Option<String>>,
+    pending_requests: &Mutex<HashMap<String, oneshot::Sender<JsonRpcResponse>>>,
+) {
+    match msg.event.as_str() {
+        "endpoint" => {
+            // 메시지 전송 엔드포인트 수신
+            // 상대 경로인 경우 SSE URL 기준으로 절대 URL로 변환
+            let endpoint_url = if msg.data.starts_with("http://") || msg.data.starts_with("https://") {
+                msg.data.clone()
+            } else {
+                match url::Url::parse(MCP_SSE_URL).and_then(|base_url| base_url.join(&msg.data)) {
+                    Ok(full_url) => full_url.to_string(),
+                    Err(_) => format!("https://mcp.atlassian.com{}", msg.data),
+                }
+            };
+            println!("[MCP] Received endpoint: {} -> {}", msg.data, endpoint_url);
+            *message_endpoint.write().await = Some(endpoint_url);
+        }
+        "message" => {
+            // JSON-RPC 응답 수신
+            let Ok(response) = serde_json::from_str::<JsonRpcResponse>(&msg.data) else {
+                return;
+            };
+            let id_str = match &response.id {
+                Some(serde_json::Value::Number(n)) => n.to_string(),
+                Some(serde_json::Value::String(s)) => s.clone(),
+                _ => return,
+            };
+            if let Some(tx) = pending_requests.lock().await.remove(&id_str) {
+                let _ = tx.send(response);
+            }
+        }
+        _ => {
+            println!("[MCP] Unknown SSE event: {} - {}", msg.event, crate::http::preview(&msg.data));
+        }
+    }
+}
+
 /// MCP 클라이언트
 pub struct McpClient {
     /// OAuth 인증 핸들러
@@ -190,10 +230,8 @@ impl McpClient {
         println!("[MCP] Starting SSE connection to: {}", MCP_SSE_URL);
         println!("[MCP] Access token: [REDACTED] (length: {})", access_token.len());
 
-        // reqwest 클라이언트 빌드 (TLS 설정 포함)
-        let client = reqwest::Client::builder()
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        // SSE는 계속 열려 있어야 하므로 요청 제한 시간이 없는 클라이언트 사용
+        let client = crate::http::streaming_client()?;
         
         let request = client
             .get(MCP_SSE_URL)
@@ -201,8 +239,16 @@ impl McpClient {
             .header("Accept", "text/event-stream")
             .header("Cache-Control", "no-cache");
 
-        let mut es = EventSource::new(request)
-            .map_err(|e| format!("Failed to create EventSource: {}", e))?;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Failed to open SSE connection: {}", e))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = crate::http::read_text(response).await.unwrap_or_default();
+            return Err(format!("SSE connection failed with status {}: {}", status, crate::http::preview(&body)));
+        }
+        println!("[MCP] SSE connection opened");
 
         let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
         *self.shutdown_tx.lock().await = Some(shutdown_tx);
@@ -210,75 +256,40 @@ impl McpClient {
         let message_endpoint = self.message_endpoint.clone();
         let pending_requests = self.pending_requests.clone();
         let status = self.status.clone();
+        // 이벤트 하나의 최대 크기는 일반 응답 본문과 같음
+        let mut parser = SseParser::new(crate::http::max_response_bytes());
 
         // SSE 이벤트 처리 태스크
         tokio::spawn(async move {
+            let mut response = response;
             loop {
                 tokio::select! {
-                    event = es.next() => {
-                        match event {
-                            Some(Ok(Event::Open)) => {
-                                println!("[MCP] SSE connection opened");
+                    chunk = response.chunk() => {
+                        let events = match chunk {
+                            Ok(Some(bytes)) => parser.feed(&bytes),
+                            Ok(None) => {
+                                println!("[MCP] SSE stream ended");
+                                break;
                             }
-                            Some(Ok(Event::Message(msg))) => {
-                                // SSE 이벤트 타입에 따라 처리
-                                match msg.event.as_str() {
-                                    "endpoint" => {
-                                        // 메시지 전송 엔드포인트 수신
-                                        // 상대 경로인 경우 절대 URL로 변환
-                                        let endpoint_url = if msg.data.starts_with("http://") || msg.data.starts_with("https://") {
-                                            msg.data.clone()
-                                        } else {
-                                            // 상대 경로를 SSE URL 기준으로 절대 URL로 변환
-                                            match url::Url::parse(MCP_SSE_URL) {
-                                                Ok(base_url) => {
-                                                    match base_url.join(&msg.data) {
-                                                        Ok(full_url) => full_url.to_string(),
-                                                        Err(_) => format!("https://mcp.atlassian.com{}", msg.data)
-                                                    }
-                                                }
-                                                Err(_) => format!("https://mcp.atlassian.com{}", msg.data)
-                                            }
-                                        };
-                                        println!("[MCP] Received endpoint: {} -> {}", msg.data, endpoint_url);
-                                        *message_endpoint.write().await = Some(endpoint_url);
-                                    }
-                                    "message" => {
-                                        // JSON-RPC 응답 수신
-                                        if let Ok(response) = serde_json::from_str::<JsonRpcResponse>(&msg.data) {
-                                            if let Some(id) = &response.id {
-                                                let id_str = match id {
-                                                    serde_json::Value::Number(n) => n.to_string(),
-                                                    serde_json::Value::String(s) => s.clone(),
-                                                    _ => continue,
-                                                };
-                                                if let Some(tx) = pending_requests.lock().await.remove(&id_str) {
-                                                    let _ = tx.send(response);
-                                                }
-                                            }
-                                        }
-                                    }
-                                    _ => {
-                                        println!("[MCP] Unknown SSE event: {} - {}", msg.event, msg.data);
-                                    }
+                            Err(e) => Err(e.to_string()),
+                        };
+                        match events {
+                            Ok(events) => {
+                                for msg in events {
+                                    handle_sse_event(msg, &message_endpoint, &pending_requests).await;
                                 }
                             }
-                            Some(Err(e)) => {
+                            Err(e) => {
                                 eprintln!("[MCP] SSE error: {}", e);
                                 let mut s = status.write().await;
                                 s.error = Some(format!("SSE error: {}", e));
                                 emit_mcp_status_changed(&s);
                                 break;
                             }
-                            None => {
-                                println!("[MCP] SSE stream ended");
-                                break;
-                            }
                         }
                     }
                     _ = shutdown_rx.recv() => {
                         println!("[MCP] Shutting down SSE connection");
-                        es.close();
                         break;
                     }
                 }
@@ -380,9 +391,7 @@ impl McpClient {
         self.pending_requests.lock().await.insert(id.to_string(), tx);
 
         // HTTP POST로 요청 전송
-        let client = reqwest::Client::builder()
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        let client = crate::http::client()?;
         
         let response = client
             .post(&endpoint)
@@ -396,7 +405,7 @@ impl McpClient {
         if !response.status().is_success() {
             self.pending_requests.lock().await.remove(&id.to_string());
             let status = response.status();
-            let body = response.text().await.unwrap_or_default();
+            let body = crate::http::read_text(response).await.unwrap_or_default();
             return Err(format!("Request failed with status {}: {}", status, body));
         }
 
@@ -427,9 +436,7 @@ impl McpClient {
 
         println!("[MCP] Sending notification: {}", method);
 
-        let client = reqwest::Client::builder()
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        let client = crate::http::client()?;
         
         let response = client
             .post(&endpoint)
@@ -442,7 +449,7 @@ impl McpClient {
 
         if !response.status().is_success() {
             let status = response.status();
-            let body = response.text().await.unwrap_or_default();
+            let body = crate::http::read_text(response).await.unwrap_or_default();
             return Err(format!("Notification failed with status {}: {}", status, body));
         }
 
@@ -207,9 +207,7 @@ impl NotionMcpClient {
 
         println!("[NotionMCP] Sending request: {} (id: {}) to {}", method, id, mcp_url);
 
-        let client = reqwest::Client::builder()
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        let client = crate::http::client()?;
 
         // 세션 ID가 있으면 헤더에 추가
         let session_id = self.session_id.read().await.clone();
@@ -244,7 +242,7 @@ impl NotionMcpClient {
 
         let status = response.status();
         if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
+            let body = crate::http::read_text(response).await.unwrap_or_default();
             if status.as_u16() == 401 {
                 return Err("Authentication failed. Please check your auth token.".to_string());
             }
@@ -255,12 +253,9 @@ impl NotionMcpClient {
         }
 
         // 응답 본문에서 JSON-RPC 응답 파싱
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| format!("Failed to read response: {}", e))?;
+        let response_text = crate::http::read_text(response).await?;
 
-        println!("[NotionMCP] Response: {}", &response_text[..response_text.len().min(200)]);
+        println!("[NotionMCP] Response: {}", crate::http::preview(&response_text));
 
         // 응답이 비어있는 경우 (일부 알림 요청에 대한 응답)
         if response_text.is_empty() {
@@ -297,9 +292,7 @@ impl NotionMcpClient {
 
         println!("[NotionMCP] Sending notification: {}", method);
 
-        let client = reqwest::Client::builder()
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        let client = crate::http::client()?;
 
         let session_id = self.session_id.read().await.clone();
 
@@ -320,7 +313,7 @@ impl NotionMcpClient {
 
         if !response.status().is_success() {
             let status = response.status();
-            let body = response.text().await.unwrap_or_default();
+            let body = crate::http::read_text(response).await.unwrap_or_default();
             return Err(format!(
                 "Notification failed with status {}: {}",
                 status, body
@@ -2,11 +2,28 @@
 //!
 //! 여러 MCP 서버(Atlassian, Notion 등)를 통합 관리합니다.
 
+use crate::mcp::cache;
 use crate::mcp::client::MCP_CLIENT;
 use crate::mcp::notion_client::NOTION_MCP_CLIENT;
-use crate::mcp::types::{McpConnectionStatus, McpTool, McpToolResult};
+use crate::mcp::schema;
+use crate::mcp::types::{McpConnectionStatus, McpContent, McpTool, McpToolResult};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 병렬 호출 1회에 받는 최대 요청 수
+pub const MAX_PARALLEL_TOOL_CALLS: usize = 16;
+
+/// 병렬 호출 전체 제한 시간 (기본값 / 허용 범위)
+pub const DEFAULT_PARALLEL_TIMEOUT_MS: u64 = 30_000;
+const MIN_PARALLEL_TIMEOUT_MS: u64 = 1_000;
+const MAX_PARALLEL_TIMEOUT_MS: u64 = 120_000;
+
+/// 창(label)별 활성 프로젝트에서 사용할 서버 (None이면 모든 서버, 기록이 없는 창도 모든 서버)
+/// - 서버 연결은 앱 전체가 공유하므로, 어느 창의 범위에도 없는 서버만 연결을 해제합니다.
+static SCOPES: Lazy<Mutex<HashMap<String, Option<Vec<McpServerId>>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
 
 /// 지원되는 MCP 서버 타입
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -24,6 +41,15 @@ impl McpServerId {
         }
     }
 
+    /// `as_str` 값(= `mcp_servers.server_type`)에서 변환
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "atlassian" => Some(McpServerId::Atlassian),
+            "notion" => Some(McpServerId::Notion),
+            _ => None,
+        }
+    }
+
     pub fn display_name(&self) -> &'static str {
         match self {
             McpServerId::Atlassian => "Atlassian Confluence",
@@ -40,6 +66,8 @@ pub struct McpServerInfo {
     pub description: String,
     pub icon: String,
     pub status: McpConnectionStatus,
+    /// 활성 프로젝트에서 사용하는 서버인지 (프로젝트 MCP 서버 연결 기준)
+    pub in_scope: bool,
 }
 
 /// 전체 MCP 상태 요약
@@ -50,6 +78,80 @@ pub struct McpRegistryStatus {
     pub has_any_token: bool,
 }
 
+/// 병렬 호출 요청 1건
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpToolCallRequest {
+    /// 결과와 짝을 맞출 호출자 지정 ID (없으면 요청 순서 번호)
+    pub id: Option<String>,
+    /// 없으면 도구 이름으로 서버를 찾음
+    pub server_id: Option<McpServerId>,
+    pub name: String,
+    pub arguments: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// 병렬 호출 1건의 결과 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum McpToolCallStatus {
+    Ok,
+    Error,
+    /// 전체 제한 시간 안에 끝나지 않음
+    Timeout,
+}
+
+/// 병렬 호출 1건의 결과 (요청 순서대로)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpToolCallOutcome {
+    pub id: String,
+    pub server_id: Option<McpServerId>,
+    pub name: String,
+    pub status: McpToolCallStatus,
+    pub result: Option<McpToolResult>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// 도구 호출 결과 → 병렬 호출 상태
+/// - 서버가 `isError`로 돌려준 결과도 실패로 세고, 결과 본문은 그대로 넘깁니다.
+fn call_status(
+    result: Result<McpToolResult, String>,
+) -> (McpToolCallStatus, Option<McpToolResult>, Option<String>) {
+    match result {
+        Ok(result) if result.is_error => {
+            let message = result
+                .content
+                .iter()
+                .filter_map(|c| match c {
+                    McpContent::Text { text } => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let message = if message.trim().is_empty() {
+                "Tool returned an error".to_string()
+            } else {
+                message
+            };
+            (McpToolCallStatus::Error, Some(result), Some(message))
+        }
+        Ok(result) => (McpToolCallStatus::Ok, Some(result), None),
+        Err(e) => (McpToolCallStatus::Error, None, Some(e)),
+    }
+}
+
+/// 병렬 호출 결과 (일부가 실패/시간 초과여도 끝난 결과는 모두 포함)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpParallelCallReport {
+    pub results: Vec<McpToolCallOutcome>,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub timed_out: usize,
+    pub elapsed_ms: u64,
+}
+
 /// MCP 레지스트리
 /// 
 /// 모든 MCP 서버의 상태를 추적하고 통합 관리합니다.
@@ -64,8 +166,75 @@ impl McpRegistry {
         ]
     }
 
-    /// 특정 MCP 서버에 연결
-    pub async fn connect(server_id: McpServerId) -> Result<(), String> {
+    /// 창의 활성 프로젝트 서버 범위 설정 (None이면 모든 서버)
+    /// - 어느 창의 범위에도 없는 서버는 연결을 해제합니다.
+    pub async fn set_scope(window: &str, servers: Option<Vec<McpServerId>>) {
+        SCOPES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(window.to_string(), servers);
+        Self::disconnect_unused().await;
+    }
+
+    /// 닫힌 창의 범위 기록 제거 (남은 창 어디에서도 쓰지 않는 서버는 연결 해제)
+    pub async fn forget_window(window: &str) {
+        let removed = SCOPES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(window)
+            .is_some();
+        if removed {
+            Self::disconnect_unused().await;
+        }
+    }
+
+    async fn disconnect_unused() {
+        for server_id in Self::supported_servers() {
+            if !Self::is_used_by_any_window(server_id) && Self::get_status(server_id).await.is_connected {
+                println!("[MCP Registry] {} is out of every project scope, disconnecting", server_id.as_str());
+                Self::disconnect(server_id).await;
+            }
+        }
+    }
+
+    /// 범위를 기록한 창 중 하나라도 이 서버를 쓰는지 (기록한 창이 없으면 true)
+    fn is_used_by_any_window(server_id: McpServerId) -> bool {
+        let scopes = SCOPES.lock().unwrap_or_else(|e| e.into_inner());
+        scopes.is_empty()
+            || scopes
+                .values()
+                .any(|scope| scope.as_ref().is_none_or(|servers| servers.contains(&server_id)))
+    }
+
+    /// 창의 활성 프로젝트에서 사용하는 서버인지
+    pub fn is_in_scope(window: &str, server_id: McpServerId) -> bool {
+        SCOPES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(window)
+            .and_then(|scope| scope.as_ref())
+            .is_none_or(|servers| servers.contains(&server_id))
+    }
+
+    /// 창의 활성 프로젝트에서 사용하는 서버 목록
+    pub fn scoped_servers(window: &str) -> Vec<McpServerId> {
+        Self::supported_servers()
+            .into_iter()
+            .filter(|server_id| Self::is_in_scope(window, *server_id))
+            .collect()
+    }
+
+    fn ensure_in_scope(window: &str, server_id: McpServerId) -> Result<(), String> {
+        if Self::is_in_scope(window, server_id) {
+            Ok(())
+        } else {
+            Err(format!("{} is not enabled for the current project", server_id.display_name()))
+        }
+    }
+
+    /// 특정 MCP 서버에 연결 (요청한 창의 프로젝트 범위 안에서만)
+    pub async fn connect(window: &str, server_id: McpServerId) -> Result<(), String> {
+        Self::ensure_in_scope(window, server_id)?;
         match server_id {
             McpServerId::Atlassian => {
                 MCP_CLIENT.connect().await
@@ -78,6 +247,7 @@ impl McpRegistry {
 
     /// 특정 MCP 서버 연결 해제
     pub async fn disconnect(server_id: McpServerId) {
+        cache::clear(Some(server_id), None);
         match server_id {
             McpServerId::Atlassian => {
                 MCP_CLIENT.disconnect().await;
@@ -88,8 +258,16 @@ impl McpRegistry {
         }
     }
 
+    /// 모든 MCP 서버 연결 해제 (앱 종료 시 SSE 연결 정리)
+    pub async fn disconnect_all() {
+        for server_id in Self::supported_servers() {
+            Self::disconnect(server_id).await;
+        }
+    }
+
     /// 특정 MCP 서버 로그아웃 (토큰 삭제)
     pub async fn logout(server_id: McpServerId) {
+        cache::clear(Some(server_id), None);
         match server_id {
             McpServerId::Atlassian => {
                 MCP_CLIENT.logout().await;
@@ -103,6 +281,7 @@ impl McpRegistry {
     /// 특정 MCP 서버 완전 초기화 (토큰 + 클라이언트 정보 모두 삭제)
     /// Client ID mismatch 등 복구 불가능한 상태일 때 사용
     pub async fn clear_all(server_id: McpServerId) {
+        cache::clear(Some(server_id), None);
         match server_id {
             McpServerId::Atlassian => {
                 MCP_CLIENT.clear_all().await;
@@ -125,8 +304,8 @@ impl McpRegistry {
         }
     }
 
-    /// 전체 레지스트리 상태 조회
-    pub async fn get_registry_status() -> McpRegistryStatus {
+    /// 전체 레지스트리 상태 조회 (`in_scope`는 요청한 창 기준)
+    pub async fn get_registry_status(window: &str) -> McpRegistryStatus {
         let mut servers = Vec::new();
         let mut connected_count = 0;
         let mut has_any_token = false;
@@ -153,6 +332,7 @@ impl McpRegistry {
                     McpServerId::Notion => "📝".to_string(),
                 },
                 status,
+                in_scope: Self::is_in_scope(window, server_id),
             });
         }
 
@@ -175,11 +355,11 @@ impl McpRegistry {
         }
     }
 
-    /// 연결된 모든 MCP 서버의 도구 목록 조회
-    pub async fn get_all_tools() -> HashMap<McpServerId, Vec<McpTool>> {
+    /// 연결된 모든 MCP 서버의 도구 목록 조회 (창의 활성 프로젝트 범위 내)
+    pub async fn get_all_tools(window: &str) -> HashMap<McpServerId, Vec<McpTool>> {
         let mut all_tools = HashMap::new();
 
-        for server_id in Self::supported_servers() {
+        for server_id in Self::scoped_servers(window) {
             let status = Self::get_status(server_id).await;
             if status.is_connected {
                 let tools = Self::get_tools(server_id).await;
@@ -193,19 +373,132 @@ impl McpRegistry {
     }
 
     /// MCP 도구 호출
+    /// - 도구 목록의 `inputSchema`로 인자를 먼저 검증합니다 (`schema` 참고).
+    /// - 같은 서버/도구/인자의 조회 결과는 캐시 유지 시간 동안 재사용합니다 (`cache` 참고).
     pub async fn call_tool(
+        window: &str,
         server_id: McpServerId,
         name: &str,
         arguments: Option<HashMap<String, serde_json::Value>>,
     ) -> Result<McpToolResult, String> {
-        match server_id {
+        Self::ensure_in_scope(window, server_id)?;
+        Self::validate_arguments(server_id, name, arguments.as_ref()).await?;
+        let key = cache::cache_key(server_id, name, arguments.as_ref());
+        if let Some(result) = cache::get(&key) {
+            println!("[MCP Registry] Cache hit: {}/{}", server_id.as_str(), name);
+            return Ok(result);
+        }
+
+        let result = match server_id {
             McpServerId::Atlassian => {
                 MCP_CLIENT.call_tool(name, arguments).await
             }
             McpServerId::Notion => {
                 NOTION_MCP_CLIENT.call_tool(name, arguments).await
             }
+        }?;
+        cache::record(key, server_id, name, &result);
+        Ok(result)
+    }
+
+    /// 캐시된 도구 목록의 `inputSchema`로 인자 검증 (도구/스키마를 모르면 통과)
+    async fn validate_arguments(
+        server_id: McpServerId,
+        name: &str,
+        arguments: Option<&HashMap<String, serde_json::Value>>,
+    ) -> Result<(), String> {
+        let tools = Self::get_tools(server_id).await;
+        let Some(input_schema) = tools
+            .iter()
+            .find(|t| t.name == name)
+            .and_then(|t| t.input_schema.as_ref())
+        else {
+            return Ok(());
+        };
+        schema::validate_arguments(input_schema, arguments)
+            .map_err(|errors| format!("Invalid arguments for tool {}: {}", name, errors.join("; ")))
+    }
+
+    /// 도구 결과 캐시 비우기 (서버/도구를 지정하면 해당 항목만), 제거된 항목 수 반환
+    pub fn clear_cache(server_id: Option<McpServerId>, tool_name: Option<&str>) -> usize {
+        cache::clear(server_id, tool_name)
+    }
+
+    /// 여러 MCP 도구를 서버와 관계없이 동시에 호출 (컨텍스트 수집용)
+    /// - 모든 호출이 같은 제한 시간(`timeout_ms`)을 공유하며, 그때까지 끝나지 않은 호출만 `timeout`이 됩니다.
+    /// - 한 호출의 실패가 다른 호출에 영향을 주지 않습니다.
+    pub async fn call_tools_parallel(
+        window: &str,
+        requests: Vec<McpToolCallRequest>,
+        timeout_ms: Option<u64>,
+    ) -> Result<McpParallelCallReport, String> {
+        if requests.len() > MAX_PARALLEL_TOOL_CALLS {
+            return Err(format!(
+                "Too many tool calls: {} (max {})",
+                requests.len(),
+                MAX_PARALLEL_TOOL_CALLS
+            ));
         }
+        let timeout = Duration::from_millis(
+            timeout_ms
+                .unwrap_or(DEFAULT_PARALLEL_TIMEOUT_MS)
+                .clamp(MIN_PARALLEL_TIMEOUT_MS, MAX_PARALLEL_TIMEOUT_MS),
+        );
+        let started = Instant::now();
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        let calls = requests.into_iter().enumerate().map(|(index, request)| async move {
+            let id = request.id.unwrap_or_else(|| index.to_string());
+            let call_started = Instant::now();
+            let call = async {
+                let server_id = match request.server_id {
+                    Some(server_id) => server_id,
+                    None => Self::find_server_for_tool(window, &request.name)
+                        .await
+                        .ok_or_else(|| format!("No connected MCP server provides tool: {}", request.name))?,
+                };
+                let result = Self::call_tool(window, server_id, &request.name, request.arguments).await;
+                Ok::<_, String>((server_id, result))
+            };
+
+            let (server_id, status, result, error) = match tokio::time::timeout_at(deadline, call).await {
+                Ok(Ok((server_id, result))) => {
+                    let (status, result, error) = call_status(result);
+                    (Some(server_id), status, result, error)
+                }
+                Ok(Err(e)) => (None, McpToolCallStatus::Error, None, Some(e)),
+                Err(_) => (
+                    request.server_id,
+                    McpToolCallStatus::Timeout,
+                    None,
+                    Some(format!("Timed out after {} ms", timeout.as_millis())),
+                ),
+            };
+            McpToolCallOutcome {
+                id,
+                server_id,
+                name: request.name,
+                status,
+                result,
+                error,
+                duration_ms: call_started.elapsed().as_millis() as u64,
+            }
+        });
+        let results = futures::future::join_all(calls).await;
+
+        let count = |status: McpToolCallStatus| results.iter().filter(|r| r.status == status).count();
+        let report = McpParallelCallReport {
+            succeeded: count(McpToolCallStatus::Ok),
+            failed: count(McpToolCallStatus::Error),
+            timed_out: count(McpToolCallStatus::Timeout),
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            results,
+        };
+        println!(
+            "[MCP Registry] Parallel calls: {} ok, {} failed, {} timed out ({} ms)",
+            report.succeeded, report.failed, report.timed_out, report.elapsed_ms
+        );
+        Ok(report)
     }
 
     /// Notion MCP 설정 저장 (URL + Auth Token)
@@ -218,9 +511,9 @@ impl McpRegistry {
             .await
     }
 
-    /// 도구 이름으로 해당 MCP 서버 찾기
-    pub async fn find_server_for_tool(tool_name: &str) -> Option<McpServerId> {
-        for server_id in Self::supported_servers() {
+    /// 도구 이름으로 해당 MCP 서버 찾기 (창의 활성 프로젝트 범위 내)
+    pub async fn find_server_for_tool(window: &str, tool_name: &str) -> Option<McpServerId> {
+        for server_id in Self::scoped_servers(window) {
             let tools = Self::get_tools(server_id).await;
             if tools.iter().any(|t| t.name == tool_name) {
                 return Some(server_id);
@@ -230,3 +523,67 @@ impl McpRegistry {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_result(text: &str, is_error: bool) -> McpToolResult {
+        McpToolResult {
+            content: vec![McpContent::Text { text: text.to_string() }],
+            is_error,
+        }
+    }
+
+    #[test]
+    fn test_error_results_count_as_failures() {
+        let (status, result, error) = call_status(Ok(text_result("Page not found", true)));
+        assert_eq!(status, McpToolCallStatus::Error);
+        assert!(result.is_some_and(|r| r.is_error));
+        assert_eq!(error.as_deref(), Some("Page not found"));
+
+        let (status, _, error) = call_status(Ok(text_result("ok", false)));
+        assert_eq!((status, error), (McpToolCallStatus::Ok, None));
+        let (status, _, error) = call_status(Err("Not connected".to_string()));
+        assert_eq!((status, error.as_deref()), (McpToolCallStatus::Error, Some("Not connected")));
+    }
+
+    #[tokio::test]
+    async fn test_call_tools_parallel_reports_each_call() {
+        // 캐시된 조회 결과는 연결 없이 돌려받음
+        let arguments: HashMap<String, serde_json::Value> = [("pageId".to_string(), serde_json::json!("42"))].into();
+        let key = cache::cache_key(McpServerId::Atlassian, "getConfluencePage", Some(&arguments));
+        cache::record(key, McpServerId::Atlassian, "getConfluencePage", &text_result("Page 42", false));
+
+        let requests = vec![
+            McpToolCallRequest {
+                id: Some("page".to_string()),
+                server_id: Some(McpServerId::Atlassian),
+                name: "getConfluencePage".to_string(),
+                arguments: Some(arguments),
+            },
+            McpToolCallRequest {
+                id: None,
+                server_id: None,
+                name: "unknownTool".to_string(),
+                arguments: None,
+            },
+        ];
+        let report = McpRegistry::call_tools_parallel("test-parallel", requests, None).await.unwrap();
+        assert_eq!((report.succeeded, report.failed, report.timed_out), (1, 1, 0));
+        assert_eq!(report.results[0].id, "page");
+        assert_eq!(report.results[0].status, McpToolCallStatus::Ok);
+        assert_eq!(report.results[1].id, "1");
+        assert_eq!(report.results[1].status, McpToolCallStatus::Error);
+        assert!(report.results[1].error.as_deref().is_some_and(|e| e.contains("unknownTool")));
+
+        let too_many = (0..=MAX_PARALLEL_TOOL_CALLS)
+            .map(|i| McpToolCallRequest {
+                id: None,
+                server_id: None,
+                name: format!("tool{i}"),
+                arguments: None,
+            })
+            .collect();
+        assert!(McpRegistry::call_tools_parallel("test-parallel", too_many, None).await.is_err());
+    }
+}
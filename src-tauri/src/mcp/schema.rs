@@ -0,0 +1,237 @@
+//! MCP 도구 인자 검증
+//!
+//! 도구가 알려준 `inputSchema`(JSON Schema)로 호출 전에 인자를 확인해,
+//! 서버의 알아보기 어려운 오류 대신 빠진/잘못된 필드를 바로 알려줍니다.
+//! - 자주 쓰이는 키워드만 지원합니다: type, required, properties, additionalProperties(false),
+//!   items, enum, const, minLength/maxLength, minimum/maximum, minItems/maxItems
+//! - 모르는 키워드($ref, oneOf 등)는 통과시킵니다 (최종 검증은 서버 몫).
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// 오류를 너무 많이 나열하지 않도록 제한
+const MAX_ERRORS: usize = 10;
+
+/// 인자 검증, 문제가 있으면 필드별 오류 목록 반환
+pub fn validate_arguments(schema: &Value, arguments: Option<&HashMap<String, Value>>) -> Result<(), Vec<String>> {
+    let args = Value::Object(
+        arguments
+            .into_iter()
+            .flatten()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+    );
+    let mut errors = Vec::new();
+    validate(schema, &args, "", &mut errors);
+    errors.truncate(MAX_ERRORS);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn field(path: &str) -> String {
+    if path.is_empty() {
+        "arguments".to_string()
+    } else {
+        format!("`{}`", path)
+    }
+}
+
+fn child(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn validate(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    if errors.len() >= MAX_ERRORS {
+        return;
+    }
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| type_matches(t, value)) {
+        errors.push(format!(
+            "{} must be {} (got {})",
+            field(path),
+            types.join(" or "),
+            type_name(value)
+        ));
+        return;
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let list: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            errors.push(format!("{} must be one of: {}", field(path), list.join(", ")));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            errors.push(format!("{} must be {}", field(path), expected));
+        }
+    }
+
+    match value {
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if len < min {
+                    errors.push(format!("{} must be at least {} characters", field(path), min));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if len > max {
+                    errors.push(format!("{} must be at most {} characters", field(path), max));
+                }
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if n < min {
+                    errors.push(format!("{} must be >= {}", field(path), min));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if n > max {
+                    errors.push(format!("{} must be <= {}", field(path), max));
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if (items.len() as u64) < min {
+                    errors.push(format!("{} must have at least {} items", field(path), min));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if items.len() as u64 > max {
+                    errors.push(format!("{} must have at most {} items", field(path), max));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate(item_schema, item, &format!("{}[{}]", path, i), errors);
+                }
+            }
+        }
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if map.get(key).is_none_or(Value::is_null) {
+                        errors.push(format!("missing required field `{}`", child(path, key)));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, item) in map {
+                match properties.and_then(|p| p.get(key)) {
+                    Some(prop_schema) => {
+                        // 선택 필드에 null을 보내는 경우가 많아 허용
+                        if !item.is_null() {
+                            validate(prop_schema, item, &child(path, key), errors);
+                        }
+                    }
+                    None => {
+                        if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+                            errors.push(format!("unknown field `{}`", child(path, key)));
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_reports_missing_and_invalid_fields() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "cloudId": { "type": "string" },
+                "pageId": { "type": "string", "minLength": 1 },
+                "limit": { "type": "integer", "minimum": 1, "maximum": 50 },
+                "format": { "enum": ["markdown", "html"] }
+            },
+            "required": ["cloudId", "pageId"],
+            "additionalProperties": false
+        });
+        let errors = validate_arguments(
+            &schema,
+            Some(&args(json!({ "pageId": 123, "limit": 0, "format": "pdf", "extra": true }))),
+        )
+        .unwrap_err();
+        assert!(errors.contains(&"missing required field `cloudId`".to_string()));
+        assert!(errors.contains(&"`pageId` must be string (got number)".to_string()));
+        assert!(errors.contains(&"`limit` must be >= 1".to_string()));
+        assert!(errors.contains(&"`format` must be one of: \"markdown\", \"html\"".to_string()));
+        assert!(errors.contains(&"unknown field `extra`".to_string()));
+    }
+
+    #[test]
+    fn test_accepts_valid_arguments() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "query": { "type": "string" },
+                "filters": { "type": "array", "items": { "type": "object", "required": ["key"] } },
+                "cursor": { "type": ["string", "null"] }
+            },
+            "required": ["query"]
+        });
+        let ok = args(json!({ "query": "glossary", "filters": [{ "key": "space" }], "cursor": null }));
+        assert!(validate_arguments(&schema, Some(&ok)).is_ok());
+
+        let bad = args(json!({ "query": "glossary", "filters": [{ "value": 1 }] }));
+        assert_eq!(
+            validate_arguments(&schema, Some(&bad)).unwrap_err(),
+            vec!["missing required field `filters[0].key`".to_string()]
+        );
+        assert!(validate_arguments(&schema, None).is_err());
+    }
+}
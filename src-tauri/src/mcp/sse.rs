@@ -0,0 +1,138 @@
+//! SSE (Server-Sent Events) 파서
+//!
+//! 응답 본문을 조각 단위로 받아 이벤트로 나눕니다.
+//! - 이벤트 하나(대기 중인 줄 + `data`)가 최대 크기를 넘으면 오류로 끊어, 서버가 끝없는 이벤트를 보내도 메모리가 늘지 않게 합니다.
+//! - `event`/`data` 필드만 쓰고 `id`/`retry`/주석은 무시합니다 (재연결은 클라이언트가 직접 함).
+
+/// SSE 이벤트 1건
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    /// `event` 필드 (없으면 `message`)
+    pub event: String,
+    pub data: String,
+}
+
+/// 조각 단위 SSE 파서
+#[derive(Debug)]
+pub struct SseParser {
+    /// 아직 줄바꿈을 받지 못한 바이트
+    line: Vec<u8>,
+    event: String,
+    data: String,
+    has_data: bool,
+    max_event_bytes: usize,
+}
+
+impl SseParser {
+    pub fn new(max_event_bytes: usize) -> Self {
+        Self {
+            line: Vec::new(),
+            event: String::new(),
+            data: String::new(),
+            has_data: false,
+            max_event_bytes,
+        }
+    }
+
+    /// 받은 조각을 넣고 완성된 이벤트를 반환 (이벤트가 최대 크기를 넘으면 오류)
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<SseEvent>, String> {
+        let mut events = Vec::new();
+        let mut rest = chunk;
+        while let Some(pos) = rest.iter().position(|b| *b == b'\n') {
+            self.line.extend_from_slice(&rest[..pos]);
+            rest = &rest[pos + 1..];
+            let mut line = std::mem::take(&mut self.line);
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            if let Some(event) = self.process_line(&String::from_utf8_lossy(&line)) {
+                events.push(event);
+            }
+            self.check_size()?;
+        }
+        self.line.extend_from_slice(rest);
+        self.check_size()?;
+        Ok(events)
+    }
+
+    fn check_size(&self) -> Result<(), String> {
+        if self.line.len() + self.data.len() > self.max_event_bytes {
+            return Err(format!(
+                "SSE event is larger than {} MB",
+                self.max_event_bytes / (1024 * 1024)
+            ));
+        }
+        Ok(())
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = value.to_string(),
+            "data" => {
+                if self.has_data {
+                    self.data.push('\n');
+                }
+                self.data.push_str(value);
+                self.has_data = true;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = std::mem::take(&mut self.event);
+        let data = std::mem::take(&mut self.data);
+        if !std::mem::take(&mut self.has_data) {
+            return None;
+        }
+        Some(SseEvent {
+            event: if event.is_empty() { "message".to_string() } else { event },
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_events_across_chunks_and_caps_size() {
+        let mut parser = SseParser::new(64);
+        assert!(parser.feed(b"event: endpoint\r\ndata: /v1/mes").unwrap().is_empty());
+        let events = parser
+            .feed(b"sages?s=1\r\n\r\n: ping\n\ndata: {\"a\":1}\ndata: {\"b\":2}\n\n")
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: "endpoint".to_string(),
+                    data: "/v1/messages?s=1".to_string()
+                },
+                SseEvent {
+                    event: "message".to_string(),
+                    data: "{\"a\":1}\n{\"b\":2}".to_string()
+                },
+            ]
+        );
+
+        // 줄바꿈 없이 계속 오는 이벤트도 최대 크기에서 끊음
+        assert!(parser.feed(&[b'x'; 40]).is_ok());
+        assert!(parser.feed(&[b'x'; 40]).is_err());
+        let mut parser = SseParser::new(64);
+        assert!(parser.feed(format!("data: {}\n", "y".repeat(40)).as_bytes()).is_ok());
+        assert!(parser.feed(format!("data: {}\n", "y".repeat(40)).as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! Connectivity Monitor & Offline Mode
+//!
+//! 네트워크가 필요한 명령(MCP, Notion, Confluence, 커넥터, 클라우드 백업, TMS, 업데이트)이
+//! 연결이 없을 때 타임아웃까지 기다리지 않고 바로 `OFFLINE` 오류로 끝나게 합니다.
+//! - 설정(`offlineMode`)을 켜면 실제 연결 상태와 관계없이 오프라인으로 취급합니다.
+//! - 모니터가 주기적으로 외부 호스트에 HEAD 요청을 보내 연결 상태를 갱신하고, 바뀌면 `connectivity-changed` 이벤트를 보냅니다.
+//!   요청은 공용 HTTP 클라이언트(`crate::http`)로 보내므로 프록시 설정을 따릅니다 (직접 연결이 막힌 사내망 대응).
+//! - 오프라인 중 요청된 작업(클라우드 백업 등)은 대기열에 넣어 두고, 다시 연결되면 해당 스케줄러가 처리합니다.
+
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::watch;
+
+use crate::error::{CommandError, CommandResult, ErrorCode};
+
+/// 연결 상태 변경 이벤트 (payload: `NetworkStatus`)
+pub const CONNECTIVITY_CHANGED_EVENT: &str = "connectivity-changed";
+
+/// 연결 확인 대상 (하나라도 응답하면 온라인, 상태 코드는 보지 않음)
+const PROBE_TARGETS: &[&str] = &["https://api.notion.com", "https://api.atlassian.com", "https://1.1.1.1"];
+
+/// 대상 1개당 응답 대기 시간
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 확인 간격 (온라인일 때 / 연결이 끊겼을 때)
+const PROBE_INTERVAL_ONLINE: Duration = Duration::from_secs(60);
+const PROBE_INTERVAL_OFFLINE: Duration = Duration::from_secs(15);
+
+/// 대기열 최대 길이 (넘으면 오래된 것부터 버림)
+const MAX_QUEUED_JOBS: usize = 50;
+
+static OFFLINE_MODE: AtomicBool = AtomicBool::new(false);
+/// 마지막 확인 결과 (확인 전에는 연결된 것으로 간주)
+static REACHABLE: AtomicBool = AtomicBool::new(true);
+/// 마지막 확인 시각 (Unix ms, 0이면 확인 전)
+static LAST_CHECKED_AT: AtomicI64 = AtomicI64::new(0);
+
+/// 온라인 여부 변경 알림 (스케줄러가 다시 연결될 때 바로 대기열을 처리하도록)
+static ONLINE: Lazy<watch::Sender<bool>> = Lazy::new(|| watch::channel(true).0);
+
+static QUEUE: Mutex<Vec<QueuedJob>> = Mutex::new(Vec::new());
+
+/// 다시 연결되면 실행할 작업 1건
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedJob {
+    /// 작업 종류 (예: "cloudBackup")
+    pub kind: String,
+    /// 대상 (예: 커넥터 ID)
+    pub target: String,
+    /// 대기열에 넣은 시각 (Unix ms)
+    pub queued_at: i64,
+}
+
+/// 연결 상태
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkStatus {
+    /// 네트워크 명령을 실행할 수 있는지 (오프라인 모드가 꺼져 있고 연결됨)
+    pub online: bool,
+    pub offline_mode: bool,
+    /// 마지막 연결 확인 결과
+    pub reachable: bool,
+    pub last_checked_at: Option<i64>,
+    pub queued: Vec<QueuedJob>,
+}
+
+pub fn is_online() -> bool {
+    !OFFLINE_MODE.load(Ordering::Relaxed) && REACHABLE.load(Ordering::Relaxed)
+}
+
+/// 오프라인이면 `OFFLINE` 오류 (`feature`는 메시지에 넣을 기능 이름)
+pub fn ensure_online(feature: &str) -> CommandResult<()> {
+    if is_online() {
+        return Ok(());
+    }
+    let reason = if OFFLINE_MODE.load(Ordering::Relaxed) {
+        "offline mode is on"
+    } else {
+        "no network connection"
+    };
+    Err(CommandError::new(
+        ErrorCode::Offline,
+        format!("{} is unavailable: {}", feature, reason),
+    ))
+}
+
+fn publish() {
+    let online = is_online();
+    ONLINE.send_if_modified(|current| {
+        if *current == online {
+            return false;
+        }
+        *current = online;
+        true
+    });
+}
+
+/// 설정의 오프라인 모드 적용
+pub fn set_offline_mode(enabled: bool) {
+    OFFLINE_MODE.store(enabled, Ordering::Relaxed);
+    publish();
+}
+
+fn set_reachable(reachable: bool) {
+    REACHABLE.store(reachable, Ordering::Relaxed);
+    LAST_CHECKED_AT.store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+    publish();
+}
+
+/// 온라인 여부 변경 구독 (현재 값은 `borrow()`)
+pub fn subscribe() -> watch::Receiver<bool> {
+    ONLINE.subscribe()
+}
+
+/// 다시 연결되면 실행할 작업 추가 (같은 종류/대상이 이미 있으면 추가하지 않음)
+pub fn enqueue(kind: &str, target: &str) -> bool {
+    let mut queue = QUEUE.lock().unwrap_or_else(|e| e.into_inner());
+    if queue.iter().any(|j| j.kind == kind && j.target == target) {
+        return false;
+    }
+    if queue.len() >= MAX_QUEUED_JOBS {
+        queue.remove(0);
+    }
+    queue.push(QueuedJob {
+        kind: kind.to_string(),
+        target: target.to_string(),
+        queued_at: chrono::Utc::now().timestamp_millis(),
+    });
+    true
+}
+
+/// 해당 종류의 대기 작업을 꺼냄 (넣은 순서)
+pub fn take_queued(kind: &str) -> Vec<QueuedJob> {
+    let mut queue = QUEUE.lock().unwrap_or_else(|e| e.into_inner());
+    let (taken, rest) = queue.drain(..).partition(|j| j.kind == kind);
+    *queue = rest;
+    taken
+}
+
+pub fn status() -> NetworkStatus {
+    let last_checked_at = LAST_CHECKED_AT.load(Ordering::Relaxed);
+    NetworkStatus {
+        online: is_online(),
+        offline_mode: OFFLINE_MODE.load(Ordering::Relaxed),
+        reachable: REACHABLE.load(Ordering::Relaxed),
+        last_checked_at: (last_checked_at > 0).then_some(last_checked_at),
+        queued: QUEUE.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+    }
+}
+
+/// 확인 대상 중 하나라도 응답하는지 (프록시 설정 적용)
+async fn probe() -> bool {
+    let client = match crate::http::client() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("[Network] {}", e);
+            return false;
+        }
+    };
+    let attempts = PROBE_TARGETS.iter().map(|target| {
+        let request = client.head(*target).timeout(PROBE_TIMEOUT);
+        async move { request.send().await.is_ok() }
+    });
+    futures::future::join_all(attempts).await.into_iter().any(|ok| ok)
+}
+
+/// 지금 연결 확인 (오프라인 모드여도 실제 연결 상태는 갱신)
+pub async fn check_now() -> NetworkStatus {
+    set_reachable(probe().await);
+    status()
+}
+
+/// 연결 상태 모니터 시작 (앱 시작 시 한 번)
+/// - 오프라인 모드 중에는 확인하지 않습니다 (끄면 다음 확인까지 마지막 결과를 사용).
+pub fn spawn_connectivity_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut online_rx = subscribe();
+        if !OFFLINE_MODE.load(Ordering::Relaxed) {
+            set_reachable(probe().await);
+        }
+        loop {
+            let interval = if REACHABLE.load(Ordering::Relaxed) {
+                PROBE_INTERVAL_ONLINE
+            } else {
+                PROBE_INTERVAL_OFFLINE
+            };
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {
+                    if !OFFLINE_MODE.load(Ordering::Relaxed) {
+                        set_reachable(probe().await);
+                    }
+                }
+                changed = online_rx.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                    let online = *online_rx.borrow_and_update();
+                    println!("[Network] {}", if online { "Online" } else { "Offline" });
+                    let _ = app.emit(CONNECTIVITY_CHANGED_EVENT, status());
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_dedupes_and_takes_by_kind() {
+        assert!(enqueue("cloudBackup", "google_drive"));
+        assert!(!enqueue("cloudBackup", "google_drive"));
+        assert!(enqueue("other", "x"));
+
+        let taken = take_queued("cloudBackup");
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].target, "google_drive");
+        assert!(take_queued("cloudBackup").is_empty());
+        assert_eq!(take_queued("other").len(), 1);
+    }
+}
@@ -3,15 +3,24 @@
 //! Notion API를 직접 호출하여 페이지 검색, 조회 등을 수행합니다.
 //! 토큰은 SecretManager vault에 저장됩니다.
 
+use crate::http::RetryPolicy;
 use crate::notion::types::*;
 use crate::secrets::SECRETS;
 use once_cell::sync::Lazy;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 const NOTION_API_BASE: &str = "https://api.notion.com/v1";
 const NOTION_VERSION: &str = "2022-06-28";
 
+/// 페이지 본문을 읽을 때 따라 들어갈 하위 블록 깊이
+const MAX_BLOCK_DEPTH: usize = 3;
+
+/// 페이지 본문 하나에 보낼 최대 블록 조회 요청 수 (rate limit 보호)
+const MAX_BLOCK_REQUESTS: usize = 50;
+
 // Vault 저장 키 (SecretManager용)
 const VAULT_NOTION_TOKEN: &str = "notion/integration_token";
 
@@ -22,15 +31,12 @@ pub static NOTION_CLIENT: Lazy<NotionClient> = Lazy::new(NotionClient::new);
 pub struct NotionClient {
     /// Integration Token (캐시)
     token: Arc<RwLock<Option<String>>>,
-    /// HTTP 클라이언트
-    http: reqwest::Client,
 }
 
 impl NotionClient {
     pub fn new() -> Self {
         Self {
             token: Arc::new(RwLock::new(None)),
-            http: reqwest::Client::new(),
         }
     }
 
@@ -93,15 +99,6 @@ impl NotionClient {
         println!("[Notion] Token cleared");
     }
 
-    /// API 요청 공통 헤더 설정
-    fn build_request(&self, token: &str) -> reqwest::RequestBuilder {
-        self.http
-            .get("") // placeholder, will be overwritten
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Notion-Version", NOTION_VERSION)
-            .header("Content-Type", "application/json")
-    }
-
     /// 검색 API 호출
     pub async fn search(
         &self,
@@ -131,32 +128,28 @@ impl NotionClient {
 
         println!("[Notion] Searching: {:?}", request_body);
 
-        let response = self
-            .http
+        let request = crate::http::client()?
             .post(&url)
             .header("Authorization", format!("Bearer {}", token))
             .header("Notion-Version", NOTION_VERSION)
             .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
+            .json(&request_body);
+        let response = crate::http::send_read_with_retry(request, RetryPolicy::NOTION)
             .await
             .map_err(|e| format!("Failed to send request: {}", e))?;
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| format!("Failed to read response: {}", e))?;
+        let body = crate::http::read_text(response).await?;
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<NotionError>(&body) {
                 return Err(format!("Notion API error: {} ({})", error.message, error.code));
             }
-            return Err(format!("Request failed with status {}: {}", status, body));
+            return Err(format!("Request failed with status {}: {}", status, crate::http::preview(&body)));
         }
 
         serde_json::from_str(&body)
-            .map_err(|e| format!("Failed to parse response: {} - {}", e, body))
+            .map_err(|e| format!("Failed to parse response: {} - {}", e, crate::http::preview(&body)))
     }
 
     /// 페이지 조회 API 호출
@@ -171,68 +164,74 @@ impl NotionClient {
 
         println!("[Notion] Getting page: {}", id);
 
-        let response = self
-            .http
+        let request = crate::http::client()?
             .get(&url)
             .header("Authorization", format!("Bearer {}", token))
-            .header("Notion-Version", NOTION_VERSION)
-            .send()
+            .header("Notion-Version", NOTION_VERSION);
+        let response = crate::http::send_with_retry(request, RetryPolicy::NOTION)
             .await
             .map_err(|e| format!("Failed to send request: {}", e))?;
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| format!("Failed to read response: {}", e))?;
+        let body = crate::http::read_text(response).await?;
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<NotionError>(&body) {
                 return Err(format!("Notion API error: {} ({})", error.message, error.code));
             }
-            return Err(format!("Request failed with status {}: {}", status, body));
+            return Err(format!("Request failed with status {}: {}", status, crate::http::preview(&body)));
         }
 
         serde_json::from_str(&body)
-            .map_err(|e| format!("Failed to parse response: {} - {}", e, body))
+            .map_err(|e| format!("Failed to parse response: {} - {}", e, crate::http::preview(&body)))
     }
 
     /// 페이지 블록(내용) 조회 API 호출
     pub async fn get_blocks(&self, block_id: &str, page_size: Option<u32>) -> Result<BlocksResponse, String> {
+        self.get_blocks_from(block_id, None, page_size).await
+    }
+
+    /// 블록 하위 목록 조회 (`start_cursor`부터, 전체를 읽을 때 `next_cursor`로 반복)
+    pub async fn get_blocks_from(
+        &self,
+        block_id: &str,
+        start_cursor: Option<String>,
+        page_size: Option<u32>,
+    ) -> Result<BlocksResponse, String> {
         let token = self
             .load_token()
             .await
             .ok_or("No Notion token. Please set your Integration Token first.")?;
 
         let id = Self::normalize_id(block_id);
-        let url = format!("{}/blocks/{}/children?page_size={}", NOTION_API_BASE, id, page_size.unwrap_or(100));
+        let mut url = format!("{}/blocks/{}/children?page_size={}", NOTION_API_BASE, id, page_size.unwrap_or(100));
+        if let Some(cursor) = start_cursor {
+            url.push_str("&start_cursor=");
+            url.push_str(&urlencoding::encode(&cursor));
+        }
 
         println!("[Notion] Getting blocks: {}", id);
 
-        let response = self
-            .http
+        let request = crate::http::client()?
             .get(&url)
             .header("Authorization", format!("Bearer {}", token))
-            .header("Notion-Version", NOTION_VERSION)
-            .send()
+            .header("Notion-Version", NOTION_VERSION);
+        let response = crate::http::send_with_retry(request, RetryPolicy::NOTION)
             .await
             .map_err(|e| format!("Failed to send request: {}", e))?;
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| format!("Failed to read response: {}", e))?;
+        let body = crate::http::read_text(response).await?;
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<NotionError>(&body) {
                 return Err(format!("Notion API error: {} ({})", error.message, error.code));
             }
-            return Err(format!("Request failed with status {}: {}", status, body));
+            return Err(format!("Request failed with status {}: {}", status, crate::http::preview(&body)));
         }
 
         serde_json::from_str(&body)
-            .map_err(|e| format!("Failed to parse response: {} - {}", e, body))
+            .map_err(|e| format!("Failed to parse response: {} - {}", e, crate::http::preview(&body)))
     }
 
     /// 데이터베이스 쿼리 API 호출
@@ -241,6 +240,17 @@ impl NotionClient {
         database_id: &str,
         filter: Option<serde_json::Value>,
         page_size: Option<u32>,
+    ) -> Result<DatabaseQueryResponse, String> {
+        self.query_database_from(database_id, filter, None, page_size).await
+    }
+
+    /// 데이터베이스 쿼리 API 호출 (`start_cursor`부터, 전체 행을 읽을 때 `next_cursor`로 반복)
+    pub async fn query_database_from(
+        &self,
+        database_id: &str,
+        filter: Option<serde_json::Value>,
+        start_cursor: Option<String>,
+        page_size: Option<u32>,
     ) -> Result<DatabaseQueryResponse, String> {
         let token = self
             .load_token()
@@ -253,38 +263,34 @@ impl NotionClient {
         let request_body = DatabaseQueryRequest {
             filter,
             sorts: None,
-            start_cursor: None,
+            start_cursor,
             page_size: page_size.or(Some(20)),
         };
 
         println!("[Notion] Querying database: {}", id);
 
-        let response = self
-            .http
+        let request = crate::http::client()?
             .post(&url)
             .header("Authorization", format!("Bearer {}", token))
             .header("Notion-Version", NOTION_VERSION)
             .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
+            .json(&request_body);
+        let response = crate::http::send_read_with_retry(request, RetryPolicy::NOTION)
             .await
             .map_err(|e| format!("Failed to send request: {}", e))?;
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| format!("Failed to read response: {}", e))?;
+        let body = crate::http::read_text(response).await?;
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<NotionError>(&body) {
                 return Err(format!("Notion API error: {} ({})", error.message, error.code));
             }
-            return Err(format!("Request failed with status {}: {}", status, body));
+            return Err(format!("Request failed with status {}: {}", status, crate::http::preview(&body)));
         }
 
         serde_json::from_str(&body)
-            .map_err(|e| format!("Failed to parse response: {} - {}", e, body))
+            .map_err(|e| format!("Failed to parse response: {} - {}", e, crate::http::preview(&body)))
     }
 
     /// ID 정규화 (URL에서 추출, 하이픈 제거 등)
@@ -321,6 +327,58 @@ impl NotionClient {
             .join("\n")
     }
 
+    /// 페이지 본문 전체를 텍스트로 변환 (다음 페이지 커서와 하위 블록까지 따라감)
+    /// - `max_bytes`를 넘거나 요청 수 한도에 닿으면 멈추고 truncated = true
+    pub async fn page_text(&self, page_id: &str, max_bytes: usize) -> Result<(String, bool), String> {
+        let mut out = PageText {
+            lines: Vec::new(),
+            bytes: 0,
+            requests: 0,
+            max_bytes,
+            truncated: false,
+        };
+        self.collect_blocks(page_id, 0, &mut out).await?;
+        Ok((out.lines.join("\n"), out.truncated))
+    }
+
+    fn collect_blocks<'a>(
+        &'a self,
+        block_id: &'a str,
+        depth: usize,
+        out: &'a mut PageText,
+    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut cursor: Option<String> = None;
+            loop {
+                if out.is_full() || out.requests >= MAX_BLOCK_REQUESTS {
+                    out.truncated = true;
+                    return Ok(());
+                }
+                out.requests += 1;
+                let page = self.get_blocks_from(block_id, cursor.take(), Some(100)).await?;
+                for block in &page.results {
+                    if let Some(text) = Self::block_to_text(block) {
+                        out.push(depth, text);
+                    }
+                    // 하위 페이지/데이터베이스는 별도 문서이므로 들어가지 않음
+                    let nested = block.has_children
+                        && depth < MAX_BLOCK_DEPTH
+                        && !matches!(block.block_type.as_str(), "child_page" | "child_database");
+                    if nested {
+                        self.collect_blocks(&block.id, depth + 1, out).await?;
+                    }
+                    if out.is_full() {
+                        return Ok(());
+                    }
+                }
+                match page.next_cursor.filter(|_| page.has_more) {
+                    Some(next) => cursor = Some(next),
+                    None => return Ok(()),
+                }
+            }
+        })
+    }
+
     /// 단일 블록을 텍스트로 변환
     fn block_to_text(block: &Block) -> Option<String> {
         let content = &block.content;
@@ -372,6 +430,36 @@ impl NotionClient {
     }
 }
 
+/// `page_text`가 모으는 본문
+struct PageText {
+    lines: Vec<String>,
+    bytes: usize,
+    requests: usize,
+    max_bytes: usize,
+    truncated: bool,
+}
+
+impl PageText {
+    /// 하위 블록은 깊이만큼 들여씀
+    fn push(&mut self, depth: usize, text: String) {
+        let line = if depth == 0 {
+            text
+        } else {
+            let indent = "  ".repeat(depth);
+            text.lines().map(|l| format!("{}{}", indent, l)).collect::<Vec<_>>().join("\n")
+        };
+        self.bytes += line.len() + 1;
+        self.lines.push(line);
+    }
+
+    fn is_full(&mut self) -> bool {
+        if self.bytes > self.max_bytes {
+            self.truncated = true;
+        }
+        self.truncated
+    }
+}
+
 impl Default for NotionClient {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,1043 @@
+//! OAuth 2.1 PKCE Engine
+//!
+//! 브라우저 인증 → 로컬 콜백 → 토큰 교환/갱신/영속화를 서버와 무관하게 처리하는 공용 엔진입니다.
+//! 서버별 차이(엔드포인트, scope, 클라이언트 등록 방식, vault 키)는 `PkceFlowConfig`로 지정합니다.
+//! (예: Atlassian MCP는 `mcp::oauth::AtlassianOAuth`)
+//!
+//! 토큰은 SecretManager vault에 영속화되어 앱 재시작 후에도 유지됩니다.
+//!
+//! 로컬 콜백 서버는 같은 PC의 다른 프로세스도 접근할 수 있으므로:
+//! - 요청 헤더 크기/읽기 시간을 제한하고 GET만 받습니다.
+//! - redirect 경로에 인증 시도마다 새로 만든 일회용 nonce를 넣어, 경로를 모르는 요청은 진행 중인 세션에 손대지 못합니다.
+//! - state는 상수 시간으로 비교하고, 처리가 끝난 뒤 같은 경로로 다시 들어온 요청은 거부합니다.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::{oneshot, Mutex};
+use url::Url;
+
+use crate::secrets::SECRETS;
+
+// 콜백 서버 포트 범위 (앞에서부터 비어 있는 포트 사용)
+const DEFAULT_REDIRECT_PORTS: RangeInclusive<u16> = 23456..=23465;
+// 포트 범위 지정 환경 변수 (예: "23456-23465" 또는 "23456")
+const REDIRECT_PORTS_ENV: &str = "ITE_OAUTH_CALLBACK_PORTS";
+
+// 콜백 요청 헤더(요청 라인 포함) 최대 크기
+const MAX_CALLBACK_REQUEST_BYTES: u64 = 16 * 1024;
+// 연결 1개의 요청 헤더를 기다리는 최대 시간
+const CALLBACK_READ_TIMEOUT_SECS: u64 = 10;
+// 콜백 처리 후 중복 요청(새로고침, 재시도)을 거부하며 서버를 유지하는 시간
+const CALLBACK_GRACE_SECS: u64 = 10;
+
+// 브라우저 인증을 기다리는 최대 시간
+const AUTH_FLOW_TIMEOUT_SECS: u64 = 300;
+
+// 토큰 만료 전 갱신 여유 시간 (5분)
+const TOKEN_REFRESH_MARGIN_SECS: i64 = 300;
+
+/// OAuth 토큰 (영속화 가능)
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OAuthToken {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: Option<i64>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    /// 토큰 발급 시점 (Unix timestamp, 초)
+    #[serde(default)]
+    pub issued_at: i64,
+}
+
+impl OAuthToken {
+    /// 토큰이 만료되었는지 (또는 곧 만료될지) 확인
+    pub fn is_expired(&self) -> bool {
+        if let Some(expires_in) = self.expires_in {
+            let now = chrono::Utc::now().timestamp();
+            let expires_at = self.issued_at + expires_in;
+            // 만료 5분 전부터 갱신 필요
+            now >= expires_at - TOKEN_REFRESH_MARGIN_SECS
+        } else {
+            // expires_in이 없으면 만료되지 않은 것으로 간주
+            false
+        }
+    }
+
+    /// 남은 유효 시간 (초)
+    pub fn remaining_seconds(&self) -> Option<i64> {
+        self.expires_in.map(|exp| {
+            let now = chrono::Utc::now().timestamp();
+            let expires_at = self.issued_at + exp;
+            (expires_at - now).max(0)
+        })
+    }
+}
+
+/// Dynamic Client Registration 응답
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ClientRegistrationResponse {
+    client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_id_issued_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret_expires_at: Option<i64>,
+}
+
+/// 등록된 클라이언트 정보 (영속화 가능)
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct RegisteredClient {
+    client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    /// 등록할 때 보낸 redirect URI (예전 버전에서 저장한 클라이언트는 비어 있음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    redirect_uris: Vec<String>,
+}
+
+/// 클라이언트 등록 방식
+#[derive(Debug, Clone)]
+pub enum ClientRegistration {
+    /// Dynamic Client Registration (RFC 7591)
+    /// - redirect URI에 인증 시도마다 바뀌는 nonce가 들어가므로 시도마다 새로 등록합니다.
+    Dynamic {
+        registration_url: String,
+        client_name: String,
+    },
+    /// 미리 발급받은 클라이언트
+    Static {
+        client_id: String,
+        client_secret: Option<String>,
+    },
+}
+
+/// 서버별 OAuth 설정
+#[derive(Debug, Clone)]
+pub struct PkceFlowConfig {
+    /// 로그에 표시할 이름 (예: "Atlassian")
+    pub name: String,
+    pub authorize_url: String,
+    pub token_url: String,
+    pub registration: ClientRegistration,
+    pub scopes: Vec<String>,
+    /// authorize URL에 덧붙일 추가 파라미터 (예: `audience`, `prompt`)
+    pub extra_authorize_params: Vec<(String, String)>,
+    /// 토큰 vault 키
+    pub token_key: String,
+    /// 등록된 클라이언트 vault 키 (`Dynamic`일 때만 사용)
+    pub client_key: String,
+}
+
+fn callback_redirect_uri(port: u16, nonce: &str) -> String {
+    format!("http://localhost:{}/callback/{}", port, nonce)
+}
+
+/// 콜백 서버가 받은 요청 경로 분류
+#[derive(Debug, PartialEq, Eq)]
+enum CallbackRoute<'a> {
+    /// 이번 인증의 redirect 경로 (쿼리 문자열 포함)
+    Callback(&'a str),
+    /// nonce가 다른 `/callback` 요청
+    UnknownCallback,
+    Other,
+}
+
+fn classify_callback_path<'a>(path: &'a str, nonce: &str) -> CallbackRoute<'a> {
+    let (route, query) = path.split_once('?').unwrap_or((path, ""));
+    let Some(rest) = route.strip_prefix("/callback") else {
+        return CallbackRoute::Other;
+    };
+    if !rest.is_empty() && !rest.starts_with('/') {
+        return CallbackRoute::Other;
+    }
+    match rest.strip_prefix('/') {
+        Some(candidate) if constant_time_eq(candidate.as_bytes(), nonce.as_bytes()) => {
+            CallbackRoute::Callback(query)
+        }
+        _ => CallbackRoute::UnknownCallback,
+    }
+}
+
+/// 길이 외의 정보가 비교 시간으로 새지 않는 바이트 비교 (state/nonce 검증용)
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// 포트 범위 파싱 ("23456-23465" 또는 "23456")
+fn parse_port_range(spec: &str) -> Option<RangeInclusive<u16>> {
+    let (start, end) = match spec.trim().split_once('-') {
+        Some((a, b)) => (a.trim().parse().ok()?, b.trim().parse().ok()?),
+        None => {
+            let port = spec.trim().parse().ok()?;
+            (port, port)
+        }
+    };
+    (start > 0 && start <= end).then_some(start..=end)
+}
+
+/// 콜백 포트 후보 (환경 변수가 없거나 잘못되었으면 기본 범위)
+fn callback_ports() -> RangeInclusive<u16> {
+    std::env::var(REDIRECT_PORTS_ENV)
+        .ok()
+        .and_then(|spec| parse_port_range(&spec))
+        .unwrap_or(DEFAULT_REDIRECT_PORTS)
+}
+
+/// 범위 안에서 비어 있는 포트로 콜백 서버 소켓 열기 (다른 앱이 쓰는 포트는 건너뜀)
+async fn bind_callback_listener() -> Result<(TcpListener, u16), String> {
+    let ports = callback_ports();
+    let mut last_error = None;
+    for port in ports.clone() {
+        match TcpListener::bind(("127.0.0.1", port)).await {
+            Ok(listener) => return Ok((listener, port)),
+            Err(e) => {
+                println!("[OAuth] Callback port {} unavailable: {}", port, e);
+                last_error = Some(e);
+            }
+        }
+    }
+    Err(format!(
+        "Failed to bind callback server on ports {}-{}: {}",
+        ports.start(),
+        ports.end(),
+        last_error.map(|e| e.to_string()).unwrap_or_default()
+    ))
+}
+
+/// 요청 라인과 헤더를 빈 줄까지 읽고 요청 라인 반환 (실패 시 응답할 HTTP 상태)
+/// - `reader`는 최대 크기로 잘라 둔 것이어야 합니다 (한도에 걸리면 줄이 끝나지 않은 채 EOF).
+async fn read_request_head<R>(reader: &mut R) -> Result<String, &'static str>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::AsyncBufReadExt;
+
+    let mut request_line = String::new();
+    match reader.read_line(&mut request_line).await {
+        Ok(0) | Err(_) => return Err("400 Bad Request"),
+        Ok(_) if !request_line.ends_with('\n') => return Err("431 Request Header Fields Too Large"),
+        Ok(_) => {}
+    }
+
+    // HTTP 헤더 모두 읽기 (빈 줄까지)
+    loop {
+        let mut header_line = String::new();
+        match reader.read_line(&mut header_line).await {
+            Ok(0) => return Ok(request_line), // EOF (헤더 없이 끝난 요청)
+            Ok(_) if !header_line.ends_with('\n') => return Err("431 Request Header Fields Too Large"),
+            Ok(_) if header_line.trim().is_empty() => return Ok(request_line), // 헤더 종료
+            Ok(_) => {}
+            Err(_) => return Err("400 Bad Request"),
+        }
+    }
+}
+
+async fn write_empty_response(writer: &mut tokio::net::tcp::OwnedWriteHalf, status: &str) {
+    use tokio::io::AsyncWriteExt;
+
+    let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status);
+    let _ = writer.write_all(response.as_bytes()).await;
+    let _ = writer.shutdown().await;
+}
+
+/// PKCE 검증 데이터
+#[derive(Debug)]
+struct PkceData {
+    code_verifier: String,
+    state: String,
+}
+
+/// PKCE code_verifier 생성
+fn generate_code_verifier() -> String {
+    let mut rng = rand::thread_rng();
+    let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+    URL_SAFE_NO_PAD.encode(&bytes)
+}
+
+/// code_verifier에서 code_challenge 생성 (S256)
+fn generate_code_challenge(verifier: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(verifier.as_bytes());
+    let hash = hasher.finalize();
+    URL_SAFE_NO_PAD.encode(hash)
+}
+
+/// 랜덤 state 생성
+fn generate_state() -> String {
+    let mut rng = rand::thread_rng();
+    let bytes: Vec<u8> = (0..16).map(|_| rng.gen()).collect();
+    URL_SAFE_NO_PAD.encode(&bytes)
+}
+
+/// 브라우저로 열 authorize URL
+fn build_authorize_url(
+    config: &PkceFlowConfig,
+    client_id: &str,
+    redirect_uri: &str,
+    state: &str,
+    code_challenge: &str,
+) -> Result<String, String> {
+    let mut url = Url::parse(&config.authorize_url)
+        .map_err(|e| format!("Invalid authorize URL {}: {}", config.authorize_url, e))?;
+    {
+        let mut query = url.query_pairs_mut();
+        query
+            .append_pair("client_id", client_id)
+            .append_pair("redirect_uri", redirect_uri)
+            .append_pair("response_type", "code");
+        if !config.scopes.is_empty() {
+            query.append_pair("scope", &config.scopes.join(" "));
+        }
+        query
+            .append_pair("state", state)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", "S256");
+        for (key, value) in &config.extra_authorize_params {
+            query.append_pair(key, value);
+        }
+    }
+    Ok(url.into())
+}
+
+/// 토큰 엔드포인트 요청 (authorization_code / refresh_token 공용)
+async fn request_token(
+    token_url: &str,
+    client: &RegisteredClient,
+    params: &[(&str, &str)],
+) -> Result<OAuthToken, String> {
+    let mut form: Vec<(&str, &str)> = params.to_vec();
+    form.push(("client_id", &client.client_id));
+    if let Some(secret) = client.client_secret.as_deref() {
+        form.push(("client_secret", secret));
+    }
+
+    let response = crate::http::client()?
+        .post(token_url)
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| format!("Token request failed: {}", e))?;
+
+    println!("[OAuth] Token response status: {}", response.status());
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Token endpoint returned {}: {}", status, body));
+    }
+
+    let mut token = response
+        .json::<OAuthToken>()
+        .await
+        .map_err(|e| format!("Failed to parse token response: {}", e))?;
+    // 발급 시점 기록
+    token.issued_at = chrono::Utc::now().timestamp();
+    Ok(token)
+}
+
+/// 콜백 결과를 기다리는 쪽으로 보내는 채널 (콜백 서버가 한 번만 꺼내 씀)
+type CallbackSender = Arc<Mutex<Option<oneshot::Sender<Result<String, String>>>>>;
+
+/// 콜백 서버 1회 실행에 필요한 값
+struct CallbackContext {
+    listener: TcpListener,
+    port: u16,
+    nonce: String,
+    redirect_uri: String,
+    token_url: String,
+    client: RegisteredClient,
+}
+
+/// OAuth 2.1 PKCE 인증 엔진 (서버 1개당 1개)
+pub struct PkceFlow {
+    config: PkceFlowConfig,
+    /// 현재 토큰 (있는 경우)
+    token: Arc<Mutex<Option<OAuthToken>>>,
+    /// 동적으로 등록된 클라이언트 정보
+    registered_client: Arc<Mutex<Option<RegisteredClient>>>,
+    /// 진행 중인 OAuth 세션
+    pending_pkce: Arc<Mutex<Option<PkceData>>>,
+    /// OAuth 콜백 수신용
+    callback_tx: CallbackSender,
+    /// 콜백 서버 종료 signal
+    callback_shutdown_tx: Arc<Mutex<Option<tokio::sync::mpsc::Sender<()>>>>,
+    /// 초기화 완료 여부
+    initialized: Arc<Mutex<bool>>,
+}
+
+impl PkceFlow {
+    pub fn new(config: PkceFlowConfig) -> Self {
+        Self {
+            config,
+            token: Arc::new(Mutex::new(None)),
+            registered_client: Arc::new(Mutex::new(None)),
+            pending_pkce: Arc::new(Mutex::new(None)),
+            callback_tx: Arc::new(Mutex::new(None)),
+            callback_shutdown_tx: Arc::new(Mutex::new(None)),
+            initialized: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub fn config(&self) -> &PkceFlowConfig {
+        &self.config
+    }
+
+    /// SecretManager에서 저장된 토큰/클라이언트 로드 (앱 시작 시 호출)
+    pub async fn initialize(&self) -> Result<(), String> {
+        let mut initialized = self.initialized.lock().await;
+        if *initialized {
+            return Ok(());
+        }
+
+        println!("[OAuth] Initializing {} from SecretManager vault...", self.config.name);
+
+        // 저장된 클라이언트 로드 (DCR만 해당)
+        if matches!(self.config.registration, ClientRegistration::Dynamic { .. }) {
+            if let Ok(Some(client_json)) = SECRETS.get(&self.config.client_key).await {
+                if let Ok(client) = serde_json::from_str::<RegisteredClient>(&client_json) {
+                    println!("[OAuth] Loaded client_id from vault: {}", client.client_id);
+                    *self.registered_client.lock().await = Some(client);
+                }
+            }
+        }
+
+        // 저장된 토큰 로드
+        if let Ok(Some(token_json)) = SECRETS.get(&self.config.token_key).await {
+            if let Ok(token) = serde_json::from_str::<OAuthToken>(&token_json) {
+                if let Some(remaining) = token.remaining_seconds() {
+                    println!("[OAuth] Loaded token from vault (expires in {} seconds)", remaining);
+                }
+                *self.token.lock().await = Some(token);
+            }
+        }
+
+        *initialized = true;
+        Ok(())
+    }
+
+    /// 토큰 저장 (메모리 + vault)
+    async fn save_token(&self, token: OAuthToken) -> Result<(), String> {
+        let token_json = serde_json::to_string(&token)
+            .map_err(|e| format!("Failed to serialize token: {}", e))?;
+
+        SECRETS
+            .set(&self.config.token_key, &token_json)
+            .await
+            .map_err(|e| format!("Failed to save token: {}", e))?;
+        *self.token.lock().await = Some(token);
+
+        println!("[OAuth] {} token saved to vault", self.config.name);
+        Ok(())
+    }
+
+    /// 클라이언트 저장 (메모리 + vault, DCR만 해당)
+    async fn save_client(&self, client: RegisteredClient) -> Result<(), String> {
+        if matches!(self.config.registration, ClientRegistration::Static { .. }) {
+            return Ok(());
+        }
+        let client_json = serde_json::to_string(&client)
+            .map_err(|e| format!("Failed to serialize client: {}", e))?;
+
+        SECRETS
+            .set(&self.config.client_key, &client_json)
+            .await
+            .map_err(|e| format!("Failed to save client: {}", e))?;
+        *self.registered_client.lock().await = Some(client);
+
+        println!("[OAuth] Client saved to vault");
+        Ok(())
+    }
+
+    /// 토큰 갱신에 쓸 클라이언트 (고정 클라이언트는 설정값, DCR은 마지막으로 인증에 성공한 클라이언트)
+    async fn current_client(&self) -> Option<RegisteredClient> {
+        match &self.config.registration {
+            ClientRegistration::Static { client_id, client_secret } => Some(RegisteredClient {
+                client_id: client_id.clone(),
+                client_secret: client_secret.clone(),
+                redirect_uris: Vec::new(),
+            }),
+            ClientRegistration::Dynamic { .. } => self.registered_client.lock().await.clone(),
+        }
+    }
+
+    /// 현재 토큰이 있는지 확인 (자동 초기화 포함)
+    pub async fn has_token(&self) -> bool {
+        let _ = self.initialize().await;
+        self.token.lock().await.is_some()
+    }
+
+    /// 유효한 액세스 토큰 가져오기 (필요 시 자동 갱신)
+    pub async fn get_access_token(&self) -> Option<String> {
+        let _ = self.initialize().await;
+
+        // 토큰 확인
+        let needs_refresh = {
+            let token = self.token.lock().await;
+            match token.as_ref() {
+                Some(t) => t.is_expired(),
+                None => return None,
+            }
+        };
+
+        // 만료된 경우 갱신 시도
+        if needs_refresh {
+            println!("[OAuth] {} token expired, attempting refresh...", self.config.name);
+            match self.refresh_token().await {
+                Ok(()) => println!("[OAuth] Token refreshed successfully"),
+                Err(e) => {
+                    eprintln!("[OAuth] Token refresh failed: {}", e);
+                    // 만료된 토큰 삭제 (메모리 + vault) - 호출자가 재인증 트리거하도록
+                    *self.token.lock().await = None;
+                    let _ = SECRETS.delete(&self.config.token_key).await;
+                    return None;
+                }
+            }
+        }
+
+        self.token.lock().await.as_ref().map(|t| t.access_token.clone())
+    }
+
+    /// 이번 인증에 쓸 클라이언트 (DCR이면 redirect URI로 새로 등록)
+    /// - 저장은 인증이 성공한 뒤에 합니다 (중간에 취소해도 기존 토큰의 클라이언트로 갱신할 수 있도록).
+    async fn register_client(&self, redirect_uri: &str) -> Result<RegisteredClient, String> {
+        let (registration_url, client_name) = match &self.config.registration {
+            ClientRegistration::Static { client_id, client_secret } => {
+                return Ok(RegisteredClient {
+                    client_id: client_id.clone(),
+                    client_secret: client_secret.clone(),
+                    redirect_uris: vec![redirect_uri.to_string()],
+                })
+            }
+            ClientRegistration::Dynamic { registration_url, client_name } => (registration_url, client_name),
+        };
+
+        let registration_request = serde_json::json!({
+            "client_name": client_name,
+            "redirect_uris": [redirect_uri],
+            "grant_types": ["authorization_code", "refresh_token"],
+            "response_types": ["code"],
+            "token_endpoint_auth_method": "none"
+        });
+
+        println!("[OAuth] Registering {} OAuth client...", self.config.name);
+
+        let client = crate::http::client()?;
+
+        let response = client
+            .post(registration_url)
+            .header("Content-Type", "application/json")
+            .json(&registration_request)
+            .send()
+            .await
+            .map_err(|e| format!("Client registration request failed: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let body_preview = if body.len() > 200 { &body[..200] } else { &body };
+            return Err(format!("Client registration failed with status {}: {}", status, body_preview));
+        }
+
+        let reg_response: ClientRegistrationResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse registration response: {}", e))?;
+
+        println!("[OAuth] Client registered: {}", reg_response.client_id);
+
+        Ok(RegisteredClient {
+            client_id: reg_response.client_id,
+            client_secret: reg_response.client_secret,
+            redirect_uris: vec![redirect_uri.to_string()],
+        })
+    }
+
+    /// OAuth 인증 플로우 시작 (브라우저를 열고 콜백까지 최대 5분 대기)
+    pub async fn start_auth_flow(&self) -> Result<String, String> {
+        let _ = self.initialize().await;
+
+        // Single-flight guard: 이미 진행 중인 OAuth 플로우가 있으면 거부
+        {
+            let existing = self.pending_pkce.lock().await;
+            if existing.is_some() {
+                return Err("OAuth flow already in progress. Please wait or cancel.".to_string());
+            }
+        }
+
+        // 콜백 포트를 먼저 확보해야 그 redirect URI로 클라이언트를 등록할 수 있음
+        let (listener, port) = bind_callback_listener().await?;
+        let nonce = generate_state();
+        let redirect_uri = callback_redirect_uri(port, &nonce);
+        let registered_client = self.register_client(&redirect_uri).await?;
+
+        let code_verifier = generate_code_verifier();
+        let code_challenge = generate_code_challenge(&code_verifier);
+        let state = generate_state();
+        let auth_url = build_authorize_url(
+            &self.config,
+            &registered_client.client_id,
+            &redirect_uri,
+            &state,
+            &code_challenge,
+        )?;
+
+        *self.pending_pkce.lock().await = Some(PkceData {
+            code_verifier,
+            state,
+        });
+
+        println!("[OAuth] Authorization URL: {}", auth_url);
+
+        let (tx, rx) = oneshot::channel();
+        *self.callback_tx.lock().await = Some(tx);
+
+        let context = CallbackContext {
+            listener,
+            port,
+            nonce,
+            redirect_uri,
+            token_url: self.config.token_url.clone(),
+            client: registered_client.clone(),
+        };
+        let callback_tx = self.callback_tx.clone();
+        let pending_pkce = self.pending_pkce.clone();
+        let token_storage = self.token.clone();
+
+        // 콜백 서버 shutdown 채널 생성
+        let (shutdown_tx, shutdown_rx) = tokio::sync::mpsc::channel::<()>(1);
+        *self.callback_shutdown_tx.lock().await = Some(shutdown_tx);
+
+        tokio::spawn(async move {
+            if let Err(e) = run_callback_server(context, callback_tx, pending_pkce, token_storage, shutdown_rx).await {
+                eprintln!("[OAuth] Callback server error: {}", e);
+            }
+        });
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        if let Err(e) = open::that(&auth_url) {
+            // 브라우저 열기 실패 시 상태 정리 후 콜백 서버 종료
+            *self.pending_pkce.lock().await = None;
+            *self.callback_tx.lock().await = None;
+            self.shutdown_callback_server().await;
+            return Err(format!("Failed to open browser: {}", e));
+        }
+
+        println!("[OAuth] Waiting for OAuth callback (max 5 minutes)...");
+
+        let auth_result = match tokio::time::timeout(tokio::time::Duration::from_secs(AUTH_FLOW_TIMEOUT_SECS), rx).await {
+            Ok(Ok(result)) => {
+                println!("[OAuth] Callback received: {:?}", result);
+                // 인증 성공 시 토큰을 vault에 저장
+                if result.is_ok() {
+                    // 이번 토큰을 발급받은 클라이언트로 교체 (갱신에 필요)
+                    if let Err(e) = self.save_client(registered_client).await {
+                        eprintln!("[OAuth] Failed to save client: {}", e);
+                    }
+
+                    // lock scope를 분리하여 데드락 방지
+                    // (save_token 내부에서 다시 lock을 잡기 때문)
+                    let token_opt = {
+                        self.token.lock().await.clone()
+                    };
+
+                    if let Some(token) = token_opt {
+                        if let Err(e) = self.save_token(token).await {
+                            eprintln!("[OAuth] Failed to save token: {}", e);
+                        } else {
+                            println!("[OAuth] Token persisted to vault");
+                        }
+                    } else {
+                        eprintln!("[OAuth] Warning: callback succeeded but no token in memory!");
+                    }
+                }
+                result
+            }
+            Ok(Err(_)) => {
+                // 채널 닫힘 시 상태 정리
+                *self.pending_pkce.lock().await = None;
+                self.shutdown_callback_server().await;
+                Err("OAuth callback channel closed".to_string())
+            }
+            Err(_) => {
+                // 타임아웃 시 상태 정리 후 콜백 서버 종료
+                *self.pending_pkce.lock().await = None;
+                self.shutdown_callback_server().await;
+                Err("OAuth timeout (5 minutes)".to_string())
+            }
+        };
+
+        println!("[OAuth] start_auth_flow returning: {:?}", auth_result);
+        auth_result
+    }
+
+    /// 콜백 서버 종료
+    async fn shutdown_callback_server(&self) {
+        if let Some(tx) = self.callback_shutdown_tx.lock().await.take() {
+            let _ = tx.send(()).await;
+            println!("[OAuth] Sent shutdown signal to callback server");
+        }
+    }
+
+    /// 토큰 갱신
+    pub async fn refresh_token(&self) -> Result<(), String> {
+        let current_token = self.token.lock().await.clone();
+
+        let refresh_token = current_token
+            .and_then(|t| t.refresh_token)
+            .ok_or("No refresh token available")?;
+
+        let client = self.current_client().await.ok_or("No registered client")?;
+
+        println!("[OAuth] Refreshing {} token...", self.config.name);
+
+        let mut new_token = request_token(
+            &self.config.token_url,
+            &client,
+            &[("grant_type", "refresh_token"), ("refresh_token", &refresh_token)],
+        )
+        .await
+        .map_err(|e| format!("Token refresh failed: {}", e))?;
+
+        // 새 refresh token을 주지 않는 서버는 기존 것을 계속 사용
+        if new_token.refresh_token.is_none() {
+            new_token.refresh_token = Some(refresh_token);
+        }
+
+        // vault에 저장
+        self.save_token(new_token).await?;
+
+        println!("[OAuth] Token refreshed and saved");
+        Ok(())
+    }
+
+    /// 로그아웃 (토큰 삭제)
+    pub async fn logout(&self) {
+        *self.token.lock().await = None;
+        *self.pending_pkce.lock().await = None;
+
+        // vault에서 토큰 삭제
+        let _ = SECRETS.delete(&self.config.token_key).await;
+
+        println!("[OAuth] Logged out of {}, token deleted from vault", self.config.name);
+    }
+
+    /// 저장된 토큰 정보 조회 (자동 초기화 포함)
+    /// 반환값: (토큰 존재 여부, 남은 유효 시간(초))
+    pub async fn get_token_info(&self) -> (bool, Option<i64>) {
+        let _ = self.initialize().await;
+
+        let token = self.token.lock().await;
+        match token.as_ref() {
+            Some(t) => {
+                let remaining = t.remaining_seconds();
+                let is_valid = !t.is_expired();
+                (is_valid, remaining)
+            }
+            None => (false, None),
+        }
+    }
+
+    /// 완전 초기화 (토큰 + 클라이언트 모두 삭제)
+    pub async fn clear_all(&self) {
+        self.logout().await;
+        *self.registered_client.lock().await = None;
+        *self.initialized.lock().await = false;
+
+        // vault에서 클라이언트도 삭제
+        let _ = SECRETS.delete(&self.config.client_key).await;
+
+        println!("[OAuth] All {} credentials cleared", self.config.name);
+    }
+}
+
+/// 로컬 콜백 서버 실행
+///
+/// shutdown signal 수신 시 또는 6분 자체 타임아웃 시 종료됨
+/// - 콜백을 처리한 뒤에는 잠시 더 떠 있으면서 같은 경로로 다시 들어온 요청을 410으로 거부
+async fn run_callback_server(
+    context: CallbackContext,
+    callback_tx: CallbackSender,
+    pending_pkce: Arc<Mutex<Option<PkceData>>>,
+    token_storage: Arc<Mutex<Option<OAuthToken>>>,
+    mut shutdown_rx: tokio::sync::mpsc::Receiver<()>,
+) -> Result<(), String> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+
+    // 서버 자체 타임아웃 (OAuth 흐름 타임아웃 + 여유)
+    const SERVER_TIMEOUT_SECS: u64 = AUTH_FLOW_TIMEOUT_SECS + 60;
+
+    let CallbackContext {
+        listener,
+        port,
+        nonce,
+        redirect_uri,
+        token_url,
+        client,
+    } = context;
+
+    println!("[OAuth] Callback server listening on port {} (timeout: {}s)", port, SERVER_TIMEOUT_SECS);
+
+    let server_start = std::time::Instant::now();
+    // 콜백을 처리한 시각 (이후 콜백 요청은 모두 거부)
+    let mut completed_at: Option<std::time::Instant> = None;
+
+    // 이번 nonce의 /callback 요청이 올 때까지 연결을 계속 수락 (shutdown signal 또는 타임아웃 시 종료)
+    loop {
+        // 서버 타임아웃 체크
+        if let Some(at) = completed_at {
+            if at.elapsed().as_secs() >= CALLBACK_GRACE_SECS {
+                return Ok(());
+            }
+        } else if server_start.elapsed().as_secs() >= SERVER_TIMEOUT_SECS {
+            println!("[OAuth] Callback server timeout, shutting down");
+            return Err("Callback server timeout".to_string());
+        }
+
+        // accept + shutdown signal 동시 대기
+        let stream_result = tokio::select! {
+            _ = shutdown_rx.recv() => {
+                println!("[OAuth] Callback server received shutdown signal");
+                return Ok(());
+            }
+            accept_result = tokio::time::timeout(
+                tokio::time::Duration::from_secs(5),
+                listener.accept()
+            ) => {
+                match accept_result {
+                    Ok(Ok((s, a))) => Some((s, a)),
+                    Ok(Err(e)) => return Err(format!("Failed to accept connection: {}", e)),
+                    Err(_) => None, // 타임아웃, 다음 루프
+                }
+            }
+        };
+
+        let (stream, addr) = match stream_result {
+            Some(v) => v,
+            None => continue,
+        };
+
+        println!("[OAuth] Accepted connection from {}", addr);
+
+        // 읽기/쓰기 분리 (BufReader와 write_all 충돌 방지), 헤더 크기 제한
+        let (reader_half, mut writer_half) = stream.into_split();
+        let mut reader = BufReader::new(reader_half.take(MAX_CALLBACK_REQUEST_BYTES));
+
+        let request_line = match tokio::time::timeout(
+            tokio::time::Duration::from_secs(CALLBACK_READ_TIMEOUT_SECS),
+            read_request_head(&mut reader),
+        )
+        .await
+        {
+            Ok(Ok(line)) => line,
+            Ok(Err(status)) => {
+                eprintln!("[OAuth] Rejected malformed request: {}", status);
+                write_empty_response(&mut writer_half, status).await;
+                continue;
+            }
+            Err(_) => {
+                eprintln!("[OAuth] Request read timed out");
+                write_empty_response(&mut writer_half, "408 Request Timeout").await;
+                continue;
+            }
+        };
+
+        // 메서드/경로 추출 (경로에 nonce가 있으므로 로그에는 남기지 않음)
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+            eprintln!("[OAuth] Invalid HTTP request format");
+            write_empty_response(&mut writer_half, "400 Bad Request").await;
+            continue;
+        };
+        if method != "GET" {
+            write_empty_response(&mut writer_half, "405 Method Not Allowed").await;
+            continue;
+        }
+
+        let query = match classify_callback_path(path, &nonce) {
+            CallbackRoute::Callback(_) if completed_at.is_some() => {
+                println!("[OAuth] Rejected repeated callback request");
+                write_empty_response(&mut writer_half, "410 Gone").await;
+                continue;
+            }
+            CallbackRoute::Callback(query) => query,
+            CallbackRoute::UnknownCallback => {
+                println!("[OAuth] Rejected callback request with unknown nonce");
+                write_empty_response(&mut writer_half, "404 Not Found").await;
+                continue;
+            }
+            // /callback 경로가 아닌 요청은 404 응답 후 다음 연결 대기
+            CallbackRoute::Other => {
+                write_empty_response(&mut writer_half, "404 Not Found").await;
+                continue;
+            }
+        };
+
+        // /callback 요청 처리
+        let params: HashMap<_, _> = url::form_urlencoded::parse(query.as_bytes()).collect();
+        let result = if let (Some(code), Some(state)) = (params.get("code"), params.get("state")) {
+            let pkce_data = pending_pkce.lock().await.take();
+            if let Some(pkce) = pkce_data {
+                if constant_time_eq(pkce.state.as_bytes(), state.as_bytes()) {
+                    println!("[OAuth] Exchanging code for token...");
+                    let exchange = request_token(
+                        &token_url,
+                        &client,
+                        &[
+                            ("grant_type", "authorization_code"),
+                            ("code", code),
+                            ("redirect_uri", &redirect_uri),
+                            ("code_verifier", &pkce.code_verifier),
+                        ],
+                    )
+                    .await;
+                    match exchange {
+                        Ok(token) => {
+                            println!("[OAuth] Token stored in memory, issued_at: {}", token.issued_at);
+                            *token_storage.lock().await = Some(token);
+                            Ok("OAuth authentication successful".to_string())
+                        }
+                        Err(e) => {
+                            eprintln!("[OAuth] Token exchange error: {}", e);
+                            Err(format!("Token exchange failed: {}", e))
+                        }
+                    }
+                } else {
+                    Err("Invalid OAuth state".to_string())
+                }
+            } else {
+                Err("No pending OAuth session".to_string())
+            }
+        } else if let Some(error) = params.get("error") {
+            let error_desc = params.get("error_description")
+                .map(|d| format!(": {}", d))
+                .unwrap_or_default();
+            Err(format!("OAuth error: {}{}", error, error_desc))
+        } else {
+            Err("Invalid callback parameters".to_string())
+        };
+
+        // 응답 생성
+        let (status, body) = match &result {
+            Ok(msg) => ("200 OK", format!(
+                r#"<!DOCTYPE html><html><head><meta charset="utf-8"><title>Success</title></head>
+                <body style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; text-align: center; padding: 50px; background: #f4f5f7;">
+                <div style="background: white; padding: 40px; border-radius: 8px; max-width: 400px; margin: 0 auto; box-shadow: 0 2px 4px rgba(0,0,0,0.1);">
+                <h1 style="color: #36B37E; margin-bottom: 16px;">✓ {}</h1>
+                <p style="color: #42526e;">You can close this window and return to the app.</p>
+                </div></body></html>"#,
+                msg
+            )),
+            Err(msg) => ("400 Bad Request", format!(
+                r#"<!DOCTYPE html><html><head><meta charset="utf-8"><title>Error</title></head>
+                <body style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; text-align: center; padding: 50px; background: #f4f5f7;">
+                <div style="background: white; padding: 40px; border-radius: 8px; max-width: 400px; margin: 0 auto; box-shadow: 0 2px 4px rgba(0,0,0,0.1);">
+                <h1 style="color: #FF5630; margin-bottom: 16px;">✗ Error</h1>
+                <p style="color: #42526e;">{}</p>
+                </div></body></html>"#,
+                msg
+            )),
+        };
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let _ = writer_half.write_all(response.as_bytes()).await;
+        let _ = writer_half.shutdown().await;
+
+        // 결과 전송 (서버는 중복 요청을 거부하며 잠시 유지)
+        if let Some(tx) = callback_tx.lock().await.take() {
+            let _ = tx.send(result);
+        }
+        completed_at = Some(std::time::Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port_range() {
+        assert_eq!(parse_port_range("23456-23460"), Some(23456..=23460));
+        assert_eq!(parse_port_range(" 8080 "), Some(8080..=8080));
+        assert_eq!(parse_port_range("9000-8000"), None);
+        assert_eq!(parse_port_range("abc"), None);
+    }
+
+    #[test]
+    fn test_callback_path_requires_nonce() {
+        let uri = callback_redirect_uri(23456, "n0nce");
+        assert_eq!(uri, "http://localhost:23456/callback/n0nce");
+
+        assert_eq!(
+            classify_callback_path("/callback/n0nce?code=a&state=b", "n0nce"),
+            CallbackRoute::Callback("code=a&state=b")
+        );
+        assert_eq!(classify_callback_path("/callback?code=a", "n0nce"), CallbackRoute::UnknownCallback);
+        assert_eq!(classify_callback_path("/callback/other", "n0nce"), CallbackRoute::UnknownCallback);
+        assert_eq!(classify_callback_path("/callbackx/n0nce", "n0nce"), CallbackRoute::Other);
+        assert_eq!(classify_callback_path("/favicon.ico", "n0nce"), CallbackRoute::Other);
+    }
+
+    #[test]
+    fn test_authorize_url_includes_pkce_and_scopes() {
+        let config = PkceFlowConfig {
+            name: "Test".to_string(),
+            authorize_url: "https://auth.example.com/authorize?tenant=x".to_string(),
+            token_url: "https://auth.example.com/token".to_string(),
+            registration: ClientRegistration::Static {
+                client_id: "client".to_string(),
+                client_secret: None,
+            },
+            scopes: vec!["read".to_string(), "offline_access".to_string()],
+            extra_authorize_params: vec![("prompt".to_string(), "consent".to_string())],
+            token_key: "test/token".to_string(),
+            client_key: "test/client".to_string(),
+        };
+        let url = build_authorize_url(&config, "client", "http://localhost:1/callback/n", "st", "ch").unwrap();
+        let parsed = Url::parse(&url).unwrap();
+        let params: HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(params["tenant"], "x");
+        assert_eq!(params["redirect_uri"], "http://localhost:1/callback/n");
+        assert_eq!(params["scope"], "read offline_access");
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert_eq!(params["prompt"], "consent");
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"state", b"state"));
+        assert!(!constant_time_eq(b"state", b"statf"));
+        assert!(!constant_time_eq(b"state", b"stat"));
+    }
+
+    #[tokio::test]
+    async fn test_read_request_head_limits_size() {
+        use tokio::io::{AsyncReadExt, BufReader};
+
+        let request: &[u8] = b"GET /callback/n HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        let mut reader = BufReader::new(request.take(MAX_CALLBACK_REQUEST_BYTES));
+        assert_eq!(read_request_head(&mut reader).await.unwrap(), "GET /callback/n HTTP/1.1\r\n");
+
+        let oversized = format!("GET /callback/n HTTP/1.1\r\nX-Pad: {}\r\n\r\n", "a".repeat(64));
+        let mut reader = BufReader::new(oversized.as_bytes().take(32));
+        assert_eq!(
+            read_request_head(&mut reader).await,
+            Err("431 Request Header Fields Too Large")
+        );
+    }
+}
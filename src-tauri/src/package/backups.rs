@@ -0,0 +1,137 @@
+//! Automatic Backups
+//!
+//! import/복원 직전에 자동으로 만들어지는 DB 백업(`app_data_dir/ite_backups`) 목록 조회와 용량 기준 정리.
+//! 파일 이름 규칙: `backup-before-<reason>-<timestamp_ms>.ite`
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::error::IteError;
+
+/// 백업 파일 이름 접두사
+pub const BACKUP_FILE_PREFIX: &str = "backup-before-";
+/// 자동 정리 기본 상한 (전체 백업 용량, bytes)
+pub const DEFAULT_BACKUP_MAX_BYTES: u64 = 500 * 1024 * 1024;
+/// 용량과 관계없이 항상 남겨 두는 최신 백업 수
+pub const MIN_BACKUPS_KEPT: usize = 3;
+
+/// 백업 파일 정보
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupFile {
+    pub path: String,
+    pub file_name: String,
+    /// 백업 사유 ("import", "restore" 등)
+    pub reason: String,
+    pub created_at: i64,
+    pub size_bytes: u64,
+}
+
+/// 정리 결과
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupPruneResult {
+    pub removed: Vec<String>,
+    pub freed_bytes: u64,
+    pub remaining_bytes: u64,
+}
+
+/// 새 백업 파일 경로
+pub fn backup_file_path(dir: &Path, reason: &str) -> PathBuf {
+    dir.join(format!(
+        "{}{}-{}.ite",
+        BACKUP_FILE_PREFIX,
+        reason,
+        chrono::Utc::now().timestamp_millis()
+    ))
+}
+
+/// 파일 이름에서 (사유, 생성 시각) 추출
+fn parse_backup_name(file_name: &str) -> Option<(String, i64)> {
+    let stem = file_name.strip_prefix(BACKUP_FILE_PREFIX)?.strip_suffix(".ite")?;
+    let (reason, ts) = stem.rsplit_once('-')?;
+    Some((reason.to_string(), ts.parse().ok()?))
+}
+
+/// 백업 목록 (최신 순), 폴더가 없으면 빈 목록
+pub fn list_backups(dir: &Path) -> Result<Vec<BackupFile>, IteError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut out = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some((reason, created_at)) = parse_backup_name(&file_name) else {
+            continue;
+        };
+        let meta = entry.metadata()?;
+        if !meta.is_file() {
+            continue;
+        }
+        out.push(BackupFile {
+            path: entry.path().to_string_lossy().to_string(),
+            file_name,
+            reason,
+            created_at,
+            size_bytes: meta.len(),
+        });
+    }
+    out.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+    Ok(out)
+}
+
+/// 전체 용량이 max_bytes 이하가 될 때까지 오래된 백업부터 삭제
+/// - 최신 `MIN_BACKUPS_KEPT`개는 용량과 관계없이 유지합니다.
+pub fn prune_backups(dir: &Path, max_bytes: u64) -> Result<BackupPruneResult, IteError> {
+    let backups = list_backups(dir)?;
+    let mut result = BackupPruneResult {
+        remaining_bytes: backups.iter().map(|b| b.size_bytes).sum(),
+        ..Default::default()
+    };
+
+    for backup in backups.iter().skip(MIN_BACKUPS_KEPT).rev() {
+        if result.remaining_bytes <= max_bytes {
+            break;
+        }
+        std::fs::remove_file(&backup.path)?;
+        result.remaining_bytes -= backup.size_bytes;
+        result.freed_bytes += backup.size_bytes;
+        result.removed.push(backup.path.clone());
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_backup_name() {
+        assert_eq!(
+            parse_backup_name("backup-before-import-1700000000000.ite"),
+            Some(("import".to_string(), 1_700_000_000_000))
+        );
+        assert_eq!(parse_backup_name("backup-before-import.ite"), None);
+        assert_eq!(parse_backup_name("notes.ite"), None);
+    }
+
+    #[test]
+    fn test_prune_backups_keeps_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        for ts in 1..=5 {
+            let name = format!("backup-before-import-{}.ite", ts);
+            std::fs::write(dir.path().join(name), vec![0u8; 100]).unwrap();
+        }
+        std::fs::write(dir.path().join("other.txt"), vec![0u8; 100]).unwrap();
+
+        let result = prune_backups(dir.path(), 150).unwrap();
+        assert_eq!(result.removed.len(), 2);
+        assert_eq!(result.remaining_bytes, 300);
+
+        let left: Vec<i64> = list_backups(dir.path()).unwrap().iter().map(|b| b.created_at).collect();
+        assert_eq!(left, vec![5, 4, 3]);
+    }
+}
@@ -0,0 +1,130 @@
+//! Encrypted Backup Container
+//!
+//! 기기 밖(클라우드)에 두는 백업용 .ite 암호화 포맷.
+//! 기기를 잃어도 복원할 수 있도록 키는 Keychain이 아니라 사용자 암호에서 유도합니다.
+//!
+//! 파일 포맷 (v1):
+//! - magic: `ITEBAK01` (8 bytes)
+//! - salt: 16 bytes
+//! - iterations: u32 LE (PBKDF2-HMAC-SHA256 반복 횟수)
+//! - nonce: 24 bytes (XChaCha20-Poly1305)
+//! - ciphertext: AEAD 결과 (= 암호문 + 태그)
+//!
+//! AAD: magic + salt + iterations (헤더 변조 방지)
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    XChaCha20Poly1305, XNonce,
+};
+use rand::Rng;
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+use crate::error::IteError;
+
+/// 파일 매직 (8 bytes)
+pub const ENCRYPTED_BACKUP_MAGIC: &[u8; 8] = b"ITEBAK01";
+
+/// 암호화된 백업 파일 확장자
+pub const ENCRYPTED_BACKUP_EXTENSION: &str = "itebak";
+
+/// 최소 암호 길이
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const HEADER_LEN: usize = 8 + SALT_LEN + 4;
+const PBKDF2_ITERATIONS: u32 = 600_000;
+/// 헤더의 반복 횟수 상한 (변조된 파일로 복원이 멈추지 않도록)
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+
+pub fn is_encrypted_backup(data: &[u8]) -> bool {
+    data.starts_with(ENCRYPTED_BACKUP_MAGIC)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+    key
+}
+
+/// .ite 파일 내용 암호화
+pub fn encrypt_backup(plain: &[u8], passphrase: &str) -> Result<Vec<u8>, IteError> {
+    encrypt_with_iterations(plain, passphrase, PBKDF2_ITERATIONS)
+}
+
+fn encrypt_with_iterations(plain: &[u8], passphrase: &str, iterations: u32) -> Result<Vec<u8>, IteError> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(IteError::InvalidOperation(format!(
+            "Backup passphrase must be at least {} characters",
+            MIN_PASSPHRASE_LEN
+        )));
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; SALT_LEN];
+    rng.fill(&mut salt);
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce);
+
+    let mut out = Vec::with_capacity(HEADER_LEN + NONCE_LEN + plain.len() + 16);
+    out.extend_from_slice(ENCRYPTED_BACKUP_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&iterations.to_le_bytes());
+
+    let mut key = derive_key(passphrase, &salt, iterations);
+    let cipher = XChaCha20Poly1305::new((&key).into());
+    key.zeroize();
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), Payload { msg: plain, aad: &out })
+        .map_err(|e| IteError::InvalidOperation(format!("Backup encryption failed: {}", e)))?;
+
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// 암호화된 백업 복호화 (암호가 틀리거나 파일이 손상되면 InvalidOperation)
+pub fn decrypt_backup(data: &[u8], passphrase: &str) -> Result<Vec<u8>, IteError> {
+    if !is_encrypted_backup(data) || data.len() < HEADER_LEN + NONCE_LEN {
+        return Err(IteError::InvalidOperation("Not an encrypted backup file".to_string()));
+    }
+    let (header, rest) = data.split_at(HEADER_LEN);
+    let salt = &header[8..8 + SALT_LEN];
+    let iterations = u32::from_le_bytes(header[8 + SALT_LEN..].try_into().expect("4-byte iteration field"));
+    if iterations == 0 || iterations > MAX_PBKDF2_ITERATIONS {
+        return Err(IteError::InvalidOperation("Encrypted backup header is corrupted".to_string()));
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let mut key = derive_key(passphrase, salt, iterations);
+    let cipher = XChaCha20Poly1305::new((&key).into());
+    key.zeroize();
+    cipher
+        .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad: header })
+        .map_err(|_| IteError::InvalidOperation("Wrong passphrase or corrupted backup".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let plain = b"PK\x03\x04 fake package bytes";
+        let encrypted = encrypt_with_iterations(plain, "correct horse", 1_000).unwrap();
+        assert!(is_encrypted_backup(&encrypted));
+        assert_eq!(decrypt_backup(&encrypted, "correct horse").unwrap(), plain);
+
+        let err = decrypt_backup(&encrypted, "wrong horse!").unwrap_err();
+        assert!(err.to_string().contains("Wrong passphrase"));
+    }
+
+    #[test]
+    fn test_header_tampering_is_detected() {
+        let mut encrypted = encrypt_with_iterations(b"data", "correct horse", 1_000).unwrap();
+        encrypted[10] ^= 0xff; // salt 변조
+        assert!(decrypt_backup(&encrypted, "correct horse").is_err());
+        assert!(encrypt_backup(b"data", "short").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+//! .ite File Inspection
+//!
+//! import 전에 파일을 읽기 전용으로 열어 손상 여부/스키마 호환성을 검사하고
+//! 포함된 프로젝트 목록을 보여줍니다. (현재 DB를 덮어쓰기 전 단계)
+
+use std::path::Path;
+
+use rusqlite::{Connection, OpenFlags};
+use serde::Serialize;
+
+use crate::db::SCHEMA_VERSION;
+use crate::error::IteError;
+
+/// import에 반드시 필요한 테이블/컬럼
+const REQUIRED_COLUMNS: &[(&str, &str)] = &[
+    ("projects", "id, metadata_json, updated_at"),
+    ("blocks", "id, project_id, block_type, content, hash, metadata_json"),
+    ("segments", "id, project_id, source_ids, target_ids, is_aligned, segment_order"),
+];
+
+/// 파일에 포함된 프로젝트 요약
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IteFileProject {
+    pub id: String,
+    pub title: String,
+    pub block_count: usize,
+    pub segment_count: usize,
+    pub updated_at: i64,
+    /// 휴지통에 있는 프로젝트
+    pub trashed: bool,
+}
+
+/// DB 파일 검사 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseFileInfo {
+    /// `PRAGMA user_version` (0: 버전 기록 이전 파일)
+    pub schema_version: i32,
+    pub projects: Vec<IteFileProject>,
+}
+
+fn invalid(message: String) -> IteError {
+    IteError::InvalidOperation(message)
+}
+
+/// SQLite DB 파일 검사 (읽기 전용)
+/// - 손상된 파일, SQLite가 아닌 파일, 현재 앱보다 새 스키마, 필수 테이블 누락이면 에러
+pub fn inspect_database_file(path: &Path) -> Result<DatabaseFileInfo, IteError> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX)?;
+
+    // 헤더가 SQLite가 아니면 첫 쿼리에서 "file is not a database" 에러가 납니다.
+    let schema_version: i32 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| invalid(format!("Not a valid .ite file: {}", e)))?;
+
+    let check: String = conn
+        .query_row("PRAGMA quick_check", [], |row| row.get(0))
+        .map_err(|e| invalid(format!("File is corrupt: {}", e)))?;
+    if check != "ok" {
+        return Err(invalid(format!("File is corrupt: {}", check)));
+    }
+
+    if schema_version > SCHEMA_VERSION {
+        return Err(invalid(format!(
+            "File schema v{} is newer than supported v{}; update the app",
+            schema_version, SCHEMA_VERSION
+        )));
+    }
+    for (table, columns) in REQUIRED_COLUMNS {
+        if conn.prepare(&format!("SELECT {} FROM {} LIMIT 0", columns, table)).is_err() {
+            return Err(invalid(format!("Incompatible .ite file: table '{}' is missing or outdated", table)));
+        }
+    }
+
+    // deleted_at은 휴지통 도입 이후 파일에만 있음
+    let trashed_expr = if conn.prepare("SELECT deleted_at FROM projects LIMIT 0").is_ok() {
+        "p.deleted_at IS NOT NULL"
+    } else {
+        "0"
+    };
+    let mut stmt = conn.prepare(&format!(
+        "SELECT p.id, COALESCE(json_extract(p.metadata_json, '$.title'), ''), p.updated_at, {},
+                (SELECT COUNT(*) FROM blocks b WHERE b.project_id = p.id),
+                (SELECT COUNT(*) FROM segments s WHERE s.project_id = p.id)
+         FROM projects p ORDER BY p.updated_at DESC",
+        trashed_expr
+    ))?;
+    let iter = stmt.query_map([], |row| {
+        Ok(IteFileProject {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            updated_at: row.get(2)?,
+            trashed: row.get(3)?,
+            block_count: row.get::<_, i64>(4)? as usize,
+            segment_count: row.get::<_, i64>(5)? as usize,
+        })
+    })?;
+
+    let mut projects = Vec::new();
+    for r in iter {
+        projects.push(r?);
+    }
+    Ok(DatabaseFileInfo {
+        schema_version,
+        projects,
+    })
+}
@@ -0,0 +1,195 @@
+//! Import Merge Preview
+//!
+//! 이미 있는 프로젝트의 새 버전을 가져올 때, 통째로 덮어쓰지 않고 블록 단위로 비교합니다.
+//! - 블록 ID가 같은 블록끼리 비교하며, 번역문(target) 변경만 적용 대상입니다.
+//! - 원문 변경과 세그먼트 추가/삭제는 구조가 바뀌는 변경이라 알려주기만 합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::models::IteProject;
+
+/// 양쪽에 모두 있는 블록의 내용 차이
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDiff {
+    pub block_id: String,
+    pub segment_id: Option<String>,
+    pub local_content: String,
+    pub incoming_content: String,
+}
+
+/// 로컬 프로젝트와 가져올 프로젝트 비교 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectImportDiff {
+    pub project_id: String,
+    pub title: String,
+    pub local_updated_at: i64,
+    pub incoming_updated_at: i64,
+    /// 번역문이 바뀐 블록 (병합 대상)
+    pub target_changes: Vec<BlockDiff>,
+    /// 원문이 바뀐 블록 (적용하지 않음)
+    pub source_changes: Vec<BlockDiff>,
+    /// 가져올 쪽에만 있는 세그먼트 ID
+    pub added_segments: Vec<String>,
+    /// 로컬에만 있는 세그먼트 ID
+    pub removed_segments: Vec<String>,
+    /// 양쪽에 있고 내용이 같은 블록 수
+    pub unchanged_blocks: usize,
+}
+
+/// 블록 ID → 세그먼트 ID
+fn segment_of(project: &IteProject) -> HashMap<&str, &str> {
+    let mut out = HashMap::new();
+    for segment in &project.segments {
+        for id in segment.source_ids.iter().chain(&segment.target_ids) {
+            out.insert(id.as_str(), segment.group_id.as_str());
+        }
+    }
+    out
+}
+
+/// 두 버전 비교 (세그먼트 순서대로)
+pub fn diff_projects(local: &IteProject, incoming: &IteProject) -> ProjectImportDiff {
+    let local_segments: HashSet<&str> = local.segments.iter().map(|s| s.group_id.as_str()).collect();
+    let incoming_segments: HashSet<&str> = incoming.segments.iter().map(|s| s.group_id.as_str()).collect();
+    let segment_ids = segment_of(local);
+
+    let mut ordered = local.segments.clone();
+    ordered.sort_by_key(|s| s.order);
+
+    let mut diff = ProjectImportDiff {
+        project_id: local.id.clone(),
+        title: local.metadata.title.clone(),
+        local_updated_at: local.metadata.updated_at,
+        incoming_updated_at: incoming.metadata.updated_at,
+        target_changes: Vec::new(),
+        source_changes: Vec::new(),
+        added_segments: Vec::new(),
+        removed_segments: Vec::new(),
+        unchanged_blocks: 0,
+    };
+
+    for segment in &ordered {
+        for block_id in segment.source_ids.iter().chain(&segment.target_ids) {
+            let (Some(mine), Some(theirs)) = (local.blocks.get(block_id), incoming.blocks.get(block_id)) else {
+                continue;
+            };
+            if mine.content == theirs.content {
+                diff.unchanged_blocks += 1;
+                continue;
+            }
+            let change = BlockDiff {
+                block_id: block_id.clone(),
+                segment_id: segment_ids.get(block_id.as_str()).map(|s| s.to_string()),
+                local_content: mine.content.clone(),
+                incoming_content: theirs.content.clone(),
+            };
+            // 블록 유형이 바뀐 경우도 원문 변경으로 취급해 덮어쓰지 않음
+            if mine.block_type == "target" && theirs.block_type == "target" {
+                diff.target_changes.push(change);
+            } else {
+                diff.source_changes.push(change);
+            }
+        }
+    }
+
+    let mut incoming_ordered: Vec<_> = incoming.segments.iter().collect();
+    incoming_ordered.sort_by_key(|s| s.order);
+    diff.added_segments = incoming_ordered
+        .iter()
+        .filter(|s| !local_segments.contains(s.group_id.as_str()))
+        .map(|s| s.group_id.clone())
+        .collect();
+    diff.removed_segments = ordered
+        .iter()
+        .filter(|s| !incoming_segments.contains(s.group_id.as_str()))
+        .map(|s| s.group_id.clone())
+        .collect();
+    diff
+}
+
+/// 병합할 번역문 (block_id, content)
+/// - `only`가 있으면 그 블록만, 병합 대상이 아닌 ID는 무시합니다.
+pub fn translation_updates(diff: &ProjectImportDiff, only: Option<&[String]>) -> Vec<(String, String)> {
+    diff.target_changes
+        .iter()
+        .filter(|c| only.is_none_or(|ids| ids.contains(&c.block_id)))
+        .map(|c| (c.block_id.clone(), c.incoming_content.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{BlockMetadata, EditorBlock, ProjectMetadata, ProjectSettings, SegmentGroup};
+
+    fn project(pairs: &[(&str, &str, &str)]) -> IteProject {
+        let block = |id: String, block_type: &str, content: &str| EditorBlock {
+            id,
+            block_type: block_type.to_string(),
+            content: content.to_string(),
+            hash: String::new(),
+            metadata: BlockMetadata::default(),
+        };
+        let mut blocks = HashMap::new();
+        let mut segments = Vec::new();
+        for (i, (seg, source, target)) in pairs.iter().enumerate() {
+            let (s, t) = (format!("{seg}-s"), format!("{seg}-t"));
+            blocks.insert(s.clone(), block(s.clone(), "source", source));
+            blocks.insert(t.clone(), block(t.clone(), "target", target));
+            segments.push(SegmentGroup {
+                group_id: seg.to_string(),
+                source_ids: vec![s],
+                target_ids: vec![t],
+                is_aligned: true,
+                order: i as i32,
+            });
+        }
+        IteProject {
+            id: "p1".to_string(),
+            version: "1.0.0".to_string(),
+            metadata: ProjectMetadata {
+                title: "Doc".to_string(),
+                description: None,
+                domain: "general".to_string(),
+                target_language: Some("ko".to_string()),
+                source_locale: None,
+                target_locale: None,
+                created_at: 1,
+                updated_at: 2,
+                author: None,
+                glossary_paths: None,
+                settings: ProjectSettings {
+                    strictness_level: 0.5,
+                    auto_save: true,
+                    auto_save_interval: 30000,
+                    theme: "system".to_string(),
+                },
+            },
+            segments,
+            blocks,
+            history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_diff_splits_target_and_source_changes() {
+        let local = project(&[("a", "Hello", ""), ("b", "Bye", "잘 가"), ("c", "Gone", "")]);
+        let incoming = project(&[("a", "Hello", "안녕"), ("b", "Bye now", "잘 가"), ("d", "New", "새")]);
+        let diff = diff_projects(&local, &incoming);
+
+        assert_eq!(diff.target_changes.len(), 1);
+        assert_eq!(diff.target_changes[0].block_id, "a-t");
+        assert_eq!(diff.target_changes[0].segment_id.as_deref(), Some("a"));
+        assert_eq!(diff.source_changes[0].block_id, "b-s");
+        assert_eq!(diff.added_segments, vec!["d"]);
+        assert_eq!(diff.removed_segments, vec!["c"]);
+        assert_eq!(diff.unchanged_blocks, 2);
+
+        assert_eq!(translation_updates(&diff, None), vec![("a-t".to_string(), "안녕".to_string())]);
+        assert!(translation_updates(&diff, Some(&["b-s".to_string()])).is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+//! .ite Package Format
+//!
+//! - v1: SQLite DB 파일 그대로 (첨부 파일 미포함)
+//! - v2: zip 컨테이너
+//!   - `manifest.json`: 포맷 버전, 프로젝트/첨부 목록, 다시 연결해야 할 시크릿 이름
+//!   - `project.sqlite`: DB 스냅샷
+//!   - `attachments/<attachment_id>/<filename>`: 첨부 원본 파일
+//!
+//! 시크릿 값은 패키지에 담지 않고 이름만 기록합니다 (받는 쪽에서 다시 입력).
+//! 클라우드 백업은 패키지를 사용자 암호로 한 번 더 암호화합니다 (`encrypted`).
+
+mod backups;
+mod encrypted;
+mod inspect;
+mod merge;
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::error::IteError;
+
+pub use backups::{
+    backup_file_path, list_backups, prune_backups, BackupFile, BackupPruneResult, BACKUP_FILE_PREFIX,
+    DEFAULT_BACKUP_MAX_BYTES, MIN_BACKUPS_KEPT,
+};
+pub use encrypted::{
+    decrypt_backup, encrypt_backup, is_encrypted_backup, ENCRYPTED_BACKUP_EXTENSION, MIN_PASSPHRASE_LEN,
+};
+pub use inspect::{inspect_database_file, DatabaseFileInfo, IteFileProject};
+pub use merge::{diff_projects, translation_updates, BlockDiff, ProjectImportDiff};
+
+/// 현재 패키지 포맷 버전
+pub const PACKAGE_FORMAT_VERSION: u32 = 2;
+pub const MANIFEST_ENTRY: &str = "manifest.json";
+pub const DATABASE_ENTRY: &str = "project.sqlite";
+const ATTACHMENTS_DIR: &str = "attachments";
+const PACKAGE_FORMAT_NAME: &str = "ite";
+
+const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// 파일 앞부분으로 판별한 .ite 포맷
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageKind {
+    /// SQLite DB 파일 (v1)
+    LegacyDatabase,
+    /// zip 컨테이너 (v2+)
+    Container,
+}
+
+/// 패키지에 포함된 프로젝트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageProject {
+    pub id: String,
+    pub title: String,
+}
+
+/// 패키지에 포함된 첨부 파일
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageAttachment {
+    pub id: String,
+    pub project_id: String,
+    pub filename: String,
+    /// zip 내부 경로
+    pub entry: String,
+    pub size: u64,
+    /// 파일 내용 SHA-256 (hex)
+    pub sha256: String,
+}
+
+/// manifest.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageManifest {
+    pub format: String,
+    pub format_version: u32,
+    pub app_version: String,
+    pub exported_at: i64,
+    pub projects: Vec<PackageProject>,
+    pub attachments: Vec<PackageAttachment>,
+    /// 연결된 외부 리소스(MCP, 커넥터 등)를 다시 열 때 필요한 시크릿 이름 (값은 미포함)
+    #[serde(default)]
+    pub required_secrets: Vec<String>,
+}
+
+/// 패키지에 넣을 첨부 파일 원본
+pub struct AttachmentSource<'a> {
+    pub id: &'a str,
+    pub project_id: &'a str,
+    pub filename: &'a str,
+    pub path: &'a Path,
+}
+
+fn zip_error(e: zip::result::ZipError) -> IteError {
+    IteError::Io(std::io::Error::other(e))
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// zip 내부 경로에 쓸 파일 이름 (경로 구분자/상위 경로 제거)
+fn safe_file_name(filename: &str) -> String {
+    let name = Path::new(filename)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+        .replace(['/', '\\'], "_");
+    if name.is_empty() || name == ".." {
+        "attachment".to_string()
+    } else {
+        name
+    }
+}
+
+/// 파일 앞부분(magic bytes)으로 .ite 포맷 판별
+pub fn detect_package_kind(path: &Path) -> Result<Option<PackageKind>, IteError> {
+    let mut header = [0u8; 16];
+    let mut file = File::open(path)?;
+    let read = file.read(&mut header)?;
+    let header = &header[..read];
+
+    if header.starts_with(SQLITE_MAGIC) {
+        Ok(Some(PackageKind::LegacyDatabase))
+    } else if header.starts_with(ZIP_MAGIC) {
+        Ok(Some(PackageKind::Container))
+    } else {
+        Ok(None)
+    }
+}
+
+/// v2 패키지 작성
+/// - 읽을 수 없는 첨부 파일은 건너뛰고 ID 목록으로 반환합니다.
+pub fn write_package(
+    out_path: &Path,
+    database_path: &Path,
+    projects: Vec<PackageProject>,
+    attachments: &[AttachmentSource],
+    required_secrets: Vec<String>,
+) -> Result<(PackageManifest, Vec<String>), IteError> {
+    if let Some(parent) = out_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut zip = ZipWriter::new(File::create(out_path)?);
+    let options = SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .large_file(true);
+
+    zip.start_file(DATABASE_ENTRY, options).map_err(zip_error)?;
+    std::io::copy(&mut File::open(database_path)?, &mut zip)?;
+
+    let mut packed = Vec::new();
+    let mut skipped = Vec::new();
+    for a in attachments {
+        let Ok(bytes) = std::fs::read(a.path) else {
+            skipped.push(a.id.to_string());
+            continue;
+        };
+        let entry = format!("{}/{}/{}", ATTACHMENTS_DIR, a.id, safe_file_name(a.filename));
+        zip.start_file(entry.as_str(), options).map_err(zip_error)?;
+        zip.write_all(&bytes)?;
+        packed.push(PackageAttachment {
+            id: a.id.to_string(),
+            project_id: a.project_id.to_string(),
+            filename: a.filename.to_string(),
+            entry,
+            size: bytes.len() as u64,
+            sha256: sha256_hex(&bytes),
+        });
+    }
+
+    let manifest = PackageManifest {
+        format: PACKAGE_FORMAT_NAME.to_string(),
+        format_version: PACKAGE_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: chrono::Utc::now().timestamp_millis(),
+        projects,
+        attachments: packed,
+        required_secrets,
+    };
+    // manifest는 마지막에 기록 (중간에 실패하면 manifest 없는 불완전 파일로 남아 import에서 거부됨)
+    zip.start_file(MANIFEST_ENTRY, options).map_err(zip_error)?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+    zip.finish().map_err(zip_error)?;
+
+    Ok((manifest, skipped))
+}
+
+/// v2 패키지의 manifest 읽기 + 호환성 검사
+pub fn read_manifest(path: &Path) -> Result<PackageManifest, IteError> {
+    let mut archive = ZipArchive::new(File::open(path)?).map_err(zip_error)?;
+    let mut json = String::new();
+    archive
+        .by_name(MANIFEST_ENTRY)
+        .map_err(|_| IteError::InvalidOperation("Package has no manifest.json".to_string()))?
+        .read_to_string(&mut json)?;
+
+    let manifest: PackageManifest = serde_json::from_str(&json)?;
+    if manifest.format != PACKAGE_FORMAT_NAME {
+        return Err(IteError::InvalidOperation(format!(
+            "Unknown package format: {}",
+            manifest.format
+        )));
+    }
+    if manifest.format_version > PACKAGE_FORMAT_VERSION {
+        return Err(IteError::InvalidOperation(format!(
+            "Package format v{} is newer than supported v{}; update the app",
+            manifest.format_version, PACKAGE_FORMAT_VERSION
+        )));
+    }
+    Ok(manifest)
+}
+
+/// 압축 해제 결과
+pub struct ExtractedPackage {
+    pub manifest: PackageManifest,
+    pub database_path: PathBuf,
+    /// (attachment_id, 추출된 파일 경로)
+    pub attachments: Vec<(String, PathBuf)>,
+}
+
+/// v2 패키지에서 DB만 `work_dir/project.sqlite`로 추출 (검사/미리보기용)
+pub fn extract_database(path: &Path, work_dir: &Path) -> Result<(PackageManifest, PathBuf), IteError> {
+    let manifest = read_manifest(path)?;
+    let mut archive = ZipArchive::new(File::open(path)?).map_err(zip_error)?;
+
+    std::fs::create_dir_all(work_dir)?;
+    let database_path = work_dir.join(DATABASE_ENTRY);
+    let mut entry = archive
+        .by_name(DATABASE_ENTRY)
+        .map_err(|_| IteError::InvalidOperation("Package has no project.sqlite".to_string()))?;
+    std::io::copy(&mut entry, &mut File::create(&database_path)?)?;
+    Ok((manifest, database_path))
+}
+
+/// v2 패키지 압축 해제
+/// - DB는 `work_dir/project.sqlite`, 첨부는 `attachments_dir/<id>/<filename>`에 풉니다.
+/// - manifest에 기록된 항목만 추출하며, 해시가 맞지 않는 첨부는 건너뜁니다.
+pub fn extract_package(path: &Path, work_dir: &Path, attachments_dir: &Path) -> Result<ExtractedPackage, IteError> {
+    let (manifest, database_path) = extract_database(path, work_dir)?;
+    let mut archive = ZipArchive::new(File::open(path)?).map_err(zip_error)?;
+
+    let mut attachments = Vec::new();
+    for a in &manifest.attachments {
+        let Ok(mut entry) = archive.by_name(&a.entry) else {
+            continue;
+        };
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        if sha256_hex(&bytes) != a.sha256 {
+            continue;
+        }
+
+        let dir = attachments_dir.join(safe_file_name(&a.id));
+        std::fs::create_dir_all(&dir)?;
+        let out = dir.join(safe_file_name(&a.filename));
+        std::fs::write(&out, &bytes)?;
+        attachments.push((a.id.clone(), out));
+    }
+
+    Ok(ExtractedPackage {
+        manifest,
+        database_path,
+        attachments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_package_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("db.sqlite");
+        std::fs::write(&db_path, b"SQLite format 3\0fake").unwrap();
+        let att_path = dir.path().join("ref.txt");
+        std::fs::write(&att_path, b"reference").unwrap();
+
+        let out = dir.path().join("out.ite");
+        let (manifest, skipped) = write_package(
+            &out,
+            &db_path,
+            vec![PackageProject { id: "p".into(), title: "T".into() }],
+            &[
+                AttachmentSource { id: "a1", project_id: "p", filename: "../ref.txt", path: &att_path },
+                AttachmentSource { id: "a2", project_id: "p", filename: "gone.txt", path: &dir.path().join("gone") },
+            ],
+            vec!["connector/x/token_json".into()],
+        )
+        .unwrap();
+        assert_eq!(skipped, vec!["a2"]);
+        assert_eq!(manifest.attachments[0].entry, "attachments/a1/ref.txt");
+        assert_eq!(detect_package_kind(&out).unwrap(), Some(PackageKind::Container));
+        assert_eq!(detect_package_kind(&db_path).unwrap(), Some(PackageKind::LegacyDatabase));
+
+        let extracted = extract_package(&out, &dir.path().join("work"), &dir.path().join("files")).unwrap();
+        assert_eq!(extracted.manifest.required_secrets, vec!["connector/x/token_json"]);
+        assert_eq!(std::fs::read(&extracted.database_path).unwrap(), b"SQLite format 3\0fake");
+        assert_eq!(extracted.attachments.len(), 1);
+        assert_eq!(std::fs::read(&extracted.attachments[0].1).unwrap(), b"reference");
+    }
+}
@@ -0,0 +1,263 @@
+//! Terminology Consistency Check
+//!
+//! 같은 원문(용어/세그먼트)이 프로젝트 안에서 서로 다르게 번역되었는지 검사합니다.
+//! - glossary: 용어집 원문 용어가 등장한 세그먼트 중 지정 번역어를 쓰지 않은 곳
+//! - segment: 원문이 같은 세그먼트(프로젝트 내 TM 매치)의 번역문이 서로 다른 경우
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::{segment_texts, QaIssue, QaSeverity};
+use crate::db::GlossaryEntryRow;
+use crate::models::{IteProject, SegmentGroup};
+use crate::text::{is_ascii_word_char, normalize_for_matching, strip_html};
+
+pub const CHECK_ID: &str = "consistency";
+
+/// 불일치 묶음의 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsistencyKind {
+    Glossary,
+    Segment,
+}
+
+/// 용어/세그먼트가 등장한 위치
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyOccurrence {
+    pub segment_id: String,
+    pub source_block_id: Option<String>,
+    pub target_block_id: Option<String>,
+}
+
+/// 같은 번역을 사용한 위치 묶음
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyVariant {
+    /// 사용된 번역 (glossary 검사에서 None이면 지정 번역어를 쓰지 않은 경우)
+    pub translation: Option<String>,
+    pub occurrences: Vec<ConsistencyOccurrence>,
+}
+
+/// 번역 불일치 묶음
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyCluster {
+    pub kind: ConsistencyKind,
+    pub source: String,
+    /// 용어집 지정 번역어 (glossary 검사만)
+    pub expected: Option<String>,
+    pub glossary_entry_id: Option<String>,
+    /// 많이 쓰인 순서
+    pub variants: Vec<ConsistencyVariant>,
+}
+
+/// 용어 포함 여부 (ASCII 영숫자로 시작/끝나는 용어는 단어 중간 매칭을 배제)
+fn contains_term(text: &str, term: &str, case_sensitive: bool) -> bool {
+    let (text, term): (Cow<str>, Cow<str>) = if case_sensitive {
+        (Cow::Borrowed(text), Cow::Borrowed(term))
+    } else {
+        (Cow::Owned(text.to_lowercase()), Cow::Owned(term.to_lowercase()))
+    };
+    if term.is_empty() {
+        return false;
+    }
+    let check_start = term.chars().next().is_some_and(is_ascii_word_char);
+    let check_end = term.chars().last().is_some_and(is_ascii_word_char);
+
+    text.match_indices(term.as_ref()).any(|(i, m)| {
+        let start_ok = !check_start || !text[..i].chars().next_back().is_some_and(is_ascii_word_char);
+        let end_ok = !check_end || !text[i + m.len()..].chars().next().is_some_and(is_ascii_word_char);
+        start_ok && end_ok
+    })
+}
+
+fn occurrence(project: &IteProject, segment: &SegmentGroup, term: Option<&GlossaryEntryRow>) -> ConsistencyOccurrence {
+    let source_block_id = match term {
+        Some(entry) => segment
+            .source_ids
+            .iter()
+            .find(|id| {
+                project
+                    .blocks
+                    .get(*id)
+                    .is_some_and(|b| contains_term(&strip_html(&b.content), &entry.source, entry.case_sensitive))
+            })
+            .cloned(),
+        None => segment.source_ids.first().cloned(),
+    };
+    ConsistencyOccurrence {
+        segment_id: segment.group_id.clone(),
+        source_block_id,
+        target_block_id: segment.target_ids.first().cloned(),
+    }
+}
+
+/// 번역 불일치 묶음 계산 (미번역 세그먼트는 제외)
+pub fn find_clusters(project: &IteProject, glossary: &[GlossaryEntryRow]) -> Vec<ConsistencyCluster> {
+    let translated: Vec<(&SegmentGroup, String, String)> = project
+        .segments
+        .iter()
+        .filter_map(|segment| {
+            let (source, target) = segment_texts(project, segment);
+            (!target.trim().is_empty()).then_some((segment, source, target))
+        })
+        .collect();
+
+    let mut clusters = Vec::new();
+
+    // 1) 용어집 기준
+    for entry in glossary {
+        let source_term = entry.source.trim();
+        let expected = entry.target.trim();
+        if source_term.is_empty() || expected.is_empty() {
+            continue;
+        }
+
+        let mut used: Vec<ConsistencyOccurrence> = Vec::new();
+        let mut missed: Vec<ConsistencyOccurrence> = Vec::new();
+        for (segment, source, target) in &translated {
+            if !contains_term(source, source_term, entry.case_sensitive) {
+                continue;
+            }
+            let occ = occurrence(project, segment, Some(entry));
+            if contains_term(target, expected, entry.case_sensitive) {
+                used.push(occ);
+            } else {
+                missed.push(occ);
+            }
+        }
+        if missed.is_empty() {
+            continue;
+        }
+
+        let mut variants = vec![
+            ConsistencyVariant {
+                translation: Some(expected.to_string()),
+                occurrences: used,
+            },
+            ConsistencyVariant {
+                translation: None,
+                occurrences: missed,
+            },
+        ];
+        variants.retain(|v| !v.occurrences.is_empty());
+        variants.sort_by_key(|v| std::cmp::Reverse(v.occurrences.len()));
+        clusters.push(ConsistencyCluster {
+            kind: ConsistencyKind::Glossary,
+            source: source_term.to_string(),
+            expected: Some(expected.to_string()),
+            glossary_entry_id: Some(entry.id.clone()),
+            variants,
+        });
+    }
+
+    // 2) 같은 원문 세그먼트 기준 (문서 순서 유지)
+    let mut order: Vec<String> = Vec::new();
+    let mut by_source: HashMap<String, Vec<(&SegmentGroup, &str)>> = HashMap::new();
+    for (segment, source, target) in &translated {
+        let key = normalize_for_matching(source);
+        if key.is_empty() {
+            continue;
+        }
+        by_source
+            .entry(key.clone())
+            .or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            })
+            .push((segment, target.as_str()));
+    }
+
+    for key in order {
+        let Some(members) = by_source.remove(&key) else {
+            continue;
+        };
+        let mut variants: Vec<ConsistencyVariant> = Vec::new();
+        for (segment, target) in members {
+            let translation = normalize_for_matching(target);
+            let occ = occurrence(project, segment, None);
+            match variants.iter_mut().find(|v| v.translation.as_deref() == Some(translation.as_str())) {
+                Some(v) => v.occurrences.push(occ),
+                None => variants.push(ConsistencyVariant {
+                    translation: Some(translation),
+                    occurrences: vec![occ],
+                }),
+            }
+        }
+        if variants.len() < 2 {
+            continue;
+        }
+        variants.sort_by_key(|v| std::cmp::Reverse(v.occurrences.len()));
+        clusters.push(ConsistencyCluster {
+            kind: ConsistencyKind::Segment,
+            source: key,
+            expected: None,
+            glossary_entry_id: None,
+            variants,
+        });
+    }
+
+    clusters
+}
+
+/// 불일치 묶음을 QA 이슈로 변환
+/// - glossary: 지정 번역어를 쓰지 않은 위치마다 경고
+/// - segment: 가장 많이 쓰인 번역과 다른 위치마다 경고
+pub fn check(project: &IteProject, glossary: &[GlossaryEntryRow]) -> Vec<QaIssue> {
+    let mut issues = Vec::new();
+    for cluster in find_clusters(project, glossary) {
+        match cluster.kind {
+            ConsistencyKind::Glossary => {
+                let expected = cluster.expected.clone().unwrap_or_default();
+                for v in cluster.variants.iter().filter(|v| v.translation.is_none()) {
+                    for occ in &v.occurrences {
+                        issues.push(QaIssue {
+                            check: CHECK_ID.to_string(),
+                            severity: QaSeverity::Warning,
+                            segment_id: Some(occ.segment_id.clone()),
+                            block_id: occ.target_block_id.clone(),
+                            message: format!(
+                                "용어 '{}'가 용어집 번역어 '{}'와 다르게 번역되었습니다.",
+                                cluster.source, expected
+                            ),
+                            details: cluster.glossary_entry_id.clone(),
+                        });
+                    }
+                }
+            }
+            ConsistencyKind::Segment => {
+                let preferred = cluster.variants[0].translation.clone().unwrap_or_default();
+                for v in cluster.variants.iter().skip(1) {
+                    for occ in &v.occurrences {
+                        issues.push(QaIssue {
+                            check: CHECK_ID.to_string(),
+                            severity: QaSeverity::Warning,
+                            segment_id: Some(occ.segment_id.clone()),
+                            block_id: occ.target_block_id.clone(),
+                            message: "같은 원문이 다른 세그먼트와 다르게 번역되었습니다.".to_string(),
+                            details: Some(format!("preferred: {}", preferred)),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_term_respects_word_boundaries_and_case() {
+        assert!(contains_term("Open the API docs", "api", false));
+        assert!(!contains_term("RAPID growth", "api", false));
+        assert!(!contains_term("Open the API docs", "api", true));
+        assert!(contains_term("설정 메뉴에서", "설정", true));
+    }
+}
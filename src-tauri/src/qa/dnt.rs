@@ -0,0 +1,57 @@
+//! DNT QA Check
+//!
+//! 원문에 있는 번역 금지 용어가 번역문에 그대로 남아 있는지 검사합니다.
+
+use std::collections::HashMap;
+
+use super::{segment_texts, QaIssue, QaSeverity};
+use crate::models::IteProject;
+use crate::text::dnt::DntMatcher;
+
+pub const CHECK_ID: &str = "dnt";
+
+/// 원문의 DNT 구간이 번역문에서 변경/누락되었으면 이슈로 보고
+/// - 번역문이 비어 있는(미번역) 세그먼트는 건너뜁니다.
+pub fn check(project: &IteProject, matcher: &DntMatcher) -> Vec<QaIssue> {
+    let mut issues = Vec::new();
+    if matcher.is_empty() {
+        return issues;
+    }
+
+    for segment in &project.segments {
+        let (source, target) = segment_texts(project, segment);
+        if target.trim().is_empty() {
+            continue;
+        }
+
+        // 원문에 등장한 DNT 문자열별 횟수
+        let mut expected: Vec<(String, usize)> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for m in matcher.find_all(&source) {
+            let text = source[m.start..m.end].to_string();
+            match index.get(&text) {
+                Some(&i) => expected[i].1 += 1,
+                None => {
+                    index.insert(text.clone(), expected.len());
+                    expected.push((text, 1));
+                }
+            }
+        }
+
+        for (term, count) in expected {
+            let found = target.matches(term.as_str()).count();
+            if found < count {
+                issues.push(QaIssue {
+                    check: CHECK_ID.to_string(),
+                    severity: QaSeverity::Error,
+                    segment_id: Some(segment.group_id.clone()),
+                    block_id: segment.target_ids.first().cloned(),
+                    message: format!("번역 금지 용어 '{}'가 번역문에서 변경되었거나 누락되었습니다.", term),
+                    details: Some(format!("source: {}, target: {}", count, found)),
+                });
+            }
+        }
+    }
+
+    issues
+}
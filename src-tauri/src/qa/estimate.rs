@@ -0,0 +1,284 @@
+//! MT Quality Estimation
+//!
+//! 참조 번역 없이 원문/번역문만 보고 세그먼트별 신뢰도(0.0–1.0)를 추정하는 휴리스틱 (리뷰 우선순위용).
+//! - 1.0에서 시작해 신호마다 감점합니다: 미번역, 다른 언어, 길이 비율 이상, 숫자 누락, 용어집 번역 누락, 단어 반복.
+//! - 길이 비율은 언어쌍마다 다르므로 프로젝트 안 세그먼트들의 중앙값을 기준으로 봅니다.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::segment_texts;
+use crate::models::IteProject;
+use crate::text::content_hash;
+use crate::text::glossary::GlossaryMatcher;
+use crate::text::lang::{detect_language, normalize_language};
+
+/// 이보다 짧은 원문은 길이 비율을 보지 않음 (글자 수)
+const MIN_RATIO_SOURCE_CHARS: usize = 20;
+
+/// 같은 단어가 이만큼 연달아 나오면 반복 출력으로 봄
+const MAX_REPEATED_TOKENS: usize = 3;
+
+/// 신뢰도 감점 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualitySignal {
+    /// 번역문이 원문과 같음
+    Untranslated,
+    /// 번역문이 프로젝트 번역 언어가 아님
+    WrongLanguage,
+    /// 원문 대비 길이가 프로젝트 평균에서 크게 벗어남
+    LengthRatio,
+    /// 원문 숫자가 번역문에 없음
+    Numbers,
+    /// 원문에 나온 용어의 용어집 번역이 번역문에 없음
+    Glossary,
+    /// 같은 단어가 연달아 반복됨 (MT 반복 출력)
+    RepeatedWords,
+}
+
+/// 세그먼트 품질 추정 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentQuality {
+    pub segment_id: String,
+    /// 0.0(낮음) ~ 1.0(높음)
+    pub score: f64,
+    pub signals: Vec<QualitySignal>,
+    /// 추정 당시 번역문 평문 해시 (번역문이 바뀌었는지 판별용)
+    pub target_hash: String,
+    /// Unix epoch ms
+    pub estimated_at: i64,
+    /// 추정 이후 번역문이 바뀜 (조회 시 계산)
+    #[serde(default)]
+    pub stale: bool,
+}
+
+/// 추정 대상 세그먼트 텍스트
+struct Candidate {
+    segment_id: String,
+    source: String,
+    target: String,
+}
+
+/// 숫자 목록 (자릿수/소수 구분 기호는 언어마다 달라 빼고 비교)
+fn numbers(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            current.push(c);
+        } else if !current.is_empty() && !matches!(c, ',' | '.') {
+            out.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        out.push(current);
+    }
+    out
+}
+
+fn has_repeated_words(text: &str) -> bool {
+    let mut run = 1;
+    let mut prev: Option<String> = None;
+    for token in text.split_whitespace() {
+        let token = token
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if token.chars().count() < 2 {
+            prev = None;
+            run = 1;
+            continue;
+        }
+        if prev.as_deref() == Some(token.as_str()) {
+            run += 1;
+            if run >= MAX_REPEATED_TOKENS {
+                return true;
+            }
+        } else {
+            run = 1;
+        }
+        prev = Some(token);
+    }
+    false
+}
+
+fn length_ratio(source: &str, target: &str) -> Option<f64> {
+    let source_len = source.chars().filter(|c| !c.is_whitespace()).count();
+    let target_len = target.chars().filter(|c| !c.is_whitespace()).count();
+    (source_len >= MIN_RATIO_SOURCE_CHARS && target_len > 0).then(|| target_len as f64 / source_len as f64)
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    Some(values[values.len() / 2])
+}
+
+/// 번역문 하나의 신뢰도와 감점 사유
+fn score_segment(
+    candidate: &Candidate,
+    target_language: Option<&str>,
+    median_ratio: Option<f64>,
+    glossary: &GlossaryMatcher,
+) -> (f64, Vec<QualitySignal>) {
+    let mut penalty = 0.0;
+    let mut signals = Vec::new();
+    let (source, target) = (candidate.source.trim(), candidate.target.trim());
+
+    let normalized = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    if normalized(source) == normalized(target) && source.chars().any(char::is_alphabetic) {
+        penalty += 0.9;
+        signals.push(QualitySignal::Untranslated);
+    } else if let Some(expected) = target_language {
+        let detection = detect_language(target);
+        if detection.reliable && detection.code.as_deref().is_some_and(|code| code != expected) {
+            penalty += 0.4;
+            signals.push(QualitySignal::WrongLanguage);
+        }
+    }
+
+    if let (Some(ratio), Some(median)) = (length_ratio(source, target), median_ratio) {
+        let deviation = (ratio / median).ln().abs();
+        if deviation > 3f64.ln() {
+            penalty += 0.4;
+            signals.push(QualitySignal::LengthRatio);
+        } else if deviation > 2f64.ln() {
+            penalty += 0.2;
+            signals.push(QualitySignal::LengthRatio);
+        }
+    }
+
+    let target_numbers: HashSet<String> = numbers(target).into_iter().collect();
+    let missing_numbers = numbers(source)
+        .iter()
+        .filter(|n| !target_numbers.contains(*n))
+        .count();
+    if missing_numbers > 0 {
+        penalty += (0.15 * missing_numbers as f64).min(0.3);
+        signals.push(QualitySignal::Numbers);
+    }
+
+    if !glossary.is_empty() {
+        let target_lower = target.to_lowercase();
+        let missing_terms = glossary
+            .find_all(source)
+            .iter()
+            .filter(|hit| !hit.target.trim().is_empty() && !target_lower.contains(&hit.target.trim().to_lowercase()))
+            .count();
+        if missing_terms > 0 {
+            penalty += (0.1 * missing_terms as f64).min(0.3);
+            signals.push(QualitySignal::Glossary);
+        }
+    }
+
+    if has_repeated_words(target) {
+        penalty += 0.3;
+        signals.push(QualitySignal::RepeatedWords);
+    }
+
+    let score = ((1.0f64 - penalty).clamp(0.0, 1.0) * 100.0).round() / 100.0;
+    (score, signals)
+}
+
+/// 번역문이 있는 세그먼트의 품질 추정 (문서 순서)
+/// - `tag`가 주어지면 번역문 블록에 그 태그가 있는 세그먼트만 추정합니다 (예: MT로 채운 세그먼트).
+pub fn estimate_project(project: &IteProject, glossary: &GlossaryMatcher, tag: Option<&str>) -> Vec<SegmentQuality> {
+    let mut segments: Vec<_> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+
+    let candidates: Vec<Candidate> = segments
+        .into_iter()
+        .filter(|segment| match tag {
+            Some(tag) => segment
+                .target_ids
+                .iter()
+                .filter_map(|id| project.blocks.get(id))
+                .any(|b| b.metadata.tags.iter().any(|t| t == tag)),
+            None => true,
+        })
+        .map(|segment| {
+            let (source, target) = segment_texts(project, segment);
+            Candidate {
+                segment_id: segment.group_id.clone(),
+                source,
+                target,
+            }
+        })
+        .filter(|c| !c.source.trim().is_empty() && !c.target.trim().is_empty())
+        .collect();
+
+    let mut ratios: Vec<f64> = candidates
+        .iter()
+        .filter_map(|c| length_ratio(&c.source, &c.target))
+        .collect();
+    let median_ratio = median(&mut ratios);
+    let target_language = project.metadata.target_language.as_deref().and_then(normalize_language);
+    let now = chrono::Utc::now().timestamp_millis();
+
+    candidates
+        .iter()
+        .map(|candidate| {
+            let (score, signals) = score_segment(candidate, target_language, median_ratio, glossary);
+            SegmentQuality {
+                segment_id: candidate.segment_id.clone(),
+                score,
+                signals,
+                target_hash: content_hash(&candidate.target),
+                estimated_at: now,
+                stale: false,
+            }
+        })
+        .collect()
+}
+
+/// 저장된 추정 결과에 현재 번역문 기준 `stale` 표시
+pub fn mark_stale(project: &IteProject, estimates: &mut [SegmentQuality]) {
+    for estimate in estimates {
+        let current = project
+            .segments
+            .iter()
+            .find(|s| s.group_id == estimate.segment_id)
+            .map(|s| content_hash(&segment_texts(project, s).1));
+        estimate.stale = current.as_deref() != Some(estimate.target_hash.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(source: &str, target: &str) -> Candidate {
+        Candidate {
+            segment_id: "s".to_string(),
+            source: source.to_string(),
+            target: target.to_string(),
+        }
+    }
+
+    #[test]
+    fn penalizes_untranslated_numbers_and_repetition() {
+        let glossary = GlossaryMatcher::new(&[]);
+        let score =
+            |source: &str, target: &str| score_segment(&candidate(source, target), Some("ko"), Some(1.0), &glossary);
+
+        let (good, signals) = score("Press the button twice.", "버튼을 두 번 누르세요.");
+        assert!(signals.is_empty());
+        assert_eq!(good, 1.0);
+
+        let (low, signals) = score("Press the button twice.", "Press the  button twice.");
+        assert_eq!(signals, vec![QualitySignal::Untranslated]);
+        assert!(low < 0.2);
+
+        let (_, signals) = score("Version 2.1 ships in 2024", "버전 2.1은 출시 출시 출시");
+        assert_eq!(signals, vec![QualitySignal::Numbers, QualitySignal::RepeatedWords]);
+    }
+
+    #[test]
+    fn extracts_numbers() {
+        assert_eq!(numbers("1,024 items at 3.5% on 2024-01"), vec!["1024", "35", "2024", "01"]);
+    }
+}
@@ -0,0 +1,137 @@
+//! Length QA Check
+//!
+//! 원본 요소의 분량 제약(`CapacityHint`)을 넘는 번역문을 찾습니다.
+//! - 번역문 블록에 제약이 없으면 같은 세그먼트 원문 블록의 제약을 씁니다.
+//! - 텍스트 상자는 크기/글꼴로 대략의 글자 수를 추정하므로, 실제 렌더링과 다를 수 있어 경고로만 보고합니다.
+
+use super::{QaIssue, QaSeverity};
+use crate::models::{CapacityHint, IteProject};
+use crate::text::strip_html;
+use crate::text::words::count_words;
+
+pub const CHECK_ID: &str = "length";
+
+/// 자막 읽기 속도 (초당 문자 수, 일반적인 자막 가이드라인 기준)
+pub const CUE_CHARS_PER_SECOND: f64 = 17.0;
+
+/// 1pt = 12700 EMU
+const EMU_PER_POINT: f64 = 12700.0;
+
+/// 줄 간격 (글꼴 크기 대비)
+const LINE_HEIGHT_RATIO: f64 = 1.2;
+
+/// 평균 글자 폭 (글꼴 크기 대비)
+const CHAR_WIDTH_RATIO: f64 = 0.55;
+
+/// 제약에서 계산한 허용 분량
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub max_chars: Option<usize>,
+    pub max_words: Option<usize>,
+}
+
+/// 텍스트 상자 크기/글꼴로 추정한 최대 글자 수
+fn textbox_chars(hint: &CapacityHint) -> Option<usize> {
+    let (width, height, font) = (hint.width_emu?, hint.height_emu?, hint.font_size_pt?);
+    if width <= 0 || height <= 0 || font <= 0.0 {
+        return None;
+    }
+    let lines = (height as f64 / EMU_PER_POINT / (font * LINE_HEIGHT_RATIO)).floor().max(1.0);
+    let per_line = (width as f64 / EMU_PER_POINT / (font * CHAR_WIDTH_RATIO)).floor().max(1.0);
+    Some((lines * per_line) as usize)
+}
+
+/// 제약의 허용 분량 (여러 값이 있으면 가장 엄격한 값)
+pub fn budget(hint: &CapacityHint) -> Budget {
+    let cue_chars = hint
+        .duration_ms
+        .filter(|ms| *ms > 0)
+        .map(|ms| (ms as f64 / 1000.0 * CUE_CHARS_PER_SECOND).floor() as usize);
+    let max_chars = [hint.max_chars.map(|c| c as usize), cue_chars, textbox_chars(hint)]
+        .into_iter()
+        .flatten()
+        .min();
+    Budget {
+        max_chars,
+        max_words: hint.max_words.map(|w| w as usize),
+    }
+}
+
+/// 분량 제약을 넘는 번역문 블록을 이슈로 보고
+/// - 비어 있는(미번역) 블록은 건너뜁니다.
+pub fn check(project: &IteProject) -> Vec<QaIssue> {
+    let mut issues = Vec::new();
+
+    for segment in &project.segments {
+        let source_hint = segment
+            .source_ids
+            .iter()
+            .filter_map(|id| project.blocks.get(id))
+            .find_map(|b| b.metadata.capacity.as_ref());
+
+        for id in &segment.target_ids {
+            let Some(block) = project.blocks.get(id) else {
+                continue;
+            };
+            let Some(hint) = block.metadata.capacity.as_ref().or(source_hint) else {
+                continue;
+            };
+            let text = strip_html(&block.content);
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let limit = budget(hint);
+            let chars = text.chars().count();
+            let words = count_words(&text);
+            let mut over = Vec::new();
+            if let Some(max) = limit.max_chars.filter(|max| chars > *max) {
+                over.push(format!("chars: {} / {}", chars, max));
+            }
+            if let Some(max) = limit.max_words.filter(|max| words > *max) {
+                over.push(format!("words: {} / {}", words, max));
+            }
+            if over.is_empty() {
+                continue;
+            }
+
+            issues.push(QaIssue {
+                check: CHECK_ID.to_string(),
+                severity: QaSeverity::Warning,
+                segment_id: Some(segment.group_id.clone()),
+                block_id: Some(id.clone()),
+                message: format!("번역문이 원본 요소({})의 분량 제한을 넘었습니다.", hint.kind),
+                details: Some(over.join(", ")),
+            });
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_uses_strictest_limit() {
+        let cue = CapacityHint {
+            kind: "cue".to_string(),
+            max_chars: Some(80),
+            duration_ms: Some(2000),
+            ..Default::default()
+        };
+        assert_eq!(budget(&cue).max_chars, Some(34));
+
+        // 10pt 글꼴, 100pt x 24pt 상자 → 2줄 x 18자
+        let textbox = CapacityHint {
+            kind: "textbox".to_string(),
+            width_emu: Some(100 * 12700),
+            height_emu: Some(24 * 12700),
+            font_size_pt: Some(10.0),
+            ..Default::default()
+        };
+        assert_eq!(budget(&textbox).max_chars, Some(36));
+        assert_eq!(budget(&CapacityHint::default()), Budget { max_chars: None, max_words: None });
+    }
+}
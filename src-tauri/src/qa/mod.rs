@@ -0,0 +1,50 @@
+//! QA Module
+//!
+//! 번역 품질 검사(QA) 규칙과 공통 결과 타입
+
+pub mod consistency;
+pub mod dnt;
+pub mod estimate;
+pub mod length;
+pub mod numeric;
+
+use serde::Serialize;
+
+use crate::models::{IteProject, SegmentGroup};
+use crate::text::strip_html;
+
+/// QA 이슈 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QaSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// QA 검사에서 발견된 이슈
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QaIssue {
+    /// 검사 종류 (예: "dnt")
+    pub check: String,
+    pub severity: QaSeverity,
+    pub segment_id: Option<String>,
+    /// 문제가 있는 번역문 블록
+    pub block_id: Option<String>,
+    pub message: String,
+    /// 관련 용어/구간 등 부가 정보
+    pub details: Option<String>,
+}
+
+/// 세그먼트의 원문/번역문 평문 (여러 블록은 줄바꿈으로 연결)
+pub fn segment_texts(project: &IteProject, segment: &SegmentGroup) -> (String, String) {
+    let join = |ids: &[String]| {
+        ids.iter()
+            .filter_map(|id| project.blocks.get(id))
+            .map(|b| strip_html(&b.content))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    (join(&segment.source_ids), join(&segment.target_ids))
+}
@@ -0,0 +1,375 @@
+//! Number/Date Format QA Check
+//!
+//! 원문의 숫자/날짜를 원문 로캘 규칙으로 읽고, 번역문이 번역 로캘 형식(소수점, 천 단위 구분, 날짜 순서)을 따르는지 검사합니다.
+//! - 원문 형식 그대로 옮겨 값이 달라지는 숫자(예: en "1,234" → es "1,234")와 날짜(예: en "03/05/2024" → ko 그대로)를 보고합니다.
+//! - 원문의 숫자가 번역문에 없으면 경고합니다. 한 자리 정수는 말로 풀어 쓰는 경우가 많아 제외합니다.
+//! - 버전/IP처럼 어느 규칙으로도 읽을 수 없는 값, 영문자에 붙은 값(예: "A4", "v2")은 비교하지 않습니다.
+
+use super::{segment_texts, QaIssue, QaSeverity};
+use crate::models::IteProject;
+use crate::text::lang::normalize_language;
+use crate::text::locale::{DateOrder, NumberFormat};
+
+pub const CHECK_ID: &str = "numbers";
+
+const DATE_SEPARATORS: [char; 3] = ['/', '.', '-'];
+
+/// 숫자 날짜 (연도는 적힌 그대로, 두 자리 연도 포함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Date {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+/// 텍스트에서 찾은 날짜 (바이트 범위, 숫자 부분, 구분자)
+struct DateToken<'a> {
+    start: usize,
+    end: usize,
+    parts: [&'a str; 3],
+    separator: char,
+}
+
+fn digit_run(text: &str, from: usize) -> &str {
+    let len = text[from..].find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len() - from);
+    &text[from..from + len]
+}
+
+/// "03/05/2024", "2024-03-05", "5.3.24" 형태의 날짜
+fn date_tokens(text: &str) -> Vec<DateToken<'_>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_here = bytes[i].is_ascii_digit() && (i == 0 || !matches!(bytes[i - 1], b'0'..=b'9' | b'.' | b','));
+        if !starts_here {
+            i += 1;
+            continue;
+        }
+        let first = digit_run(text, i);
+        let after_first = i + first.len();
+        let Some(separator) = text[after_first..].chars().next().filter(|c| DATE_SEPARATORS.contains(c)) else {
+            i = after_first;
+            continue;
+        };
+        let second = digit_run(text, after_first + 1);
+        let after_second = after_first + 1 + second.len();
+        let third = if text[after_second..].starts_with(separator) {
+            digit_run(text, after_second + 1)
+        } else {
+            ""
+        };
+        let end = after_second + 1 + third.len();
+        let shape_ok = (1..=4).contains(&first.len())
+            && (1..=2).contains(&second.len())
+            && (1..=4).contains(&third.len())
+            && (first.len() == 4 || matches!(third.len(), 2 | 4));
+        // "1.2.3.4"처럼 구분자 뒤에 숫자가 더 이어지면 날짜가 아님
+        let trailing = || {
+            text[end..]
+                .strip_prefix(separator)
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        };
+        if shape_ok && !trailing() {
+            tokens.push(DateToken {
+                start: i,
+                end,
+                parts: [first, second, third],
+                separator,
+            });
+            i = end;
+        } else {
+            i = after_first;
+        }
+    }
+    tokens
+}
+
+/// 날짜 순서에 맞춰 읽기 (네 자리로 시작하면 순서와 관계없이 연-월-일)
+fn parse_date(parts: [&str; 3], order: DateOrder) -> Option<Date> {
+    let [a, b, c] = parts.map(|p| p.parse::<u32>().ok());
+    let (a, b, c) = (a?, b?, c?);
+    let date = if parts[0].len() == 4 {
+        Date { year: a, month: b, day: c }
+    } else {
+        match order {
+            DateOrder::Mdy => Date { year: c, month: a, day: b },
+            DateOrder::Dmy => Date { year: c, month: b, day: a },
+            DateOrder::Ymd => return None,
+        }
+    };
+    ((1..=12).contains(&date.month) && (1..=31).contains(&date.day)).then_some(date)
+}
+
+fn format_date(date: Date, order: DateOrder, separator: char, pad: bool) -> String {
+    let part = |n: u32| if pad { format!("{:02}", n) } else { n.to_string() };
+    let (y, m, d) = (date.year.to_string(), part(date.month), part(date.day));
+    let s = separator;
+    match order {
+        DateOrder::Ymd => format!("{y}{s}{m}{s}{d}"),
+        DateOrder::Mdy => format!("{m}{s}{d}{s}{y}"),
+        DateOrder::Dmy => format!("{d}{s}{m}{s}{y}"),
+    }
+}
+
+fn is_space_separator(c: char) -> bool {
+    matches!(c, ' ' | '\u{A0}' | '\u{202F}')
+}
+
+/// 숫자 토큰 (바이트 범위), 영문자에 붙은 토큰은 제외
+/// - `spaces`가 true면 "1 234"처럼 공백 뒤에 정확히 세 자리가 오는 경우도 한 숫자로 봅니다.
+fn number_tokens(text: &str, spaces: bool) -> Vec<(usize, usize)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let digit_at = |k: usize| chars.get(k).is_some_and(|(_, c)| c.is_ascii_digit());
+    let three_digits_at = |k: usize| (k..k + 3).all(digit_at) && !digit_at(k + 3);
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if !digit_at(i) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut j = i;
+        loop {
+            while digit_at(j) {
+                j += 1;
+            }
+            let joins = match chars.get(j) {
+                Some((_, '.' | ',')) => digit_at(j + 1),
+                Some((_, c)) if spaces && is_space_separator(*c) => three_digits_at(j + 1),
+                _ => false,
+            };
+            if !joins {
+                break;
+            }
+            j += 1;
+        }
+        let attached = |k: Option<usize>| k.and_then(|k| chars.get(k)).is_some_and(|(_, c)| c.is_ascii_alphabetic());
+        if !attached(start.checked_sub(1)) && !attached(Some(j)) {
+            let end = chars.get(j).map_or(text.len(), |(b, _)| *b);
+            tokens.push((chars[start].0, end));
+        }
+        i = j;
+    }
+    tokens
+}
+
+/// 표기 규칙으로 숫자 읽기, 값은 "1234.5" 형태 (천 단위 구분은 있어도 없어도 됨)
+fn parse_number(token: &str, format: &NumberFormat) -> Option<String> {
+    let (int, frac) = match token.rfind(format.decimal) {
+        Some(pos) => (&token[..pos], Some(&token[pos + format.decimal.len_utf8()..])),
+        None => (token, None),
+    };
+    if let Some(frac) = frac {
+        if frac.is_empty() || !frac.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+    }
+    let groups: Vec<&str> = int.split(|c| format.grouping.contains(&c)).collect();
+    let grouped = groups.len() > 1;
+    for (i, group) in groups.iter().enumerate() {
+        let len_ok = match (grouped, i) {
+            (false, _) => !group.is_empty(),
+            (true, 0) => (1..=3).contains(&group.len()),
+            (true, _) => group.len() == 3,
+        };
+        if !len_ok || !group.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+    }
+    let digits: String = groups.concat();
+    let digits = match digits.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+    Some(match frac {
+        Some(frac) => format!("{}.{}", digits, frac),
+        None => digits.to_string(),
+    })
+}
+
+/// 값을 번역 로캘 표기로 (네 자리 수는 소수점이 쉼표인 로캘에서 묶지 않음)
+fn format_number(value: &str, format: &NumberFormat) -> String {
+    let (int, frac) = match value.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (value, None),
+    };
+    let group = int.len() > 4 || (int.len() == 4 && format.decimal == '.');
+    let mut out = String::new();
+    for (i, c) in int.chars().enumerate() {
+        if group && i > 0 && (int.len() - i) % 3 == 0 {
+            out.push(format.grouping[0]);
+        }
+        out.push(c);
+    }
+    if let Some(frac) = frac {
+        out.push(format.decimal);
+        out.push_str(frac);
+    }
+    out
+}
+
+/// 두 자리 이상이거나 소수인 숫자만 누락 검사
+fn is_significant(value: &str) -> bool {
+    value.len() > 1
+}
+
+fn take(values: &mut Vec<String>, value: &str) -> bool {
+    match values.iter().position(|v| v == value) {
+        Some(pos) => {
+            values.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 날짜 자리를 공백으로 (숫자 토큰에서 제외, 바이트 위치 유지)
+fn mask_dates(text: &str, dates: &[DateToken<'_>]) -> String {
+    let mut masked = text.to_string();
+    for date in dates {
+        masked.replace_range(date.start..date.end, &" ".repeat(date.end - date.start));
+    }
+    masked
+}
+
+/// 숫자/날짜 형식이 번역 로캘에 맞지 않거나 원문 숫자가 빠진 번역문을 이슈로 보고
+/// - 번역 로캘을 알 수 없으면 검사하지 않습니다. 원문 로캘을 모르면 영어 규칙으로 읽습니다.
+/// - 번역문이 비어 있는(미번역) 세그먼트는 건너뜁니다.
+pub fn check(project: &IteProject, source_locale: Option<&str>) -> Vec<QaIssue> {
+    let mut issues = Vec::new();
+    let metadata = &project.metadata;
+    let Some(target_locale) = metadata
+        .target_locale
+        .clone()
+        .or_else(|| metadata.target_language.as_deref().and_then(normalize_language).map(str::to_string))
+    else {
+        return issues;
+    };
+
+    let (source_number, target_number) = (
+        NumberFormat::for_locale(source_locale),
+        NumberFormat::for_locale(Some(&target_locale)),
+    );
+    let (source_order, target_order) = (
+        DateOrder::for_locale(source_locale),
+        DateOrder::for_locale(Some(&target_locale)),
+    );
+    let spaces = source_number.groups_with_spaces() || target_number.groups_with_spaces();
+
+    for segment in &project.segments {
+        let (source, target) = segment_texts(project, segment);
+        if target.trim().is_empty() {
+            continue;
+        }
+        let mut issue = |message: String, details: String| {
+            issues.push(QaIssue {
+                check: CHECK_ID.to_string(),
+                severity: QaSeverity::Warning,
+                segment_id: Some(segment.group_id.clone()),
+                block_id: segment.target_ids.first().cloned(),
+                message,
+                details: Some(details),
+            });
+        };
+
+        // 날짜: 번역 순서로 읽어 원문과 다르고, 원문 순서로 읽으면 같으면 순서 오류
+        let source_date_tokens = date_tokens(&source);
+        let target_date_tokens = date_tokens(&target);
+        let mut source_dates: Vec<Date> = source_date_tokens
+            .iter()
+            .filter_map(|t| parse_date(t.parts, source_order))
+            .collect();
+        for token in &target_date_tokens {
+            if let Some(pos) = parse_date(token.parts, target_order).and_then(|d| source_dates.iter().position(|s| *s == d)) {
+                source_dates.remove(pos);
+                continue;
+            }
+            let Some(pos) = parse_date(token.parts, source_order).and_then(|d| source_dates.iter().position(|s| *s == d)) else {
+                continue;
+            };
+            let date = source_dates.remove(pos);
+            let pad = token.parts[1].len() == 2;
+            issue(
+                format!("번역문 날짜 순서가 번역 언어({}) 형식과 다릅니다.", target_locale),
+                format!(
+                    "{} → {}",
+                    &target[token.start..token.end],
+                    format_date(date, target_order, token.separator, pad)
+                ),
+            );
+        }
+
+        // 숫자: 번역 규칙으로 읽어 원문 값과 같으면 통과, 원문 규칙으로만 같으면 형식 오류
+        let source_masked = mask_dates(&source, &source_date_tokens);
+        let target_masked = mask_dates(&target, &target_date_tokens);
+        let mut source_values: Vec<String> = number_tokens(&source_masked, spaces)
+            .into_iter()
+            .filter_map(|(s, e)| parse_number(&source_masked[s..e], &source_number))
+            .collect();
+        for (start, end) in number_tokens(&target_masked, spaces) {
+            let token = &target_masked[start..end];
+            if parse_number(token, &target_number).is_some_and(|v| take(&mut source_values, &v)) {
+                continue;
+            }
+            let Some(value) = parse_number(token, &source_number).filter(|v| take(&mut source_values, v)) else {
+                continue;
+            };
+            issue(
+                format!("번역문 숫자 형식이 번역 언어({}) 규칙과 다릅니다.", target_locale),
+                format!("{} → {}", token, format_number(&value, &target_number)),
+            );
+        }
+
+        let missing: Vec<String> = source_values
+            .iter()
+            .filter(|v| is_significant(v))
+            .map(|v| format_number(v, &source_number))
+            .collect();
+        if !missing.is_empty() {
+            issue("원문의 숫자가 번역문에 없습니다.".to_string(), missing.join(", "));
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_by_locale() {
+        let en = NumberFormat::for_locale(Some("en-US"));
+        let es = NumberFormat::for_locale(Some("es"));
+        let ru = NumberFormat::for_locale(Some("ru-RU"));
+        assert_eq!(parse_number("1,234.5", &en).as_deref(), Some("1234.5"));
+        assert_eq!(parse_number("1.234,5", &es).as_deref(), Some("1234.5"));
+        assert_eq!(parse_number("1\u{A0}234,5", &ru).as_deref(), Some("1234.5"));
+        assert_eq!(parse_number("1,234.5", &es), None);
+        assert_eq!(parse_number("1.2.3", &en), None);
+        assert_eq!(format_number("1234567.5", &es), "1.234.567,5");
+        assert_eq!(format_number("1234", &es), "1234");
+        assert_eq!(format_number("1234", &en), "1,234");
+
+        let tokens = number_tokens("v2 costs 1 234,5 or 12. A4", true);
+        assert_eq!(tokens, vec![(9, 16), (20, 22)]);
+    }
+
+    #[test]
+    fn test_dates_follow_locale_order() {
+        let tokens = date_tokens("due 03/05/2024, build 1.2.3.4, 2024-03-05");
+        let parts: Vec<[&str; 3]> = tokens.iter().map(|t| t.parts).collect();
+        assert_eq!(parts, vec![["03", "05", "2024"], ["2024", "03", "05"]]);
+
+        let date = parse_date(["03", "05", "2024"], DateOrder::Mdy).unwrap();
+        assert_eq!(date, Date { year: 2024, month: 3, day: 5 });
+        assert_eq!(parse_date(["03", "05", "2024"], DateOrder::Dmy).map(|d| d.month), Some(5));
+        assert_eq!(parse_date(["13", "05", "2024"], DateOrder::Mdy), None);
+        assert_eq!(format_date(date, DateOrder::Ymd, '.', true), "2024.03.05");
+        assert_eq!(DateOrder::for_locale(Some("en-GB")), DateOrder::Dmy);
+        assert_eq!(DateOrder::for_locale(Some("영어")), DateOrder::Mdy);
+    }
+}
@@ -1,17 +1,22 @@
 //! Secret Manager - 마스터키 관리 및 시크릿 캐시
 //!
 //! - 마스터키는 Keychain에서 1회 로드 (`ite:master_key_v1`)
+//! - Keychain을 쓸 수 없는 환경(headless Linux/CI, 일부 Wayland)에서는
+//!   `app_data_dir/secrets.key` 파일에 마스터키를 저장하는 fallback 사용 (보안 수준 낮음)
+//!   - `ITE_SECRETS_BACKEND=file` 환경 변수로 파일 백엔드를 강제할 수 있음
+//!   - 한 번 파일 백엔드로 전환되면 이후에도 파일 키를 계속 사용 (vault가 그 키로 암호화됨)
 //! - 시크릿은 메모리 캐시로 보관
 //! - 변경 시 vault 파일 업데이트
 
 use crate::secrets::vault::{
-    encrypt_and_write, get_vault_path, read_and_decrypt, vault_exists, SecretsPayload,
-    MASTER_KEY_LEN,
+    encrypt_and_write, get_master_key_file_path, get_vault_path, master_key_file_permission_warning,
+    read_and_decrypt, read_master_key_file, vault_exists, write_master_key_file, SecretsPayload, MASTER_KEY_LEN,
 };
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use keyring::Entry;
 use once_cell::sync::Lazy;
 use rand::Rng;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -22,6 +27,8 @@ use zeroize::Zeroize;
 const KEYCHAIN_SERVICE: &str = "com.ite.app";
 /// 마스터키 Keychain 키
 const MASTER_KEY_KEYCHAIN_KEY: &str = "ite:master_key_v1";
+/// 백엔드 강제 지정 환경 변수 ("file"이면 Keychain을 건너뜀)
+const BACKEND_ENV_VAR: &str = "ITE_SECRETS_BACKEND";
 
 /// 전역 SecretManager 인스턴스
 pub static SECRETS: Lazy<SecretManager> = Lazy::new(SecretManager::new);
@@ -54,6 +61,35 @@ pub enum SecretManagerError {
     VaultDecryptFailed(String),
 }
 
+/// 마스터키 저장 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretsBackend {
+    /// OS Keychain (macOS Keychain, Windows Credential Manager, Secret Service)
+    Keychain,
+    /// app_data_dir/secrets.key 파일 (Keychain 사용 불가 시 fallback)
+    File,
+}
+
+/// 현재 백엔드 정보 (`secrets_backend_info` 명령 응답)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretsBackendInfo {
+    /// 초기화 전이면 None
+    pub backend: Option<SecretsBackend>,
+    /// 파일 백엔드처럼 Keychain보다 보안 수준이 낮은 경우 true
+    pub reduced_security: bool,
+    /// 파일 백엔드로 전환된 이유
+    pub fallback_reason: Option<String>,
+    /// 파일 백엔드의 키 파일 경로
+    pub key_path: Option<String>,
+    /// 키 파일 권한이 0600보다 넓을 때 경고
+    pub key_file_warning: Option<String>,
+    pub ready: bool,
+    /// 초기화 실패 메시지
+    pub error: Option<String>,
+}
+
 /// 초기화 상태
 #[derive(Debug, Clone, PartialEq)]
 pub enum InitState {
@@ -77,6 +113,34 @@ pub struct SecretManager {
     state: Arc<RwLock<InitState>>,
     /// app_data_dir 경로
     app_data_dir: Arc<RwLock<Option<PathBuf>>>,
+    /// 마스터키를 가져온 백엔드
+    backend: Arc<RwLock<Option<ActiveBackend>>>,
+}
+
+/// 마스터키를 가져온 백엔드와 전환 사유
+#[derive(Debug, Clone)]
+struct ActiveBackend {
+    kind: SecretsBackend,
+    fallback_reason: Option<String>,
+    key_path: Option<PathBuf>,
+}
+
+impl ActiveBackend {
+    fn keychain() -> Self {
+        Self {
+            kind: SecretsBackend::Keychain,
+            fallback_reason: None,
+            key_path: None,
+        }
+    }
+
+    fn file(reason: String, key_path: PathBuf) -> Self {
+        Self {
+            kind: SecretsBackend::File,
+            fallback_reason: Some(reason),
+            key_path: Some(key_path),
+        }
+    }
 }
 
 /// Zeroize가 적용된 마스터키 래퍼
@@ -98,6 +162,7 @@ impl SecretManager {
             cache: Arc::new(RwLock::new(HashMap::new())),
             state: Arc::new(RwLock::new(InitState::NotInitialized)),
             app_data_dir: Arc::new(RwLock::new(None)),
+            backend: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -149,26 +214,13 @@ impl SecretManager {
 
         println!("[SecretManager] Initializing...");
 
-        // 1. 마스터키 로드 또는 생성
-        let master_key = match self.load_master_key_from_keychain() {
-            Ok(key) => {
-                println!("[SecretManager] Master key loaded from keychain");
+        // 1. 마스터키 로드 또는 생성 (Keychain, 불가하면 파일)
+        let app_data_dir = self.app_data_dir.read().await.clone();
+        let master_key = match Self::resolve_master_key(app_data_dir.as_deref()) {
+            Ok((key, backend)) => {
+                *self.backend.write().await = Some(backend);
                 key
             }
-            Err(SecretManagerError::KeychainNoEntry) => {
-                // 마스터키가 없으면 새로 생성
-                println!("[SecretManager] No master key found, generating new one...");
-                let new_key = Self::generate_master_key();
-                if let Err(e) = self.save_master_key_to_keychain(&new_key) {
-                    // 키체인 저장 실패 시 Failed 상태로 전환
-                    let error_msg = format!("Failed to save master key to keychain: {}", e);
-                    eprintln!("[SecretManager] {}", error_msg);
-                    *self.state.write().await = InitState::Failed(error_msg);
-                    return Err(e);
-                }
-                println!("[SecretManager] New master key saved to keychain");
-                new_key
-            }
             Err(e) => {
                 *self.state.write().await = InitState::Failed(e.to_string());
                 return Err(e);
@@ -180,7 +232,6 @@ impl SecretManager {
         });
 
         // 2. Vault 파일 로드 (있으면)
-        let app_data_dir = self.app_data_dir.read().await.clone();
         if let Some(dir) = app_data_dir {
             let vault_path = get_vault_path(&dir);
             if vault_exists(&vault_path) {
@@ -219,11 +270,110 @@ impl SecretManager {
         Ok(())
     }
 
+    /// 마스터키 결정
+    /// 1. 키 파일이 이미 있으면 파일 백엔드 유지
+    /// 2. Keychain 로드 (없으면 생성 후 저장)
+    /// 3. Keychain 사용 불가면 새 키를 파일에 저장 (단, Keychain 키로 암호화된 vault가 있으면 실패)
+    fn resolve_master_key(
+        app_data_dir: Option<&std::path::Path>,
+    ) -> Result<([u8; MASTER_KEY_LEN], ActiveBackend), SecretManagerError> {
+        let key_path = app_data_dir.map(get_master_key_file_path);
+        if let Some(path) = key_path.as_ref().filter(|p| p.exists()) {
+            let key = read_master_key_file(path)?;
+            println!("[SecretManager] Master key loaded from key file (reduced security)");
+            if let Some(warning) = master_key_file_permission_warning(path) {
+                eprintln!("[SecretManager] Warning: {}", warning);
+            }
+            let reason = "Master key file exists from a previous keychain fallback".to_string();
+            return Ok((key, ActiveBackend::file(reason, path.clone())));
+        }
+
+        let forced_file = std::env::var(BACKEND_ENV_VAR).is_ok_and(|v| v.eq_ignore_ascii_case("file"));
+        let (new_key, reason) = if forced_file {
+            (Self::generate_master_key(), format!("{}=file", BACKEND_ENV_VAR))
+        } else {
+            match Self::load_master_key_from_keychain() {
+                Ok(key) => {
+                    println!("[SecretManager] Master key loaded from keychain");
+                    return Ok((key, ActiveBackend::keychain()));
+                }
+                Err(SecretManagerError::KeychainNoEntry) => {
+                    // 마스터키가 없으면 새로 생성
+                    println!("[SecretManager] No master key found, generating new one...");
+                    let new_key = Self::generate_master_key();
+                    match Self::save_master_key_to_keychain(&new_key) {
+                        Ok(()) => {
+                            println!("[SecretManager] New master key saved to keychain");
+                            return Ok((new_key, ActiveBackend::keychain()));
+                        }
+                        Err(e) => (new_key, format!("Failed to save master key to keychain: {}", e)),
+                    }
+                }
+                Err(e @ SecretManagerError::Keychain(_)) => (Self::generate_master_key(), e.to_string()),
+                Err(e) => return Err(e),
+            }
+        };
+
+        let key_path = key_path.ok_or(SecretManagerError::AppDataDirNotSet)?;
+        if let Some(dir) = app_data_dir {
+            if vault_exists(&get_vault_path(dir)) {
+                // 기존 vault는 Keychain 키로 암호화되어 있어 새 키로는 열 수 없음
+                return Err(SecretManagerError::Keychain(format!(
+                    "{}; the existing vault needs the keychain master key",
+                    reason
+                )));
+            }
+        }
+        if let Some(parent) = key_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_master_key_file(&key_path, &new_key)?;
+        eprintln!(
+            "[SecretManager] Keychain unavailable ({}). Using key file {:?} (reduced security)",
+            reason, key_path
+        );
+        Ok((new_key, ActiveBackend::file(reason, key_path)))
+    }
+
+    /// 현재 백엔드 정보
+    pub async fn backend_info(&self) -> SecretsBackendInfo {
+        let active = self.backend.read().await.clone();
+        let state = self.state.read().await.clone();
+        let backend = active.as_ref().map(|a| a.kind);
+
+        SecretsBackendInfo {
+            reduced_security: backend == Some(SecretsBackend::File),
+            backend,
+            fallback_reason: active.as_ref().and_then(|a| a.fallback_reason.clone()),
+            key_path: active
+                .as_ref()
+                .and_then(|a| a.key_path.as_ref())
+                .map(|p| p.to_string_lossy().to_string()),
+            key_file_warning: active
+                .as_ref()
+                .and_then(|a| a.key_path.as_deref())
+                .and_then(master_key_file_permission_warning),
+            ready: state == InitState::Ready,
+            error: match state {
+                InitState::Failed(msg) => Some(msg),
+                _ => None,
+            },
+        }
+    }
+
     /// 초기화 상태 확인
     pub async fn is_initialized(&self) -> bool {
         *self.state.read().await == InitState::Ready
     }
 
+    /// 캐시를 vault 파일에 다시 저장 (종료 시, 초기화 전이면 할 일 없음)
+    pub async fn flush(&self) -> Result<(), SecretManagerError> {
+        if !self.is_initialized().await {
+            return Ok(());
+        }
+        self.persist_vault().await
+    }
+
     /// 필요 시 자동 초기화 (lazy init)
     async fn ensure_initialized(&self) -> Result<(), SecretManagerError> {
         if !self.is_initialized().await {
@@ -386,7 +536,7 @@ impl SecretManager {
     }
 
     /// Keychain에서 마스터키 로드
-    fn load_master_key_from_keychain(&self) -> Result<[u8; MASTER_KEY_LEN], SecretManagerError> {
+    fn load_master_key_from_keychain() -> Result<[u8; MASTER_KEY_LEN], SecretManagerError> {
         let entry = Entry::new(KEYCHAIN_SERVICE, MASTER_KEY_KEYCHAIN_KEY)
             .map_err(|e| SecretManagerError::Keychain(e.to_string()))?;
 
@@ -413,7 +563,6 @@ impl SecretManager {
 
     /// Keychain에 마스터키 저장
     fn save_master_key_to_keychain(
-        &self,
         key: &[u8; MASTER_KEY_LEN],
     ) -> Result<(), SecretManagerError> {
         let entry = Entry::new(KEYCHAIN_SERVICE, MASTER_KEY_KEYCHAIN_KEY)
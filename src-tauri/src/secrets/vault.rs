@@ -93,7 +93,7 @@ pub fn encrypt_and_write(
         .map_err(|e| VaultError::EncryptionFailed(e.to_string()))?;
 
     // Atomic write: 임시 파일에 쓰고 rename
-    let tmp_path = path.with_extension("vault.tmp");
+    let tmp_path = get_vault_tmp_path(path);
 
     let mut file = fs::File::create(&tmp_path)?;
     file.write_all(VAULT_MAGIC)?;
@@ -156,6 +156,92 @@ pub fn get_vault_path(app_data_dir: &Path) -> PathBuf {
     app_data_dir.join("secrets.vault")
 }
 
+/// vault 쓰기용 임시 파일 경로 (쓰기 도중 종료되면 남을 수 있음)
+pub fn get_vault_tmp_path(vault_path: &Path) -> PathBuf {
+    vault_path.with_extension("vault.tmp")
+}
+
+/// app_data_dir 기반 마스터키 파일 경로 (Keychain 사용 불가 시 fallback)
+pub fn get_master_key_file_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("secrets.key")
+}
+
+/// 마스터키 파일 저장 (Unix에서는 소유자만 읽기/쓰기 0600)
+///
+/// 파일 자체는 암호화되지 않으므로 Keychain보다 보안 수준이 낮습니다.
+/// (같은 사용자 권한의 프로세스는 키를 읽을 수 있음)
+/// 0600 임시 파일에 쓰고 rename하므로, 기존 파일의 권한이 넓어도 그대로 남지 않습니다.
+pub fn write_master_key_file(path: &Path, master_key: &[u8; MASTER_KEY_LEN]) -> Result<(), VaultError> {
+    let mut hex: String = master_key.iter().map(|b| format!("{:02x}", b)).collect();
+
+    let tmp_path = path.with_extension("key.tmp");
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&tmp_path)?;
+    // mode()는 새로 만들 때만 적용되므로 남아 있던 임시 파일도 0600으로
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&tmp_path, fs::Permissions::from_mode(0o600))?;
+    }
+    let written = file.write_all(hex.as_bytes()).and_then(|_| file.sync_all());
+    hex.zeroize();
+    drop(file);
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e.into());
+    }
+
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// 마스터키 파일 권한이 0600보다 넓으면 경고 메시지 (Unix 외에는 항상 None)
+pub fn master_key_file_permission_warning(path: &Path) -> Option<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path).ok()?.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Some(format!(
+                "Master key file {:?} is accessible by other users (mode {:o}); expected 600",
+                path, mode
+            ));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    None
+}
+
+/// 마스터키 파일 읽기
+pub fn read_master_key_file(path: &Path) -> Result<[u8; MASTER_KEY_LEN], VaultError> {
+    let mut hex = fs::read_to_string(path)?;
+    let trimmed = hex.trim();
+    if trimmed.len() != MASTER_KEY_LEN * 2 || !trimmed.is_ascii() {
+        hex.zeroize();
+        return Err(VaultError::InvalidFormat("Invalid master key file".to_string()));
+    }
+
+    let mut key = [0u8; MASTER_KEY_LEN];
+    for (i, byte) in key.iter_mut().enumerate() {
+        match u8::from_str_radix(&trimmed[i * 2..i * 2 + 2], 16) {
+            Ok(b) => *byte = b,
+            Err(_) => {
+                hex.zeroize();
+                return Err(VaultError::InvalidFormat("Invalid master key file".to_string()));
+            }
+        }
+    }
+    hex.zeroize();
+    Ok(key)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,5 +290,46 @@ mod tests {
         let result = read_and_decrypt(&vault_path, &key2);
         assert!(result.is_err());
     }
-}
 
+    #[test]
+    fn test_master_key_file_roundtrip() {
+        let dir = tempdir().unwrap();
+        let key_path = get_master_key_file_path(dir.path());
+
+        let mut key = [0u8; MASTER_KEY_LEN];
+        rand::thread_rng().fill(&mut key);
+        write_master_key_file(&key_path, &key).unwrap();
+
+        assert_eq!(read_master_key_file(&key_path).unwrap(), key);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&key_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        fs::write(&key_path, "not-a-key").unwrap();
+        assert!(read_master_key_file(&key_path).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_master_key_file_rewrite_tightens_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let key_path = get_master_key_file_path(dir.path());
+        fs::write(&key_path, "old").unwrap();
+        fs::set_permissions(&key_path, fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(master_key_file_permission_warning(&key_path).is_some());
+
+        let key = [7u8; MASTER_KEY_LEN];
+        write_master_key_file(&key_path, &key).unwrap();
+
+        let mode = fs::metadata(&key_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(master_key_file_permission_warning(&key_path).is_none());
+        assert!(!key_path.with_extension("key.tmp").exists());
+    }
+}
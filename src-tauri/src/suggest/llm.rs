@@ -0,0 +1,341 @@
+//! LLM Translation Providers
+//!
+//! 설정 화면에서 저장한 API 키 번들(`ai/api_keys_bundle`)로 OpenAI/Anthropic에 번역을 요청합니다.
+//! - 결과는 번역문만 받도록 지시하고, 원문에 쓰인 용어는 프롬프트에 함께 넣습니다.
+//! - 인라인 태그/플레이스홀더와 DNT 용어는 요청 본문에 넣기 전에 토큰으로 바꾸고, 응답에서 원래대로 복원합니다.
+//!   토큰이 빠지거나 중복된 응답은 깨진 마크업을 돌려주지 않고 오류로 보고합니다.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{AppliedTerm, SuggestionEngine};
+use crate::http::RetryPolicy;
+use crate::secrets::SECRETS;
+use crate::text::dnt::DntMatcher;
+use crate::text::protect::{restore_response, ProviderMasker, TOKEN_INSTRUCTION};
+use crate::text::Placeholder;
+
+/// API 키 번들 vault 키 (프론트엔드 aiConfigStore와 같음)
+const VAULT_API_KEYS_BUNDLE: &str = "ai/api_keys_bundle";
+
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+const ANTHROPIC_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// 모델을 지정하지 않았을 때 (빠르고 저렴한 모델)
+pub const DEFAULT_OPENAI_MODEL: &str = "gpt-5-mini";
+pub const DEFAULT_ANTHROPIC_MODEL: &str = "claude-haiku-4-5";
+
+/// Anthropic 응답 최대 토큰
+const MAX_OUTPUT_TOKENS: u32 = 4096;
+
+#[derive(Debug, Default, Deserialize)]
+struct ApiKeysBundle {
+    openai: Option<String>,
+    anthropic: Option<String>,
+}
+
+/// 번역 요청 1건
+#[derive(Debug, Clone)]
+pub struct LlmRequest<'a> {
+    pub source: &'a str,
+    pub source_language: Option<&'a str>,
+    pub target_language: &'a str,
+    pub terms: &'a [AppliedTerm],
+}
+
+/// 시스템 프롬프트 (번역문만 출력, 용어 지정)
+pub fn system_prompt(request: &LlmRequest<'_>) -> String {
+    let mut prompt = format!(
+        "You are a professional translator. Translate the user's text {}into {}.\n\
+         Output only the translation, without quotes, notes or explanations. \
+         Keep inline formatting, placeholders, numbers and URLs unchanged.",
+        request
+            .source_language
+            .map(|lang| format!("from {} ", lang))
+            .unwrap_or_default(),
+        request.target_language
+    );
+    if !request.terms.is_empty() {
+        prompt.push_str("\nUse these glossary translations:");
+        for term in request.terms {
+            prompt.push_str(&format!("\n- {} => {}", term.source, term.target));
+        }
+    }
+    prompt
+}
+
+/// 전송할 번역 요청 (인라인 태그와 DNT 용어를 토큰으로 바꾼 상태)
+#[derive(Debug, Clone)]
+pub struct MaskedRequest {
+    pub system: String,
+    pub user: String,
+    source: Vec<Placeholder>,
+    instructions: Vec<Placeholder>,
+}
+
+impl MaskedRequest {
+    /// 응답의 토큰 복원 (원문 토큰이 빠지거나 중복되면 오류)
+    pub fn restore(&self, engine: SuggestionEngine, response: &str) -> Result<String, String> {
+        let result = restore_response(response, &self.source, &self.instructions);
+        if !result.is_intact() {
+            return Err(format!(
+                "{} response lost inline tags or protected terms (missing: [{}], duplicated: [{}])",
+                display_name(engine),
+                result.missing_tokens.join(", "),
+                result.duplicated_tokens.join(", ")
+            ));
+        }
+        Ok(result.text)
+    }
+}
+
+/// 시스템 프롬프트에 토큰 지시 추가 (토큰이 하나라도 있을 때)
+pub fn with_token_instruction(mut system: String, has_tokens: bool) -> String {
+    if has_tokens {
+        system.push('\n');
+        system.push_str(TOKEN_INSTRUCTION);
+    }
+    system
+}
+
+/// 번역 요청 마스킹 (시스템 프롬프트의 용어와 원문을 한 토큰 공간에서)
+pub fn mask_request(request: &LlmRequest<'_>, dnt: &DntMatcher) -> MaskedRequest {
+    let mut masker = ProviderMasker::new(dnt);
+    let system = masker.instructions(&system_prompt(request));
+    let user = masker.source(request.source);
+    let has_tokens = !system.placeholders.is_empty() || !user.placeholders.is_empty();
+    MaskedRequest {
+        system: with_token_instruction(system.text, has_tokens),
+        user: user.text,
+        source: user.placeholders,
+        instructions: system.placeholders,
+    }
+}
+
+/// OpenAI chat completions 응답에서 번역문
+fn openai_text(response: &Value) -> Option<String> {
+    response
+        .get("choices")?
+        .get(0)?
+        .get("message")?
+        .get("content")?
+        .as_str()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Anthropic messages 응답에서 번역문 (text 블록 이어붙임)
+fn anthropic_text(response: &Value) -> Option<String> {
+    let text: String = response
+        .get("content")?
+        .as_array()?
+        .iter()
+        .filter(|block| block.get("type").and_then(Value::as_str) == Some("text"))
+        .filter_map(|block| block.get("text").and_then(Value::as_str))
+        .collect();
+    let text = text.trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// API 오류 응답의 메시지 (`{"error":{"message":...}}`)
+fn error_message(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| v.get("error")?.get("message")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| crate::http::preview(body).to_string())
+}
+
+async fn api_key(engine: SuggestionEngine) -> Result<String, String> {
+    let bundle: ApiKeysBundle = match SECRETS.get(VAULT_API_KEYS_BUNDLE).await {
+        Ok(Some(json)) => serde_json::from_str(&json).unwrap_or_default(),
+        Ok(None) => ApiKeysBundle::default(),
+        Err(e) => return Err(format!("Failed to load API keys: {}", e)),
+    };
+    let key = match engine {
+        SuggestionEngine::Openai => bundle.openai,
+        SuggestionEngine::Anthropic => bundle.anthropic,
+        _ => None,
+    };
+    key.filter(|k| !k.trim().is_empty())
+        .ok_or_else(|| format!("{} API key is not set", display_name(engine)))
+}
+
+pub fn display_name(engine: SuggestionEngine) -> &'static str {
+    match engine {
+        SuggestionEngine::Openai => "OpenAI",
+        SuggestionEngine::Anthropic => "Anthropic",
+        SuggestionEngine::Tm => "TM",
+        SuggestionEngine::Glossary => "Glossary",
+    }
+}
+
+/// LLM 번역 요청, 번역문 반환 (태그/DNT 용어는 토큰으로 보내고 응답에서 복원)
+pub async fn translate(
+    engine: SuggestionEngine,
+    model: &str,
+    request: &LlmRequest<'_>,
+    dnt: &DntMatcher,
+) -> Result<String, String> {
+    let masked = mask_request(request, dnt);
+    let response = complete(engine, model, &masked.system, &masked.user).await?;
+    masked.restore(engine, &response)
+}
+
+/// 프로바이더별 요청 본문
+pub fn request_body(engine: SuggestionEngine, model: &str, system: &str, user: &str) -> Result<Value, String> {
+    match engine {
+        SuggestionEngine::Openai => Ok(json!({
+            "model": model,
+            "messages": [
+                { "role": "system", "content": system },
+                { "role": "user", "content": user },
+            ],
+        })),
+        SuggestionEngine::Anthropic => Ok(json!({
+            "model": model,
+            "max_tokens": MAX_OUTPUT_TOKENS,
+            "system": system,
+            "messages": [{ "role": "user", "content": user }],
+        })),
+        _ => Err(format!("{} is not an LLM engine", engine.as_str())),
+    }
+}
+
+/// 시스템/사용자 프롬프트로 LLM 호출, 응답 텍스트 반환
+/// - 프롬프트는 그대로 보내므로 태그/DNT 마스킹은 호출하는 쪽에서 합니다 (`mask_request`, `ProviderMasker`).
+pub async fn complete(engine: SuggestionEngine, model: &str, system: &str, user: &str) -> Result<String, String> {
+    let body = request_body(engine, model, system, user)?;
+    let key = api_key(engine).await?;
+    let client = crate::http::client()?;
+
+    let builder = match engine {
+        SuggestionEngine::Openai => client.post(OPENAI_CHAT_URL).bearer_auth(&key),
+        _ => client
+            .post(ANTHROPIC_MESSAGES_URL)
+            .header("x-api-key", &key)
+            .header("anthropic-version", ANTHROPIC_VERSION),
+    }
+    .json(&body);
+
+    // 번역 요청은 서버 상태를 바꾸지 않으므로 429/5xx는 재시도
+    let response = crate::http::send_read_with_retry(builder, RetryPolicy::LLM)
+        .await
+        .map_err(|e| format!("{} request failed: {}", display_name(engine), e))?;
+    let status = response.status();
+    let body = crate::http::read_text(response).await?;
+    if !status.is_success() {
+        return Err(format!("{} API error ({}): {}", display_name(engine), status, error_message(&body)));
+    }
+
+    let value: Value = serde_json::from_str(&body)
+        .map_err(|e| format!("Failed to parse {} response: {}", display_name(engine), e))?;
+    let text = match engine {
+        SuggestionEngine::Openai => openai_text(&value),
+        _ => anthropic_text(&value),
+    };
+    text.ok_or_else(|| format!("{} returned an empty translation", display_name(engine)))
+}
+
+/// 모델 이름 (비어 있으면 엔진 기본 모델)
+pub fn model_or_default(engine: SuggestionEngine, model: Option<&str>) -> String {
+    model
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .unwrap_or(match engine {
+            SuggestionEngine::Anthropic => DEFAULT_ANTHROPIC_MODEL,
+            _ => DEFAULT_OPENAI_MODEL,
+        })
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_prompt_lists_terms() {
+        let terms = vec![AppliedTerm {
+            source: "Save".to_string(),
+            target: "저장".to_string(),
+        }];
+        let prompt = system_prompt(&LlmRequest {
+            source: "Save",
+            source_language: Some("en"),
+            target_language: "ko",
+            terms: &terms,
+        });
+        assert!(prompt.contains("from en into ko."));
+        assert!(prompt.ends_with("\n- Save => 저장"));
+    }
+
+    #[test]
+    fn test_dnt_terms_never_reach_request_body() {
+        let dnt = DntMatcher::new(&[crate::models::DntTerm {
+            id: "1".to_string(),
+            project_id: None,
+            term: "OddEyes".to_string(),
+            is_regex: false,
+            case_sensitive: true,
+            notes: None,
+            created_at: 0,
+            updated_at: 0,
+        }])
+        .unwrap();
+        let terms = vec![AppliedTerm {
+            source: "OddEyes settings".to_string(),
+            target: "OddEyes 설정".to_string(),
+        }];
+        let masked = mask_request(
+            &LlmRequest {
+                source: "Open OddEyes settings",
+                source_language: Some("en"),
+                target_language: "ko",
+                terms: &terms,
+            },
+            &dnt,
+        );
+        for engine in [SuggestionEngine::Openai, SuggestionEngine::Anthropic] {
+            let body = request_body(engine, "model", &masked.system, &masked.user).unwrap().to_string();
+            assert!(!body.contains("OddEyes"), "{}", body);
+        }
+        assert_eq!(masked.user, "Open ⟦D3⟧ settings");
+        assert_eq!(
+            masked.restore(SuggestionEngine::Openai, "⟦D3⟧ 설정 열기").unwrap(),
+            "OddEyes 설정 열기"
+        );
+        assert!(masked.restore(SuggestionEngine::Openai, "설정 열기").is_err());
+    }
+
+    #[test]
+    fn test_lost_tag_is_reported() {
+        let dnt = DntMatcher::new(&[]).unwrap();
+        let masked = mask_request(
+            &LlmRequest {
+                source: "Hello {name}, you have %d files",
+                source_language: None,
+                target_language: "ko",
+                terms: &[],
+            },
+            &dnt,
+        );
+        assert_eq!(masked.user, "Hello ⟦T1⟧, you have ⟦T2⟧ files");
+        assert!(masked.system.ends_with(TOKEN_INSTRUCTION));
+        assert_eq!(
+            masked.restore(SuggestionEngine::Anthropic, "⟦T1⟧님, 파일 ⟦T2⟧개").unwrap(),
+            "{name}님, 파일 %d개"
+        );
+        let error = masked.restore(SuggestionEngine::Anthropic, "⟦T1⟧님, 파일이 있습니다").unwrap_err();
+        assert!(error.contains("missing: [⟦T2⟧]"), "{}", error);
+    }
+
+    #[test]
+    fn test_parse_provider_responses() {
+        let openai = json!({ "choices": [{ "message": { "role": "assistant", "content": " 파일 저장 \n" } }] });
+        assert_eq!(openai_text(&openai).as_deref(), Some("파일 저장"));
+        let anthropic = json!({ "content": [{ "type": "text", "text": "파일 " }, { "type": "text", "text": "저장" }] });
+        assert_eq!(anthropic_text(&anthropic).as_deref(), Some("파일 저장"));
+        assert_eq!(anthropic_text(&json!({ "content": [] })), None);
+        assert_eq!(error_message(r#"{"error":{"message":"Invalid key"}}"#), "Invalid key");
+    }
+}
@@ -0,0 +1,252 @@
+//! Translation Suggestions
+//!
+//! 원문 블록 하나에 대한 번역 후보를 여러 엔진에서 모읍니다 (에디터 제안 패널용).
+//! - `tm`: 프로젝트/전역 TM 퍼지 매치
+//! - `glossary`: 용어집 항목으로 조립한 번역 (원문 전체가 용어면 그대로)
+//! - `openai` / `anthropic`: 저장된 API 키로 LLM 번역 (`llm`)
+//!
+//! 후보마다 어디서 왔는지(`label`, `origin`)를 함께 돌려줍니다.
+//! 여러 세그먼트를 한 번에 번역하는 프롬프트 조립은 `prompt`에 있습니다.
+
+pub mod llm;
+pub mod prompt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::GlossaryEntryRow;
+use crate::text::is_ascii_word_char;
+use crate::text::tm_match::TmMatch;
+
+/// 번역 후보를 만드는 엔진
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestionEngine {
+    Tm,
+    Glossary,
+    Openai,
+    Anthropic,
+}
+
+impl SuggestionEngine {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Tm => "tm",
+            Self::Glossary => "glossary",
+            Self::Openai => "openai",
+            Self::Anthropic => "anthropic",
+        }
+    }
+
+    /// 외부 API를 호출하는 엔진인지
+    pub fn is_remote(self) -> bool {
+        matches!(self, Self::Openai | Self::Anthropic)
+    }
+}
+
+/// 후보에 쓰인 용어
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedTerm {
+    pub source: String,
+    pub target: String,
+}
+
+/// 번역 후보 1건
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationSuggestion {
+    pub engine: SuggestionEngine,
+    pub text: String,
+    /// 일치율 (TM/용어집만, 0–100)
+    pub score: Option<u8>,
+    /// 화면 표시용 출처 (예: "TM 95%", "Glossary (2 terms)", "OpenAI gpt-5-mini")
+    pub label: String,
+    /// 세부 출처 (TM 가져온 곳, LLM 모델 이름)
+    pub origin: Option<String>,
+    /// TM 매치의 원문 (차이 표시용)
+    pub matched_source: Option<String>,
+    /// 조립/프롬프트에 쓰인 용어
+    pub terms: Vec<AppliedTerm>,
+}
+
+/// TM 매치 → 후보
+pub fn tm_suggestions(matches: &[TmMatch<'_>]) -> Vec<TranslationSuggestion> {
+    matches
+        .iter()
+        .map(|m| TranslationSuggestion {
+            engine: SuggestionEngine::Tm,
+            text: m.unit.target.clone(),
+            score: Some(m.score),
+            label: format!("TM {}%", m.score),
+            origin: m
+                .unit
+                .origin
+                .clone()
+                .or_else(|| Some(if m.unit.project_id.is_some() { "project" } else { "global" }.to_string())),
+            matched_source: Some(m.unit.source.clone()),
+            terms: Vec::new(),
+        })
+        .collect()
+}
+
+fn chars_eq(a: char, b: char, case_sensitive: bool) -> bool {
+    a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()))
+}
+
+/// 원문에서 용어 위치 찾기 (긴 용어 우선, 겹치지 않게), (시작, 끝, 항목) 목록
+fn find_terms<'a>(source: &[char], entries: &'a [GlossaryEntryRow]) -> Vec<(usize, usize, &'a GlossaryEntryRow)> {
+    let mut sorted: Vec<&GlossaryEntryRow> = entries.iter().filter(|e| !e.source.trim().is_empty()).collect();
+    sorted.sort_by_key(|e| std::cmp::Reverse(e.source.chars().count()));
+
+    let mut covered = vec![false; source.len()];
+    let mut spans = Vec::new();
+    for entry in sorted {
+        let term: Vec<char> = entry.source.trim().chars().collect();
+        if term.len() > source.len() {
+            continue;
+        }
+        let mut i = 0;
+        while i + term.len() <= source.len() {
+            let end = i + term.len();
+            let matches = (i..end).all(|k| !covered[k] && chars_eq(source[k], term[k - i], entry.case_sensitive))
+                && !(is_ascii_word_char(term[0]) && i > 0 && is_ascii_word_char(source[i - 1]))
+                && !(is_ascii_word_char(term[term.len() - 1]) && end < source.len() && is_ascii_word_char(source[end]));
+            if matches {
+                covered[i..end].iter_mut().for_each(|c| *c = true);
+                spans.push((i, end, entry));
+                i = end;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    spans.sort_by_key(|(start, _, _)| *start);
+    spans
+}
+
+/// 용어집 조립 후보
+/// - 원문 전체가 용어 하나와 같으면 그 번역 (100%)
+/// - 아니면 원문 안의 용어를 번역으로 바꾼 초안 (점수 없음), 용어가 없으면 None
+pub fn glossary_suggestion(source: &str, entries: &[GlossaryEntryRow]) -> Option<TranslationSuggestion> {
+    let source = source.trim();
+    let chars: Vec<char> = source.chars().collect();
+    let spans = find_terms(&chars, entries);
+    if spans.is_empty() {
+        return None;
+    }
+
+    let mut terms: Vec<AppliedTerm> = Vec::new();
+    for (_, _, entry) in &spans {
+        let term = AppliedTerm {
+            source: entry.source.trim().to_string(),
+            target: entry.target.clone(),
+        };
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+
+    if let [(0, end, entry)] = spans.as_slice() {
+        if *end == chars.len() {
+            return Some(TranslationSuggestion {
+                engine: SuggestionEngine::Glossary,
+                text: entry.target.clone(),
+                score: Some(100),
+                label: "Glossary".to_string(),
+                origin: entry.domain.clone(),
+                matched_source: Some(entry.source.clone()),
+                terms,
+            });
+        }
+    }
+
+    let mut text = String::with_capacity(source.len());
+    let mut pos = 0;
+    for (start, end, entry) in &spans {
+        text.extend(&chars[pos..*start]);
+        text.push_str(&entry.target);
+        pos = *end;
+    }
+    text.extend(&chars[pos..]);
+
+    Some(TranslationSuggestion {
+        engine: SuggestionEngine::Glossary,
+        text,
+        score: None,
+        label: format!(
+            "Glossary ({} {})",
+            terms.len(),
+            if terms.len() == 1 { "term" } else { "terms" }
+        ),
+        origin: None,
+        matched_source: None,
+        terms,
+    })
+}
+
+/// 원문에 쓰인 용어 (LLM 프롬프트용)
+pub fn terms_in_source(source: &str, entries: &[GlossaryEntryRow]) -> Vec<AppliedTerm> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut terms: Vec<AppliedTerm> = Vec::new();
+    for (_, _, entry) in find_terms(&chars, entries) {
+        let term = AppliedTerm {
+            source: entry.source.trim().to_string(),
+            target: entry.target.clone(),
+        };
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(source: &str, target: &str, case_sensitive: bool) -> GlossaryEntryRow {
+        GlossaryEntryRow {
+            id: source.to_string(),
+            source: source.to_string(),
+            target: target.to_string(),
+            notes: None,
+            domain: None,
+            case_sensitive,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_glossary_assembly_prefers_longest_terms() {
+        let entries = vec![
+            entry("file", "파일", false),
+            entry("Save file", "파일 저장", false),
+            entry("cat", "고양이", false),
+            entry("API", "API", true),
+        ];
+        let suggestion = glossary_suggestion("Click save file to store the category api.", &entries).unwrap();
+        assert_eq!(suggestion.text, "Click 파일 저장 to store the category api.");
+        assert_eq!(suggestion.score, None);
+        assert_eq!(suggestion.label, "Glossary (1 term)");
+
+        let exact = glossary_suggestion("  save file ", &entries).unwrap();
+        assert_eq!(exact.text, "파일 저장");
+        assert_eq!(exact.score, Some(100));
+
+        assert!(glossary_suggestion("Nothing here", &entries).is_none());
+    }
+
+    #[test]
+    fn test_terms_in_source_dedupes() {
+        let entries = vec![entry("로그인", "sign in", false)];
+        let terms = terms_in_source("로그인 후 다시 로그인하세요", &entries);
+        assert_eq!(
+            terms,
+            vec![AppliedTerm {
+                source: "로그인".to_string(),
+                target: "sign in".to_string()
+            }]
+        );
+    }
+}
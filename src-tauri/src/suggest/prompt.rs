@@ -0,0 +1,168 @@
+//! Translation Prompt Assembly
+//!
+//! 여러 세그먼트를 한 번에 번역하는 LLM 프롬프트를 백엔드에서 조립합니다.
+//! - 시스템 프롬프트: 페르소나, 번역 규칙, 프로젝트 맥락, 용어집, DNT, TM 참고 번역
+//! - 사용자 프롬프트: `<seg id="N">원문</seg>` 목록 (응답도 같은 태그로 받아 세그먼트별로 나눔)
+
+use serde::Serialize;
+
+use super::AppliedTerm;
+
+/// 프롬프트에 넣는 TM 참고 번역
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmReference {
+    pub source: String,
+    pub target: String,
+    pub score: u8,
+}
+
+/// 프롬프트 재료 (세그먼트 제외)
+#[derive(Debug, Clone, Default)]
+pub struct PromptContext<'a> {
+    pub source_language: Option<&'a str>,
+    pub target_language: &'a str,
+    /// 번역가 페르소나 (채팅 설정)
+    pub persona: &'a str,
+    /// 번역 규칙 (채팅 설정)
+    pub rules: &'a str,
+    /// 프로젝트 맥락 (채팅 설정)
+    pub project_context: &'a str,
+    pub terms: &'a [AppliedTerm],
+    /// 원문에 나온 DNT 용어 (그대로 둘 것)
+    pub dnt: &'a [String],
+    pub tm: &'a [TmReference],
+}
+
+/// 조립한 프롬프트
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembledPrompt {
+    pub system: String,
+    pub user: String,
+}
+
+fn push_section(prompt: &mut String, title: &str, body: &str) {
+    let body = body.trim();
+    if !body.is_empty() {
+        prompt.push_str(&format!("\n\n## {}\n{}", title, body));
+    }
+}
+
+/// 시스템/사용자 프롬프트 조립 (세그먼트 번호는 1부터)
+pub fn assemble(context: &PromptContext<'_>, segments: &[&str]) -> AssembledPrompt {
+    let persona = context.persona.trim();
+    let mut system = if persona.is_empty() {
+        "You are a professional translator.".to_string()
+    } else {
+        persona.to_string()
+    };
+    system.push_str(&format!(
+        "\n\nTranslate each segment {}into {}. \
+         Reply with every segment wrapped in the same <seg id=\"N\"></seg> tags, in the same order, and nothing else. \
+         Keep inline formatting, placeholders, numbers and URLs unchanged.",
+        context
+            .source_language
+            .map(|lang| format!("from {} ", lang))
+            .unwrap_or_default(),
+        context.target_language
+    ));
+
+    push_section(&mut system, "Translation rules", context.rules);
+    push_section(&mut system, "Project context", context.project_context);
+    let glossary: Vec<String> = context
+        .terms
+        .iter()
+        .map(|t| format!("- {} => {}", t.source, t.target))
+        .collect();
+    push_section(&mut system, "Glossary (use these translations)", &glossary.join("\n"));
+    let dnt: Vec<String> = context.dnt.iter().map(|t| format!("- {}", t)).collect();
+    push_section(&mut system, "Do not translate (keep as is)", &dnt.join("\n"));
+    let tm: Vec<String> = context
+        .tm
+        .iter()
+        .map(|m| format!("- [{}%] {} => {}", m.score, m.source, m.target))
+        .collect();
+    push_section(&mut system, "Translation memory (for reference)", &tm.join("\n"));
+
+    let user = segments
+        .iter()
+        .enumerate()
+        .map(|(i, text)| format!("<seg id=\"{}\">{}</seg>", i + 1, text))
+        .collect::<Vec<_>>()
+        .join("\n");
+    AssembledPrompt { system, user }
+}
+
+/// 응답을 세그먼트별 번역으로 나눔 (`count`개, 빠진 세그먼트는 None)
+/// - 세그먼트가 하나인데 태그 없이 답한 경우 응답 전체를 번역으로 봅니다.
+pub fn split_output(output: &str, count: usize) -> Vec<Option<String>> {
+    let mut translations: Vec<Option<String>> = vec![None; count];
+    let mut rest = output;
+    while let Some(start) = rest.find("<seg id=\"") {
+        rest = &rest[start + "<seg id=\"".len()..];
+        let Some((id, after)) = rest.split_once("\">") else {
+            break;
+        };
+        let Some((text, after)) = after.split_once("</seg>") else {
+            break;
+        };
+        let index = id.trim().parse::<usize>().ok().and_then(|n| n.checked_sub(1));
+        if let Some(slot) = index.and_then(|i| translations.get_mut(i)) {
+            if slot.is_none() {
+                *slot = Some(text.trim().to_string());
+            }
+        }
+        rest = after;
+    }
+    if count == 1 && translations[0].is_none() && !output.contains("<seg") {
+        let text = output.trim();
+        if !text.is_empty() {
+            translations[0] = Some(text.to_string());
+        }
+    }
+    translations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assemble_includes_context_sections() {
+        let terms = vec![AppliedTerm {
+            source: "Save".to_string(),
+            target: "저장".to_string(),
+        }];
+        let dnt = vec!["OddEyes".to_string()];
+        let prompt = assemble(
+            &PromptContext {
+                source_language: Some("en"),
+                target_language: "ko",
+                rules: "Use polite form.",
+                terms: &terms,
+                dnt: &dnt,
+                ..Default::default()
+            },
+            &["Save in OddEyes", "Done"],
+        );
+        assert!(prompt.system.starts_with("You are a professional translator.\n\nTranslate each segment from en into ko."));
+        assert!(prompt.system.contains("## Translation rules\nUse polite form."));
+        assert!(prompt.system.contains("\n- Save => 저장"));
+        assert!(prompt.system.contains("## Do not translate (keep as is)\n- OddEyes"));
+        assert!(!prompt.system.contains("Project context"));
+        assert!(!prompt.system.contains("Translation memory"));
+        assert_eq!(prompt.user, "<seg id=\"1\">Save in OddEyes</seg>\n<seg id=\"2\">Done</seg>");
+    }
+
+    #[test]
+    fn test_split_output() {
+        let output = "<seg id=\"2\">완료</seg>\n<seg id=\"1\"> OddEyes에 저장 </seg><seg id=\"9\">x</seg>";
+        assert_eq!(
+            split_output(output, 3),
+            vec![Some("OddEyes에 저장".to_string()), Some("완료".to_string()), None]
+        );
+        assert_eq!(split_output(" 저장\n", 1), vec![Some("저장".to_string())]);
+        assert_eq!(split_output("<seg id=\"1\">unterminated", 1), vec![None]);
+    }
+}
@@ -0,0 +1,267 @@
+//! Global Glossary Sync
+//!
+//! 전역 용어집(project_id IS NULL)을 원격 JSON 문서와 병합합니다.
+//! 용어 id는 source/target으로 정해지므로(md5) 다른 컴퓨터에서 만든 같은 용어도 같은 항목으로 합쳐집니다.
+//! 삭제는 동기화하지 않습니다 (원격에 남은 용어는 다음 동기화 때 다시 들어옴).
+
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use super::remote::{PutOutcome, RemoteFile};
+use crate::db::{DbState, GlossaryEntryRow};
+
+pub const GLOSSARY_SYNC_FORMAT: &str = "ite-glossary-sync";
+pub const GLOSSARY_SYNC_FORMAT_VERSION: u32 = 1;
+
+/// 원격 파일이 그 사이 바뀌었을 때 다시 병합할 최대 횟수
+const MAX_SYNC_ATTEMPTS: usize = 3;
+
+/// 동시에 한 번만 동기화 (수동 + 스케줄)
+static SYNC_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// 원격 용어집 문서
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossarySyncDocument {
+    pub format: String,
+    pub format_version: u32,
+    pub updated_at: i64,
+    pub entries: Vec<SyncedTerm>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedTerm {
+    pub id: String,
+    pub source: String,
+    pub target: String,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub domain: Option<String>,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<GlossaryEntryRow> for SyncedTerm {
+    fn from(row: GlossaryEntryRow) -> Self {
+        Self {
+            id: row.id,
+            source: row.source,
+            target: row.target,
+            notes: row.notes,
+            domain: row.domain,
+            case_sensitive: row.case_sensitive,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl From<SyncedTerm> for GlossaryEntryRow {
+    fn from(term: SyncedTerm) -> Self {
+        Self {
+            id: term.id,
+            source: term.source,
+            target: term.target,
+            notes: term.notes,
+            domain: term.domain,
+            case_sensitive: term.case_sensitive,
+            created_at: term.created_at,
+            updated_at: term.updated_at,
+        }
+    }
+}
+
+/// 병합 결과
+#[derive(Debug, Clone, Default)]
+pub struct MergeResult {
+    /// 병합된 전체 용어 (id 순)
+    pub entries: Vec<SyncedTerm>,
+    /// 로컬에 반영할 용어 (원격에만 있거나 원격이 더 최근)
+    pub to_apply: Vec<SyncedTerm>,
+    /// 원격에 새로 올라가는 용어 수 (로컬에만 있거나 로컬이 더 최근)
+    pub pushed: usize,
+    /// 양쪽 내용이 달랐던 용어 수
+    pub conflicts: usize,
+}
+
+/// 항목별 last-write-wins 병합 (시각이 같으면 원격 우선)
+pub fn merge(local: &[SyncedTerm], remote: &[SyncedTerm]) -> MergeResult {
+    let mut result = MergeResult::default();
+    let mut merged: BTreeMap<&str, &SyncedTerm> = remote.iter().map(|t| (t.id.as_str(), t)).collect();
+
+    let mut seen_local = std::collections::HashSet::new();
+    for term in local {
+        seen_local.insert(term.id.as_str());
+        match merged.get(term.id.as_str()) {
+            None => {
+                merged.insert(&term.id, term);
+                result.pushed += 1;
+            }
+            Some(theirs) if *theirs == term => {}
+            Some(theirs) => {
+                result.conflicts += 1;
+                if term.updated_at > theirs.updated_at {
+                    merged.insert(&term.id, term);
+                    result.pushed += 1;
+                } else {
+                    result.to_apply.push((*theirs).clone());
+                }
+            }
+        }
+    }
+    result
+        .to_apply
+        .extend(remote.iter().filter(|t| !seen_local.contains(t.id.as_str())).cloned());
+    result.entries = merged.into_values().cloned().collect();
+    result
+}
+
+pub fn parse_document(json: &str) -> Result<GlossarySyncDocument, String> {
+    let doc: GlossarySyncDocument =
+        serde_json::from_str(json).map_err(|e| format!("Invalid remote glossary file: {}", e))?;
+    if doc.format != GLOSSARY_SYNC_FORMAT {
+        return Err(format!("Unexpected remote glossary format: {}", doc.format));
+    }
+    if doc.format_version > GLOSSARY_SYNC_FORMAT_VERSION {
+        return Err(format!(
+            "Remote glossary was written by a newer app (format version {})",
+            doc.format_version
+        ));
+    }
+    Ok(doc)
+}
+
+/// 동기화 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossarySyncReport {
+    /// 로컬에 반영한 용어 수
+    pub pulled: u32,
+    /// 원격에 올린 용어 수
+    pub pushed: usize,
+    pub conflicts: usize,
+    pub total_entries: usize,
+    pub synced_at: i64,
+}
+
+fn local_terms(db_state: &DbState) -> Result<Vec<SyncedTerm>, String> {
+    let db = db_state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+    let rows = db
+        .list_global_glossary_entries()
+        .map_err(|e| e.to_string())?;
+    Ok(rows.into_iter().map(SyncedTerm::from).collect())
+}
+
+/// 전역 용어집 동기화 한 번 실행
+pub async fn run_glossary_sync(db_state: &DbState) -> Result<GlossarySyncReport, String> {
+    let _guard = SYNC_LOCK.lock().await;
+    let remote = super::load_glossary_remote()
+        .await?
+        .ok_or("Glossary sync location is not configured")?;
+    let client = crate::http::client()?;
+
+    for attempt in 1..=MAX_SYNC_ATTEMPTS {
+        let file: RemoteFile = remote.get(&client).await?;
+        let remote_entries = match file.body.as_deref().filter(|b| !b.trim().is_empty()) {
+            Some(body) => parse_document(body)?.entries,
+            None => Vec::new(),
+        };
+        let merged = merge(&local_terms(db_state)?, &remote_entries);
+        let now = chrono::Utc::now().timestamp_millis();
+
+        if merged.pushed > 0 || file.body.is_none() {
+            let doc = GlossarySyncDocument {
+                format: GLOSSARY_SYNC_FORMAT.to_string(),
+                format_version: GLOSSARY_SYNC_FORMAT_VERSION,
+                updated_at: now,
+                entries: merged.entries.clone(),
+            };
+            let body = serde_json::to_string_pretty(&doc).map_err(|e| e.to_string())?;
+            if remote.put(&client, &body, &file).await? == PutOutcome::Conflict {
+                eprintln!("[Sync] Remote glossary changed during sync (attempt {}), merging again", attempt);
+                continue;
+            }
+        }
+
+        let rows: Vec<GlossaryEntryRow> = merged.to_apply.into_iter().map(GlossaryEntryRow::from).collect();
+        let pulled = {
+            let db = db_state
+                .0
+                .lock()
+                .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+            db.apply_global_glossary_entries(&rows).map_err(|e| e.to_string())?
+        };
+
+        println!(
+            "[Sync] Glossary synced: {} pulled, {} pushed, {} conflicts",
+            pulled, merged.pushed, merged.conflicts
+        );
+        return Ok(GlossarySyncReport {
+            pulled,
+            pushed: merged.pushed,
+            conflicts: merged.conflicts,
+            total_entries: merged.entries.len(),
+            synced_at: now,
+        });
+    }
+
+    Err("Remote glossary kept changing during sync. Please try again.".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(id: &str, target: &str, updated_at: i64) -> SyncedTerm {
+        SyncedTerm {
+            id: id.to_string(),
+            source: format!("src-{}", id),
+            target: target.to_string(),
+            notes: None,
+            domain: None,
+            case_sensitive: false,
+            created_at: 1,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn test_merge_last_write_wins() {
+        let local = vec![term("a", "local-new", 20), term("b", "local-old", 5), term("c", "same", 3), term("d", "mine", 1)];
+        let remote = vec![term("a", "remote-old", 10), term("b", "remote-new", 9), term("c", "same", 3), term("e", "theirs", 2)];
+
+        let merged = merge(&local, &remote);
+        let targets: Vec<(&str, &str)> = merged.entries.iter().map(|t| (t.id.as_str(), t.target.as_str())).collect();
+        assert_eq!(
+            targets,
+            vec![("a", "local-new"), ("b", "remote-new"), ("c", "same"), ("d", "mine"), ("e", "theirs")]
+        );
+        let applied: Vec<&str> = merged.to_apply.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(applied, vec!["b", "e"]);
+        assert_eq!(merged.pushed, 2);
+        assert_eq!(merged.conflicts, 2);
+    }
+
+    #[test]
+    fn test_merge_tie_prefers_remote() {
+        let merged = merge(&[term("a", "local", 7)], &[term("a", "remote", 7)]);
+        assert_eq!(merged.entries[0].target, "remote");
+        assert_eq!(merged.to_apply.len(), 1);
+        assert_eq!(merged.pushed, 0);
+    }
+
+    #[test]
+    fn test_parse_document_rejects_other_formats() {
+        assert!(parse_document(r#"{"format":"x","formatVersion":1,"updatedAt":0,"entries":[]}"#).is_err());
+        assert!(parse_document(r#"{"format":"ite-glossary-sync","formatVersion":1,"updatedAt":0,"entries":[]}"#).is_ok());
+    }
+}
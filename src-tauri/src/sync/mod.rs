@@ -0,0 +1,49 @@
+//! Remote Sync
+//!
+//! 팀이 함께 쓰는 전역 용어집을 WebDAV/S3 위치의 JSON 파일 하나로 동기화합니다.
+//! - 원격 위치와 자격 증명은 SecretManager vault에 저장
+//! - 항목별 last-write-wins (`updated_at`이 더 최근인 쪽이 이김)
+//! - 원격 파일은 ETag 조건부 쓰기로 갱신하고, 그 사이 바뀌었으면 다시 병합
+//!
+//! Notion 데이터베이스로 관리하는 용어집은 프로젝트 용어집으로 가져옵니다 (`notion`, 단방향).
+
+pub mod glossary;
+pub mod notion;
+pub mod remote;
+
+pub use glossary::{run_glossary_sync, GlossarySyncReport};
+pub use notion::{run_notion_glossary_sync, NotionGlossarySyncReport};
+pub use remote::{SyncRemote, SyncRemoteInfo};
+
+use crate::secrets::SECRETS;
+
+/// vault 저장 키
+const VAULT_GLOSSARY_REMOTE: &str = "sync/glossary_remote_json";
+
+pub async fn save_glossary_remote(remote: &SyncRemote) -> Result<(), String> {
+    remote.validate()?;
+    let json = serde_json::to_string(remote).map_err(|e| format!("Failed to serialize remote: {}", e))?;
+    SECRETS
+        .set(VAULT_GLOSSARY_REMOTE, &json)
+        .await
+        .map_err(|e| format!("Failed to save remote to vault: {}", e))?;
+    println!("[Sync] Glossary remote saved ({})", remote.info().location);
+    Ok(())
+}
+
+pub async fn load_glossary_remote() -> Result<Option<SyncRemote>, String> {
+    match SECRETS.get(VAULT_GLOSSARY_REMOTE).await {
+        Ok(Some(json)) => serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("Failed to parse glossary remote: {}", e)),
+        Ok(None) => Ok(None),
+        Err(e) => Err(format!("Failed to load glossary remote: {}", e)),
+    }
+}
+
+pub async fn clear_glossary_remote() -> Result<(), String> {
+    SECRETS
+        .delete(VAULT_GLOSSARY_REMOTE)
+        .await
+        .map_err(|e| format!("Failed to delete glossary remote: {}", e))
+}
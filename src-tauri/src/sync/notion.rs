@@ -0,0 +1,237 @@
+//! Notion Glossary Sync
+//!
+//! Notion 데이터베이스에 관리하는 용어집을 프로젝트 용어집으로 가져옵니다.
+//! - 매핑(`NotionGlossaryMapping`)으로 지정한 속성을 source/target/notes/domain으로 읽습니다.
+//! - 용어 id는 source/target으로 정해지므로 같은 용어는 갱신되고, 새 용어만 추가됩니다.
+//! - Notion에서 지운 행은 로컬에서 지우지 않습니다.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::db::{DbState, NewGlossaryTerm};
+use crate::models::NotionGlossaryMapping;
+use crate::notion::types::Page;
+use crate::notion::NOTION_CLIENT;
+
+/// 한 번에 가져오는 행 수 (Notion API 최대값)
+const QUERY_PAGE_SIZE: u32 = 100;
+
+/// 읽을 최대 행 수 (너무 큰 데이터베이스 보호)
+const MAX_ROWS: usize = 10_000;
+
+/// 동시에 한 번만 동기화 (수동 + 스케줄)
+static SYNC_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// 동기화 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotionGlossarySyncReport {
+    pub project_id: String,
+    /// Notion에서 읽은 행 수
+    pub fetched: usize,
+    /// 용어집에 반영한 용어 수
+    pub imported: u32,
+    /// source/target이 비어 건너뛴 행 수
+    pub skipped: usize,
+    /// `MAX_ROWS`에서 멈췄는지
+    pub truncated: bool,
+    pub synced_at: i64,
+}
+
+/// 속성 값을 텍스트로 변환 (title, rich_text, select, multi_select, status, number, checkbox, url, email, phone_number, formula)
+pub fn property_text(property: &Value) -> Option<String> {
+    let kind = property.get("type")?.as_str()?;
+    let value = property.get(kind)?;
+    let text = match kind {
+        "title" | "rich_text" => value
+            .as_array()?
+            .iter()
+            .filter_map(|t| t.get("plain_text").and_then(Value::as_str))
+            .collect::<String>(),
+        "select" | "status" => value.get("name")?.as_str()?.to_string(),
+        "multi_select" => value
+            .as_array()?
+            .iter()
+            .filter_map(|o| o.get("name").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join(", "),
+        "number" => value.as_f64().map(|n| {
+            if n.fract() == 0.0 {
+                format!("{}", n as i64)
+            } else {
+                n.to_string()
+            }
+        })?,
+        "checkbox" => value.as_bool()?.to_string(),
+        "url" | "email" | "phone_number" => value.as_str()?.to_string(),
+        "formula" => return property_text(value),
+        "string" => value.as_str()?.to_string(),
+        "boolean" => value.as_bool()?.to_string(),
+        _ => return None,
+    };
+    let text = text.trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// 속성 이름으로 값 찾기 (정확히 같은 이름 우선, 없으면 대소문자 무시)
+fn property<'a>(page: &'a Page, name: &str) -> Option<&'a Value> {
+    let properties = page.properties.as_object()?;
+    properties.get(name).or_else(|| {
+        properties
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(name.trim()))
+            .map(|(_, value)| value)
+    })
+}
+
+fn mapped_text(page: &Page, name: Option<&str>) -> Option<String> {
+    name.filter(|n| !n.trim().is_empty())
+        .and_then(|n| property(page, n))
+        .and_then(property_text)
+}
+
+/// 데이터베이스 행을 용어로 변환, (용어, 건너뛴 행 수) 반환
+pub fn pages_to_terms(pages: &[Page], mapping: &NotionGlossaryMapping) -> (Vec<NewGlossaryTerm>, usize) {
+    let mut terms = Vec::new();
+    let mut skipped = 0;
+    for page in pages {
+        let (Some(source), Some(target)) = (
+            mapped_text(page, Some(&mapping.source)),
+            mapped_text(page, Some(&mapping.target)),
+        ) else {
+            skipped += 1;
+            continue;
+        };
+        let case_sensitive = mapped_text(page, mapping.case_sensitive.as_deref())
+            .map(|v| matches!(v.to_lowercase().as_str(), "true" | "yes" | "y" | "1"))
+            .unwrap_or(false);
+        terms.push(NewGlossaryTerm {
+            source,
+            target,
+            notes: mapped_text(page, mapping.notes.as_deref()),
+            domain: mapped_text(page, mapping.domain.as_deref()),
+            case_sensitive,
+            origin_key: Some(format!("notion:{}", page.id)),
+        });
+    }
+    (terms, skipped)
+}
+
+/// Notion 데이터베이스를 프로젝트 용어집으로 한 번 가져오기
+pub async fn run_notion_glossary_sync(
+    db_state: &DbState,
+    project_id: &str,
+    database_id: &str,
+    mapping: &NotionGlossaryMapping,
+) -> Result<NotionGlossarySyncReport, String> {
+    mapping.validate()?;
+    {
+        let db = db_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+        db.load_project_metadata(project_id).map_err(|e| e.to_string())?;
+        db.ensure_project_writable(project_id).map_err(|e| e.to_string())?;
+    }
+    let _guard = SYNC_LOCK.lock().await;
+
+    let mut pages: Vec<Page> = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut truncated = false;
+    loop {
+        let response = NOTION_CLIENT
+            .query_database_from(database_id, None, cursor.take(), Some(QUERY_PAGE_SIZE))
+            .await?;
+        pages.extend(response.results);
+        if pages.len() >= MAX_ROWS {
+            truncated = response.has_more || pages.len() > MAX_ROWS;
+            pages.truncate(MAX_ROWS);
+            break;
+        }
+        match response.next_cursor.filter(|_| response.has_more) {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    let (terms, skipped) = pages_to_terms(&pages, mapping);
+    let imported = {
+        let db = db_state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire database lock: {}", e))?;
+        db.upsert_project_glossary_entries(project_id, &terms)
+            .map_err(|e| e.to_string())?
+    };
+
+    println!(
+        "[Sync] Notion glossary synced into {}: {} rows, {} imported, {} skipped",
+        project_id,
+        pages.len(),
+        imported,
+        skipped
+    );
+    Ok(NotionGlossarySyncReport {
+        project_id: project_id.to_string(),
+        fetched: pages.len(),
+        imported,
+        skipped,
+        truncated,
+        synced_at: chrono::Utc::now().timestamp_millis(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn page(properties: Value) -> Page {
+        serde_json::from_value(json!({ "id": "p", "object": "page", "properties": properties })).unwrap()
+    }
+
+    #[test]
+    fn test_property_text() {
+        let title = json!({ "type": "title", "title": [{ "plain_text": "Save " }, { "plain_text": "file" }] });
+        assert_eq!(property_text(&title).as_deref(), Some("Save file"));
+        let tags = json!({ "type": "multi_select", "multi_select": [{ "name": "UI" }, { "name": "Menu" }] });
+        assert_eq!(property_text(&tags).as_deref(), Some("UI, Menu"));
+        let formula = json!({ "type": "formula", "formula": { "type": "number", "number": 3.0 } });
+        assert_eq!(property_text(&formula).as_deref(), Some("3"));
+        assert_eq!(property_text(&json!({ "type": "select", "select": null })), None);
+        assert_eq!(property_text(&json!({ "type": "rich_text", "rich_text": [] })), None);
+    }
+
+    #[test]
+    fn test_pages_to_terms() {
+        let mapping = NotionGlossaryMapping {
+            source: "Term".to_string(),
+            target: "korean".to_string(),
+            notes: Some("Notes".to_string()),
+            domain: Some("Area".to_string()),
+            case_sensitive: Some("Exact".to_string()),
+        };
+        let pages = vec![
+            page(json!({
+                "Term": { "type": "title", "title": [{ "plain_text": "Save" }] },
+                "Korean": { "type": "rich_text", "rich_text": [{ "plain_text": "저장" }] },
+                "Area": { "type": "select", "select": { "name": "UI" } },
+                "Exact": { "type": "checkbox", "checkbox": true }
+            })),
+            page(json!({
+                "Term": { "type": "title", "title": [{ "plain_text": "Draft" }] },
+                "Korean": { "type": "rich_text", "rich_text": [] }
+            })),
+        ];
+        let (terms, skipped) = pages_to_terms(&pages, &mapping);
+        assert_eq!(skipped, 1);
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].source, "Save");
+        assert_eq!(terms[0].target, "저장");
+        assert_eq!(terms[0].domain.as_deref(), Some("UI"));
+        assert_eq!(terms[0].notes, None);
+        assert!(terms[0].case_sensitive);
+        assert_eq!(terms[0].origin_key.as_deref(), Some("notion:p"));
+    }
+}
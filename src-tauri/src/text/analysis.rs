@@ -0,0 +1,208 @@
+//! Project Analysis
+//!
+//! 세그먼트 원문을 프로젝트 안의 반복과 TM 일치율로 분류해 구간별 세그먼트/단어 수를 집계합니다 (견적용).
+//! - 판정 순서: TM 101%/100% → 반복(같은 원문이 두 번째 이후로 등장) → 퍼지 구간 → 일치 없음
+//! - TM에는 앞뒤 문맥이 없으므로, 앞 세그먼트(문서 첫 세그먼트 포함)도 100% 매치인 100% 매치를 101%로 봅니다.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use super::normalize_for_matching;
+use super::tm_match::{MatchBand, TmMatcher};
+use super::words::{cjk_factor, count_text, resolve_source_language};
+use crate::models::{IteProject, TmUnit};
+
+/// 구간별 집계
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BandStats {
+    pub band: MatchBand,
+    pub label: &'static str,
+    pub segments: usize,
+    pub words: usize,
+}
+
+/// 프로젝트 분석 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectAnalysis {
+    pub source_language: Option<String>,
+    /// 원문이 비어 있지 않은 세그먼트 수
+    pub segments: usize,
+    pub words: usize,
+    /// `MatchBand::ALL` 순서
+    pub bands: Vec<BandStats>,
+}
+
+impl ProjectAnalysis {
+    pub fn band(&self, band: MatchBand) -> Option<&BandStats> {
+        self.bands.iter().find(|b| b.band == band)
+    }
+}
+
+/// 프로젝트 원문을 반복/TM 기준으로 분석
+/// - `source_language`가 없으면 원문에서 감지하고, 언어쌍이 다른 TM 단위는 비교하지 않습니다.
+/// - `on_progress(처리한 세그먼트 수, 전체)`는 세그먼트마다 호출됩니다.
+pub fn analyze_project(
+    project: &IteProject,
+    units: &[TmUnit],
+    source_language: Option<&str>,
+    mut on_progress: impl FnMut(usize, usize),
+) -> ProjectAnalysis {
+    let mut segments: Vec<_> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+    let sources: Vec<String> = segments
+        .iter()
+        .map(|segment| {
+            segment
+                .source_ids
+                .iter()
+                .filter_map(|id| project.blocks.get(id))
+                .map(|b| normalize_for_matching(&b.content))
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|text| !text.is_empty())
+        .collect();
+
+    let source_language = resolve_source_language(source_language, sources.iter().map(String::as_str));
+    let factor = cjk_factor(source_language.as_deref());
+    let matcher = TmMatcher::new(units, source_language.as_deref(), project.metadata.target_language.as_deref());
+
+    let mut totals: HashMap<MatchBand, (usize, usize)> = HashMap::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut words = 0;
+    let mut previous_exact = true;
+    for (i, source) in sources.iter().enumerate() {
+        let tm_score = matcher.best_match(source).map(|m| m.score);
+        let exact = tm_score == Some(100);
+        let band = match tm_score {
+            Some(100) if previous_exact => MatchBand::Context,
+            Some(100) => MatchBand::Exact,
+            _ if seen.contains(source.as_str()) => MatchBand::Repetition,
+            Some(score) => MatchBand::from_score(score),
+            None => MatchBand::NoMatch,
+        };
+        seen.insert(source);
+        previous_exact = exact;
+
+        let count = count_text(source, factor).words;
+        let entry = totals.entry(band).or_default();
+        entry.0 += 1;
+        entry.1 += count;
+        words += count;
+        on_progress(i + 1, sources.len());
+    }
+
+    ProjectAnalysis {
+        source_language,
+        segments: sources.len(),
+        words,
+        bands: MatchBand::ALL
+            .iter()
+            .map(|&band| {
+                let (segments, words) = totals.get(&band).copied().unwrap_or_default();
+                BandStats {
+                    band,
+                    label: band.label(),
+                    segments,
+                    words,
+                }
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::models::{BlockMetadata, EditorBlock, ProjectMetadata, ProjectSettings, SegmentGroup};
+
+    fn project(sources: &[&str]) -> IteProject {
+        let mut blocks = HashMap::new();
+        let mut segments = Vec::new();
+        for (i, source) in sources.iter().enumerate() {
+            let id = format!("s{i}");
+            blocks.insert(id.clone(), EditorBlock {
+                id: id.clone(),
+                block_type: "source".to_string(),
+                content: source.to_string(),
+                hash: String::new(),
+                metadata: BlockMetadata::default(),
+            });
+            segments.push(SegmentGroup {
+                group_id: format!("g{i}"),
+                source_ids: vec![id],
+                target_ids: Vec::new(),
+                is_aligned: true,
+                order: i as i32,
+            });
+        }
+        IteProject {
+            id: "p1".to_string(),
+            version: "1.0.0".to_string(),
+            metadata: ProjectMetadata {
+                title: "Doc".to_string(),
+                description: None,
+                domain: "general".to_string(),
+                target_language: Some("ko".to_string()),
+                source_locale: None,
+                target_locale: None,
+                created_at: 1,
+                updated_at: 2,
+                author: None,
+                glossary_paths: None,
+                settings: ProjectSettings {
+                    strictness_level: 0.5,
+                    auto_save: true,
+                    auto_save_interval: 30000,
+                    theme: "system".to_string(),
+                },
+            },
+            segments,
+            blocks,
+            history: Vec::new(),
+        }
+    }
+
+    fn unit(source: &str) -> TmUnit {
+        TmUnit {
+            id: source.to_string(),
+            project_id: None,
+            source_lang: "en".to_string(),
+            target_lang: "ko".to_string(),
+            source: source.to_string(),
+            target: format!("{}-ko", source),
+            origin: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_context_band_needs_exact_previous_segment() {
+        let saved = "Save the file before closing.";
+        let open = "Open the project settings.";
+        let other = "Completely unrelated sentence here.";
+        let units = vec![unit(saved), unit(open)];
+        let project = project(&[saved, open, other, open, other, saved]);
+
+        let mut progress = Vec::new();
+        let analysis = analyze_project(&project, &units, Some("en"), |done, total| progress.push((done, total)));
+        let segments = |band| analysis.band(band).map(|b| b.segments);
+
+        // 문서 첫 세그먼트와 그 뒤를 잇는 100% 매치만 101%
+        assert_eq!(segments(MatchBand::Context), Some(2));
+        assert_eq!(analysis.band(MatchBand::Context).map(|b| b.words), Some(9));
+        // 일치 없음 뒤의 100% 매치는 반복보다 TM 매치가 우선
+        assert_eq!(segments(MatchBand::Exact), Some(2));
+        assert_eq!(segments(MatchBand::Repetition), Some(1));
+        assert_eq!(segments(MatchBand::NoMatch), Some(1));
+        assert_eq!((analysis.segments, analysis.words), (6, 26));
+        assert_eq!(progress.last(), Some(&(6, 6)));
+    }
+}
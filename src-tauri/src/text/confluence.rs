@@ -0,0 +1,749 @@
+//! Confluence Storage Format Conversion
+//!
+//! Confluence storage 포맷(XHTML + `ac:`/`ri:` 매크로)을 평문 또는 Markdown으로 바꿉니다.
+//! 인용, LLM 컨텍스트, 단어 수 계산에 쓰며 프론트엔드가 storage HTML을 직접 해석하지 않아도 되게 합니다.
+//! - 표는 Markdown 표(평문은 탭 구분), 목록은 `-`/`1.`, 제목은 `#`으로 바꿉니다.
+//! - 코드 매크로는 코드 블록, 정보/경고 패널은 인용 블록으로 펼치고, 목차 등 내용이 없는 매크로는 버립니다.
+//! - 닫히지 않은 태그나 알 수 없는 엔티티가 있어도 실패하지 않습니다 (XML 파서 대신 관대한 토크나이저 사용).
+//! - 단어 수는 목록 기호/라벨/코드 블록을 뺀 본문 기준입니다.
+
+use serde::{Deserialize, Serialize};
+
+use super::decode_entity;
+use super::words::{count_text, WordCount};
+
+/// 출력 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageFormat {
+    Text,
+    #[default]
+    Markdown,
+}
+
+/// 변환 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageConversion {
+    pub content: String,
+    pub word_count: WordCount,
+}
+
+/// 자식이 없는 태그
+const VOID_ELEMENTS: &[&str] = &["br", "hr", "img", "col", "input", "meta", "link", "wbr"];
+
+/// 내용을 출력하지 않는 태그
+const SKIPPED_ELEMENTS: &[&str] = &["script", "style", "ac:parameter", "ac:placeholder", "ac:task-id"];
+
+/// 문단 안에 놓이는 매크로
+const INLINE_MACROS: &[&str] = &["status", "jira", "anchor"];
+
+/// 인용 블록으로 펼치는 매크로
+const PANEL_MACROS: &[&str] = &["info", "note", "warning", "tip", "panel", "success", "error"];
+
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find_map(|n| match n {
+            Node::Element(el) if el.name == name => Some(el),
+            _ => None,
+        })
+    }
+
+    fn child_elements<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter_map(move |n| match n {
+            Node::Element(el) if el.name == name => Some(el),
+            _ => None,
+        })
+    }
+
+    /// 매크로 파라미터 (`<ac:parameter ac:name="...">`)
+    fn param(&self, name: &str) -> Option<String> {
+        self.child_elements("ac:parameter")
+            .find(|p| p.attr("ac:name") == Some(name))
+            .map(|p| p.raw_text().trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 공백을 그대로 둔 하위 텍스트 전체 (코드 블록용)
+    fn raw_text(&self) -> String {
+        let mut out = String::new();
+        collect_raw_text(&self.children, &mut out);
+        out
+    }
+}
+
+fn collect_raw_text(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(t) => out.push_str(t),
+            Node::Element(el) if el.name == "br" => out.push('\n'),
+            Node::Element(el) => collect_raw_text(&el.children, out),
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end > 0 && end <= 10)
+            .and_then(|end| decode_entity(&rest[1..=end]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 따옴표 밖의 첫 `>` 위치
+fn find_tag_end(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 여는 태그 내용(`name a="1" b`)을 이름과 속성으로 분리
+fn parse_tag(inner: &str) -> (String, Vec<(String, String)>) {
+    let inner = inner.trim();
+    let name_end = inner.find(|c: char| c.is_whitespace()).unwrap_or(inner.len());
+    let name = inner[..name_end].to_lowercase();
+    let mut attrs = Vec::new();
+    let mut rest = inner[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+        let mut value = String::new();
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            match after_eq.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let body = &after_eq[1..];
+                    let end = body.find(q).unwrap_or(body.len());
+                    value = decode_entities(&body[..end]);
+                    rest = body.get(end + 1..).unwrap_or("");
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    value = decode_entities(&after_eq[..end]);
+                    rest = &after_eq[end..];
+                }
+            }
+        }
+        if !key.is_empty() {
+            attrs.push((key, value));
+        }
+        rest = rest.trim_start();
+    }
+    (name, attrs)
+}
+
+fn parse(html: &str) -> Vec<Node> {
+    let mut stack: Vec<Element> = vec![Element::default()];
+    let mut rest = html;
+
+    fn push_node(stack: &mut [Element], node: Node) {
+        if let Some(top) = stack.last_mut() {
+            top.children.push(node);
+        }
+    }
+
+    fn close_top(stack: &mut Vec<Element>) {
+        if let Some(el) = stack.pop() {
+            push_node(stack, Node::Element(el));
+        }
+    }
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            push_node(&mut stack, Node::Text(decode_entities(rest)));
+            break;
+        };
+        if lt > 0 {
+            push_node(&mut stack, Node::Text(decode_entities(&rest[..lt])));
+            rest = &rest[lt..];
+        }
+
+        if let Some(body) = rest.strip_prefix("<![CDATA[") {
+            let end = body.find("]]>").unwrap_or(body.len());
+            push_node(&mut stack, Node::Text(body[..end].to_string()));
+            rest = body.get(end + 3..).unwrap_or("");
+            continue;
+        }
+        if let Some(body) = rest.strip_prefix("<!--") {
+            rest = body.find("-->").map_or("", |end| &body[end + 3..]);
+            continue;
+        }
+        let Some(end) = find_tag_end(rest) else {
+            // 닫히지 않은 `<`는 글자로 취급
+            push_node(&mut stack, Node::Text(rest.to_string()));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            // 짝이 맞는 태그까지 닫음 (없으면 무시)
+            if let Some(pos) = stack.iter().skip(1).rposition(|el| el.name == name) {
+                while stack.len() > pos + 1 {
+                    close_top(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let (name, attrs) = parse_tag(tag.trim_end_matches('/'));
+        if name.is_empty() {
+            continue;
+        }
+        let element = Element {
+            name,
+            attrs,
+            children: Vec::new(),
+        };
+        if self_closing || VOID_ELEMENTS.contains(&element.name.as_str()) {
+            push_node(&mut stack, Node::Element(element));
+        } else {
+            stack.push(element);
+        }
+    }
+
+    while stack.len() > 1 {
+        close_top(&mut stack);
+    }
+    stack.pop().map(|root| root.children).unwrap_or_default()
+}
+
+fn is_block(el: &Element) -> bool {
+    match el.name.as_str() {
+        "p" | "div" | "section" | "center" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul" | "ol" | "table"
+        | "pre" | "blockquote" | "hr" | "ac:task-list" | "ac:layout" | "ac:layout-section" | "ac:layout-cell"
+        | "ac:rich-text-body" => true,
+        "ac:structured-macro" => !INLINE_MACROS.contains(&el.attr("ac:name").unwrap_or("")),
+        _ => false,
+    }
+}
+
+/// 인라인 텍스트 추가 (연속 공백은 하나로)
+fn push_collapsed(out: &mut String, text: &str) {
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !out.is_empty() && !out.ends_with([' ', '\n']) {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// 인라인 버퍼를 블록으로 정리 (줄마다 양끝 공백 제거)
+fn flush_inline(inline: &mut String, blocks: &mut Vec<String>) {
+    let text = inline
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    if !text.is_empty() {
+        blocks.push(text);
+    }
+    inline.clear();
+}
+
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first } else { rest };
+            if line.is_empty() {
+                prefix.trim_end().to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+struct Renderer {
+    markdown: bool,
+    /// 단어 수 계산용: 목록 기호/라벨/코드 블록을 출력하지 않음
+    counting: bool,
+}
+
+impl Renderer {
+    fn render(&self, nodes: &[Node]) -> String {
+        self.blocks(nodes).join("\n\n")
+    }
+
+    fn blocks(&self, nodes: &[Node]) -> Vec<String> {
+        let mut blocks = Vec::new();
+        let mut inline = String::new();
+        for node in nodes {
+            match node {
+                Node::Element(el) if is_block(el) => {
+                    flush_inline(&mut inline, &mut blocks);
+                    self.block(el, &mut blocks);
+                }
+                _ => self.inline(node, &mut inline),
+            }
+        }
+        flush_inline(&mut inline, &mut blocks);
+        blocks
+    }
+
+    fn inline_text(&self, nodes: &[Node]) -> String {
+        let mut out = String::new();
+        for node in nodes {
+            self.inline(node, &mut out);
+        }
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn block(&self, el: &Element, out: &mut Vec<String>) {
+        match el.name.as_str() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let text = self.inline_text(&el.children);
+                if text.is_empty() {
+                    return;
+                }
+                if self.markdown {
+                    let level = el.name[1..].parse::<usize>().unwrap_or(1);
+                    out.push(format!("{} {}", "#".repeat(level), text));
+                } else {
+                    out.push(text);
+                }
+            }
+            "ul" | "ol" => {
+                let list = self.list(el, el.name == "ol");
+                if !list.is_empty() {
+                    out.push(list);
+                }
+            }
+            "table" => {
+                let table = self.table(el);
+                if !table.is_empty() {
+                    out.push(table);
+                }
+            }
+            "pre" => self.code_block(&el.raw_text(), None, out),
+            "blockquote" => {
+                let inner = self.render(&el.children);
+                if !inner.is_empty() {
+                    out.push(self.quote(&inner));
+                }
+            }
+            "hr" => {
+                if self.markdown {
+                    out.push("---".to_string());
+                }
+            }
+            "ac:task-list" => {
+                let tasks = self.task_list(el);
+                if !tasks.is_empty() {
+                    out.push(tasks);
+                }
+            }
+            "ac:structured-macro" => self.macro_block(el, out),
+            _ => out.extend(self.blocks(&el.children)),
+        }
+    }
+
+    fn quote(&self, text: &str) -> String {
+        if self.markdown {
+            prefix_lines(text, "> ", "> ")
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn code_block(&self, code: &str, language: Option<&str>, out: &mut Vec<String>) {
+        let code = code.trim_matches('\n');
+        if self.counting || code.trim().is_empty() {
+            return;
+        }
+        if self.markdown {
+            out.push(format!("```{}\n{}\n```", language.unwrap_or(""), code));
+        } else {
+            out.push(code.to_string());
+        }
+    }
+
+    fn list(&self, el: &Element, ordered: bool) -> String {
+        let mut items = Vec::new();
+        for (i, li) in el.child_elements("li").enumerate() {
+            let content = self.blocks(&li.children).join("\n");
+            if content.is_empty() {
+                continue;
+            }
+            let marker = match (self.counting, ordered) {
+                (true, _) => String::new(),
+                (false, true) => format!("{}. ", i + 1),
+                (false, false) => "- ".to_string(),
+            };
+            let indent = " ".repeat(marker.len());
+            items.push(prefix_lines(&content, &marker, &indent));
+        }
+        items.join("\n")
+    }
+
+    fn table(&self, el: &Element) -> String {
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut sections = vec![el];
+        for name in ["thead", "tbody", "tfoot"] {
+            sections.extend(el.child_elements(name));
+        }
+        for section in sections {
+            for tr in section.child_elements("tr") {
+                let cells: Vec<String> = tr
+                    .children
+                    .iter()
+                    .filter_map(|n| match n {
+                        Node::Element(cell) if cell.name == "td" || cell.name == "th" => {
+                            let text = self.blocks(&cell.children).join(" ").replace('\n', " ");
+                            Some(if self.markdown { text.replace('|', "\\|") } else { text })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                if !cells.is_empty() {
+                    rows.push(cells);
+                }
+            }
+        }
+        if rows.is_empty() {
+            return String::new();
+        }
+        if !self.markdown {
+            let separator = if self.counting { " " } else { "\t" };
+            return rows
+                .iter()
+                .map(|r| r.join(separator).trim().to_string())
+                .filter(|r| !r.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines = Vec::with_capacity(rows.len() + 1);
+        for (i, row) in rows.iter().enumerate() {
+            let cells: Vec<&str> = (0..width).map(|c| row.get(c).map_or("", String::as_str)).collect();
+            lines.push(format!("| {} |", cells.join(" | ")));
+            if i == 0 {
+                lines.push(format!("|{}|", vec![" --- "; width].join("|")));
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn task_list(&self, el: &Element) -> String {
+        let mut lines = Vec::new();
+        for task in el.child_elements("ac:task") {
+            let body = task
+                .child("ac:task-body")
+                .map(|b| self.blocks(&b.children).join(" ").replace('\n', " "))
+                .unwrap_or_default();
+            if body.is_empty() {
+                continue;
+            }
+            if self.counting {
+                lines.push(body);
+            } else {
+                let done = task.child("ac:task-status").is_some_and(|s| s.raw_text().trim() == "complete");
+                lines.push(format!("- [{}] {}", if done { "x" } else { " " }, body));
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn macro_block(&self, el: &Element, out: &mut Vec<String>) {
+        let name = el.attr("ac:name").unwrap_or("");
+        let rich_body = el.child("ac:rich-text-body");
+        let plain_body = el.child("ac:plain-text-body");
+
+        if name == "code" || name == "noformat" {
+            let code = plain_body.map(Element::raw_text).unwrap_or_default();
+            self.code_block(&code, el.param("language").as_deref(), out);
+            return;
+        }
+
+        let title = el.param("title");
+        let body = rich_body.map(|b| self.render(&b.children)).unwrap_or_default();
+
+        if PANEL_MACROS.contains(&name) {
+            let label = match (&title, self.counting) {
+                (Some(title), _) => Some(title.clone()),
+                (None, false) if name != "panel" => Some(capitalize(name)),
+                _ => None,
+            };
+            let label = label.map(|l| if self.markdown { format!("**{}**", l) } else { l });
+            let inner = [label.unwrap_or_default(), body]
+                .into_iter()
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            if !inner.is_empty() {
+                out.push(self.quote(&inner));
+            }
+            return;
+        }
+
+        // expand, excerpt 등: 제목(있으면)과 본문만 펼침 / 본문이 없는 매크로(toc, children 등)는 버림
+        if let Some(title) = title.filter(|_| rich_body.is_some()) {
+            out.push(if self.markdown { format!("**{}**", title) } else { title });
+        }
+        if !body.is_empty() {
+            out.push(body);
+        } else if let Some(plain) = plain_body {
+            self.code_block(&plain.raw_text(), None, out);
+        }
+    }
+
+    fn inline(&self, node: &Node, out: &mut String) {
+        let el = match node {
+            Node::Text(text) => return push_collapsed(out, text),
+            Node::Element(el) => el,
+        };
+        if SKIPPED_ELEMENTS.contains(&el.name.as_str()) {
+            return;
+        }
+        match el.name.as_str() {
+            "br" => out.push('\n'),
+            "strong" | "b" => self.wrap(el, "**", out),
+            "em" | "i" => self.wrap(el, "*", out),
+            "s" | "del" | "strike" => self.wrap(el, "~~", out),
+            "code" | "tt" => self.wrap(el, "`", out),
+            "a" => {
+                let text = self.inline_text(&el.children);
+                match el.attr("href") {
+                    Some(href) if self.markdown && !href.is_empty() && !href.starts_with('#') && !text.is_empty() => {
+                        push_collapsed(out, &format!("[{}]({})", text, href))
+                    }
+                    _ => push_collapsed(out, &text),
+                }
+            }
+            "ac:link" => {
+                let text = if let Some(body) = el.child("ac:link-body") {
+                    self.inline_text(&body.children)
+                } else if let Some(body) = el.child("ac:plain-text-link-body") {
+                    body.raw_text()
+                } else if let Some(page) = el.child("ri:page") {
+                    page.attr("ri:content-title").unwrap_or("").to_string()
+                } else if let Some(file) = el.child("ri:attachment") {
+                    file.attr("ri:filename").unwrap_or("").to_string()
+                } else if el.child("ri:user").is_some() && !self.counting {
+                    "@user".to_string()
+                } else {
+                    String::new()
+                };
+                push_collapsed(out, &text);
+            }
+            "ac:image" => {
+                if self.markdown && !self.counting {
+                    let src = el
+                        .child("ri:attachment")
+                        .and_then(|a| a.attr("ri:filename"))
+                        .or_else(|| el.child("ri:url").and_then(|u| u.attr("ri:value")))
+                        .unwrap_or("");
+                    let alt = el.attr("ac:alt").unwrap_or("");
+                    push_collapsed(out, &format!("![{}]({})", alt, src));
+                }
+            }
+            "ac:emoticon" => {
+                if !self.counting {
+                    push_collapsed(out, el.attr("ac:emoji-fallback").unwrap_or(""));
+                }
+            }
+            "time" => push_collapsed(out, el.attr("datetime").unwrap_or("")),
+            "ac:structured-macro" => match el.attr("ac:name").unwrap_or("") {
+                "status" => {
+                    if let Some(title) = el.param("title") {
+                        let text = if self.counting { title } else { format!("[{}]", title) };
+                        push_collapsed(out, &text);
+                    }
+                }
+                "jira" => {
+                    if !self.counting {
+                        push_collapsed(out, &el.param("key").unwrap_or_default());
+                    }
+                }
+                "anchor" => {}
+                _ => self.push_blocks_inline(&el.children, out),
+            },
+            _ if is_block(el) => self.push_blocks_inline(std::slice::from_ref(node), out),
+            _ => {
+                for child in &el.children {
+                    self.inline(child, out);
+                }
+            }
+        }
+    }
+
+    /// 인라인 위치에 블록이 나온 경우 (잘못된 중첩) 줄을 나눠 이어 붙임
+    fn push_blocks_inline(&self, nodes: &[Node], out: &mut String) {
+        let text = self.blocks(nodes).join("\n");
+        if text.is_empty() {
+            return;
+        }
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(&text);
+        out.push('\n');
+    }
+
+    fn wrap(&self, el: &Element, marker: &str, out: &mut String) {
+        let text = self.inline_text(&el.children);
+        if text.is_empty() {
+            return;
+        }
+        let leading_space = el.children.first().is_some_and(|n| matches!(n, Node::Text(t) if t.starts_with(char::is_whitespace)));
+        if leading_space {
+            push_collapsed(out, " ");
+        }
+        if self.markdown {
+            out.push_str(marker);
+            out.push_str(&text);
+            out.push_str(marker);
+        } else {
+            out.push_str(&text);
+        }
+        let trailing_space = el.children.last().is_some_and(|n| matches!(n, Node::Text(t) if t.ends_with(char::is_whitespace)));
+        if trailing_space {
+            out.push(' ');
+        }
+    }
+}
+
+/// storage HTML을 평문/Markdown으로 변환하고 단어 수 계산 (CJK 글자 수 ÷ `cjk_factor`)
+pub fn convert_storage(html: &str, format: StorageFormat, cjk_factor: f64) -> StorageConversion {
+    let nodes = parse(html);
+    let content = Renderer {
+        markdown: format == StorageFormat::Markdown,
+        counting: false,
+    }
+    .render(&nodes);
+    let countable = Renderer {
+        markdown: false,
+        counting: true,
+    }
+    .render(&nodes);
+    StorageConversion {
+        content,
+        word_count: count_text(&countable, cjk_factor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markdown(html: &str) -> String {
+        convert_storage(html, StorageFormat::Markdown, 1.0).content
+    }
+
+    #[test]
+    fn test_headings_lists_and_inline_marks() {
+        let html = "<h2>Setup&nbsp;guide</h2><p>Use <strong>bold</strong> and <a href=\"https://x.io\">link</a>.</p>\
+                    <ul><li>One<ul><li>Nested</li></ul></li><li><p>Two</p></li></ul><ol><li>First</li><li>Second</li></ol>";
+        assert_eq!(
+            markdown(html),
+            "## Setup guide\n\nUse **bold** and [link](https://x.io).\n\n- One\n  - Nested\n- Two\n\n1. First\n2. Second"
+        );
+    }
+
+    #[test]
+    fn test_table_to_markdown_and_text() {
+        let html = "<table><tbody><tr><th>Key</th><th>Value</th></tr><tr><td><p>a|b</p></td><td>2</td></tr></tbody></table>";
+        assert_eq!(markdown(html), "| Key | Value |\n| --- | --- |\n| a\\|b | 2 |");
+        assert_eq!(
+            convert_storage(html, StorageFormat::Text, 1.0).content,
+            "Key\tValue\na|b\t2"
+        );
+    }
+
+    #[test]
+    fn test_macros_are_flattened() {
+        let html = "<ac:structured-macro ac:name=\"toc\"><ac:parameter ac:name=\"maxLevel\">3</ac:parameter></ac:structured-macro>\
+                    <ac:structured-macro ac:name=\"info\"><ac:rich-text-body><p>Read this</p></ac:rich-text-body></ac:structured-macro>\
+                    <ac:structured-macro ac:name=\"code\"><ac:parameter ac:name=\"language\">rust</ac:parameter>\
+                    <ac:plain-text-body><![CDATA[let a = 1 < 2;]]></ac:plain-text-body></ac:structured-macro>\
+                    <p>See <ac:link><ri:page ri:content-title=\"Style Guide\" /></ac:link> \
+                    <ac:structured-macro ac:name=\"status\"><ac:parameter ac:name=\"title\">DONE</ac:parameter></ac:structured-macro></p>";
+        assert_eq!(
+            markdown(html),
+            "> **Info**\n>\n> Read this\n\n```rust\nlet a = 1 < 2;\n```\n\nSee Style Guide [DONE]"
+        );
+    }
+
+    #[test]
+    fn test_word_count_skips_markers_and_code() {
+        let html = "<ol><li>alpha beta</li></ol><ac:structured-macro ac:name=\"code\">\
+                    <ac:plain-text-body><![CDATA[fn main() {}]]></ac:plain-text-body></ac:structured-macro>\
+                    <ac:structured-macro ac:name=\"note\"><ac:rich-text-body><p>gamma</p></ac:rich-text-body></ac:structured-macro>";
+        let result = convert_storage(html, StorageFormat::Markdown, 1.0);
+        assert_eq!(result.word_count.words, 3);
+    }
+
+    #[test]
+    fn test_tolerates_malformed_markup() {
+        assert_eq!(markdown("<p>a &unknown; b <em>c</p><p>d < e"), "a &unknown; b *c*\n\nd < e");
+    }
+}
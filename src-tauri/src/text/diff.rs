@@ -0,0 +1,144 @@
+//! Word Diff
+//!
+//! 두 평문을 단어 단위로 비교해 삽입/삭제 구간을 계산합니다 (리뷰 변경 추적용).
+
+use serde::{Deserialize, Serialize};
+
+/// 이보다 토큰이 많으면 LCS 대신 전체 삭제 + 전체 삽입으로 처리 (O(n·m) 메모리 제한)
+const MAX_DIFF_TOKENS: usize = 4000;
+
+/// 변경 구간 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// 변경 구간
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffOp {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// 단어/공백/구두점 단위로 분리 (한자/가나는 글자 단위)
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut prev: Option<u8> = None;
+
+    for (i, c) in text.char_indices() {
+        let class = if c.is_whitespace() {
+            0
+        } else if matches!(c, '\u{3040}'..='\u{30FF}' | '\u{3400}'..='\u{9FFF}') {
+            3
+        } else if c.is_alphanumeric() {
+            1
+        } else {
+            2
+        };
+        // 같은 종류가 이어지면 한 토큰 (구두점/CJK는 글자마다 분리)
+        if let Some(p) = prev {
+            if p != class || class >= 2 {
+                tokens.push(&text[start..i]);
+                start = i;
+            }
+        }
+        prev = Some(class);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+fn push_op(ops: &mut Vec<DiffOp>, kind: DiffKind, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match ops.last_mut() {
+        Some(last) if last.kind == kind => last.text.push_str(text),
+        _ => ops.push(DiffOp {
+            kind,
+            text: text.to_string(),
+        }),
+    }
+}
+
+/// before → after 단어 단위 diff
+pub fn diff_words(before: &str, after: &str) -> Vec<DiffOp> {
+    let a = tokenize(before);
+    let b = tokenize(after);
+    let mut ops = Vec::new();
+
+    if a.len() > MAX_DIFF_TOKENS || b.len() > MAX_DIFF_TOKENS {
+        if before != after {
+            push_op(&mut ops, DiffKind::Delete, before);
+            push_op(&mut ops, DiffKind::Insert, after);
+        } else {
+            push_op(&mut ops, DiffKind::Equal, before);
+        }
+        return ops;
+    }
+
+    // lcs[i][j] = a[i..], b[j..]의 LCS 길이
+    let (n, m) = (a.len(), b.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            push_op(&mut ops, DiffKind::Equal, a[i]);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            push_op(&mut ops, DiffKind::Delete, a[i]);
+            i += 1;
+        } else {
+            push_op(&mut ops, DiffKind::Insert, b[j]);
+            j += 1;
+        }
+    }
+    for t in &a[i..] {
+        push_op(&mut ops, DiffKind::Delete, t);
+    }
+    for t in &b[j..] {
+        push_op(&mut ops, DiffKind::Insert, t);
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_words() {
+        let ops = diff_words("설정 메뉴를 여세요.", "환경 설정 메뉴를 여십시오.");
+        let rebuilt_after: String = ops
+            .iter()
+            .filter(|o| o.kind != DiffKind::Delete)
+            .map(|o| o.text.as_str())
+            .collect();
+        let rebuilt_before: String = ops
+            .iter()
+            .filter(|o| o.kind != DiffKind::Insert)
+            .map(|o| o.text.as_str())
+            .collect();
+        assert_eq!(rebuilt_after, "환경 설정 메뉴를 여십시오.");
+        assert_eq!(rebuilt_before, "설정 메뉴를 여세요.");
+        assert_eq!(ops[0], DiffOp { kind: DiffKind::Insert, text: "환경 ".to_string() });
+        assert!(ops.iter().any(|o| o.kind == DiffKind::Delete && o.text == "여세요"));
+    }
+}
@@ -0,0 +1,183 @@
+//! DNT (Do-Not-Translate) Matching
+//!
+//! 번역 금지 용어/패턴을 찾아 QA 검사와 LLM/MT 전송 전 마스킹에 사용합니다.
+
+use regex::{Regex, RegexBuilder};
+
+use super::{is_ascii_word_char, make_token, MaskedText, Placeholder};
+use crate::models::DntTerm;
+
+/// DNT 마스킹 토큰 접두사 (`⟦D1⟧`, `⟦D2⟧`, ...)
+const DNT_TOKEN_PREFIX: &str = "D";
+
+/// 텍스트 안에서 찾은 DNT 구간
+#[derive(Debug, Clone)]
+pub struct DntMatch {
+    pub start: usize,
+    pub end: usize,
+    pub term_id: String,
+}
+
+struct CompiledTerm {
+    term_id: String,
+    regex: Regex,
+    /// 리터럴 용어의 앞/뒤가 ASCII 영숫자면 단어 중간 매칭을 배제
+    check_start_boundary: bool,
+    check_end_boundary: bool,
+}
+
+/// 컴파일된 DNT 용어 집합
+pub struct DntMatcher {
+    terms: Vec<CompiledTerm>,
+}
+
+/// 용어 하나를 정규식으로 컴파일 (잘못된 패턴 검증에도 사용)
+pub fn compile_term(term: &DntTerm) -> Result<Regex, regex::Error> {
+    let pattern = if term.is_regex {
+        term.term.clone()
+    } else {
+        regex::escape(term.term.trim())
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!term.case_sensitive)
+        .build()
+}
+
+impl DntMatcher {
+    /// 용어 목록으로 매처 생성
+    /// - 잘못된 정규식은 건너뛰지 않고 오류로 반환합니다(저장 시점에 이미 검증됨).
+    pub fn new(terms: &[DntTerm]) -> Result<Self, regex::Error> {
+        let mut compiled = Vec::with_capacity(terms.len());
+        for t in terms {
+            if t.term.trim().is_empty() {
+                continue;
+            }
+            let literal = t.term.trim();
+            compiled.push(CompiledTerm {
+                term_id: t.id.clone(),
+                regex: compile_term(t)?,
+                check_start_boundary: !t.is_regex && literal.chars().next().is_some_and(is_ascii_word_char),
+                check_end_boundary: !t.is_regex && literal.chars().last().is_some_and(is_ascii_word_char),
+            });
+        }
+        Ok(Self { terms: compiled })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// 겹치지 않는 DNT 구간을 앞에서부터 찾음 (같은 위치면 긴 매칭 우선)
+    pub fn find_all(&self, text: &str) -> Vec<DntMatch> {
+        let mut candidates: Vec<DntMatch> = Vec::new();
+        for t in &self.terms {
+            for m in t.regex.find_iter(text) {
+                if m.start() == m.end() {
+                    continue;
+                }
+                if t.check_start_boundary
+                    && text[..m.start()].chars().next_back().is_some_and(is_ascii_word_char)
+                {
+                    continue;
+                }
+                if t.check_end_boundary && text[m.end()..].chars().next().is_some_and(is_ascii_word_char) {
+                    continue;
+                }
+                candidates.push(DntMatch {
+                    start: m.start(),
+                    end: m.end(),
+                    term_id: t.term_id.clone(),
+                });
+            }
+        }
+
+        candidates.sort_by(|a, b| a.start.cmp(&b.start).then((b.end - b.start).cmp(&(a.end - a.start))));
+
+        let mut out: Vec<DntMatch> = Vec::new();
+        let mut cursor = 0;
+        for c in candidates {
+            if c.start >= cursor {
+                cursor = c.end;
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// DNT 구간을 토큰으로 치환 (등장마다 고유 토큰을 부여해 복원 시 누락/중복을 검증할 수 있게 함)
+    pub fn mask(&self, text: &str) -> MaskedText {
+        self.mask_from(text, 0)
+    }
+
+    /// `mask`와 같되 토큰 번호를 `first + 1`부터 매김 (한 요청의 여러 텍스트를 겹치지 않게 마스킹)
+    pub fn mask_from(&self, text: &str, first: usize) -> MaskedText {
+        let mut placeholders: Vec<Placeholder> = Vec::new();
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+
+        for m in self.find_all(text) {
+            let token = make_token(DNT_TOKEN_PREFIX, first + placeholders.len() + 1);
+            out.push_str(&text[last..m.start]);
+            out.push_str(&token);
+            placeholders.push(Placeholder {
+                token,
+                original: text[m.start..m.end].to_string(),
+            });
+            last = m.end;
+        }
+        out.push_str(&text[last..]);
+
+        MaskedText {
+            text: out,
+            placeholders,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::restore_placeholders;
+
+    fn term(id: &str, term: &str, is_regex: bool, case_sensitive: bool) -> DntTerm {
+        DntTerm {
+            id: id.to_string(),
+            project_id: None,
+            term: term.to_string(),
+            is_regex,
+            case_sensitive,
+            notes: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_mask_and_restore_roundtrip() {
+        let matcher = DntMatcher::new(&[
+            term("1", "OddEyes", false, true),
+            term("2", r"v\d+\.\d+", true, true),
+        ])
+        .unwrap();
+
+        let masked = matcher.mask("OddEyes v1.6 출시, OddEyes는 번역 도구");
+        assert_eq!(masked.text, "⟦D1⟧ ⟦D2⟧ 출시, ⟦D3⟧는 번역 도구");
+        assert_eq!(masked.placeholders.len(), 3);
+
+        let restored = restore_placeholders("⟦D3⟧ is a tool; ⟦D1⟧ ⟦D2⟧ released", &masked.placeholders);
+        assert!(restored.is_intact());
+        assert_eq!(restored.text, "OddEyes is a tool; OddEyes v1.6 released");
+
+        let broken = restore_placeholders("⟦D1⟧ ⟦D1⟧ released", &masked.placeholders);
+        assert_eq!(broken.missing_tokens, vec!["⟦D2⟧", "⟦D3⟧"]);
+        assert_eq!(broken.duplicated_tokens, vec!["⟦D1⟧"]);
+    }
+
+    #[test]
+    fn test_ascii_terms_do_not_match_inside_words() {
+        let matcher = DntMatcher::new(&[term("1", "API", false, false)]).unwrap();
+
+        assert!(matcher.find_all("RAPID growth").is_empty());
+        assert_eq!(matcher.find_all("api 키와 API는").len(), 2);
+    }
+}
@@ -0,0 +1,77 @@
+//! Character Encoding Detection
+//!
+//! 가져온 텍스트 파일(TXT/CSV/SRT 등)의 문자 인코딩을 감지해 UTF-8로 변환합니다.
+//! - BOM이 있으면 BOM을 따르고, 유효한 UTF-8이면 그대로 사용합니다.
+//! - 그 외(EUC-KR, Shift_JIS, GBK 등)는 chardetng로 추정합니다.
+
+use std::path::Path;
+
+use chardetng::EncodingDetector;
+use encoding_rs::{Encoding, UTF_8};
+use serde::Serialize;
+
+use super::unicode::into_nfc;
+
+/// 디코딩 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedText {
+    pub text: String,
+    /// 감지된 인코딩 이름 (예: "UTF-8", "EUC-KR", "Shift_JIS")
+    pub encoding: String,
+    /// 변환할 수 없는 바이트가 있어 U+FFFD로 대체되었는지
+    pub had_errors: bool,
+}
+
+/// 바이트열의 인코딩 추정
+pub fn detect_encoding(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+    if std::str::from_utf8(bytes).is_ok() {
+        return UTF_8;
+    }
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    detector.guess(None, false)
+}
+
+/// 인코딩을 감지해 UTF-8 문자열로 변환 (BOM 제거)
+pub fn decode_bytes(bytes: &[u8]) -> DecodedText {
+    let encoding = detect_encoding(bytes);
+    let (text, actual, had_errors) = encoding.decode(bytes);
+    DecodedText {
+        text: into_nfc(text.into_owned()),
+        encoding: actual.name().to_string(),
+        had_errors,
+    }
+}
+
+/// 텍스트 파일을 읽어 UTF-8로 변환 (`fs::read_to_string` 대체)
+pub fn read_text_file(path: &Path) -> std::io::Result<DecodedText> {
+    let bytes = std::fs::read(path)?;
+    Ok(decode_bytes(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf8_and_bom() {
+        let decoded = decode_bytes("번역,translation".as_bytes());
+        assert_eq!(decoded.encoding, "UTF-8");
+        assert_eq!(decoded.text, "번역,translation");
+
+        let decoded = decode_bytes(b"\xEF\xBB\xBFsource,target");
+        assert_eq!(decoded.text, "source,target");
+
+        let decoded = decode_bytes(b"\xFF\xFEa\x00b\x00");
+        assert_eq!(decoded.encoding, "UTF-16LE");
+        assert_eq!(decoded.text, "ab");
+
+        // macOS에서 저장한 NFD 한글도 NFC로 변환
+        let decoded = decode_bytes("\u{1107}\u{1165}\u{11AB}\u{110B}\u{1167}\u{11A8}".as_bytes());
+        assert_eq!(decoded.text, "번역");
+    }
+}
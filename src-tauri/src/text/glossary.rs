@@ -0,0 +1,142 @@
+//! Glossary Term Matching
+//!
+//! 원문 평문에서 용어집 원문 용어가 등장한 구간을 찾습니다 (에디터 용어 하이라이트/호버용).
+//! - 같은 위치에서 여러 용어가 겹치면 긴 용어를 우선합니다.
+//! - 구간 오프셋은 프론트엔드 문자열과 맞도록 UTF-16 code unit 기준입니다.
+
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+use super::is_ascii_word_char;
+use crate::db::GlossaryEntryRow;
+
+/// 블록 안에서 찾은 용어 구간
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossaryHit {
+    pub entry_id: String,
+    pub source: String,
+    pub target: String,
+    pub notes: Option<String>,
+    /// 평문 기준 시작 위치 (UTF-16)
+    pub start: usize,
+    /// 평문 기준 끝 위치 (UTF-16, 미포함)
+    pub end: usize,
+    /// 실제로 매칭된 문자열 (대소문자 구분 없는 용어는 용어집 표기와 다를 수 있음)
+    pub text: String,
+}
+
+struct CompiledEntry {
+    entry: GlossaryEntryRow,
+    regex: Regex,
+    check_start_boundary: bool,
+    check_end_boundary: bool,
+}
+
+/// 컴파일된 용어집 (프로젝트별로 한 번 만들어 재사용)
+pub struct GlossaryMatcher {
+    entries: Vec<CompiledEntry>,
+}
+
+fn utf16_offset(text: &str, byte_idx: usize) -> usize {
+    text[..byte_idx].encode_utf16().count()
+}
+
+impl GlossaryMatcher {
+    /// 용어 목록으로 매처 생성 (빈 용어는 건너뜀)
+    pub fn new(entries: &[GlossaryEntryRow]) -> Self {
+        let entries = entries
+            .iter()
+            .filter_map(|entry| {
+                let term = entry.source.trim();
+                if term.is_empty() {
+                    return None;
+                }
+                let regex = RegexBuilder::new(&regex::escape(term))
+                    .case_insensitive(!entry.case_sensitive)
+                    .build()
+                    .ok()?;
+                Some(CompiledEntry {
+                    entry: entry.clone(),
+                    regex,
+                    check_start_boundary: term.chars().next().is_some_and(is_ascii_word_char),
+                    check_end_boundary: term.chars().last().is_some_and(is_ascii_word_char),
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 겹치지 않는 용어 구간을 앞에서부터 찾음 (같은 위치면 긴 매칭 우선)
+    pub fn find_all(&self, text: &str) -> Vec<GlossaryHit> {
+        let mut candidates: Vec<(usize, usize, &CompiledEntry)> = Vec::new();
+        for compiled in &self.entries {
+            for m in compiled.regex.find_iter(text) {
+                if compiled.check_start_boundary
+                    && text[..m.start()].chars().next_back().is_some_and(is_ascii_word_char)
+                {
+                    continue;
+                }
+                if compiled.check_end_boundary && text[m.end()..].chars().next().is_some_and(is_ascii_word_char) {
+                    continue;
+                }
+                candidates.push((m.start(), m.end(), compiled));
+            }
+        }
+
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then((b.1 - b.0).cmp(&(a.1 - a.0))));
+
+        let mut out = Vec::new();
+        let mut cursor = 0;
+        for (start, end, compiled) in candidates {
+            if start < cursor {
+                continue;
+            }
+            cursor = end;
+            out.push(GlossaryHit {
+                entry_id: compiled.entry.id.clone(),
+                source: compiled.entry.source.clone(),
+                target: compiled.entry.target.clone(),
+                notes: compiled.entry.notes.clone(),
+                start: utf16_offset(text, start),
+                end: utf16_offset(text, end),
+                text: text[start..end].to_string(),
+            });
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, source: &str, case_sensitive: bool) -> GlossaryEntryRow {
+        GlossaryEntryRow {
+            id: id.to_string(),
+            source: source.to_string(),
+            target: format!("{}-ko", source),
+            notes: None,
+            domain: None,
+            case_sensitive,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn prefers_longer_terms_and_respects_word_boundaries() {
+        let matcher = GlossaryMatcher::new(&[
+            entry("1", "Game", false),
+            entry("2", "game engine", false),
+            entry("3", "API", true),
+        ]);
+        let hits = matcher.find_all("🎮 Game Engine uses Games and api, API");
+        let spans: Vec<_> = hits.iter().map(|h| (h.entry_id.as_str(), h.start, h.end, h.text.as_str())).collect();
+        assert_eq!(spans, vec![("2", 3, 14, "Game Engine"), ("3", 35, 38, "API")]);
+    }
+}
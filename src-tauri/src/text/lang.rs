@@ -0,0 +1,246 @@
+//! Language Detection
+//!
+//! 문자 체계(script) 비율과 라틴 문자권 불용어 빈도를 이용한 가벼운 언어 감지.
+//! 가져오기 시 원문 언어가 프로젝트 언어쌍과 맞는지 경고하고,
+//! 새 프로젝트의 번역 언어를 자동으로 채우는 데 사용합니다.
+
+use serde::Serialize;
+
+/// 지원 언어 (BCP 47 코드, 에디터에서 쓰는 표시 이름)
+const LANGUAGES: &[(&str, &str)] = &[
+    ("ko", "한국어"),
+    ("en", "영어"),
+    ("ja", "일본어"),
+    ("zh", "중국어"),
+    ("es", "스페인어"),
+    ("ru", "러시아어"),
+];
+
+/// 이보다 글자 수가 적으면 감지 결과를 신뢰하지 않음
+const MIN_RELIABLE_LETTERS: usize = 10;
+
+/// 신뢰 가능한 결과로 보는 최소 confidence
+const MIN_RELIABLE_CONFIDENCE: f64 = 0.6;
+
+const EN_STOPWORDS: &[&str] = &[
+    "the", "and", "of", "to", "is", "in", "that", "it", "for", "with", "you", "this", "are", "on", "be",
+];
+const ES_STOPWORDS: &[&str] = &[
+    "el", "la", "los", "las", "de", "que", "y", "en", "es", "por", "para", "con", "una", "un", "del",
+];
+
+/// 언어 감지 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageDetection {
+    /// BCP 47 코드 (감지 실패 시 None)
+    pub code: Option<String>,
+    /// 에디터 표시 이름 (예: "한국어")
+    pub name: Option<String>,
+    /// 0.0 ~ 1.0
+    pub confidence: f64,
+    /// 텍스트가 충분히 길고 confidence가 높은지
+    pub reliable: bool,
+}
+
+/// BCP 47 코드 → 표시 이름
+pub fn display_name(code: &str) -> Option<&'static str> {
+    LANGUAGES.iter().find(|(c, _)| *c == code).map(|(_, n)| *n)
+}
+
+/// 표시 이름 또는 코드(예: "한국어", "ko", "ko-KR")를 BCP 47 기본 코드로 변환
+pub fn normalize_language(value: &str) -> Option<&'static str> {
+    let v = value.trim();
+    let primary = v.split(['-', '_']).next().unwrap_or(v).to_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|(code, name)| *code == primary || *name == v)
+        .map(|(code, _)| *code)
+}
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}')
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+fn is_cyrillic(c: char) -> bool {
+    matches!(c, '\u{0400}'..='\u{04FF}')
+}
+
+/// 텍스트 언어 감지
+pub fn detect_language(text: &str) -> LanguageDetection {
+    let (mut hangul, mut kana, mut han, mut cyrillic, mut latin, mut letters) = (0usize, 0, 0, 0, 0, 0);
+    for c in text.chars() {
+        if !c.is_alphabetic() {
+            continue;
+        }
+        letters += 1;
+        if is_hangul(c) {
+            hangul += 1;
+        } else if is_kana(c) {
+            kana += 1;
+        } else if is_han(c) {
+            han += 1;
+        } else if is_cyrillic(c) {
+            cyrillic += 1;
+        } else if c.is_ascii_alphabetic() || matches!(c, '\u{00C0}'..='\u{024F}') {
+            latin += 1;
+        }
+    }
+
+    if letters == 0 {
+        return LanguageDetection {
+            code: None,
+            name: None,
+            confidence: 0.0,
+            reliable: false,
+        };
+    }
+
+    // 일본어는 한자를 섞어 쓰므로 가나가 조금이라도 있으면 한자를 일본어 쪽으로 합산
+    let (ja, zh) = if kana * 20 >= kana + han && kana > 0 {
+        (kana + han, 0)
+    } else {
+        (kana, han)
+    };
+
+    let (code, count) = [("ko", hangul), ("ja", ja), ("zh", zh), ("ru", cyrillic), ("latin", latin)]
+        .into_iter()
+        .max_by_key(|(_, n)| *n)
+        .unwrap_or(("latin", 0));
+
+    let mut confidence = count as f64 / letters as f64;
+    let code = if code == "latin" {
+        let (latin_code, ratio) = detect_latin(text);
+        confidence *= ratio;
+        latin_code
+    } else {
+        code
+    };
+
+    LanguageDetection {
+        code: Some(code.to_string()),
+        name: display_name(code).map(str::to_string),
+        confidence,
+        reliable: letters >= MIN_RELIABLE_LETTERS && confidence >= MIN_RELIABLE_CONFIDENCE,
+    }
+}
+
+/// 라틴 문자권 구분 (영어/스페인어), 불용어 신호가 없으면 영어로 보고 신뢰도를 낮춤
+fn detect_latin(text: &str) -> (&'static str, f64) {
+    let (mut en, mut es) = (0usize, 0usize);
+    for word in text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+    {
+        if EN_STOPWORDS.contains(&word.as_str()) {
+            en += 1;
+        }
+        if ES_STOPWORDS.contains(&word.as_str()) {
+            es += 1;
+        }
+    }
+    if es > en {
+        ("es", es as f64 / (en + es) as f64)
+    } else if en > 0 {
+        ("en", en as f64 / (en + es) as f64)
+    } else {
+        ("en", 0.5)
+    }
+}
+
+/// 가져온 원문이 프로젝트 언어쌍과 맞지 않으면 경고 메시지 반환
+/// - 감지 결과가 신뢰할 만할 때만 판단합니다.
+/// - target_language/source_language는 표시 이름 또는 BCP 47 코드 모두 허용합니다.
+pub fn language_mismatch_warning(
+    detection: &LanguageDetection,
+    source_language: Option<&str>,
+    target_language: Option<&str>,
+) -> Option<String> {
+    if !detection.reliable {
+        return None;
+    }
+    let detected = detection.code.as_deref()?;
+    let label = display_name(detected).unwrap_or(detected);
+
+    if let Some(target) = target_language.and_then(normalize_language) {
+        if target == detected {
+            return Some(format!(
+                "가져온 원문이 번역 언어({})로 작성된 것 같습니다. 원문/번역문이 바뀌지 않았는지 확인해주세요.",
+                label
+            ));
+        }
+    }
+    if let Some(source) = source_language.and_then(normalize_language) {
+        if source != detected {
+            return Some(format!(
+                "가져온 원문이 {}로 감지되었습니다. 프로젝트 원문 언어({})와 다릅니다.",
+                label,
+                display_name(source).unwrap_or(source)
+            ));
+        }
+    }
+    None
+}
+
+/// 새 프로젝트의 번역 언어 추정 (에디터 표시 이름으로 반환)
+/// - 앱 기본 번역 언어가 있고 원문 언어와 다르면 그대로 사용
+/// - 없으면 원문이 한국어일 때 영어, 그 외에는 한국어
+pub fn suggest_target_language(
+    source: Option<&LanguageDetection>,
+    default_target: Option<&str>,
+) -> Option<&'static str> {
+    let detected = source.filter(|d| d.reliable).and_then(|d| d.code.as_deref());
+    let default_target = default_target.and_then(normalize_language);
+
+    let code = match (default_target, detected) {
+        (Some(target), Some(source)) if target != source => target,
+        (Some(target), None) => target,
+        (_, Some("ko")) => "en",
+        (_, Some(_)) => "ko",
+        (None, None) => return None,
+    };
+    display_name(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_scripts() {
+        assert_eq!(detect_language("이 문서는 번역 편집기 사용법을 설명합니다.").code.as_deref(), Some("ko"));
+        assert_eq!(detect_language("このドキュメントは翻訳エディタの使い方を説明します。").code.as_deref(), Some("ja"));
+        assert_eq!(detect_language("本文档介绍翻译编辑器的使用方法。").code.as_deref(), Some("zh"));
+        assert_eq!(detect_language("Этот документ описывает редактор перевода.").code.as_deref(), Some("ru"));
+        assert_eq!(detect_language("This is the guide for the translation editor.").code.as_deref(), Some("en"));
+        assert_eq!(detect_language("Este es el manual de la herramienta para los traductores.").code.as_deref(), Some("es"));
+        assert!(!detect_language("OK").reliable);
+    }
+
+    #[test]
+    fn test_mismatch_warning() {
+        let ko = detect_language("이 문서는 번역 편집기 사용법을 설명합니다.");
+        assert!(language_mismatch_warning(&ko, None, Some("한국어")).is_some());
+        assert!(language_mismatch_warning(&ko, Some("en"), Some("영어")).is_some());
+        assert!(language_mismatch_warning(&ko, Some("ko-KR"), Some("영어")).is_none());
+    }
+
+    #[test]
+    fn test_suggest_target_language() {
+        let ko = detect_language("이 문서는 번역 편집기 사용법을 설명합니다.");
+        let en = detect_language("This is the guide for the translation editor.");
+        assert_eq!(suggest_target_language(Some(&en), None), Some("한국어"));
+        assert_eq!(suggest_target_language(Some(&ko), Some("ko")), Some("영어"));
+        assert_eq!(suggest_target_language(Some(&en), Some("ja")), Some("일본어"));
+        assert_eq!(suggest_target_language(None, None), None);
+    }
+}
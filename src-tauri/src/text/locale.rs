@@ -0,0 +1,304 @@
+//! Project Locales
+//!
+//! 프로젝트 원문/번역 로캘(BCP 47)을 정규화·검증하고, 로캘에 맞는 설정을 고릅니다.
+//! - 문장 분할 규칙 (CJK는 공백 없이 잇고, 약어 뒤 마침표에서 끊지 않음)
+//! - 맞춤법 검사 사전 (hunspell 이름, 예: "en_US")
+//! - MT/LLM에 넘길 언어 코드
+//! - 숫자 표기(소수점, 천 단위 구분)와 숫자 날짜 순서 (QA 검사용)
+
+use serde::Serialize;
+
+use super::lang::{display_name, normalize_language};
+
+/// 영어 이름 (LLM 프롬프트용)
+const ENGLISH_NAMES: &[(&str, &str)] = &[
+    ("ko", "Korean"),
+    ("en", "English"),
+    ("ja", "Japanese"),
+    ("zh", "Chinese"),
+    ("es", "Spanish"),
+    ("ru", "Russian"),
+];
+
+/// 지역이 없을 때 쓰는 기본 지역 (맞춤법 사전 선택용)
+const DEFAULT_REGIONS: &[(&str, &str)] = &[("ko", "KR"), ("en", "US"), ("es", "ES"), ("ru", "RU")];
+
+const EN_ABBREVIATIONS: &[&str] = &["mr.", "mrs.", "ms.", "dr.", "prof.", "st.", "vs.", "etc.", "e.g.", "i.e.", "no."];
+const ES_ABBREVIATIONS: &[&str] = &["sr.", "sra.", "srta.", "dr.", "dra.", "ud.", "uds.", "etc.", "pág.", "núm."];
+const RU_ABBREVIATIONS: &[&str] = &["т.е.", "т.д.", "т.п.", "см.", "г.", "гг.", "стр."];
+
+/// 로캘 정규화 (예: "ko_kr" → "ko-KR", "zh-hant-tw" → "zh-Hant-TW", "한국어" → "ko")
+/// - 에디터가 지원하는 언어(`lang::LANGUAGES`)만 허용합니다.
+pub fn canonicalize_locale(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let primary = normalize_language(value).ok_or_else(|| format!("Unsupported language: {}", value))?;
+    // 표시 이름("한국어")은 언어 코드만
+    if display_name(primary) == Some(value) {
+        return Ok(primary.to_string());
+    }
+
+    let mut parts = vec![primary.to_string()];
+    for subtag in value.split(['-', '_']).skip(1) {
+        let valid = match subtag.len() {
+            4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                let mut chars = subtag.chars();
+                let first = chars.next().map(|c| c.to_ascii_uppercase()).unwrap_or_default();
+                parts.push(format!("{}{}", first, chars.as_str().to_ascii_lowercase()));
+                true
+            }
+            2 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                parts.push(subtag.to_ascii_uppercase());
+                true
+            }
+            3 if subtag.chars().all(|c| c.is_ascii_digit()) => {
+                parts.push(subtag.to_string());
+                true
+            }
+            _ => false,
+        };
+        if !valid {
+            return Err(format!("Invalid locale: {}", value));
+        }
+    }
+    Ok(parts.join("-"))
+}
+
+/// 로캘의 언어 코드 (예: "ko-KR" → "ko")
+pub fn primary_language(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
+
+fn script(locale: &str) -> Option<&str> {
+    locale.split(['-', '_']).skip(1).find(|s| s.len() == 4)
+}
+
+fn region(locale: &str) -> Option<&str> {
+    locale.split(['-', '_']).skip(1).find(|s| s.len() == 2 || s.len() == 3)
+}
+
+/// 원문/번역 로캘 조합 검증 (같은 언어로 번역할 수 없음)
+pub fn validate_language_pair(source: Option<&str>, target: Option<&str>) -> Result<(), String> {
+    if let (Some(source), Some(target)) = (source, target) {
+        if primary_language(source) == primary_language(target) {
+            return Err(format!(
+                "sourceLocale ({}) and targetLocale ({}) must be different languages",
+                source, target
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 문장 분할 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentationRules {
+    /// 문장을 다시 이을 때 사이에 넣는 문자 (CJK는 빈 문자열)
+    pub joiner: &'static str,
+    /// 뒤에 공백이 와도 문장을 끊지 않는 약어 (소문자)
+    pub abbreviations: &'static [&'static str],
+}
+
+impl SegmentationRules {
+    /// 로캘에 맞는 규칙 (로캘이 없거나 모르면 영어 기준)
+    pub fn for_locale(locale: Option<&str>) -> Self {
+        match locale.and_then(normalize_language) {
+            Some("ja") | Some("zh") => Self { joiner: "", abbreviations: &[] },
+            Some("ko") => Self { joiner: " ", abbreviations: &[] },
+            Some("es") => Self { joiner: " ", abbreviations: ES_ABBREVIATIONS },
+            Some("ru") => Self { joiner: " ", abbreviations: RU_ABBREVIATIONS },
+            _ => Self { joiner: " ", abbreviations: EN_ABBREVIATIONS },
+        }
+    }
+
+    fn is_abbreviation(&self, text: &str, end: usize) -> bool {
+        let word = text[..end].rsplit(char::is_whitespace).next().unwrap_or_default();
+        let word = word.trim_start_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+        self.abbreviations.contains(&word.as_str())
+    }
+
+    /// 문장 단위로 나누기 (마침표류 뒤 공백, 전각 마침표 뒤)
+    pub fn split<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut out = Vec::new();
+        let mut start = 0;
+        let mut chars = text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let end = i + c.len_utf8();
+            let boundary = match c {
+                '。' | '！' | '？' => true,
+                '.' | '!' | '?' => {
+                    chars.peek().is_some_and(|(_, next)| next.is_whitespace())
+                        && !(c == '.' && self.is_abbreviation(text, end))
+                }
+                _ => false,
+            };
+            if boundary {
+                let sentence = text[start..end].trim();
+                if !sentence.is_empty() {
+                    out.push(sentence);
+                }
+                start = end;
+            }
+        }
+        let rest = text[start..].trim();
+        if !rest.is_empty() {
+            out.push(rest);
+        }
+        out
+    }
+}
+
+/// 숫자 표기 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal: char,
+    /// 천 단위 구분 문자 (첫 번째가 권장 표기)
+    pub grouping: &'static [char],
+}
+
+impl NumberFormat {
+    /// 로캘에 맞는 규칙 (로캘이 없거나 모르면 "1,234.5")
+    pub fn for_locale(locale: Option<&str>) -> Self {
+        match locale.and_then(normalize_language) {
+            Some("es") => Self { decimal: ',', grouping: &['.', ' ', '\u{A0}', '\u{202F}'] },
+            Some("ru") => Self { decimal: ',', grouping: &['\u{A0}', ' ', '\u{202F}'] },
+            _ => Self { decimal: '.', grouping: &[','] },
+        }
+    }
+
+    /// 공백을 천 단위 구분에 쓰는지
+    pub fn groups_with_spaces(&self) -> bool {
+        self.grouping.iter().any(|c| c.is_whitespace())
+    }
+}
+
+/// 숫자 날짜(예: "03/05/2024")의 순서
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    Mdy,
+    Dmy,
+    Ymd,
+}
+
+impl DateOrder {
+    /// 로캘에 맞는 순서 (영어는 미국식, 영국/호주 등은 일-월-연)
+    pub fn for_locale(locale: Option<&str>) -> Self {
+        match locale.and_then(|l| normalize_language(l).map(|code| (code, region(l)))) {
+            Some(("ko" | "ja" | "zh", _)) => Self::Ymd,
+            Some(("es" | "ru", _)) => Self::Dmy,
+            Some(("en", Some("GB" | "AU" | "NZ" | "IE" | "IN" | "ZA"))) => Self::Dmy,
+            _ => Self::Mdy,
+        }
+    }
+}
+
+/// 맞춤법 검사 사전 이름 (hunspell, 예: "ko_KR"), 사전이 없는 언어(일본어/중국어)는 None
+pub fn spellcheck_dictionary(locale: &str) -> Option<String> {
+    let primary = primary_language(locale);
+    let region = region(locale)
+        .filter(|r| r.len() == 2)
+        .or_else(|| DEFAULT_REGIONS.iter().find(|(l, _)| *l == primary).map(|(_, r)| *r))?;
+    Some(format!("{}_{}", primary, region))
+}
+
+/// MT/LLM 언어 코드
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MtLanguageCodes {
+    /// DeepL 번역 언어 코드 (예: "EN-US", "ZH-HANS", "KO")
+    pub deepl: String,
+    /// Google/Papago 코드 (예: "ko", "zh-CN", "zh-TW")
+    pub google: String,
+    /// LLM 프롬프트에 쓰는 이름 (예: "Korean (ko-KR)")
+    pub llm: String,
+}
+
+fn is_traditional_chinese(locale: &str) -> bool {
+    script(locale) == Some("Hant") || matches!(region(locale), Some("TW") | Some("HK") | Some("MO"))
+}
+
+/// 로캘에 맞는 MT/LLM 언어 코드
+pub fn mt_language_codes(locale: &str) -> MtLanguageCodes {
+    let primary = primary_language(locale);
+    let deepl = match primary {
+        "en" if region(locale) == Some("GB") => "EN-GB".to_string(),
+        "en" => "EN-US".to_string(),
+        "zh" if is_traditional_chinese(locale) => "ZH-HANT".to_string(),
+        "zh" => "ZH-HANS".to_string(),
+        _ => primary.to_ascii_uppercase(),
+    };
+    let google = match primary {
+        "zh" if is_traditional_chinese(locale) => "zh-TW".to_string(),
+        "zh" => "zh-CN".to_string(),
+        _ => primary.to_string(),
+    };
+    let name = ENGLISH_NAMES
+        .iter()
+        .find(|(code, _)| *code == primary)
+        .map_or(primary, |(_, name)| *name);
+    let llm = if locale == primary {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, locale)
+    };
+    MtLanguageCodes { deepl, google, llm }
+}
+
+/// 로캘 하나에 대해 자동으로 고른 설정
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocaleProfile {
+    pub locale: String,
+    /// 에디터 표시 이름 (예: "한국어")
+    pub language: Option<&'static str>,
+    pub segmentation: SegmentationRules,
+    pub spellcheck_dictionary: Option<String>,
+    pub mt: MtLanguageCodes,
+}
+
+impl LocaleProfile {
+    pub fn new(locale: &str) -> Self {
+        Self {
+            locale: locale.to_string(),
+            language: display_name(primary_language(locale)),
+            segmentation: SegmentationRules::for_locale(Some(locale)),
+            spellcheck_dictionary: spellcheck_dictionary(locale),
+            mt: mt_language_codes(locale),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize_and_validate_pair() {
+        assert_eq!(canonicalize_locale("ko_kr").as_deref(), Ok("ko-KR"));
+        assert_eq!(canonicalize_locale("zh-hant-tw").as_deref(), Ok("zh-Hant-TW"));
+        assert_eq!(canonicalize_locale("es-419").as_deref(), Ok("es-419"));
+        assert_eq!(canonicalize_locale("영어").as_deref(), Ok("en"));
+        assert!(canonicalize_locale("fr-FR").is_err());
+        assert!(canonicalize_locale("en-toolong").is_err());
+
+        assert!(validate_language_pair(Some("en-US"), Some("ko-KR")).is_ok());
+        assert!(validate_language_pair(Some("en-US"), Some("en-GB")).is_err());
+        assert!(validate_language_pair(None, Some("ko")).is_ok());
+    }
+
+    #[test]
+    fn test_locale_profile() {
+        let en = SegmentationRules::for_locale(Some("en-US"));
+        assert_eq!(en.split("Ask Dr. Kim first. Then save!"), vec!["Ask Dr. Kim first.", "Then save!"]);
+        assert_eq!(SegmentationRules::for_locale(Some("ja-JP")).joiner, "");
+
+        assert_eq!(spellcheck_dictionary("en-GB").as_deref(), Some("en_GB"));
+        assert_eq!(spellcheck_dictionary("ko").as_deref(), Some("ko_KR"));
+        assert_eq!(spellcheck_dictionary("ja"), None);
+
+        let zh = mt_language_codes("zh-Hant-TW");
+        assert_eq!((zh.deepl.as_str(), zh.google.as_str()), ("ZH-HANT", "zh-TW"));
+        assert_eq!(mt_language_codes("ko-KR").llm, "Korean (ko-KR)");
+        assert_eq!(mt_language_codes("en").deepl, "EN-US");
+    }
+}
@@ -0,0 +1,207 @@
+//! Text Utilities
+//!
+//! 블록 콘텐츠(HTML) 처리 및 LLM/MT 전송용 텍스트 변환 유틸리티
+
+pub mod analysis;
+pub mod confluence;
+pub mod diff;
+pub mod dnt;
+pub mod encoding;
+pub mod glossary;
+pub mod lang;
+pub mod locale;
+pub mod protect;
+pub mod pseudo;
+pub mod tags;
+pub mod tm_match;
+pub mod translit;
+pub mod unicode;
+pub mod words;
+
+use serde::{Deserialize, Serialize};
+
+/// LLM/MT 왕복 시 원문 일부를 대신하는 불투명 토큰
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Placeholder {
+    /// 예: `⟦D1⟧`
+    pub token: String,
+    /// 복원할 원래 문자열
+    pub original: String,
+}
+
+/// 마스킹 결과 (토큰으로 치환된 텍스트 + 복원용 매핑)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaskedText {
+    pub text: String,
+    pub placeholders: Vec<Placeholder>,
+}
+
+/// 토큰 복원 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreResult {
+    pub text: String,
+    /// 응답에서 사라진 토큰 (복원 불가)
+    pub missing_tokens: Vec<String>,
+    /// 응답에서 두 번 이상 등장한 토큰 (모두 복원됨)
+    pub duplicated_tokens: Vec<String>,
+}
+
+impl RestoreResult {
+    /// 모든 토큰이 정확히 한 번씩 살아남았는지
+    pub fn is_intact(&self) -> bool {
+        self.missing_tokens.is_empty() && self.duplicated_tokens.is_empty()
+    }
+}
+
+/// `⟦{prefix}{n}⟧` 형태의 토큰 생성
+pub fn make_token(prefix: &str, n: usize) -> String {
+    format!("⟦{}{}⟧", prefix, n)
+}
+
+/// 단어 경계 판정용 문자 (영숫자로 시작/끝나는 용어가 "cat" → "category"처럼 단어 중간에 걸리지 않도록)
+pub(crate) fn is_ascii_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// 토큰을 원래 문자열로 되돌리고, 누락/중복된 토큰을 보고
+pub fn restore_placeholders(text: &str, placeholders: &[Placeholder]) -> RestoreResult {
+    let mut out = text.to_string();
+    let mut missing_tokens = Vec::new();
+    let mut duplicated_tokens = Vec::new();
+
+    for p in placeholders {
+        match out.matches(p.token.as_str()).count() {
+            0 => missing_tokens.push(p.token.clone()),
+            1 => {}
+            _ => duplicated_tokens.push(p.token.clone()),
+        }
+        out = out.replace(p.token.as_str(), &p.original);
+    }
+
+    RestoreResult {
+        text: out,
+        missing_tokens,
+        duplicated_tokens,
+    }
+}
+
+/// 블록 콘텐츠 해시 (프론트엔드 `hashContent`와 동일한 값)
+/// - UTF-16 코드 유닛 기준 `hash * 31 + c` (32bit), 절댓값을 36진수로 표기
+pub fn content_hash(content: &str) -> String {
+    let mut hash: i32 = 0;
+    for unit in content.encode_utf16() {
+        hash = (hash << 5).wrapping_sub(hash).wrapping_add(unit as i32);
+    }
+
+    let mut n = (hash as i64).unsigned_abs();
+    if n == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push(std::char::from_digit((n % 36) as u32, 36).unwrap_or('0'));
+        n /= 36;
+    }
+    digits.iter().rev().collect()
+}
+
+/// 블록 HTML을 평문으로 변환
+/// - 태그는 제거하고, 블록 경계(`</p>`, `<br>` 등)는 줄바꿈으로 바꿉니다.
+/// - 기본 HTML 엔티티를 디코딩합니다.
+pub fn strip_html(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut chars = html.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch == '<' {
+            let mut tag = String::new();
+            for c in chars.by_ref() {
+                if c == '>' {
+                    break;
+                }
+                tag.push(c);
+            }
+            let name = tag
+                .trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or("")
+                .to_lowercase();
+            let is_closing = tag.starts_with('/');
+            let breaks_line = name == "br"
+                || (is_closing
+                    && matches!(
+                        name.as_str(),
+                        "p" | "div" | "li" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "blockquote" | "tr" | "pre"
+                    ));
+            if breaks_line && !out.ends_with('\n') {
+                out.push('\n');
+            }
+        } else if ch == '&' {
+            let mut entity = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ';' || entity.len() > 10 {
+                    break;
+                }
+                entity.push(c);
+                chars.next();
+            }
+            if chars.peek() == Some(&';') {
+                chars.next();
+                match decode_entity(&entity) {
+                    Some(decoded) => out.push(decoded),
+                    None => {
+                        out.push('&');
+                        out.push_str(&entity);
+                        out.push(';');
+                    }
+                }
+            } else {
+                out.push('&');
+                out.push_str(&entity);
+            }
+        } else {
+            out.push(ch);
+        }
+    }
+
+    out.trim_end_matches('\n').to_string()
+}
+
+/// 매칭용 정규화: 블록 HTML을 평문으로 바꾼 뒤 연속 공백을 하나로 합치고 양끝을 자름
+/// (반복 세그먼트 탐지, 일관성 검사 등에서 "같은 원문" 판정 기준)
+pub fn normalize_for_matching(html: &str) -> String {
+    strip_html(html).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" | "#39" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        "ndash" => Some('\u{2013}'),
+        "mdash" => Some('\u{2014}'),
+        "hellip" => Some('\u{2026}'),
+        "lsquo" => Some('\u{2018}'),
+        "rsquo" => Some('\u{2019}'),
+        "ldquo" => Some('\u{201c}'),
+        "rdquo" => Some('\u{201d}'),
+        "middot" => Some('\u{b7}'),
+        "bull" => Some('\u{2022}'),
+        "copy" => Some('\u{a9}'),
+        _ => {
+            let num = entity.strip_prefix('#')?;
+            let code = match num.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => num.parse::<u32>().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Provider Text Protection
+//!
+//! LLM 요청 하나에 들어가는 여러 텍스트(번역할 원문, 시스템 프롬프트의 용어집/TM/맥락)를
+//! 같은 토큰 공간에서 마스킹해 DNT 용어가 프로바이더로 나가지 않게 합니다.
+//! - 원문은 인라인 태그/플레이스홀더도 토큰으로 바꿉니다 (지시문은 `<seg>` 형식 지시가 있어 DNT만).
+//! - 원문 토큰은 응답에 정확히 한 번씩 있어야 하고, 지시문 토큰은 응답에 있으면 복원만 합니다.
+
+use super::dnt::DntMatcher;
+use super::tags::protect_tags_from;
+use super::{restore_placeholders, MaskedText, Placeholder, RestoreResult};
+
+/// 토큰이 있을 때 시스템 프롬프트에 덧붙이는 지시
+pub const TOKEN_INSTRUCTION: &str =
+    "Tokens like ⟦T1⟧ or ⟦D1⟧ stand for inline tags and protected terms: copy each one exactly once, unchanged.";
+
+/// 요청 하나 안에서 토큰 번호가 겹치지 않게 마스킹
+pub struct ProviderMasker<'a> {
+    dnt: &'a DntMatcher,
+    tag_tokens: usize,
+    dnt_tokens: usize,
+}
+
+impl<'a> ProviderMasker<'a> {
+    pub fn new(dnt: &'a DntMatcher) -> Self {
+        Self {
+            dnt,
+            tag_tokens: 0,
+            dnt_tokens: 0,
+        }
+    }
+
+    /// 같은 요청에서 앞서 쓴 토큰 수부터 이어서 번호를 매김 (요청을 나눠 마스킹할 때)
+    pub fn resume(dnt: &'a DntMatcher, tag_tokens: usize, dnt_tokens: usize) -> Self {
+        Self {
+            dnt,
+            tag_tokens,
+            dnt_tokens,
+        }
+    }
+
+    /// 지금까지 쓴 (태그, DNT) 토큰 수
+    pub fn token_counts(&self) -> (usize, usize) {
+        (self.tag_tokens, self.dnt_tokens)
+    }
+
+    /// 번역할 원문 보호 (인라인 태그 + DNT 용어)
+    /// - DNT 토큰을 먼저 복원해야 DNT 구간 안의 태그 토큰까지 복원되므로 DNT 매핑이 앞에 옵니다.
+    pub fn source(&mut self, text: &str) -> MaskedText {
+        let tags = protect_tags_from(text, self.tag_tokens);
+        self.tag_tokens += tags.placeholders.len();
+        let mut masked = self.mask_dnt(&tags.text);
+        masked.placeholders.extend(tags.placeholders);
+        masked
+    }
+
+    /// 프롬프트 지시문 보호 (용어집, TM 참고 번역, 프로젝트 맥락)
+    pub fn instructions(&mut self, text: &str) -> MaskedText {
+        self.mask_dnt(text)
+    }
+
+    fn mask_dnt(&mut self, text: &str) -> MaskedText {
+        let masked = self.dnt.mask_from(text, self.dnt_tokens);
+        self.dnt_tokens += masked.placeholders.len();
+        masked
+    }
+}
+
+/// 응답 복원 (원문 토큰의 누락/중복만 보고, 지시문 토큰은 있으면 복원)
+pub fn restore_response(text: &str, source: &[Placeholder], instructions: &[Placeholder]) -> RestoreResult {
+    let mut result = restore_placeholders(text, source);
+    result.text = restore_placeholders(&result.text, instructions).text;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DntTerm;
+
+    #[test]
+    fn test_tokens_do_not_collide_across_texts() {
+        let dnt = DntMatcher::new(&[DntTerm {
+            id: "1".to_string(),
+            project_id: None,
+            term: "OddEyes".to_string(),
+            is_regex: false,
+            case_sensitive: true,
+            notes: None,
+            created_at: 0,
+            updated_at: 0,
+        }])
+        .unwrap();
+        let mut masker = ProviderMasker::new(&dnt);
+        let system = masker.instructions("- OddEyes => OddEyes");
+        let source = masker.source("Open OddEyes");
+        let second = masker.source("Hi {name}, <b>OddEyes</b>");
+        assert_eq!(system.text, "- ⟦D1⟧ => ⟦D2⟧");
+        assert_eq!(source.text, "Open ⟦D3⟧");
+        assert_eq!(second.text, "Hi ⟦T1⟧, ⟦T2⟧⟦D4⟧⟦T3⟧");
+
+        let restored = restore_response("⟦D3⟧ 열기 (⟦D1⟧)", &source.placeholders, &system.placeholders);
+        assert!(restored.is_intact());
+        assert_eq!(restored.text, "OddEyes 열기 (OddEyes)");
+        assert_eq!(
+            restore_response("열기", &source.placeholders, &system.placeholders).missing_tokens,
+            vec!["⟦D3⟧"]
+        );
+
+        let restored = restore_response("⟦T1⟧님, ⟦T2⟧⟦D4⟧⟦T3⟧", &second.placeholders, &[]);
+        assert_eq!(restored.text, "{name}님, <b>OddEyes</b>");
+        let lost_tag = restore_response("⟦T1⟧님, ⟦D4⟧⟦T3⟧", &second.placeholders, &[]);
+        assert_eq!(lost_tag.missing_tokens, vec!["⟦T2⟧"]);
+
+        // 나눠서 마스킹해도 앞선 호출의 토큰 수부터 이어서 번호를 매김
+        let (tag_tokens, dnt_tokens) = masker.token_counts();
+        let resumed = ProviderMasker::resume(&dnt, tag_tokens, dnt_tokens).source("<i>OddEyes</i>");
+        assert_eq!(resumed.text, "⟦T4⟧⟦D5⟧⟦T5⟧");
+    }
+}
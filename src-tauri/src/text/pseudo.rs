@@ -0,0 +1,143 @@
+//! Pseudo-Translation
+//!
+//! 실제 번역 전에 레이아웃/인코딩 문제를 확인할 수 있도록 원문을 악센트 문자로 바꾸고
+//! 길이를 늘린 의사 번역문(`[Ĥéļļö ···]`)을 만듭니다.
+//! - HTML 태그, 플레이스홀더(`{0}`, `%s` 등), HTML 엔티티는 그대로 유지합니다.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+use super::tags::TAG_PATTERN;
+
+/// 확장률 상한 (%)
+pub const MAX_EXPANSION_PERCENT: u32 = 300;
+
+/// 길이 확장에 쓰는 채움 문자
+const PADDING_CHAR: char = '·';
+
+/// 태그/플레이스홀더 + HTML 엔티티
+static PROTECTED_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!("{}|&#?[A-Za-z0-9]+;", TAG_PATTERN.as_str())).expect("valid pseudo pattern")
+});
+
+/// 의사 번역 옵션
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PseudoOptions {
+    /// 원문 대비 늘릴 길이 (%)
+    pub expansion_percent: u32,
+    /// 라틴 문자를 악센트 문자로 치환
+    pub accent: bool,
+    /// 앞뒤를 `[` `]`로 감싸 잘림 여부를 확인
+    pub brackets: bool,
+}
+
+impl Default for PseudoOptions {
+    fn default() -> Self {
+        Self {
+            expansion_percent: 30,
+            accent: true,
+            brackets: true,
+        }
+    }
+}
+
+fn accent_char(c: char) -> char {
+    const LOWER: &[char] = &[
+        'å', 'ƀ', 'ç', 'ð', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ', 'ɱ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ', 'š', 'ţ', 'û', 'ṽ', 'ŵ', 'ẋ',
+        'ý', 'ž',
+    ];
+    const UPPER: &[char] = &[
+        'Å', 'Ɓ', 'Ç', 'Đ', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ', 'Š', 'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ',
+        'Ý', 'Ž',
+    ];
+    match c {
+        'a'..='z' => LOWER[(c as u8 - b'a') as usize],
+        'A'..='Z' => UPPER[(c as u8 - b'A') as usize],
+        _ => c,
+    }
+}
+
+/// 블록 콘텐츠(HTML)를 의사 번역
+/// - 보이는 텍스트가 없으면 원본을 그대로 반환합니다.
+pub fn pseudo_translate(html: &str, options: &PseudoOptions) -> String {
+    // (텍스트 여부, 조각)
+    let mut pieces: Vec<(bool, String)> = Vec::new();
+    let mut last = 0;
+    for m in PROTECTED_PATTERN.find_iter(html) {
+        if m.start() > last {
+            pieces.push((true, html[last..m.start()].to_string()));
+        }
+        pieces.push((false, m.as_str().to_string()));
+        last = m.end();
+    }
+    if last < html.len() {
+        pieces.push((true, html[last..].to_string()));
+    }
+
+    let visible: usize = pieces
+        .iter()
+        .filter(|(is_text, _)| *is_text)
+        .map(|(_, t)| t.chars().filter(|c| !c.is_whitespace()).count())
+        .sum();
+    if visible == 0 {
+        return html.to_string();
+    }
+
+    if options.accent {
+        for (is_text, text) in pieces.iter_mut() {
+            if *is_text {
+                *text = text.chars().map(accent_char).collect();
+            }
+        }
+    }
+
+    let padding_len = (visible * options.expansion_percent as usize).div_ceil(100);
+    let mut suffix = String::new();
+    if padding_len > 0 {
+        suffix.push(' ');
+        suffix.extend(std::iter::repeat_n(PADDING_CHAR, padding_len));
+    }
+    if options.brackets {
+        suffix.push(']');
+    }
+
+    let has_visible = |t: &str| t.chars().any(|c| !c.is_whitespace());
+    if let Some((_, first)) = pieces.iter_mut().find(|(is_text, t)| *is_text && has_visible(t)) {
+        if options.brackets {
+            let at = first.len() - first.trim_start().len();
+            first.insert(at, '[');
+        }
+    }
+    if let Some((_, last)) = pieces.iter_mut().rev().find(|(is_text, t)| *is_text && has_visible(t)) {
+        let at = last.trim_end().len();
+        last.insert_str(at, &suffix);
+    }
+
+    pieces.into_iter().map(|(_, t)| t).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pseudo_translate_keeps_markup() {
+        let options = PseudoOptions::default();
+        let out = pseudo_translate("<p>Hello <strong>{name}</strong> &amp; bye</p>", &options);
+        assert_eq!(out, "<p>[Ĥéļļö <strong>{name}</strong> &amp; ƀýé ···]</p>");
+
+        assert_eq!(pseudo_translate("<p></p>", &options), "<p></p>");
+    }
+
+    #[test]
+    fn test_expansion_percent() {
+        let options = PseudoOptions {
+            expansion_percent: 100,
+            accent: false,
+            brackets: false,
+        };
+        assert_eq!(pseudo_translate("abcd", &options), "abcd ····");
+    }
+}
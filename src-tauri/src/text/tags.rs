@@ -0,0 +1,83 @@
+//! Inline Tag Protection
+//!
+//! 블록 콘텐츠의 인라인 HTML 태그와 플레이스홀더(`{0}`, `%s`, `{{name}}` 등)를
+//! LLM/MT 전송 전에 불투명 토큰으로 바꾸고, 응답을 받은 뒤 원래대로 복원합니다.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{make_token, restore_placeholders, MaskedText, Placeholder, RestoreResult};
+
+/// 태그 토큰 접두사 (`⟦T1⟧`, `⟦T2⟧`, ...)
+const TAG_TOKEN_PREFIX: &str = "T";
+
+/// 보호 대상 패턴
+/// - HTML 태그: `<strong>`, `</a>`, `<br/>`
+/// - 템플릿 변수: `{{name}}`, `${name}`
+/// - 포맷 플레이스홀더: `{0}`, `{count}`, `%s`, `%1$d`
+pub(crate) static TAG_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"</?[A-Za-z][^<>]*>|\{\{[^{}]+\}\}|\$\{[^{}]+\}|\{[A-Za-z0-9_]+\}|%(?:\d+\$)?[sdifu@]")
+        .expect("valid tag pattern")
+});
+
+/// 인라인 태그/플레이스홀더를 토큰으로 치환
+/// - 등장마다 고유 토큰을 부여하므로 복원 시 누락/중복을 검증할 수 있습니다.
+pub fn protect_tags(text: &str) -> MaskedText {
+    protect_tags_from(text, 0)
+}
+
+/// `protect_tags`와 같되 토큰 번호를 `first + 1`부터 매김
+pub fn protect_tags_from(text: &str, first: usize) -> MaskedText {
+    let mut placeholders: Vec<Placeholder> = Vec::new();
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for m in TAG_PATTERN.find_iter(text) {
+        let token = make_token(TAG_TOKEN_PREFIX, first + placeholders.len() + 1);
+        out.push_str(&text[last..m.start()]);
+        out.push_str(&token);
+        placeholders.push(Placeholder {
+            token,
+            original: m.as_str().to_string(),
+        });
+        last = m.end();
+    }
+    out.push_str(&text[last..]);
+
+    MaskedText {
+        text: out,
+        placeholders,
+    }
+}
+
+/// 토큰을 원래 태그로 복원
+/// - 복원 결과의 `missing_tokens`/`duplicated_tokens`가 비어 있어야 안전하게 반영할 수 있습니다.
+pub fn restore_tags(text: &str, placeholders: &[Placeholder]) -> RestoreResult {
+    restore_placeholders(text, placeholders)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protect_and_restore_tags() {
+        let masked = protect_tags("<p>Hello <strong>{name}</strong>, you have %d new %1$s</p>");
+        assert_eq!(masked.text, "⟦T1⟧Hello ⟦T2⟧⟦T3⟧⟦T4⟧, you have ⟦T5⟧ new ⟦T6⟧⟦T7⟧");
+
+        let restored = restore_tags(
+            "⟦T1⟧안녕하세요 ⟦T2⟧⟦T3⟧⟦T4⟧님, 새 ⟦T6⟧ ⟦T5⟧개⟦T7⟧",
+            &masked.placeholders,
+        );
+        assert!(restored.is_intact());
+        assert_eq!(restored.text, "<p>안녕하세요 <strong>{name}</strong>님, 새 %1$s %d개</p>");
+    }
+
+    #[test]
+    fn test_restore_reports_lost_tokens() {
+        let masked = protect_tags("<em>a</em> {{b}}");
+        let restored = restore_tags("⟦T1⟧a {{b}}", &masked.placeholders);
+        assert!(!restored.is_intact());
+        assert_eq!(restored.missing_tokens, vec!["⟦T2⟧", "⟦T3⟧"]);
+    }
+}
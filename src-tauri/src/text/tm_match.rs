@@ -0,0 +1,292 @@
+//! TM Matching
+//!
+//! 원문을 TM 단위와 비교해 일치율(%)과 CAT 도구식 매치 구간(band)을 계산합니다 (분석/견적용).
+//! - 비교는 `normalize_for_matching` 기준 평문의 글자 단위 편집 거리로 합니다.
+//! - 완전히 같은 원문만 100%이고, 편집 거리가 있으면 반올림해도 99%를 넘지 않습니다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::lang::normalize_language;
+use crate::models::TmUnit;
+
+/// 이보다 긴 원문은 퍼지 비교를 건너뜀 (O(n·m) 계산 제한)
+const MAX_FUZZY_CHARS: usize = 1000;
+
+/// 퍼지 매치로 인정하는 최소 일치율
+pub const MIN_FUZZY_SCORE: u8 = 50;
+
+/// 매치 구간 (분석/견적 행 순서대로)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchBand {
+    /// 101%: 100% 매치이면서 앞 세그먼트도 100% 매치 (문맥까지 같은 것으로 봄)
+    Context,
+    /// 프로젝트 안에서 앞서 나온 원문과 같은 세그먼트
+    Repetition,
+    /// 100%
+    Exact,
+    /// 95–99%
+    Fuzzy95,
+    /// 85–94%
+    Fuzzy85,
+    /// 75–84%
+    Fuzzy75,
+    /// 50–74%
+    Fuzzy50,
+    NoMatch,
+}
+
+impl MatchBand {
+    pub const ALL: [MatchBand; 8] = [
+        MatchBand::Context,
+        MatchBand::Repetition,
+        MatchBand::Exact,
+        MatchBand::Fuzzy95,
+        MatchBand::Fuzzy85,
+        MatchBand::Fuzzy75,
+        MatchBand::Fuzzy50,
+        MatchBand::NoMatch,
+    ];
+
+    /// 일치율 → 구간 (문맥 매치/반복은 호출 측에서 판정)
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            100.. => Self::Exact,
+            95..=99 => Self::Fuzzy95,
+            85..=94 => Self::Fuzzy85,
+            75..=84 => Self::Fuzzy75,
+            50..=74 => Self::Fuzzy50,
+            _ => Self::NoMatch,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Context => "101%",
+            Self::Repetition => "Repetitions",
+            Self::Exact => "100%",
+            Self::Fuzzy95 => "95–99%",
+            Self::Fuzzy85 => "85–94%",
+            Self::Fuzzy75 => "75–84%",
+            Self::Fuzzy50 => "50–74%",
+            Self::NoMatch => "No match",
+        }
+    }
+}
+
+/// 가장 비슷한 TM 단위
+#[derive(Debug, Clone, Copy)]
+pub struct TmMatch<'a> {
+    pub score: u8,
+    pub unit: &'a TmUnit,
+}
+
+/// 두 글자열의 일치율 (0–100, 편집 거리가 `max_distance`를 넘으면 None)
+fn similarity(a: &[char], b: &[char], max_distance: usize) -> Option<u8> {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return Some(100);
+    }
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        let mut row_min = curr[0];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+            row_min = row_min.min(curr[j + 1]);
+        }
+        if row_min > max_distance {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let distance = prev[b.len()];
+    if distance > max_distance {
+        return None;
+    }
+    if distance == 0 {
+        return Some(100);
+    }
+    let score = ((1.0 - distance as f64 / longest as f64) * 100.0).round() as u8;
+    Some(score.min(99))
+}
+
+/// 일치율 계산에 허용되는 최대 편집 거리
+fn max_distance(len: usize, min_score: u8) -> usize {
+    len * (100 - min_score as usize) / 100
+}
+
+struct IndexedUnit<'a> {
+    unit: &'a TmUnit,
+    chars: Vec<char>,
+}
+
+/// 프로젝트 언어쌍에 맞는 TM 단위 색인 (분석 한 번에 하나 만들어 재사용)
+pub struct TmMatcher<'a> {
+    exact: HashMap<String, &'a TmUnit>,
+    fuzzy: Vec<IndexedUnit<'a>>,
+}
+
+impl<'a> TmMatcher<'a> {
+    /// 언어가 주어지면 언어쌍이 다른 단위는 제외
+    pub fn new(units: &'a [TmUnit], source_language: Option<&str>, target_language: Option<&str>) -> Self {
+        let language_matches = |wanted: Option<&str>, actual: &str| match wanted.and_then(normalize_language) {
+            Some(wanted) => normalize_language(actual).is_none_or(|actual| actual == wanted),
+            None => true,
+        };
+
+        let mut exact = HashMap::new();
+        let mut fuzzy = Vec::new();
+        for unit in units {
+            if !language_matches(source_language, &unit.source_lang)
+                || !language_matches(target_language, &unit.target_lang)
+            {
+                continue;
+            }
+            let normalized = unit.source.split_whitespace().collect::<Vec<_>>().join(" ");
+            if normalized.is_empty() {
+                continue;
+            }
+            let chars: Vec<char> = normalized.chars().collect();
+            if chars.len() <= MAX_FUZZY_CHARS {
+                fuzzy.push(IndexedUnit { unit, chars });
+            }
+            // 목록이 최근 수정 순이므로 먼저 나온 단위를 유지
+            exact.entry(normalized).or_insert(unit);
+        }
+        Self { exact, fuzzy }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_empty()
+    }
+
+    /// 정규화된 원문과 가장 비슷한 TM 단위 (`MIN_FUZZY_SCORE` 미만이면 None)
+    pub fn best_match(&self, normalized: &str) -> Option<TmMatch<'a>> {
+        if let Some(unit) = self.exact.get(normalized) {
+            return Some(TmMatch { score: 100, unit });
+        }
+        let chars: Vec<char> = normalized.chars().collect();
+        if chars.is_empty() || chars.len() > MAX_FUZZY_CHARS {
+            return None;
+        }
+
+        let mut best: Option<TmMatch<'a>> = None;
+        for indexed in &self.fuzzy {
+            let floor = best.map(|b| b.score + 1).unwrap_or(MIN_FUZZY_SCORE).min(99);
+            let longest = chars.len().max(indexed.chars.len());
+            let Some(score) = similarity(&chars, &indexed.chars, max_distance(longest, floor)) else {
+                continue;
+            };
+            if score >= floor {
+                best = Some(TmMatch { score, unit: indexed.unit });
+                if score == 99 {
+                    break;
+                }
+            }
+        }
+        best
+    }
+
+    /// 일치율 높은 순으로 최대 `limit`개 (번역이 같은 단위는 하나만, `MIN_FUZZY_SCORE` 이상)
+    pub fn top_matches(&self, normalized: &str, limit: usize) -> Vec<TmMatch<'a>> {
+        let mut matches: Vec<TmMatch<'a>> = Vec::new();
+        if let Some(unit) = self.exact.get(normalized) {
+            matches.push(TmMatch { score: 100, unit });
+        }
+        let chars: Vec<char> = normalized.chars().collect();
+        if !chars.is_empty() && chars.len() <= MAX_FUZZY_CHARS {
+            for indexed in &self.fuzzy {
+                let longest = chars.len().max(indexed.chars.len());
+                if let Some(score) = similarity(&chars, &indexed.chars, max_distance(longest, MIN_FUZZY_SCORE)) {
+                    if score >= MIN_FUZZY_SCORE {
+                        matches.push(TmMatch { score, unit: indexed.unit });
+                    }
+                }
+            }
+        }
+
+        // 점수가 같으면 목록 순서(최근 수정 순) 유지
+        matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        let mut seen = std::collections::HashSet::new();
+        matches.retain(|m| seen.insert(m.unit.target.trim()));
+        matches.truncate(limit);
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(source: &str, source_lang: &str) -> TmUnit {
+        TmUnit {
+            id: source.to_string(),
+            project_id: None,
+            source_lang: source_lang.to_string(),
+            target_lang: "ko".to_string(),
+            source: source.to_string(),
+            target: format!("{}-ko", source),
+            origin: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn scores_and_bands() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(similarity(&chars("kitten"), &chars("sitting"), 10), Some(57));
+        assert_eq!(similarity(&chars("abc"), &chars("abc"), 0), Some(100));
+        assert_eq!(similarity(&chars("abcdefghij"), &chars("xyz"), 3), None);
+        assert_eq!(MatchBand::from_score(100), MatchBand::Exact);
+        assert_eq!(MatchBand::from_score(95), MatchBand::Fuzzy95);
+        assert_eq!(MatchBand::from_score(49), MatchBand::NoMatch);
+    }
+
+    #[test]
+    fn finds_best_match_in_language_pair() {
+        let units = vec![
+            unit("Save the file before closing.", "en-US"),
+            unit("Save the file before closing the editor.", "en"),
+            unit("Save the file before closing!", "ja"),
+        ];
+        let matcher = TmMatcher::new(&units, Some("en"), Some("ko"));
+
+        let exact = matcher.best_match("Save the file before closing.").unwrap();
+        assert_eq!(exact.score, 100);
+
+        let fuzzy = matcher.best_match("Save the files before closing.").unwrap();
+        assert_eq!(fuzzy.unit.id, "Save the file before closing.");
+        assert_eq!(MatchBand::from_score(fuzzy.score), MatchBand::Fuzzy95);
+
+        assert!(matcher.best_match("Completely unrelated sentence").is_none());
+    }
+
+    #[test]
+    fn lists_top_matches_without_duplicate_targets() {
+        let mut units = vec![
+            unit("Save the file before closing.", "en"),
+            unit("Save the file before closing the editor.", "en"),
+            unit("Save the files before closing.", "en"),
+        ];
+        units[2].target = units[0].target.clone();
+        let matcher = TmMatcher::new(&units, None, None);
+
+        let matches = matcher.top_matches("Save the file before closing.", 5);
+        let scored: Vec<(&str, u8)> = matches.iter().map(|m| (m.unit.id.as_str(), m.score)).collect();
+        assert_eq!(scored.len(), 2);
+        assert_eq!(scored[0], ("Save the file before closing.", 100));
+        assert_eq!(scored[1].0, "Save the file before closing the editor.");
+        assert_eq!(matcher.top_matches("Save the file before closing.", 1).len(), 1);
+    }
+}
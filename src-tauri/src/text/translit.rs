@@ -0,0 +1,310 @@
+//! Transliteration
+//!
+//! 인명/용어를 라틴 문자로 일관되게 음역합니다. 해당 문자 체계가 아닌 글자는 그대로 둡니다.
+//! - 한글: 국어의 로마자 표기법(Revised Romanization), 연음/비음화/유음화/ㅎ 축약 반영
+//! - 가나: 헵번식 (장음은 모음을 반복, 조사 は/へ는 구분하지 않음)
+//! - 키릴 문자: BGN/PCGN 간소화 (러시아어 기준, 우크라이나/벨라루스 글자 일부 포함)
+
+use serde::Deserialize;
+
+/// 음역할 문자 체계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransliterationScheme {
+    Hangul,
+    Kana,
+    Cyrillic,
+}
+
+const HANGUL_BASE: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+
+const RR_INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+
+const RR_VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu",
+    "ui", "i",
+];
+
+/// 받침: (자음 앞/끝에서의 소리, 모음 앞에서 남는 소리, 모음 앞에서 다음 음절로 넘어가는 소리)
+const RR_FINALS: [(&str, &str, &str); 28] = [
+    ("", "", ""),
+    ("k", "", "g"),    // ㄱ
+    ("k", "", "kk"),   // ㄲ
+    ("k", "k", "s"),   // ㄳ
+    ("n", "", "n"),    // ㄴ
+    ("n", "n", "j"),   // ㄵ
+    ("n", "", "n"),    // ㄶ
+    ("t", "", "d"),    // ㄷ
+    ("l", "", "r"),    // ㄹ
+    ("k", "l", "g"),   // ㄺ
+    ("m", "l", "m"),   // ㄻ
+    ("l", "l", "b"),   // ㄼ
+    ("l", "l", "s"),   // ㄽ
+    ("l", "l", "t"),   // ㄾ
+    ("p", "l", "p"),   // ㄿ
+    ("l", "", "r"),    // ㅀ
+    ("m", "", "m"),    // ㅁ
+    ("p", "", "b"),    // ㅂ
+    ("p", "p", "s"),   // ㅄ
+    ("t", "", "s"),    // ㅅ
+    ("t", "", "ss"),   // ㅆ
+    ("ng", "ng", ""),  // ㅇ
+    ("t", "", "j"),    // ㅈ
+    ("t", "", "ch"),   // ㅊ
+    ("k", "", "k"),    // ㅋ
+    ("t", "", "t"),    // ㅌ
+    ("p", "", "p"),    // ㅍ
+    ("t", "", ""),     // ㅎ
+];
+
+// 초성/받침 인덱스
+const INITIAL_N: usize = 2;
+const INITIAL_D: usize = 3;
+const INITIAL_R: usize = 5;
+const INITIAL_M: usize = 6;
+const INITIAL_SILENT: usize = 11;
+const INITIAL_J: usize = 12;
+const INITIAL_G: usize = 0;
+const FINAL_N: usize = 4;
+const FINAL_H: usize = 27;
+
+/// 받침 소리와 다음 초성을 바꿀 소리 (다음 음절 초성에 따른 음운 변화)
+fn coda(final_index: usize, next_initial: Option<usize>) -> (&'static str, Option<&'static str>) {
+    let (plain, rest, moved) = RR_FINALS[final_index];
+    let nasal = |sound: &'static str| match sound {
+        "k" => "ng",
+        "t" => "n",
+        "p" => "m",
+        other => other,
+    };
+    match next_initial {
+        _ if final_index == 0 => ("", None),
+        Some(INITIAL_SILENT) => (rest, Some(moved)),
+        Some(INITIAL_R) if final_index == FINAL_N || plain == "l" => ("l", Some("l")),
+        Some(INITIAL_R) => (nasal(plain), Some("n")),
+        Some(INITIAL_N) if plain == "l" => ("l", Some("l")),
+        Some(INITIAL_N | INITIAL_M) => (nasal(plain), None),
+        Some(INITIAL_G) if final_index == FINAL_H => ("", Some("k")),
+        Some(INITIAL_D) if final_index == FINAL_H => ("", Some("t")),
+        Some(INITIAL_J) if final_index == FINAL_H => ("", Some("ch")),
+        _ => (plain, None),
+    }
+}
+
+/// 한글 음절 (초성, 중성, 종성 인덱스)
+fn decompose(c: char) -> Option<(usize, usize, usize)> {
+    let code = c as u32;
+    if !(HANGUL_BASE..=HANGUL_LAST).contains(&code) {
+        return None;
+    }
+    let index = (code - HANGUL_BASE) as usize;
+    Some((index / (21 * 28), (index % (21 * 28)) / 28, index % 28))
+}
+
+fn romanize_hangul(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut carried: Option<&str> = None;
+    for (i, &c) in chars.iter().enumerate() {
+        let Some((initial, vowel, final_index)) = decompose(c) else {
+            carried = None;
+            out.push(c);
+            continue;
+        };
+        out.push_str(carried.take().unwrap_or(RR_INITIALS[initial]));
+        out.push_str(RR_VOWELS[vowel]);
+        let next_initial = chars.get(i + 1).and_then(|&n| decompose(n)).map(|(n, _, _)| n);
+        let (sound, next) = coda(final_index, next_initial);
+        out.push_str(sound);
+        carried = next;
+    }
+    out
+}
+
+const KANA_DIGRAPHS: &[(&str, &str)] = &[
+    ("しぇ", "she"), ("じぇ", "je"), ("ちぇ", "che"), ("ふぁ", "fa"), ("ふぃ", "fi"), ("ふぇ", "fe"), ("ふぉ", "fo"),
+    ("てぃ", "ti"), ("でぃ", "di"), ("とぅ", "tu"), ("どぅ", "du"), ("うぃ", "wi"), ("うぇ", "we"), ("うぉ", "wo"),
+    ("ゔぁ", "va"), ("ゔぃ", "vi"), ("ゔぇ", "ve"), ("ゔぉ", "vo"), ("つぁ", "tsa"),
+];
+
+/// 요음 (き + ゃ → kya)
+const KANA_YOON: &[(char, &str)] = &[
+    ('き', "ky"), ('ぎ', "gy"), ('し', "sh"), ('じ', "j"), ('ち', "ch"), ('ぢ', "j"), ('に', "ny"),
+    ('ひ', "hy"), ('び', "by"), ('ぴ', "py"), ('み', "my"), ('り', "ry"),
+];
+
+const KANA: &[(char, &str)] = &[
+    ('あ', "a"), ('い', "i"), ('う', "u"), ('え', "e"), ('お', "o"),
+    ('か', "ka"), ('き', "ki"), ('く', "ku"), ('け', "ke"), ('こ', "ko"),
+    ('が', "ga"), ('ぎ', "gi"), ('ぐ', "gu"), ('げ', "ge"), ('ご', "go"),
+    ('さ', "sa"), ('し', "shi"), ('す', "su"), ('せ', "se"), ('そ', "so"),
+    ('ざ', "za"), ('じ', "ji"), ('ず', "zu"), ('ぜ', "ze"), ('ぞ', "zo"),
+    ('た', "ta"), ('ち', "chi"), ('つ', "tsu"), ('て', "te"), ('と', "to"),
+    ('だ', "da"), ('ぢ', "ji"), ('づ', "zu"), ('で', "de"), ('ど', "do"),
+    ('な', "na"), ('に', "ni"), ('ぬ', "nu"), ('ね', "ne"), ('の', "no"),
+    ('は', "ha"), ('ひ', "hi"), ('ふ', "fu"), ('へ', "he"), ('ほ', "ho"),
+    ('ば', "ba"), ('び', "bi"), ('ぶ', "bu"), ('べ', "be"), ('ぼ', "bo"),
+    ('ぱ', "pa"), ('ぴ', "pi"), ('ぷ', "pu"), ('ぺ', "pe"), ('ぽ', "po"),
+    ('ま', "ma"), ('み', "mi"), ('む', "mu"), ('め', "me"), ('も', "mo"),
+    ('や', "ya"), ('ゆ', "yu"), ('よ', "yo"),
+    ('ら', "ra"), ('り', "ri"), ('る', "ru"), ('れ', "re"), ('ろ', "ro"),
+    ('わ', "wa"), ('ゐ', "i"), ('ゑ', "e"), ('を', "o"), ('ゔ', "vu"),
+    ('ぁ', "a"), ('ぃ', "i"), ('ぅ', "u"), ('ぇ', "e"), ('ぉ', "o"),
+    ('ゃ', "ya"), ('ゅ', "yu"), ('ょ', "yo"), ('ゎ', "wa"),
+];
+
+/// 가타카나 → 히라가나 (ァ–ヶ)
+fn to_hiragana(c: char) -> char {
+    match c {
+        'ァ'..='ヶ' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// 가나 한 단위(요음/외래음 포함)를 읽고 (로마자, 읽은 글자 수)
+fn kana_unit(chars: &[char]) -> Option<(String, usize)> {
+    let first = *chars.first()?;
+    if let Some(&second) = chars.get(1) {
+        let pair: String = [first, second].iter().collect();
+        if let Some((_, romaji)) = KANA_DIGRAPHS.iter().find(|(kana, _)| *kana == pair) {
+            return Some((romaji.to_string(), 2));
+        }
+        let small = match second {
+            'ゃ' => Some("a"),
+            'ゅ' => Some("u"),
+            'ょ' => Some("o"),
+            _ => None,
+        };
+        if let (Some(vowel), Some((_, head))) = (small, KANA_YOON.iter().find(|(k, _)| *k == first)) {
+            return Some((format!("{}{}", head, vowel), 2));
+        }
+    }
+    KANA.iter()
+        .find(|(k, _)| *k == first)
+        .map(|(_, romaji)| (romaji.to_string(), 1))
+}
+
+fn romanize_kana(text: &str) -> String {
+    let original: Vec<char> = text.chars().collect();
+    let chars: Vec<char> = original.iter().map(|&c| to_hiragana(c)).collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            // 촉음: 다음 자음을 겹침 (ch 앞은 t)
+            'っ' => {
+                if let Some((next, _)) = kana_unit(&chars[i + 1..]) {
+                    match next.chars().next() {
+                        Some('c') => out.push('t'),
+                        Some(c) if !"aiueo".contains(c) => out.push(c),
+                        _ => {}
+                    }
+                }
+                i += 1;
+            }
+            'ん' => {
+                let next = kana_unit(&chars[i + 1..]).and_then(|(r, _)| r.chars().next());
+                out.push_str(if next.is_some_and(|c| "aiueoy".contains(c)) { "n'" } else { "n" });
+                i += 1;
+            }
+            // 장음 부호: 앞 모음 반복
+            'ー' => {
+                if let Some(vowel) = out.chars().last().filter(|c| "aiueo".contains(*c)) {
+                    out.push(vowel);
+                }
+                i += 1;
+            }
+            _ => match kana_unit(&chars[i..]) {
+                Some((romaji, len)) => {
+                    out.push_str(&romaji);
+                    i += len;
+                }
+                None => {
+                    out.push(original[i]);
+                    i += 1;
+                }
+            },
+        }
+    }
+    out
+}
+
+fn cyrillic_letter(c: char) -> Option<&'static str> {
+    Some(match c {
+        'а' => "a", 'б' => "b", 'в' => "v", 'г' => "g", 'д' => "d", 'е' => "e", 'ё' => "yo", 'ж' => "zh",
+        'з' => "z", 'и' => "i", 'й' => "y", 'к' => "k", 'л' => "l", 'м' => "m", 'н' => "n", 'о' => "o",
+        'п' => "p", 'р' => "r", 'с' => "s", 'т' => "t", 'у' => "u", 'ф' => "f", 'х' => "kh", 'ц' => "ts",
+        'ч' => "ch", 'ш' => "sh", 'щ' => "shch", 'ъ' => "", 'ы' => "y", 'ь' => "", 'э' => "e", 'ю' => "yu",
+        'я' => "ya", 'і' => "i", 'ї' => "yi", 'є' => "ye", 'ґ' => "g", 'ў' => "w",
+        _ => return None,
+    })
+}
+
+fn romanize_cyrillic(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        let Some(latin) = cyrillic_letter(lower) else {
+            out.push(c);
+            continue;
+        };
+        if lower == c {
+            out.push_str(latin);
+            continue;
+        }
+        // 대문자: 앞뒤도 대문자면 전부 대문자 ("ЩИ" → "SHCHI"), 아니면 첫 글자만 ("Щи" → "Shchi")
+        let upper_at = |k: Option<usize>| k.and_then(|k| chars.get(k)).is_some_and(|n| n.is_uppercase());
+        if upper_at(i.checked_sub(1)) || upper_at(Some(i + 1)) {
+            out.push_str(&latin.to_uppercase());
+        } else {
+            let mut letters = latin.chars();
+            if let Some(first) = letters.next() {
+                out.extend(first.to_uppercase());
+                out.push_str(letters.as_str());
+            }
+        }
+    }
+    out
+}
+
+/// 텍스트를 라틴 문자로 음역 (해당 문자 체계가 아닌 글자는 그대로)
+pub fn transliterate(text: &str, scheme: TransliterationScheme) -> String {
+    match scheme {
+        TransliterationScheme::Hangul => romanize_hangul(text),
+        TransliterationScheme::Kana => romanize_kana(text),
+        TransliterationScheme::Cyrillic => romanize_cyrillic(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hangul_revised_romanization() {
+        let rr = |text| transliterate(text, TransliterationScheme::Hangul);
+        assert_eq!(rr("한국어"), "hangugeo");
+        assert_eq!(rr("신라"), "silla");
+        assert_eq!(rr("종로 백마"), "jongno baengma");
+        assert_eq!(rr("좋다"), "jota");
+        assert_eq!(rr("설날, 부산"), "seollal, busan");
+    }
+
+    #[test]
+    fn test_kana_and_cyrillic() {
+        let romaji = |text| transliterate(text, TransliterationScheme::Kana);
+        assert_eq!(romaji("とうきょう"), "toukyou");
+        assert_eq!(romaji("キッチン"), "kitchin");
+        assert_eq!(romaji("ほっかいどう"), "hokkaidou");
+        assert_eq!(romaji("コーヒー"), "koohii");
+        assert_eq!(romaji("きんえん ファイル"), "kin'en fairu");
+
+        let latin = |text| transliterate(text, TransliterationScheme::Cyrillic);
+        assert_eq!(latin("Щукин"), "Shchukin");
+        assert_eq!(latin("ЖКХ и Юлия"), "ZHKKH i Yuliya");
+    }
+}
@@ -0,0 +1,136 @@
+//! Unicode Normalization
+//!
+//! 명령 경계에서 텍스트를 NFC로 맞춥니다.
+//! - macOS 파일 이름/클립보드에서 온 NFD 한글(자모 분리)이 섞이면 검색/치환/분할 결과가 어긋납니다.
+//! - 분할 위치는 프론트엔드(JS 문자열) 기준 UTF-16 오프셋으로 받고, 음절/결합 문자 중간이면 앞으로 당깁니다.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+/// NFC 정규화 (이미 NFC면 복사하지 않음)
+pub fn nfc(text: &str) -> Cow<'_, str> {
+    if is_nfc(text) {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.nfc().collect())
+    }
+}
+
+/// NFC 정규화 (소유 문자열)
+pub fn into_nfc(text: String) -> String {
+    match nfc(&text) {
+        Cow::Borrowed(_) => text,
+        Cow::Owned(normalized) => normalized,
+    }
+}
+
+/// serde 필드용: 역직렬화하면서 NFC로 (`#[serde(deserialize_with = "...")]`)
+pub fn deserialize_nfc<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    String::deserialize(deserializer).map(into_nfc)
+}
+
+/// 경로의 파일 이름 (NFC)
+pub fn file_name_nfc(path: &Path) -> Option<String> {
+    path.file_name().map(|n| into_nfc(n.to_string_lossy().into_owned()))
+}
+
+/// 앞 글자와 떨어지면 안 되는 글자 (결합 문자, 한글 중성/종성 자모)
+fn continues_previous(c: char) -> bool {
+    is_combining_mark(c) || matches!(c, '\u{1160}'..='\u{11FF}' | '\u{D7B0}'..='\u{D7FF}')
+}
+
+/// UTF-16 오프셋을 안전한 바이트 위치로 변환 (범위를 넘으면 끝)
+/// - 서로게이트 쌍, 결합 문자, 자모 조합 중간이면 그 글자 앞으로 당깁니다.
+pub fn split_index(text: &str, utf16_offset: usize) -> usize {
+    let mut units = 0;
+    let mut index = text.len();
+    for (i, c) in text.char_indices() {
+        if units + c.len_utf16() > utf16_offset {
+            index = i;
+            break;
+        }
+        units += c.len_utf16();
+    }
+    while index > 0 && text[index..].chars().next().is_some_and(continues_previous) {
+        index = text[..index].char_indices().next_back().map_or(0, |(i, _)| i);
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HANGUL_FIRST: u32 = 0xAC00;
+    const HANGUL_LAST: u32 = 0xD7A3;
+
+    /// 결정적 의사 난수 (테스트 입력 생성용 LCG)
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, bound: usize) -> usize {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((self.0 >> 33) as usize) % bound
+        }
+    }
+
+    /// NFC/NFD 한글, 결합 악센트, 서로게이트 쌍, ASCII가 섞인 문자열
+    fn mixed_text(rng: &mut Lcg) -> String {
+        let mut text = String::new();
+        for _ in 0..rng.next(12) + 1 {
+            let syllable = char::from_u32(HANGUL_FIRST + rng.next(11172) as u32).unwrap();
+            match rng.next(6) {
+                0 => text.push(syllable),
+                1 => text.extend(syllable.to_string().nfd()),
+                2 => text.push_str("e\u{301}"),
+                3 => text.push('😀'),
+                4 => text.push(' '),
+                _ => text.push((b'a' + rng.next(26) as u8) as char),
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn test_every_hangul_syllable_round_trips() {
+        for code in HANGUL_FIRST..=HANGUL_LAST {
+            let syllable = char::from_u32(code).unwrap().to_string();
+            let decomposed: String = syllable.nfd().collect();
+            assert_eq!(into_nfc(decomposed.clone()), syllable);
+            // 분해된 채로 들어와도 음절 중간에서 끊지 않음
+            for offset in 1..decomposed.chars().count() {
+                assert_eq!(split_index(&decomposed, offset), 0, "{:?} @ {}", decomposed, offset);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_and_search_on_mixed_input() {
+        let mut rng = Lcg(0x5eed);
+        for _ in 0..2_000 {
+            let raw = mixed_text(&mut rng);
+            let text = nfc(&raw).into_owned();
+            assert!(is_nfc(&text));
+            assert_eq!(into_nfc(text.nfd().collect()), text);
+
+            let utf16_len = text.encode_utf16().count();
+            for offset in 0..=utf16_len + 1 {
+                let index = split_index(&text, offset);
+                assert!(text.is_char_boundary(index));
+                let (head, tail) = text.split_at(index);
+                // 나눈 양쪽이 그대로 NFC (음절/결합 문자가 깨지지 않음)
+                assert_eq!(nfc(head), head, "{:?} @ {}", text, offset);
+                assert_eq!(nfc(tail), tail, "{:?} @ {}", text, offset);
+            }
+
+            // 분해형으로 입력한 검색어도 정규화하면 찾음
+            let needle: String = text.chars().skip(rng.next(text.chars().count())).take(3).collect();
+            let query: String = needle.nfd().collect();
+            assert!(text.contains(nfc(&query).as_ref()), "{:?} in {:?}", query, text);
+        }
+    }
+}
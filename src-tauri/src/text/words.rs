@@ -0,0 +1,183 @@
+//! Word Counting
+//!
+//! 블록 텍스트의 단어 수 계산 (CAT 도구 방식)
+//! - 공백으로 구분되는 언어(영어, 한국어 어절 등)는 공백 단위로 셉니다.
+//! - 공백 없이 쓰는 한자/가나는 글자 수를 언어별 계수로 나눠 단어 수로 환산합니다.
+//! - 태그는 세지 않고, 문장 부호만 있는 토큰도 단어로 치지 않습니다.
+
+use serde::Serialize;
+
+use super::lang::{detect_language, normalize_language};
+use crate::models::IteProject;
+
+/// 중국어 글자 → 단어 환산 계수
+pub const ZH_CJK_FACTOR: f64 = 1.7;
+/// 일본어 글자 → 단어 환산 계수
+pub const JA_CJK_FACTOR: f64 = 2.5;
+
+fn is_cjk_ideographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' | '\u{3040}'..='\u{30FF}' | '\u{F900}'..='\u{FAFF}'
+    )
+}
+
+/// 단어/글자 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WordCount {
+    /// 청구/통계용 단어 수 (공백 구분 단어 + 환산한 CJK 글자)
+    pub words: usize,
+    /// 공백으로 구분된 단어 수
+    pub spaced_words: usize,
+    /// 한자/가나 글자 수
+    pub cjk_characters: usize,
+    /// 공백 제외 글자 수
+    pub characters: usize,
+    /// 공백 포함 글자 수
+    pub characters_with_spaces: usize,
+}
+
+impl WordCount {
+    /// 합산 (CJK 환산은 항목별로 이미 반영된 값을 더함)
+    pub fn add(&mut self, other: &WordCount) {
+        self.words += other.words;
+        self.spaced_words += other.spaced_words;
+        self.cjk_characters += other.cjk_characters;
+        self.characters += other.characters;
+        self.characters_with_spaces += other.characters_with_spaces;
+    }
+}
+
+/// 언어별 CJK 환산 계수 (중국어/일본어 외에는 글자마다 한 단어)
+pub fn cjk_factor(language: Option<&str>) -> f64 {
+    match language.and_then(normalize_language) {
+        Some("zh") => ZH_CJK_FACTOR,
+        Some("ja") => JA_CJK_FACTOR,
+        _ => 1.0,
+    }
+}
+
+/// 평문의 단어/글자 수 (CJK 글자 수 ÷ `cjk_factor`, 반올림)
+pub fn count_text(text: &str, cjk_factor: f64) -> WordCount {
+    let mut count = WordCount {
+        characters_with_spaces: text.chars().count(),
+        ..Default::default()
+    };
+    for token in text.split_whitespace() {
+        let mut in_word = false;
+        for c in token.chars() {
+            count.characters += 1;
+            if is_cjk_ideographic(c) {
+                count.cjk_characters += 1;
+                in_word = false;
+            } else if c.is_alphanumeric() && !in_word {
+                count.spaced_words += 1;
+                in_word = true;
+            }
+        }
+    }
+    let factor = if cjk_factor > 0.0 { cjk_factor } else { 1.0 };
+    count.words = count.spaced_words + (count.cjk_characters as f64 / factor).round() as usize;
+    count
+}
+
+/// 평문의 단어 수 (CJK 글자마다 한 단어)
+pub fn count_words(text: &str) -> usize {
+    count_text(text, 1.0).words
+}
+
+/// 블록 HTML의 단어 수 (태그 제외)
+pub fn count_html_words(html: &str) -> usize {
+    count_words(&super::strip_html(html))
+}
+
+/// 프로젝트 원문/번역문 단어 통계
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectWordStats {
+    /// 원문 언어 (감지 결과, 불확실하면 None)
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub segments: usize,
+    /// 번역문이 비어 있지 않은 세그먼트 수
+    pub translated_segments: usize,
+    pub source: WordCount,
+    pub target: WordCount,
+}
+
+fn blocks_text(project: &IteProject, ids: &[String]) -> String {
+    ids.iter()
+        .filter_map(|id| project.blocks.get(id))
+        .map(|b| super::strip_html(&b.content))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 원문 언어 결정 (주어지면 정규화, 없으면 원문 앞부분에서 감지하고 불확실하면 None)
+pub fn resolve_source_language<'a>(
+    source_language: Option<&str>,
+    source_texts: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    match source_language {
+        Some(lang) => normalize_language(lang).map(str::to_string),
+        None => {
+            let sample: String = source_texts.into_iter().collect::<Vec<_>>().join("\n");
+            let detection = detect_language(&sample.chars().take(5_000).collect::<String>());
+            detection.code.filter(|_| detection.reliable)
+        }
+    }
+}
+
+/// 프로젝트 단어 통계 (원문 언어는 `source_language`가 없으면 원문에서 감지)
+pub fn project_word_stats(project: &IteProject, source_language: Option<&str>) -> ProjectWordStats {
+    let mut segments: Vec<_> = project.segments.iter().collect();
+    segments.sort_by_key(|s| s.order);
+    let texts: Vec<(String, String)> = segments
+        .iter()
+        .map(|s| (blocks_text(project, &s.source_ids), blocks_text(project, &s.target_ids)))
+        .collect();
+
+    let source_language = resolve_source_language(source_language, texts.iter().map(|(s, _)| s.as_str()));
+    let target_language = project.metadata.target_language.as_deref().and_then(normalize_language).map(str::to_string);
+    let (source_factor, target_factor) = (cjk_factor(source_language.as_deref()), cjk_factor(target_language.as_deref()));
+
+    let mut stats = ProjectWordStats {
+        source_language,
+        target_language,
+        segments: texts.len(),
+        translated_segments: 0,
+        source: WordCount::default(),
+        target: WordCount::default(),
+    };
+    for (source, target) in texts {
+        stats.source.add(&count_text(&source, source_factor));
+        stats.target.add(&count_text(&target, target_factor));
+        if !target.trim().is_empty() {
+            stats.translated_segments += 1;
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_words() {
+        assert_eq!(count_words("Hello, world!"), 2);
+        assert_eq!(count_words("번역 편집기를 사용합니다."), 3);
+        assert_eq!(count_words("翻訳エディタ"), 6);
+        assert_eq!(count_html_words("<p>Open <strong>API</strong> docs</p>"), 3);
+    }
+
+    #[test]
+    fn applies_cjk_factor_and_skips_punctuation() {
+        let count = count_text("翻訳エディタ v2 — OK", cjk_factor(Some("ja-JP")));
+        assert_eq!(count.cjk_characters, 6);
+        assert_eq!(count.spaced_words, 2);
+        assert_eq!(count.words, 2 + 2);
+        assert_eq!(count.characters, 11);
+    }
+}
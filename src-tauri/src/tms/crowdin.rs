@@ -0,0 +1,264 @@
+//! Crowdin API v2
+//!
+//! - 작업 목록: `GET /user/tasks` (토큰 사용자에게 배정된 작업) 또는 `GET /projects/{id}/tasks`
+//! - 받기: 작업의 파일별 `GET /projects/{id}/strings` + `GET /projects/{id}/languages/{lang}/translations`
+//! - 올리기: 바뀐 문자열마다 `POST /projects/{id}/translations`
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use super::{error_from_response, PulledJob, PushItem, PushOutcome, RemoteSegment, TmsCredentials, TmsJob, TmsProvider};
+use crate::http::RetryPolicy;
+
+const CROWDIN_API_BASE: &str = "https://api.crowdin.com/api/v2";
+const PAGE_LIMIT: usize = 500;
+/// 목록 최대 페이지 수 (500 × 200 = 100,000개)
+const MAX_PAGES: usize = 200;
+
+/// Crowdin 목록 응답 (`{"data": [{"data": {...}}], "pagination": ...}`)
+#[derive(Debug, Deserialize)]
+struct ListResponse<T> {
+    data: Vec<Item<T>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Item<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CrowdinTask {
+    id: u64,
+    project_id: u64,
+    title: String,
+    #[serde(default)]
+    status: Option<String>,
+    /// 최근 API는 targetLanguageId, 이전 API는 languageId
+    #[serde(default)]
+    target_language_id: Option<String>,
+    #[serde(default)]
+    language_id: Option<String>,
+    #[serde(default)]
+    file_ids: Vec<u64>,
+    #[serde(default)]
+    deadline: Option<String>,
+}
+
+impl CrowdinTask {
+    fn target_lang(&self) -> String {
+        self.target_language_id
+            .clone()
+            .or_else(|| self.language_id.clone())
+            .unwrap_or_default()
+    }
+
+    fn into_job(self) -> TmsJob {
+        let target_lang = self.target_lang();
+        TmsJob {
+            provider: TmsProvider::Crowdin,
+            remote_project: self.project_id.to_string(),
+            id: self.id.to_string(),
+            name: self.title,
+            source_lang: None,
+            target_lang,
+            status: self.status,
+            due_date: self.deadline,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CrowdinProject {
+    source_language_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CrowdinFile {
+    #[serde(default)]
+    path: Option<String>,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CrowdinString {
+    id: u64,
+    /// 복수형 문자열은 객체라서 문자열일 때만 사용
+    text: serde_json::Value,
+    #[serde(default)]
+    is_hidden: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CrowdinTranslation {
+    string_id: u64,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+struct Api<'a> {
+    client: reqwest::Client,
+    base: String,
+    credentials: &'a TmsCredentials,
+}
+
+impl<'a> Api<'a> {
+    fn new(credentials: &'a TmsCredentials) -> Result<Self, String> {
+        Ok(Self {
+            client: crate::http::client()?,
+            base: credentials.base_url(CROWDIN_API_BASE),
+            credentials,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        self.client
+            .request(method, format!("{}{}", self.base, path))
+            .header("Authorization", format!("Bearer {}", self.credentials.token.trim()))
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        let request = self
+            .request(reqwest::Method::GET, path);
+        let response = crate::http::send_with_retry(request, RetryPolicy::TMS)
+            .await
+            .map_err(|e| format!("Crowdin request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(error_from_response("Crowdin", response).await);
+        }
+        let item: Item<T> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Crowdin response: {}", e))?;
+        Ok(item.data)
+    }
+
+    /// offset/limit 페이지를 끝까지 읽기
+    async fn list<T: DeserializeOwned>(&self, path: &str, query: &[(&str, String)]) -> Result<Vec<T>, String> {
+        let mut out = Vec::new();
+        for page in 0..MAX_PAGES {
+            let request = self
+                .request(reqwest::Method::GET, path)
+                .query(query)
+                .query(&[("limit", PAGE_LIMIT), ("offset", page * PAGE_LIMIT)]);
+            let response = crate::http::send_with_retry(request, RetryPolicy::TMS)
+                .await
+                .map_err(|e| format!("Crowdin request failed: {}", e))?;
+            if !response.status().is_success() {
+                return Err(error_from_response("Crowdin", response).await);
+            }
+            let list: ListResponse<T> = response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse Crowdin response: {}", e))?;
+            let count = list.data.len();
+            out.extend(list.data.into_iter().map(|i| i.data));
+            if count < PAGE_LIMIT {
+                break;
+            }
+        }
+        Ok(out)
+    }
+}
+
+pub async fn list_jobs(credentials: &TmsCredentials, remote_project: Option<&str>) -> Result<Vec<TmsJob>, String> {
+    let api = Api::new(credentials)?;
+    let path = match remote_project {
+        Some(project) => format!("/projects/{}/tasks", project),
+        None => "/user/tasks".to_string(),
+    };
+    let tasks: Vec<CrowdinTask> = api.list(&path, &[]).await?;
+    Ok(tasks.into_iter().map(CrowdinTask::into_job).collect())
+}
+
+pub async fn pull_job(credentials: &TmsCredentials, remote_project: &str, task_id: &str) -> Result<PulledJob, String> {
+    let api = Api::new(credentials)?;
+    let task: CrowdinTask = api.get(&format!("/projects/{}/tasks/{}", remote_project, task_id)).await?;
+    let project: CrowdinProject = api.get(&format!("/projects/{}", remote_project)).await?;
+
+    let target_lang = task.target_lang();
+    if target_lang.is_empty() {
+        return Err(format!("Crowdin task {} has no target language", task_id));
+    }
+
+    let mut segments = Vec::new();
+    for file_id in &task.file_ids {
+        let file: CrowdinFile = api.get(&format!("/projects/{}/files/{}", remote_project, file_id)).await?;
+        let file_name = file.path.unwrap_or(file.name);
+        let query = [("fileId", file_id.to_string())];
+        let strings: Vec<CrowdinString> = api
+            .list(&format!("/projects/{}/strings", remote_project), &query)
+            .await?;
+        let translations: Vec<CrowdinTranslation> = api
+            .list(
+                &format!("/projects/{}/languages/{}/translations", remote_project, target_lang),
+                &query,
+            )
+            .await?;
+        let mut translated: HashMap<u64, String> = translations
+            .into_iter()
+            .filter_map(|t| Some((t.string_id, t.text?)))
+            .collect();
+
+        for s in strings.into_iter().filter(|s| !s.is_hidden) {
+            let Some(source) = s.text.as_str().filter(|t| !t.trim().is_empty()) else {
+                continue;
+            };
+            segments.push(RemoteSegment {
+                remote_id: s.id.to_string(),
+                file: file_name.clone(),
+                source: source.to_string(),
+                target: translated.remove(&s.id).unwrap_or_default(),
+            });
+        }
+    }
+
+    let mut job = task.into_job();
+    job.source_lang = Some(project.source_language_id);
+    Ok(PulledJob {
+        job,
+        segments,
+        document: None,
+    })
+}
+
+pub async fn push_job(
+    credentials: &TmsCredentials,
+    remote_project: &str,
+    target_lang: &str,
+    items: &[PushItem],
+) -> Result<PushOutcome, String> {
+    let api = Api::new(credentials)?;
+    let path = format!("/projects/{}/translations", remote_project);
+    let mut outcome = PushOutcome::default();
+    for item in items {
+        let Ok(string_id) = item.remote_id.parse::<u64>() else {
+            outcome.errors.push(format!("{}: invalid string id", item.remote_id));
+            continue;
+        };
+        let result = api
+            .request(reqwest::Method::POST, &path)
+            .json(&serde_json::json!({
+                "stringId": string_id,
+                "languageId": target_lang,
+                "text": item.target,
+            }))
+            .send()
+            .await;
+        match result {
+            Ok(response) if response.status().is_success() => outcome.pushed.push(item.remote_id.clone()),
+            Ok(response) => {
+                let message = error_from_response("Crowdin", response).await;
+                outcome.errors.push(format!("{}: {}", item.remote_id, message));
+            }
+            Err(e) => outcome.errors.push(format!("{}: {}", item.remote_id, e)),
+        }
+    }
+    Ok(outcome)
+}
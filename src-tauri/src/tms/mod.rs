@@ -0,0 +1,297 @@
+//! TMS Connector
+//!
+//! Phrase TMS / Crowdin REST API로 배정된 작업을 받아 ITE 프로젝트로 만들고,
+//! 완료한 번역을 다시 올립니다. API 토큰은 SecretManager vault에 저장됩니다.
+//!
+//! - Phrase: 이중 언어 파일(MXLIFF)을 내려받고, 번역문을 채워 다시 올림
+//! - Crowdin: 작업(task)의 파일별 원문/번역 문자열을 받고, 바뀐 번역만 문자열 단위로 올림
+
+pub mod crowdin;
+pub mod phrase;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::TmsSegmentLink;
+use crate::interop::{file_tag, ProjectBuilder};
+use crate::models::IteProject;
+use crate::secrets::SECRETS;
+
+/// 지원하는 TMS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TmsProvider {
+    Phrase,
+    Crowdin,
+}
+
+impl TmsProvider {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "phrase" => Some(Self::Phrase),
+            "crowdin" => Some(Self::Crowdin),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Phrase => "phrase",
+            Self::Crowdin => "crowdin",
+        }
+    }
+
+    fn display_name(&self) -> &'static str {
+        match self {
+            Self::Phrase => "Phrase TMS",
+            Self::Crowdin => "Crowdin",
+        }
+    }
+
+    fn vault_key(&self) -> String {
+        format!("tms/{}/credentials_json", self.as_str())
+    }
+}
+
+/// TMS 접속 정보 (vault에 JSON으로 저장)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsCredentials {
+    pub token: String,
+    /// API 주소 (Phrase US 데이터센터, Crowdin Enterprise 등), 없으면 기본값
+    #[serde(default)]
+    pub base_url: Option<String>,
+}
+
+impl TmsCredentials {
+    fn base_url(&self, default: &str) -> String {
+        self.base_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .unwrap_or(default)
+            .trim_end_matches('/')
+            .to_string()
+    }
+}
+
+/// 원격 작업 (Phrase job / Crowdin task)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmsJob {
+    pub provider: TmsProvider,
+    pub remote_project: String,
+    pub id: String,
+    pub name: String,
+    pub source_lang: Option<String>,
+    pub target_lang: String,
+    pub status: Option<String>,
+    pub due_date: Option<String>,
+}
+
+/// 원격 세그먼트
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSegment {
+    /// Phrase trans-unit id / Crowdin string id
+    pub remote_id: String,
+    /// 파일 이름 (세그먼트 태그로 사용)
+    pub file: String,
+    pub source: String,
+    pub target: String,
+}
+
+/// 받아 온 작업
+#[derive(Debug, Clone)]
+pub struct PulledJob {
+    pub job: TmsJob,
+    pub segments: Vec<RemoteSegment>,
+    /// Phrase MXLIFF 원본 (올릴 때 번역문을 채워 넣음)
+    pub document: Option<String>,
+}
+
+/// 올릴 번역문
+#[derive(Debug, Clone)]
+pub struct PushItem {
+    pub remote_id: String,
+    pub target: String,
+}
+
+/// 올리기 결과
+#[derive(Debug, Clone, Default)]
+pub struct PushOutcome {
+    /// 올라간 원격 세그먼트 id
+    pub pushed: Vec<String>,
+    /// 실패한 세그먼트별 오류 (Crowdin은 문자열 단위로 올리므로 일부만 실패할 수 있음)
+    pub errors: Vec<String>,
+    /// 번역문을 채운 Phrase MXLIFF
+    pub document: Option<String>,
+}
+
+pub async fn save_credentials(provider: TmsProvider, credentials: &TmsCredentials) -> Result<(), String> {
+    if credentials.token.trim().is_empty() {
+        return Err("Token cannot be empty".to_string());
+    }
+    let json = serde_json::to_string(credentials).map_err(|e| format!("Failed to serialize credentials: {}", e))?;
+    SECRETS
+        .set(&provider.vault_key(), &json)
+        .await
+        .map_err(|e| format!("Failed to save credentials to vault: {}", e))?;
+    println!("[TMS] Credentials saved for {}", provider.as_str());
+    Ok(())
+}
+
+pub async fn load_credentials(provider: TmsProvider) -> Result<Option<TmsCredentials>, String> {
+    match SECRETS.get(&provider.vault_key()).await {
+        Ok(Some(json)) => serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("Failed to parse credentials: {}", e)),
+        Ok(None) => Ok(None),
+        Err(e) => Err(format!("Failed to load credentials: {}", e)),
+    }
+}
+
+pub async fn clear_credentials(provider: TmsProvider) -> Result<(), String> {
+    SECRETS
+        .delete(&provider.vault_key())
+        .await
+        .map_err(|e| format!("Failed to delete credentials: {}", e))?;
+    println!("[TMS] Credentials cleared for {}", provider.as_str());
+    Ok(())
+}
+
+async fn require_credentials(provider: TmsProvider) -> Result<TmsCredentials, String> {
+    load_credentials(provider)
+        .await?
+        .ok_or_else(|| format!("No {} token. Please set your API token first.", provider.display_name()))
+}
+
+/// 배정된 작업 목록
+/// - Phrase: 프로젝트 UID 필수 (토큰 사용자에게 보이는 작업)
+/// - Crowdin: 프로젝트 ID가 없으면 토큰 사용자에게 배정된 전체 작업
+pub async fn list_jobs(provider: TmsProvider, remote_project: Option<&str>) -> Result<Vec<TmsJob>, String> {
+    let credentials = require_credentials(provider).await?;
+    match provider {
+        TmsProvider::Phrase => {
+            let project = remote_project.ok_or("Phrase project UID is required")?;
+            phrase::list_jobs(&credentials, project).await
+        }
+        TmsProvider::Crowdin => crowdin::list_jobs(&credentials, remote_project).await,
+    }
+}
+
+/// 작업 받기
+pub async fn pull_job(provider: TmsProvider, remote_project: &str, job_id: &str) -> Result<PulledJob, String> {
+    let credentials = require_credentials(provider).await?;
+    match provider {
+        TmsProvider::Phrase => phrase::pull_job(&credentials, remote_project, job_id).await,
+        TmsProvider::Crowdin => crowdin::pull_job(&credentials, remote_project, job_id).await,
+    }
+}
+
+/// 번역문 올리기
+pub async fn push_job(
+    provider: TmsProvider,
+    remote_project: &str,
+    job_id: &str,
+    target_lang: &str,
+    document: Option<&str>,
+    items: &[PushItem],
+) -> Result<PushOutcome, String> {
+    let credentials = require_credentials(provider).await?;
+    let outcome = match provider {
+        TmsProvider::Phrase => {
+            let document = document.ok_or("Bilingual file is missing. Pull the job again.")?;
+            phrase::push_job(&credentials, document, items).await?
+        }
+        TmsProvider::Crowdin => crowdin::push_job(&credentials, remote_project, target_lang, items).await?,
+    };
+    println!("[TMS] Pushed {} segments for job {}", outcome.pushed.len(), job_id);
+    Ok(outcome)
+}
+
+/// 받아 온 작업 → ITE 프로젝트
+/// - 반환: (프로젝트, 세그먼트 연결 정보)
+pub fn build_project(pulled: &PulledJob, now: i64) -> (IteProject, Vec<TmsSegmentLink>) {
+    let mut builder = ProjectBuilder::new(now);
+    let mut links = Vec::with_capacity(pulled.segments.len());
+    for segment in &pulled.segments {
+        let segment_id = builder.push(&segment.source, &segment.target, &file_tag(&segment.file));
+        links.push(TmsSegmentLink {
+            segment_id,
+            remote_id: segment.remote_id.clone(),
+            base_target: segment.target.trim().to_string(),
+        });
+    }
+
+    let job = &pulled.job;
+    let description = match &job.source_lang {
+        Some(source) => format!("Pulled from {} ({} → {})", job.provider.display_name(), source, job.target_lang),
+        None => format!("Pulled from {} (→ {})", job.provider.display_name(), job.target_lang),
+    };
+    let project = builder.finish(
+        job.name.clone(),
+        description,
+        job.source_lang.as_deref(),
+        Some(&job.target_lang),
+    );
+    (project, links)
+}
+
+/// 오류 응답 본문을 잘라 메시지로
+async fn error_from_response(service: &str, response: reqwest::Response) -> String {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let body: String = body.chars().take(500).collect();
+    format!("{} API error ({}): {}", service, status, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_project_links_segments() {
+        let pulled = PulledJob {
+            job: TmsJob {
+                provider: TmsProvider::Crowdin,
+                remote_project: "12".to_string(),
+                id: "7".to_string(),
+                name: "Release notes".to_string(),
+                source_lang: Some("en".to_string()),
+                target_lang: "ko".to_string(),
+                status: None,
+                due_date: None,
+            },
+            segments: vec![
+                RemoteSegment {
+                    remote_id: "100".to_string(),
+                    file: "/docs/notes.md".to_string(),
+                    source: "Hello".to_string(),
+                    target: "안녕하세요 ".to_string(),
+                },
+                RemoteSegment {
+                    remote_id: "101".to_string(),
+                    file: "/docs/notes.md".to_string(),
+                    source: "Bye".to_string(),
+                    target: String::new(),
+                },
+            ],
+            document: None,
+        };
+
+        let (project, links) = build_project(&pulled, 1);
+        assert_eq!(project.metadata.title, "Release notes");
+        assert_eq!(project.segments.len(), 2);
+        assert_eq!(links[0].segment_id, project.segments[0].group_id);
+        assert_eq!(links[0].remote_id, "100");
+        assert_eq!(links[0].base_target, "안녕하세요");
+        let source = &project.blocks[&project.segments[0].source_ids[0]];
+        assert_eq!(source.metadata.tags, vec!["notes.md".to_string()]);
+    }
+
+    #[test]
+    fn test_provider_parse() {
+        assert_eq!(TmsProvider::parse(" Phrase "), Some(TmsProvider::Phrase));
+        assert_eq!(TmsProvider::parse("crowdin"), Some(TmsProvider::Crowdin));
+        assert_eq!(TmsProvider::parse("smartling"), None);
+    }
+}
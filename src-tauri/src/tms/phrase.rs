@@ -0,0 +1,357 @@
+//! Phrase TMS (Memsource) API
+//!
+//! - 작업 목록: `GET /api2/v2/projects/{projectUid}/jobs`
+//! - 받기: `POST /api2/v1/projects/{projectUid}/jobs/bilingualFile?format=MXLF` (MXLIFF)
+//! - 올리기: `PUT /api2/v1/bilingualFiles` (번역문을 채운 MXLIFF)
+//!
+//! MXLIFF는 XLIFF 1.2 형식이며 인라인 태그는 `{1>`, `<1}` 같은 평문 표기로 들어 있어
+//! trans-unit의 `<source>`/`<target>` 텍스트를 그대로 세그먼트로 씁니다.
+
+use std::collections::HashMap;
+
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::reader::Reader;
+use quick_xml::Writer;
+use serde::Deserialize;
+
+use super::{
+    error_from_response, PulledJob, PushItem, PushOutcome, RemoteSegment, TmsCredentials, TmsJob, TmsProvider,
+};
+use crate::http::RetryPolicy;
+use crate::text::encoding::decode_bytes;
+
+const PHRASE_API_BASE: &str = "https://cloud.memsource.com/web";
+const JOBS_PAGE_SIZE: u32 = 50;
+/// 작업 목록 최대 페이지 수 (50 × 20 = 1000개)
+const MAX_JOB_PAGES: u32 = 20;
+
+/// 읽어 온 MXLIFF
+#[derive(Debug, Clone, Default)]
+pub struct MxliffFile {
+    pub source_lang: Option<String>,
+    pub segments: Vec<RemoteSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JobPage {
+    #[serde(default)]
+    content: Vec<PhraseJob>,
+    #[serde(default)]
+    total_pages: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PhraseJob {
+    uid: String,
+    #[serde(default)]
+    filename: Option<String>,
+    target_lang: String,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    date_due: Option<String>,
+}
+
+impl PhraseJob {
+    fn into_job(self, remote_project: &str) -> TmsJob {
+        TmsJob {
+            provider: TmsProvider::Phrase,
+            remote_project: remote_project.to_string(),
+            name: self.filename.clone().unwrap_or_else(|| self.uid.clone()),
+            id: self.uid,
+            source_lang: None,
+            target_lang: self.target_lang,
+            status: self.status,
+            due_date: self.date_due,
+        }
+    }
+}
+
+fn auth_header(credentials: &TmsCredentials) -> String {
+    format!("ApiToken {}", credentials.token.trim())
+}
+
+pub async fn list_jobs(credentials: &TmsCredentials, remote_project: &str) -> Result<Vec<TmsJob>, String> {
+    let base = credentials.base_url(PHRASE_API_BASE);
+    let client = crate::http::client()?;
+    let mut jobs = Vec::new();
+    for page_number in 0..MAX_JOB_PAGES {
+        let url = format!("{}/api2/v2/projects/{}/jobs", base, remote_project);
+        let request = client
+            .get(&url)
+            .header("Authorization", auth_header(credentials))
+            .query(&[("pageNumber", page_number), ("pageSize", JOBS_PAGE_SIZE)]);
+        let response = crate::http::send_with_retry(request, RetryPolicy::TMS)
+            .await
+            .map_err(|e| format!("Phrase request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(error_from_response("Phrase", response).await);
+        }
+        let page: JobPage = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Phrase jobs: {}", e))?;
+        jobs.extend(page.content.into_iter().map(|j| j.into_job(remote_project)));
+        if page_number + 1 >= page.total_pages {
+            break;
+        }
+    }
+    Ok(jobs)
+}
+
+pub async fn pull_job(credentials: &TmsCredentials, remote_project: &str, job_uid: &str) -> Result<PulledJob, String> {
+    let base = credentials.base_url(PHRASE_API_BASE);
+    let client = crate::http::client()?;
+
+    let request = client
+        .get(format!("{}/api2/v1/projects/{}/jobs/{}", base, remote_project, job_uid))
+        .header("Authorization", auth_header(credentials));
+    let response = crate::http::send_with_retry(request, RetryPolicy::TMS)
+        .await
+        .map_err(|e| format!("Phrase request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Phrase", response).await);
+    }
+    let job: PhraseJob = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Phrase job: {}", e))?;
+
+    let response = client
+        .post(format!("{}/api2/v1/projects/{}/jobs/bilingualFile", base, remote_project))
+        .header("Authorization", auth_header(credentials))
+        .query(&[("format", "MXLF")])
+        .json(&serde_json::json!({ "jobs": [{ "uid": job_uid }] }))
+        .send()
+        .await
+        .map_err(|e| format!("Phrase request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Phrase", response).await);
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to download bilingual file: {}", e))?;
+    let document = decode_bytes(&bytes).text;
+
+    let parts = parse_mxliff(&document)?;
+    let mut job = job.into_job(remote_project);
+    job.source_lang = parts.source_lang;
+    Ok(PulledJob {
+        job,
+        segments: parts.segments,
+        document: Some(document),
+    })
+}
+
+pub async fn push_job(credentials: &TmsCredentials, document: &str, items: &[PushItem]) -> Result<PushOutcome, String> {
+    let targets: HashMap<&str, &str> = items
+        .iter()
+        .map(|i| (i.remote_id.as_str(), i.target.as_str()))
+        .collect();
+    let updated = apply_mxliff_targets(document, &targets)?;
+
+    let response = crate::http::client()?
+        .put(format!("{}/api2/v1/bilingualFiles", credentials.base_url(PHRASE_API_BASE)))
+        .header("Authorization", auth_header(credentials))
+        .header("Content-Type", "application/octet-stream")
+        .header("Content-Disposition", "filename*=UTF-8''job.mxliff")
+        .body(updated.clone())
+        .send()
+        .await
+        .map_err(|e| format!("Phrase request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(error_from_response("Phrase", response).await);
+    }
+
+    Ok(PushOutcome {
+        pushed: items.iter().map(|i| i.remote_id.clone()).collect(),
+        errors: Vec::new(),
+        document: Some(updated),
+    })
+}
+
+fn xml_error(e: impl std::fmt::Display) -> String {
+    format!("Invalid MXLIFF: {}", e)
+}
+
+fn attr(e: &BytesStart, name: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == name)
+        .and_then(|a| a.unescape_value().ok().map(|v| v.to_string()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Source,
+    Target,
+}
+
+/// MXLIFF 파싱 (trans-unit 1개 = 세그먼트 1개)
+pub fn parse_mxliff(xml: &str) -> Result<MxliffFile, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut parts = MxliffFile::default();
+    let mut file_name = String::new();
+    let mut unit: Option<RemoteSegment> = None;
+    let mut section: Option<Section> = None;
+
+    loop {
+        match reader.read_event().map_err(xml_error)? {
+            Event::Start(e) if e.name().as_ref() == b"file" => {
+                file_name = attr(&e, b"original").unwrap_or_default();
+                parts.source_lang = parts.source_lang.take().or_else(|| attr(&e, b"source-language"));
+            }
+            Event::Start(e) if e.name().as_ref() == b"trans-unit" => {
+                unit = Some(RemoteSegment {
+                    remote_id: attr(&e, b"id").unwrap_or_default(),
+                    file: file_name.clone(),
+                    source: String::new(),
+                    target: String::new(),
+                });
+            }
+            Event::Start(e) if unit.is_some() && e.name().as_ref() == b"source" => section = Some(Section::Source),
+            Event::Start(e) if unit.is_some() && e.name().as_ref() == b"target" => section = Some(Section::Target),
+            Event::Text(t) => {
+                if let (Some(u), Some(s)) = (unit.as_mut(), section) {
+                    let text = t.unescape().map_err(xml_error)?;
+                    match s {
+                        Section::Source => u.source.push_str(&text),
+                        Section::Target => u.target.push_str(&text),
+                    }
+                }
+            }
+            Event::CData(t) => {
+                if let (Some(u), Some(s)) = (unit.as_mut(), section) {
+                    let text = String::from_utf8_lossy(&t);
+                    match s {
+                        Section::Source => u.source.push_str(&text),
+                        Section::Target => u.target.push_str(&text),
+                    }
+                }
+            }
+            Event::End(e) => match e.name().as_ref() {
+                b"source" | b"target" => section = None,
+                b"trans-unit" => {
+                    if let Some(u) = unit.take().filter(|u| !u.source.trim().is_empty()) {
+                        parts.segments.push(u);
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if parts.segments.is_empty() && parts.source_lang.is_none() {
+        return Err(xml_error("no <file> element found"));
+    }
+    Ok(parts)
+}
+
+/// MXLIFF의 trans-unit 번역문 교체 (`<target>`이 없으면 trans-unit 끝에 추가)
+pub fn apply_mxliff_targets(xml: &str, targets: &HashMap<&str, &str>) -> Result<String, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len() + xml.len() / 4));
+    let mut current: Option<&str> = None;
+    let mut wrote_target = false;
+    let mut skipping = false;
+
+    loop {
+        let event = reader.read_event().map_err(xml_error)?;
+        if skipping {
+            if matches!(&event, Event::End(e) if e.name().as_ref() == b"target") {
+                skipping = false;
+                writer.write_event(event).map_err(xml_error)?;
+            }
+            continue;
+        }
+        match event {
+            Event::Eof => break,
+            Event::Start(e) if e.name().as_ref() == b"trans-unit" => {
+                current = attr(&e, b"id").and_then(|id| targets.get(id.as_str()).copied());
+                wrote_target = false;
+                writer.write_event(Event::Start(e)).map_err(xml_error)?;
+            }
+            Event::Start(e) if e.name().as_ref() == b"target" && current.is_some() => {
+                writer.write_event(Event::Start(e)).map_err(xml_error)?;
+                writer
+                    .write_event(Event::Text(BytesText::new(current.unwrap_or_default())))
+                    .map_err(xml_error)?;
+                wrote_target = true;
+                skipping = true;
+            }
+            Event::Empty(e) if e.name().as_ref() == b"target" && current.is_some() => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                writer.write_event(Event::Start(e)).map_err(xml_error)?;
+                writer
+                    .write_event(Event::Text(BytesText::new(current.unwrap_or_default())))
+                    .map_err(xml_error)?;
+                writer.write_event(Event::End(BytesEnd::new(name))).map_err(xml_error)?;
+                wrote_target = true;
+            }
+            Event::End(e) if e.name().as_ref() == b"trans-unit" => {
+                if let (Some(text), false) = (current, wrote_target) {
+                    writer
+                        .write_event(Event::Start(BytesStart::new("target")))
+                        .map_err(xml_error)?;
+                    writer.write_event(Event::Text(BytesText::new(text))).map_err(xml_error)?;
+                    writer
+                        .write_event(Event::End(BytesEnd::new("target")))
+                        .map_err(xml_error)?;
+                }
+                current = None;
+                writer.write_event(Event::End(e)).map_err(xml_error)?;
+            }
+            event => writer.write_event(event).map_err(xml_error)?,
+        }
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(xml_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:1.2" xmlns:m="http://www.memsource.com/mxlf/2.0" version="1.2">
+<file original="guide.docx" source-language="en" target-language="ko" datatype="x-undefined">
+<body>
+<group id="0">
+<trans-unit id="a1" m:para-id="0"><source>Click {1&gt;Save&lt;1}.</source><target>{1&gt;저장&lt;1}을 클릭하세요.</target></trans-unit>
+<trans-unit id="a2" m:para-id="1"><source>Done &amp; dusted</source><target/></trans-unit>
+<trans-unit id="a3" m:para-id="2"><source>Last</source></trans-unit>
+</group>
+</body>
+</file>
+</xliff>"#;
+
+    #[test]
+    fn test_parse_mxliff() {
+        let parts = parse_mxliff(SAMPLE).unwrap();
+        assert_eq!(parts.source_lang.as_deref(), Some("en"));
+        assert_eq!(parts.segments.len(), 3);
+        assert_eq!(parts.segments[0].remote_id, "a1");
+        assert_eq!(parts.segments[0].file, "guide.docx");
+        assert_eq!(parts.segments[0].source, "Click {1>Save<1}.");
+        assert_eq!(parts.segments[0].target, "{1>저장<1}을 클릭하세요.");
+        assert_eq!(parts.segments[1].source, "Done & dusted");
+        assert_eq!(parts.segments[1].target, "");
+    }
+
+    #[test]
+    fn test_apply_mxliff_targets_round_trip() {
+        let targets = HashMap::from([("a1", "{1>저장<1}을 누르세요."), ("a2", "끝 & 완료"), ("a3", "마지막")]);
+        let updated = apply_mxliff_targets(SAMPLE, &targets).unwrap();
+        assert!(updated.contains(r#"m:para-id="1""#));
+
+        let parts = parse_mxliff(&updated).unwrap();
+        let got: Vec<&str> = parts.segments.iter().map(|s| s.target.as_str()).collect();
+        assert_eq!(got, vec!["{1>저장<1}을 누르세요.", "끝 & 완료", "마지막"]);
+        assert_eq!(parts.segments[0].source, "Click {1>Save<1}.");
+    }
+}
@@ -1,5 +1,5 @@
 use std::path::{Path, PathBuf};
-use crate::error::{CommandError, CommandResult};
+use crate::error::{CommandError, CommandResult, ErrorCode};
 
 /// 시스템 중요 디렉토리 접근을 차단하는 Blocklist 검증 함수
 /// - canonicalize()로 경로 정규화 후, 차단 목록과 비교합니다.
@@ -9,26 +9,17 @@ pub fn validate_path(path_str: &str) -> CommandResult<PathBuf> {
     // 1. 존재 여부 확인 및 Canonicalize
     // 파일이 존재하면 canonicalize 시도, 없으면 부모 디렉토리 검사
     let canonical_path = if path.exists() {
-        path.canonicalize().map_err(|e| CommandError {
-            code: "PATH_ERROR".to_string(),
-            message: format!("Invalid path: {}", e),
-            details: None,
-        })?
+        path.canonicalize().map_err(|e| CommandError::new(ErrorCode::PathError, format!("Invalid path: {}", e)))?
     } else {
         if let Some(parent) = path.parent() {
             if parent.exists() {
-                let canonical_parent = parent.canonicalize().map_err(|e| CommandError {
-                    code: "PATH_ERROR".to_string(),
-                    message: format!("Invalid parent path: {}", e),
-                    details: None,
-                })?;
+                let canonical_parent = parent.canonicalize().map_err(|e| CommandError::new(
+                    ErrorCode::PathError,
+                    format!("Invalid parent path: {}", e),
+                ))?;
                 canonical_parent.join(path.file_name().unwrap_or_default())
             } else {
-                return Err(CommandError {
-                    code: "PATH_ERROR".to_string(),
-                    message: "Parent directory does not exist".to_string(),
-                    details: None,
-                });
+                return Err(CommandError::new(ErrorCode::PathError, "Parent directory does not exist"));
             }
         } else {
             // 부모 경로가 없는 경우 (루트 등)
@@ -38,11 +29,7 @@ pub fn validate_path(path_str: &str) -> CommandResult<PathBuf> {
 
     // 2. Blocklist Check (OS별 시스템 경로 차단)
     if is_blocked_path(&canonical_path) {
-        return Err(CommandError {
-            code: "SECURITY_ERROR".to_string(),
-            message: "Access to system directory is blocked.".to_string(),
-            details: None,
-        });
+        return Err(CommandError::new(ErrorCode::SecurityError, "Access to system directory is blocked."));
     }
 
     Ok(canonical_path)
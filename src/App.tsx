@@ -7,7 +7,13 @@ import { mcpClientManager } from '@/ai/mcp/McpClientManager';
 import { initializeSecrets } from '@/tauri/secrets';
 import { initializeConnectors } from '@/stores/connectorStore';
 import { cleanupTempImages } from '@/tauri/attachments';
+import { flushMetrics } from '@/tauri/metrics';
+import { trackNetworkStatus } from '@/tauri/network';
+import { invoke, isTauriRuntime } from '@/tauri/invoke';
 import { useAutoUpdate } from '@/hooks/useAutoUpdate';
+import { useWindowProject } from '@/hooks/useWindowProject';
+import { useDeepLinks } from '@/hooks/useDeepLinks';
+import { useDroppedFiles } from '@/hooks/useDroppedFiles';
 import { UpdateModal } from '@/components/ui/UpdateModal';
 
 function App(): JSX.Element {
@@ -52,6 +58,15 @@ function App(): JSX.Element {
     initializeProject();
   }, [initializeProject]);
 
+  // 창별 프로젝트 기록 + 빠른 캡처 등 창 단위 이벤트
+  useWindowProject();
+
+  // ite:// 링크, OS에서 연 .ite 파일
+  useDeepLinks();
+
+  // 창에 드롭한 파일의 가져오기 결과
+  useDroppedFiles();
+
   // SecretManager 초기화 및 보안 저장소에서 API 키 로드
   // 앱 시작 시 1회 Keychain 접근으로 마스터키 로드 후 Vault 복호화
   useEffect(() => {
@@ -73,6 +88,25 @@ function App(): JSX.Element {
     void initSecrets();
   }, [loadSecureKeys]);
 
+  // 연결 상태 추적 (오프라인이면 LLM 요청을 보내지 않음)
+  useEffect(() => {
+    if (!isTauriRuntime()) return;
+    let unlisten: (() => void) | undefined;
+    let disposed = false;
+    trackNetworkStatus()
+      .then((fn) => {
+        if (disposed) fn();
+        else unlisten = fn;
+      })
+      .catch((error) => {
+        console.warn('[App] Failed to track network status:', error);
+      });
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, []);
+
   // MCP 클라이언트 초기화 (저장된 토큰이 있으면 자동 연결)
   useEffect(() => {
     void mcpClientManager.initialize();
@@ -130,6 +164,38 @@ function App(): JSX.Element {
     };
   }, []);
 
+  // 앱 종료(메뉴/트레이 종료 포함) 전: 자동 저장을 멈추고 남은 변경사항 저장 후 백엔드에 알림
+  useEffect(() => {
+    if (!isTauriRuntime()) return;
+    let unlisten: (() => void) | undefined;
+    let disposed = false;
+
+    void (async () => {
+      const { listen } = await import('@tauri-apps/api/event');
+      const unlistenFn = await listen('app-shutdown-requested', async () => {
+        const { isDirty, saveProject, stopAutoSave: stop } = useProjectStore.getState();
+        stop();
+        try {
+          if (isDirty) await saveProject();
+        } catch (e) {
+          console.error('Failed to save before shutdown:', e instanceof Error ? e.message : String(e));
+        }
+        await flushMetrics();
+        await invoke('shutdown_ready').catch(() => undefined);
+      });
+      if (disposed) {
+        unlistenFn();
+      } else {
+        unlisten = unlistenFn;
+      }
+    })();
+
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, []);
+
   return (
     <div className="min-h-screen bg-editor-bg text-editor-text">
       <MainLayout />
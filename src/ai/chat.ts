@@ -1,4 +1,4 @@
-import type { ChatMessage, EditorBlock, ITEProject } from '@/types';
+import type { ChatMessage, ChatMessageArtifact, EditorBlock, ITEProject } from '@/types';
 import { getAiConfig } from '@/ai/config';
 import { createChatModel } from '@/ai/client';
 import { buildLangChainMessages, detectRequestType, type RequestType } from '@/ai/prompt';
@@ -6,7 +6,8 @@ import { getSourceDocumentTool, getTargetDocumentTool, getReviewResultsTool } fr
 import { suggestTranslationRule, suggestProjectContext } from '@/ai/tools/suggestionTools';
 import { confluenceWordCountTool } from '@/ai/tools/confluenceTools';
 import { withRetry } from './retry';
-import { AIMessageChunk, HumanMessage, SystemMessage, ToolMessage } from '@langchain/core/messages';
+import { ProviderTextMask } from './providerMask';
+import { AIMessage, AIMessageChunk, HumanMessage, SystemMessage, ToolMessage } from '@langchain/core/messages';
 import type { ToolCall, ToolCallChunk } from '@langchain/core/messages/tool';
 import type { BaseMessage } from '@langchain/core/messages';
 import { v4 as uuidv4 } from 'uuid';
@@ -104,6 +105,7 @@ function detectOpenAiBuiltInToolsFromMessage(ai: unknown, bindTools: any[]): str
 }
 
 import { mcpClientManager } from '@/ai/mcp/McpClientManager';
+import { getRegistryStatus, type McpServerId } from '@/tauri/mcpRegistry';
 import { buildConnectorTools, type ConnectorConfig } from '@/ai/connectors';
 
 export interface GenerateReplyInput {
@@ -168,6 +170,8 @@ export interface StreamCallbacks {
   onToken?: (fullText: string, delta: string) => void;
   onToolsUsed?: (toolNames: string[]) => void;
   onToolCall?: (event: { phase: 'start' | 'end'; toolName: string; args?: any; status?: 'success' | 'error' }) => void;
+  /** 툴 결과/검색 인용 발생 시 호출 (메시지 artifacts로 저장) */
+  onArtifact?: (artifact: ChatMessageArtifact) => void;
   /** 모델 실행(생각) 시작 시 호출 */
   onModelRun?: (step: number) => void;
 }
@@ -303,6 +307,93 @@ function wrapExternalToolOutput(toolName: string, output: string): string {
   ].join('\n');
 }
 
+// 메시지 아티팩트에 보관하는 툴 결과/발췌 최대 길이 (DB 저장량 제한)
+const TOOL_RESULT_ARTIFACT_CHARS = 2000;
+const CITATION_EXCERPT_CHARS = 500;
+// 툴 결과 하나에서 추출하는 최대 인용 수
+const MAX_TOOL_CITATIONS = 10;
+
+function clip(text: string, max: number): string {
+  return text.length > max ? `${text.slice(0, max)}…` : text;
+}
+
+function citationArtifact(
+  entry: { url: string; title?: string; excerpt?: string },
+  source: 'web' | 'notion' | 'confluence',
+  toolName: string,
+): ChatMessageArtifact {
+  return {
+    kind: 'citation',
+    reference: entry.url,
+    title: entry.title || null,
+    content: entry.excerpt ? clip(entry.excerpt, CITATION_EXCERPT_CHARS) : null,
+    data: { source, toolName, retrievedAt: Date.now() },
+  };
+}
+
+/**
+ * 웹검색 응답의 URL 인용 추출
+ * - OpenAI: content block의 annotations(url_citation)
+ * - Anthropic: text block의 citations(web_search_result_location)
+ */
+export function extractWebCitations(ai: unknown, toolName: string): ChatMessageArtifact[] {
+  const content = (ai as any)?.content;
+  if (!Array.isArray(content)) return [];
+
+  const byUrl = new Map<string, ChatMessageArtifact>();
+  for (const c of content) {
+    if (!c || typeof c !== 'object') continue;
+    const refs = [...((c as any).annotations ?? []), ...((c as any).citations ?? [])];
+    for (const ref of refs) {
+      const url = typeof ref?.url === 'string' ? ref.url : '';
+      if (!url || byUrl.has(url)) continue;
+      byUrl.set(url, citationArtifact({ url, title: ref.title, excerpt: ref.cited_text }, 'web', toolName));
+    }
+  }
+  return [...byUrl.values()];
+}
+
+/**
+ * 외부 문서 툴(Notion/Confluence) 결과의 문서 링크 추출
+ * - JSON 결과에서 `url` 필드를 가진 객체를 찾습니다. (JSON이 아니면 인용 없음)
+ */
+function extractToolCitations(toolName: string, rawContent: string): ChatMessageArtifact[] {
+  if (!EXTERNAL_TOOLS.includes(toolName)) return [];
+  const source = toolName.startsWith('notion_') ? 'notion' : 'confluence';
+
+  const byUrl = new Map<string, ChatMessageArtifact>();
+  const visit = (value: unknown, depth: number): void => {
+    if (byUrl.size >= MAX_TOOL_CITATIONS || depth > 6 || !value || typeof value !== 'object') return;
+    if (Array.isArray(value)) {
+      value.forEach((v) => visit(v, depth + 1));
+      return;
+    }
+    const v = value as Record<string, unknown>;
+    if (typeof v.url === 'string' && /^https?:\/\//.test(v.url) && !byUrl.has(v.url)) {
+      const title = typeof v.title === 'string' ? v.title : undefined;
+      byUrl.set(v.url, citationArtifact({ url: v.url, ...(title ? { title } : {}) }, source, toolName));
+    }
+    Object.values(v).forEach((child) => visit(child, depth + 1));
+  };
+  visit(safeJsonParse(rawContent), 0);
+  return [...byUrl.values()];
+}
+
+function toolResultArtifact(
+  toolName: string,
+  status: 'success' | 'error',
+  content: string,
+  args: unknown,
+): ChatMessageArtifact {
+  return {
+    kind: 'tool_result',
+    reference: toolName,
+    title: null,
+    content: clip(content, TOOL_RESULT_ARTIFACT_CHARS),
+    data: { status, args: args ?? {} },
+  };
+}
+
 // 같은 에러 반복 시 조기 중단을 위한 상수
 const MAX_SAME_ERROR = 2;
 
@@ -328,6 +419,8 @@ async function runToolCallingLoop(params: {
   maxSteps?: number;
   cb?: StreamCallbacks;
   abortSignal?: AbortSignal;
+  /** 주어지면 도구 결과의 DNT 용어도 마스킹하고, 스트리밍 텍스트/도구 인자는 토큰을 되돌려 씀 */
+  mask?: ProviderTextMask;
 }): Promise<{ finalText: string; usedTools: boolean; toolsUsed: string[] }> {
   const maxSteps = Math.max(1, Math.min(12, params.maxSteps ?? 6));
   const toolMap = new Map(params.tools.map((t) => [t.name, t]));
@@ -377,7 +470,10 @@ async function runToolCallingLoop(params: {
         if (textDelta) {
           accumulatedText += textDelta;
           // 실시간으로 UI에 전달
-          params.cb?.onToken?.(accumulatedText, textDelta);
+          params.cb?.onToken?.(
+            params.mask ? params.mask.reveal(accumulatedText) : accumulatedText,
+            params.mask ? params.mask.reveal(textDelta) : textDelta,
+          );
         }
 
         // 도구 호출 청크 수집
@@ -417,6 +513,16 @@ async function runToolCallingLoop(params: {
     if (builtIns.length > 0) {
       console.info('[AI builtin_tools_used]', builtIns);
     }
+    // 내장 웹검색 인용 (Anthropic web_search는 tool_calls로 노출되지 않아 바인딩된 도구로 판단)
+    const webSearchTool = bindTools.find(
+      (t: any) => t && (t.type === 'web_search_preview' || t.name === 'web_search'),
+    );
+    if (webSearchTool) {
+      const toolName = webSearchTool.type === 'web_search_preview' ? 'web_search_preview' : 'web_search';
+      for (const artifact of extractWebCitations(finalAiMessage, toolName)) {
+        params.cb?.onArtifact?.(artifact);
+      }
+    }
 
     // 도구 호출 처리
     // 1) 스트리밍 청크에서 병합된 도구 호출
@@ -443,6 +549,7 @@ async function runToolCallingLoop(params: {
       if (!tool) {
         params.cb?.onToolCall?.({ phase: 'start', toolName: call.name, args: call.args });
         params.cb?.onToolCall?.({ phase: 'end', toolName: call.name, status: 'error' });
+        params.cb?.onArtifact?.(toolResultArtifact(call.name, 'error', `Tool not found: ${call.name}`, call.args));
         return {
           msg: new ToolMessage({
             tool_call_id: toolCallId,
@@ -462,7 +569,7 @@ async function runToolCallingLoop(params: {
 
         params.cb?.onToolCall?.({ phase: 'start', toolName: call.name, args: call.args });
         const out = await withTimeout(
-          tool.invoke(call.args ?? {}),
+          tool.invoke(params.mask ? revealToolArgs(params.mask, call.args ?? {}) : (call.args ?? {})),
           30000,
           `Tool ${call.name} timed out`
         );
@@ -473,9 +580,17 @@ async function runToolCallingLoop(params: {
         }
 
         const rawContent = typeof out === 'string' ? out : JSON.stringify(out);
+        // 문서/검색 결과의 DNT 용어도 프로바이더로 나가지 않게 마스킹
+        const providerContent = params.mask
+          ? (await params.mask.protect({ instructions: [rawContent] })).instructions[0] ?? rawContent
+          : rawContent;
         // Phase 4.2: 외부 도구 출력에 인젝션 방어 태그 적용
-        const content = wrapExternalToolOutput(call.name, rawContent);
+        const content = wrapExternalToolOutput(call.name, providerContent);
         params.cb?.onToolCall?.({ phase: 'end', toolName: call.name, status: 'success' });
+        params.cb?.onArtifact?.(toolResultArtifact(call.name, 'success', rawContent, call.args));
+        for (const artifact of extractToolCitations(call.name, rawContent)) {
+          params.cb?.onArtifact?.(artifact);
+        }
         return {
           msg: new ToolMessage({
             tool_call_id: toolCallId,
@@ -486,6 +601,11 @@ async function runToolCallingLoop(params: {
         };
       } catch (e) {
         params.cb?.onToolCall?.({ phase: 'end', toolName: call.name, status: 'error' });
+        if (!(e instanceof DOMException && e.name === 'AbortError')) {
+          params.cb?.onArtifact?.(
+            toolResultArtifact(call.name, 'error', e instanceof Error ? e.message : 'Tool execution failed', call.args),
+          );
+        }
         return {
           msg: new ToolMessage({
             tool_call_id: toolCallId,
@@ -590,9 +710,14 @@ async function buildToolSpecs(input: BuildToolSpecsInput): Promise<BuildToolSpec
   // 검수 결과 도구 (항상 사용 가능)
   toolSpecs.push(getReviewResultsTool);
 
+  // 프로젝트에 연결된 MCP 서버만 사용 (이 창의 활성 프로젝트 기준)
+  const registry = await getRegistryStatus().catch(() => null);
+  const inScope = (serverId: McpServerId): boolean =>
+    registry?.servers.find((s) => s.id === serverId)?.inScope ?? true;
+
   // MCP 도구 (Atlassian Confluence)
   // getConfluencePage는 제외 - confluence_word_count가 REST API로 직접 처리
-  if (input.confluenceSearchEnabled) {
+  if (input.confluenceSearchEnabled && inScope('atlassian')) {
     const allMcpTools = await mcpClientManager.getTools();
     const mcpTools = allMcpTools.filter((tool) => tool.name !== 'getConfluencePage');
     toolSpecs.push(...mcpTools);
@@ -601,7 +726,7 @@ async function buildToolSpecs(input: BuildToolSpecsInput): Promise<BuildToolSpec
   }
 
   // Notion 도구 (REST API 기반)
-  if (input.notionSearchEnabled) {
+  if (input.notionSearchEnabled && inScope('notion')) {
     const notionTools = await mcpClientManager.getNotionTools();
     toolSpecs.push(...notionTools);
   }
@@ -762,6 +887,58 @@ function bytesToBase64(bytes: Uint8Array): string {
   return btoa(binary);
 }
 
+/** 모델이 토큰을 넣어 부른 도구 인자를 원래 문자열로 되돌림 */
+function revealToolArgs(mask: ProviderTextMask, args: unknown): any {
+  if (typeof args === 'string') return mask.reveal(args);
+  if (Array.isArray(args)) return args.map((v) => revealToolArgs(mask, v));
+  if (args && typeof args === 'object') {
+    return Object.fromEntries(Object.entries(args).map(([k, v]) => [k, revealToolArgs(mask, v)]));
+  }
+  return args;
+}
+
+/**
+ * 프로바이더로 보낼 메시지의 DNT 용어를 토큰으로 바꿈
+ * - 문자열/텍스트 블록만 마스킹하고 이미지 블록은 그대로 둠
+ * - 토큰이 생기면 시스템 프롬프트에 토큰 보존 지시를 덧붙임
+ */
+async function maskChatMessages(
+  mask: ProviderTextMask,
+  messages: BaseMessage[],
+  userMessage: string,
+): Promise<{ messages: BaseMessage[]; userMessage: string }> {
+  const texts: string[] = [userMessage];
+  for (const m of messages) {
+    if (typeof m.content === 'string') {
+      texts.push(m.content);
+    } else {
+      for (const block of m.content as any[]) {
+        if (block?.type === 'text' && typeof block.text === 'string') texts.push(block.text);
+      }
+    }
+  }
+
+  const { instructions } = await mask.protect({ instructions: texts });
+  let next = 0;
+  const take = (): string => instructions[next++] ?? '';
+  const maskedUser = take();
+
+  const out = messages.map((m) => {
+    const content = typeof m.content === 'string'
+      ? take()
+      : (m.content as any[]).map((block) =>
+        block?.type === 'text' && typeof block.text === 'string' ? { ...block, text: take() } : block);
+    if (m instanceof SystemMessage) return new SystemMessage({ content });
+    if (m instanceof AIMessage) return new AIMessage({ content });
+    return new HumanMessage({ content });
+  });
+
+  if (mask.tokenInstruction && out[0] instanceof SystemMessage) {
+    out[0] = new SystemMessage([out[0].content, '', mask.tokenInstruction].join('\n'));
+  }
+  return { messages: out, userMessage: maskedUser };
+}
+
 async function maybeReplaceLastHumanMessageWithImages(params: {
   messages: BaseMessage[];
   userText: string;
@@ -914,9 +1091,12 @@ export async function generateAssistantReply(input: GenerateReplyInput): Promise
     ].join('\n')),
     ...messages.slice(1),
   ];
+  // DNT 용어는 토큰으로 바꿔 보내고 응답에서 복원
+  const mask = new ProviderTextMask(input.project?.id ?? null);
+  const masked = await maskChatMessages(mask, messagesWithGuide, input.userMessage);
   const { messages: finalMessages, usedImages } = await maybeReplaceLastHumanMessageWithImages({
-    messages: messagesWithGuide,
-    userText: input.userMessage,
+    messages: masked.messages,
+    userText: masked.userMessage,
     ...(input.imageAttachments ? { imageAttachments: input.imageAttachments } : {}),
     provider: cfg.provider,
   });
@@ -928,14 +1108,15 @@ export async function generateAssistantReply(input: GenerateReplyInput): Promise
       tools: toolSpecs as any,
       bindTools,
       messages: finalMessages,
+      mask,
     }));
   } catch (e) {
     // 이미지 입력을 지원하지 않는 모델(OpenAI text-only 등)에서 400이 발생할 수 있어 폴백합니다.
     if (usedImages) {
       const fallback = replaceLastHumanMessageText(
-        messagesWithGuide,
+        masked.messages,
         [
-          input.userMessage,
+          masked.userMessage,
           '',
           '[첨부 이미지 안내]',
           '현재 선택된 모델/Provider에서 이미지 입력이 지원되지 않아, 이미지는 제외하고 진행합니다.',
@@ -947,13 +1128,14 @@ export async function generateAssistantReply(input: GenerateReplyInput): Promise
         tools: toolSpecs as any,
         bindTools,
         messages: fallback,
+        mask,
       }));
     } else {
       throw e;
     }
   }
 
-  return finalText;
+  return await mask.restore(finalText);
 }
 
 /**
@@ -1022,9 +1204,12 @@ export async function streamAssistantReply(
     ].join('\n')),
     ...messages.slice(1),
   ];
+  // DNT 용어는 토큰으로 바꿔 보내고 응답에서 복원
+  const mask = new ProviderTextMask(input.project?.id ?? null);
+  const masked = await maskChatMessages(mask, messagesWithGuide, input.userMessage);
   const { messages: finalMessages, usedImages } = await maybeReplaceLastHumanMessageWithImages({
-    messages: messagesWithGuide,
-    userText: input.userMessage,
+    messages: masked.messages,
+    userText: masked.userMessage,
     ...(input.imageAttachments ? { imageAttachments: input.imageAttachments } : {}),
     provider: cfg.provider,
   });
@@ -1039,13 +1224,14 @@ export async function streamAssistantReply(
       messages: finalMessages,
       ...(cb ? { cb } : {}),
       ...(input.abortSignal ? { abortSignal: input.abortSignal } : {}),
+      mask,
     }));
   } catch (e) {
     if (usedImages) {
       const fallback = replaceLastHumanMessageText(
-        messagesWithGuide,
+        masked.messages,
         [
-          input.userMessage,
+          masked.userMessage,
           '',
           '[첨부 이미지 안내]',
           '현재 선택된 모델/Provider에서 이미지 입력이 지원되지 않아, 이미지는 제외하고 진행합니다.',
@@ -1058,6 +1244,7 @@ export async function streamAssistantReply(
         bindTools,
         messages: fallback,
         ...(cb ? { cb } : {}),
+        mask,
       }));
     } else {
       throw e;
@@ -1067,8 +1254,8 @@ export async function streamAssistantReply(
   cb?.onToolsUsed?.(toolsUsed);
 
   // 실시간 스트리밍: onToken 콜백은 runToolCallingLoop 내에서 이미 호출됨
-  // 최종 텍스트만 반환
-  return finalText;
+  // 최종 텍스트만 반환 (토큰 복원)
+  return await mask.restore(finalText);
 }
 
 /**
@@ -3,11 +3,13 @@ import { ChatAnthropic } from '@langchain/anthropic';
 import type { BaseChatModel } from '@langchain/core/language_models/chat_models';
 import { getAiConfig } from '@/ai/config';
 import i18n from '@/i18n/config';
+import { isNetworkOnline } from '@/tauri/network';
 
 /**
  * Chat 모델 생성
  * - Provider: OpenAI, Anthropic 지원
  * - mock 모드는 개발용으로 유지 (OpenAI 모델로 fallback)
+ * - 오프라인(연결 끊김/오프라인 모드)이면 요청 전에 실패
  */
 export function createChatModel(
   modelOverride?: string,
@@ -17,6 +19,10 @@ export function createChatModel(
   const model = modelOverride ?? cfg.model;
   const useFor = options?.useFor ?? 'chat';
 
+  if (!isNetworkOnline()) {
+    throw new Error(i18n.t('errors.offline'));
+  }
+
   // Anthropic (Claude)
   if (cfg.provider === 'anthropic') {
     if (!cfg.anthropicApiKey) {
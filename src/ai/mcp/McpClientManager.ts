@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/tauri/invoke";
 import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import { DynamicStructuredTool } from "@langchain/core/tools";
 import { z } from "zod";
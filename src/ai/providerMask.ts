@@ -0,0 +1,73 @@
+import {
+  protectTextForProvider,
+  restoreProviderText,
+  type ProviderPlaceholder,
+} from '@/tauri/text';
+
+/**
+ * LLM 요청 하나의 마스킹 상태
+ * - 원문/지시문/도구 결과를 나눠 보호해도 토큰 번호가 겹치지 않게 누적 토큰 수를 이어서 넘깁니다.
+ * - 응답 복원은 백엔드(`restore_provider_text`)가 하고, 스트리밍 표시와 도구 인자만 로컬에서 치환합니다.
+ */
+export class ProviderTextMask {
+  private placeholders: ProviderPlaceholder[] = [];
+  private instructionPlaceholders: ProviderPlaceholder[] = [];
+  private tagTokens = 0;
+  private dntTokens = 0;
+  /** 병렬 도구 결과를 마스킹해도 누적 토큰 수가 꼬이지 않게 순서대로 실행 */
+  private pending: Promise<unknown> = Promise.resolve();
+  /** 토큰이 생겼으면 시스템 프롬프트에 덧붙일 지시 */
+  tokenInstruction: string | null = null;
+
+  constructor(private readonly projectId: string | null) {}
+
+  /**
+   * text(번역할 원문)는 인라인 태그 + DNT, instructions는 DNT만 토큰으로 바꿈
+   */
+  protect(params: { text?: string; instructions?: string[] }): Promise<{ text: string; instructions: string[] }> {
+    const run = this.pending.then(() => this.protectNow(params));
+    this.pending = run.catch(() => undefined);
+    return run;
+  }
+
+  private async protectNow(params: { text?: string; instructions?: string[] }): Promise<{ text: string; instructions: string[] }> {
+    const masked = await protectTextForProvider({
+      ...params,
+      projectId: this.projectId,
+      tagTokens: this.tagTokens,
+      dntTokens: this.dntTokens,
+    });
+    this.placeholders.push(...masked.placeholders);
+    this.instructionPlaceholders.push(...masked.instructionPlaceholders);
+    this.tagTokens = masked.tagTokens;
+    this.dntTokens = masked.dntTokens;
+    this.tokenInstruction = this.tokenInstruction ?? masked.tokenInstruction;
+    return { text: masked.text, instructions: masked.instructions };
+  }
+
+  /** 로컬 치환 (스트리밍 표시, 도구 인자용 / 누락·중복 검사 없음) */
+  reveal(text: string): string {
+    let out = text;
+    for (const p of [...this.placeholders, ...this.instructionPlaceholders]) {
+      out = out.split(p.token).join(p.original);
+    }
+    return out;
+  }
+
+  /**
+   * 응답 복원
+   * - strict면 원문 토큰이 누락/중복되었을 때 오류
+   */
+  async restore(text: string, strict = false): Promise<string> {
+    if (this.placeholders.length === 0 && this.instructionPlaceholders.length === 0) {
+      return text;
+    }
+    const restored = await restoreProviderText({
+      text,
+      placeholders: this.placeholders,
+      instructionPlaceholders: this.instructionPlaceholders,
+      strict,
+    });
+    return restored.text;
+  }
+}
@@ -0,0 +1,63 @@
+/**
+ * 스냅샷용 채팅 요약
+ * - 스냅샷을 만들 때 현재 채팅 세션의 논의(결정 사항, 용어 선택 이유)를 짧게 요약해 함께 저장
+ * - 요약 실패(API 키 없음, 네트워크 오류 등) 시 null → 백엔드 발췌 요약으로 대체
+ */
+
+import { SystemMessage, HumanMessage } from '@langchain/core/messages';
+import type { ChatSession, HistorySnapshot } from '@/types';
+import { createChatModel } from '@/ai/client';
+import { createSnapshot } from '@/tauri/history';
+
+/** 요약 입력에 넣을 최근 메시지 수 */
+const MAX_MESSAGES = 30;
+/** 메시지 하나의 최대 길이 (문자 수) */
+const MAX_MESSAGE_CHARS = 1500;
+
+const SYSTEM_PROMPT = [
+  'You summarize a translator\'s chat with an AI assistant so it can be stored with a document version snapshot.',
+  'Write 3-6 short bullet points in the language the user mostly wrote in.',
+  'Focus on decisions and their reasons: terminology choices, style rules, open questions.',
+  'Do not invent anything that is not in the conversation.',
+].join('\n');
+
+export async function summarizeChatForSnapshot(session: ChatSession): Promise<string | null> {
+  const turns = session.messages
+    .filter((m) => (m.role === 'user' || m.role === 'assistant') && m.content.trim())
+    .slice(-MAX_MESSAGES);
+  if (turns.length === 0) return null;
+
+  const transcript = turns
+    .map((m) => `${m.role === 'user' ? 'User' : 'Assistant'}: ${m.content.trim().slice(0, MAX_MESSAGE_CHARS)}`)
+    .join('\n\n');
+
+  try {
+    const model = createChatModel(undefined, { useFor: 'chat', maxTokens: 512 });
+    const res = await model.invoke([new SystemMessage(SYSTEM_PROMPT), new HumanMessage(transcript)]);
+    const text = typeof res.content === 'string'
+      ? res.content
+      : res.content.map((c) => (typeof c === 'object' && c && 'text' in c ? String((c as any).text ?? '') : '')).join('');
+    return text.trim() || null;
+  } catch (e) {
+    console.warn('[snapshotSummary] LLM summary failed, falling back to excerpt:', e);
+    return null;
+  }
+}
+
+/**
+ * 채팅 요약을 붙여 스냅샷 생성
+ * - LLM 요약이 없으면 sessionId만 넘겨 백엔드 발췌 요약 사용
+ */
+export async function createSnapshotWithChatSummary(params: {
+  projectId: string;
+  description: string;
+  session: ChatSession | null;
+}): Promise<HistorySnapshot> {
+  const chatSummary = params.session ? await summarizeChatForSnapshot(params.session) : null;
+  return await createSnapshot({
+    projectId: params.projectId,
+    description: params.description,
+    chatSummary,
+    sessionId: params.session?.id ?? null,
+  });
+}
@@ -12,7 +12,7 @@
 
 import { tool } from '@langchain/core/tools';
 import { z } from 'zod';
-import { invoke } from '@tauri-apps/api/core';
+import { invoke } from '@/tauri/invoke';
 import {
   countWords,
   extractPageIdFromUrl,
@@ -6,7 +6,7 @@
 
 import { DynamicStructuredTool } from "@langchain/core/tools";
 import { z } from "zod";
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "@/tauri/invoke";
 
 // 최대 출력 크기 상수
 const MAX_TOOL_OUTPUT_CHARS = 8000;
@@ -17,6 +17,8 @@ import {
   type TipTapDocJson,
 } from '@/utils/markdownConverter';
 import { stripImages } from '@/utils/imagePlaceholder';
+import { isCommandError } from '@/tauri/invoke';
+import { ProviderTextMask } from './providerMask';
 
 // TipTapDocJson 타입을 re-export
 export type { TipTapDocJson };
@@ -46,6 +48,8 @@ export function isTimeoutError(error: unknown): boolean {
  */
 export function isRetryableTranslationError(error: unknown): boolean {
   if (!(error instanceof Error)) return false;
+  // 응답에서 보호 토큰(태그/DNT)이 빠지거나 중복된 경우
+  if (isCommandError(error) && error.code === 'TOKEN_MISMATCH') return true;
   const msg = error.message.toLowerCase();
 
   return (
@@ -143,6 +147,14 @@ export async function translateSourceDocToTargetDocJson(params: {
     console.log(`[Translation] Stripped ${imageCount} images from source`);
   }
 
+  // 인라인 태그/DNT 용어를 토큰으로 바꿔 전송 (용어집/맥락의 DNT 용어도 같은 토큰 공간에서 마스킹)
+  const mask = new ProviderTextMask(params.project.id);
+  const protectedInput = await mask.protect({
+    text: sourceMarkdown,
+    instructions: [params.projectContext?.trim() ?? '', params.glossary?.trim() ?? ''],
+  });
+  const [maskedProjectContext, maskedGlossary] = protectedInput.instructions;
+
   const srcLang = 'Source';
   const tgtLang = params.project.metadata.targetLanguage ?? 'Target';
 
@@ -180,22 +192,26 @@ export async function translateSourceDocToTargetDocJson(params: {
     systemLines.push('[번역 규칙]', rules, '');
   }
 
-  const projectContext = params.projectContext?.trim();
+  const projectContext = maskedProjectContext;
   if (projectContext) {
     systemLines.push('[Project Context]', projectContext, '');
   }
 
-  const glossary = params.glossary?.trim();
+  const glossary = maskedGlossary;
   if (glossary) {
     systemLines.push('[용어집]', '아래 용어집의 번역을 준수하세요:', glossary, '');
   }
 
+  if (mask.tokenInstruction) {
+    systemLines.push('[보호 토큰]', mask.tokenInstruction, '');
+  }
+
   const systemPrompt = systemLines.join('\n').trim();
 
   // ============================================================
   // 동적 max_tokens 계산 (Markdown 기준, JSON 오버헤드 없음)
   // ============================================================
-  const estimatedInputTokens = estimateMarkdownTokens(sourceMarkdown);
+  const estimatedInputTokens = estimateMarkdownTokens(protectedInput.text);
   const systemPromptTokens = estimateMarkdownTokens(systemPrompt);
   const totalInputTokens = estimatedInputTokens + systemPromptTokens;
 
@@ -238,7 +254,7 @@ export async function translateSourceDocToTargetDocJson(params: {
         '아래 Markdown 문서를 번역하여, 구분자 내에 번역된 Markdown만 반환하세요.',
         '',
         '---INPUT_DOCUMENT_START---',
-        sourceMarkdown,
+        protectedInput.text,
         '---INPUT_DOCUMENT_END---',
         '',
         '(DO NOT TRANSLATE THIS INSTRUCTION) Output ONLY the translated Markdown between ---TRANSLATION_START--- and ---TRANSLATION_END--- markers.',
@@ -281,7 +297,7 @@ export async function translateSourceDocToTargetDocJson(params: {
   // ============================================================
   // Markdown 응답 추출 및 검증
   // ============================================================
-  const translatedMarkdownRaw = extractTranslationMarkdown(raw);
+  const translatedMarkdownRaw = await mask.restore(extractTranslationMarkdown(raw), true);
 
   // Markdown truncation 감지
   const truncation = detectMarkdownTruncation(translatedMarkdownRaw);
@@ -373,6 +389,14 @@ export async function translateWithStreaming(
     console.log(`[Streaming Translation] Stripped ${imageCount} images from source`);
   }
 
+  // 인라인 태그/DNT 용어를 토큰으로 바꿔 전송 (용어집/맥락의 DNT 용어도 같은 토큰 공간에서 마스킹)
+  const mask = new ProviderTextMask(params.project.id);
+  const protectedInput = await mask.protect({
+    text: sourceMarkdown,
+    instructions: [params.projectContext?.trim() ?? '', params.glossary?.trim() ?? ''],
+  });
+  const [maskedProjectContext, maskedGlossary] = protectedInput.instructions;
+
   const srcLang = 'Source';
   const tgtLang = params.project.metadata.targetLanguage ?? 'Target';
 
@@ -410,16 +434,20 @@ export async function translateWithStreaming(
     systemLines.push('[번역 규칙]', rules, '');
   }
 
-  const projectContext = params.projectContext?.trim();
+  const projectContext = maskedProjectContext;
   if (projectContext) {
     systemLines.push('[Project Context]', projectContext, '');
   }
 
-  const glossary = params.glossary?.trim();
+  const glossary = maskedGlossary;
   if (glossary) {
     systemLines.push('[용어집]', '아래 용어집의 번역을 준수하세요:', glossary, '');
   }
 
+  if (mask.tokenInstruction) {
+    systemLines.push('[보호 토큰]', mask.tokenInstruction, '');
+  }
+
   // 검수 이슈가 있으면 재번역 컨텍스트로 추가
   if (params.reviewIssues && params.reviewIssues.length > 0) {
     const typeLabels: Record<string, string> = {
@@ -457,7 +485,7 @@ export async function translateWithStreaming(
   const systemPrompt = systemLines.join('\n').trim();
 
   // 동적 max_tokens 계산
-  const estimatedInputTokens = estimateMarkdownTokens(sourceMarkdown);
+  const estimatedInputTokens = estimateMarkdownTokens(protectedInput.text);
   const systemPromptTokens = estimateMarkdownTokens(systemPrompt);
   const totalInputTokens = estimatedInputTokens + systemPromptTokens;
 
@@ -498,7 +526,7 @@ export async function translateWithStreaming(
         '아래 Markdown 문서를 번역하여, 구분자 내에 번역된 Markdown만 반환하세요.',
         '',
         '---INPUT_DOCUMENT_START---',
-        sourceMarkdown,
+        protectedInput.text,
         '---INPUT_DOCUMENT_END---',
         '',
         '(DO NOT TRANSLATE THIS INSTRUCTION) Output ONLY the translated Markdown between ---TRANSLATION_START--- and ---TRANSLATION_END--- markers.',
@@ -541,7 +569,7 @@ export async function translateWithStreaming(
         if (endIdx !== -1) {
           filtered = filtered.slice(0, endIdx);
         }
-        params.onToken?.(filtered.trim());
+        params.onToken?.(mask.reveal(filtered.trim()));
       }
       // 마커가 아직 없으면 콜백 호출 안함 (로딩 상태 유지)
     }
@@ -553,7 +581,7 @@ export async function translateWithStreaming(
   }
 
   // Markdown 응답 추출 및 검증
-  const translatedMarkdownRaw = extractTranslationMarkdown(accumulated);
+  const translatedMarkdownRaw = await mask.restore(extractTranslationMarkdown(accumulated), true);
 
   const truncation = detectMarkdownTruncation(translatedMarkdownRaw);
   if (truncation.isTruncated) {
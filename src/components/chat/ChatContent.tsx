@@ -28,6 +28,9 @@ import { fileToBytes, isImageMimeType, isImageFile } from '@/utils/fileUtils';
 import type { ChatMessageMetadata } from '@/types';
 import type { Editor } from '@tiptap/react';
 
+/** 본문을 가져올 수 있는 참고 문서 링크 (판단은 백엔드 `resolve_reference_url`과 같은 호스트 기준) */
+const REFERENCE_LINK_PATTERN = /^https?:\/\/([\w-]+\.)*(notion\.so|notion\.site|atlassian\.net|jira\.com)\/\S*$/i;
+
 /**
  * 채팅 콘텐츠 컴포넌트
  * 플로팅 패널 내부에 렌더링되는 채팅 기능
@@ -69,6 +72,7 @@ export function ChatContent(): JSX.Element {
 
   // 개별 선택자 (그룹에 포함되지 않는 것들)
   const createSession = useChatStore((s) => s.createSession);
+  const addComposerReference = useChatStore((s) => s.addComposerReference);
   const editorRef = useRef<Editor | null>(null);
   const messagesEndRef = useRef<HTMLDivElement | null>(null);
 
@@ -319,9 +323,10 @@ export function ChatContent(): JSX.Element {
     }
   }, [addComposerAttachment]);
 
-  // 클립보드 붙여넣기 핸들러 (이미지)
+  // 클립보드 붙여넣기 핸들러 (이미지, 참고 문서 링크)
   const handlePaste = useCallback(async (e: React.ClipboardEvent) => {
     const items = e.clipboardData.items;
+    const pastedText = e.clipboardData.getData('text/plain').trim();
 
     for (const item of items) {
       if (isImageMimeType(item.type)) {
@@ -344,8 +349,12 @@ export function ChatContent(): JSX.Element {
         return;
       }
     }
+    // Notion/Confluence/Jira 링크 하나만 붙여 넣으면 본문을 첨부로 가져옴 (링크 텍스트는 그대로 입력)
+    if (isTauriRuntime() && REFERENCE_LINK_PATTERN.test(pastedText)) {
+      void addComposerReference(pastedText);
+    }
     // 텍스트 붙여넣기는 기본 동작 유지
-  }, [addComposerAttachment]);
+  }, [addComposerAttachment, addComposerReference]);
 
   // 파일 첨부 버튼 클릭 핸들러
   const handleAttachClick = useCallback(async () => {
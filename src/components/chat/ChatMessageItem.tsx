@@ -1,7 +1,7 @@
 import { memo, useState, useCallback } from 'react';
 import { useTranslation } from 'react-i18next';
 import { confirm } from '@tauri-apps/plugin-dialog';
-import type { ChatMessage, ChatMessageMetadata } from '@/types';
+import type { ChatMessage, ChatMessageArtifact, ChatMessageMetadata } from '@/types';
 import { MemoizedMarkdown } from './MemoizedMarkdown';
 import { SkeletonParagraph } from '@/components/ui/Skeleton';
 import { useUIStore } from '@/stores/uiStore';
@@ -16,6 +16,71 @@ function cleanCitationArtifacts(content: string): string {
     .replace(/[\uE000-\uF8FF]/g, '');
 }
 
+/**
+ * 메시지 아티팩트(참조 블록/출처/도구 결과)를 접이식 목록으로 표시합니다.
+ * - 저장된 메시지에서 다시 읽어온 artifacts를 그대로 사용합니다.
+ */
+function MessageArtifacts({ artifacts }: { artifacts: ChatMessageArtifact[] }): JSX.Element | null {
+  const groups: Array<{ label: string; items: ChatMessageArtifact[] }> = [
+    { label: '참조 블록', items: artifacts.filter((a) => a.kind === 'block') },
+    { label: '출처', items: artifacts.filter((a) => a.kind === 'citation') },
+    { label: '도구 결과', items: artifacts.filter((a) => a.kind === 'tool_result') },
+  ].filter((g) => g.items.length > 0);
+  if (groups.length === 0) return null;
+
+  const renderItem = (a: ChatMessageArtifact): JSX.Element => {
+    if (a.kind === 'citation') {
+      const label = a.title || a.reference;
+      return /^https?:\/\//.test(a.reference) ? (
+        <a
+          href={a.reference}
+          target="_blank"
+          rel="noreferrer noopener"
+          title={a.reference}
+          className="underline break-all text-primary-500 hover:text-primary-600"
+        >
+          {label}
+        </a>
+      ) : (
+        <span className="break-all">{label}</span>
+      );
+    }
+    if (a.kind === 'block') {
+      const side = a.title === 'target' ? '번역문' : '원문';
+      return (
+        <span className="line-clamp-2">
+          <span className="font-medium">[{side}]</span> {a.content}
+        </span>
+      );
+    }
+    const status = (a.data as { status?: string } | null | undefined)?.status;
+    return (
+      <>
+        <span className="font-medium">{a.reference}</span>
+        {status === 'error' && <span className="ml-1 text-red-500">(실패)</span>}
+        {a.content && <span className="block line-clamp-2 opacity-80 break-all">{a.content}</span>}
+      </>
+    );
+  };
+
+  return (
+    <div className="mt-2 space-y-1">
+      {groups.map((g) => (
+        <details key={g.label} className="text-[11px] text-editor-muted">
+          <summary className="cursor-pointer select-none hover:text-editor-text">
+            {g.label} {g.items.length}개
+          </summary>
+          <ul className="mt-1 ml-3 space-y-1 list-disc">
+            {g.items.map((a, idx) => (
+              <li key={`${a.kind}-${idx}`}>{renderItem(a)}</li>
+            ))}
+          </ul>
+        </details>
+      ))}
+    </div>
+  );
+}
+
 interface ChatMessageItemProps {
   message: ChatMessage;
   isStreaming: boolean;
@@ -284,6 +349,9 @@ export const ChatMessageItem = memo(function ChatMessageItem({
                   {message.role === 'assistant' &&
                     !!displayMetadata?.toolsUsed?.length &&
                     renderToolsUsedBadge(displayMetadata.toolsUsed)}
+                  {!isStreaming && !!message.artifacts?.length && (
+                    <MessageArtifacts artifacts={message.artifacts} />
+                  )}
                 </>
               )}
             </>
@@ -439,6 +507,7 @@ export const ChatMessageItem = memo(function ChatMessageItem({
   }
   // 메시지 내용 비교
   if (prev.message.content !== next.message.content) return false;
+  if (prev.message.artifacts !== next.message.artifacts) return false;
   if (prev.message.metadata?.toolCallsInProgress !== next.message.metadata?.toolCallsInProgress) return false;
   if (prev.message.metadata?.toolsUsed !== next.message.metadata?.toolsUsed) return false;
   if (prev.message.metadata?.suggestedRule !== next.message.metadata?.suggestedRule) return false;
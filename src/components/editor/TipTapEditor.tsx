@@ -19,6 +19,7 @@ import { useReviewStore } from '@/stores/reviewStore';
 import { ReviewHighlight, refreshEditorHighlight } from '@/editor/extensions/ReviewHighlight';
 import { SearchHighlight } from '@/editor/extensions/SearchHighlight';
 import { normalizePastedHtml } from '@/utils/htmlNormalizer';
+import { useEditorLocale, useProjectLocales } from '@/hooks/useProjectLocales';
 
 /**
  * Source 패널용 편집 가능 에디터
@@ -189,6 +190,10 @@ export function SourceTipTapEditor({
     }
   }, [editor, highlightNonce]);
 
+  // 원문 로캘로 맞춤법 검사 언어 지정
+  const locales = useProjectLocales();
+  useEditorLocale(editor, locales?.source);
+
   // 에디터 cleanup (메모리 누수 방지)
   useEffect(() => {
     return () => {
@@ -373,6 +378,10 @@ export function TargetTipTapEditor({
     }
   }, [editor, highlightNonce]);
 
+  // 번역 로캘로 맞춤법 검사 언어 지정
+  const locales = useProjectLocales();
+  useEditorLocale(editor, locales?.target);
+
   // 에디터 cleanup (메모리 누수 방지)
   useEffect(() => {
     return () => {
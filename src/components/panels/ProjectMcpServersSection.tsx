@@ -0,0 +1,95 @@
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { useUIStore } from '@/stores/uiStore';
+import {
+  getProjectMcpServers,
+  listMcpServers,
+  setActiveMcpProject,
+  setProjectMcpServers,
+  type McpServerRow,
+} from '@/tauri/mcpRegistry';
+
+/**
+ * 프로젝트에서 사용할 MCP 서버 선택 (아무것도 고르지 않으면 모든 서버 사용)
+ * - 바꾸면 이 창의 MCP 서버 범위를 바로 갱신합니다.
+ */
+export function ProjectMcpServersSection({ projectId, index }: { projectId: string; index: number }): JSX.Element {
+  const { t } = useTranslation();
+  const [servers, setServers] = useState<McpServerRow[]>([]);
+  const [selected, setSelected] = useState<string[]>([]);
+  const [saving, setSaving] = useState(false);
+
+  useEffect(() => {
+    let cancelled = false;
+    Promise.all([listMcpServers(), getProjectMcpServers(projectId)])
+      .then(([all, bound]) => {
+        if (cancelled) return;
+        setServers(all);
+        setSelected(bound.map((row) => row.id));
+      })
+      .catch((e) => {
+        console.warn('[ProjectMcpServers] Failed to load MCP servers:', e instanceof Error ? e.message : String(e));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId]);
+
+  const toggle = async (serverId: string): Promise<void> => {
+    const next = selected.includes(serverId)
+      ? selected.filter((id) => id !== serverId)
+      : [...selected, serverId];
+    setSaving(true);
+    try {
+      const bound = await setProjectMcpServers(projectId, next);
+      setSelected(bound.map((row) => row.id));
+      await setActiveMcpProject(projectId);
+    } catch (e) {
+      useUIStore.getState().addToast({
+        type: 'error',
+        message: t('settings.mcpServersSaveFailed', { detail: e instanceof Error ? e.message : String(e) }),
+      });
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <section className="space-y-2">
+      <div className="flex flex-col gap-1">
+        <h3 className="text-xs font-semibold text-editor-text">{index}. {t('settings.mcpServers')}</h3>
+        <span className="text-[10px] text-editor-muted whitespace-pre-line">
+          {t('settings.mcpServersDescription')}
+        </span>
+      </div>
+
+      {servers.length > 0 ? (
+        <div className="space-y-1.5">
+          {servers.map((server) => (
+            <label
+              key={server.id}
+              className="flex items-center gap-2 p-2 rounded bg-editor-surface border border-editor-border text-[11px] text-editor-text cursor-pointer"
+            >
+              <input
+                type="checkbox"
+                checked={selected.includes(server.id)}
+                disabled={saving || !server.is_enabled}
+                onChange={() => {
+                  void toggle(server.id);
+                }}
+              />
+              <span className="truncate">{server.name}</span>
+            </label>
+          ))}
+          {selected.length === 0 && (
+            <div className="text-[10px] text-editor-muted italic">{t('settings.mcpServersAll')}</div>
+          )}
+        </div>
+      ) : (
+        <div className="text-xs text-editor-muted italic p-2 border border-dashed border-editor-border rounded">
+          {t('settings.mcpServersNone')}
+        </div>
+      )}
+    </section>
+  );
+}
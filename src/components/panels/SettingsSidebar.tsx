@@ -9,6 +9,7 @@ import { isTauriRuntime } from '@/tauri/invoke';
 import { confirm } from '@tauri-apps/plugin-dialog';
 import { DebouncedTextarea } from '@/components/ui/DebouncedTextarea';
 import { ReviewPanel } from '@/components/review/ReviewPanel';
+import { ProjectMcpServersSection } from './ProjectMcpServersSection';
 
 /**
  * Settings & Review 사이드바 컴포넌트
@@ -313,6 +314,9 @@ export function SettingsSidebar(): JSX.Element {
           </div>
         )}
       </section>
+
+      {/* Section 6: MCP Servers */}
+      {project && isTauriRuntime() && <ProjectMcpServersSection key={settingsKey} projectId={project.id} index={6} />}
     </div>
   );
 
@@ -0,0 +1,73 @@
+import { useEffect } from 'react';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import { confirm, message } from '@tauri-apps/plugin-dialog';
+import i18n from '@/i18n/config';
+import { useProjectStore } from '@/stores/projectStore';
+import { onDeepLinkNavigate, takePendingDeepLinks, type DeepLinkTarget } from '@/tauri/deepLink';
+import { isTauriRuntime } from '@/tauri/invoke';
+import { importProjectFileSafe } from '@/tauri/storage';
+
+/**
+ * 딥 링크 대상 처리
+ * - 프로젝트 열기: 현재 프로젝트를 저장하고 전환
+ * - 가져오기: 사용자 확인 후 가져오고(백업 포함) 첫 프로젝트로 전환
+ */
+async function handleDeepLink(target: DeepLinkTarget): Promise<void> {
+  const { switchProjectById } = useProjectStore.getState();
+  if (target.action === 'openProject') {
+    await switchProjectById(target.projectId);
+    return;
+  }
+
+  const ok = await confirm(i18n.t('deepLink.importConfirm', { path: target.path }), {
+    title: i18n.t('deepLink.importTitle'),
+    kind: 'info',
+  });
+  if (!ok) return;
+  const result = await importProjectFileSafe(target.path);
+  const first = result.projectIds[0];
+  if (first) {
+    await switchProjectById(first);
+  }
+}
+
+function reportFailure(e: unknown): void {
+  const detail = e instanceof Error ? e.message : String(e);
+  console.error('[DeepLink] Failed to handle deep link:', detail);
+  void message(i18n.t('deepLink.failed', { detail }), { title: i18n.t('deepLink.importTitle'), kind: 'error' });
+}
+
+/**
+ * 딥 링크 처리 (메인 창에서만, 프로젝트 창은 건너뜀)
+ * - 먼저 이벤트를 구독한 뒤 준비 전에 받은 대상을 꺼내 처리합니다 (그 뒤로는 이벤트로 옴).
+ */
+export function useDeepLinks(): void {
+  useEffect(() => {
+    if (!isTauriRuntime() || getCurrentWindow().label !== 'main') return;
+    let unlisten: (() => void) | undefined;
+    let disposed = false;
+
+    const start = async (): Promise<void> => {
+      const fn = await onDeepLinkNavigate((target) => {
+        handleDeepLink(target).catch(reportFailure);
+      });
+      if (disposed) {
+        fn();
+        return;
+      }
+      unlisten = fn;
+      const pending = await takePendingDeepLinks();
+      for (const target of pending) {
+        await handleDeepLink(target).catch(reportFailure);
+      }
+    };
+    start().catch((e) => {
+      console.warn('[DeepLink] Failed to start deep link handling:', e instanceof Error ? e.message : String(e));
+    });
+
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, []);
+}
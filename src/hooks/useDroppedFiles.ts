@@ -0,0 +1,82 @@
+import { useEffect } from 'react';
+import i18n from '@/i18n/config';
+import { useChatStore } from '@/stores/chatStore';
+import { useUIStore } from '@/stores/uiStore';
+import { onDroppedFileImported, type DroppedFileEvent } from '@/tauri/dragDrop';
+import { isTauriRuntime } from '@/tauri/invoke';
+
+function fileName(path: string): string {
+  return path.split(/[\\/]/).pop() || path;
+}
+
+/**
+ * 드롭한 파일 결과 반영
+ * - 첨부: 이 창의 프로젝트면 첨부 목록을 다시 읽음
+ * - 용어집/건너뜀/실패: 토스트로 알림 (.ite 파일은 딥 링크 처리에서 알림)
+ */
+function handleDroppedFile(event: DroppedFileEvent): void {
+  const { addToast } = useUIStore.getState();
+  const name = fileName(event.path);
+  const { outcome } = event;
+  switch (outcome.status) {
+    case 'attached': {
+      const chat = useChatStore.getState();
+      if (chat.loadedProjectId === event.projectId) {
+        void chat.loadAttachments();
+      }
+      addToast({ type: 'success', message: i18n.t('dragDrop.attached', { name }) });
+      break;
+    }
+    case 'glossaryImported':
+      addToast({
+        type: 'success',
+        message: i18n.t('dragDrop.glossaryImported', {
+          name,
+          inserted: outcome.result.inserted,
+          updated: outcome.result.updated,
+          skipped: outcome.result.skipped,
+        }),
+      });
+      break;
+    case 'skipped':
+      addToast({ type: 'warning', message: i18n.t('dragDrop.skipped', { name, reason: outcome.reason }) });
+      break;
+    case 'failed':
+      addToast({
+        type: 'error',
+        message: i18n.t('dragDrop.failed', { name, detail: outcome.message }),
+        duration: 6000,
+      });
+      break;
+    case 'projectFileOpened':
+      break;
+  }
+}
+
+/**
+ * 창에 드롭한 파일의 가져오기 결과 구독 (`import-dropped-file`)
+ */
+export function useDroppedFiles(): void {
+  useEffect(() => {
+    if (!isTauriRuntime()) return;
+    let unlisten: (() => void) | undefined;
+    let disposed = false;
+
+    onDroppedFileImported(handleDroppedFile)
+      .then((fn) => {
+        if (disposed) {
+          fn();
+        } else {
+          unlisten = fn;
+        }
+      })
+      .catch((e) => {
+        console.warn('[DragDrop] Failed to listen for dropped files:', e instanceof Error ? e.message : String(e));
+      });
+
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, []);
+}
@@ -0,0 +1,53 @@
+import { useEffect, useState } from 'react';
+import type { Editor } from '@tiptap/react';
+import { useProjectStore } from '@/stores/projectStore';
+import { isTauriRuntime } from '@/tauri/invoke';
+import { getProjectLocales, type LocaleProfile, type ProjectLocales } from '@/tauri/project';
+
+/**
+ * 현재 프로젝트 언어쌍의 로캘별 설정 (언어/로캘을 바꾸면 다시 조회)
+ */
+export function useProjectLocales(): ProjectLocales | null {
+  const projectId = useProjectStore((s) => s.project?.id ?? null);
+  const sourceLocale = useProjectStore((s) => s.project?.metadata.sourceLocale);
+  const targetLocale = useProjectStore((s) => s.project?.metadata.targetLocale);
+  const targetLanguage = useProjectStore((s) => s.project?.metadata.targetLanguage);
+  const [locales, setLocales] = useState<ProjectLocales | null>(null);
+
+  useEffect(() => {
+    if (!projectId || !isTauriRuntime()) {
+      setLocales(null);
+      return;
+    }
+    let cancelled = false;
+    getProjectLocales(projectId)
+      .then((result) => {
+        if (!cancelled) setLocales(result);
+      })
+      .catch((e) => {
+        console.warn('[useProjectLocales] Failed to load project locales:', e instanceof Error ? e.message : String(e));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId, sourceLocale, targetLocale, targetLanguage]);
+
+  return locales;
+}
+
+/**
+ * 에디터에 로캘 적용 (`lang`으로 맞춤법 검사 언어를 고르고, 사전이 없는 언어는 맞춤법 검사를 끔)
+ */
+export function useEditorLocale(editor: Editor | null, profile: LocaleProfile | null | undefined): void {
+  useEffect(() => {
+    if (!editor || editor.isDestroyed) return;
+    const dom = editor.view.dom;
+    if (profile) {
+      dom.setAttribute('lang', profile.locale);
+      dom.setAttribute('spellcheck', profile.spellcheckDictionary ? 'true' : 'false');
+    } else {
+      dom.removeAttribute('lang');
+      dom.removeAttribute('spellcheck');
+    }
+  }, [editor, profile]);
+}
@@ -0,0 +1,68 @@
+import { useEffect } from 'react';
+import type { UnlistenFn } from '@tauri-apps/api/event';
+import { useProjectStore } from '@/stores/projectStore';
+import { isTauriRuntime } from '@/tauri/invoke';
+import { setActiveMcpProject } from '@/tauri/mcpRegistry';
+import { onBlockChanged, onSegmentCaptured, setWindowProject } from '@/tauri/window';
+
+/**
+ * 백엔드 이벤트 구독 (구독 해제가 늦게 끝나도 누수 없게)
+ */
+function useTauriListener(subscribe: () => Promise<UnlistenFn>): void {
+  useEffect(() => {
+    if (!isTauriRuntime()) return;
+    let unlisten: UnlistenFn | undefined;
+    let disposed = false;
+    subscribe()
+      .then((fn) => {
+        if (disposed) fn();
+        else unlisten = fn;
+      })
+      .catch((e) => console.warn('[useWindowProject] Failed to listen:', e instanceof Error ? e.message : String(e)));
+    return () => {
+      disposed = true;
+      if (unlisten) unlisten();
+    };
+  }, [subscribe]);
+}
+
+const subscribeCaptures = (): Promise<UnlistenFn> =>
+  onSegmentCaptured((event) => {
+    const { project, appendCapturedSegments } = useProjectStore.getState();
+    if (project?.id !== event.projectId) return;
+    appendCapturedSegments(event.sentences).catch((e) => {
+      console.error('[Capture] Failed to append segment:', e instanceof Error ? e.message : String(e));
+    });
+  });
+
+const subscribeBlockChanges = (): Promise<UnlistenFn> =>
+  onBlockChanged((event) => {
+    useProjectStore
+      .getState()
+      .mergeRemoteBlockChanges(event.projectId, event.blockIds)
+      .catch((e) => {
+        console.error('[BlockChanged] Failed to merge remote changes:', e instanceof Error ? e.message : String(e));
+      });
+  });
+
+/**
+ * 이 창이 보고 있는 프로젝트를 백엔드에 기록하고(MCP 서버 범위 포함), 창 단위 이벤트를 스토어에 반영합니다.
+ * - 빠른 캡처: 스토어에 문장별 세그먼트를 추가 (자동 저장이 DB에 씀)
+ * - 블록 변경: 다른 창이 저장한 블록을 스토어에 합침
+ */
+export function useWindowProject(): void {
+  const projectId = useProjectStore((s) => s.project?.id ?? null);
+
+  useEffect(() => {
+    if (!isTauriRuntime()) return;
+    setWindowProject(projectId).catch((e) => {
+      console.warn('[useWindowProject] Failed to register window project:', e instanceof Error ? e.message : String(e));
+    });
+    setActiveMcpProject(projectId).catch((e) => {
+      console.warn('[useWindowProject] Failed to set MCP project scope:', e instanceof Error ? e.message : String(e));
+    });
+  }, [projectId]);
+
+  useTauriListener(subscribeCaptures);
+  useTauriListener(subscribeBlockChanges);
+}
@@ -12,6 +12,7 @@ import App from './App';
 import './index.css';
 import i18n from './i18n/config';
 import { useUIStore } from './stores/uiStore';
+import { syncBackendLanguage } from './tauri/settings';
 
 // Monaco를 CDN(jsDelivr)에서 로드하지 않고, 로컬 npm 패키지(monaco-editor)를 사용하도록 고정합니다.
 // - Tauri(WebView) 환경에서 외부 CDN 접근/소스맵 로딩으로 인한 404 노이즈를 제거
@@ -30,6 +31,7 @@ loader.config({ monaco });
 const savedLanguage = useUIStore.getState().language;
 if (savedLanguage) {
   i18n.changeLanguage(savedLanguage);
+  void syncBackendLanguage(savedLanguage);
 }
 
 const rootElement = document.getElementById('root');
@@ -1,7 +1,7 @@
 import { create } from 'zustand';
 import { v4 as uuidv4 } from 'uuid';
-import type { ChatSession, ChatMessage, GlossaryEntry } from '@/types';
-import { streamAssistantReply } from '@/ai/chat';
+import type { ChatSession, ChatMessage, ChatMessageArtifact, EditorBlock, GlossaryEntry, ITEProject } from '@/types';
+import { extractWebCitations, streamAssistantReply } from '@/ai/chat';
 import { useConnectorStore } from '@/stores/connectorStore';
 import { getAiConfig } from '@/ai/config';
 import { createChatModel } from '@/ai/client';
@@ -31,6 +31,7 @@ import {
 import { cleanSuggestionContent } from '@/utils/cleanSuggestionContent';
 import { resizeImageForApi, IMAGE_SIZE_LIMITS } from '@/utils/imageResize';
 import { stripHtml } from '@/utils/hash';
+import { resolveReferenceUrl } from '@/tauri/reference';
 
 const CHAT_PERSIST_DEBOUNCE_MS = 800;
 let chatPersistTimer: number | null = null;
@@ -46,6 +47,29 @@ const DEFAULT_TRANSLATOR_PERSONA = '';
 const MAX_CHAT_SESSIONS = 5;
 const MAX_MESSAGES_PER_SESSION = 1000;
 const CHAT_LENGTH_THRESHOLD = 30;
+// 사용자 메시지 block 아티팩트에 남기는 블록 발췌 길이
+const BLOCK_ARTIFACT_CHARS = 500;
+
+/** 세션 컨텍스트 블록 (프로젝트에 없는 블록은 제외) */
+function resolveContextBlocks(session: ChatSession | null, project: ITEProject | null): EditorBlock[] {
+  if (!project) return [];
+  return (session?.contextBlockIds ?? [])
+    .map((id) => project.blocks[id])
+    .filter((b): b is NonNullable<typeof b> => b !== undefined);
+}
+
+/** 모델에 함께 보낸 컨텍스트 블록 → 사용자 메시지 아티팩트 */
+function blockArtifacts(blocks: EditorBlock[]): ChatMessageArtifact[] {
+  return blocks.map((b): ChatMessageArtifact => {
+    const text = stripHtml(b.content);
+    return {
+      kind: 'block',
+      reference: b.id,
+      title: b.type,
+      content: text.length > BLOCK_ARTIFACT_CHARS ? `${text.slice(0, BLOCK_ARTIFACT_CHARS)}…` : text,
+    };
+  });
+}
 
 function tryExtractWebSearchQuery(raw: string): string | null {
   const t = (raw ?? '').trim();
@@ -244,6 +268,8 @@ interface ChatActions {
   loadAttachments: () => Promise<void>;
   // 채팅 컴포저 전용 첨부(일회성)
   addComposerAttachment: (path: string) => Promise<void>;
+  /** 붙여 넣은 Notion/Confluence/Jira 링크 본문을 일회성 첨부로 추가 */
+  addComposerReference: (url: string) => Promise<void>;
   removeComposerAttachment: (id: string) => void;
   clearComposerAttachments: () => void;
 
@@ -657,12 +683,19 @@ export const useChatStore = create<ChatStore>((set, get) => {
         .filter((a) => ['png', 'jpg', 'jpeg', 'webp', 'gif'].includes(a.fileType.toLowerCase()) && a.thumbnailDataUrl)
         .map((a) => ({ filename: a.filename, thumbnailDataUrl: a.thumbnailDataUrl! }));
 
+      // 명시적 웹검색(/web)은 컨텍스트 블록을 보내지 않으므로 block 아티팩트도 남기지 않음
+      const webQuery = tryExtractWebSearchQuery(content);
+      const contextArtifacts = webQuery
+        ? []
+        : blockArtifacts(resolveContextBlocks(get().currentSession, useProjectStore.getState().project));
+
       addMessage({
         role: 'user',
         content,
         ...(imageAttachmentsForMessage.length > 0
           ? { metadata: { imageAttachments: imageAttachmentsForMessage } }
           : {}),
+        ...(contextArtifacts.length > 0 ? { artifacts: contextArtifacts } : {}),
       });
 
       // [Auto-Title] 첫 메시지인 경우 세션 이름 자동 변경
@@ -674,7 +707,6 @@ export const useChatStore = create<ChatStore>((set, get) => {
       }
 
       // 명시적 웹검색 트리거: /web 명령어로 내장 웹검색을 직접 실행
-      const webQuery = tryExtractWebSearchQuery(content);
       if (webQuery) {
         // 기존 진행 중인 요청이 있으면 abort
         const prevAbortController = get().abortController;
@@ -708,6 +740,7 @@ export const useChatStore = create<ChatStore>((set, get) => {
         try {
           let text = '';
           const toolsUsed: string[] = [];
+          let citations: ChatMessageArtifact[] = [];
 
           // 내장 웹검색 사용 (OpenAI: web_search_preview, Anthropic: web_search_20250305)
           const modelAny = createChatModel(undefined, { useFor: 'chat' }) as any;
@@ -730,6 +763,7 @@ export const useChatStore = create<ChatStore>((set, get) => {
               ].join('\n'),
             );
             text = extractTextFromAiMessage(ai);
+            citations = extractWebCitations(ai, 'web_search_preview');
             if (text.trim()) toolsUsed.push('web_search_preview');
           } else if (cfg.provider === 'anthropic') {
             set({ statusMessage: 'Anthropic 웹 검색 중...' });
@@ -749,13 +783,18 @@ export const useChatStore = create<ChatStore>((set, get) => {
               ].join('\n'),
             );
             text = extractTextFromAiMessage(ai);
+            citations = extractWebCitations(ai, 'web_search');
             if (text.trim()) toolsUsed.push('web_search');
           }
 
           if (assistantId) {
-            updateMessage(assistantId, { content: text, metadata: { toolCallsInProgress: [], toolsUsed } });
+            updateMessage(assistantId, {
+              content: text,
+              metadata: { toolCallsInProgress: [], toolsUsed },
+              ...(citations.length > 0 ? { artifacts: citations } : {}),
+            });
           } else {
-            addMessage({ role: 'assistant', content: text });
+            addMessage({ role: 'assistant', content: text, ...(citations.length > 0 ? { artifacts: citations } : {}) });
           }
           set({ isLoading: false, streamingMessageId: null, error: null, statusMessage: null });
           schedulePersist();
@@ -780,13 +819,7 @@ export const useChatStore = create<ChatStore>((set, get) => {
         const translatorPersona = get().translatorPersona;
         const webSearchEnabled = get().webSearchEnabled;
 
-        const contextBlockIds = session?.contextBlockIds ?? [];
-        const contextBlocks =
-          project
-            ? contextBlockIds
-              .map((id) => project.blocks[id])
-              .filter((b): b is NonNullable<typeof b> => b !== undefined)
-            : [];
+        const contextBlocks = resolveContextBlocks(session, project);
         const translationRulesRaw = get().translationRules;
         const projectContextRaw = get().projectContext;
         // 채팅(Question): 문서는 기본적으로 payload에 인라인 포함하지 않고, 필요 시 Tool로 on-demand 조회합니다.
@@ -851,6 +884,7 @@ export const useChatStore = create<ChatStore>((set, get) => {
         if (assistantId) {
           set({ streamingMessageId: assistantId });
         }
+        const artifacts: ChatMessageArtifact[] = [];
 
         const replyMasked = await streamAssistantReply(
           {
@@ -948,6 +982,9 @@ export const useChatStore = create<ChatStore>((set, get) => {
                 },
               });
             },
+            onArtifact: (artifact) => {
+              artifacts.push(artifact);
+            },
             onToolsUsed: (toolsUsed) => {
               // 성능 최적화: 스트리밍 메타데이터만 업데이트
               const currentMetadata = get().streamingMetadata ?? {};
@@ -975,6 +1012,9 @@ export const useChatStore = create<ChatStore>((set, get) => {
           // 최종 콘텐츠 설정 후 한 번에 messages 배열에 반영
           set({ streamingContent: restored });
           get().finalizeStreaming();
+          if (artifacts.length > 0) {
+            get().updateMessage(assistantId, { artifacts });
+          }
         }
 
         set({ abortController: null });
@@ -1231,13 +1271,9 @@ export const useChatStore = create<ChatStore>((set, get) => {
         const project = useProjectStore.getState().project;
         const translatorPersona = get().translatorPersona;
 
-        const contextBlockIds = session.contextBlockIds ?? [];
-        const contextBlocks =
-          project
-            ? contextBlockIds
-              .map((id) => project.blocks[id])
-              .filter((b): b is NonNullable<typeof b> => b !== undefined)
-            : [];
+        const contextBlocks = resolveContextBlocks(session, project);
+        // 재전송은 현재 컨텍스트 블록으로 다시 보내므로 사용자 메시지의 block 아티팩트도 갱신
+        get().updateMessage(messageId, { artifacts: blockArtifacts(contextBlocks) });
         const translationRulesRaw = get().translationRules;
         const projectContextRaw = get().projectContext;
         // 채팅(Question): 문서는 기본적으로 payload에 인라인 포함하지 않고, 필요 시 Tool로 on-demand 조회합니다.
@@ -1289,6 +1325,7 @@ export const useChatStore = create<ChatStore>((set, get) => {
         if (assistantId) {
           set({ streamingMessageId: assistantId });
         }
+        const artifacts: ChatMessageArtifact[] = [];
 
         const replyMasked = await streamAssistantReply(
           {
@@ -1392,6 +1429,9 @@ export const useChatStore = create<ChatStore>((set, get) => {
                 set({ statusMessage: isWeb ? '답변 생성 및 웹 검색 확인 중...' : '답변 생성 및 도구 확인 중...' });
               }
             },
+            onArtifact: (artifact) => {
+              artifacts.push(artifact);
+            },
             onToolsUsed: (toolsUsed) => {
               // 성능 최적화: 스트리밍 메타데이터만 업데이트
               const currentMetadata = get().streamingMetadata ?? {};
@@ -1418,6 +1458,9 @@ export const useChatStore = create<ChatStore>((set, get) => {
           // 최종 콘텐츠 설정 후 한 번에 messages 배열에 반영
           set({ streamingContent: restored });
           get().finalizeStreaming();
+          if (artifacts.length > 0) {
+            get().updateMessage(assistantId, { artifacts });
+          }
         }
 
         set({ abortController: null });
@@ -1693,6 +1736,36 @@ export const useChatStore = create<ChatStore>((set, get) => {
       }
     },
 
+    addComposerReference: async (url: string): Promise<void> => {
+      if (!get().loadedProjectId) return;
+      if (get().composerAttachments.some((a) => a.filePath === null && a.extractedText?.startsWith(`${url}\n`))) return;
+
+      try {
+        const reference = await resolveReferenceUrl(url);
+        const now = Date.now();
+        const text = [reference.url, '', reference.content].join('\n');
+        set((state) => ({
+          composerAttachments: [
+            ...state.composerAttachments,
+            {
+              id: uuidv4(),
+              filename: reference.title || reference.id,
+              fileType: reference.source,
+              fileSize: text.length,
+              extractedText: text,
+              filePath: null,
+              createdAt: now,
+              updatedAt: now,
+            },
+          ],
+        }));
+      } catch (e) {
+        set({
+          error: e instanceof Error ? e.message : '링크 내용을 가져오지 못했습니다',
+        });
+      }
+    },
+
     removeComposerAttachment: (id: string): void => {
       set((state) => ({
         composerAttachments: state.composerAttachments.filter((a) => a.id !== id),
@@ -198,6 +198,8 @@ interface ProjectActions {
   // 세그먼트 관리
   getSegment: (segmentGroupId: string) => SegmentGroup | undefined;
   addSegment: (sourceContent: string, targetContent: string) => void;
+  appendCapturedSegments: (sentences: string[]) => Promise<void>;
+  mergeRemoteBlockChanges: (projectId: string, blockIds: string[]) => Promise<void>;
 
   // 유틸리티
   setError: (error: string | null) => void;
@@ -612,7 +614,7 @@ export const useProjectStore = create<ProjectStore>()(
           await saveInFlight;
         }
 
-        const { project, targetDocument, sourceDocument, targetDocHandle } = get();
+        const { project } = get();
 
         console.debug('[saveProject] called, projectId:', project?.id);
 
@@ -628,139 +630,7 @@ export const useProjectStore = create<ProjectStore>()(
 
         try {
           const now = Date.now();
-          // 저장 직전: Target/Source 단일 문서 내용을 blocks로 역투영
-          // 1) 가능하면 tracked ranges 기반(정확)
-          // 2) 실패/미설정 시 segment/ids 기반 fallback(저장 누락 방지)
-          let nextBlocks: Record<string, EditorBlock> = { ...project.blocks };
-
-          const applyTargetByTrackedRanges = (): boolean => {
-            // targetDocument가 비어있으면 기존 blocks 유지 (데이터 손실 방지)
-            if (!targetDocument || targetDocument.length === 0) return false;
-            if (!targetDocHandle) return false;
-            const ranges = targetDocHandle.getBlockOffsets();
-            const entries = Object.entries(ranges);
-            if (entries.length === 0) return false;
-
-            let touched = 0;
-            for (const [blockId, r] of entries) {
-              const block = nextBlocks[blockId];
-              if (!block || block.type !== 'target') continue;
-              const start = Math.max(0, Math.min(r.startOffset, targetDocument.length));
-              const end = Math.max(start, Math.min(r.endOffset, targetDocument.length));
-              const plain = targetDocument.slice(start, end);
-              const html = toParagraphHtml(plain);
-              nextBlocks[blockId] = {
-                ...block,
-                content: html,
-                hash: hashContent(html),
-                metadata: { ...block.metadata, updatedAt: now },
-              };
-              touched++;
-            }
-            return touched > 0;
-          };
-
-          const applyTargetFallback = (): void => {
-            // targetDocument가 비어있거나 초기화되지 않았으면 blocks 역투영 스킵
-            // (기존 blocks 내용 유지)
-            if (!targetDocument || targetDocument.length === 0) {
-              return;
-            }
-
-            // 원본 blocks 기준으로 초기 offset을 계산하고,
-            // 현재 targetDocument 길이와의 차이를 마지막 블록에 적용합니다.
-            // 이렇게 하면 사용자가 추가한 줄바꿈이 잘못된 세그먼트로 매핑되는 문제를 방지합니다.
-            const initialBuild = buildTargetDocument(project);
-            const initialLength = initialBuild.text.length;
-            const currentLength = targetDocument.length;
-            const delta = currentLength - initialLength;
-
-            const blockIds = Object.keys(initialBuild.blockRanges);
-            if (blockIds.length === 0) return; // 블록이 없으면 스킵
-
-            const lastBlockId = blockIds[blockIds.length - 1];
-
-            for (const [blockId, r] of Object.entries(initialBuild.blockRanges)) {
-              const block = nextBlocks[blockId];
-              if (!block || block.type !== 'target') continue;
-
-              let start = r.startOffset;
-              let end = r.endOffset;
-
-              // 마지막 블록이면 길이 변화(delta)를 반영
-              if (blockId === lastBlockId) {
-                end = Math.max(start, Math.min(end + delta, currentLength));
-              }
-
-              // 범위 안전 체크
-              start = Math.max(0, Math.min(start, currentLength));
-              end = Math.max(start, Math.min(end, currentLength));
-
-              const plain = targetDocument.slice(start, end);
-              const html = toParagraphHtml(plain);
-              nextBlocks[blockId] = {
-                ...block,
-                content: html,
-                hash: hashContent(html),
-                metadata: { ...block.metadata, updatedAt: now },
-              };
-            }
-          };
-
-          const applySourceFallback = (): void => {
-            // sourceDocument가 비어있거나 초기화되지 않았으면 blocks 역투영 스킵
-            // (기존 blocks 내용 유지)
-            if (!sourceDocument || sourceDocument.length === 0) {
-              return;
-            }
-
-            // 원본 blocks 기준으로 초기 offset을 계산하고,
-            // 현재 sourceDocument 길이와의 차이를 마지막 블록에 적용합니다.
-            const initialBuild = buildSourceDocument(project);
-            const initialLength = initialBuild.text.length;
-            const currentLength = sourceDocument.length;
-            const delta = currentLength - initialLength;
-
-            const blockIds = Object.keys(initialBuild.blockRanges);
-            if (blockIds.length === 0) return; // 블록이 없으면 스킵
-
-            const lastBlockId = blockIds[blockIds.length - 1];
-
-            for (const [blockId, r] of Object.entries(initialBuild.blockRanges)) {
-              const block = nextBlocks[blockId];
-              if (!block || block.type !== 'source') continue;
-
-              let start = r.startOffset;
-              let end = r.endOffset;
-
-              // 마지막 블록이면 길이 변화(delta)를 반영
-              if (blockId === lastBlockId) {
-                end = Math.max(start, Math.min(end + delta, currentLength));
-              }
-
-              // 범위 안전 체크
-              start = Math.max(0, Math.min(start, currentLength));
-              end = Math.max(start, Math.min(end, currentLength));
-
-              const plain = sourceDocument.slice(start, end);
-              const html = toParagraphHtml(plain);
-              nextBlocks[blockId] = {
-                ...block,
-                content: html,
-                hash: hashContent(html),
-                metadata: { ...block.metadata, updatedAt: now },
-              };
-            }
-          };
-
-          const okTracked = applyTargetByTrackedRanges();
-          console.log('[saveProject] applyTargetByTrackedRanges result:', okTracked);
-          if (!okTracked) {
-            console.log('[saveProject] Using applyTargetFallback');
-            applyTargetFallback();
-          }
-          // Source는 tracked ranges 브릿지가 없으므로 항상 fallback으로 매핑
-          applySourceFallback();
+          const nextBlocks = projectDocumentsToBlocks(get(), project, now);
 
           const projectToSave: ITEProject = {
             ...project,
@@ -1481,6 +1351,103 @@ export const useProjectStore = create<ProjectStore>()(
         scheduleWriteThroughSave(set, get);
       },
 
+      // 빠른 캡처: 편집 중인 문서를 먼저 blocks로 저장한 뒤 끝에 문장별 세그먼트를 붙이고 문서를 다시 구성
+      // - 클립보드 텍스트는 HTML로 해석하지 않고 평문으로 넣습니다.
+      appendCapturedSegments: async (sentences: string[]): Promise<void> => {
+        if (get().isDirty) {
+          await get().saveProject();
+        }
+        const { project } = get();
+        if (!project || sentences.length === 0) return;
+
+        const now = Date.now();
+        const block = (type: BlockType, content: string): EditorBlock => ({
+          id: uuidv4(),
+          type,
+          content,
+          hash: hashContent(content),
+          metadata: { createdAt: now, updatedAt: now, tags: ['captured'] },
+        });
+        const blocks = { ...project.blocks };
+        const segments = [...project.segments];
+        for (const sentence of sentences) {
+          const sourceBlock = block('source', `<p>${escapeHtml(sentence)}</p>`);
+          const targetBlock = block('target', '<p></p>');
+          blocks[sourceBlock.id] = sourceBlock;
+          blocks[targetBlock.id] = targetBlock;
+          segments.push({
+            groupId: uuidv4(),
+            sourceIds: [sourceBlock.id],
+            targetIds: [targetBlock.id],
+            isAligned: true,
+            order: segments.length,
+          });
+        }
+        const nextProject: ITEProject = { ...project, blocks, segments };
+        const td = buildTargetDocument(nextProject);
+        const sd = buildSourceDocument(nextProject);
+        set({
+          project: nextProject,
+          isDirty: true,
+          targetDocument: td.text,
+          sourceDocument: sd.text,
+          sourceDocJson: htmlToTipTapJson(sd.text),
+          targetDocJson: htmlToTipTapJson(td.text),
+        });
+        scheduleWriteThroughSave(set, get);
+      },
+
+      // 다른 창(또는 백엔드 작업)이 저장한 블록 변경 반영
+      // - 저장되지 않은 편집이 없으면 DB 상태를 그대로 다시 불러옵니다.
+      // - 편집 중이면 문서를 blocks로 역투영한 뒤 바뀐 블록만 DB 값으로 바꿔, 다음 자동 저장이 다른 창의 변경을 덮어쓰지 않게 합니다.
+      mergeRemoteBlockChanges: async (projectId: string, blockIds: string[]): Promise<void> => {
+        if (get().project?.id !== projectId) return;
+        if (saveInFlight) {
+          await saveInFlight;
+        }
+        const fresh = await tauriLoadProject(projectId);
+
+        const state = get();
+        const { project } = state;
+        if (!project || project.id !== projectId) return;
+        if (!state.isDirty) {
+          const td = buildTargetDocument(fresh);
+          const sd = buildSourceDocument(fresh);
+          set({
+            project: fresh,
+            targetDocument: td.text,
+            sourceDocument: sd.text,
+            sourceDocJson: htmlToTipTapJson(sd.text),
+            targetDocJson: htmlToTipTapJson(td.text),
+          });
+          return;
+        }
+
+        const blocks = projectDocumentsToBlocks(state, project, Date.now());
+        for (const id of blockIds) {
+          const remote = fresh.blocks[id];
+          if (remote) blocks[id] = remote;
+          else delete blocks[id];
+        }
+        // 세그먼트 구성은 DB 기준 + 이 창에서만 추가한 세그먼트
+        const knownSegments = new Set(fresh.segments.map((seg) => seg.groupId));
+        const localOnly = project.segments.filter((seg) => !knownSegments.has(seg.groupId));
+        const nextProject: ITEProject = {
+          ...project,
+          blocks,
+          segments: [...fresh.segments, ...localOnly],
+        };
+        const td = buildTargetDocument(nextProject);
+        const sd = buildSourceDocument(nextProject);
+        set({
+          project: nextProject,
+          targetDocument: td.text,
+          sourceDocument: sd.text,
+          sourceDocJson: htmlToTipTapJson(sd.text),
+          targetDocJson: htmlToTipTapJson(td.text),
+        });
+      },
+
       // 에러 설정
       setError: (error: string | null): void => {
         set({ error });
@@ -1538,6 +1505,153 @@ function escapeHtml(text: string): string {
     .replace(/'/g, '&#39;');
 }
 
+/**
+ * 편집 중인 Target/Source 단일 문서 내용을 blocks로 역투영
+ * - 저장 직전과 다른 창의 블록 변경을 합칠 때 사용합니다.
+ */
+function projectDocumentsToBlocks(
+  state: Pick<ProjectState, 'targetDocument' | 'sourceDocument' | 'targetDocHandle'>,
+  project: ITEProject,
+  now: number,
+): Record<string, EditorBlock> {
+  const { targetDocument, sourceDocument, targetDocHandle } = state;
+  // 저장 직전: Target/Source 단일 문서 내용을 blocks로 역투영
+  // 1) 가능하면 tracked ranges 기반(정확)
+  // 2) 실패/미설정 시 segment/ids 기반 fallback(저장 누락 방지)
+  let nextBlocks: Record<string, EditorBlock> = { ...project.blocks };
+
+  const applyTargetByTrackedRanges = (): boolean => {
+    // targetDocument가 비어있으면 기존 blocks 유지 (데이터 손실 방지)
+    if (!targetDocument || targetDocument.length === 0) return false;
+    if (!targetDocHandle) return false;
+    const ranges = targetDocHandle.getBlockOffsets();
+    const entries = Object.entries(ranges);
+    if (entries.length === 0) return false;
+
+    let touched = 0;
+    for (const [blockId, r] of entries) {
+      const block = nextBlocks[blockId];
+      if (!block || block.type !== 'target') continue;
+      const start = Math.max(0, Math.min(r.startOffset, targetDocument.length));
+      const end = Math.max(start, Math.min(r.endOffset, targetDocument.length));
+      const plain = targetDocument.slice(start, end);
+      const html = toParagraphHtml(plain);
+      nextBlocks[blockId] = {
+        ...block,
+        content: html,
+        hash: hashContent(html),
+        metadata: { ...block.metadata, updatedAt: now },
+      };
+      touched++;
+    }
+    return touched > 0;
+  };
+
+  const applyTargetFallback = (): void => {
+    // targetDocument가 비어있거나 초기화되지 않았으면 blocks 역투영 스킵
+    // (기존 blocks 내용 유지)
+    if (!targetDocument || targetDocument.length === 0) {
+      return;
+    }
+
+    // 원본 blocks 기준으로 초기 offset을 계산하고,
+    // 현재 targetDocument 길이와의 차이를 마지막 블록에 적용합니다.
+    // 이렇게 하면 사용자가 추가한 줄바꿈이 잘못된 세그먼트로 매핑되는 문제를 방지합니다.
+    const initialBuild = buildTargetDocument(project);
+    const initialLength = initialBuild.text.length;
+    const currentLength = targetDocument.length;
+    const delta = currentLength - initialLength;
+
+    const blockIds = Object.keys(initialBuild.blockRanges);
+    if (blockIds.length === 0) return; // 블록이 없으면 스킵
+
+    const lastBlockId = blockIds[blockIds.length - 1];
+
+    for (const [blockId, r] of Object.entries(initialBuild.blockRanges)) {
+      const block = nextBlocks[blockId];
+      if (!block || block.type !== 'target') continue;
+
+      let start = r.startOffset;
+      let end = r.endOffset;
+
+      // 마지막 블록이면 길이 변화(delta)를 반영
+      if (blockId === lastBlockId) {
+        end = Math.max(start, Math.min(end + delta, currentLength));
+      }
+
+      // 범위 안전 체크
+      start = Math.max(0, Math.min(start, currentLength));
+      end = Math.max(start, Math.min(end, currentLength));
+
+      const plain = targetDocument.slice(start, end);
+      const html = toParagraphHtml(plain);
+      nextBlocks[blockId] = {
+        ...block,
+        content: html,
+        hash: hashContent(html),
+        metadata: { ...block.metadata, updatedAt: now },
+      };
+    }
+  };
+
+  const applySourceFallback = (): void => {
+    // sourceDocument가 비어있거나 초기화되지 않았으면 blocks 역투영 스킵
+    // (기존 blocks 내용 유지)
+    if (!sourceDocument || sourceDocument.length === 0) {
+      return;
+    }
+
+    // 원본 blocks 기준으로 초기 offset을 계산하고,
+    // 현재 sourceDocument 길이와의 차이를 마지막 블록에 적용합니다.
+    const initialBuild = buildSourceDocument(project);
+    const initialLength = initialBuild.text.length;
+    const currentLength = sourceDocument.length;
+    const delta = currentLength - initialLength;
+
+    const blockIds = Object.keys(initialBuild.blockRanges);
+    if (blockIds.length === 0) return; // 블록이 없으면 스킵
+
+    const lastBlockId = blockIds[blockIds.length - 1];
+
+    for (const [blockId, r] of Object.entries(initialBuild.blockRanges)) {
+      const block = nextBlocks[blockId];
+      if (!block || block.type !== 'source') continue;
+
+      let start = r.startOffset;
+      let end = r.endOffset;
+
+      // 마지막 블록이면 길이 변화(delta)를 반영
+      if (blockId === lastBlockId) {
+        end = Math.max(start, Math.min(end + delta, currentLength));
+      }
+
+      // 범위 안전 체크
+      start = Math.max(0, Math.min(start, currentLength));
+      end = Math.max(start, Math.min(end, currentLength));
+
+      const plain = sourceDocument.slice(start, end);
+      const html = toParagraphHtml(plain);
+      nextBlocks[blockId] = {
+        ...block,
+        content: html,
+        hash: hashContent(html),
+        metadata: { ...block.metadata, updatedAt: now },
+      };
+    }
+  };
+
+  const okTracked = applyTargetByTrackedRanges();
+  console.log('[saveProject] applyTargetByTrackedRanges result:', okTracked);
+  if (!okTracked) {
+    console.log('[saveProject] Using applyTargetFallback');
+    applyTargetFallback();
+  }
+  // Source는 tracked ranges 브릿지가 없으므로 항상 fallback으로 매핑
+  applySourceFallback();
+
+  return nextBlocks;
+}
+
 function toParagraphHtml(text: string): string {
   const trimmed = text.trim();
   // 실제 HTML 태그(<p>, <div>, <img ...> 등)가 존재하는지 확인
@@ -3,6 +3,7 @@ import { persist } from 'zustand/middleware';
 import { toast as sonnerToast } from 'sonner';
 import type { EditorUIState, Toast } from '@/types';
 import { useReviewStore } from '@/stores/reviewStore';
+import { syncBackendLanguage } from '@/tauri/settings';
 
 // ============================================
 // Store State Interface
@@ -211,6 +212,7 @@ export const useUIStore = create<UIStore>()(
       // Language
       setLanguage: (language: 'ko' | 'en'): void => {
         set({ language });
+        void syncBackendLanguage(language);
       },
 
       // Panel Layout
@@ -31,6 +31,29 @@ export async function listAttachments(projectId: string): Promise<AttachmentDto[
     return await invoke<AttachmentDto[]>('list_attachments', { projectId });
 }
 
+export interface AttachmentTextSlice {
+    id: string;
+    /** 시작 위치 (문자 단위) */
+    offset: number;
+    text: string;
+    totalChars: number;
+    /** 다음 구간 시작 위치 */
+    nextOffset: number;
+    hasMore: boolean;
+}
+
+/**
+ * 첨부 추출 텍스트를 구간으로 조회
+ * - limit 기본/최대 100,000자. hasMore가 true면 nextOffset부터 이어서 읽습니다.
+ */
+export async function getAttachmentText(
+    id: string,
+    offset = 0,
+    limit?: number,
+): Promise<AttachmentTextSlice> {
+    return await invoke<AttachmentTextSlice>('get_attachment_text', { args: { id, offset, limit } });
+}
+
 export async function deleteAttachment(id: string): Promise<void> {
     return await invoke<void>('delete_attachment', { id });
 }
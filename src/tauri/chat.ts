@@ -62,3 +62,42 @@ export async function loadChatProjectSettings(projectId: string): Promise<ChatPr
 }
 
 
+
+export type CitationSource = 'web' | 'mcp' | 'notion' | 'confluence';
+
+export interface CitationInput {
+  source: CitationSource;
+  /** URL 또는 문서 ID */
+  url: string;
+  title?: string | null;
+  snippet?: string | null;
+  toolName?: string | null;
+}
+
+export interface Citation {
+  messageId: string;
+  sessionId: string;
+  position: number;
+  source: CitationSource;
+  url: string;
+  title: string | null;
+  snippet: string | null;
+  toolName: string | null;
+  retrievedAt: number;
+}
+
+/**
+ * 어시스턴트에게 보여준 검색/MCP 결과의 출처 기록 (같은 URL은 제목/발췌만 갱신)
+ * - 저장된 메시지의 artifacts(kind: 'citation')에 추가되므로 메시지를 먼저 저장해야 합니다.
+ */
+export async function saveMessageCitations(params: {
+  sessionId: string;
+  messageId: string;
+  citations: CitationInput[];
+}): Promise<Citation[]> {
+  return await invoke<Citation[]>('save_message_citations', { args: params });
+}
+
+export async function getMessageCitations(messageId: string): Promise<Citation[]> {
+  return await invoke<Citation[]>('get_message_citations', { args: { messageId } });
+}
@@ -0,0 +1,53 @@
+import { invoke } from '@/tauri/invoke';
+
+export type ConfluenceTextFormat = 'markdown' | 'text';
+
+export interface ConfluenceWordCount {
+  words: number;
+  spacedWords: number;
+  cjkCharacters: number;
+  characters: number;
+  charactersWithSpaces: number;
+}
+
+export interface ConfluencePageText {
+  pageId: string;
+  title: string;
+  format: ConfluenceTextFormat;
+  content: string;
+  /** 목록 기호/코드 블록을 뺀 본문 단어 수 */
+  wordCount: ConfluenceWordCount;
+  /** 원본이 문서 최대 크기를 넘어 잘렸는지 */
+  truncated: boolean;
+}
+
+export interface ConfluenceStorageConversion {
+  content: string;
+  wordCount: ConfluenceWordCount;
+}
+
+/**
+ * Confluence 페이지를 평문/Markdown으로 가져오기 (표/목록/매크로를 펼친 결과)
+ */
+export async function getConfluencePageText(
+  pageId: string,
+  format: ConfluenceTextFormat = 'markdown',
+  language?: string,
+): Promise<ConfluencePageText> {
+  return await invoke<ConfluencePageText>('confluence_get_page_text', {
+    args: { pageId, format, language },
+  });
+}
+
+/**
+ * 이미 받은 storage HTML을 평문/Markdown으로 변환
+ */
+export async function convertConfluenceStorage(
+  html: string,
+  format: ConfluenceTextFormat = 'markdown',
+  language?: string,
+): Promise<ConfluenceStorageConversion> {
+  return await invoke<ConfluenceStorageConversion>('convert_confluence_storage', {
+    args: { html, format, language },
+  });
+}
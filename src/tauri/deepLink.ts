@@ -0,0 +1,23 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import { invoke } from '@/tauri/invoke';
+
+/**
+ * `ite://` 링크 또는 OS에서 연 .ite 파일이 가리키는 대상
+ */
+export type DeepLinkTarget =
+  | { action: 'openProject'; projectId: string }
+  | { action: 'import'; path: string };
+
+/**
+ * 프론트엔드가 준비되기 전에 받은 대상 (한 번만 반환, 이후는 이벤트로 전달)
+ */
+export async function takePendingDeepLinks(): Promise<DeepLinkTarget[]> {
+  return await invoke<DeepLinkTarget[]>('take_pending_deep_links');
+}
+
+/**
+ * 딥 링크 이동 구독 (메인 창에만 옴)
+ */
+export async function onDeepLinkNavigate(handler: (target: DeepLinkTarget) => void): Promise<UnlistenFn> {
+  return await listen<DeepLinkTarget>('deep-link-navigate', (event) => handler(event.payload));
+}
@@ -0,0 +1,74 @@
+import { invoke } from '@/tauri/invoke';
+
+/**
+ * 백엔드 패닉 요약 1건
+ */
+export interface CrashMarker {
+  /** 발생 시각 (Unix ms) */
+  at: number;
+  command: string | null;
+  thread: string | null;
+  message: string;
+  /** "file:line:col" */
+  location: string | null;
+}
+
+export interface CrashReport {
+  /** 최근 것이 앞 */
+  markers: CrashMarker[];
+  /** 백트레이스 로그 파일 경로 */
+  logPath: string | null;
+}
+
+/**
+ * 기록된 패닉 목록 (진단 자료용)
+ */
+export async function getCrashReport(): Promise<CrashReport> {
+  return await invoke<CrashReport>('get_crash_report');
+}
+
+/**
+ * 패닉 기록 삭제, 지운 요약 수 반환
+ */
+export async function clearCrashReport(): Promise<number> {
+  return await invoke<number>('clear_crash_report');
+}
+
+export interface EnvKeyInfo {
+  key: string;
+  /** 값을 가져온 파일 이름 */
+  source: string;
+  /** 비밀로 보이는 키면 null */
+  value: string | null;
+  secret: boolean;
+}
+
+export interface EnvFileInfo {
+  name: string;
+  path: string | null;
+  loadedKeys: number;
+  /** strict 파서가 실패해 KEY=VALUE 라인만 읽은 경우 */
+  lenient: boolean;
+}
+
+export interface EnvInfo {
+  /** "development" | "production" */
+  profile: string;
+  /** 우선순위 순 */
+  files: EnvFileInfo[];
+  keys: EnvKeyInfo[];
+}
+
+/**
+ * 불러온 .env* 파일/키 목록 (개발 환경 설정 확인용)
+ */
+export async function getEnvInfo(): Promise<EnvInfo> {
+  return await invoke<EnvInfo>('ite_env_info');
+}
+
+/**
+ * .env* 파일 다시 불러오기 (개발 빌드 전용)
+ */
+export async function reloadEnv(): Promise<EnvInfo> {
+  return await invoke<EnvInfo>('reload_env');
+}
@@ -0,0 +1,27 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import type { AttachmentDto } from '@/tauri/attachments';
+import type { ImportGlossaryCsvResult } from '@/tauri/glossary';
+
+/**
+ * 드롭한 파일 1개 처리 결과 (백엔드 `DropOutcome`)
+ */
+export type DropOutcome =
+  | { status: 'attached'; attachment: AttachmentDto }
+  | { status: 'glossaryImported'; result: ImportGlossaryCsvResult }
+  /** .ite 파일은 `deep-link-navigate` 이벤트로 이어서 처리 */
+  | { status: 'projectFileOpened' }
+  | { status: 'skipped'; reason: string }
+  | { status: 'failed'; code: string; message: string };
+
+export interface DroppedFileEvent {
+  path: string;
+  projectId: string | null;
+  outcome: DropOutcome;
+}
+
+/**
+ * 드롭한 파일 처리 결과 구독 (파일을 드롭한 창에만 옴)
+ */
+export async function onDroppedFileImported(handler: (event: DroppedFileEvent) => void): Promise<UnlistenFn> {
+  return await listen<DroppedFileEvent>('import-dropped-file', (event) => handler(event.payload));
+}
@@ -52,3 +52,41 @@ export async function searchGlossary(params: {
 }
 
 
+
+/**
+ * Notion 데이터베이스 속성 → 용어 필드 매핑 (값은 Notion 속성 이름)
+ */
+export interface NotionGlossaryMapping {
+  source: string;
+  target: string;
+  notes?: string | null;
+  domain?: string | null;
+  /** 체크박스 또는 "true"/"yes"/"1" 값 */
+  caseSensitive?: string | null;
+}
+
+export interface NotionGlossarySyncReport {
+  projectId: string;
+  fetched: number;
+  imported: number;
+  skipped: number;
+  truncated: boolean;
+  syncedAt: number;
+}
+
+/**
+ * Notion 데이터베이스를 프로젝트 용어집으로 가져오기 (같은 source/target은 갱신)
+ */
+export async function syncGlossaryFromNotion(params: {
+  projectId: string;
+  databaseId: string;
+  mapping: NotionGlossaryMapping;
+}): Promise<NotionGlossarySyncReport> {
+  return await invoke<NotionGlossarySyncReport>('sync_glossary_from_notion', {
+    args: {
+      projectId: params.projectId,
+      databaseId: params.databaseId,
+      mapping: params.mapping,
+    },
+  });
+}
@@ -0,0 +1,76 @@
+import type { HistorySnapshot, ITEProject } from '@/types';
+import { invoke } from '@/tauri/invoke';
+
+/**
+ * 스냅샷 생성
+ * - chatSummary가 없고 sessionId가 있으면 백엔드가 해당 세션의 최근 대화를 발췌해 요약으로 저장
+ */
+export async function createSnapshot(params: {
+  projectId: string;
+  description: string;
+  chatSummary?: string | null;
+  sessionId?: string | null;
+}): Promise<HistorySnapshot> {
+  return await invoke<HistorySnapshot>('create_snapshot', {
+    projectId: params.projectId,
+    description: params.description,
+    chatSummary: params.chatSummary ?? null,
+    sessionId: params.sessionId ?? null,
+  });
+}
+
+export async function listHistory(projectId: string): Promise<HistorySnapshot[]> {
+  return await invoke<HistorySnapshot[]>('list_history', { projectId });
+}
+
+export interface RestoreBlockChange {
+  blockId: string;
+  blockType: string;
+  /** create: 복원하면 다시 생김, delete: 복원하면 사라짐 */
+  changeType: 'create' | 'update' | 'delete';
+  currentChars: number;
+  restoredChars: number;
+}
+
+export interface RestorePreview {
+  snapshotId: string;
+  snapshotTimestamp: number;
+  description: string;
+  created: number;
+  updated: number;
+  deleted: number;
+  currentSegments: number;
+  restoredSegments: number;
+  changes: RestoreBlockChange[];
+}
+
+export interface RestoreOutcome {
+  /** 복원 직전 상태를 담은 자동 스냅샷 */
+  preRestoreSnapshot: HistorySnapshot;
+  project: ITEProject;
+}
+
+export async function previewRestore(projectId: string, snapshotId: string): Promise<RestorePreview> {
+  return await invoke<RestorePreview>('preview_restore', { projectId, snapshotId });
+}
+
+export async function restoreSnapshot(projectId: string, snapshotId: string): Promise<RestoreOutcome> {
+  return await invoke<RestoreOutcome>('restore_snapshot', { projectId, snapshotId });
+}
+
+export interface BlockHistoryEntry {
+  source: 'edit' | 'revision' | 'snapshot';
+  timestamp: number;
+  author: string | null;
+  /** 변경 전 콘텐츠 (알 수 없으면 null) */
+  previousContent: string | null;
+  content: string;
+  /** 리뷰어 수정 ID 또는 스냅샷 ID */
+  reference: string | null;
+  /** 리뷰어 수정 상태 또는 스냅샷 설명 */
+  note: string | null;
+}
+
+export async function getBlockHistory(blockId: string): Promise<BlockHistoryEntry[]> {
+  return await invoke<BlockHistoryEntry[]>('get_block_history', { blockId });
+}
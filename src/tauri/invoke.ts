@@ -1,4 +1,6 @@
 import { invoke as tauriInvoke } from '@tauri-apps/api/core';
+import { recordCommandSample } from '@/tauri/metrics';
+import type { CommandErrorPayload, ErrorCode } from '@/types';
 
 /**
  * Tauri 환경 여부(대략적) 체크
@@ -11,8 +13,70 @@ export function isTauriRuntime(): boolean {
   return typeof g.__TAURI__ !== 'undefined' || typeof g.__TAURI_INTERNALS__ !== 'undefined';
 }
 
+/**
+ * 백엔드 명령 오류
+ * - 메시지 문자열 대신 `code`/`retryable`로 분기합니다.
+ */
+export class CommandError extends Error {
+  readonly code: ErrorCode;
+  readonly details: string | null;
+  readonly retryable: boolean;
+  readonly sourceChain: string[];
+  readonly localizedMessage: string;
+
+  constructor(payload: CommandErrorPayload) {
+    super(payload.message);
+    this.name = 'CommandError';
+    this.code = payload.code;
+    this.details = payload.details;
+    this.retryable = payload.retryable;
+    this.sourceChain = payload.sourceChain;
+    this.localizedMessage = payload.localizedMessage;
+  }
+}
+
+export function isCommandError(error: unknown): error is CommandError {
+  return error instanceof CommandError;
+}
+
+/**
+ * 화면에 보여줄 오류 문구 (백엔드 오류는 설정 언어의 코드별 문구)
+ */
+export function userErrorMessage(error: unknown): string {
+  if (error instanceof CommandError) return error.localizedMessage;
+  return error instanceof Error ? error.message : String(error);
+}
+
+/**
+ * reject 값을 Error로 통일
+ * - 구조화된 오류 본문은 `CommandError`, 문자열은 일반 `Error`로 감쌉니다.
+ */
+function normalizeError(error: unknown): unknown {
+  if (typeof error === 'string') return new Error(error);
+  if (
+    typeof error === 'object' &&
+    error !== null &&
+    !(error instanceof Error) &&
+    'code' in error &&
+    'message' in error
+  ) {
+    const payload = error as Partial<CommandErrorPayload> & Pick<CommandErrorPayload, 'code' | 'message'>;
+    return new CommandError({
+      code: payload.code,
+      message: payload.message,
+      details: payload.details ?? null,
+      retryable: payload.retryable ?? false,
+      sourceChain: payload.sourceChain ?? [],
+      localizedMessage: payload.localizedMessage ?? payload.message,
+    });
+  }
+  return error;
+}
+
 /**
  * 타입 안전 invoke 래퍼
+ * - 응답 시간/오류 코드를 로컬 사용 지표로 모읍니다 (설정이 꺼져 있으면 저장되지 않음).
+ * - 실패 시 `CommandError`(또는 `Error`)를 던집니다.
  */
 export async function invoke<T>(
   cmd: string,
@@ -21,7 +85,16 @@ export async function invoke<T>(
   if (!isTauriRuntime()) {
     throw new Error(`Tauri runtime not detected. Tried to invoke: ${cmd}`);
   }
-  return await tauriInvoke<T>(cmd, args);
+  const startedAt = performance.now();
+  try {
+    const result = await tauriInvoke<T>(cmd, args);
+    recordCommandSample(cmd, performance.now() - startedAt);
+    return result;
+  } catch (error) {
+    const normalized = normalizeError(error);
+    recordCommandSample(cmd, performance.now() - startedAt, normalized);
+    throw normalized;
+  }
 }
 
 
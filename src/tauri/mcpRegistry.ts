@@ -32,6 +32,8 @@ export interface McpServerInfo {
   description: string;
   icon: string;
   status: McpConnectionStatus;
+  /** 활성 프로젝트에서 사용하는 서버인지 */
+  inScope: boolean;
 }
 
 /**
@@ -68,36 +70,29 @@ export interface McpContent {
 }
 
 /**
- * 전체 MCP 레지스트리 상태 조회
+ * 레지스트리 상태 원본 (Rust 필드명 그대로)
  */
-export async function getRegistryStatus(): Promise<McpRegistryStatus> {
-  if (!isTauriRuntime()) {
-    return {
-      servers: [],
-      connectedCount: 0,
-      hasAnyToken: false,
+interface RawRegistryStatus {
+  servers: Array<{
+    id: string;
+    display_name: string;
+    description: string;
+    icon: string;
+    status: {
+      is_connected: boolean;
+      is_connecting: boolean;
+      error?: string | null;
+      server_name?: string | null;
+      has_stored_token?: boolean;
+      token_expires_in?: number | null;
     };
-  }
-
-  const result = await invoke<{
-    servers: Array<{
-      id: string;
-      display_name: string;
-      description: string;
-      icon: string;
-      status: {
-        is_connected: boolean;
-        is_connecting: boolean;
-        error?: string | null;
-        server_name?: string | null;
-        has_stored_token?: boolean;
-        token_expires_in?: number | null;
-      };
-    }>;
-    connected_count: number;
-    has_any_token: boolean;
-  }>('mcp_registry_status');
+    in_scope: boolean;
+  }>;
+  connected_count: number;
+  has_any_token: boolean;
+}
 
+function toRegistryStatus(result: RawRegistryStatus): McpRegistryStatus {
   return {
     servers: result.servers.map((s) => {
       const status: McpConnectionStatus = {
@@ -115,6 +110,7 @@ export async function getRegistryStatus(): Promise<McpRegistryStatus> {
         description: s.description,
         icon: s.icon,
         status,
+        inScope: s.in_scope,
       };
     }),
     connectedCount: result.connected_count,
@@ -122,6 +118,36 @@ export async function getRegistryStatus(): Promise<McpRegistryStatus> {
   };
 }
 
+/**
+ * 전체 MCP 레지스트리 상태 조회
+ */
+export async function getRegistryStatus(): Promise<McpRegistryStatus> {
+  if (!isTauriRuntime()) {
+    return {
+      servers: [],
+      connectedCount: 0,
+      hasAnyToken: false,
+    };
+  }
+
+  return toRegistryStatus(await invoke<RawRegistryStatus>('mcp_registry_status'));
+}
+
+/**
+ * 이 창의 활성 프로젝트 지정 (null이면 프로젝트를 닫은 상태)
+ * - 프로젝트에 연결된 서버만 이 창에 연결/노출하고, 어느 창의 범위에도 없는 서버는 연결을 해제합니다.
+ * - 연결된 서버가 없는 프로젝트는 모든 서버를 사용합니다.
+ */
+export async function setActiveMcpProject(projectId: string | null): Promise<McpRegistryStatus | null> {
+  if (!isTauriRuntime()) {
+    return null;
+  }
+
+  return toRegistryStatus(
+    await invoke<RawRegistryStatus>('mcp_registry_set_active_project', { projectId })
+  );
+}
+
 /**
  * 특정 MCP 서버에 연결
  */
@@ -197,3 +223,105 @@ export async function callMcpTool(
   });
 }
 
+
+export interface McpToolCallRequest {
+  /** 결과와 짝을 맞출 ID (없으면 요청 순서 번호) */
+  id?: string;
+  /** 없으면 도구 이름으로 서버를 찾음 */
+  serverId?: McpServerId;
+  name: string;
+  arguments?: Record<string, unknown>;
+}
+
+export interface McpToolCallOutcome {
+  id: string;
+  serverId: McpServerId | null;
+  name: string;
+  status: 'ok' | 'error' | 'timeout';
+  result: McpToolResult | null;
+  error: string | null;
+  durationMs: number;
+}
+
+export interface McpParallelCallReport {
+  /** 요청 순서대로 */
+  results: McpToolCallOutcome[];
+  succeeded: number;
+  failed: number;
+  timedOut: number;
+  elapsedMs: number;
+}
+
+/**
+ * 여러 MCP 도구 동시 호출 (Confluence + Notion 등 컨텍스트 수집)
+ * - 전체 제한 시간을 공유하며, 일부가 실패/시간 초과여도 끝난 결과는 모두 반환합니다.
+ */
+export async function callMcpToolsParallel(
+  requests: McpToolCallRequest[],
+  timeoutMs?: number
+): Promise<McpParallelCallReport> {
+  if (!isTauriRuntime()) {
+    throw new Error('MCP tool call requires Tauri runtime');
+  }
+
+  return await invoke<McpParallelCallReport>('mcp_registry_call_tools_parallel', {
+    requests,
+    timeoutMs,
+  });
+}
+
+/**
+ * MCP 도구 결과 캐시 비우기 (서버/도구를 지정하면 해당 항목만)
+ * @returns 제거된 항목 수
+ */
+export async function clearMcpCache(serverId?: McpServerId, toolName?: string): Promise<number> {
+  if (!isTauriRuntime()) {
+    return 0;
+  }
+
+  return await invoke<number>('mcp_registry_clear_cache', { serverId, toolName });
+}
+
+/**
+ * 저장된 MCP 서버 설정 (`mcp_servers` 행, Rust 필드명 그대로)
+ */
+export interface McpServerRow {
+  id: string;
+  name: string;
+  /** 'atlassian' | 'notion' */
+  server_type: string;
+  config_json: string;
+  is_enabled: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+/**
+ * 저장된 MCP 서버 목록 (지원 서버마다 기본 행이 없으면 백엔드가 먼저 만듦)
+ */
+export async function listMcpServers(): Promise<McpServerRow[]> {
+  if (!isTauriRuntime()) {
+    return [];
+  }
+
+  return await invoke<McpServerRow[]>('list_mcp_servers');
+}
+
+/**
+ * 프로젝트에 연결된 MCP 서버 목록 (비어 있으면 모든 서버 사용)
+ */
+export async function getProjectMcpServers(projectId: string): Promise<McpServerRow[]> {
+  if (!isTauriRuntime()) {
+    return [];
+  }
+
+  return await invoke<McpServerRow[]>('get_project_mcp_servers', { projectId });
+}
+
+/**
+ * 프로젝트의 MCP 서버 연결 교체 (빈 목록이면 모든 서버 사용)
+ * 활성 프로젝트라면 이후 setActiveMcpProject로 범위를 갱신합니다.
+ */
+export async function setProjectMcpServers(projectId: string, serverIds: string[]): Promise<McpServerRow[]> {
+  return await invoke<McpServerRow[]>('set_project_mcp_servers', { projectId, serverIds });
+}
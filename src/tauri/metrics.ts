@@ -0,0 +1,106 @@
+import { invoke as tauriInvoke } from '@tauri-apps/api/core';
+
+/**
+ * 로컬 사용 지표 (설정 usageMetrics를 켠 경우에만 DB에 저장, 외부 전송 없음)
+ * - invoke 래퍼가 명령 응답 시간/오류 코드를 모으고, 일정 주기로 한꺼번에 보냅니다.
+ */
+
+export interface MetricSample {
+  kind: 'command' | 'feature';
+  name: string;
+  durationMs?: number;
+  errorCode?: string;
+}
+
+export interface CommandMetric {
+  name: string;
+  count: number;
+  errorCount: number;
+  errorPercent: number;
+  slowCount: number;
+  avgMs: number;
+  maxMs: number;
+  lastErrorCode: string | null;
+}
+
+export interface FeatureUsage {
+  name: string;
+  count: number;
+}
+
+export interface MetricsReport {
+  enabled: boolean;
+  sinceDay: string;
+  days: number;
+  commands: CommandMetric[];
+  features: FeatureUsage[];
+}
+
+const FLUSH_INTERVAL_MS = 30_000;
+const MAX_BUFFERED_SAMPLES = 200;
+
+/** 지표 기록 명령 자체는 재지 않음 */
+const UNTRACKED_COMMANDS = new Set(['record_metrics']);
+
+let buffer: MetricSample[] = [];
+let flushTimer: ReturnType<typeof setTimeout> | null = null;
+
+/**
+ * 모아 둔 지표를 바로 보냄 (주기 전송 외에 앱 종료 직전에도 호출)
+ */
+export async function flushMetrics(): Promise<void> {
+  if (flushTimer) clearTimeout(flushTimer);
+  flushTimer = null;
+  if (buffer.length === 0) return;
+  const samples = buffer;
+  buffer = [];
+  try {
+    await tauriInvoke<number>('record_metrics', { args: { samples } });
+  } catch {
+    // 지표 기록 실패는 앱 동작에 영향을 주지 않음
+  }
+}
+
+function pushSample(sample: MetricSample): void {
+  buffer.push(sample);
+  if (buffer.length >= MAX_BUFFERED_SAMPLES) {
+    if (flushTimer) clearTimeout(flushTimer);
+    void flushMetrics();
+  } else if (!flushTimer) {
+    flushTimer = setTimeout(() => void flushMetrics(), FLUSH_INTERVAL_MS);
+  }
+}
+
+/**
+ * 명령 응답 시간/오류 기록 (invoke 래퍼에서 호출)
+ */
+export function recordCommandSample(cmd: string, durationMs: number, error?: unknown): void {
+  if (UNTRACKED_COMMANDS.has(cmd)) return;
+  const code =
+    error === undefined
+      ? undefined
+      : typeof error === 'object' && error !== null && 'code' in error
+        ? String((error as { code: unknown }).code)
+        : 'UNKNOWN';
+  pushSample({ kind: 'command', name: cmd, durationMs, errorCode: code });
+}
+
+/**
+ * 기능 사용 횟수 기록 (예: 'qa.run', 'export.xliff')
+ */
+export function recordFeatureUsage(name: string): void {
+  pushSample({ kind: 'feature', name });
+}
+
+/**
+ * 최근 N일 사용 지표 보고서 (기본 7일, 최대 30일)
+ */
+export async function getMetricsReport(days?: number): Promise<MetricsReport> {
+  await flushMetrics();
+  return await tauriInvoke<MetricsReport>('get_metrics_report', { args: { days } });
+}
+
+export async function clearMetrics(): Promise<number> {
+  buffer = [];
+  return await tauriInvoke<number>('clear_metrics');
+}
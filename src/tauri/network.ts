@@ -0,0 +1,66 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import { invoke } from '@/tauri/invoke';
+
+/**
+ * 다시 연결되면 실행할 작업 1건
+ */
+export interface QueuedJob {
+  /** 예: "cloudBackup" */
+  kind: string;
+  /** 예: 커넥터 ID */
+  target: string;
+  /** Unix ms */
+  queuedAt: number;
+}
+
+export interface NetworkStatus {
+  /** 네트워크 명령을 실행할 수 있는지 (오프라인 모드가 꺼져 있고 연결됨) */
+  online: boolean;
+  offlineMode: boolean;
+  /** 마지막 연결 확인 결과 */
+  reachable: boolean;
+  lastCheckedAt: number | null;
+  queued: QueuedJob[];
+}
+
+/**
+ * 현재 연결 상태와 대기 중인 작업
+ */
+export async function getNetworkStatus(): Promise<NetworkStatus> {
+  return await invoke<NetworkStatus>('get_network_status');
+}
+
+/**
+ * 지금 연결 확인
+ */
+export async function checkConnectivity(): Promise<NetworkStatus> {
+  return await invoke<NetworkStatus>('check_connectivity');
+}
+
+/**
+ * 연결 상태 변경 구독 (오프라인 모드 토글 포함)
+ */
+export async function onConnectivityChanged(handler: (status: NetworkStatus) => void): Promise<UnlistenFn> {
+  return await listen<NetworkStatus>('connectivity-changed', (event) => handler(event.payload));
+}
+
+/** 마지막으로 받은 연결 상태 (받기 전에는 온라인으로 간주) */
+let lastKnownStatus: NetworkStatus | null = null;
+
+/**
+ * 마지막으로 받은 연결 상태 기준 온라인 여부 (LLM 호출처럼 백엔드를 거치지 않는 요청용)
+ */
+export function isNetworkOnline(): boolean {
+  return lastKnownStatus?.online ?? true;
+}
+
+/**
+ * 연결 상태 추적 시작 (앱 시작 시 한 번, 현재 상태를 받고 변경을 구독)
+ */
+export async function trackNetworkStatus(): Promise<UnlistenFn> {
+  const unlisten = await onConnectivityChanged((status) => {
+    lastKnownStatus = status;
+  });
+  lastKnownStatus = await getNetworkStatus();
+  return unlisten;
+}
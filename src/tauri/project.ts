@@ -1,9 +1,13 @@
-import type { ITEProject, ProjectDomain } from '@/types';
+import type { CapacityHint, ITEProject, ProjectDomain } from '@/types';
 import { invoke } from '@/tauri/invoke';
 
 export interface CreateProjectParams {
   title: string;
   domain: ProjectDomain;
+  /** 원문 로캘 (BCP 47, 예: "en-US") */
+  sourceLocale?: string;
+  /** 번역 로캘 (BCP 47, 예: "ko-KR") */
+  targetLocale?: string;
 }
 
 export async function createProject(params: CreateProjectParams): Promise<ITEProject> {
@@ -11,20 +15,70 @@ export async function createProject(params: CreateProjectParams): Promise<ITEPro
     args: {
       title: params.title,
       domain: params.domain,
+      sourceLocale: params.sourceLocale,
+      targetLocale: params.targetLocale,
     },
   });
 }
 
+/**
+ * 프로젝트 저장
+ * - 원문 로캘이 번역 언어와 같아졌거나 잘못되면 원문 로캘을 비우고 저장 (번역 로캘이 잘못되면 INVALID_INPUT 오류)
+ */
 export async function saveProject(project: ITEProject): Promise<void> {
   await invoke<void>('save_project', { project });
 }
 
-export async function loadProject(projectId: string): Promise<ITEProject> {
-  return await invoke<ITEProject>('load_project', { args: { projectId } });
+export interface LocaleProfile {
+  locale: string;
+  /** 에디터 표시 이름 (예: "한국어") */
+  language: string | null;
+  segmentation: { joiner: string; abbreviations: string[] };
+  /** hunspell 사전 이름 (예: "en_US"), 없는 언어는 null */
+  spellcheckDictionary: string | null;
+  mt: { deepl: string; google: string; llm: string };
+}
+
+export interface ProjectLocales {
+  /** 프로젝트 원문 로캘이 없으면 앱 기본 원문 언어 */
+  source: LocaleProfile | null;
+  target: LocaleProfile | null;
+}
+
+/** 프로젝트 언어쌍에 맞는 문장 분할 규칙, 맞춤법 사전, MT 언어 코드 */
+export async function getProjectLocales(projectId: string): Promise<ProjectLocales> {
+  return await invoke<ProjectLocales>('get_project_locales', { args: { projectId } });
+}
+
+/**
+ * 프로젝트 로드
+ * - readOnly: true면 읽기 전용으로 열고 false면 해제 (생략 시 현재 상태 유지)
+ */
+export async function loadProject(projectId: string, readOnly?: boolean): Promise<ITEProject> {
+  return await invoke<ITEProject>('load_project', { args: { projectId, readOnly } });
+}
+
+/**
+ * 프로젝트 읽기 전용 설정/해제
+ * - 읽기 전용 프로젝트의 수정 명령은 READ_ONLY 오류로 거부됩니다 (앱 재시작 시 해제).
+ */
+export async function setProjectReadOnly(projectId: string, readOnly: boolean): Promise<boolean> {
+  return await invoke<boolean>('set_project_read_only', { args: { projectId, readOnly } });
 }
 
 export async function duplicateProject(projectId: string): Promise<ITEProject> {
   return await invoke<ITEProject>('duplicate_project', { args: { projectId } });
 }
 
-
+/**
+ * 블록 분량 제약 설정/해제 (capacity가 null이면 해제)
+ * - PPTX/자막 가져오기에서 원본 요소 크기를 기록하거나, 사용자가 직접 지정할 때 씁니다.
+ * - 변경된 블록 수를 반환합니다.
+ */
+export async function setBlockCapacity(
+  projectId: string,
+  blockIds: string[],
+  capacity: CapacityHint | null,
+): Promise<number> {
+  return await invoke<number>('set_block_capacity', { args: { projectId, blockIds, capacity } });
+}
@@ -0,0 +1,30 @@
+import { invoke } from '@/tauri/invoke';
+import type { ConfluenceTextFormat, ConfluenceWordCount } from '@/tauri/confluence';
+
+export type ReferenceSource = 'notion' | 'confluence' | 'jira';
+
+export interface ResolvedReference {
+  url: string;
+  source: ReferenceSource;
+  /** Notion 페이지 ID / Confluence 페이지 ID / Jira 이슈 키 */
+  id: string;
+  title: string;
+  content: string;
+  wordCount: ConfluenceWordCount;
+  /** 문서 최대 크기를 넘어 잘렸는지 */
+  truncated: boolean;
+}
+
+/**
+ * 붙여 넣은 Notion/Confluence/Jira 링크의 제목과 본문 가져오기
+ * - 지원하지 않는 링크면 INVALID_INPUT 오류
+ */
+export async function resolveReferenceUrl(
+  url: string,
+  format: ConfluenceTextFormat = 'markdown',
+  language?: string,
+): Promise<ResolvedReference> {
+  return await invoke<ResolvedReference>('resolve_reference_url', {
+    args: { url, format, language },
+  });
+}
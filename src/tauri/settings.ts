@@ -0,0 +1,20 @@
+import { invoke, isTauriRuntime } from '@/tauri/invoke';
+
+/**
+ * 전역 설정 단일 값 변경 (null이면 기본값으로 되돌림)
+ */
+export async function setAppSetting(key: string, value: unknown): Promise<void> {
+  await invoke('set_app_setting', { args: { key, value } });
+}
+
+/**
+ * 백엔드 오류 메시지 언어를 화면 언어와 맞춤
+ */
+export async function syncBackendLanguage(language: 'ko' | 'en'): Promise<void> {
+  if (!isTauriRuntime()) return;
+  try {
+    await setAppSetting('uiLanguage', language);
+  } catch (error) {
+    console.warn('[Settings] Failed to sync backend language:', error instanceof Error ? error.message : String(error));
+  }
+}
@@ -4,6 +4,22 @@ export async function exportProjectFile(path: string): Promise<void> {
   await invoke<void>('export_project_file', { args: { path } });
 }
 
+export interface ExportProjectPackageResult {
+  formatVersion: number;
+  projectCount: number;
+  attachmentCount: number;
+  skippedAttachments: string[];
+  requiredSecrets: string[];
+}
+
+/**
+ * 선택한 프로젝트만 .ite 파일로 내보내기
+ * - 다른 프로젝트의 데이터와 전역 TM은 포함되지 않습니다.
+ */
+export async function exportProjects(projectIds: string[], path: string): Promise<ExportProjectPackageResult> {
+  return await invoke<ExportProjectPackageResult>('export_projects', { args: { projectIds, path } });
+}
+
 export async function deleteProject(projectId: string): Promise<void> {
   await invoke<void>('delete_project', { args: { projectId } });
 }
@@ -25,6 +41,52 @@ export async function importProjectFileSafe(path: string): Promise<ImportProject
   return await invoke<ImportProjectFileSafeResult>('import_project_file_safe', { args: { path } });
 }
 
+export interface BlockDiff {
+  blockId: string;
+  segmentId: string | null;
+  localContent: string;
+  incomingContent: string;
+}
+
+export interface ProjectImportDiff {
+  projectId: string;
+  title: string;
+  localUpdatedAt: number;
+  incomingUpdatedAt: number;
+  targetChanges: BlockDiff[];
+  sourceChanges: BlockDiff[];
+  addedSegments: string[];
+  removedSegments: string[];
+  unchangedBlocks: number;
+}
+
+export interface MergeTranslationsResult {
+  updatedBlocks: number;
+  snapshotId: string | null;
+  skippedStructuralChanges: number;
+}
+
+/**
+ * .ite 파일의 새 버전과 로컬 프로젝트 비교 (현재 DB는 변경하지 않음)
+ */
+export async function previewProjectImport(path: string, projectId: string): Promise<ProjectImportDiff> {
+  return await invoke<ProjectImportDiff>('preview_project_import', { args: { path, projectId } });
+}
+
+/**
+ * 번역문 변경만 병합 (blockIds 생략 시 바뀐 번역문 전체)
+ * - 원문/세그먼트 구조는 바꾸지 않으며, 병합 전 히스토리 스냅샷을 남깁니다.
+ */
+export async function mergeProjectTranslations(
+  path: string,
+  projectId: string,
+  blockIds?: string[],
+): Promise<MergeTranslationsResult> {
+  return await invoke<MergeTranslationsResult>('merge_project_translations', {
+    args: { path, projectId, blockIds },
+  });
+}
+
 export async function listProjectIds(): Promise<string[]> {
   return await invoke<string[]>('list_project_ids');
 }
@@ -33,10 +95,124 @@ export interface RecentProjectInfo {
   id: string;
   title: string;
   updatedAt: number;
+  pinned?: boolean;
+  archived?: boolean;
+}
+
+/**
+ * 최근 프로젝트 목록
+ * - 보관된 프로젝트는 includeArchived가 true일 때만 포함됩니다.
+ */
+export async function listRecentProjects(includeArchived = false): Promise<RecentProjectInfo[]> {
+  return await invoke<RecentProjectInfo[]>('list_recent_projects', { args: { includeArchived } });
+}
+
+export async function archiveProject(projectId: string): Promise<void> {
+  await invoke<void>('archive_project', { args: { projectId } });
+}
+
+export async function unarchiveProject(projectId: string): Promise<void> {
+  await invoke<void>('unarchive_project', { args: { projectId } });
 }
 
-export async function listRecentProjects(): Promise<RecentProjectInfo[]> {
-  return await invoke<RecentProjectInfo[]>('list_recent_projects');
+
+
+export interface ProjectStorageUsage {
+  projectId: string;
+  title: string;
+  trashed: boolean;
+  documentBytes: number;
+  historyBytes: number;
+  logBytes: number;
+  chatBytes: number;
+  attachmentTextBytes: number;
+  attachments: number;
+  attachmentFileBytes: number;
+  totalBytes: number;
 }
 
+export interface StorageSuggestion {
+  action: 'optimize_database' | 'prune_auto_backups' | 'purge_trash' | 'prune_chat_history' | 'cleanup_temp_images';
+  message: string;
+  projectId: string | null;
+  reclaimableBytes: number;
+}
+
+export interface StorageReport {
+  dataDir: string;
+  databaseBytes: number;
+  freePageBytes: number;
+  attachmentStoreBytes: number;
+  referencedAttachmentBytes: number;
+  backupBytes: number;
+  backupCount: number;
+  tempUploadBytes: number;
+  totalBytes: number;
+  projects: ProjectStorageUsage[];
+  suggestions: StorageSuggestion[];
+}
 
+/**
+ * 디스크 사용량 리포트 (DB/첨부/백업/임시 파일, 프로젝트별 용량, 정리 제안)
+ * - 조회만 하며 아무것도 지우지 않습니다.
+ */
+export async function getStorageReport(): Promise<StorageReport> {
+  return await invoke<StorageReport>('get_storage_report');
+}
+
+export interface OrphanCleanupReport {
+  blocks: number;
+  chatMessages: number;
+  chatMessageArtifacts: number;
+  attachments: { id: string; projectId: string; filename: string; filePath: string | null }[];
+  removedFiles: string[];
+  removedTempFiles: string[];
+}
+
+/**
+ * 고아 데이터 정리 (세그먼트 밖 블록, 세션 없는 채팅 메시지와 첨부 항목, 삭제된 프로젝트의 첨부, 오래된 vault 임시 파일)
+ */
+export async function cleanupOrphans(): Promise<OrphanCleanupReport> {
+  return await invoke<OrphanCleanupReport>('cleanup_orphans');
+}
+
+export interface OptimizeJob {
+  jobId: string;
+  alreadyRunning: boolean;
+}
+
+/** `database-optimize-progress` 이벤트 페이로드 */
+export interface DatabaseOptimizeProgressEvent {
+  jobId: string;
+  stage: 'fts' | 'analyze' | 'vacuum' | 'completed' | 'failed';
+  completedSteps: number;
+  totalSteps: number;
+  automatic: boolean;
+  result: { beforeBytes: number; afterBytes: number; reclaimedBytes: number; elapsedMs: number } | null;
+  error: string | null;
+}
+
+/**
+ * DB 최적화 시작 (FTS 인덱스 병합, ANALYZE, VACUUM)
+ * - 진행 상황은 `database-optimize-progress` 이벤트로 받습니다. 이미 실행 중이면 그 작업 ID를 돌려줍니다.
+ */
+export async function optimizeDatabase(): Promise<OptimizeJob> {
+  return await invoke<OptimizeJob>('optimize_database');
+}
+
+export interface ConsoleQueryResult {
+  columns: string[];
+  /** 행별 값 (BLOB은 "<BLOB n bytes>") */
+  rows: Array<Array<string | number | null>>;
+  /** maxRows에서 잘렸는지 */
+  truncated: boolean;
+  elapsedMs: number;
+}
+
+/**
+ * 읽기 전용 SQL 콘솔 (SELECT 한 문장만, 5초 넘으면 중단)
+ * - 쓰기/ATTACH/트랜잭션 등은 백엔드에서 거부됩니다.
+ */
+export async function runReadonlyQuery(sql: string, maxRows?: number): Promise<ConsoleQueryResult> {
+  return await invoke<ConsoleQueryResult>('run_readonly_query', { args: { sql, maxRows } });
+}
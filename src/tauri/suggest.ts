@@ -0,0 +1,92 @@
+import { invoke } from '@/tauri/invoke';
+
+export type SuggestionEngine = 'tm' | 'glossary' | 'openai' | 'anthropic';
+
+export interface SuggestionEngineRequest {
+  engine: SuggestionEngine;
+  /** LLM 모델 (없으면 엔진 기본 모델) */
+  model?: string;
+}
+
+export interface AppliedTerm {
+  source: string;
+  target: string;
+}
+
+export interface TranslationSuggestion {
+  engine: SuggestionEngine;
+  text: string;
+  /** 일치율 0–100 (TM/용어집만) */
+  score: number | null;
+  /** 표시용 출처 (예: "TM 95%", "OpenAI gpt-5-mini") */
+  label: string;
+  /** TM 가져온 곳 / LLM 모델 이름 */
+  origin: string | null;
+  /** TM 매치 원문 */
+  matchedSource: string | null;
+  terms: AppliedTerm[];
+}
+
+export interface TranslationSuggestions {
+  blockId: string;
+  sourceText: string;
+  sourceLanguage: string | null;
+  targetLanguage: string | null;
+  suggestions: TranslationSuggestion[];
+  /** 실패한 엔진 (다른 엔진 후보는 그대로 옴) */
+  errors: Array<{ engine: SuggestionEngine; error: string }>;
+}
+
+/**
+ * 원문 블록 하나에 대한 번역 후보 (TM, 용어집 조립, LLM)
+ * - engines를 비우면 TM + 용어집
+ */
+export async function suggestTranslation(
+  projectId: string,
+  blockId: string,
+  engines: SuggestionEngineRequest[] = [],
+  maxTmMatches?: number,
+): Promise<TranslationSuggestions> {
+  return await invoke<TranslationSuggestions>('suggest_translation', {
+    args: { projectId, blockId, engines, maxTmMatches },
+  });
+}
+
+export interface TmReference {
+  source: string;
+  target: string;
+  score: number;
+}
+
+export interface TranslationPrompt {
+  /** 프롬프트에 넣은 블록 (텍스트 없는 블록 제외, 세그먼트 번호 순) */
+  blockIds: string[];
+  sourceLanguage: string | null;
+  targetLanguage: string | null;
+  prompt: { system: string; user: string };
+  terms: AppliedTerm[];
+  dntTerms: string[];
+  tmMatches: TmReference[];
+  /** execute를 요청했을 때만 */
+  execution: {
+    engine: SuggestionEngine;
+    model: string;
+    /** 보호 토큰(태그/DNT)이 깨진 세그먼트는 text가 null이고 error에 이유 */
+    translations: Array<{ blockId: string; text: string | null; error: string | null }>;
+    raw: string;
+  } | null;
+}
+
+/**
+ * 세그먼트 번역 프롬프트를 백엔드에서 조립 (용어집, DNT, TM, 프로젝트 번역 규칙)
+ * - execute를 주면 저장된 API 키로 바로 번역 (키는 WebView로 오지 않음)
+ */
+export async function buildTranslationPrompt(
+  projectId: string,
+  blockIds: string[],
+  execute?: SuggestionEngineRequest,
+): Promise<TranslationPrompt> {
+  return await invoke<TranslationPrompt>('build_translation_prompt', {
+    args: { projectId, blockIds, execute },
+  });
+}
@@ -0,0 +1,82 @@
+import { invoke } from '@/tauri/invoke';
+
+/** 음역할 원문 문자 체계 */
+export type TransliterationScheme = 'hangul' | 'kana' | 'cyrillic';
+
+/**
+ * 인명/용어를 라틴 문자로 음역
+ * - hangul: 국어의 로마자 표기법, kana: 헵번식, cyrillic: BGN/PCGN 간소화
+ * - 해당 문자 체계가 아닌 글자는 그대로 둠
+ */
+export async function transliterate(text: string, scheme: TransliterationScheme): Promise<string> {
+  return await invoke<string>('transliterate', { args: { text, scheme } });
+}
+
+/** 마스킹 토큰과 원래 문자열 */
+export interface ProviderPlaceholder {
+  /** 예: `⟦D1⟧` */
+  token: string;
+  original: string;
+}
+
+export interface ProviderMaskedText {
+  text: string;
+  placeholders: ProviderPlaceholder[];
+  /** 입력 순서대로 */
+  instructions: string[];
+  instructionPlaceholders: ProviderPlaceholder[];
+  /** 같은 요청에서 이어서 마스킹할 때 다음 호출에 넘길 누적 토큰 수 */
+  tagTokens: number;
+  dntTokens: number;
+  /** 토큰이 생겼으면 시스템 프롬프트에 덧붙일 지시 */
+  tokenInstruction: string | null;
+}
+
+export interface ProviderRestoreResult {
+  text: string;
+  missingTokens: string[];
+  duplicatedTokens: string[];
+}
+
+/**
+ * LLM 요청 텍스트 보호
+ * - text(번역할 원문)는 인라인 태그 + DNT 용어, instructions(용어집/맥락/대화)는 DNT 용어만 토큰으로 바꿈
+ * - projectId가 없으면 전역 DNT 용어만 사용
+ */
+export async function protectTextForProvider(params: {
+  text?: string;
+  instructions?: string[];
+  projectId?: string | null;
+  tagTokens?: number;
+  dntTokens?: number;
+}): Promise<ProviderMaskedText> {
+  return await invoke<ProviderMaskedText>('protect_text_for_provider', {
+    args: {
+      text: params.text ?? '',
+      instructions: params.instructions ?? [],
+      projectId: params.projectId ?? null,
+      tagTokens: params.tagTokens ?? 0,
+      dntTokens: params.dntTokens ?? 0,
+    },
+  });
+}
+
+/**
+ * LLM 응답의 토큰을 원래 문자열로 복원
+ * - strict면 원문 토큰이 누락/중복되었을 때 TOKEN_MISMATCH 오류
+ */
+export async function restoreProviderText(params: {
+  text: string;
+  placeholders: ProviderPlaceholder[];
+  instructionPlaceholders?: ProviderPlaceholder[];
+  strict?: boolean;
+}): Promise<ProviderRestoreResult> {
+  return await invoke<ProviderRestoreResult>('restore_provider_text', {
+    args: {
+      text: params.text,
+      placeholders: params.placeholders,
+      instructionPlaceholders: params.instructionPlaceholders ?? [],
+      strict: params.strict ?? false,
+    },
+  });
+}
@@ -0,0 +1,43 @@
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import { invoke } from '@/tauri/invoke';
+
+/**
+ * 빠른 캡처(전역 단축키)로 받은 클립보드 텍스트
+ */
+export interface SegmentCapturedEvent {
+  projectId: string;
+  /** 원문 로캘 규칙으로 나눈 문장 (문장마다 새 원문 세그먼트, 공백 정리됨) */
+  sentences: string[];
+}
+
+/**
+ * 같은 프로젝트를 연 다른 창(또는 백엔드 작업)이 블록을 바꿨다는 알림
+ */
+export interface BlockChangedEvent {
+  projectId: string;
+  blockIds: string[];
+  /** 변경을 일으킨 창 label (없으면 백엔드 작업) */
+  sourceWindow: string | null;
+}
+
+/**
+ * 이 창이 보고 있는 프로젝트 기록 (null이면 프로젝트를 닫은 상태)
+ * - 빠른 캡처/파일 드롭/블록 변경 알림은 이 기록으로 대상 창을 찾습니다.
+ */
+export async function setWindowProject(projectId: string | null): Promise<void> {
+  await invoke<void>('set_window_project', { args: { projectId } });
+}
+
+/**
+ * 빠른 캡처 구독 (이 창이 캡처 대상일 때만 옴)
+ */
+export async function onSegmentCaptured(handler: (event: SegmentCapturedEvent) => void): Promise<UnlistenFn> {
+  return await listen<SegmentCapturedEvent>('segment-captured', (event) => handler(event.payload));
+}
+
+/**
+ * 블록 변경 알림 구독 (변경을 일으킨 창에는 오지 않음)
+ */
+export async function onBlockChanged(handler: (event: BlockChangedEvent) => void): Promise<UnlistenFn> {
+  return await listen<BlockChangedEvent>('block-changed', (event) => handler(event.payload));
+}
@@ -29,6 +29,8 @@ export interface ProjectMetadata {
   description?: string;
   domain: ProjectDomain;
   targetLanguage?: string; // 타겟 언어 (선택 사항)
+  sourceLocale?: string; // 원문 로캘 (BCP 47, 예: en-US)
+  targetLocale?: string; // 번역 로캘 (BCP 47, 저장 시 targetLanguage와 맞춰짐)
   createdAt: number;
   updatedAt: number;
   author?: string;
@@ -98,6 +100,21 @@ export interface BlockMetadata {
   updatedAt: number;
   tags: string[]; // {user} 등의 변수 인덱스
   comments?: BlockComment[];
+  capacity?: CapacityHint;
+}
+
+/**
+ * 원본 요소의 분량 제약 (PPTX 텍스트 상자 크기, 자막 큐 길이 등)
+ * - 원문 블록에 두면 같은 세그먼트의 번역문 블록에도 적용되며, 길이 QA가 이 값으로 검사합니다.
+ */
+export interface CapacityHint {
+  kind: 'textbox' | 'cue' | 'manual';
+  maxChars?: number;
+  maxWords?: number;
+  durationMs?: number;
+  widthEmu?: number;
+  heightEmu?: number;
+  fontSizePt?: number;
 }
 
 /**
@@ -161,6 +178,23 @@ export interface ChatMessage {
   content: string;
   timestamp: number;
   metadata?: ChatMessageMetadata;
+  /** 참조 블록/검색 인용/툴 결과 (DB에 행 단위로 저장되어 세션을 다시 열어도 유지됨) */
+  artifacts?: ChatMessageArtifact[];
+}
+
+/**
+ * 채팅 메시지 첨부 항목
+ * - block: reference = 블록 ID
+ * - citation: reference = URL 또는 문서 ID
+ * - tool_result: reference = 툴 이름
+ */
+export interface ChatMessageArtifact {
+  kind: 'block' | 'citation' | 'tool_result';
+  reference: string;
+  title?: string | null;
+  /** 발췌 또는 툴 결과 요약 */
+  content?: string | null;
+  data?: unknown;
 }
 
 /**
@@ -322,3 +356,69 @@ export interface Toast {
   duration?: number;
 }
 
+
+// ============================================
+// Command Errors
+// ============================================
+
+/**
+ * 백엔드 오류 코드 레지스트리 (src-tauri/src/error.rs `ErrorCode`와 동일하게 유지)
+ */
+export type ErrorCode =
+  | 'LOCK_ERROR'
+  | 'DB_ERROR'
+  | 'IO_ERROR'
+  | 'SERIALIZATION_ERROR'
+  | 'INVALID_INPUT'
+  | 'INVALID_OPERATION'
+  | 'NOT_FOUND'
+  | 'PROJECT_NOT_FOUND'
+  | 'BLOCK_NOT_FOUND'
+  | 'SEGMENT_NOT_FOUND'
+  | 'READ_ONLY'
+  | 'PATH_ERROR'
+  | 'SECURITY_ERROR'
+  | 'READ_ERROR'
+  | 'WRITE_ERROR'
+  | 'READ_DIR_ERROR'
+  | 'DIR_CREATE_ERROR'
+  | 'FILE_ERROR'
+  | 'FILE_TOO_LARGE'
+  | 'EXTRACT_ERROR'
+  | 'INVALID_TYPE'
+  | 'INVALID_PATTERN'
+  | 'INVALID_KEY'
+  | 'TOKEN_MISMATCH'
+  | 'NETWORK_ERROR'
+  | 'TIMEOUT'
+  | 'AUTH_REQUIRED'
+  | 'RATE_LIMITED'
+  | 'OFFLINE'
+  | 'QA_ERROR'
+  | 'MCP_ERROR'
+  | 'NOTION_ERROR'
+  | 'CONNECTOR_ERROR'
+  | 'CONFLUENCE_ERROR'
+  | 'CLOUD_ERROR'
+  | 'TMS_ERROR'
+  | 'SYNC_ERROR'
+  | 'UPDATE_ERROR'
+  | 'SECRET_MANAGER_ERROR'
+  | 'SECURE_STORE_ERROR'
+  | 'CLIPBOARD_ERROR'
+  | 'INTERNAL_PANIC';
+
+/**
+ * Tauri 명령이 reject할 때 전달되는 오류 본문
+ */
+export interface CommandErrorPayload {
+  code: ErrorCode;
+  message: string;
+  details: string | null;
+  /** 잠시 뒤 같은 요청을 다시 보내면 성공할 수 있음 */
+  retryable: boolean;
+  /** 원인 오류 메시지 (바깥 → 안쪽) */
+  sourceChain: string[];
+  /** 코드별 사용자용 문구 (설정 언어 uiLanguage 기준) */
+  localizedMessage: string;
+}
@@ -69,6 +69,7 @@ export interface UpdateBlockRequest {
  */
 export interface SplitBlockRequest {
   blockId: string;
+  /** 콘텐츠 기준 JS 문자열 오프셋 (음절/결합 문자 중간이면 앞으로 당겨 분할) */
   splitPosition: number;
 }
 